@@ -15,9 +15,11 @@
 
 use nv_redfish_csdl_compiler::commands::process_command;
 use nv_redfish_csdl_compiler::commands::Commands;
+use nv_redfish_csdl_compiler::commands::OutputFormat;
 use nv_redfish_schema::out_dir;
 use nv_redfish_schema::redfish_schema;
 use nv_redfish_schema::rerun_for;
+use nv_redfish_schema::REDFISH_CSDL_DIR_ENV;
 use std::env::var;
 use std::error::Error as StdError;
 
@@ -26,6 +28,7 @@ fn main() -> Result<(), Box<dyn StdError>> {
     if var("CARGO_FEATURE_REQWEST").is_err() {
         return Ok(());
     }
+    println!("cargo:rerun-if-env-changed={REDFISH_CSDL_DIR_ENV}");
 
     let root_csdls = ["RedfishError_v1.xml", "Message_v1.xml"]
         .iter()
@@ -45,10 +48,13 @@ fn main() -> Result<(), Box<dyn StdError>> {
 
     process_command(&Commands::CompileOem {
         output: out_dir().join("redfish.rs"),
+        split: false,
+        format: OutputFormat::Rust,
         root_csdls,
         resolve_csdls,
         entity_type_patterns: Vec::new(),
         rigid_array_patterns: Vec::new(),
+        intern_strings: false,
     })?;
 
     Ok(())
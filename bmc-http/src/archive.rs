@@ -0,0 +1,509 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! On-disk archive of GET responses.
+//!
+//! Unlike the response cache, the archive keeps history: every
+//! response body received by [`crate::HttpBmc`] is written as a
+//! gzip-compressed JSON file, so after an incident operators can
+//! inspect what the BMC reported at a specific time.
+//!
+//! The archive works as a ring buffer. Records older than
+//! [`ArchiveLimits::ttl`] are removed, and the oldest records are
+//! removed when the total size of the archive exceeds
+//! [`ArchiveLimits::max_bytes`].
+//!
+//! Records are written with the request that received the response.
+//! File system access and compression run on the blocking thread pool
+//! of the Tokio runtime, so archive operations must be called within
+//! the runtime.
+
+use std::collections::VecDeque;
+use std::convert::TryFrom as _;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::BufReader;
+use std::io::BufWriter;
+use std::io::Write as _;
+use std::ops::RangeBounds;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::clock::Clock;
+use crate::clock::SystemClock;
+use tokio::task;
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use nv_redfish_core::ODataId;
+use serde::de::Error as DeError;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+/// Extension of archive record files.
+const RECORD_EXTENSION: &str = "json.gz";
+
+/// Size and age limits of the [`ResponseArchive`].
+#[derive(Debug, Clone, Copy)]
+pub struct ArchiveLimits {
+    max_bytes: u64,
+    ttl: Duration,
+}
+
+impl Default for ArchiveLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 64 * 1024 * 1024,
+            ttl: Duration::from_secs(7 * 24 * 60 * 60),
+        }
+    }
+}
+
+impl ArchiveLimits {
+    /// Maximum total size of compressed records in bytes.
+    #[must_use]
+    pub const fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Maximum age of records.
+    #[must_use]
+    pub const fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+}
+
+/// Response read back from the archive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedResponse {
+    /// Identifier of the resource.
+    pub odata_id: ODataId,
+    /// Time when the response was received.
+    pub received_at: SystemTime,
+    /// Response body as it was received from the BMC.
+    pub body: JsonValue,
+}
+
+/// Response body together with the entity deserialized from it.
+pub(crate) struct Archived<T> {
+    pub body: JsonValue,
+    pub entity: T,
+}
+
+impl<'de, T: for<'a> Deserialize<'a>> Deserialize<'de> for Archived<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let body = JsonValue::deserialize(deserializer)?;
+        let entity = T::deserialize(&body).map_err(DeError::custom)?;
+        Ok(Self { body, entity })
+    }
+}
+
+/// Persistent archive of GET responses.
+pub struct ResponseArchive {
+    inner: Arc<ArchiveInner>,
+}
+
+struct ArchiveInner {
+    dir: PathBuf,
+    limits: ArchiveLimits,
+    clock: Arc<dyn Clock>,
+    state: Mutex<ArchiveState>,
+}
+
+impl ResponseArchive {
+    /// Open archive in the `dir` directory. The directory is created if
+    /// it doesn't exist. Records left by previous runs are kept as long
+    /// as they fit into `limits`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created or read.
+    pub async fn open<P: Into<PathBuf>>(dir: P, limits: ArchiveLimits) -> io::Result<Self> {
+        Self::open_with_clock(dir, limits, Arc::new(SystemClock)).await
+    }
+
+    /// Open archive like [`Self::open`], taking receive time of
+    /// records and age of records from `clock`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created or read.
+    pub async fn open_with_clock<P: Into<PathBuf>>(
+        dir: P,
+        limits: ArchiveLimits,
+        clock: Arc<dyn Clock>,
+    ) -> io::Result<Self> {
+        let dir = dir.into();
+        blocking(move || ArchiveInner::open(dir, limits, clock))
+            .await
+            .map(|inner| Self {
+                inner: Arc::new(inner),
+            })
+    }
+
+    /// Write the response `body` of the `odata_id` resource to the
+    /// archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the record cannot be written.
+    pub async fn record(&self, odata_id: &ODataId, body: JsonValue) -> io::Result<()> {
+        let received_at = self.inner.clock.system_time();
+        self.record_at(odata_id, body, received_at).await
+    }
+
+    /// Responses of the `odata_id` resource received within `range`,
+    /// oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record cannot be read.
+    pub async fn responses<R>(
+        &self,
+        odata_id: &ODataId,
+        range: R,
+    ) -> io::Result<Vec<ArchivedResponse>>
+    where
+        R: RangeBounds<SystemTime> + Send + 'static,
+    {
+        let inner = self.inner.clone();
+        let odata_id = odata_id.to_string();
+        blocking(move || inner.responses(&odata_id, &range)).await
+    }
+
+    /// Total size of compressed records in bytes.
+    #[must_use]
+    pub fn size(&self) -> u64 {
+        self.inner.state().total_bytes
+    }
+
+    async fn record_at(
+        &self,
+        odata_id: &ODataId,
+        body: JsonValue,
+        received_at: SystemTime,
+    ) -> io::Result<()> {
+        let inner = self.inner.clone();
+        let odata_id = odata_id.to_string();
+        blocking(move || inner.record_at(odata_id, &body, received_at)).await
+    }
+}
+
+impl ArchiveInner {
+    fn open(dir: PathBuf, limits: ArchiveLimits, clock: Arc<dyn Clock>) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        let mut records = Vec::new();
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if !is_record_file(&path) {
+                continue;
+            }
+            // Unreadable files are not ours or were partially written.
+            // They are not part of the archive.
+            if let Ok(record) = IndexEntry::load(path) {
+                records.push(record);
+            }
+        }
+        records.sort_by_key(|r| (r.received_at, r.seq));
+        let mut state = ArchiveState {
+            next_seq: records.last().map_or(0, |r| r.seq + 1),
+            total_bytes: records.iter().map(|r| r.size).sum(),
+            records: records.into(),
+        };
+        state.enforce(limits, clock.system_time());
+        Ok(Self {
+            dir,
+            limits,
+            clock,
+            state: Mutex::new(state),
+        })
+    }
+
+    fn responses<R: RangeBounds<SystemTime>>(
+        &self,
+        odata_id: &str,
+        range: &R,
+    ) -> io::Result<Vec<ArchivedResponse>> {
+        let paths = {
+            let mut state = self.state();
+            state.enforce(self.limits, self.clock.system_time());
+            state
+                .records
+                .iter()
+                .filter(|r| r.odata_id == odata_id && range.contains(&r.received_at))
+                .map(|r| r.path.clone())
+                .collect::<Vec<_>>()
+        };
+        paths
+            .into_iter()
+            .map(|path| {
+                let record = Record::load(&path)?;
+                Ok(ArchivedResponse {
+                    odata_id: record.odata_id.into(),
+                    received_at: from_unix_nanos(record.received_at),
+                    body: record.body,
+                })
+            })
+            .collect()
+    }
+
+    fn record_at(
+        &self,
+        odata_id: String,
+        body: &JsonValue,
+        received_at: SystemTime,
+    ) -> io::Result<()> {
+        let seq = {
+            let mut state = self.state();
+            state.next_seq += 1;
+            state.next_seq - 1
+        };
+        let record = Record {
+            odata_id,
+            received_at: unix_nanos(received_at),
+            body,
+        };
+        let path = self.dir.join(format!(
+            "{:020}-{seq:010}.{RECORD_EXTENSION}",
+            record.received_at
+        ));
+        let size = record.store(&path)?;
+        let mut state = self.state();
+        state.total_bytes += size;
+        state.records.push_back(IndexEntry {
+            odata_id: record.odata_id,
+            received_at,
+            seq,
+            path,
+            size,
+        });
+        state.enforce(self.limits, self.clock.system_time());
+        drop(state);
+        Ok(())
+    }
+
+    fn state(&self) -> MutexGuard<'_, ArchiveState> {
+        // Index is always consistent between statements, so it is safe
+        // to continue with poisoned lock.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Run blocking file system and compression work on the blocking
+/// thread pool.
+async fn blocking<T, F>(f: F) -> io::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> io::Result<T> + Send + 'static,
+{
+    task::spawn_blocking(f).await.map_err(io::Error::other)?
+}
+
+struct ArchiveState {
+    // Records sorted by receive time, oldest first.
+    records: VecDeque<IndexEntry>,
+    total_bytes: u64,
+    next_seq: u64,
+}
+
+impl ArchiveState {
+    fn enforce(&mut self, limits: ArchiveLimits, now: SystemTime) {
+        while let Some(oldest) = self.records.front() {
+            let expired = now
+                .duration_since(oldest.received_at)
+                .is_ok_and(|age| age > limits.ttl);
+            if !expired && self.total_bytes <= limits.max_bytes {
+                break;
+            }
+            if let Some(oldest) = self.records.pop_front() {
+                self.total_bytes = self.total_bytes.saturating_sub(oldest.size);
+                // Record file may be already removed by operator.
+                let _ = fs::remove_file(&oldest.path);
+            }
+        }
+    }
+}
+
+/// In-memory index entry of a record file.
+struct IndexEntry {
+    odata_id: String,
+    received_at: SystemTime,
+    seq: u64,
+    path: PathBuf,
+    size: u64,
+}
+
+impl IndexEntry {
+    fn load(path: PathBuf) -> io::Result<Self> {
+        let record = Record::load(&path)?;
+        let seq = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split(['-', '.']).nth(1))
+            .and_then(|seq| seq.parse().ok())
+            .unwrap_or_default();
+        Ok(Self {
+            odata_id: record.odata_id,
+            received_at: from_unix_nanos(record.received_at),
+            seq,
+            size: fs::metadata(&path)?.len(),
+            path,
+        })
+    }
+}
+
+/// Content of a record file.
+#[derive(Serialize, Deserialize)]
+struct Record<B> {
+    odata_id: String,
+    received_at: u64,
+    body: B,
+}
+
+impl Record<JsonValue> {
+    fn load(path: &Path) -> io::Result<Self> {
+        let decoder = GzDecoder::new(BufReader::new(File::open(path)?));
+        Ok(serde_json::from_reader(decoder)?)
+    }
+}
+
+impl<B: Serialize> Record<B> {
+    /// Store record to `path`. Returns size of the written file.
+    fn store(&self, path: &Path) -> io::Result<u64> {
+        let mut encoder =
+            GzEncoder::new(BufWriter::new(File::create(path)?), Compression::default());
+        serde_json::to_writer(&mut encoder, self)?;
+        encoder.finish()?.flush()?;
+        Ok(fs::metadata(path)?.len())
+    }
+}
+
+fn is_record_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(RECORD_EXTENSION))
+}
+
+fn unix_nanos(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .and_then(|d| u64::try_from(d.as_nanos()).ok())
+        .unwrap_or_default()
+}
+
+fn from_unix_nanos(nanos: u64) -> SystemTime {
+    UNIX_EPOCH + Duration::from_nanos(nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+    use std::env;
+    use std::process;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    fn archive_dir() -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = env::temp_dir().join(format!("nv-redfish-archive-{}-{n}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn id(v: &str) -> ODataId {
+        v.to_string().into()
+    }
+
+    #[tokio::test]
+    async fn responses_are_queried_by_id_and_time() -> io::Result<()> {
+        let dir = archive_dir();
+        let archive = ResponseArchive::open(&dir, ArchiveLimits::default()).await?;
+        let now = SystemTime::now();
+        let hour_ago = now - Duration::from_secs(3600);
+        archive
+            .record_at(&id("/redfish/v1"), json!({"v": 1}), hour_ago)
+            .await?;
+        archive
+            .record_at(&id("/redfish/v1/Systems"), json!({"v": 2}), now)
+            .await?;
+        archive
+            .record_at(&id("/redfish/v1"), json!({"v": 3}), now)
+            .await?;
+
+        let all = archive.responses(&id("/redfish/v1"), ..).await?;
+        assert_eq!(
+            all.iter().map(|r| r.body.clone()).collect::<Vec<_>>(),
+            vec![json!({"v": 1}), json!({"v": 3})]
+        );
+        let recent = archive.responses(&id("/redfish/v1"), now..).await?;
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].body, json!({"v": 3}));
+
+        // Archive is restored from disk.
+        drop(archive);
+        let archive = ResponseArchive::open(&dir, ArchiveLimits::default()).await?;
+        assert_eq!(archive.responses(&id("/redfish/v1"), ..).await?.len(), 2);
+        fs::remove_dir_all(dir)
+    }
+
+    #[tokio::test]
+    async fn limits_evict_oldest_records() -> io::Result<()> {
+        let dir = archive_dir();
+        let limits = ArchiveLimits::default().ttl(Duration::from_secs(60));
+        let archive = ResponseArchive::open(&dir, limits).await?;
+        let now = SystemTime::now();
+        archive
+            .record_at(
+                &id("/redfish/v1"),
+                json!({"v": 1}),
+                now - Duration::from_secs(120),
+            )
+            .await?;
+        archive
+            .record_at(&id("/redfish/v1"), json!({"v": 2}), now)
+            .await?;
+        assert_eq!(archive.responses(&id("/redfish/v1"), ..).await?.len(), 1);
+
+        // Compressed size varies slightly with receive time, leave half
+        // a record of slack to fit exactly two records.
+        let record_size = archive.size();
+        let limits = ArchiveLimits::default().max_bytes(record_size * 2 + record_size / 2);
+        let archive = ResponseArchive::open(&dir, limits).await?;
+        for v in 3..6 {
+            archive.record(&id("/redfish/v1"), json!({"v": v})).await?;
+        }
+        let responses = archive.responses(&id("/redfish/v1"), ..).await?;
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[1].body, json!({"v": 5}));
+        assert_eq!(fs::read_dir(&dir)?.count(), 2);
+        fs::remove_dir_all(dir)
+    }
+}
@@ -0,0 +1,65 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Time source of the HTTP client.
+//!
+//! Expiry of archived responses, expiry of idle pool connections and
+//! sleeps between retries read time through [`Clock`]. Production code
+//! uses [`SystemClock`]; tests install a clock that is advanced
+//! manually, so time-dependent behavior is checked without real
+//! sleeps.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use tokio::time::sleep;
+
+/// Future returned by [`Clock::sleep`].
+pub type Sleep = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Source of time.
+pub trait Clock: fmt::Debug + Send + Sync {
+    /// Current monotonic time. Used to measure durations.
+    fn now(&self) -> Instant;
+
+    /// Current wall clock time. Used for timestamps that outlive the
+    /// process.
+    fn system_time(&self) -> SystemTime;
+
+    /// Wait until `duration` passes on this clock.
+    fn sleep(&self, duration: Duration) -> Sleep;
+}
+
+/// Clock backed by the operating system.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        Box::pin(sleep(duration))
+    }
+}
@@ -13,9 +13,30 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! HTTP credentials type.
+//! HTTP credentials type and sources of credentials.
+//!
+//! [`CredentialsProvider`] looks credentials up by BMC endpoint.
+//! [`FileCredentials`] keeps credentials of endpoints in a JSON file
+//! readable only by its owner; the `keyring` feature adds an OS
+//! keyring backend.
 
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::error::Error as StdError;
 use std::fmt;
+use std::fs;
+use std::hash::BuildHasher as _;
+use std::hash::Hasher as _;
+use std::io;
+use std::io::ErrorKind;
+use std::io::Write as _;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+use url::Url;
 
 /// Credentials used to access the BMC.
 ///
@@ -87,3 +108,396 @@ impl fmt::Display for BmcCredentials {
         }
     }
 }
+
+/// Source of credentials of BMC endpoints.
+///
+/// Lets tools look credentials up by BMC endpoint instead of taking
+/// them from command line arguments.
+pub trait CredentialsProvider {
+    /// Error of the credentials lookup.
+    type Error: StdError + Send + Sync + 'static;
+
+    /// Credentials of the BMC at `endpoint`. Returns `Ok(None)` if the
+    /// provider doesn't have credentials of this endpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying store cannot be read.
+    fn credentials(&self, endpoint: &Url) -> Result<Option<BmcCredentials>, Self::Error>;
+
+    /// Credentials of the BMC at `endpoint` that must be known to the
+    /// provider.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CredentialsLookupError::NotFound`] if the provider
+    /// doesn't have credentials of this endpoint and
+    /// [`CredentialsLookupError::Provider`] if the lookup fails.
+    fn require_credentials(
+        &self,
+        endpoint: &Url,
+    ) -> Result<BmcCredentials, CredentialsLookupError<Self::Error>> {
+        self.credentials(endpoint)
+            .map_err(CredentialsLookupError::Provider)?
+            .ok_or_else(|| CredentialsLookupError::NotFound(endpoint.clone()))
+    }
+}
+
+/// Errors of [`CredentialsProvider::require_credentials`].
+#[derive(Debug)]
+pub enum CredentialsLookupError<E> {
+    /// Provider doesn't have credentials of the endpoint.
+    NotFound(Url),
+    /// Provider failed to look credentials up.
+    Provider(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CredentialsLookupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound(endpoint) => write!(f, "no credentials of {endpoint}"),
+            Self::Provider(err) => write!(f, "credentials lookup error: {err}"),
+        }
+    }
+}
+
+impl<E: StdError + 'static> StdError for CredentialsLookupError<E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::NotFound(_) => None,
+            Self::Provider(err) => Some(err),
+        }
+    }
+}
+
+impl CredentialsProvider for BmcCredentials {
+    type Error = Infallible;
+
+    fn credentials(&self, _endpoint: &Url) -> Result<Option<BmcCredentials>, Self::Error> {
+        Ok(Some(self.clone()))
+    }
+}
+
+/// Key of credentials of the `endpoint` in credentials stores. Paths
+/// of the same BMC share credentials.
+pub(crate) fn endpoint_key(endpoint: &Url) -> String {
+    endpoint.origin().ascii_serialization()
+}
+
+/// Credentials as saved by credentials stores.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub(crate) enum StoredCredentials {
+    UsernamePassword {
+        username: String,
+        password: Option<String>,
+    },
+    Token {
+        token: String,
+    },
+}
+
+impl From<&BmcCredentials> for StoredCredentials {
+    fn from(credentials: &BmcCredentials) -> Self {
+        match credentials {
+            BmcCredentials::UsernamePassword { username, password } => Self::UsernamePassword {
+                username: username.clone(),
+                password: password.clone(),
+            },
+            BmcCredentials::Token { token } => Self::Token {
+                token: token.clone(),
+            },
+        }
+    }
+}
+
+impl From<StoredCredentials> for BmcCredentials {
+    fn from(credentials: StoredCredentials) -> Self {
+        match credentials {
+            StoredCredentials::UsernamePassword { username, password } => {
+                Self::username_password(username, password)
+            }
+            StoredCredentials::Token { token } => Self::token(token),
+        }
+    }
+}
+
+/// Errors of [`FileCredentials`].
+#[derive(Debug)]
+pub enum FileCredentialsError {
+    /// Credentials file cannot be read or written.
+    Io(io::Error),
+    /// Credentials file doesn't contain credentials written by
+    /// [`FileCredentials`].
+    InvalidFile(serde_json::Error),
+}
+
+impl fmt::Display for FileCredentialsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "credentials file error: {err}"),
+            Self::InvalidFile(err) => write!(f, "invalid credentials file: {err}"),
+        }
+    }
+}
+
+impl StdError for FileCredentialsError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::InvalidFile(err) => Some(err),
+        }
+    }
+}
+
+/// Credentials provider backed by a JSON file.
+///
+/// The file maps origins of BMC endpoints (scheme, host and port) to
+/// their credentials. A missing file has no credentials. The file is
+/// written with owner-only permissions on Unix and replaced as a
+/// whole, so readers never see a partially written file.
+///
+/// # Example
+///
+/// ```no_run
+/// use nv_redfish_bmc_http::credentials::CredentialsProvider as _;
+/// use nv_redfish_bmc_http::credentials::FileCredentials;
+/// use nv_redfish_bmc_http::BmcCredentials;
+/// use url::Url;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let file = FileCredentials::new("/home/user/.config/my-bmc-tool/credentials.json");
+/// let endpoint = Url::parse("https://bmc.example.com")?;
+/// file.store(
+///     &endpoint,
+///     &BmcCredentials::new("admin".into(), "password".into()),
+/// )?;
+/// let credentials = file.credentials(&endpoint)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileCredentials {
+    path: PathBuf,
+}
+
+impl FileCredentials {
+    /// Create provider that keeps credentials in the file at `path`.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Path of the credentials file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Save credentials of `endpoint`, replacing previously saved
+    /// ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or written.
+    pub fn store(
+        &self,
+        endpoint: &Url,
+        credentials: &BmcCredentials,
+    ) -> Result<(), FileCredentialsError> {
+        let mut entries = self.read()?;
+        entries.insert(endpoint_key(endpoint), credentials.into());
+        self.write(&entries)
+    }
+
+    /// Remove saved credentials of `endpoint`. Removing credentials
+    /// that were not saved is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or written.
+    pub fn remove(&self, endpoint: &Url) -> Result<(), FileCredentialsError> {
+        let mut entries = self.read()?;
+        if entries.remove(&endpoint_key(endpoint)).is_some() {
+            self.write(&entries)?;
+        }
+        Ok(())
+    }
+
+    fn read(&self) -> Result<BTreeMap<String, StoredCredentials>, FileCredentialsError> {
+        match fs::read(&self.path) {
+            Ok(data) => serde_json::from_slice(&data).map_err(FileCredentialsError::InvalidFile),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(err) => Err(FileCredentialsError::Io(err)),
+        }
+    }
+
+    fn write(
+        &self,
+        entries: &BTreeMap<String, StoredCredentials>,
+    ) -> Result<(), FileCredentialsError> {
+        let data = serde_json::to_vec_pretty(entries).map_err(FileCredentialsError::InvalidFile)?;
+        let (tmp, mut file) = self.create_temp_file().map_err(FileCredentialsError::Io)?;
+        let written = file
+            .write_all(&data)
+            .and_then(|()| file.sync_all())
+            .and_then(|()| fs::rename(&tmp, &self.path));
+        if written.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+        written.map_err(FileCredentialsError::Io)
+    }
+
+    /// Create a temporary file next to the credentials file. The name
+    /// is random and the file is created exclusively, so a file or a
+    /// symlink planted at a predictable path is never written to.
+    fn create_temp_file(&self) -> io::Result<(PathBuf, fs::File)> {
+        const ATTEMPTS: usize = 8;
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt as _;
+            options.mode(0o600);
+        }
+        let mut attempt = 1;
+        loop {
+            // Every `RandomState` has new random keys.
+            let suffix = RandomState::new().build_hasher().finish();
+            let mut name = self.path.clone().into_os_string();
+            name.push(format!(".{suffix:016x}.tmp"));
+            let tmp = PathBuf::from(name);
+            match options.open(&tmp) {
+                Err(err) if err.kind() == ErrorKind::AlreadyExists && attempt < ATTEMPTS => {
+                    attempt += 1;
+                }
+                result => return result.map(|file| (tmp, file)),
+            }
+        }
+    }
+}
+
+impl CredentialsProvider for FileCredentials {
+    type Error = FileCredentialsError;
+
+    fn credentials(&self, endpoint: &Url) -> Result<Option<BmcCredentials>, Self::Error> {
+        Ok(self
+            .read()?
+            .remove(&endpoint_key(endpoint))
+            .map(BmcCredentials::from))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::process;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+
+    fn credentials_file() -> FileCredentials {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path =
+            env::temp_dir().join(format!("nv-redfish-credentials-{}-{n}.json", process::id()));
+        let _ = fs::remove_file(&path);
+        FileCredentials::new(path)
+    }
+
+    fn url(v: &str) -> Url {
+        Url::parse(v).expect("valid URL")
+    }
+
+    #[test]
+    fn file_credentials_are_stored_by_endpoint_origin() -> Result<(), Box<dyn StdError>> {
+        let file = credentials_file();
+        let bmc1 = url("https://bmc1.example.com");
+        let bmc2 = url("https://bmc2.example.com:8443");
+        assert_eq!(file.credentials(&bmc1)?, None);
+
+        let admin = BmcCredentials::new("admin".into(), "secret".into());
+        let token = BmcCredentials::token("token".into());
+        file.store(&bmc1, &admin)?;
+        file.store(&bmc2, &token)?;
+
+        assert_eq!(
+            file.credentials(&url("https://bmc1.example.com/redfish/v1"))?,
+            Some(admin)
+        );
+        assert_eq!(file.credentials(&bmc2)?, Some(token));
+        assert_eq!(file.credentials(&url("https://bmc2.example.com"))?, None);
+
+        file.remove(&bmc1)?;
+        file.remove(&bmc1)?;
+        assert_eq!(file.credentials(&bmc1)?, None);
+        assert!(file.credentials(&bmc2)?.is_some());
+
+        fs::remove_file(file.path())?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn file_credentials_are_readable_only_by_owner() -> Result<(), Box<dyn StdError>> {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let file = credentials_file();
+        file.store(
+            &url("https://bmc.example.com"),
+            &BmcCredentials::new("admin".into(), "secret".into()),
+        )?;
+        let mode = fs::metadata(file.path())?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        fs::remove_file(file.path())?;
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn leftover_temp_file_is_not_reused() -> Result<(), Box<dyn StdError>> {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        let file = credentials_file();
+        let mut tmp_name = file.path().to_path_buf().into_os_string();
+        tmp_name.push(".tmp");
+        let tmp = PathBuf::from(tmp_name);
+        fs::write(&tmp, "planted")?;
+        fs::set_permissions(&tmp, fs::Permissions::from_mode(0o644))?;
+
+        file.store(
+            &url("https://bmc.example.com"),
+            &BmcCredentials::new("admin".into(), "secret".into()),
+        )?;
+        let mode = fs::metadata(file.path())?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(fs::read_to_string(&tmp)?, "planted");
+
+        fs::remove_file(&tmp)?;
+        fs::remove_file(file.path())?;
+        Ok(())
+    }
+
+    #[test]
+    fn require_credentials_reports_missing_endpoint() -> Result<(), Box<dyn StdError>> {
+        let file = credentials_file();
+        let endpoint = url("https://bmc.example.com");
+        assert!(matches!(
+            file.require_credentials(&endpoint),
+            Err(CredentialsLookupError::NotFound(missing)) if missing == endpoint
+        ));
+
+        fs::write(file.path(), "not json")?;
+        assert!(matches!(
+            file.require_credentials(&endpoint),
+            Err(CredentialsLookupError::Provider(
+                FileCredentialsError::InvalidFile(_)
+            ))
+        ));
+
+        fs::remove_file(file.path())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Credentials stored in the OS keyring.
+//!
+//! [`KeyringCredentials`] keeps credentials of BMC endpoints in the
+//! platform secret store (Secret Service on Linux, Keychain on macOS,
+//! Credential Manager on Windows), so command line tools can save
+//! credentials once and don't take passwords as arguments.
+//!
+//! Each endpoint is stored as a separate keyring entry of the
+//! configured service. Entries are keyed by the origin of the
+//! endpoint URL (scheme, host and port), so different paths of the
+//! same BMC share credentials.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use keyring::Entry;
+use url::Url;
+
+use crate::credentials::endpoint_key;
+use crate::credentials::CredentialsProvider;
+use crate::credentials::StoredCredentials;
+use crate::BmcCredentials;
+
+/// Errors of [`KeyringCredentials`].
+#[derive(Debug)]
+pub enum KeyringError {
+    /// Error of the platform keyring.
+    Keyring(keyring::Error),
+    /// Keyring entry doesn't contain credentials written by
+    /// [`KeyringCredentials`].
+    InvalidEntry(serde_json::Error),
+}
+
+impl fmt::Display for KeyringError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keyring(err) => write!(f, "keyring error: {err}"),
+            Self::InvalidEntry(err) => write!(f, "invalid keyring entry: {err}"),
+        }
+    }
+}
+
+impl StdError for KeyringError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Keyring(err) => Some(err),
+            Self::InvalidEntry(err) => Some(err),
+        }
+    }
+}
+
+/// Credentials provider backed by the OS keyring.
+///
+/// # Example
+///
+/// ```no_run
+/// use nv_redfish_bmc_http::credentials::CredentialsProvider as _;
+/// use nv_redfish_bmc_http::keyring::KeyringCredentials;
+/// use nv_redfish_bmc_http::BmcCredentials;
+/// use url::Url;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let keyring = KeyringCredentials::new("my-bmc-tool");
+/// let endpoint = Url::parse("https://bmc.example.com")?;
+/// keyring.store(
+///     &endpoint,
+///     &BmcCredentials::new("admin".into(), "password".into()),
+/// )?;
+/// let credentials = keyring.credentials(&endpoint)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyringCredentials {
+    service: String,
+}
+
+impl KeyringCredentials {
+    /// Create provider that stores entries under `service` name,
+    /// usually the name of the tool.
+    #[must_use]
+    pub fn new(service: impl Into<String>) -> Self {
+        Self {
+            service: service.into(),
+        }
+    }
+
+    /// Save credentials of `endpoint`, replacing previously saved
+    /// ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyring rejects the entry.
+    pub fn store(&self, endpoint: &Url, credentials: &BmcCredentials) -> Result<(), KeyringError> {
+        let secret = serde_json::to_string(&StoredCredentials::from(credentials))
+            .map_err(KeyringError::InvalidEntry)?;
+        self.entry(endpoint)?
+            .set_password(&secret)
+            .map_err(KeyringError::Keyring)
+    }
+
+    /// Remove saved credentials of `endpoint`. Removing credentials
+    /// that were not saved is not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the keyring fails to remove the entry.
+    pub fn remove(&self, endpoint: &Url) -> Result<(), KeyringError> {
+        match self.entry(endpoint)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(err) => Err(KeyringError::Keyring(err)),
+        }
+    }
+
+    fn entry(&self, endpoint: &Url) -> Result<Entry, KeyringError> {
+        Entry::new(&self.service, &endpoint_key(endpoint)).map_err(KeyringError::Keyring)
+    }
+}
+
+impl CredentialsProvider for KeyringCredentials {
+    type Error = KeyringError;
+
+    fn credentials(&self, endpoint: &Url) -> Result<Option<BmcCredentials>, Self::Error> {
+        let secret = match self.entry(endpoint)?.get_password() {
+            Ok(secret) => secret,
+            Err(keyring::Error::NoEntry) => return Ok(None),
+            Err(err) => return Err(KeyringError::Keyring(err)),
+        };
+        serde_json::from_str::<StoredCredentials>(&secret)
+            .map(|credentials| Some(credentials.into()))
+            .map_err(KeyringError::InvalidEntry)
+    }
+}
@@ -39,8 +39,14 @@
 
 //! HTTP implementation of [`nv_redfish_core::Bmc`] trait.
 
+#[cfg(feature = "archive")]
+pub mod archive;
 pub mod cache;
+#[cfg(any(feature = "reqwest", feature = "archive"))]
+pub mod clock;
 pub mod credentials;
+#[cfg(feature = "keyring")]
+pub mod keyring;
 
 #[cfg(feature = "reqwest")]
 mod schema;
@@ -51,7 +57,10 @@ pub mod reqwest;
 use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::fmt;
+use std::future::ready;
 use std::future::Future;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::RwLock;
 
@@ -59,30 +68,49 @@ use crate::cache::TypeErasedCarCache;
 
 use http::HeaderMap;
 use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::query::FilterQuery;
 use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ETagPolicy;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::Expandable;
-use nv_redfish_core::FilterQuery;
+use nv_redfish_core::LatencyClass;
 use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NullChecked;
+use nv_redfish_core::NullPolicy;
+use nv_redfish_core::NullValueError;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::RedfishError;
 use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::TaskMonitorResponse;
 use nv_redfish_core::UploadReader;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use url::Url;
 
+#[cfg(any(feature = "reqwest", feature = "archive"))]
+#[doc(inline)]
+pub use clock::Clock;
+#[cfg(any(feature = "reqwest", feature = "archive"))]
+#[doc(inline)]
+pub use clock::SystemClock;
 #[doc(inline)]
 pub use credentials::BmcCredentials;
+#[doc(inline)]
+pub use credentials::CredentialsLookupError;
+#[doc(inline)]
+pub use credentials::CredentialsProvider;
 
 #[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
 pub use nv_redfish_core::HttpPushUriUpdateRequest;
-#[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
 pub use nv_redfish_core::UploadStream;
 
+#[doc(inline)]
+pub use nv_redfish_core::BinaryUploadRequest;
 #[doc(inline)]
 pub use nv_redfish_core::MultipartUpdateRequest;
 
@@ -105,6 +133,22 @@ pub trait HttpClient: Send + Sync {
     where
         T: DeserializeOwned + Send + Sync;
 
+    /// Perform an HTTP GET request for a JSON document that is not a
+    /// Redfish resource, such as a message registry file.
+    ///
+    /// The default implementation performs [`Self::get`].
+    fn get_json<T>(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<T, Self::Error>> + Send
+    where
+        T: DeserializeOwned + Send + Sync,
+    {
+        self.get(url, credentials, None, custom_headers)
+    }
+
     /// Perform an HTTP POST request.
     fn post<B, T>(
         &self,
@@ -162,11 +206,42 @@ pub trait HttpClient: Send + Sync {
         U: UploadReader,
         T: DeserializeOwned + Send + Sync;
 
+    /// Perform an HTTP GET request for raw binary data.
+    ///
+    /// Clients that cannot transfer binary data return `Ok(None)`.
+    fn get_binary(
+        &self,
+        _url: Url,
+        _credentials: &BmcCredentials,
+        _custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<Option<Vec<u8>>, Self::Error>> + Send {
+        ready(Ok(None))
+    }
+
+    /// Perform an HTTP PUT request with a raw binary body.
+    ///
+    /// Clients that cannot transfer binary data return `Ok(None)`.
+    fn put_binary<U, T>(
+        &self,
+        _url: Url,
+        _request: BinaryUploadRequest<U>,
+        _credentials: &BmcCredentials,
+        _custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<Option<ModificationResponse<T>>, Self::Error>> + Send
+    where
+        U: UploadReader,
+        T: DeserializeOwned + Send + Sync,
+    {
+        ready(Ok(None))
+    }
+
     /// Perform an HTTP PATCH request.
+    ///
+    /// `etag` is sent as `If-Match` header when provided.
     fn patch<B, T>(
         &self,
         url: Url,
-        etag: ODataETag,
+        etag: Option<ODataETag>,
         body: &B,
         credentials: &BmcCredentials,
         custom_headers: &HeaderMap,
@@ -192,6 +267,70 @@ pub trait HttpClient: Send + Sync {
         credentials: &BmcCredentials,
         custom_headers: &HeaderMap,
     ) -> impl Future<Output = Result<BoxTryStream<T, Self::Error>, Self::Error>> + Send;
+
+    /// Perform an HTTP HEAD request and return methods declared in the
+    /// `Allow` header of the response. `None` if the service doesn't
+    /// declare them.
+    ///
+    /// Clients that cannot send HEAD requests return `Ok(None)`, which
+    /// means that allowed methods are unknown.
+    fn allowed_methods(
+        &self,
+        _url: Url,
+        _credentials: &BmcCredentials,
+        _custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<Option<AllowedMethods>, Self::Error>> + Send {
+        ready(Ok(None))
+    }
+
+    /// Perform an HTTP GET request to the task monitor of an
+    /// asynchronous operation.
+    ///
+    /// Clients that cannot read task monitors return `Ok(None)`.
+    fn get_task_monitor(
+        &self,
+        _url: Url,
+        _credentials: &BmcCredentials,
+        _custom_headers: &HeaderMap,
+    ) -> impl Future<Output = Result<Option<TaskMonitorResponse>, Self::Error>> + Send {
+        ready(Ok(None))
+    }
+
+    /// Latency class of the `url` host derived from time to the first
+    /// byte of recent responses. `None` if the client does not measure
+    /// latency or did not send requests to the host yet.
+    fn latency_class(&self, _url: &Url) -> Option<LatencyClass> {
+        None
+    }
+
+    /// Body of the HTTP error response carried by the error, if any.
+    fn error_response_body(_error: &Self::Error) -> Option<&str> {
+        None
+    }
+
+    /// Language of the HTTP error response carried by the error, if
+    /// any.
+    fn error_response_language(_error: &Self::Error) -> Option<&str> {
+        None
+    }
+
+    /// Status code of the HTTP error response carried by the error, if
+    /// any.
+    fn error_status_code(_error: &Self::Error) -> Option<u16> {
+        None
+    }
+
+    /// Redfish error parsed from the HTTP error response carried by the
+    /// error, if any.
+    fn redfish_error(_error: &Self::Error) -> Option<&RedfishError> {
+        None
+    }
+
+    /// `@odata.type` of the response payload that could not be
+    /// deserialized, if the error carries one.
+    fn error_odata_type(_error: &Self::Error) -> Option<&str> {
+        None
+    }
 }
 
 /// HTTP-based BMC implementation that wraps an [`HttpClient`].
@@ -216,11 +355,24 @@ pub struct HttpBmc<C: HttpClient> {
     credentials: RwLock<Arc<BmcCredentials>>,
     cache: RwLock<TypeErasedCarCache<Url>>,
     etags: RwLock<HashMap<Url, ODataETag>>,
+    etag_policy: RwLock<ETagPolicy>,
+    null_policy: RwLock<NullPolicy>,
     custom_headers: HeaderMap,
 
+    // Number of times an update rejected with 412 Precondition Failed
+    // is sent again with a fresh ETag. Zero disables retries.
+    precondition_retries: u32,
+
     // Response bodies and ETags are enabled or disabled together because a
     // 304 Not Modified response contains no replacement body.
     cache_enabled: bool,
+
+    // Number of cached entries that were invalidated and fetched again
+    // because they could not be served as the requested type.
+    cache_refetches: AtomicU64,
+
+    #[cfg(feature = "archive")]
+    archive: Option<archive::ResponseArchive>,
 }
 
 impl<C: HttpClient> HttpBmc<C>
@@ -332,11 +484,81 @@ where
             credentials: RwLock::new(Arc::new(credentials)),
             cache: RwLock::new(TypeErasedCarCache::new(cache_settings.capacity)),
             etags: RwLock::new(HashMap::new()),
+            etag_policy: RwLock::new(ETagPolicy::default()),
+            null_policy: RwLock::new(NullPolicy::Permissive),
             custom_headers,
+            precondition_retries: 0,
             cache_enabled: cache_settings.capacity > 0,
+            cache_refetches: AtomicU64::new(0),
+            #[cfg(feature = "archive")]
+            archive: None,
         }
     }
 
+    /// Retry entity updates rejected with `412 Precondition Failed`.
+    ///
+    /// A 412 response means that the entity changed after its `ETag`
+    /// was read. Before every retry the entity is read again to get
+    /// its current `ETag`, and properties that already have the
+    /// requested values are dropped from the payload. When the
+    /// service still answers 412 after `retries` retries, `update`
+    /// returns [`RequestError::conflict`]. Retries are disabled by
+    /// default.
+    #[must_use]
+    pub const fn with_precondition_retries(mut self, retries: u32) -> Self {
+        self.precondition_retries = retries;
+        self
+    }
+
+    /// Handle `null` values of non-nullable properties in responses
+    /// according to `policy`. Default is [`NullPolicy::Permissive`].
+    ///
+    /// The policy is applied to the response of every request. It is
+    /// part of the configuration of this BMC, so clients that need
+    /// another policy use their own BMC.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal policy lock is poisoned. This should not
+    /// occur in normal operation.
+    #[must_use]
+    #[allow(clippy::panic)] // See panics section.
+    pub fn with_null_policy(mut self, policy: NullPolicy) -> Self {
+        *self.null_policy.get_mut().expect("poisoned") = policy;
+        self
+    }
+
+    /// Write body of every GET response to `archive`.
+    ///
+    /// Responses served from cache (304 Not Modified) are not written
+    /// again. Failures to write the archive do not affect requests.
+    #[cfg(feature = "archive")]
+    #[must_use]
+    pub fn with_response_archive(mut self, archive: archive::ResponseArchive) -> Self {
+        self.archive = Some(archive);
+        self
+    }
+
+    /// Archive of GET responses, if configured.
+    #[cfg(feature = "archive")]
+    #[must_use]
+    pub const fn response_archive(&self) -> Option<&archive::ResponseArchive> {
+        self.archive.as_ref()
+    }
+
+    /// Number of cached responses that were invalidated and fetched
+    /// again from the BMC.
+    ///
+    /// This happens when the BMC confirms with 304 Not Modified that
+    /// the cached response is up to date, but the cached entry cannot
+    /// be used as the requested type (for example, the entry was
+    /// evicted or the same resource is now read with a different
+    /// schema type).
+    #[must_use]
+    pub fn cache_refetch_count(&self) -> u64 {
+        self.cache_refetches.load(Ordering::Relaxed)
+    }
+
     /// Replace the credentials used for subsequent requests.
     ///
     /// Existing cache and ETag state is preserved.
@@ -349,6 +571,52 @@ where
     pub fn set_credentials(&self, credentials: BmcCredentials) {
         *self.credentials.write().expect("poisoned") = Arc::new(credentials);
     }
+
+    /// Create a new HTTP-based BMC client with credentials of
+    /// `redfish_endpoint` looked up in `provider`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider doesn't have credentials of
+    /// the endpoint or fails to look them up.
+    pub fn with_credentials_provider<P>(
+        client: C,
+        redfish_endpoint: Url,
+        provider: &P,
+        cache_settings: CacheSettings,
+    ) -> Result<Self, CredentialsLookupError<P::Error>>
+    where
+        P: CredentialsProvider + ?Sized,
+    {
+        let credentials = provider.require_credentials(&redfish_endpoint)?;
+        Ok(Self::new(
+            client,
+            redfish_endpoint,
+            credentials,
+            cache_settings,
+        ))
+    }
+
+    /// Replace the credentials used for subsequent requests with the
+    /// credentials of the BMC endpoint looked up in `provider`, for
+    /// example after they were changed in the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the provider doesn't have credentials of
+    /// the endpoint or fails to look them up. The credentials in use
+    /// are kept in this case.
+    pub fn reload_credentials<P>(
+        &self,
+        provider: &P,
+    ) -> Result<(), CredentialsLookupError<P::Error>>
+    where
+        P: CredentialsProvider + ?Sized,
+    {
+        let credentials = provider.require_credentials(&self.redfish_endpoint.base_url)?;
+        self.set_credentials(credentials);
+        Ok(())
+    }
 }
 
 /// A tagged type representing a Redfish endpoint URL.
@@ -438,9 +706,9 @@ impl RedfishEndpoint {
     /// Callers must explicitly wrap service-provided values in
     /// [`UriReference`] before using this method. Use it only for schema fields
     /// that allow URI references, such as action targets,
-    /// `MultipartHttpPushUri`, `HttpPushUri`, and event stream URIs. Same-origin
-    /// is checked with parsed URL origins: scheme, host, and effective port. It
-    /// is not a string prefix check, so prefix lookalikes such as
+    /// `MultipartHttpPushUri`, `HttpPushUri`, `BinaryDataURI`, and event stream
+    /// URIs. Same-origin is checked with parsed URL origins: scheme, host, and
+    /// effective port. It is not a string prefix check, so prefix lookalikes such as
     /// `https://bmc.example.evil/...` are rejected.
     ///
     /// With base URL `https://bmc.example`:
@@ -548,6 +816,15 @@ pub trait CacheableError {
 pub trait RequestError {
     /// Create an error from a rejected service URI reference.
     fn rejected_uri_reference(error: RejectedUriReferenceError) -> Self;
+
+    /// Create an error for an update of `url` that was rejected with
+    /// `412 Precondition Failed` on every one of `attempts` requests.
+    fn conflict(url: Url, attempts: u32) -> Self;
+
+    /// Create an error for a response of `url` that contained `null`
+    /// values of non-nullable properties rejected by
+    /// [`NullPolicy::Strict`].
+    fn null_value(url: Url, error: NullValueError) -> Self;
 }
 
 impl<C: HttpClient> HttpBmc<C>
@@ -562,12 +839,160 @@ where
             .expect("lock poisoned")
     }
 
+    #[allow(clippy::panic)] // See set_etag_policy Panic doc.
+    fn read_etag_policy(&self) -> ETagPolicy {
+        *self.etag_policy.read().expect("lock poisoned")
+    }
+
+    #[allow(clippy::panic)] // See set_null_policy Panic doc.
+    fn read_null_policy(&self) -> NullPolicy {
+        *self.null_policy.read().expect("lock poisoned")
+    }
+
+    /// Apply the null policy to `value` deserialized from response of
+    /// `url`.
+    fn null_checked<T>(&self, url: &Url, value: NullChecked<T>) -> Result<T, C::Error> {
+        value
+            .apply(self.read_null_policy())
+            .map_err(|e| C::Error::null_value(url.clone(), e))
+    }
+
+    fn null_checked_modification<T>(
+        &self,
+        url: &Url,
+        response: ModificationResponse<NullChecked<T>>,
+    ) -> Result<ModificationResponse<T>, C::Error> {
+        response.try_map_entity(|value| self.null_checked(url, value))
+    }
+
+    /// Send the update again after `412 Precondition Failed`.
+    ///
+    /// Every attempt reads the entity without cache to get its current
+    /// `ETag` and recomputes the payload against it. If the entity
+    /// already has all requested values, nothing is sent.
+    async fn retry_update<V, R>(
+        &self,
+        endpoint_url: Url,
+        v: &V,
+    ) -> Result<ModificationResponse<R>, C::Error>
+    where
+        V: Serialize + Send + Sync,
+        R: DeserializeOwned + Send + Sync,
+    {
+        // Payload that cannot be represented as JSON object is sent
+        // as is.
+        let payload = serde_json::to_value(v)
+            .ok()
+            .filter(serde_json::Value::is_object);
+        let credentials = self.read_credentials();
+        for _ in 0..self.precondition_retries {
+            // Cached representation is stale: the service just
+            // rejected its ETag.
+            self.forget_etag(&endpoint_url)?;
+            let current = self
+                .client
+                .get::<serde_json::Value>(
+                    endpoint_url.clone(),
+                    credentials.as_ref(),
+                    None,
+                    &self.custom_headers,
+                )
+                .await?;
+            let etag = current
+                .get("@odata.etag")
+                .and_then(serde_json::Value::as_str)
+                .map(|etag| ODataETag::from(etag.to_string()));
+            let etag = self.read_etag_policy().if_match(etag.as_ref());
+            let result = if let Some(payload) = &payload {
+                let patch = changed_properties(payload, &current);
+                if patch.as_object().is_some_and(serde_json::Map::is_empty) {
+                    return Ok(ModificationResponse::Empty);
+                }
+                self.client
+                    .patch::<_, NullChecked<R>>(
+                        endpoint_url.clone(),
+                        etag,
+                        &patch,
+                        credentials.as_ref(),
+                        &self.custom_headers,
+                    )
+                    .await
+            } else {
+                self.client
+                    .patch::<_, NullChecked<R>>(
+                        endpoint_url.clone(),
+                        etag,
+                        v,
+                        credentials.as_ref(),
+                        &self.custom_headers,
+                    )
+                    .await
+            }
+            .and_then(|response| self.null_checked_modification(&endpoint_url, response));
+            match result {
+                Err(e) if is_precondition_failed::<C>(&e) => {}
+                result => return result,
+            }
+        }
+        Err(C::Error::conflict(
+            endpoint_url,
+            self.precondition_retries.saturating_add(1),
+        ))
+    }
+
+    /// Forget `ETag` of `url`, so the next read fetches the entity
+    /// instead of serving the cached representation.
+    fn forget_etag(&self, url: &Url) -> Result<(), C::Error> {
+        self.etags
+            .write()
+            .map_err(|e| C::Error::cache_error(e.to_string()))?
+            .remove(url);
+        Ok(())
+    }
+
+    /// Perform a GET request and write the response to the archive if
+    /// it is configured.
+    async fn fetch<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        endpoint_url: Url,
+        credentials: &BmcCredentials,
+        etag: Option<ODataETag>,
+    ) -> Result<T, C::Error> {
+        #[cfg(feature = "archive")]
+        if let Some(archive) = &self.archive {
+            let response = self
+                .client
+                .get::<archive::Archived<NullChecked<T>>>(
+                    endpoint_url.clone(),
+                    credentials,
+                    etag,
+                    &self.custom_headers,
+                )
+                .await?;
+            let entity = self.null_checked(&endpoint_url, response.entity)?;
+            // Archive is best effort and must not affect requests.
+            let _ = archive.record(entity.odata_id(), response.body).await;
+            return Ok(entity);
+        }
+        self.client
+            .get::<NullChecked<T>>(
+                endpoint_url.clone(),
+                credentials,
+                etag,
+                &self.custom_headers,
+            )
+            .await
+            .and_then(|value| self.null_checked(&endpoint_url, value))
+    }
+
     /// Perform a GET request with `ETag` caching support
     ///
     /// This handles:
     /// - Retrieving cached `ETag` before request
     /// - Sending conditional GET with If-None-Match
     /// - Handling 304 Not Modified responses from cache
+    /// - Fetching the resource again when the cached entry cannot be
+    ///   read as `T`
     /// - Updating cache and `ETag` storage on success
     #[allow(clippy::significant_drop_tightening)]
     async fn get_with_cache<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
@@ -591,16 +1016,11 @@ where
         };
 
         let credentials = self.read_credentials();
+        let conditional = etag.is_some();
 
         // Perform GET request
         match self
-            .client
-            .get::<T>(
-                endpoint_url,
-                credentials.as_ref(),
-                etag,
-                &self.custom_headers,
-            )
+            .fetch::<T>(endpoint_url.clone(), credentials.as_ref(), etag)
             .await
         {
             Ok(response) if !self.cache_enabled => {
@@ -608,46 +1028,84 @@ where
                 // `None`, and we can return early with the response entity.
                 Ok(Arc::new(response))
             }
-            Ok(response) => {
-                let entity = Arc::new(response);
-                // Update cache if entity has etag
-                if let Some(etag) = entity.etag() {
-                    let mut cache = self
-                        .cache
-                        .write()
-                        .map_err(|e| C::Error::cache_error(e.to_string()))?;
-
-                    let mut etags = self
-                        .etags
-                        .write()
-                        .map_err(|e| C::Error::cache_error(e.to_string()))?;
-
-                    if let Some(evicted_url) =
-                        cache.put_typed(cache_key.clone(), Arc::clone(&entity))
-                    {
-                        etags.remove(&evicted_url);
-                    }
-                    etags.insert(cache_key.clone(), etag.clone());
-                }
-                Ok(entity)
-            }
+            Ok(response) => self.store_in_cache(cache_key, response),
             Err(e) => {
                 // Handle 304 Not Modified - return from cache
                 if e.is_cached() {
-                    let mut cache = self
+                    let cached = self
                         .cache
                         .write()
-                        .map_err(|e| C::Error::cache_error(e.to_string()))?;
-                    cache
+                        .map_err(|e| C::Error::cache_error(e.to_string()))?
                         .get_typed::<Arc<T>>(&cache_key)
-                        .cloned()
-                        .ok_or_else(C::Error::cache_miss)
+                        .cloned();
+                    match cached {
+                        Some(entity) => Ok(entity),
+                        None if conditional => {
+                            // Cached entry cannot be read as `T`. Forget
+                            // its ETag and read the resource again
+                            // without If-None-Match.
+                            self.etags
+                                .write()
+                                .map_err(|e| C::Error::cache_error(e.to_string()))?
+                                .remove(&cache_key);
+                            self.cache_refetches.fetch_add(1, Ordering::Relaxed);
+                            let response = self
+                                .fetch::<T>(endpoint_url, credentials.as_ref(), None)
+                                .await
+                                .map_err(|e| {
+                                    if e.is_cached() {
+                                        C::Error::cache_miss()
+                                    } else {
+                                        e
+                                    }
+                                })?;
+                            self.store_in_cache(cache_key, response)
+                        }
+                        None => Err(C::Error::cache_miss()),
+                    }
                 } else {
                     Err(e)
                 }
             }
         }
     }
+
+    /// Store the entity in cache if it has `ETag`.
+    fn store_in_cache<T: EntityTypeRef + 'static>(
+        &self,
+        cache_key: Url,
+        response: T,
+    ) -> Result<Arc<T>, C::Error> {
+        let entity = Arc::new(response);
+        self.put_in_cache(cache_key, &entity)?;
+        Ok(entity)
+    }
+
+    /// Put the entity to cache if it has `ETag`.
+    #[allow(clippy::significant_drop_tightening)]
+    fn put_in_cache<T: EntityTypeRef + 'static>(
+        &self,
+        cache_key: Url,
+        entity: &Arc<T>,
+    ) -> Result<(), C::Error> {
+        if let Some(etag) = entity.etag() {
+            let mut cache = self
+                .cache
+                .write()
+                .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+            let mut etags = self
+                .etags
+                .write()
+                .map_err(|e| C::Error::cache_error(e.to_string()))?;
+
+            if let Some(evicted_url) = cache.put_typed(cache_key.clone(), Arc::clone(entity)) {
+                etags.remove(&evicted_url);
+            }
+            etags.insert(cache_key, etag.clone());
+        }
+        Ok(())
+    }
 }
 
 impl<C: HttpClient> Bmc for HttpBmc<C>
@@ -664,6 +1122,29 @@ where
         self.get_with_cache(endpoint_url).await
     }
 
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        let credentials = self.read_credentials();
+        match self
+            .fetch::<T>(
+                endpoint_url.clone(),
+                credentials.as_ref(),
+                Some(etag.clone()),
+            )
+            .await
+        {
+            Ok(response) if !self.cache_enabled => Ok(Some(Arc::new(response))),
+            Ok(response) => self.store_in_cache(endpoint_url, response).map(Some),
+            // 304 Not Modified.
+            Err(e) if e.is_cached() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     async fn expand<T: Expandable + 'static>(
         &self,
         id: &ODataId,
@@ -684,8 +1165,14 @@ where
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
         let credentials = self.read_credentials();
         self.client
-            .post(endpoint_url, v, credentials.as_ref(), &self.custom_headers)
+            .post::<_, NullChecked<R>>(
+                endpoint_url.clone(),
+                v,
+                credentials.as_ref(),
+                &self.custom_headers,
+            )
             .await
+            .and_then(|response| self.null_checked_modification(&endpoint_url, response))
     }
 
     async fn create_session<
@@ -697,9 +1184,15 @@ where
         v: &V,
     ) -> Result<SessionCreateResponse<R>, Self::Error> {
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
-        self.client
-            .post_session(endpoint_url, v, &self.custom_headers)
-            .await
+        let response = self
+            .client
+            .post_session::<_, NullChecked<R>>(endpoint_url.clone(), v, &self.custom_headers)
+            .await?;
+        Ok(SessionCreateResponse {
+            entity: self.null_checked(&endpoint_url, response.entity)?,
+            auth_token: response.auth_token,
+            location: response.location,
+        })
     }
 
     async fn update<V: Sync + Send + Serialize, R: Sync + Send + for<'de> Deserialize<'de>>(
@@ -709,19 +1202,25 @@ where
         v: &V,
     ) -> Result<ModificationResponse<R>, Self::Error> {
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
-        let etag = etag
-            .cloned()
-            .unwrap_or_else(|| ODataETag::from(String::from("*")));
+        let etag = self.read_etag_policy().if_match(etag);
         let credentials = self.read_credentials();
-        self.client
-            .patch(
-                endpoint_url,
+        let result = self
+            .client
+            .patch::<_, NullChecked<R>>(
+                endpoint_url.clone(),
                 etag,
                 v,
                 credentials.as_ref(),
                 &self.custom_headers,
             )
             .await
+            .and_then(|response| self.null_checked_modification(&endpoint_url, response));
+        match result {
+            Err(e) if self.precondition_retries > 0 && is_precondition_failed::<C>(&e) => {
+                self.retry_update(endpoint_url, v).await
+            }
+            result => result,
+        }
     }
 
     async fn delete<T: Sync + Send + for<'de> Deserialize<'de>>(
@@ -731,7 +1230,20 @@ where
         let endpoint_url = self.redfish_endpoint.with_odata_id(id);
         let credentials = self.read_credentials();
         self.client
-            .delete(endpoint_url, credentials.as_ref(), &self.custom_headers)
+            .delete::<NullChecked<T>>(
+                endpoint_url.clone(),
+                credentials.as_ref(),
+                &self.custom_headers,
+            )
+            .await
+            .and_then(|response| self.null_checked_modification(&endpoint_url, response))
+    }
+
+    async fn allowed_methods(&self, id: &ODataId) -> Result<Option<AllowedMethods>, Self::Error> {
+        let endpoint_url = self.redfish_endpoint.with_odata_id(id);
+        let credentials = self.read_credentials();
+        self.client
+            .allowed_methods(endpoint_url, credentials.as_ref(), &self.custom_headers)
             .await
     }
 
@@ -747,13 +1259,14 @@ where
 
         let credentials = self.read_credentials();
         self.client
-            .post(
-                endpoint_url,
+            .post::<_, NullChecked<R>>(
+                endpoint_url.clone(),
                 params,
                 credentials.as_ref(),
                 &self.custom_headers,
             )
             .await
+            .and_then(|response| self.null_checked_modification(&endpoint_url, response))
     }
 
     async fn multipart_update<U, V, R>(
@@ -774,13 +1287,14 @@ where
         let credentials = self.read_credentials();
 
         self.client
-            .post_multipart_update(
-                endpoint_url,
+            .post_multipart_update::<_, _, NullChecked<R>>(
+                endpoint_url.clone(),
                 request,
                 credentials.as_ref(),
                 &self.custom_headers,
             )
             .await
+            .and_then(|response| self.null_checked_modification(&endpoint_url, response))
     }
 
     #[cfg(feature = "update-service-deprecated")]
@@ -801,13 +1315,80 @@ where
         let credentials = self.read_credentials();
 
         self.client
-            .post_http_push_uri_update(
-                endpoint_url,
+            .post_http_push_uri_update::<_, NullChecked<R>>(
+                endpoint_url.clone(),
                 request,
                 credentials.as_ref(),
                 &self.custom_headers,
             )
             .await
+            .and_then(|response| self.null_checked_modification(&endpoint_url, response))
+    }
+
+    async fn binary_download(&self, uri: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_same_origin_uri_reference(UriReference(uri))
+            .map_err(C::Error::rejected_uri_reference)?;
+
+        let credentials = self.read_credentials();
+        self.client
+            .get_binary(endpoint_url, credentials.as_ref(), &self.custom_headers)
+            .await
+    }
+
+    async fn get_json<T>(&self, uri: &str) -> Result<T, Self::Error>
+    where
+        T: for<'de> Deserialize<'de> + Clone + Send + Sync + 'static,
+    {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_same_origin_uri_reference(UriReference(uri))
+            .map_err(C::Error::rejected_uri_reference)?;
+
+        let credentials = self.read_credentials();
+        self.client
+            .get_json(endpoint_url, credentials.as_ref(), &self.custom_headers)
+            .await
+    }
+
+    async fn task_monitor(&self, uri: &str) -> Result<Option<TaskMonitorResponse>, Self::Error> {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_same_origin_uri_reference(UriReference(uri))
+            .map_err(C::Error::rejected_uri_reference)?;
+
+        let credentials = self.read_credentials();
+        self.client
+            .get_task_monitor(endpoint_url, credentials.as_ref(), &self.custom_headers)
+            .await
+    }
+
+    async fn binary_upload<U, R>(
+        &self,
+        uri: &str,
+        request: BinaryUploadRequest<U>,
+    ) -> Result<Option<ModificationResponse<R>>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        let endpoint_url = self
+            .redfish_endpoint
+            .with_same_origin_uri_reference(UriReference(uri))
+            .map_err(C::Error::rejected_uri_reference)?;
+
+        let credentials = self.read_credentials();
+        self.client
+            .put_binary::<_, NullChecked<R>>(
+                endpoint_url.clone(),
+                request,
+                credentials.as_ref(),
+                &self.custom_headers,
+            )
+            .await?
+            .map(|response| self.null_checked_modification(&endpoint_url, response))
+            .transpose()
     }
 
     async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
@@ -822,6 +1403,70 @@ where
         self.get_with_cache(endpoint_url).await
     }
 
+    /// Set policy of sending `If-Match` with entity updates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal policy lock is poisoned. This should not
+    /// occur in normal operation.
+    #[allow(clippy::panic)] // See panics section.
+    fn set_etag_policy(&self, policy: ETagPolicy) {
+        *self.etag_policy.write().expect("poisoned") = policy;
+    }
+
+    /// Set policy of handling `null` values of non-nullable
+    /// properties in responses.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal policy lock is poisoned. This should not
+    /// occur in normal operation.
+    #[allow(clippy::panic)] // See panics section.
+    fn set_null_policy(&self, policy: NullPolicy) {
+        *self.null_policy.write().expect("poisoned") = policy;
+    }
+
+    #[allow(clippy::panic)] // See set_credentials Panic doc.
+    fn invalidate_cache(&self) {
+        let mut cache = self.cache.write().expect("poisoned");
+        *cache = TypeErasedCarCache::new(cache.capacity());
+        drop(cache);
+        self.etags.write().expect("poisoned").clear();
+    }
+
+    fn cache_entity<T: EntityTypeRef + 'static>(&self, entity: &Arc<T>) {
+        if self.cache_enabled {
+            let cache_key = self.redfish_endpoint.with_odata_id(entity.odata_id());
+            // Cache is best effort: failure only means that the next
+            // read fetches the entity.
+            let _ = self.put_in_cache(cache_key, entity);
+        }
+    }
+
+    fn latency_class(&self) -> Option<LatencyClass> {
+        self.client.latency_class(&self.redfish_endpoint.base_url)
+    }
+
+    fn error_status_code(error: &Self::Error) -> Option<u16> {
+        C::error_status_code(error)
+    }
+
+    fn error_response_body(error: &Self::Error) -> Option<&str> {
+        C::error_response_body(error)
+    }
+
+    fn error_odata_type(error: &Self::Error) -> Option<&str> {
+        C::error_odata_type(error)
+    }
+
+    fn error_response_language(error: &Self::Error) -> Option<&str> {
+        C::error_response_language(error)
+    }
+
+    fn redfish_error(error: &Self::Error) -> Option<RedfishError> {
+        C::redfish_error(error).cloned()
+    }
+
     async fn stream<T: Send + Sized + for<'de> Deserialize<'de>>(
         &self,
         uri: &str,
@@ -832,12 +1477,48 @@ where
             .map_err(C::Error::rejected_uri_reference)?;
 
         let credentials = self.read_credentials();
+
         self.client
             .sse(endpoint_url, credentials.as_ref(), &self.custom_headers)
             .await
     }
 }
 
+/// HTTP status of a PATCH rejected because `If-Match` does not match
+/// the current `ETag` of the entity.
+const PRECONDITION_FAILED: u16 = 412;
+
+fn is_precondition_failed<C: HttpClient>(error: &C::Error) -> bool {
+    C::error_status_code(error) == Some(PRECONDITION_FAILED)
+}
+
+/// Part of the update `payload` that differs from the `current`
+/// representation of the entity. Nested objects are compared property
+/// by property, arrays and other values as a whole.
+fn changed_properties(
+    payload: &serde_json::Value,
+    current: &serde_json::Value,
+) -> serde_json::Value {
+    match (payload, current) {
+        (serde_json::Value::Object(payload), serde_json::Value::Object(current)) => payload
+            .iter()
+            .filter_map(|(name, value)| match current.get(name) {
+                Some(current) if current == value => None,
+                Some(current) if value.is_object() => {
+                    let changed = changed_properties(value, current);
+                    (changed
+                        .as_object()
+                        .is_none_or(|changed| !changed.is_empty()))
+                    .then(|| (name.clone(), changed))
+                }
+                _ => Some((name.clone(), value.clone())),
+            })
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        _ => payload.clone(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -954,4 +1635,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn changed_properties_drops_applied_values() {
+        let payload = serde_json::json!({
+            "AssetTag": "rack-7",
+            "IndicatorLED": "Lit",
+            "Boot": {
+                "BootSourceOverrideEnabled": "Once",
+                "BootSourceOverrideTarget": "Pxe",
+            },
+            "Oem": {
+                "Contoso": { "Mode": "Fast" },
+            },
+        });
+        let current = serde_json::json!({
+            "@odata.etag": "W/\"2\"",
+            "AssetTag": "rack-7",
+            "IndicatorLED": "Off",
+            "Boot": {
+                "BootSourceOverrideEnabled": "Disabled",
+                "BootSourceOverrideTarget": "Pxe",
+            },
+            "Oem": {
+                "Contoso": { "Mode": "Fast" },
+            },
+        });
+
+        assert_eq!(
+            changed_properties(&payload, &current),
+            serde_json::json!({
+                "IndicatorLED": "Lit",
+                "Boot": { "BootSourceOverrideEnabled": "Once" },
+            })
+        );
+        assert_eq!(
+            changed_properties(&current, &current),
+            serde_json::json!({})
+        );
+    }
 }
@@ -15,14 +15,28 @@
 
 //! Implementation of [`HttpClient`] trait using reqwest crate.
 
+mod latency;
+mod pool;
+mod request_id;
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::convert::TryFrom as _;
 use std::error::Error as StdErr;
 use std::fmt;
 use std::future::ready;
+use std::hash::BuildHasher as _;
+use std::hash::Hasher as _;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::clock::Clock;
+use crate::clock::SystemClock;
 use crate::schema::redfish::message::Message;
-use crate::schema::redfish::redfish_error::RedfishError;
+use crate::schema::redfish::redfish_error::RedfishError as RedfishErrorSchema;
+use crate::BinaryUploadRequest;
 use crate::BmcCredentials;
 use crate::CacheableError;
 use crate::HttpClient;
@@ -32,36 +46,57 @@ use crate::MultipartUpdateRequest;
 use crate::RejectedUriReferenceError;
 use crate::RequestError;
 
+use crate::reqwest::latency::LatencyTracker;
+use crate::reqwest::pool::ConnectionGuard;
+use crate::reqwest::pool::ConnectionPool;
+use crate::reqwest::pool::PoolConfig;
+use crate::reqwest::request_id::RequestIds;
+
 use bytes::Bytes;
 use futures_util::stream::unfold;
 use futures_util::Stream;
 use futures_util::StreamExt as _;
 use http::header;
 use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::AsyncTask;
+use nv_redfish_core::AsyncTaskResult;
 use nv_redfish_core::BoxTryStream;
 use nv_redfish_core::DataStream;
+use nv_redfish_core::LatencyClass;
 use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NullValueError;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
 use nv_redfish_core::OemMultipartPart;
+use nv_redfish_core::RedfishError;
 use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::TaskMonitorResponse;
 use nv_redfish_core::UploadReader;
-#[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::UploadStream;
 use reqwest::multipart::Form;
 use reqwest::multipart::Part;
 use reqwest::redirect::Policy as RedirectPolicy;
+use reqwest::Body;
 use reqwest::Client as ReqwestClient;
 use reqwest::Error as ReqwestError;
+use reqwest::ResponseBuilderExt as _;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use tokio::time::sleep;
 use tokio::time::timeout;
 use tokio_util::compat::FuturesAsyncReadCompatExt as _;
 use tokio_util::io::ReaderStream;
 use url::Url;
 
+#[doc(inline)]
+pub use latency::HostLatencyStats;
+#[doc(inline)]
+pub use latency::LatencyThresholds;
+#[doc(inline)]
+pub use pool::HostPoolStats;
+
 /// Errors of reqwest implementation of the HTTP trait.
 #[derive(Debug)]
 pub enum BmcError {
@@ -69,15 +104,16 @@ pub enum BmcError {
     ReqwestError(reqwest::Error),
     /// JSON to model deserialize error with path tracking.
     JsonError(serde_path_to_error::Error<serde_json::Error>),
-    /// Unexpected HTTP response.
-    InvalidResponse {
-        /// URL in request that caused error.
-        url: url::Url,
-        /// Returned status.
-        status: reqwest::StatusCode,
-        /// Text in the response.
-        text: String,
+    /// JSON to model deserialize error of a payload that declares its
+    /// type with `@odata.type`.
+    PayloadJsonError {
+        /// Value of `@odata.type` of the payload.
+        odata_type: String,
+        /// Deserialize error with path tracking.
+        error: serde_path_to_error::Error<serde_json::Error>,
     },
+    /// Unexpected HTTP response.
+    InvalidResponse(Box<InvalidResponse>),
     /// SSE stream error.
     SseStreamError(sse_stream::Error),
     /// No resource found in cache.
@@ -95,11 +131,68 @@ pub enum BmcError {
         /// Configured byte limit that was exceeded.
         limit: usize,
     },
+    /// Binary data exceeded the configured maximum size.
+    BinaryTooLarge {
+        /// Configured byte limit that was exceeded.
+        limit: u64,
+    },
     /// No SSE event was received within the configured idle timeout.
     SseIdleTimeout {
         /// Idle duration that elapsed with no event.
         idle: Duration,
     },
+    /// Update was rejected with `412 Precondition Failed` after all
+    /// configured retries.
+    Conflict {
+        /// URL of the updated resource.
+        url: url::Url,
+        /// Number of PATCH requests that were sent.
+        attempts: u32,
+    },
+    /// Response contained `null` values of non-nullable properties
+    /// and [`nv_redfish_core::NullPolicy::Strict`] is configured.
+    NullValue {
+        /// URL in request that returned the response.
+        url: url::Url,
+        /// Properties that were `null`.
+        error: NullValueError,
+    },
+    /// Request was still failing after all attempts allowed by the
+    /// retry policy.
+    RetriesExhausted {
+        /// URL in request that failed.
+        url: url::Url,
+        /// Number of requests that were sent.
+        attempts: u32,
+        /// Error of the last attempt.
+        last: Box<Self>,
+    },
+}
+
+/// Unexpected HTTP response of [`BmcError::InvalidResponse`].
+#[derive(Debug)]
+pub struct InvalidResponse {
+    /// URL in request that caused error.
+    pub url: url::Url,
+    /// Returned status.
+    pub status: reqwest::StatusCode,
+    /// Text in the response.
+    pub text: String,
+    /// Language of the response text from `Content-Language`
+    /// header, if the service provided it.
+    pub language: Option<String>,
+    /// Redfish error parsed from the response text, if the text is
+    /// a Redfish error response.
+    pub redfish_error: Option<RedfishError>,
+    /// Correlation ID sent with the request, if
+    /// [`ClientParams::request_id_header`] is configured.
+    pub request_id: Option<String>,
+}
+
+impl From<InvalidResponse> for BmcError {
+    fn from(response: InvalidResponse) -> Self {
+        Self::InvalidResponse(Box::new(response))
+    }
 }
 
 impl From<reqwest::Error> for BmcError {
@@ -111,7 +204,8 @@ impl From<reqwest::Error> for BmcError {
 impl CacheableError for BmcError {
     fn is_cached(&self) -> bool {
         match self {
-            Self::InvalidResponse { status, .. } => status == &reqwest::StatusCode::NOT_MODIFIED,
+            Self::InvalidResponse(response) => response.status == reqwest::StatusCode::NOT_MODIFIED,
+            Self::RetriesExhausted { last, .. } => last.is_cached(),
             _ => false,
         }
     }
@@ -129,17 +223,33 @@ impl RequestError for BmcError {
     fn rejected_uri_reference(error: RejectedUriReferenceError) -> Self {
         Self::InvalidRequest(error.reason)
     }
+
+    fn conflict(url: Url, attempts: u32) -> Self {
+        Self::Conflict { url, attempts }
+    }
+
+    fn null_value(url: Url, error: NullValueError) -> Self {
+        Self::NullValue { url, error }
+    }
 }
 
 impl fmt::Display for BmcError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::ReqwestError(e) => write!(f, "HTTP client error: {e:?}"),
-            Self::InvalidResponse { url, status, text } => {
-                write!(
-                    f,
-                    "Invalid HTTP response - url: {url} status: {status} text: {text}"
-                )
+            Self::InvalidResponse(response) => {
+                let InvalidResponse {
+                    url,
+                    status,
+                    text,
+                    request_id,
+                    ..
+                } = response.as_ref();
+                write!(f, "Invalid HTTP response - url: {url} status: {status}")?;
+                if let Some(request_id) = request_id {
+                    write!(f, " request id: {request_id}")?;
+                }
+                write!(f, " text: {text}")
             }
             Self::CacheMiss => write!(f, "Resource not found in cache"),
             Self::CacheError(r) => write!(f, "Error occurred in cache {r:?}"),
@@ -150,6 +260,13 @@ impl fmt::Display for BmcError {
                 e.inner().column(),
                 e.path(),
             ),
+            Self::PayloadJsonError { odata_type, error } => write!(
+                f,
+                "JSON deserialization error of {odata_type} at line {} column {} path {}: {error}",
+                error.inner().line(),
+                error.inner().column(),
+                error.path(),
+            ),
             Self::SseStreamError(e) => write!(f, "SSE stream decode error: {e}"),
             Self::DecodeError(e) => write!(f, "JSON Decode error: {e}"),
             Self::EncodeError(e) => write!(f, "JSON Encode error: {e}"),
@@ -158,9 +275,25 @@ impl fmt::Display for BmcError {
                 f,
                 "SSE event exceeded maximum buffered size of {limit} bytes"
             ),
+            Self::BinaryTooLarge { limit } => {
+                write!(f, "Binary data exceeded maximum size of {limit} bytes")
+            }
             Self::SseIdleTimeout { idle } => {
                 write!(f, "SSE stream idle for longer than {idle:?}")
             }
+            Self::Conflict { url, attempts } => write!(
+                f,
+                "Update of {url} failed with precondition conflict after {attempts} attempts"
+            ),
+            Self::NullValue { url, error } => write!(f, "Invalid response of {url}: {error}"),
+            Self::RetriesExhausted {
+                url,
+                attempts,
+                last,
+            } => write!(
+                f,
+                "Request to {url} failed after {attempts} attempts: {last}"
+            ),
         }
     }
 }
@@ -169,9 +302,11 @@ impl StdErr for BmcError {
     fn source(&self) -> Option<&(dyn StdErr + 'static)> {
         match self {
             Self::ReqwestError(e) => Some(e),
-            Self::JsonError(e) => Some(e.inner()),
+            Self::JsonError(e) | Self::PayloadJsonError { error: e, .. } => Some(e.inner()),
             Self::SseStreamError(e) => Some(e),
             Self::DecodeError(e) | Self::EncodeError(e) => Some(e),
+            Self::NullValue { error, .. } => Some(error),
+            Self::RetriesExhausted { last, .. } => Some(last.as_ref()),
             _ => None,
         }
     }
@@ -304,13 +439,28 @@ fn event_to_item<T: DeserializeOwned>(
 type RetryClassifier =
     dyn Fn(&reqwest::Request, &reqwest::Response) -> bool + Send + Sync + 'static;
 
+/// Classifier deciding whether a transport error should be retried.
+///
+/// Receives the request and the error, returns `true` if the request
+/// should be retried.
+type RetryErrorClassifier =
+    dyn Fn(&reqwest::Request, &reqwest::Error) -> bool + Send + Sync + 'static;
+
 /// Retry policy with a configurable delay between attempts.
 ///
 /// While retries remain, the classifier is called for every received HTTP
 /// response, regardless of the request method, and decides whether to retry.
-/// Transport and connection errors are returned immediately. Requests with
-/// non-clonable (streaming) bodies, such as multipart uploads, are sent exactly
-/// once and never retried.
+/// Transport and connection errors are returned immediately unless an error
+/// classifier is set with [`Self::retry_errors`]. Requests with non-clonable
+/// (streaming) bodies, such as multipart uploads, are sent exactly once and
+/// never retried.
+///
+/// When the last attempt is still classified as retryable, the request
+/// fails with [`BmcError::RetriesExhausted`] that carries the number of
+/// attempts and the error of the last one.
+///
+/// [`RetryPolicy::transient`] is a ready-made policy for transient BMC
+/// failures.
 ///
 /// # Examples
 ///
@@ -337,8 +487,51 @@ pub struct RetryPolicy {
     max_retries: u32,
     /// Fixed sleep between attempts; `None` retries immediately.
     delay: Option<Duration>,
+    /// Exponential backoff, takes precedence over the fixed delay.
+    backoff: Option<Backoff>,
+    /// Whether `Retry-After` of the retried response sets the delay.
+    honor_retry_after: bool,
+    /// Upper bound of the delay taken from `Retry-After`.
+    max_retry_after: Duration,
     /// Decides whether a response should be retried.
     classifier: Arc<RetryClassifier>,
+    /// Decides whether a transport error should be retried.
+    error_classifier: Option<Arc<RetryErrorClassifier>>,
+}
+
+/// Default of [`RetryPolicy::max_retry_after`].
+const DEFAULT_MAX_RETRY_AFTER: Duration = Duration::from_mins(1);
+
+/// Jittered exponential backoff between attempts.
+#[derive(Clone, Copy, Debug)]
+struct Backoff {
+    initial: Duration,
+    max: Duration,
+}
+
+impl Backoff {
+    /// Delay before the retry that follows `attempt` (starting from 1):
+    /// `initial * 2^(attempt - 1)` capped by `max`, of which a random
+    /// part up to one half is dropped so that clients don't retry in
+    /// lockstep.
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 1_u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let delay = self.initial.saturating_mul(factor).min(self.max);
+        let half = delay / 2;
+        half + random_up_to(half)
+    }
+}
+
+/// Returns random duration in `[0, max]`.
+///
+/// Jitter doesn't need good randomness, so randomly seeded hasher of the
+/// standard library is used as a source.
+fn random_up_to(max: Duration) -> Duration {
+    let max = u64::try_from(max.as_nanos()).unwrap_or(u64::MAX);
+    let random = RandomState::new().build_hasher().finish();
+    Duration::from_nanos(random % max.saturating_add(1))
 }
 
 impl RetryPolicy {
@@ -354,10 +547,39 @@ impl RetryPolicy {
         Self {
             max_retries: 0,
             delay: None,
+            backoff: None,
+            honor_retry_after: false,
+            max_retry_after: DEFAULT_MAX_RETRY_AFTER,
             classifier: Arc::new(classifier),
+            error_classifier: None,
         }
     }
 
+    /// Policy for transient failures of BMCs.
+    ///
+    /// Retries `GET` and `HEAD` requests that return `429 Too Many
+    /// Requests`, `502 Bad Gateway`, `503 Service Unavailable` or `504
+    /// Gateway Timeout`, or that fail to connect or lose the connection.
+    /// Up to 3 retries are performed with jittered exponential backoff
+    /// from 500 ms to 10 s; `Retry-After` of the response is honored.
+    #[must_use]
+    pub fn transient() -> Self {
+        Self::new(|request, response| {
+            is_idempotent_read(request)
+                && matches!(
+                    response.status(),
+                    reqwest::StatusCode::TOO_MANY_REQUESTS
+                        | reqwest::StatusCode::BAD_GATEWAY
+                        | reqwest::StatusCode::SERVICE_UNAVAILABLE
+                        | reqwest::StatusCode::GATEWAY_TIMEOUT
+                )
+        })
+        .retry_errors(|request, error| is_idempotent_read(request) && is_connection_error(error))
+        .max_retries(3)
+        .exponential_backoff(Duration::from_millis(500), Duration::from_secs(10))
+        .honor_retry_after(true)
+    }
+
     /// Maximum number of extra attempts after the initial request.
     #[must_use]
     pub const fn max_retries(mut self, max_retries: u32) -> Self {
@@ -371,6 +593,64 @@ impl RetryPolicy {
         self.delay = Some(delay);
         self
     }
+
+    /// Jittered exponential backoff between attempts. The delay starts
+    /// at `initial`, doubles with every retry up to `max`, and a random
+    /// part of up to one half of it is dropped. Takes precedence over
+    /// [`Self::delay`].
+    #[must_use]
+    pub const fn exponential_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.backoff = Some(Backoff { initial, max });
+        self
+    }
+
+    /// Whether the delay is taken from the `Retry-After` header (in
+    /// seconds) of the retried response when it is present. The service
+    /// asked to wait that long, so the delay is capped by
+    /// [`Self::max_retry_after`] rather than by the maximum of the
+    /// exponential backoff.
+    #[must_use]
+    pub const fn honor_retry_after(mut self, honor: bool) -> Self {
+        self.honor_retry_after = honor;
+        self
+    }
+
+    /// Maximum delay taken from `Retry-After`, one minute by default.
+    /// Longer hints are shortened to it so that a misbehaving service
+    /// cannot park the request for hours. The maximum of the
+    /// exponential backoff is used instead if it is larger.
+    #[must_use]
+    pub const fn max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    /// Retries transport errors accepted by `classifier`, for example
+    /// refused or reset connections. Without it transport errors are
+    /// returned immediately.
+    #[must_use]
+    pub fn retry_errors<F>(mut self, classifier: F) -> Self
+    where
+        F: Fn(&reqwest::Request, &reqwest::Error) -> bool + Send + Sync + 'static,
+    {
+        self.error_classifier = Some(Arc::new(classifier));
+        self
+    }
+
+    /// Delay before the retry that follows `attempt` (starting from 1).
+    fn delay_before_retry(&self, attempt: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        let retry_after = retry_after.filter(|_| self.honor_retry_after);
+        match (retry_after, &self.backoff) {
+            (Some(retry_after), backoff) => {
+                let max = backoff.map_or(self.max_retry_after, |backoff| {
+                    backoff.max.max(self.max_retry_after)
+                });
+                Some(retry_after.min(max))
+            }
+            (None, Some(backoff)) => Some(backoff.delay(attempt)),
+            (None, None) => self.delay,
+        }
+    }
 }
 
 impl fmt::Debug for RetryPolicy {
@@ -378,11 +658,51 @@ impl fmt::Debug for RetryPolicy {
         f.debug_struct("RetryPolicy")
             .field("max_retries", &self.max_retries)
             .field("delay", &self.delay)
+            .field("backoff", &self.backoff)
+            .field("honor_retry_after", &self.honor_retry_after)
+            .field("max_retry_after", &self.max_retry_after)
             .field("classifier", &"<closure>")
+            .field(
+                "error_classifier",
+                &self.error_classifier.as_ref().map(|_| "<closure>"),
+            )
             .finish()
     }
 }
 
+/// `GET` and `HEAD` requests only read the resource, so they are safe
+/// to repeat.
+fn is_idempotent_read(request: &reqwest::Request) -> bool {
+    matches!(
+        *request.method(),
+        reqwest::Method::GET | reqwest::Method::HEAD
+    )
+}
+
+/// Returns `true` if the connection could not be established or was
+/// closed by the peer before the response was received.
+fn is_connection_error(error: &reqwest::Error) -> bool {
+    if error.is_connect() {
+        return true;
+    }
+    let mut source = StdErr::source(error);
+    while let Some(current) = source {
+        if let Some(io_error) = current.downcast_ref::<IoError>() {
+            if matches!(
+                io_error.kind(),
+                IoErrorKind::ConnectionReset
+                    | IoErrorKind::ConnectionAborted
+                    | IoErrorKind::BrokenPipe
+                    | IoErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+        source = current.source();
+    }
+    false
+}
+
 /// Configuration parameters for the reqwest HTTP client.
 ///
 /// This struct allows customizing various aspects of the reqwest client behavior,
@@ -408,6 +728,17 @@ pub struct ClientParams {
     pub connect_timeout: Option<Duration>,
     /// User-Agent header value
     pub user_agent: Option<String>,
+    /// Identity of the application that uses the client (for example,
+    /// `inventory-collector/2.3`). It is prepended to the User-Agent
+    /// header so that BMC logs show which application sent requests.
+    pub application: Option<String>,
+    /// Header that carries a generated correlation ID of each request
+    /// (for example, `X-Request-ID`). `None` disables correlation IDs.
+    pub request_id_header: Option<HeaderName>,
+    /// Language tag sent in the Accept-Language header of every request
+    /// (for example, `de-DE`). Services that have localized message
+    /// registries use it to render messages in this language.
+    pub accept_language: Option<HeaderValue>,
     /// Whether to accept invalid TLS certificates
     pub accept_invalid_certs: bool,
 
@@ -422,14 +753,44 @@ pub struct ClientParams {
     pub pool_idle_timeout: Option<Duration>,
     /// Maximum idle connections per host
     pub pool_max_idle_per_host: Option<usize>,
+    /// Maximum active connections per host, `None` means no limit.
+    ///
+    /// Requests above the limit wait until a connection to the same
+    /// host is released.
+    pub pool_max_connections_per_host: Option<usize>,
+    /// Per-host overrides of [`Self::pool_max_connections_per_host`],
+    /// keyed by host name or address as it appears in the URL, or by
+    /// origin (`host:port`) to limit a single port of the host. An
+    /// origin override wins over a host override.
+    pub pool_max_connections_overrides: HashMap<String, usize>,
+    /// Maximum number of requests started per second per host, `None`
+    /// means no limit.
+    ///
+    /// Starts of requests to the same host are spaced evenly, so a
+    /// caller that walks the whole tree in parallel cannot overload
+    /// the BMC. Clones of the client share the limit.
+    pub pool_max_requests_per_second: Option<u32>,
     /// List of default headers, added to every request
     pub default_headers: Option<HeaderMap>,
     /// Forces use of rust TLS, enabled by default
     pub use_rust_tls: bool,
-    /// Retry policy for received responses, `None` disables retries
+    /// Retry policy for received responses and transport errors,
+    /// `None` disables retries
     pub retry: Option<RetryPolicy>,
     /// SSE-specific limits applied by [`Client::sse`].
     pub sse: SseOptions,
+    /// Maximum size of binary data downloaded from or uploaded to
+    /// service-provided binary data URIs (for example,
+    /// `AssemblyData.BinaryDataURI`). Downloaded data is buffered in
+    /// memory. Transfers that exceed the limit fail with
+    /// [`BmcError::BinaryTooLarge`].
+    pub max_binary_bytes: u64,
+    /// Thresholds of time to the first byte that classify BMC hosts,
+    /// see [`Client::latency_stats`].
+    pub latency_thresholds: LatencyThresholds,
+    /// Time source for retry delays, expiry of idle connections and
+    /// latency measurement.
+    pub clock: Arc<dyn Clock>,
 }
 
 /// Limits applied to Server-Sent Event streams opened by [`Client::sse`].
@@ -444,6 +805,9 @@ pub struct SseOptions {
     pub idle_timeout: Option<Duration>,
 }
 
+/// Default of [`ClientParams::max_binary_bytes`].
+const DEFAULT_MAX_BINARY_BYTES: u64 = 64 * 1024 * 1024;
+
 impl Default for SseOptions {
     fn default() -> Self {
         Self {
@@ -459,15 +823,24 @@ impl Default for ClientParams {
             timeout: Some(Duration::from_secs(120)),
             connect_timeout: Some(Duration::from_secs(5)),
             user_agent: Some("nv-redfish/v1".to_string()),
+            application: None,
+            request_id_header: None,
+            accept_language: None,
             accept_invalid_certs: false,
             max_redirects: Some(10),
             tcp_keepalive: Some(Duration::from_secs(60)),
             pool_idle_timeout: Some(Duration::from_secs(90)),
             pool_max_idle_per_host: Some(1),
+            pool_max_connections_per_host: None,
+            pool_max_connections_overrides: HashMap::new(),
+            pool_max_requests_per_second: None,
             default_headers: None,
             use_rust_tls: true,
             retry: None,
             sse: SseOptions::default(),
+            max_binary_bytes: DEFAULT_MAX_BINARY_BYTES,
+            latency_thresholds: LatencyThresholds::default(),
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -500,6 +873,33 @@ impl ClientParams {
         self
     }
 
+    /// Sets identity of the application that uses the client.
+    ///
+    /// See [`ClientParams::application`].
+    #[must_use]
+    pub fn application<S: Into<String>>(mut self, application: S) -> Self {
+        self.application = Some(application.into());
+        self
+    }
+
+    /// Stamps every request with a generated correlation ID in `header`.
+    ///
+    /// See [`ClientParams::request_id_header`].
+    #[must_use]
+    pub fn request_id_header(mut self, header: HeaderName) -> Self {
+        self.request_id_header = Some(header);
+        self
+    }
+
+    /// Asks the service to localize messages into language `tag`.
+    ///
+    /// See [`ClientParams::accept_language`].
+    #[must_use]
+    pub fn accept_language(mut self, tag: HeaderValue) -> Self {
+        self.accept_language = Some(tag);
+        self
+    }
+
     /// See: [`reqwest::ClientBuilder::danger_accept_invalid_certs`].
     #[must_use]
     pub const fn accept_invalid_certs(mut self, accept: bool) -> Self {
@@ -528,6 +928,37 @@ impl ClientParams {
         self
     }
 
+    /// Sets the maximum number of active connections per host.
+    ///
+    /// See [`ClientParams::pool_max_connections_per_host`].
+    #[must_use]
+    pub const fn pool_max_connections_per_host(mut self, max: usize) -> Self {
+        self.pool_max_connections_per_host = Some(max);
+        self
+    }
+
+    /// Overrides the maximum number of active connections for `host`,
+    /// either a host name or address (all ports) or an origin
+    /// (`host:port`).
+    ///
+    /// Useful when one client serves both aggregators and individual
+    /// BMCs that need different limits.
+    #[must_use]
+    pub fn pool_max_connections_for_host<S: Into<String>>(mut self, host: S, max: usize) -> Self {
+        self.pool_max_connections_overrides.insert(host.into(), max);
+        self
+    }
+
+    /// Sets the maximum number of requests started per second per
+    /// host. Zero is treated as one request per second.
+    ///
+    /// See [`ClientParams::pool_max_requests_per_second`].
+    #[must_use]
+    pub const fn pool_max_requests_per_second(mut self, max: u32) -> Self {
+        self.pool_max_requests_per_second = Some(max);
+        self
+    }
+
     /// See: [`reqwest::ClientBuilder::pool_idle_timeout`].
     #[must_use]
     pub const fn idle_timeout(mut self, pool_idle_timeout: Duration) -> Self {
@@ -556,6 +987,25 @@ impl ClientParams {
         self
     }
 
+    /// Sets thresholds of time to the first byte that classify BMC
+    /// hosts.
+    ///
+    /// See [`ClientParams::latency_thresholds`].
+    #[must_use]
+    pub const fn latency_thresholds(mut self, thresholds: LatencyThresholds) -> Self {
+        self.latency_thresholds = thresholds;
+        self
+    }
+
+    /// Sets the time source of the client.
+    ///
+    /// See [`ClientParams::clock`].
+    #[must_use]
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Sets the maximum buffered size of a single, not-yet-terminated SSE event.
     ///
     /// See [`SseOptions::max_event_bytes`].
@@ -565,6 +1015,15 @@ impl ClientParams {
         self
     }
 
+    /// Sets the maximum size of binary data transfers.
+    ///
+    /// See [`ClientParams::max_binary_bytes`].
+    #[must_use]
+    pub const fn max_binary_bytes(mut self, bytes: u64) -> Self {
+        self.max_binary_bytes = bytes;
+        self
+    }
+
     /// Sets the maximum idle time between SSE events.
     ///
     /// See [`SseOptions::idle_timeout`].
@@ -585,6 +1044,11 @@ pub struct Client {
     inner: ReqwestClient,
     retry: Option<RetryPolicy>,
     sse: SseOptions,
+    max_binary_bytes: u64,
+    pool: Arc<ConnectionPool>,
+    latency: Arc<LatencyTracker>,
+    request_ids: Option<Arc<RequestIds>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl Client {
@@ -619,7 +1083,11 @@ impl Client {
             builder = builder.connect_timeout(connect_timeout);
         }
 
-        if let Some(user_agent) = params.user_agent {
+        let user_agent = match (params.application, params.user_agent) {
+            (Some(application), Some(user_agent)) => Some(format!("{application} {user_agent}")),
+            (application, user_agent) => application.or(user_agent),
+        };
+        if let Some(user_agent) = user_agent {
             builder = builder.user_agent(user_agent);
         }
 
@@ -649,14 +1117,38 @@ impl Client {
             builder = builder.pool_max_idle_per_host(max_idle);
         }
 
-        if let Some(default_headers) = params.default_headers {
+        let mut default_headers = params.default_headers;
+        if let Some(language) = params.accept_language {
+            default_headers
+                .get_or_insert_with(HeaderMap::new)
+                .insert(header::ACCEPT_LANGUAGE, language);
+        }
+        if let Some(default_headers) = default_headers {
             builder = builder.default_headers(default_headers);
         }
 
+        let pool = ConnectionPool::new(
+            PoolConfig {
+                max_connections_per_host: params.pool_max_connections_per_host,
+                max_connections_overrides: params.pool_max_connections_overrides,
+                max_requests_per_second: params.pool_max_requests_per_second,
+                max_idle_per_host: params.pool_max_idle_per_host,
+                idle_timeout: params.pool_idle_timeout,
+            },
+            Arc::clone(&params.clock),
+        );
+
         Ok(Self {
             inner: builder.build()?,
             retry: params.retry,
             sse: params.sse,
+            max_binary_bytes: params.max_binary_bytes,
+            pool: Arc::new(pool),
+            latency: Arc::new(LatencyTracker::new(params.latency_thresholds)),
+            request_ids: params
+                .request_id_header
+                .map(|header| Arc::new(RequestIds::new(header))),
+            clock: params.clock,
         })
     }
 
@@ -671,12 +1163,52 @@ impl Client {
     /// can forward Redfish `X-Auth-Token` and arbitrary custom headers to another origin.
     #[must_use]
     pub fn with_client(client: ReqwestClient) -> Self {
+        // Pool settings of the pre-built client are unknown, assume
+        // reqwest defaults.
+        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let pool = ConnectionPool::new(
+            PoolConfig {
+                max_connections_per_host: None,
+                max_connections_overrides: HashMap::new(),
+                max_requests_per_second: None,
+                max_idle_per_host: None,
+                idle_timeout: Some(Duration::from_secs(90)),
+            },
+            Arc::clone(&clock),
+        );
         Self {
             inner: client,
             retry: None,
             sse: SseOptions::default(),
+            max_binary_bytes: DEFAULT_MAX_BINARY_BYTES,
+            pool: Arc::new(pool),
+            latency: Arc::new(LatencyTracker::new(LatencyThresholds::default())),
+            request_ids: None,
+            clock,
         }
     }
+
+    /// Connection pool state of every BMC origin (`host:port`) this
+    /// client has active or idle connections to, sorted by origin.
+    ///
+    /// reqwest does not expose its pool, so the state is derived from
+    /// request lifecycle: a connection is active until its response body
+    /// is consumed or dropped, and idle afterwards until it is reused or
+    /// pool idle timeout expires.
+    #[must_use]
+    pub fn pool_stats(&self) -> Vec<HostPoolStats> {
+        self.pool.stats()
+    }
+
+    /// Latency of every BMC origin (`host:port`) this client has sent
+    /// requests to, sorted by origin.
+    ///
+    /// Latency is time to the first byte of responses, classified
+    /// with [`ClientParams::latency_thresholds`].
+    #[must_use]
+    pub fn latency_stats(&self) -> Vec<HostLatencyStats> {
+        self.latency.stats()
+    }
 }
 
 impl Client {
@@ -684,44 +1216,147 @@ impl Client {
     ///
     /// Transport errors are returned immediately. Requests with streaming
     /// bodies cannot be cloned and are sent exactly once.
+    ///
+    /// Every attempt waits for a free connection slot of its host, and
+    /// the slot is held until the response body is consumed or dropped.
+    /// The slot is released while waiting before the next attempt.
+    ///
+    /// When correlation IDs are enabled, retries of the request carry
+    /// the same ID.
     async fn send(&self, request: reqwest::Request) -> Result<reqwest::Response, BmcError> {
+        let request = self.with_request_id(request);
+        self.send_with_retry(request, Slot::Wait).await
+    }
+
+    /// Sends the request of an event stream like [`Self::send`], but
+    /// without waiting for a free connection slot: the stream stays open
+    /// for an unbounded time and would block every other request to the
+    /// host. The connection is still counted in [`Self::pool_stats`].
+    async fn send_stream(&self, request: reqwest::Request) -> Result<reqwest::Response, BmcError> {
+        let request = self.with_request_id(request);
+        self.send_with_retry(request, Slot::Track).await
+    }
+
+    fn with_request_id(&self, mut request: reqwest::Request) -> reqwest::Request {
+        if let Some(request_ids) = &self.request_ids {
+            if let Some(id) = request_ids.next() {
+                request
+                    .headers_mut()
+                    .insert(request_ids.header().clone(), id);
+            }
+        }
+        request
+    }
+
+    async fn send_with_retry(
+        &self,
+        request: reqwest::Request,
+        slot: Slot,
+    ) -> Result<reqwest::Response, BmcError> {
         let Some(policy) = &self.retry else {
-            return Ok(self.inner.execute(request).await?);
+            return self.execute_in_slot(request, slot).await;
         };
 
-        let mut attempt: u32 = 0;
+        let mut attempt: u32 = 1;
         let mut current = request;
         loop {
-            let is_last = attempt >= policy.max_retries;
             // try_clone() returns None for streaming bodies, which therefore
             // get a single attempt.
-            let next = if is_last { None } else { current.try_clone() };
-            let response = self.inner.execute(current).await?;
-            match next {
-                // The clone is identical to the request just sent, so the
-                // classifier sees what went over the wire.
-                Some(next_request) if (policy.classifier)(&next_request, &response) => {
-                    if let Some(delay) = policy.delay {
-                        sleep(delay).await;
-                    }
-                    current = next_request;
-                    attempt += 1;
-                }
-                _ => return Ok(response),
+            let next = current.try_clone();
+            let url = current.url().clone();
+            let result = self.execute_in_slot(current, slot).await;
+            let Some(next_request) = next else {
+                return result;
+            };
+            // The clone is identical to the request just sent, so the
+            // classifiers see what went over the wire.
+            let retry = match &result {
+                Ok(response) => (policy.classifier)(&next_request, response),
+                Err(BmcError::ReqwestError(error)) => policy
+                    .error_classifier
+                    .as_ref()
+                    .is_some_and(|classifier| classifier(&next_request, error)),
+                Err(_) => false,
+            };
+            if !retry {
+                return result;
+            }
+            if attempt > policy.max_retries {
+                let last = match result {
+                    // The classifier may retry successful statuses (for
+                    // example `202 Accepted`); the last one is still a
+                    // valid response.
+                    Ok(response) if response.status().is_success() => return Ok(response),
+                    Ok(response) => error_response(response).await,
+                    Err(error) => error,
+                };
+                return Err(BmcError::RetriesExhausted {
+                    url,
+                    attempts: attempt,
+                    last: Box::new(last),
+                });
+            }
+            // The retried response is dropped here, releasing its
+            // connection slot for the time of the delay.
+            let retry_after = result
+                .ok()
+                .and_then(|response| retry_after_from_headers(response.headers()));
+            if let Some(delay) = policy.delay_before_retry(attempt, retry_after) {
+                self.clock.sleep(delay).await;
             }
+            current = next_request;
+            attempt += 1;
         }
     }
 
+    /// Sends a single request in a connection slot of its host. The slot
+    /// is held until the response body is consumed or dropped.
+    async fn execute_in_slot(
+        &self,
+        request: reqwest::Request,
+        slot: Slot,
+    ) -> Result<reqwest::Response, BmcError> {
+        let guard = match slot {
+            Slot::Wait => self.pool.acquire(request.url()).await,
+            Slot::Track => self.pool.track(request.url()),
+        };
+        let response = self.execute(request).await?;
+        Ok(hold_until_consumed(response, guard))
+    }
+
+    /// Sends a single request and records its time to the first byte.
+    /// Timed out requests are recorded with their full duration.
+    /// Correlation ID of the request is kept in extensions of the
+    /// response.
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response, BmcError> {
+        let url = request.url().clone();
+        let request_id = self
+            .request_ids
+            .as_ref()
+            .and_then(|request_ids| request_ids.of(&request).map(RequestId));
+        let started = self.clock.now();
+        let result = self.inner.execute(request).await;
+        let measured = match &result {
+            Ok(_) => true,
+            Err(error) => error.is_timeout(),
+        };
+        if measured {
+            self.latency
+                .record(&url, self.clock.now().saturating_duration_since(started));
+        }
+        let mut response = result?;
+        if let Some(request_id) = request_id {
+            response.extensions_mut().insert(request_id);
+        }
+        Ok(response)
+    }
+
     async fn handle_response<T>(&self, response: reqwest::Response) -> Result<T, BmcError>
     where
         T: DeserializeOwned,
     {
         if !response.status().is_success() {
-            return Err(BmcError::InvalidResponse {
-                url: response.url().clone(),
-                status: response.status(),
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let headers = response.headers().clone();
@@ -734,7 +1369,7 @@ impl Client {
             inject_etag(&etag, &mut value);
         }
 
-        serde_path_to_error::deserialize(value).map_err(BmcError::JsonError)
+        deserialize_payload(value)
     }
 
     async fn handle_modification_response<T>(
@@ -747,12 +1382,9 @@ impl Client {
         let status = response.status();
         let url = response.url().clone();
         let headers = response.headers().clone();
+        let request_id = request_id(&response);
         if !status.is_success() {
-            return Err(BmcError::InvalidResponse {
-                url,
-                status,
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let etag = etag_from_headers(&headers);
@@ -761,17 +1393,20 @@ impl Client {
         // status branch actually uses Location. A malformed, irrelevant
         // Location must not turn a valid 204 or body-bearing 200/201 into an
         // error.
-        let location = location_from_headers(&headers, &url, status);
+        let location = location_from_headers(&headers, &url, status, request_id.as_deref());
 
         match status {
             reqwest::StatusCode::NO_CONTENT => Ok(ModificationResponse::Empty),
             reqwest::StatusCode::ACCEPTED => {
                 let Some(task_location) = location? else {
-                    return Err(BmcError::InvalidResponse {
+                    return Err(BmcError::from(InvalidResponse {
                         url,
                         status,
                         text: String::from("202 Accepted without Location header"),
-                    });
+                        language: None,
+                        redfish_error: None,
+                        request_id,
+                    }));
                 };
 
                 Ok(ModificationResponse::Task(AsyncTask {
@@ -823,11 +1458,14 @@ impl Client {
 
                 Ok(ModificationResponse::Empty)
             }
-            _ => Err(BmcError::InvalidResponse {
+            _ => Err(BmcError::from(InvalidResponse {
                 url,
                 status,
                 text: format!("Unexpected successful status code: {status}"),
-            }),
+                language: None,
+                redfish_error: None,
+                request_id,
+            })),
         }
     }
 
@@ -841,30 +1479,34 @@ impl Client {
         let status = response.status();
         let url = response.url().clone();
         let headers = response.headers().clone();
+        let request_id = request_id(&response);
         if !status.is_success() {
-            return Err(BmcError::InvalidResponse {
-                url,
-                status,
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let Some(auth_token) = auth_token_from_headers(&headers) else {
-            return Err(BmcError::InvalidResponse {
+            return Err(BmcError::from(InvalidResponse {
                 url,
                 status,
                 text: String::from("session creation response missing X-Auth-Token header"),
-            });
+                language: None,
+                redfish_error: None,
+                request_id,
+            }));
         };
 
         // The returned location is the durable session identifier used for
         // later deletion, so normalize and validate it before exposing it.
-        let Some(location) = location_from_headers(&headers, &url, status)? else {
-            return Err(BmcError::InvalidResponse {
+        let Some(location) = location_from_headers(&headers, &url, status, request_id.as_deref())?
+        else {
+            return Err(BmcError::from(InvalidResponse {
                 url,
                 status,
                 text: String::from("session creation response missing Location header"),
-            });
+                language: None,
+                redfish_error: None,
+                request_id,
+            }));
         };
 
         match status {
@@ -872,11 +1514,14 @@ impl Client {
                 let etag = etag_from_headers(&headers);
                 let bytes = response.bytes().await.map_err(BmcError::ReqwestError)?;
                 if bytes.is_empty() {
-                    return Err(BmcError::InvalidResponse {
+                    return Err(BmcError::from(InvalidResponse {
                         url,
                         status,
                         text: String::from("session creation response missing entity body"),
-                    });
+                        language: None,
+                        redfish_error: None,
+                        request_id,
+                    }));
                 }
 
                 let mut value: serde_json::Value =
@@ -884,8 +1529,7 @@ impl Client {
                 if let Some(etag) = etag {
                     inject_etag(&etag, &mut value);
                 }
-                let entity =
-                    serde_path_to_error::deserialize(value).map_err(BmcError::JsonError)?;
+                let entity = deserialize_payload(value)?;
 
                 Ok(SessionCreateResponse {
                     entity,
@@ -893,21 +1537,30 @@ impl Client {
                     location,
                 })
             }
-            reqwest::StatusCode::ACCEPTED => Err(BmcError::InvalidResponse {
+            reqwest::StatusCode::ACCEPTED => Err(BmcError::from(InvalidResponse {
                 url,
                 status,
                 text: String::from("session creation returned 202 Accepted without session entity"),
-            }),
-            reqwest::StatusCode::NO_CONTENT => Err(BmcError::InvalidResponse {
+                language: None,
+                redfish_error: None,
+                request_id,
+            })),
+            reqwest::StatusCode::NO_CONTENT => Err(BmcError::from(InvalidResponse {
                 url,
                 status,
                 text: String::from("session creation returned 204 No Content"),
-            }),
-            _ => Err(BmcError::InvalidResponse {
+                language: None,
+                redfish_error: None,
+                request_id,
+            })),
+            _ => Err(BmcError::from(InvalidResponse {
                 url,
                 status,
                 text: format!("Unexpected successful status code for session creation: {status}"),
-            }),
+                language: None,
+                redfish_error: None,
+                request_id,
+            })),
         }
     }
 }
@@ -939,11 +1592,17 @@ fn location_from_headers(
     headers: &HeaderMap,
     response_url: &Url,
     status: reqwest::StatusCode,
+    request_id: Option<&str>,
 ) -> Result<Option<ODataId>, BmcError> {
-    let invalid_response = |text: &'static str| BmcError::InvalidResponse {
-        url: response_url.clone(),
-        status,
-        text: text.to_string(),
+    let invalid_response = |text: &'static str| {
+        BmcError::from(InvalidResponse {
+            url: response_url.clone(),
+            status,
+            text: text.to_string(),
+            language: None,
+            redfish_error: None,
+            request_id: request_id.map(ToString::to_string),
+        })
     };
 
     let Some(value) = headers.get(header::LOCATION) else {
@@ -995,6 +1654,72 @@ fn auth_token_from_headers(headers: &HeaderMap) -> Option<String> {
         .map(ToString::to_string)
 }
 
+/// How a request takes a connection slot of its host.
+#[derive(Clone, Copy, Debug)]
+enum Slot {
+    /// Wait for a free slot.
+    Wait,
+    /// Count the connection without waiting; used by event streams.
+    Track,
+}
+
+/// Attach connection `guard` to the response body, so the connection slot
+/// is released only when the body is consumed or dropped.
+fn hold_until_consumed(response: reqwest::Response, guard: ConnectionGuard) -> reqwest::Response {
+    // Builder without status, version or headers set cannot fail.
+    let Ok(mut held) = http::Response::builder()
+        .url(response.url().clone())
+        .body(())
+    else {
+        return response;
+    };
+    *held.status_mut() = response.status();
+    *held.version_mut() = response.version();
+    *held.headers_mut() = response.headers().clone();
+    if let Some(request_id) = response.extensions().get::<RequestId>() {
+        held.extensions_mut().insert(request_id.clone());
+    }
+    let body = response.bytes_stream().map(move |chunk| {
+        let _ = &guard;
+        chunk
+    });
+    reqwest::Response::from(held.map(|()| Body::wrap_stream(body)))
+}
+
+/// Error for the unsuccessful `response`. Keeps the response text and
+/// its language.
+async fn error_response(response: reqwest::Response) -> BmcError {
+    let url = response.url().clone();
+    let status = response.status();
+    let language = response
+        .headers()
+        .get(header::CONTENT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(ToString::to_string);
+    let request_id = request_id(&response);
+    let text = response.text().await.unwrap_or_else(|_| "<no data>".into());
+    BmcError::from(InvalidResponse {
+        url,
+        status,
+        language,
+        redfish_error: RedfishError::from_body(&text),
+        text,
+        request_id,
+    })
+}
+
+/// Correlation ID of the request that produced a response. It is kept
+/// in extensions of the response.
+#[derive(Clone)]
+struct RequestId(String);
+
+fn request_id(response: &reqwest::Response) -> Option<String> {
+    response
+        .extensions()
+        .get::<RequestId>()
+        .map(|RequestId(id)| id.clone())
+}
+
 fn etag_from_headers(headers: &HeaderMap) -> Option<ODataETag> {
     headers
         .get(header::ETAG)
@@ -1038,7 +1763,7 @@ fn is_redfish_success_response(value: &serde_json::Value) -> bool {
         return true;
     }
 
-    let Ok(response) = <RedfishError as serde::Deserialize>::deserialize(value) else {
+    let Ok(response) = <RedfishErrorSchema as serde::Deserialize>::deserialize(value) else {
         return false;
     };
 
@@ -1084,6 +1809,23 @@ impl HttpClient for Client {
         self.handle_response(response).await
     }
 
+    async fn get_json<T>(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<T, Self::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let request = auth_headers(self.inner.get(url), credentials)
+            .headers(custom_headers.clone())
+            .header(header::ACCEPT, "application/json");
+
+        let response = self.send(request.build()?).await?;
+        self.handle_response(response).await
+    }
+
     async fn post<B, T>(
         &self,
         url: Url,
@@ -1123,10 +1865,145 @@ impl HttpClient for Client {
         self.handle_session_response(response).await
     }
 
+    async fn get_binary(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<Option<Vec<u8>>, Self::Error> {
+        let request = auth_headers(self.inner.get(url), credentials)
+            .headers(custom_headers.clone())
+            .header(header::ACCEPT, "application/octet-stream");
+
+        let response = self.send(request.build()?).await?;
+        if !response.status().is_success() {
+            return Err(error_response(response).await);
+        }
+
+        // Body of the response is wrapped to hold the connection slot,
+        // so the size is known only from the header.
+        let limit = self.max_binary_bytes;
+        let content_length = response
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+        if content_length.is_some_and(|len| len > limit) {
+            return Err(BmcError::BinaryTooLarge { limit });
+        }
+        let mut data = Vec::new();
+        let mut chunks = response.bytes_stream();
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk.map_err(BmcError::ReqwestError)?;
+            if exceeds(data.len() + chunk.len(), limit) {
+                return Err(BmcError::BinaryTooLarge { limit });
+            }
+            data.extend_from_slice(&chunk);
+        }
+        Ok(Some(data))
+    }
+
+    async fn allowed_methods(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<Option<AllowedMethods>, Self::Error> {
+        let request =
+            auth_headers(self.inner.head(url), credentials).headers(custom_headers.clone());
+
+        let response = self.send(request.build()?).await?;
+        let status = response.status();
+        // Services that don't implement HEAD don't declare allowed
+        // methods either.
+        if status == reqwest::StatusCode::METHOD_NOT_ALLOWED
+            || status == reqwest::StatusCode::NOT_IMPLEMENTED
+        {
+            return Ok(None);
+        }
+        if !status.is_success() {
+            return Err(error_response(response).await);
+        }
+
+        Ok(response
+            .headers()
+            .get(header::ALLOW)
+            .and_then(|value| value.to_str().ok())
+            .map(AllowedMethods::from_header))
+    }
+
+    async fn get_task_monitor(
+        &self,
+        url: Url,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<Option<TaskMonitorResponse>, Self::Error> {
+        let request = auth_headers(self.inner.get(url), credentials)
+            .headers(custom_headers.clone())
+            .header(header::ACCEPT, "application/json");
+
+        let response = self.send(request.build()?).await?;
+        let status = response.status();
+        let url = response.url().clone();
+        let headers = response.headers().clone();
+        let request_id = request_id(&response);
+        if !status.is_success() {
+            return Err(error_response(response).await);
+        }
+        if status == reqwest::StatusCode::ACCEPTED {
+            return Ok(Some(TaskMonitorResponse::Running {
+                retry_after: retry_after_from_headers(&headers),
+            }));
+        }
+
+        let location = location_from_headers(&headers, &url, status, request_id.as_deref())?;
+        let bytes = response.bytes().await.map_err(BmcError::ReqwestError)?;
+        let payload = if bytes.is_empty() {
+            None
+        } else {
+            Some(serde_json::from_slice(&bytes).map_err(BmcError::DecodeError)?)
+        };
+        Ok(Some(TaskMonitorResponse::Finished(AsyncTaskResult {
+            location,
+            payload,
+        })))
+    }
+
+    async fn put_binary<U, T>(
+        &self,
+        url: Url,
+        upload_request: BinaryUploadRequest<U>,
+        credentials: &BmcCredentials,
+        custom_headers: &HeaderMap,
+    ) -> Result<Option<ModificationResponse<T>>, Self::Error>
+    where
+        U: UploadReader,
+        T: DeserializeOwned + Send + Sync,
+    {
+        let BinaryUploadRequest {
+            upload_stream,
+            upload_timeout,
+        } = upload_request;
+
+        let limit = self.max_binary_bytes;
+        if upload_stream.content_length.is_some_and(|len| len > limit) {
+            return Err(BmcError::BinaryTooLarge { limit });
+        }
+
+        let request = auth_headers(self.inner.put(url), credentials)
+            .headers(custom_headers.clone())
+            .timeout(upload_timeout);
+
+        let request = binary_body(request, upload_stream, Some(limit));
+
+        let response = self.send(request.build()?).await?;
+        self.handle_modification_response(response).await.map(Some)
+    }
+
     async fn patch<B, T>(
         &self,
         url: Url,
-        etag: ODataETag,
+        etag: Option<ODataETag>,
         body: &B,
         credentials: &BmcCredentials,
         custom_headers: &HeaderMap,
@@ -1138,7 +2015,9 @@ impl HttpClient for Client {
         let mut request =
             auth_headers(self.inner.patch(url), credentials).headers(custom_headers.clone());
 
-        request = request.header(header::IF_MATCH, etag.to_string());
+        if let Some(etag) = etag {
+            request = request.header(header::IF_MATCH, etag.to_string());
+        }
 
         let response = self.send(request.json(body).build()?).await?;
         self.handle_modification_response(response).await
@@ -1227,22 +2106,11 @@ impl HttpClient for Client {
             upload_timeout,
         } = update_request;
 
-        let UploadStream {
-            reader,
-            content_length,
-        } = update_stream;
-
-        let body = reqwest::Body::wrap_stream(ReaderStream::new(reader.compat()));
-
-        let mut request = auth_headers(self.inner.post(url), credentials)
+        let request = auth_headers(self.inner.post(url), credentials)
             .headers(custom_headers.clone())
-            .header(header::CONTENT_TYPE, "application/octet-stream")
-            .body(body)
             .timeout(upload_timeout);
 
-        if let Some(content_length) = content_length {
-            request = request.header(header::CONTENT_LENGTH, content_length.to_string());
-        }
+        let request = binary_body(request, update_stream, None);
 
         let response = self.send(request.build()?).await?;
         self.handle_modification_response(response).await
@@ -1263,14 +2131,10 @@ impl HttpClient for Client {
             .header(header::ACCEPT, "text/event-stream")
             .timeout(Duration::MAX);
 
-        let response = self.send(request.build()?).await?;
+        let response = self.send_stream(request.build()?).await?;
 
         if !response.status().is_success() {
-            return Err(BmcError::InvalidResponse {
-                url: response.url().clone(),
-                status: response.status(),
-                text: response.text().await.unwrap_or_else(|_| "<no data>".into()),
-            });
+            return Err(error_response(response).await);
         }
 
         let capped = cap_event_bytes(response.bytes_stream(), self.sse.max_event_bytes);
@@ -1308,6 +2172,107 @@ impl HttpClient for Client {
 
         Ok(Box::pin(guarded))
     }
+
+    fn error_response_body(error: &BmcError) -> Option<&str> {
+        match error {
+            BmcError::InvalidResponse(response) => Some(&response.text),
+            BmcError::RetriesExhausted { last, .. } => Self::error_response_body(last),
+            _ => None,
+        }
+    }
+
+    fn error_response_language(error: &BmcError) -> Option<&str> {
+        match error {
+            BmcError::InvalidResponse(response) => response.language.as_deref(),
+            BmcError::RetriesExhausted { last, .. } => Self::error_response_language(last),
+            _ => None,
+        }
+    }
+
+    fn latency_class(&self, url: &Url) -> Option<LatencyClass> {
+        self.latency.class(url)
+    }
+
+    fn redfish_error(error: &BmcError) -> Option<&RedfishError> {
+        match error {
+            BmcError::InvalidResponse(response) => response.redfish_error.as_ref(),
+            BmcError::RetriesExhausted { last, .. } => Self::redfish_error(last),
+            _ => None,
+        }
+    }
+
+    fn error_status_code(error: &BmcError) -> Option<u16> {
+        match error {
+            BmcError::InvalidResponse(response) => Some(response.status.as_u16()),
+            BmcError::Conflict { .. } => Some(reqwest::StatusCode::PRECONDITION_FAILED.as_u16()),
+            BmcError::RetriesExhausted { last, .. } => Self::error_status_code(last),
+            _ => None,
+        }
+    }
+
+    fn error_odata_type(error: &BmcError) -> Option<&str> {
+        match error {
+            BmcError::PayloadJsonError { odata_type, .. } => Some(odata_type),
+            _ => None,
+        }
+    }
+}
+
+/// Deserialize response payload. `@odata.type` of the payload is
+/// taken before deserializing so that the error tells which type
+/// failed to parse.
+fn deserialize_payload<T: DeserializeOwned>(value: serde_json::Value) -> Result<T, BmcError> {
+    let odata_type = value
+        .get("@odata.type")
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned);
+    serde_path_to_error::deserialize(value).map_err(|error| match odata_type {
+        Some(odata_type) => BmcError::PayloadJsonError { odata_type, error },
+        None => BmcError::JsonError(error),
+    })
+}
+
+/// Streamed body of a raw binary upload. With `limit` the upload is
+/// aborted once the reader produces more than `limit` bytes.
+fn binary_body<U>(
+    request: reqwest::RequestBuilder,
+    stream: UploadStream<U>,
+    limit: Option<u64>,
+) -> reqwest::RequestBuilder
+where
+    U: UploadReader,
+{
+    let UploadStream {
+        reader,
+        content_length,
+    } = stream;
+
+    let mut sent = 0;
+    let chunks = ReaderStream::new(reader.compat()).map(move |chunk| {
+        let chunk = chunk?;
+        sent += chunk.len();
+        match limit {
+            Some(limit) if exceeds(sent, limit) => Err(IoError::other(
+                BmcError::BinaryTooLarge { limit }.to_string(),
+            )),
+            _ => Ok(chunk),
+        }
+    });
+    let body = reqwest::Body::wrap_stream(chunks);
+
+    let mut request = request
+        .header(header::CONTENT_TYPE, "application/octet-stream")
+        .body(body);
+
+    if let Some(content_length) = content_length {
+        request = request.header(header::CONTENT_LENGTH, content_length.to_string());
+    }
+
+    request
+}
+
+fn exceeds(len: usize, limit: u64) -> bool {
+    u64::try_from(len).map_or(true, |len| len > limit)
 }
 
 fn build_update_parameters_part<V>(update_parameters: &V) -> Result<Part, BmcError>
@@ -1424,11 +2389,14 @@ mod tests {
                 .body("")
                 .expect("Valid empty body"),
         );
-        let error = BmcError::InvalidResponse {
+        let error = BmcError::from(InvalidResponse {
             url: "http://example.com/redfish/v1".parse().unwrap(),
             status: mock_response.status(),
             text: "".into(),
-        };
+            language: None,
+            redfish_error: None,
+            request_id: None,
+        });
         assert!(error.is_cached());
 
         let cache_miss = BmcError::CacheMiss;
@@ -1571,14 +2539,14 @@ mod tests {
             headers.insert(header::LOCATION, raw.parse::<HeaderValue>()?);
 
             let result =
-                location_from_headers(&headers, &response_url, reqwest::StatusCode::CREATED);
+                location_from_headers(&headers, &response_url, reqwest::StatusCode::CREATED, None);
 
             match (result, expected) {
                 (Ok(Some(location)), Ok(expected)) => {
                     assert_eq!(location.to_string(), expected, "{raw}");
                 }
-                (Err(BmcError::InvalidResponse { text, .. }), Err(expected)) => {
-                    assert_eq!(text, expected, "{raw}");
+                (Err(BmcError::InvalidResponse(response)), Err(expected)) => {
+                    assert_eq!(response.text, expected, "{raw}");
                 }
                 _ => return Err(format!("{raw}: unexpected Location result").into()),
             }
@@ -1648,6 +2616,43 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_allowed_methods_are_read_from_allow_header() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("HEAD"))
+            .and(path("/redfish/v1/Systems/1"))
+            .respond_with(ResponseTemplate::new(200).insert_header("Allow", "GET, HEAD, PATCH"))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("HEAD"))
+            .and(path("/redfish/v1/Chassis/1"))
+            .respond_with(ResponseTemplate::new(405))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new()?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+        let allowed = client
+            .allowed_methods(
+                Url::parse(&format!("{}/redfish/v1/Systems/1", mock_server.uri()))?,
+                &credentials,
+                &HeaderMap::new(),
+            )
+            .await?;
+        assert_eq!(allowed, Some(AllowedMethods::from_header("GET, PATCH")));
+
+        let unknown = client
+            .allowed_methods(
+                Url::parse(&format!("{}/redfish/v1/Chassis/1", mock_server.uri()))?,
+                &credentials,
+                &HeaderMap::new(),
+            )
+            .await?;
+        assert_eq!(unknown, None);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_post_is_not_retried() -> Result<(), Box<dyn StdError>> {
         let mock_server = MockServer::start().await;
@@ -1676,8 +2681,8 @@ mod tests {
 
         assert!(matches!(
             response,
-            Err(BmcError::InvalidResponse { status, .. })
-                if status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+            Err(BmcError::InvalidResponse(response))
+                if response.status == reqwest::StatusCode::SERVICE_UNAVAILABLE
         ));
 
         Ok(())
@@ -1712,6 +2717,243 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_exhausted_retries_report_attempts() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1";
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(503).set_body_string("busy"))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_params(ClientParams::new().retry(test_retry_policy(2, None)))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let error = client
+            .get::<serde_json::Value>(
+                Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await
+            .expect_err("retries must be exhausted");
+
+        let BmcError::RetriesExhausted { attempts, last, .. } = &error else {
+            return Err(String::from("expected exhausted retries").into());
+        };
+        assert_eq!(*attempts, 3);
+        assert!(matches!(
+            last.as_ref(),
+            BmcError::InvalidResponse(response)
+                if response.status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+        assert_eq!(Client::error_status_code(&error), Some(503));
+        assert_eq!(Client::error_response_body(&error), Some("busy"));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_exhausted_retries_return_last_successful_response(
+    ) -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1/TaskService/Tasks/1";
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+                "@odata.id": resource_path,
+                "TaskState": "Running"
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let policy =
+            RetryPolicy::new(|_, response| response.status() == reqwest::StatusCode::ACCEPTED)
+                .max_retries(1);
+        let client = Client::with_params(ClientParams::new().retry(policy))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let response = client
+            .get::<serde_json::Value>(
+                Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await?;
+        assert_eq!(response["TaskState"], "Running");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_retry_releases_connection_slot() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1";
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "@odata.id": resource_path
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_params(
+            ClientParams::new()
+                .retry(test_retry_policy(1, Some(Duration::from_millis(200))))
+                .pool_max_connections_per_host(1),
+        )?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+        let url = Url::parse(&format!("{}{resource_path}", mock_server.uri()))?;
+
+        let retried =
+            client.get::<serde_json::Value>(url.clone(), &credentials, None, &HeaderMap::new());
+        let other = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            let stats = client.pool_stats();
+            assert!(stats.iter().all(|host| host.active == 0));
+            client
+                .get::<serde_json::Value>(url.clone(), &credentials, None, &HeaderMap::new())
+                .await
+        };
+        let (retried, other) = tokio::join!(retried, other);
+        assert_eq!(retried?["@odata.id"], resource_path);
+        assert_eq!(other?["@odata.id"], resource_path);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transient_policy_retries_connection_errors() -> Result<(), Box<dyn StdError>> {
+        // Nothing listens on the port once the listener is dropped, so
+        // every attempt is refused.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        drop(listener);
+
+        let policy = RetryPolicy::transient()
+            .max_retries(2)
+            .exponential_backoff(Duration::from_millis(1), Duration::from_millis(2));
+        let client = Client::with_params(ClientParams::new().retry(policy))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let error = client
+            .get::<serde_json::Value>(
+                Url::parse(&format!("http://{address}/redfish/v1"))?,
+                &credentials,
+                None,
+                &HeaderMap::new(),
+            )
+            .await
+            .expect_err("connection must be refused");
+
+        let BmcError::RetriesExhausted { attempts, last, .. } = &error else {
+            return Err(String::from("expected exhausted retries").into());
+        };
+        assert_eq!(*attempts, 3);
+        assert!(matches!(last.as_ref(), BmcError::ReqwestError(e) if e.is_connect()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_transient_policy_does_not_retry_post() -> Result<(), Box<dyn StdError>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset";
+        Mock::given(method("POST"))
+            .and(path(resource_path))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::with_params(ClientParams::new().retry(RetryPolicy::transient()))?;
+        let credentials = BmcCredentials::new("root".to_string(), "password".to_string());
+
+        let response = client
+            .post::<_, serde_json::Value>(
+                Url::parse(&format!("{}{resource_path}", mock_server.uri()))?,
+                &serde_json::json!({ "ResetType": "ForceRestart" }),
+                &credentials,
+                &HeaderMap::new(),
+            )
+            .await;
+
+        assert!(matches!(
+            response,
+            Err(BmcError::InvalidResponse(response))
+                if response.status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_backoff_is_jittered_and_capped() {
+        let backoff = Backoff {
+            initial: Duration::from_millis(100),
+            max: Duration::from_millis(1000),
+        };
+        for (attempt, full) in [
+            (1, 100),
+            (2, 200),
+            (3, 400),
+            (4, 800),
+            (5, 1000),
+            (40, 1000),
+        ] {
+            let full = Duration::from_millis(full);
+            let delay = backoff.delay(attempt);
+            assert!(
+                delay >= full / 2 && delay <= full,
+                "attempt {}: {:?} is not within [{:?}, {:?}]",
+                attempt,
+                delay,
+                full / 2,
+                full
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_after_takes_precedence_and_is_capped() {
+        let policy = RetryPolicy::transient();
+        assert_eq!(
+            policy.delay_before_retry(1, Some(Duration::from_secs(3))),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(
+            policy.delay_before_retry(1, Some(Duration::from_secs(45))),
+            Some(Duration::from_secs(45))
+        );
+        assert_eq!(
+            policy.delay_before_retry(1, Some(Duration::from_hours(24))),
+            Some(Duration::from_mins(1))
+        );
+        assert_eq!(
+            policy
+                .clone()
+                .max_retry_after(Duration::from_mins(5))
+                .delay_before_retry(1, Some(Duration::from_hours(24))),
+            Some(Duration::from_mins(5))
+        );
+
+        let ignoring = policy
+            .honor_retry_after(false)
+            .delay_before_retry(1, Some(Duration::from_secs(3)));
+        assert!(ignoring.is_some_and(|delay| delay <= Duration::from_millis(500)));
+    }
+
     #[tokio::test]
     async fn test_streaming_body_is_not_retried() -> Result<(), Box<dyn StdError>> {
         let mock_server = MockServer::start().await;
@@ -1761,8 +3003,8 @@ mod tests {
 
         assert!(matches!(
             response,
-            Err(BmcError::InvalidResponse { status, .. })
-                if status == reqwest::StatusCode::SERVICE_UNAVAILABLE
+            Err(BmcError::InvalidResponse(response))
+                if response.status == reqwest::StatusCode::SERVICE_UNAVAILABLE
         ));
 
         Ok(())
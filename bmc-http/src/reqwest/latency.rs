@@ -0,0 +1,247 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-host latency classification of [`super::Client`].
+//!
+//! Every response is timed from the moment the request is sent until
+//! its headers arrive (time to the first byte). Requests that time out
+//! count as samples of their full duration. Samples are folded into an
+//! exponentially weighted average per host, so a single slow response
+//! does not flip classification of an otherwise fast BMC.
+//!
+//! Hosts are origins (`host:port`) of request URLs, so BMCs behind one
+//! address on different ports are classified separately.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use nv_redfish_core::LatencyClass;
+use url::Url;
+
+use super::pool::origin;
+
+// Weight of the newest sample in the average is 1 / AVERAGE_WEIGHT.
+const AVERAGE_WEIGHT: u32 = 4;
+
+/// Thresholds of time to the first byte that classify hosts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyThresholds {
+    /// Hosts with average latency below this value are
+    /// [`LatencyClass::Fast`].
+    pub fast: Duration,
+    /// Hosts with average latency at or above this value are
+    /// [`LatencyClass::Slow`].
+    pub slow: Duration,
+}
+
+impl Default for LatencyThresholds {
+    fn default() -> Self {
+        Self {
+            fast: Duration::from_millis(200),
+            slow: Duration::from_secs(3),
+        }
+    }
+}
+
+impl LatencyThresholds {
+    /// Class of latency `ttfb`.
+    #[must_use]
+    pub fn classify(&self, ttfb: Duration) -> LatencyClass {
+        if ttfb >= self.slow {
+            LatencyClass::Slow
+        } else if ttfb < self.fast {
+            LatencyClass::Fast
+        } else {
+            LatencyClass::Normal
+        }
+    }
+}
+
+/// Latency of a single BMC host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostLatencyStats {
+    /// Origin (`host:port`) of the BMC.
+    pub origin: String,
+    /// Number of timed requests.
+    pub samples: u64,
+    /// Time to the first byte of the latest request.
+    pub last: Duration,
+    /// Exponentially weighted average time to the first byte.
+    pub average: Duration,
+    /// Maximum time to the first byte.
+    pub max: Duration,
+    /// Class of [`Self::average`].
+    pub class: LatencyClass,
+}
+
+pub(super) struct LatencyTracker {
+    thresholds: LatencyThresholds,
+    hosts: Mutex<HashMap<String, HostLatency>>,
+}
+
+impl LatencyTracker {
+    pub fn new(thresholds: LatencyThresholds) -> Self {
+        Self {
+            thresholds,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Account time to the first byte of a request to `url`.
+    pub fn record(&self, url: &Url, ttfb: Duration) {
+        let origin = origin(url);
+        let mut hosts = self.hosts();
+        if let Some(latency) = hosts.get_mut(&origin) {
+            latency.add(ttfb);
+        } else {
+            hosts.insert(origin, HostLatency::new(ttfb));
+        }
+    }
+
+    /// Class of the `url` host. `None` if no request was timed yet.
+    pub fn class(&self, url: &Url) -> Option<LatencyClass> {
+        self.hosts()
+            .get(&origin(url))
+            .map(|latency| self.thresholds.classify(latency.average))
+    }
+
+    /// Snapshot of latency of all hosts, sorted by origin.
+    pub fn stats(&self) -> Vec<HostLatencyStats> {
+        let mut stats = self
+            .hosts()
+            .iter()
+            .map(|(origin, latency)| HostLatencyStats {
+                origin: origin.clone(),
+                samples: latency.samples,
+                last: latency.last,
+                average: latency.average,
+                max: latency.max,
+                class: self.thresholds.classify(latency.average),
+            })
+            .collect::<Vec<_>>();
+        stats.sort_by(|a, b| a.origin.cmp(&b.origin));
+        stats
+    }
+
+    fn hosts(&self) -> MutexGuard<'_, HashMap<String, HostLatency>> {
+        // State is always consistent between statements, so it is safe
+        // to continue with poisoned lock.
+        self.hosts.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct HostLatency {
+    samples: u64,
+    last: Duration,
+    average: Duration,
+    max: Duration,
+}
+
+impl HostLatency {
+    const fn new(ttfb: Duration) -> Self {
+        Self {
+            samples: 1,
+            last: ttfb,
+            average: ttfb,
+            max: ttfb,
+        }
+    }
+
+    fn add(&mut self, ttfb: Duration) {
+        self.samples += 1;
+        self.last = ttfb;
+        self.max = self.max.max(ttfb);
+        self.average = (self.average * (AVERAGE_WEIGHT - 1) + ttfb) / AVERAGE_WEIGHT;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(host: &str) -> Url {
+        Url::parse(&format!("https://{host}/redfish/v1")).expect("valid url")
+    }
+
+    #[test]
+    fn classifies_by_thresholds() {
+        let thresholds = LatencyThresholds {
+            fast: Duration::from_millis(100),
+            slow: Duration::from_secs(1),
+        };
+        assert_eq!(
+            thresholds.classify(Duration::from_millis(99)),
+            LatencyClass::Fast
+        );
+        assert_eq!(
+            thresholds.classify(Duration::from_millis(100)),
+            LatencyClass::Normal
+        );
+        assert_eq!(
+            thresholds.classify(Duration::from_secs(1)),
+            LatencyClass::Slow
+        );
+    }
+
+    #[test]
+    fn single_outlier_does_not_flip_class() {
+        let tracker = LatencyTracker::new(LatencyThresholds {
+            fast: Duration::from_millis(100),
+            slow: Duration::from_secs(1),
+        });
+        let bmc = url("10.0.0.1");
+        assert_eq!(tracker.class(&bmc), None);
+
+        for _ in 0..4 {
+            tracker.record(&bmc, Duration::from_millis(50));
+        }
+        tracker.record(&bmc, Duration::from_secs(2));
+        assert_eq!(tracker.class(&bmc), Some(LatencyClass::Normal));
+
+        for _ in 0..4 {
+            tracker.record(&bmc, Duration::from_secs(2));
+        }
+        let stats = tracker.stats();
+        assert_eq!(stats[0].origin, "10.0.0.1:443");
+        assert_eq!(stats[0].samples, 9);
+        assert_eq!(stats[0].last, Duration::from_secs(2));
+        assert_eq!(stats[0].max, Duration::from_secs(2));
+        assert_eq!(stats[0].class, LatencyClass::Slow);
+        assert_eq!(tracker.class(&url("10.0.0.2")), None);
+    }
+
+    #[test]
+    fn ports_of_one_address_are_classified_separately() {
+        let tracker = LatencyTracker::new(LatencyThresholds {
+            fast: Duration::from_millis(100),
+            slow: Duration::from_secs(1),
+        });
+        tracker.record(&url("10.0.0.1"), Duration::from_millis(50));
+        tracker.record(&url("10.0.0.1:8443"), Duration::from_secs(2));
+
+        assert_eq!(tracker.class(&url("10.0.0.1")), Some(LatencyClass::Fast));
+        assert_eq!(
+            tracker.class(&url("10.0.0.1:443")),
+            Some(LatencyClass::Fast)
+        );
+        assert_eq!(
+            tracker.class(&url("10.0.0.1:8443")),
+            Some(LatencyClass::Slow)
+        );
+    }
+}
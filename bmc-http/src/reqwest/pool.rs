@@ -0,0 +1,485 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-host connection accounting of [`super::Client`].
+//!
+//! A host is an origin (`host:port`) of request URLs, so BMCs behind one
+//! address on different ports are accounted separately.
+//!
+//! reqwest does not expose state of its connection pool. Instead, this
+//! module tracks connections from the request lifecycle: a connection is
+//! active from the moment the request is sent until its response body
+//! is consumed or dropped. After that the connection is considered idle
+//! until it is reused by the next request to the same host or until the
+//! pool idle timeout expires.
+//!
+//! The same accounting enforces per-host limits: a request waits until
+//! the number of active connections to its host drops below the
+//! configured limit and then until its turn to start, so starts of
+//! requests to the host are spaced by `1 / max_requests_per_second`.
+//! The slot is held until the response body is consumed, so streamed
+//! bodies count against the limit too. Long-lived event streams are
+//! tracked but don't take a slot and don't wait for their turn, so an
+//! open stream never blocks other requests.
+//!
+//! Hosts without active or idle connections are forgotten, so a
+//! long-running client doesn't keep state of every BMC it ever
+//! contacted.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+use std::sync::PoisonError;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::clock::Clock;
+
+use tokio::sync::OwnedSemaphorePermit;
+use tokio::sync::Semaphore;
+use url::Url;
+
+/// Origin (`host:port`) of `url` that keys state of a BMC host. The
+/// port is the default port of the scheme if the URL doesn't set it.
+pub(super) fn origin(url: &Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    url.port_or_known_default()
+        .map_or_else(|| host.to_owned(), |port| format!("{host}:{port}"))
+}
+
+/// Connection pool state of a single BMC host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostPoolStats {
+    /// Origin (`host:port`) of the BMC.
+    pub origin: String,
+    /// Connections that currently carry a request or a response body.
+    pub active: usize,
+    /// Estimated number of connections kept open for reuse: released
+    /// connections that are neither reused nor expired by the pool idle
+    /// timeout. reqwest may close connections earlier (for example,
+    /// when the BMC closes them).
+    pub idle: usize,
+    /// Effective maximum number of active connections. `None` means
+    /// there is no limit.
+    pub max_connections: Option<usize>,
+}
+
+/// Connection limits and idle settings of the pool.
+pub(super) struct PoolConfig {
+    pub max_connections_per_host: Option<usize>,
+    pub max_connections_overrides: HashMap<String, usize>,
+    pub max_requests_per_second: Option<u32>,
+    pub max_idle_per_host: Option<usize>,
+    pub idle_timeout: Option<Duration>,
+}
+
+pub(super) struct ConnectionPool {
+    config: PoolConfig,
+    clock: Arc<dyn Clock>,
+    hosts: Mutex<HashMap<String, Arc<HostSlot>>>,
+}
+
+impl ConnectionPool {
+    pub fn new(config: PoolConfig, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            config,
+            clock,
+            hosts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait for a free connection slot of the `url` host and for the
+    /// turn of the request to start.
+    pub async fn acquire(&self, url: &Url) -> ConnectionGuard {
+        let slot = self.slot(url);
+        let permit = match &slot.semaphore {
+            // Semaphore is never closed, so acquire cannot fail.
+            Some(semaphore) => Arc::clone(semaphore).acquire_owned().await.ok(),
+            None => None,
+        };
+        if let Some(delay) = self.reserve_start(&slot) {
+            self.clock.sleep(delay).await;
+        }
+        self.activate(slot, permit)
+    }
+
+    /// Reserve the earliest free start time of a request to the host
+    /// and return how long the request has to wait for it.
+    fn reserve_start(&self, slot: &HostSlot) -> Option<Duration> {
+        let interval = self
+            .config
+            .max_requests_per_second
+            .map(|max| Duration::from_secs(1) / max.max(1))?;
+        let now = self.clock.now();
+        let mut state = slot.state();
+        let start = state.next_start.map_or(now, |next| next.max(now));
+        state.next_start = Some(start + interval);
+        drop(state);
+        Some(start.saturating_duration_since(now)).filter(|delay| !delay.is_zero())
+    }
+
+    /// Track a connection of the `url` host without waiting for a free
+    /// slot. Used for event streams that stay open for an unbounded
+    /// time: holding a slot for the whole stream would block every
+    /// other request to the host.
+    pub fn track(&self, url: &Url) -> ConnectionGuard {
+        let slot = self.slot(url);
+        self.activate(slot, None)
+    }
+
+    fn activate(
+        &self,
+        slot: Arc<HostSlot>,
+        permit: Option<OwnedSemaphorePermit>,
+    ) -> ConnectionGuard {
+        slot.state()
+            .activate(self.config.idle_timeout, self.clock.now());
+        ConnectionGuard {
+            slot,
+            max_idle: self.config.max_idle_per_host,
+            clock: Arc::clone(&self.clock),
+            _permit: permit,
+        }
+    }
+
+    /// Snapshot of state of all hosts that have active or idle
+    /// connections, sorted by origin.
+    pub fn stats(&self) -> Vec<HostPoolStats> {
+        let mut hosts = self.hosts();
+        self.evict_unused(&mut hosts);
+        let mut stats = hosts
+            .iter()
+            .map(|(origin, slot)| {
+                let mut state = slot.state();
+                state.expire_idle(self.config.idle_timeout, self.clock.now());
+                HostPoolStats {
+                    origin: origin.clone(),
+                    active: state.active,
+                    idle: state.idle.len(),
+                    max_connections: slot.max_connections,
+                }
+            })
+            .collect::<Vec<_>>();
+        drop(hosts);
+        stats.sort_by(|a, b| a.origin.cmp(&b.origin));
+        stats
+    }
+
+    fn slot(&self, url: &Url) -> Arc<HostSlot> {
+        let origin = origin(url);
+        let mut hosts = self.hosts();
+        if let Some(slot) = hosts.get(&origin) {
+            return Arc::clone(slot);
+        }
+        self.evict_unused(&mut hosts);
+        // Override of the exact origin wins over override of the host
+        // name that applies to all its ports.
+        let overrides = &self.config.max_connections_overrides;
+        let max_connections = overrides
+            .get(&origin)
+            .or_else(|| overrides.get(url.host_str().unwrap_or_default()))
+            .copied()
+            .or(self.config.max_connections_per_host);
+        let slot = Arc::new(HostSlot {
+            max_connections,
+            semaphore: max_connections.map(|max| Arc::new(Semaphore::new(max))),
+            state: Mutex::new(HostState::default()),
+        });
+        hosts.insert(origin, Arc::clone(&slot));
+        slot
+    }
+
+    /// Forget hosts that have neither active nor idle connections and
+    /// whose reserved start time has passed. A slot referenced outside
+    /// of the map belongs to a request that holds or waits for a
+    /// connection, so it is kept.
+    fn evict_unused(&self, hosts: &mut HashMap<String, Arc<HostSlot>>) {
+        let now = self.clock.now();
+        hosts.retain(|_, slot| {
+            if Arc::strong_count(slot) > 1 {
+                return true;
+            }
+            let mut state = slot.state();
+            state.expire_idle(self.config.idle_timeout, now);
+            state.active > 0
+                || !state.idle.is_empty()
+                || state.next_start.is_some_and(|next| next > now)
+        });
+    }
+
+    fn hosts(&self) -> MutexGuard<'_, HashMap<String, Arc<HostSlot>>> {
+        // State is always consistent between statements, so it is safe
+        // to continue with poisoned lock.
+        self.hosts.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+struct HostSlot {
+    max_connections: Option<usize>,
+    semaphore: Option<Arc<Semaphore>>,
+    state: Mutex<HostState>,
+}
+
+impl HostSlot {
+    fn state(&self) -> MutexGuard<'_, HostState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Default)]
+struct HostState {
+    active: usize,
+    // Time when each idle connection was released, oldest first.
+    idle: Vec<Instant>,
+    // Earliest time when the next request to the host may start.
+    next_start: Option<Instant>,
+}
+
+impl HostState {
+    fn activate(&mut self, idle_timeout: Option<Duration>, now: Instant) {
+        self.expire_idle(idle_timeout, now);
+        // The most recently released connection is reused first.
+        self.idle.pop();
+        self.active += 1;
+    }
+
+    fn release(&mut self, max_idle: Option<usize>, now: Instant) {
+        self.active = self.active.saturating_sub(1);
+        self.idle.push(now);
+        if let Some(max_idle) = max_idle {
+            let excess = self.idle.len().saturating_sub(max_idle);
+            self.idle.drain(..excess);
+        }
+    }
+
+    fn expire_idle(&mut self, idle_timeout: Option<Duration>, now: Instant) {
+        if let Some(idle_timeout) = idle_timeout {
+            self.idle
+                .retain(|released| now.saturating_duration_since(*released) < idle_timeout);
+        }
+    }
+}
+
+/// Active connection slot. The slot is released when guard is dropped.
+pub(super) struct ConnectionGuard {
+    slot: Arc<HostSlot>,
+    max_idle: Option<usize>,
+    clock: Arc<dyn Clock>,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.slot.state().release(self.max_idle, self.clock.now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::SystemTime;
+
+    use crate::clock::Sleep;
+    use crate::clock::SystemClock;
+
+    use tokio::time::timeout;
+
+    /// Clock that advances only when it is asked to sleep and records
+    /// requested sleeps.
+    #[derive(Debug)]
+    struct ManualClock {
+        start: Instant,
+        state: Mutex<(Duration, Vec<Duration>)>,
+    }
+
+    impl ManualClock {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                start: Instant::now(),
+                state: Mutex::new((Duration::ZERO, Vec::new())),
+            })
+        }
+
+        fn sleeps(&self) -> Vec<Duration> {
+            self.state.lock().expect("not poisoned").1.clone()
+        }
+    }
+
+    impl Clock for ManualClock {
+        fn now(&self) -> Instant {
+            self.start + self.state.lock().expect("not poisoned").0
+        }
+
+        fn system_time(&self) -> SystemTime {
+            SystemTime::UNIX_EPOCH
+        }
+
+        fn sleep(&self, duration: Duration) -> Sleep {
+            let mut state = self.state.lock().expect("not poisoned");
+            state.0 += duration;
+            state.1.push(duration);
+            drop(state);
+            Box::pin(async {})
+        }
+    }
+
+    fn pool(
+        max_connections_per_host: Option<usize>,
+        overrides: &[(&str, usize)],
+    ) -> ConnectionPool {
+        pool_with_idle(max_connections_per_host, overrides, Some(1))
+    }
+
+    fn pool_with_idle(
+        max_connections_per_host: Option<usize>,
+        overrides: &[(&str, usize)],
+        max_idle_per_host: Option<usize>,
+    ) -> ConnectionPool {
+        ConnectionPool::new(
+            PoolConfig {
+                max_connections_per_host,
+                max_connections_overrides: overrides
+                    .iter()
+                    .map(|(host, max)| ((*host).to_owned(), *max))
+                    .collect(),
+                max_requests_per_second: None,
+                max_idle_per_host,
+                idle_timeout: None,
+            },
+            Arc::new(SystemClock),
+        )
+    }
+
+    fn url(host: &str) -> Url {
+        Url::parse(&format!("https://{host}/redfish/v1")).expect("valid url")
+    }
+
+    #[tokio::test]
+    async fn tracks_active_and_idle_connections() {
+        let pool = pool(None, &[]);
+        let first = pool.acquire(&url("10.0.0.1")).await;
+        let second = pool.acquire(&url("10.0.0.1")).await;
+        assert_eq!(pool.stats()[0].active, 2);
+        assert_eq!(pool.stats()[0].idle, 0);
+
+        drop(first);
+        drop(second);
+        let stats = pool.stats();
+        assert_eq!(stats[0].active, 0);
+        // Limited by max idle connections per host.
+        assert_eq!(stats[0].idle, 1);
+
+        let _reused = pool.acquire(&url("10.0.0.1")).await;
+        assert_eq!(pool.stats()[0].active, 1);
+        assert_eq!(pool.stats()[0].idle, 0);
+    }
+
+    #[tokio::test]
+    async fn per_host_override_limits_connections() {
+        let pool = pool(Some(4), &[("aggregator", 1)]);
+        let guard = pool.acquire(&url("aggregator")).await;
+        let _bmc = pool.acquire(&url("10.0.0.1")).await;
+
+        let stats = pool.stats();
+        assert_eq!(stats[0].origin, "10.0.0.1:443");
+        assert_eq!(stats[0].max_connections, Some(4));
+        assert_eq!(stats[1].origin, "aggregator:443");
+        assert_eq!(stats[1].max_connections, Some(1));
+
+        let blocked = timeout(Duration::from_millis(10), pool.acquire(&url("aggregator")))
+            .await
+            .is_err();
+        assert!(blocked);
+        assert_eq!(pool.stats()[1].active, 1);
+
+        drop(guard);
+        let _next = pool.acquire(&url("aggregator")).await;
+        assert_eq!(pool.stats()[1].active, 1);
+    }
+
+    #[tokio::test]
+    async fn ports_of_one_address_have_separate_limits() {
+        let pool = pool(Some(1), &[("10.0.0.1:8443", 2)]);
+        let _first = pool.acquire(&url("10.0.0.1")).await;
+        // Port 443 is at its limit, but port 8443 has its own slot.
+        let _second = timeout(
+            Duration::from_millis(10),
+            pool.acquire(&url("10.0.0.1:8443")),
+        )
+        .await
+        .expect("not blocked");
+
+        let stats = pool.stats();
+        assert_eq!(stats[0].origin, "10.0.0.1:443");
+        assert_eq!(stats[0].max_connections, Some(1));
+        assert_eq!(stats[1].origin, "10.0.0.1:8443");
+        assert_eq!(stats[1].max_connections, Some(2));
+    }
+
+    #[tokio::test]
+    async fn tracked_stream_does_not_take_slot() {
+        let pool = pool(Some(1), &[]);
+        let _stream = pool.track(&url("10.0.0.1"));
+        let request = timeout(Duration::from_millis(10), pool.acquire(&url("10.0.0.1"))).await;
+        assert!(request.is_ok());
+        assert_eq!(pool.stats()[0].active, 2);
+        drop(request);
+    }
+
+    #[tokio::test]
+    async fn spaces_starts_of_requests_per_host() {
+        let clock = ManualClock::new();
+        let pool = ConnectionPool::new(
+            PoolConfig {
+                max_connections_per_host: None,
+                max_connections_overrides: HashMap::new(),
+                max_requests_per_second: Some(4),
+                max_idle_per_host: Some(0),
+                idle_timeout: None,
+            },
+            clock.clone(),
+        );
+
+        let first = pool.acquire(&url("10.0.0.1")).await;
+        drop(first);
+        // Host without connections is not forgotten until its reserved
+        // start, so the next request still waits for its turn.
+        let _other = pool.acquire(&url("10.0.0.2")).await;
+        let _second = pool.acquire(&url("10.0.0.1")).await;
+        let _third = pool.acquire(&url("10.0.0.1")).await;
+        // Streams don't wait for their turn.
+        let _stream = pool.track(&url("10.0.0.1"));
+        assert_eq!(
+            clock.sleeps(),
+            vec![Duration::from_millis(250), Duration::from_millis(250)]
+        );
+    }
+
+    #[tokio::test]
+    async fn unused_hosts_are_forgotten() {
+        let pool = pool_with_idle(None, &[], Some(0));
+        let guard = pool.acquire(&url("10.0.0.1")).await;
+        assert_eq!(pool.stats().len(), 1);
+
+        // No idle connections are kept, so the host has no state.
+        drop(guard);
+        assert!(pool.stats().is_empty());
+
+        let _other = pool.acquire(&url("10.0.0.2")).await;
+        assert_eq!(pool.hosts().len(), 1);
+    }
+}
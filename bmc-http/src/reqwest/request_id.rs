@@ -0,0 +1,100 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Correlation IDs of requests sent by [`super::Client`].
+//!
+//! Each request gets a unique ID in a configured header so that
+//! records in BMC logs can be matched with client-side operations.
+//! The ID is also reported in [`super::BmcError::InvalidResponse`].
+//!
+//! IDs are formatted as version 8 (custom) UUIDs. They are built from a
+//! per-client seed and a request counter, so no random number source is
+//! required.
+
+use std::process;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use http::HeaderName;
+use http::HeaderValue;
+use uuid::Builder;
+
+pub(super) struct RequestIds {
+    header: HeaderName,
+    seed: u64,
+    counter: AtomicU64,
+}
+
+impl RequestIds {
+    pub fn new(header: HeaderName) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let seed = now.as_secs().rotate_left(32)
+            ^ u64::from(now.subsec_nanos())
+            ^ u64::from(process::id()).rotate_left(16);
+        Self {
+            header,
+            seed,
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Header that carries correlation ID.
+    pub const fn header(&self) -> &HeaderName {
+        &self.header
+    }
+
+    /// Generate correlation ID of the next request.
+    pub fn next(&self) -> Option<HeaderValue> {
+        let n = self.counter.fetch_add(1, Ordering::Relaxed);
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.seed.to_be_bytes());
+        bytes[8..].copy_from_slice(&n.to_be_bytes());
+        let id = Builder::from_custom_bytes(bytes).into_uuid().hyphenated();
+        HeaderValue::from_str(&id.to_string()).ok()
+    }
+
+    /// Correlation ID carried by the `request`.
+    pub fn of(&self, request: &reqwest::Request) -> Option<String> {
+        request
+            .headers()
+            .get(&self.header)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use uuid::Uuid;
+
+    #[test]
+    fn generates_distinct_custom_uuid_ids() {
+        let ids = RequestIds::new(HeaderName::from_static("x-request-id"));
+        let first = ids.next().expect("valid header value");
+        let second = ids.next().expect("valid header value");
+        assert_ne!(first, second);
+        for id in [first, second] {
+            let id = id.to_str().expect("ascii header");
+            let uuid = Uuid::parse_str(id).expect("valid uuid");
+            assert_eq!(uuid.get_version_num(), 8);
+        }
+    }
+}
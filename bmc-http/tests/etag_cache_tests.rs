@@ -27,6 +27,11 @@ mod cache_integration_tests {
     };
     use nv_redfish_core::query::{ExpandQuery, FilterQuery};
     use nv_redfish_core::Bmc;
+    use nv_redfish_core::EntityTypeRef;
+    use nv_redfish_core::ModificationResponse;
+    use nv_redfish_core::ODataETag;
+    use nv_redfish_core::ODataId;
+    use serde::Deserialize;
     use url::Url;
     use wiremock::{
         matchers::{header, method, path, query_param},
@@ -167,6 +172,58 @@ mod cache_integration_tests {
         assert!(Arc::ptr_eq(&retrieved1, &retrieved2));
     }
 
+    #[tokio::test]
+    async fn test_action_response_updates_cache() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let action_path = "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset";
+        let etag_value = "after-action";
+
+        let updated_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 7);
+
+        Mock::given(method("POST"))
+            .and(path(action_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(&updated_resource)
+                    .insert_header("etag", etag_value),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Following read is served from cache.
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .and(header("if-none-match", etag_value))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let action = create_test_action(action_path);
+        let request = ActionRequest {
+            parameter: "ForceRestart".to_string(),
+        };
+
+        let ModificationResponse::Entity(updated) = action
+            .run_and_parse::<_, TestResource>(&bmc, &request)
+            .await?
+        else {
+            return Err("expected updated resource".into());
+        };
+        assert_eq!(updated.value, 7);
+
+        let retrieved = bmc
+            .get::<TestResource>(&create_odata_id(resource_path))
+            .await?;
+        assert!(Arc::ptr_eq(&updated, &retrieved));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_expand_cache_key_includes_query() {
         let mock_server = MockServer::start().await;
@@ -372,6 +429,70 @@ mod cache_integration_tests {
         assert!(matches!(error, BmcError::CacheMiss));
     }
 
+    #[derive(Debug, Deserialize)]
+    struct RenamedResource {
+        #[serde(rename = "@odata.id")]
+        id: ODataId,
+        #[serde(rename = "@odata.etag")]
+        etag: Option<ODataETag>,
+        name: String,
+    }
+
+    impl EntityTypeRef for RenamedResource {
+        fn odata_id(&self) -> &ODataId {
+            &self.id
+        }
+
+        fn etag(&self) -> Option<&ODataETag> {
+            self.etag.as_ref()
+        }
+    }
+
+    #[tokio::test]
+    async fn cached_entry_of_other_type_is_fetched_again() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "other-type-etag";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 7);
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .and(header("if-none-match", etag_value))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(&test_resource)
+                    .insert_header("etag", etag_value),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let bmc = create_test_bmc(&mock_server);
+        let resource_id = create_odata_id(resource_path);
+
+        let first = bmc.get::<TestResource>(&resource_id).await?;
+        assert_eq!(first.value, 7);
+        assert_eq!(bmc.cache_refetch_count(), 0);
+
+        // BMC reports that resource is not modified, but the cached
+        // entry cannot be used as `RenamedResource`.
+        let renamed = bmc.get::<RenamedResource>(&resource_id).await?;
+        assert_eq!(renamed.name, names::TEST_SYSTEM);
+        assert_eq!(bmc.cache_refetch_count(), 1);
+
+        mock_server.verify().await;
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn zero_capacity_disables_etag_and_body_caching() -> Result<(), Box<dyn Error>> {
         let mock_server = MockServer::start().await;
@@ -424,6 +545,53 @@ mod cache_integration_tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn invalidated_cache_does_not_send_if_none_match() -> Result<(), Box<dyn Error>> {
+        let mock_server = MockServer::start().await;
+        let resource_path = paths::SYSTEMS_1;
+        let etag_value = "before-restart-etag";
+        let test_resource =
+            create_test_resource(resource_path, Some(etag_value), names::TEST_SYSTEM, 42);
+
+        Mock::given(method("GET"))
+            .and(path(resource_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(&test_resource)
+                    .insert_header("etag", etag_value),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let client = Client::new()?;
+        let bmc = HttpBmc::new(
+            client,
+            Url::parse(&mock_server.uri())?,
+            create_test_credentials(),
+            CacheSettings::default(),
+        );
+
+        let resource_id = create_odata_id(resource_path);
+
+        bmc.get::<TestResource>(&resource_id).await?;
+        bmc.invalidate_cache();
+        let result = bmc.get::<TestResource>(&resource_id).await?;
+
+        mock_server.verify().await;
+
+        let Some(received_requests) = mock_server.received_requests().await else {
+            panic!("request recording should be enabled");
+        };
+
+        assert_eq!(result.value, 42);
+        assert!(received_requests
+            .iter()
+            .all(|request| !request.headers.contains_key("if-none-match")));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_etag_cache_from_header() {
         let mock_server = MockServer::start().await;
@@ -261,6 +261,61 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn open_stream_does_not_block_requests_to_host() {
+        use nv_redfish_bmc_http::reqwest::{Client, ClientParams};
+        use nv_redfish_bmc_http::{CacheSettings, HttpBmc};
+        use nv_redfish_core::ODataId;
+        use std::time::Duration;
+        use url::Url;
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path(SSE_URI))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-type", "text/event-stream")
+                    .set_body_string("data: {}\n\n"),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/redfish/v1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "@odata.id": "/redfish/v1",
+                "name": "root",
+                "value": 1
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client =
+            Client::with_params(ClientParams::new().pool_max_connections_per_host(1)).unwrap();
+        let bmc = HttpBmc::new(
+            client,
+            Url::parse(&mock_server.uri()).unwrap(),
+            create_test_credentials(),
+            CacheSettings::default(),
+        );
+
+        // The stream is kept open while the next request is sent.
+        let _stream = bmc
+            .stream::<JsonValue>(SSE_URI)
+            .await
+            .expect("stream must open");
+
+        let root = tokio::time::timeout(
+            Duration::from_secs(5),
+            bmc.get::<TestResource>(&ODataId::from("/redfish/v1".to_string())),
+        )
+        .await;
+        assert!(
+            matches!(root, Ok(Ok(_))),
+            "request must not wait for stream"
+        );
+    }
+
     #[tokio::test]
     async fn test_event_stream_rejects_cross_origin_uri() {
         let mock_server = MockServer::start().await;
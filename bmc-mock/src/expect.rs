@@ -16,10 +16,14 @@
 //! Expectations for Bmc Mock.
 
 use std::fmt::Display;
+use std::time::Duration;
 
 use nv_redfish_core::action::ActionTarget;
+use nv_redfish_core::AllowedMethods;
 use nv_redfish_core::AsyncTask;
+use nv_redfish_core::AsyncTaskResult;
 use nv_redfish_core::ODataId;
+use nv_redfish_core::TaskMonitorResponse;
 
 use serde_json::from_str;
 use serde_json::Value as JsonValue;
@@ -32,9 +36,18 @@ pub enum ExpectedRequest {
     /// Expected Get.
     Get { id: ODataId },
 
+    /// Expected Get answered with error status.
+    GetStatus { id: ODataId, status: u16 },
+
     /// Expected Expand.
     Expand { id: ODataId },
 
+    /// Expected Expand answered with error status.
+    ExpandStatus { id: ODataId, status: u16 },
+
+    /// Expected Filter.
+    Filter { id: ODataId },
+
     /// Expected Update.
     Update { id: ODataId, request: JsonValue },
 
@@ -75,6 +88,13 @@ pub enum ExpectedRequest {
         request: JsonValue,
     },
 
+    /// Expected asynchronous action.
+    ActionTask {
+        target: ActionTarget,
+        request: JsonValue,
+        task: AsyncTask,
+    },
+
     /// Expected multipart update.
     MultipartUpdate {
         uri: String,
@@ -87,12 +107,33 @@ pub enum ExpectedRequest {
     #[cfg(feature = "update-service-deprecated")]
     HttpPushUriUpdate { uri: String },
 
+    /// Expected binary download.
+    BinaryDownload { uri: String, data: Vec<u8> },
+
+    /// Expected binary upload.
+    BinaryUpload { uri: String },
+
     /// Expected Delete.
     Delete { id: ODataId },
 
     /// Expected asynchronous delete.
     DeleteTask { id: ODataId, task: AsyncTask },
 
+    /// Expected query of allowed methods.
+    AllowedMethods {
+        id: ODataId,
+        methods: Option<AllowedMethods>,
+    },
+
+    /// Expected poll of a task monitor.
+    TaskMonitor {
+        uri: String,
+        response: TaskMonitorResponse,
+    },
+
+    /// Expected poll of a task monitor that returns error status.
+    TaskMonitorStatus { uri: String, status: u16 },
+
     /// Expected Stream.
     Stream { uri: String },
 }
@@ -102,6 +143,7 @@ pub enum ExpectedRequest {
 pub struct Expect<E> {
     pub request: ExpectedRequest,
     pub response: Response<E>,
+    pub matcher: RequestMatcher,
 }
 
 impl<E> Expect<E> {
@@ -111,14 +153,47 @@ impl<E> Expect<E> {
                 id: uri.to_string().into(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
+
+    pub fn get_status(uri: impl Display, status: u16) -> Self {
+        Expect {
+            request: ExpectedRequest::GetStatus {
+                id: uri.to_string().into(),
+                status,
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
     pub fn expand(uri: impl Display, response: impl Display) -> Self {
         Expect {
             request: ExpectedRequest::Expand {
                 id: uri.to_string().into(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
+        }
+    }
+    pub fn expand_status(uri: impl Display, status: u16) -> Self {
+        Expect {
+            request: ExpectedRequest::ExpandStatus {
+                id: uri.to_string().into(),
+                status,
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+    pub fn filter(uri: impl Display, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Filter {
+                id: uri.to_string().into(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
     pub fn update(uri: impl Display, request: impl Display, response: impl Display) -> Self {
@@ -128,6 +203,7 @@ impl<E> Expect<E> {
                 request: from_str(&request.to_string()).expect("invalid json"),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -139,6 +215,7 @@ impl<E> Expect<E> {
                 task,
             },
             response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -149,6 +226,7 @@ impl<E> Expect<E> {
                 request: from_str(&request.to_string()).expect("invalid json"),
             },
             response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -159,6 +237,7 @@ impl<E> Expect<E> {
                 request: from_str(&request.to_string()).expect("invalid json"),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -170,6 +249,7 @@ impl<E> Expect<E> {
                 task,
             },
             response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -180,6 +260,7 @@ impl<E> Expect<E> {
                 request: from_str(&request.to_string()).expect("invalid json"),
             },
             response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -198,6 +279,7 @@ impl<E> Expect<E> {
                 location: location.to_string().into(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
     pub fn action(uri: impl Display, request: impl Display, response: impl Display) -> Self {
@@ -207,6 +289,19 @@ impl<E> Expect<E> {
                 request: from_str(&request.to_string()).expect("invalid json"),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn action_task(uri: impl Display, request: impl Display, task: AsyncTask) -> Self {
+        Expect {
+            request: ExpectedRequest::ActionTask {
+                target: ActionTarget::new(uri.to_string()),
+                request: from_str(&request.to_string()).expect("invalid json"),
+                task,
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -224,6 +319,7 @@ impl<E> Expect<E> {
                 oem_parts: Vec::new(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -246,6 +342,7 @@ impl<E> Expect<E> {
                 oem_parts: oem_parts.into_iter().map(|part| part.to_string()).collect(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -256,6 +353,28 @@ impl<E> Expect<E> {
                 uri: uri.to_string(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn binary_download(uri: impl Display, data: impl Into<Vec<u8>>) -> Self {
+        Expect {
+            request: ExpectedRequest::BinaryDownload {
+                uri: uri.to_string(),
+                data: data.into(),
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn binary_upload(uri: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::BinaryUpload {
+                uri: uri.to_string(),
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -265,6 +384,17 @@ impl<E> Expect<E> {
                 id: uri.to_string().into(),
             },
             response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn delete_with_response(uri: impl Display, response: impl Display) -> Self {
+        Expect {
+            request: ExpectedRequest::Delete {
+                id: uri.to_string().into(),
+            },
+            response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -275,6 +405,51 @@ impl<E> Expect<E> {
                 task,
             },
             response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn allowed_methods(uri: impl Display, allow: Option<&str>) -> Self {
+        Expect {
+            request: ExpectedRequest::AllowedMethods {
+                id: uri.to_string().into(),
+                methods: allow.map(AllowedMethods::from_header),
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn task_monitor_running(uri: impl Display, retry_after: Option<Duration>) -> Self {
+        Expect {
+            request: ExpectedRequest::TaskMonitor {
+                uri: uri.to_string(),
+                response: TaskMonitorResponse::Running { retry_after },
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn task_monitor_finished(uri: impl Display, result: AsyncTaskResult) -> Self {
+        Expect {
+            request: ExpectedRequest::TaskMonitor {
+                uri: uri.to_string(),
+                response: TaskMonitorResponse::Finished(result),
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
+        }
+    }
+
+    pub fn task_monitor_status(uri: impl Display, status: u16) -> Self {
+        Expect {
+            request: ExpectedRequest::TaskMonitorStatus {
+                uri: uri.to_string(),
+                status,
+            },
+            response: Ok(JsonValue::Null),
+            matcher: RequestMatcher::default(),
         }
     }
 
@@ -284,6 +459,203 @@ impl<E> Expect<E> {
                 uri: uri.to_string(),
             },
             response: Ok(from_str(&response.to_string()).expect("invalid json")),
+            matcher: RequestMatcher::default(),
         }
     }
+
+    /// Accept request body that contains all fields of the expected
+    /// body. Fields that are not in the expected body are ignored.
+    #[must_use]
+    pub fn partial_body(mut self) -> Self {
+        self.matcher.partial_body = true;
+        self
+    }
+
+    /// Require query string sent with expand request.
+    #[must_use]
+    pub fn with_query(mut self, query: impl Display) -> Self {
+        self.matcher.query = Some(query.to_string());
+        self
+    }
+
+    /// Require `If-Match` header sent with update request. `None`
+    /// requires update without `If-Match`.
+    #[must_use]
+    pub fn with_if_match(mut self, etag: Option<&str>) -> Self {
+        self.matcher.if_match = Some(etag.map(ToString::to_string));
+        self
+    }
+
+    /// Require `If-None-Match` header sent with get request. `None`
+    /// requires get without `If-None-Match`.
+    #[must_use]
+    pub fn with_if_none_match(mut self, etag: Option<&str>) -> Self {
+        self.matcher.if_none_match = Some(etag.map(ToString::to_string));
+        self
+    }
+}
+
+impl ExpectedRequest {
+    /// JSON body of the expected request.
+    #[must_use]
+    pub const fn body(&self) -> Option<&JsonValue> {
+        match self {
+            Self::Update { request, .. }
+            | Self::UpdateTask { request, .. }
+            | Self::UpdateEmpty { request, .. }
+            | Self::Create { request, .. }
+            | Self::CreateTask { request, .. }
+            | Self::CreateEmpty { request, .. }
+            | Self::CreateSession { request, .. }
+            | Self::Action { request, .. }
+            | Self::ActionTask { request, .. }
+            | Self::MultipartUpdate { request, .. } => Some(request),
+            _ => None,
+        }
+    }
+}
+
+/// Additional constraints checked when request matches expectation.
+#[derive(Debug, Default)]
+pub struct RequestMatcher {
+    /// Expected body is subset of the request body.
+    pub partial_body: bool,
+    /// Expected query string of expand or filter request.
+    pub query: Option<String>,
+    /// Expected `If-Match` header of update request.
+    pub if_match: Option<Option<String>>,
+    /// Expected `If-None-Match` header of get request.
+    pub if_none_match: Option<Option<String>>,
+}
+
+impl RequestMatcher {
+    /// Check that request body matches expected one.
+    #[must_use]
+    pub fn body_matches(&self, expected: &JsonValue, actual: &JsonValue) -> bool {
+        if self.partial_body {
+            json_contains(actual, expected)
+        } else {
+            expected == actual
+        }
+    }
+
+    /// Check query string of expand request.
+    #[must_use]
+    pub fn query_matches(&self, query: &str) -> bool {
+        self.query.as_ref().is_none_or(|expected| expected == query)
+    }
+
+    /// Check `If-None-Match` header of get request.
+    #[must_use]
+    pub fn if_none_match_matches(&self, etag: Option<&str>) -> bool {
+        self.if_none_match
+            .as_ref()
+            .is_none_or(|expected| expected.as_deref() == etag)
+    }
+
+    /// Check `If-Match` header of update request.
+    #[must_use]
+    pub fn if_match_matches(&self, etag: Option<&str>) -> bool {
+        self.if_match
+            .as_ref()
+            .is_none_or(|expected| expected.as_deref() == etag)
+    }
+}
+
+/// Check that `actual` contains everything from `expected`. Objects
+/// may have additional fields, arrays must have the same length.
+#[must_use]
+pub fn json_contains(actual: &JsonValue, expected: &JsonValue) -> bool {
+    match (actual, expected) {
+        (JsonValue::Object(actual), JsonValue::Object(expected)) => {
+            expected.iter().all(|(name, expected)| {
+                actual
+                    .get(name)
+                    .is_some_and(|actual| json_contains(actual, expected))
+            })
+        }
+        (JsonValue::Array(actual), JsonValue::Array(expected)) => {
+            actual.len() == expected.len()
+                && actual
+                    .iter()
+                    .zip(expected)
+                    .all(|(actual, expected)| json_contains(actual, expected))
+        }
+        _ => actual == expected,
+    }
+}
+
+/// Differences between expected and actual JSON. Each line refers
+/// to the value by JSON pointer.
+#[must_use]
+pub fn json_diff(expected: &JsonValue, actual: &JsonValue) -> Vec<String> {
+    let mut diff = Vec::new();
+    collect_diff("", expected, actual, &mut diff);
+    diff
+}
+
+fn collect_diff(path: &str, expected: &JsonValue, actual: &JsonValue, diff: &mut Vec<String>) {
+    match (expected, actual) {
+        (JsonValue::Object(expected), JsonValue::Object(actual)) => {
+            for (name, expected) in expected {
+                let path = format!("{path}/{name}");
+                match actual.get(name) {
+                    Some(actual) => collect_diff(&path, expected, actual, diff),
+                    None => diff.push(format!("{path}: missing, expected {expected}")),
+                }
+            }
+            for (name, actual) in actual {
+                if !expected.contains_key(name) {
+                    diff.push(format!("{path}/{name}: unexpected {actual}"));
+                }
+            }
+        }
+        (JsonValue::Array(expected), JsonValue::Array(actual))
+            if expected.len() == actual.len() =>
+        {
+            for (index, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                collect_diff(&format!("{path}/{index}"), expected, actual, diff);
+            }
+        }
+        _ if expected != actual => {
+            let path = if path.is_empty() { "/" } else { path };
+            diff.push(format!("{path}: expected {expected}, got {actual}"));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::json_contains;
+    use super::json_diff;
+    use serde_json::json;
+
+    #[test]
+    fn partial_body_ignores_extra_fields() {
+        let actual = json!({"Image": "http://a/b.iso", "Inserted": true, "Oem": {"A": 1, "B": 2}});
+        assert!(json_contains(&actual, &json!({"Inserted": true})));
+        assert!(json_contains(&actual, &json!({"Oem": {"B": 2}})));
+        assert!(!json_contains(&actual, &json!({"Oem": {"B": 3}})));
+        assert!(!json_contains(&actual, &json!({"WriteProtected": true})));
+        assert!(!json_contains(&json!([1, 2]), &json!([1])));
+    }
+
+    #[test]
+    fn diff_refers_to_json_pointer() {
+        let expected = json!({"Boot": {"Target": "Pxe", "Mode": "UEFI"}, "List": [1, 2]});
+        let actual = json!({"Boot": {"Target": "Hdd"}, "List": [1, 3], "Extra": null});
+        let mut diff = json_diff(&expected, &actual);
+        diff.sort();
+        assert_eq!(
+            diff,
+            vec![
+                r#"/Boot/Mode: missing, expected "UEFI""#,
+                r#"/Boot/Target: expected "Pxe", got "Hdd""#,
+                "/Extra: unexpected null",
+                "/List/1: expected 2, got 3",
+            ]
+        );
+        assert!(json_diff(&expected, &expected).is_empty());
+    }
 }
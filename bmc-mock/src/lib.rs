@@ -14,10 +14,16 @@
 // limitations under the License.
 
 pub mod expect;
+pub mod offline;
 
 #[doc(inline)]
 pub use expect::Expect;
 pub use expect::ExpectedRequest;
+pub use expect::RequestMatcher;
+#[doc(inline)]
+pub use offline::InventorySnapshot;
+#[doc(inline)]
+pub use offline::OfflineBmc;
 
 use std::collections::VecDeque;
 use std::error::Error as StdError;
@@ -28,24 +34,36 @@ use std::sync::Arc;
 use std::sync::Mutex;
 use std::sync::PoisonError;
 
+use expect::json_diff;
 use nv_redfish_core::action::ActionTarget;
 use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::query::FilterQuery;
 use nv_redfish_core::ActionError;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::BinaryUploadRequest;
 use nv_redfish_core::Bmc as NvRedfishBmc;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::Expandable;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::LatencyClass;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::NullChecked;
+use nv_redfish_core::NullPolicy;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
 use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::TaskMonitorResponse;
 use nv_redfish_core::UploadReader;
+use serde::de::DeserializeOwned;
+use serde::de::Error as _;
 use serde::Serialize;
+use serde_json::from_str;
 use serde_json::from_value;
 use serde_json::to_value;
 use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
 
 #[derive(Debug)]
 pub enum Error {
@@ -54,17 +72,27 @@ pub enum Error {
     MutexLock(String),
     NothingIsExpected,
     BadResponseJson(JsonError),
+    BadTypedResponseJson(String, JsonError),
     UnexpectedGet(ODataId, ExpectedRequest),
     UnexpectedExpand(ODataId, ExpectedRequest),
+    UnexpectedFilter(ODataId, ExpectedRequest),
     UnexpectedUpdate(ODataId, String, ExpectedRequest),
     UnexpectedCreate(ODataId, String, ExpectedRequest),
     UnexpectedCreateSession(ODataId, String, ExpectedRequest),
     UnexpectedDelete(ODataId, ExpectedRequest),
+    UnexpectedAllowedMethods(ODataId, ExpectedRequest),
     UnexpectedAction(ActionTarget, String, ExpectedRequest),
     UnexpectedMultipartUpdate(String, String, String, ExpectedRequest),
     #[cfg(feature = "update-service-deprecated")]
     UnexpectedHttpPushUriUpdate(String, ExpectedRequest),
+    UnexpectedBinaryDownload(String, ExpectedRequest),
+    UnexpectedBinaryUpload(String, ExpectedRequest),
+    UnexpectedTaskMonitor(String, ExpectedRequest),
     UnexpectedStream(String, ExpectedRequest),
+    UnexpectedQuery(ODataId, String, String),
+    UnexpectedIfMatch(ODataId, Option<String>, Option<String>),
+    UnexpectedIfNoneMatch(ODataId, Option<String>, Option<String>),
+    Status(ODataId, u16),
 }
 
 impl Display for Error {
@@ -77,43 +105,60 @@ impl Display for Error {
                 write!(f, "nothing is expected to happen but something happened")
             }
             Self::BadResponseJson(err) => write!(f, "bad json response: {err}"),
+            Self::BadTypedResponseJson(odata_type, err) => {
+                write!(f, "bad json response of {odata_type}: {err}")
+            }
             Self::UnexpectedGet(id, expected) => {
                 write!(f, "unexpected get: {id}; expected: {expected:?}")
             }
             Self::UnexpectedExpand(id, expected) => {
                 write!(f, "unexpected expand: {id}; expected: {expected:?}")
             }
+            Self::UnexpectedFilter(id, expected) => {
+                write!(f, "unexpected filter: {id}; expected: {expected:?}")
+            }
             Self::UnexpectedUpdate(id, json, expected) => {
                 write!(
                     f,
-                    "unexpected update: {id}; json: {json} expected: {expected:?}"
+                    "unexpected update: {id}; json: {json} expected: {expected:?}{}",
+                    body_diff(expected, json)
                 )
             }
             Self::UnexpectedCreate(id, json, expected) => {
                 write!(
                     f,
-                    "unexpected create: {id}; json: {json} expected: {expected:?}"
+                    "unexpected create: {id}; json: {json} expected: {expected:?}{}",
+                    body_diff(expected, json)
                 )
             }
             Self::UnexpectedCreateSession(id, json, expected) => {
                 write!(
                     f,
-                    "unexpected session create: {id}; json: {json} expected: {expected:?}"
+                    "unexpected session create: {id}; json: {json} expected: {expected:?}{}",
+                    body_diff(expected, json)
                 )
             }
             Self::UnexpectedDelete(id, expected) => {
                 write!(f, "unexpected delete: {id}; expected: {expected:?}")
             }
+            Self::UnexpectedAllowedMethods(id, expected) => {
+                write!(
+                    f,
+                    "unexpected allowed methods query: {id}; expected: {expected:?}"
+                )
+            }
             Self::UnexpectedAction(id, json, expected) => {
                 write!(
                     f,
-                    "unexpected action: {id}; json: {json} expected: {expected:?}"
+                    "unexpected action: {id}; json: {json} expected: {expected:?}{}",
+                    body_diff(expected, json)
                 )
             }
             Self::UnexpectedMultipartUpdate(uri, json, file, expected) => {
                 write!(
                     f,
-                    "unexpected multipart update: {uri}; json: {json}; file: {file}; expected: {expected:?}"
+                    "unexpected multipart update: {uri}; json: {json}; file: {file}; expected: {expected:?}{}",
+                    body_diff(expected, json)
                 )
             }
             #[cfg(feature = "update-service-deprecated")]
@@ -123,15 +168,93 @@ impl Display for Error {
                     "unexpected HttpPushUri update: {uri}; expected: {expected:?}"
                 )
             }
+            Self::UnexpectedBinaryDownload(uri, expected) => {
+                write!(
+                    f,
+                    "unexpected binary download: {uri}; expected: {expected:?}"
+                )
+            }
+            Self::UnexpectedBinaryUpload(uri, expected) => {
+                write!(f, "unexpected binary upload: {uri}; expected: {expected:?}")
+            }
+            Self::UnexpectedTaskMonitor(uri, expected) => {
+                write!(
+                    f,
+                    "unexpected task monitor poll: {uri}; expected: {expected:?}"
+                )
+            }
             Self::UnexpectedStream(uri, expected) => {
                 write!(f, "unexpected stream: {uri}; expected: {expected:?}")
             }
+            Self::UnexpectedQuery(id, query, expected) => {
+                write!(
+                    f,
+                    "unexpected query: {id}; query: {query} expected: {expected}"
+                )
+            }
+            Self::UnexpectedIfMatch(id, etag, expected) => {
+                write!(
+                    f,
+                    "unexpected If-Match: {id}; etag: {etag:?} expected: {expected:?}"
+                )
+            }
+            Self::UnexpectedIfNoneMatch(id, etag, expected) => {
+                write!(
+                    f,
+                    "unexpected If-None-Match: {id}; etag: {etag:?} expected: {expected:?}"
+                )
+            }
+            Self::Status(id, status) => write!(f, "status {status}: {id}"),
         }
     }
 }
 
 impl StdError for Error {}
 
+/// Response that cannot be parsed and its `@odata.type`.
+struct BadResponseJson(Option<String>, JsonError);
+
+impl From<BadResponseJson> for Error {
+    fn from(BadResponseJson(odata_type, err): BadResponseJson) -> Self {
+        match odata_type {
+            Some(odata_type) => Self::BadTypedResponseJson(odata_type, err),
+            None => Self::BadResponseJson(err),
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)] // Same error as returned by Bmc methods.
+fn check_if_match(
+    id: &ODataId,
+    etag: Option<&ODataETag>,
+    matcher: RequestMatcher,
+) -> Result<(), Error> {
+    let etag = etag.map(ToString::to_string);
+    if matcher.if_match_matches(etag.as_deref()) {
+        Ok(())
+    } else {
+        Err(Error::UnexpectedIfMatch(
+            id.clone(),
+            etag,
+            matcher.if_match.flatten(),
+        ))
+    }
+}
+
+/// Differences between expected and actual request body.
+fn body_diff(expected: &ExpectedRequest, json: &str) -> String {
+    let diff = expected
+        .body()
+        .zip(from_str(json).ok())
+        .map(|(expected, actual)| json_diff(expected, &actual))
+        .unwrap_or_default();
+    if diff.is_empty() {
+        String::new()
+    } else {
+        format!("; diff: {}", diff.join(", "))
+    }
+}
+
 impl Error {
     pub fn mutex_lock<T>(err: PoisonError<T>) -> Self {
         Self::MutexLock(err.to_string())
@@ -140,22 +263,56 @@ impl Error {
 
 pub struct Bmc<E> {
     expect: Mutex<VecDeque<Expect<E>>>,
+    null_policy: Mutex<NullPolicy>,
+    latency_class: Mutex<Option<LatencyClass>>,
 }
 
 impl<E> Default for Bmc<E> {
     fn default() -> Self {
         Self {
             expect: Mutex::default(),
+            null_policy: Mutex::default(),
+            latency_class: Mutex::default(),
         }
     }
 }
 
 impl<E> Bmc<E> {
+    /// Handle `null` values of non-nullable properties in responses
+    /// according to `policy`.
+    #[must_use]
+    pub fn with_null_policy(mut self, policy: NullPolicy) -> Self {
+        *self.null_policy.get_mut().expect("not poisoned") = policy;
+        self
+    }
+
     pub fn expect(&self, exp: Expect<E>) {
         let expect: &mut VecDeque<Expect<E>> = &mut self.expect.lock().expect("not poisoned");
         expect.push_back(exp);
     }
 
+    pub fn expect_sequence(&self, seq: impl IntoIterator<Item = Expect<E>>) {
+        let expect: &mut VecDeque<Expect<E>> = &mut self.expect.lock().expect("not poisoned");
+        expect.extend(seq);
+    }
+
+    /// Latency class reported by [`NvRedfishBmc::latency_class`].
+    pub fn set_latency_class(&self, class: Option<LatencyClass>) {
+        *self.latency_class.lock().expect("not poisoned") = class;
+    }
+
+    /// Panics if some of expected requests were not made.
+    #[track_caller]
+    pub fn assert_consumed(&self) {
+        let expect: &VecDeque<Expect<E>> = &self.expect.lock().expect("not poisoned");
+        assert!(
+            expect.is_empty(),
+            "expected requests were not made (total: {}): {:#?}",
+            expect.len(),
+            expect.iter().map(|v| &v.request).collect::<Vec<_>>()
+        );
+    }
+
     pub fn debug_expect(&self) {
         let expect: &VecDeque<Expect<E>> = &self.expect.lock().expect("not poisoned");
         println!("Expectations (total: {})", expect.len());
@@ -163,41 +320,74 @@ impl<E> Bmc<E> {
             println!("{:#?}", v.request);
         }
     }
+
+    fn parse_response<T: DeserializeOwned>(
+        &self,
+        response: JsonValue,
+    ) -> Result<T, BadResponseJson> {
+        let odata_type = response
+            .get("@odata.type")
+            .and_then(JsonValue::as_str)
+            .map(ToOwned::to_owned);
+        let policy = *self.null_policy.lock().expect("not poisoned");
+        from_value::<NullChecked<T>>(response)
+            .and_then(|v| v.apply(policy).map_err(JsonError::custom))
+            .map_err(|err| BadResponseJson(odata_type, err))
+    }
 }
 
-impl<E> NvRedfishBmc for Bmc<E>
+impl<E> Bmc<E>
 where
     E: StdError + Send + Sync + 'static,
 {
-    type Error = Error;
-
-    async fn expand<T>(&self, in_id: &ODataId, _query: ExpandQuery) -> Result<Arc<T>, Error>
-    where
-        T: Expandable,
-    {
+    async fn get_checked<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
+        &self,
+        in_id: &ODataId,
+        if_none_match: Option<&ODataETag>,
+    ) -> Result<Arc<T>, Error> {
         let expect = self
             .expect
             .lock()
             .map_err(Error::mutex_lock)?
             .pop_front()
             .ok_or(Error::NothingIsExpected)?;
+        let if_none_match = if_none_match.map(ToString::to_string);
         match expect {
+            Expect { matcher, .. } if !matcher.if_none_match_matches(if_none_match.as_deref()) => {
+                Err(Error::UnexpectedIfNoneMatch(
+                    in_id.clone(),
+                    if_none_match,
+                    matcher.if_none_match.flatten(),
+                ))
+            }
             Expect {
-                request: ExpectedRequest::Expand { id },
+                request: ExpectedRequest::Get { id },
                 response,
+                ..
             } if id == *in_id => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: T = self.parse_response(response)?;
                 Ok(Arc::new(result))
             }
-            _ => Err(Error::UnexpectedExpand(in_id.clone(), expect.request)),
+            Expect {
+                request: ExpectedRequest::GetStatus { id, status },
+                ..
+            } if id == *in_id => Err(Error::Status(id, status)),
+            _ => Err(Error::UnexpectedGet(in_id.clone(), expect.request)),
         }
     }
+}
 
-    async fn get<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
-        &self,
-        in_id: &ODataId,
-    ) -> Result<Arc<T>, Self::Error> {
+impl<E> NvRedfishBmc for Bmc<E>
+where
+    E: StdError + Send + Sync + 'static,
+{
+    type Error = Error;
+
+    async fn expand<T>(&self, in_id: &ODataId, query: ExpandQuery) -> Result<Arc<T>, Error>
+    where
+        T: Expandable,
+    {
         let expect = self
             .expect
             .lock()
@@ -206,24 +396,54 @@ where
             .ok_or(Error::NothingIsExpected)?;
         match expect {
             Expect {
-                request: ExpectedRequest::Get { id },
+                request: ExpectedRequest::Expand { id },
                 response,
+                matcher,
             } if id == *in_id => {
+                let query = query.to_query_string();
+                if !matcher.query_matches(&query) {
+                    return Err(Error::UnexpectedQuery(
+                        in_id.clone(),
+                        query,
+                        matcher.query.unwrap_or_default(),
+                    ));
+                }
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: T = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: T = self.parse_response(response)?;
                 Ok(Arc::new(result))
             }
-            _ => Err(Error::UnexpectedGet(in_id.clone(), expect.request)),
+            Expect {
+                request: ExpectedRequest::ExpandStatus { id, status },
+                ..
+            } if id == *in_id => Err(Error::Status(id, status)),
+            _ => Err(Error::UnexpectedExpand(in_id.clone(), expect.request)),
         }
     }
 
+    async fn get<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
+        &self,
+        in_id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.get_checked(in_id, None).await
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> serde::Deserialize<'de> + 'static>(
+        &self,
+        in_id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        let entity = self.get_checked::<T>(in_id, Some(etag)).await?;
+        // Service answers `304 Not Modified` when ETag matches.
+        Ok((entity.etag() != Some(etag)).then_some(entity))
+    }
+
     async fn update<
         V: Sync + Send + Serialize,
         R: Sync + Send + Sized + for<'de> serde::Deserialize<'de>,
     >(
         &self,
         in_id: &ODataId,
-        _etag: Option<&ODataETag>,
+        etag: Option<&ODataETag>,
         update: &V,
     ) -> Result<ModificationResponse<R>, Self::Error> {
         let expect = self
@@ -239,19 +459,29 @@ where
             Expect {
                 request: ExpectedRequest::Update { id, request },
                 response,
-            } if id == *in_id && request == in_request => {
+                matcher,
+            } if id == *in_id && matcher.body_matches(&request, &in_request) => {
+                check_if_match(in_id, etag, matcher)?;
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: R = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: R = self.parse_response(response)?;
                 Ok(ModificationResponse::Entity(result))
             }
             Expect {
                 request: ExpectedRequest::UpdateTask { id, request, task },
+                matcher,
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Task(task)),
+            } if id == *in_id && matcher.body_matches(&request, &in_request) => {
+                check_if_match(in_id, etag, matcher)?;
+                Ok(ModificationResponse::Task(task))
+            }
             Expect {
                 request: ExpectedRequest::UpdateEmpty { id, request },
+                matcher,
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Empty),
+            } if id == *in_id && matcher.body_matches(&request, &in_request) => {
+                check_if_match(in_id, etag, matcher)?;
+                Ok(ModificationResponse::Empty)
+            }
             _ => Err(Error::UnexpectedUpdate(
                 in_id.clone(),
                 in_request.to_string(),
@@ -281,19 +511,26 @@ where
             Expect {
                 request: ExpectedRequest::Create { id, request },
                 response,
-            } if id == *in_id && request == in_request => {
+                matcher,
+            } if id == *in_id && matcher.body_matches(&request, &in_request) => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: R = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: R = self.parse_response(response)?;
                 Ok(ModificationResponse::Entity(result))
             }
             Expect {
                 request: ExpectedRequest::CreateTask { id, request, task },
+                matcher,
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Task(task)),
+            } if id == *in_id && matcher.body_matches(&request, &in_request) => {
+                Ok(ModificationResponse::Task(task))
+            }
             Expect {
                 request: ExpectedRequest::CreateEmpty { id, request },
+                matcher,
                 ..
-            } if id == *in_id && request == in_request => Ok(ModificationResponse::Empty),
+            } if id == *in_id && matcher.body_matches(&request, &in_request) => {
+                Ok(ModificationResponse::Empty)
+            }
             _ => Err(Error::UnexpectedCreate(
                 in_id.clone(),
                 in_request.to_string(),
@@ -327,9 +564,10 @@ where
                         location,
                     },
                 response,
-            } if id == *in_id && request == in_request => {
+                matcher,
+            } if id == *in_id && matcher.body_matches(&request, &in_request) => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let entity: R = from_value(response).map_err(Error::BadResponseJson)?;
+                let entity: R = self.parse_response(response)?;
                 Ok(SessionCreateResponse {
                     entity,
                     auth_token,
@@ -344,7 +582,7 @@ where
         }
     }
 
-    async fn delete<R: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
+    async fn delete<R: Send + Sync + Sized + for<'de> serde::Deserialize<'de>>(
         &self,
         in_id: &ODataId,
     ) -> Result<ModificationResponse<R>, Self::Error> {
@@ -357,8 +595,16 @@ where
         match expect {
             Expect {
                 request: ExpectedRequest::Delete { id },
+                response,
                 ..
-            } if id == *in_id => Ok(ModificationResponse::Empty),
+            } if id == *in_id => {
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                if response.is_null() {
+                    return Ok(ModificationResponse::Empty);
+                }
+                let result: R = self.parse_response(response)?;
+                Ok(ModificationResponse::Entity(result))
+            }
             Expect {
                 request: ExpectedRequest::DeleteTask { id, task },
                 ..
@@ -367,6 +613,29 @@ where
         }
     }
 
+    async fn allowed_methods(&self, in_id: &ODataId) -> Result<Option<AllowedMethods>, Error> {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::AllowedMethods { id, methods },
+                response,
+                ..
+            } if id == *in_id => {
+                response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                Ok(methods)
+            }
+            _ => Err(Error::UnexpectedAllowedMethods(
+                in_id.clone(),
+                expect.request,
+            )),
+        }
+    }
+
     async fn action<
         T: Send + Sync + serde::Serialize,
         R: Send + Sync + Sized + for<'de> serde::Deserialize<'de>,
@@ -386,11 +655,24 @@ where
             Expect {
                 request: ExpectedRequest::Action { target, request },
                 response,
-            } if target == action.target && request == in_request => {
+                matcher,
+            } if target == action.target && matcher.body_matches(&request, &in_request) => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: R = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: R = self.parse_response(response)?;
                 Ok(ModificationResponse::Entity(result))
             }
+            Expect {
+                request:
+                    ExpectedRequest::ActionTask {
+                        target,
+                        request,
+                        task,
+                    },
+                matcher,
+                ..
+            } if target == action.target && matcher.body_matches(&request, &in_request) => {
+                Ok(ModificationResponse::Task(task))
+            }
             _ => Err(Error::UnexpectedAction(
                 action.target.clone(),
                 in_request.to_string(),
@@ -439,13 +721,14 @@ where
                         oem_parts: expected_parts,
                     },
                 response,
+                matcher,
             } if uri == *in_uri
-                && request == in_request
+                && matcher.body_matches(&request, &in_request)
                 && expected_file_name == file_name
                 && expected_parts == oem_parts =>
             {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: R = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: R = self.parse_response(response)?;
                 Ok(ModificationResponse::Entity(result))
             }
             _ => Err(Error::UnexpectedMultipartUpdate(
@@ -478,9 +761,10 @@ where
             Expect {
                 request: ExpectedRequest::HttpPushUriUpdate { uri },
                 response,
+                ..
             } if uri == *in_uri => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: R = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: R = self.parse_response(response)?;
                 Ok(ModificationResponse::Entity(result))
             }
             _ => Err(Error::UnexpectedHttpPushUriUpdate(
@@ -490,12 +774,139 @@ where
         }
     }
 
+    async fn binary_download(&self, in_uri: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+
+        match expect {
+            Expect {
+                request: ExpectedRequest::BinaryDownload { uri, data },
+                response,
+                ..
+            } if uri == *in_uri => {
+                response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                Ok(Some(data))
+            }
+            _ => Err(Error::UnexpectedBinaryDownload(
+                in_uri.to_string(),
+                expect.request,
+            )),
+        }
+    }
+
+    async fn task_monitor(&self, in_uri: &str) -> Result<Option<TaskMonitorResponse>, Self::Error> {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+
+        match expect {
+            Expect {
+                request: ExpectedRequest::TaskMonitor { uri, response },
+                ..
+            } if uri == *in_uri => Ok(Some(response)),
+            Expect {
+                request: ExpectedRequest::TaskMonitorStatus { uri, status },
+                ..
+            } if uri == *in_uri => Err(Error::Status(uri.into(), status)),
+            _ => Err(Error::UnexpectedTaskMonitor(
+                in_uri.to_string(),
+                expect.request,
+            )),
+        }
+    }
+
+    async fn binary_upload<U, R>(
+        &self,
+        in_uri: &str,
+        _upload_request: BinaryUploadRequest<U>,
+    ) -> Result<Option<ModificationResponse<R>>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> serde::Deserialize<'de>,
+    {
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+
+        match expect {
+            Expect {
+                request: ExpectedRequest::BinaryUpload { uri },
+                response,
+                ..
+            } if uri == *in_uri => {
+                response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                Ok(Some(ModificationResponse::Empty))
+            }
+            _ => Err(Error::UnexpectedBinaryUpload(
+                in_uri.to_string(),
+                expect.request,
+            )),
+        }
+    }
+
     async fn filter<T: EntityTypeRef + for<'de> serde::Deserialize<'de>>(
         &self,
-        _id: &ODataId,
-        _query: nv_redfish_core::FilterQuery,
+        in_id: &ODataId,
+        query: FilterQuery,
     ) -> Result<Arc<T>, Self::Error> {
-        todo!("unimplemented")
+        let expect = self
+            .expect
+            .lock()
+            .map_err(Error::mutex_lock)?
+            .pop_front()
+            .ok_or(Error::NothingIsExpected)?;
+        match expect {
+            Expect {
+                request: ExpectedRequest::Filter { id },
+                response,
+                matcher,
+            } if id == *in_id => {
+                let query = query.to_query_string();
+                if !matcher.query_matches(&query) {
+                    return Err(Error::UnexpectedQuery(
+                        in_id.clone(),
+                        query,
+                        matcher.query.unwrap_or_default(),
+                    ));
+                }
+                let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
+                let result: T = self.parse_response(response)?;
+                Ok(Arc::new(result))
+            }
+            _ => Err(Error::UnexpectedFilter(in_id.clone(), expect.request)),
+        }
+    }
+
+    fn latency_class(&self) -> Option<LatencyClass> {
+        *self.latency_class.lock().expect("not poisoned")
+    }
+
+    fn set_null_policy(&self, policy: NullPolicy) {
+        *self.null_policy.lock().expect("not poisoned") = policy;
+    }
+
+    fn error_status_code(error: &Error) -> Option<u16> {
+        match error {
+            Error::Status(_, status) => Some(*status),
+            _ => None,
+        }
+    }
+
+    fn error_odata_type(error: &Error) -> Option<&str> {
+        match error {
+            Error::BadTypedResponseJson(odata_type, _) => Some(odata_type),
+            _ => None,
+        }
     }
 
     async fn stream<T: Sized + for<'de> serde::Deserialize<'de> + Send + 'static>(
@@ -512,9 +923,10 @@ where
             Expect {
                 request: ExpectedRequest::Stream { uri },
                 response,
+                ..
             } if uri == *in_uri => {
                 let response = response.map_err(|err| Error::ErrorResponse(Box::new(err)))?;
-                let result: Vec<T> = from_value(response).map_err(Error::BadResponseJson)?;
+                let result: Vec<T> = self.parse_response(response)?;
                 Ok(Box::pin(futures_util::stream::iter(
                     result.into_iter().map(Ok),
                 )))
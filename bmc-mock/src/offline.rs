@@ -0,0 +1,412 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline BMC backed by captured Redfish data.
+//!
+//! [`OfflineBmc`] answers reads from a saved copy of a Redfish service
+//! and rejects all modifications. It allows analysis and report tools to
+//! run against captured data without network access. Two sources of data
+//! are supported:
+//!
+//! - Mockup directory in the layout of DMTF Redfish Mockup Creator: each
+//!   resource is stored in `index.json` of the directory that repeats the
+//!   resource path, so `/redfish/v1/Systems/1` is read from
+//!   `<root>/redfish/v1/Systems/1/index.json`.
+//! - [`InventorySnapshot`]: JSON object that maps `@odata.id` of each
+//!   resource to its payload.
+//!
+//! Expand requests inline one level of subordinate resources that are
+//! present in the captured data. Navigation properties in `Links` are
+//! never expanded.
+
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::fs;
+use std::io::Error as IoError;
+use std::io::ErrorKind as IoErrorKind;
+use std::iter::FromIterator;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::query::FilterQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::ActionError;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::NullChecked;
+use nv_redfish_core::NullPolicy;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::UploadReader;
+use serde::de::Error as _;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::from_slice;
+use serde_json::from_value;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+
+const ODATA_ID: &str = "@odata.id";
+const MOCKUP_INDEX: &str = "index.json";
+
+/// Errors of the offline BMC.
+#[derive(Debug)]
+pub enum OfflineError {
+    /// Resource is not present in the captured data.
+    NotFound(String),
+    /// Failed to read captured data.
+    Io(PathBuf, IoError),
+    /// Captured data is not valid JSON.
+    BadJson(PathBuf, JsonError),
+    /// Captured resource doesn't match requested type.
+    BadResource(String, JsonError),
+    /// Modification of captured data was requested.
+    ReadOnly,
+    /// Operation cannot be served from captured data.
+    NotSupported,
+}
+
+impl Display for OfflineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NotFound(id) => write!(f, "resource is not captured: {id}"),
+            Self::Io(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+            Self::BadJson(path, err) => write!(f, "bad json in {}: {err}", path.display()),
+            Self::BadResource(id, err) => write!(f, "bad resource {id}: {err}"),
+            Self::ReadOnly => write!(f, "offline BMC is read-only"),
+            Self::NotSupported => write!(f, "not supported by offline BMC"),
+        }
+    }
+}
+
+impl StdError for OfflineError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Io(_, err) => Some(err),
+            Self::BadJson(_, err) | Self::BadResource(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl ActionError for OfflineError {
+    fn not_supported() -> Self {
+        Self::NotSupported
+    }
+}
+
+/// Captured resources of a Redfish service indexed by `@odata.id`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InventorySnapshot {
+    resources: HashMap<String, JsonValue>,
+}
+
+impl InventorySnapshot {
+    /// Create empty snapshot.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load snapshot from JSON file.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if file cannot be read or it is not a JSON object
+    /// that maps `@odata.id` to resource payload.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, OfflineError> {
+        let path = path.as_ref();
+        let data = fs::read(path).map_err(|err| OfflineError::Io(path.into(), err))?;
+        from_slice(&data).map_err(|err| OfflineError::BadJson(path.into(), err))
+    }
+
+    /// Add captured resource payload. Returns previous payload of the
+    /// resource if any.
+    pub fn insert(&mut self, id: impl Into<String>, payload: JsonValue) -> Option<JsonValue> {
+        self.resources.insert(id.into(), payload)
+    }
+
+    /// Payload of the captured resource.
+    #[must_use]
+    pub fn get(&self, id: &str) -> Option<&JsonValue> {
+        self.resources.get(id)
+    }
+
+    /// Number of captured resources.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    /// Returns true if snapshot has no resources.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, JsonValue)> for InventorySnapshot {
+    fn from_iter<I: IntoIterator<Item = (S, JsonValue)>>(iter: I) -> Self {
+        Self {
+            resources: iter.into_iter().map(|(id, v)| (id.into(), v)).collect(),
+        }
+    }
+}
+
+enum Source {
+    Mockup(PathBuf),
+    Snapshot(InventorySnapshot),
+}
+
+/// BMC that serves reads from captured data and rejects writes.
+pub struct OfflineBmc {
+    source: Source,
+    null_policy: Mutex<NullPolicy>,
+}
+
+impl OfflineBmc {
+    /// Create offline BMC on top of mockup directory.
+    pub fn from_mockup_dir(root: impl Into<PathBuf>) -> Self {
+        Self {
+            source: Source::Mockup(root.into()),
+            null_policy: Mutex::new(NullPolicy::Permissive),
+        }
+    }
+
+    /// Create offline BMC on top of inventory snapshot.
+    #[must_use]
+    pub const fn from_snapshot(snapshot: InventorySnapshot) -> Self {
+        Self {
+            source: Source::Snapshot(snapshot),
+            null_policy: Mutex::new(NullPolicy::Permissive),
+        }
+    }
+
+    /// Read captured resource. `uri` may contain fragment that refers
+    /// to a part of the resource (`/redfish/v1/Chassis/1/Thermal#/Fans/0`).
+    fn read(&self, uri: &str) -> Result<JsonValue, OfflineError> {
+        let (path, fragment) = uri.split_once('#').unwrap_or((uri, ""));
+        let path = path.split_once('?').map_or(path, |(path, _)| path);
+        let path = path.trim_end_matches('/');
+        let payload = match &self.source {
+            Source::Snapshot(snapshot) => snapshot
+                .get(path)
+                .cloned()
+                .ok_or_else(|| OfflineError::NotFound(uri.into()))?,
+            Source::Mockup(root) => {
+                let file =
+                    mockup_file(root, path).ok_or_else(|| OfflineError::NotFound(uri.into()))?;
+                let data = fs::read(&file).map_err(|err| match err.kind() {
+                    IoErrorKind::NotFound => OfflineError::NotFound(uri.into()),
+                    _ => OfflineError::Io(file.clone(), err),
+                })?;
+                from_slice(&data).map_err(|err| OfflineError::BadJson(file, err))?
+            }
+        };
+        if fragment.is_empty() {
+            Ok(payload)
+        } else {
+            payload
+                .pointer(fragment)
+                .cloned()
+                .ok_or_else(|| OfflineError::NotFound(uri.into()))
+        }
+    }
+
+    fn read_as<T: for<'de> Deserialize<'de>>(&self, uri: &str) -> Result<T, OfflineError> {
+        self.read(uri).and_then(|v| self.parse(uri, v))
+    }
+
+    /// Deserialize captured resource and apply the null policy to it.
+    fn parse<T: for<'de> Deserialize<'de>>(
+        &self,
+        uri: &str,
+        payload: JsonValue,
+    ) -> Result<T, OfflineError> {
+        let policy = *self.null_policy.lock().expect("not poisoned");
+        from_value::<NullChecked<T>>(payload)
+            .and_then(|v| v.apply(policy).map_err(JsonError::custom))
+            .map_err(|err| OfflineError::BadResource(uri.into(), err))
+    }
+
+    /// Replace references to subordinate resources by their payloads.
+    fn expand_value(&self, value: &mut JsonValue) {
+        match value {
+            JsonValue::Object(obj) => {
+                if obj.len() == 1 {
+                    if let Some(uri) = obj.get(ODATA_ID).and_then(JsonValue::as_str) {
+                        if let Ok(payload) = self.read(uri) {
+                            *value = payload;
+                        }
+                        return;
+                    }
+                }
+                for (name, v) in obj.iter_mut() {
+                    if name != "Links" {
+                        self.expand_value(v);
+                    }
+                }
+            }
+            JsonValue::Array(items) => items.iter_mut().for_each(|v| self.expand_value(v)),
+            _ => (),
+        }
+    }
+}
+
+/// Path of the mockup file for the resource path. Returns `None` if the
+/// path tries to leave mockup directory.
+fn mockup_file(root: &Path, path: &str) -> Option<PathBuf> {
+    let relative = Path::new(path.trim_start_matches('/'));
+    relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)))
+        .then(|| root.join(relative).join(MOCKUP_INDEX))
+}
+
+impl Bmc for OfflineBmc {
+    type Error = OfflineError;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        _query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        let mut payload = self.read(&id.to_string())?;
+        if let JsonValue::Object(obj) = &mut payload {
+            for (name, v) in obj.iter_mut() {
+                if name != "Links" && name != ODATA_ID {
+                    self.expand_value(v);
+                }
+            }
+        }
+        self.parse(&id.to_string(), payload).map(Arc::new)
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.read_as(&id.to_string()).map(Arc::new)
+    }
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        _id: &ODataId,
+        _query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        Err(OfflineError::NotSupported)
+    }
+
+    async fn create<V: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+        _query: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(OfflineError::ReadOnly)
+    }
+
+    async fn create_session<
+        V: Send + Sync + Serialize,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        _id: &ODataId,
+        _query: &V,
+    ) -> Result<SessionCreateResponse<R>, Self::Error> {
+        Err(OfflineError::ReadOnly)
+    }
+
+    async fn update<
+        V: Sync + Send + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        _id: &ODataId,
+        _etag: Option<&ODataETag>,
+        _update: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(OfflineError::ReadOnly)
+    }
+
+    async fn delete<R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(OfflineError::ReadOnly)
+    }
+
+    async fn action<
+        T: Send + Sync + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        _action: &Action<T, R>,
+        _params: &T,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(OfflineError::ReadOnly)
+    }
+
+    async fn multipart_update<U, V, R>(
+        &self,
+        _uri: &str,
+        _request: MultipartUpdateRequest<'_, U, V>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+        V: Send + Sync + Serialize,
+    {
+        Err(OfflineError::ReadOnly)
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn http_push_uri_update<U, R>(
+        &self,
+        _uri: &str,
+        _request: HttpPushUriUpdateRequest<U>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        Err(OfflineError::ReadOnly)
+    }
+
+    fn set_null_policy(&self, policy: NullPolicy) {
+        *self.null_policy.lock().expect("not poisoned") = policy;
+    }
+
+    async fn stream<T: Sized + for<'de> Deserialize<'de> + Send + 'static>(
+        &self,
+        _uri: &str,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        Err(OfflineError::NotSupported)
+    }
+}
@@ -22,9 +22,10 @@
 //! - `T`: request parameters payload type (sent as the POST body when running the action)
 //! - `R`: response type returned by the BMC for that action
 //!
-//! Only the `target` field is deserialized. Any additional metadata
-//! (such as `...@Redfish.AllowableValues`) is ignored by this type
-//! and may be used by higher layers.
+//! Besides the `target` field, `...@Redfish.AllowableValues`
+//! annotations of action parameters are collected into
+//! [`AllowableValues`] so callers can validate parameters before
+//! running the action. Any other metadata is ignored by this type.
 //!
 //! Example: how an action appears in a Redfish resource and which part maps to [`Action`]
 //!
@@ -44,18 +45,31 @@
 //! ```
 //!
 //! The [`Action<T, R>`] value corresponds to the inner object of
-//! `"#ComputerSystem.Reset"` and deserializes the `target` field only.
+//! `"#ComputerSystem.Reset"`: it deserializes the `target` field and
+//! the allowable values of the `ResetType` parameter.
 //!
 
 use crate::Bmc;
+use crate::EntityTypeRef;
 use crate::ModificationResponse;
 use core::fmt::Debug;
 use core::fmt::Display;
 use core::fmt::Formatter;
 use core::fmt::Result as FmtResult;
+use serde::de::IgnoredAny;
+use serde::de::MapAccess;
+use serde::de::Visitor;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Suffix of the annotation that lists allowable values of an action
+/// parameter.
+const ALLOWABLE_VALUES_SUFFIX: &str = "@Redfish.AllowableValues";
 
 /// URI reference for the `target` field of an action.
 ///
@@ -95,7 +109,9 @@ pub struct Action<T, R> {
     /// URI reference used to trigger the action.
     #[serde(rename = "target")]
     pub target: ActionTarget,
-    // TODO: we can retrieve constraints on attributes here.
+    /// Values of parameters allowed by the service.
+    #[serde(flatten)]
+    pub allowable_values: AllowableValues,
     /// Establishes a dependency on the `T` (parameters) type.
     #[serde(skip_deserializing)]
     _marker: PhantomData<T>,
@@ -108,10 +124,107 @@ impl<T, R> Debug for Action<T, R> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("Action")
             .field("target", &self.target)
+            .field("allowable_values", &self.allowable_values)
             .finish()
     }
 }
 
+impl<T, R> Action<T, R> {
+    /// Values of `parameter` allowed by the service. `None` means
+    /// that the service doesn't restrict the parameter.
+    #[must_use]
+    pub fn allowed(&self, parameter: &str) -> Option<&[String]> {
+        self.allowable_values.get(parameter)
+    }
+}
+
+/// Allowable values of action parameters collected from
+/// `<Parameter>@Redfish.AllowableValues` annotations.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AllowableValues(HashMap<String, Vec<String>>);
+
+impl AllowableValues {
+    /// Values of `parameter` allowed by the service.
+    #[must_use]
+    pub fn get(&self, parameter: &str) -> Option<&[String]> {
+        self.0.get(parameter).map(Vec::as_slice)
+    }
+
+    /// Names of parameters restricted by the service.
+    pub fn parameters(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Check if `value` of `parameter` is allowed by the service.
+    ///
+    /// The value is compared in its serialized form, so generated
+    /// enums can be checked directly. Parameters that are not
+    /// restricted by the service accept any value.
+    pub fn is_allowed<V: Serialize + ?Sized>(&self, parameter: &str, value: &V) -> bool {
+        self.get(parameter).is_none_or(|allowed| {
+            serde_json::to_value(value)
+                .is_ok_and(|value| allowed.contains(&value_to_string(&value)))
+        })
+    }
+}
+
+/// String form of allowable value. Strings are used as-is, other
+/// values (for example, integers) use their JSON representation.
+fn value_to_string(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(v) => v.clone(),
+        v => v.to_string(),
+    }
+}
+
+impl<'de> Deserialize<'de> for AllowableValues {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AllowableValuesVisitor;
+
+        impl<'de> Visitor<'de> for AllowableValuesVisitor {
+            type Value = AllowableValues;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("action object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut values = HashMap::new();
+                while let Some(key) = map.next_key::<String>()? {
+                    if let Some(parameter) = key.strip_suffix(ALLOWABLE_VALUES_SUFFIX) {
+                        // Malformed annotation must not break the
+                        // whole resource, so it is tolerated.
+                        if let JsonValue::Array(allowed) = map.next_value()? {
+                            let allowed = allowed.iter().map(value_to_string).collect();
+                            values.insert(parameter.to_owned(), allowed);
+                        }
+                    } else {
+                        map.next_value::<IgnoredAny>()?;
+                    }
+                }
+                Ok(AllowableValues(values))
+            }
+        }
+
+        deserializer.deserialize_map(AllowableValuesVisitor)
+    }
+}
+
+/// Access to allowable values of all actions of a Redfish `Actions`
+/// object. Implemented by generated code.
+pub trait ActionsAllowableValues {
+    /// Allowable values of parameters of `action` where `action` is
+    /// the action name as it appears in payload (for example,
+    /// `#ComputerSystem.Reset`). `None` if the action is not
+    /// provided by the service.
+    fn allowable_values(&self, action: &str) -> Option<&AllowableValues>;
+
+    /// Values of `parameter` of `action` allowed by the service.
+    fn allowed(&self, action: &str, parameter: &str) -> Option<&[String]> {
+        self.allowable_values(action)?.get(parameter)
+    }
+}
+
 /// Action error trait. Needed in generated code when an action function
 /// is called for an action that wasn't specified by the server.
 pub trait ActionError {
@@ -136,12 +249,74 @@ impl<T: Send + Sync + Serialize, R: Send + Sync + Sized + for<'de> Deserialize<'
     ) -> Result<ModificationResponse<R>, B::Error> {
         bmc.action::<T, R>(self, params).await
     }
+
+    /// Run action with parameters of type `P` instead of `T`.
+    ///
+    /// Used for services that expect parameters that don't follow
+    /// the schema of the action.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::run`].
+    pub async fn run_with<B, P>(
+        &self,
+        bmc: &B,
+        params: &P,
+    ) -> Result<ModificationResponse<R>, B::Error>
+    where
+        B: Bmc,
+        P: Send + Sync + Serialize,
+    {
+        let action = Action::<P, R> {
+            target: self.target.clone(),
+            allowable_values: self.allowable_values.clone(),
+            _marker: PhantomData,
+            _marker_retval: PhantomData,
+        };
+        bmc.action::<P, R>(&action, params).await
+    }
+
+    /// Run action that returns the modified resource in the
+    /// response and parse it as `R2`.
+    ///
+    /// The returned representation is passed to [`Bmc::cache_entity`],
+    /// so a following read of the resource is served from cache
+    /// instead of downloading the resource again.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::run`]. Also returns an error if the response
+    /// cannot be parsed as `R2`.
+    pub async fn run_and_parse<B, R2>(
+        &self,
+        bmc: &B,
+        params: &T,
+    ) -> Result<ModificationResponse<Arc<R2>>, B::Error>
+    where
+        B: Bmc,
+        R2: EntityTypeRef + for<'de> Deserialize<'de> + 'static,
+    {
+        let action = Action::<T, R2> {
+            target: self.target.clone(),
+            allowable_values: self.allowable_values.clone(),
+            _marker: PhantomData,
+            _marker_retval: PhantomData,
+        };
+        let response = bmc.action::<T, R2>(&action, params).await?;
+        Ok(response.map_entity(|entity| {
+            let entity = Arc::new(entity);
+            bmc.cache_entity(&entity);
+            entity
+        }))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Action;
     use super::ActionTarget;
+    use super::AllowableValues;
+    use serde_json::json;
     use std::marker::PhantomData;
 
     struct NotDebug;
@@ -150,13 +325,40 @@ mod tests {
     fn debug_does_not_require_parameter_or_result_debug() {
         let action: Action<NotDebug, NotDebug> = Action {
             target: ActionTarget::new("/redfish/v1/Actions/Test".into()),
+            allowable_values: AllowableValues::default(),
             _marker: PhantomData,
             _marker_retval: PhantomData,
         };
 
         assert_eq!(
             format!("{action:?}"),
-            "Action { target: ActionTarget(\"/redfish/v1/Actions/Test\") }"
+            "Action { target: ActionTarget(\"/redfish/v1/Actions/Test\"), \
+             allowable_values: AllowableValues({}) }"
+        );
+    }
+
+    #[test]
+    fn allowable_values_are_collected() {
+        let action: Action<(), ()> = serde_json::from_value(json!({
+            "target": "/redfish/v1/Systems/1/Actions/ComputerSystem.Reset",
+            "ResetType@Redfish.AllowableValues": ["On", "ForceRestart"],
+            "Index@Redfish.AllowableValues": [1, 2],
+            "Broken@Redfish.AllowableValues": "On",
+            "@Redfish.ActionInfo": "/redfish/v1/Systems/1/ResetActionInfo"
+        }))
+        .expect("valid action");
+
+        assert_eq!(
+            action.allowed("ResetType"),
+            Some(&["On".to_string(), "ForceRestart".to_string()][..])
         );
+        assert!(action.allowable_values.is_allowed("ResetType", "On"));
+        assert!(!action
+            .allowable_values
+            .is_allowed("ResetType", "GracefulShutdown"));
+        assert!(action.allowable_values.is_allowed("Unrestricted", "Any"));
+        assert!(action.allowable_values.is_allowed("Index", &2));
+        assert!(!action.allowable_values.is_allowed("Index", &3));
+        assert_eq!(action.allowed("Broken"), None);
     }
 }
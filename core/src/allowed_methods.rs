@@ -0,0 +1,157 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HTTP methods that the Redfish service allows on a resource.
+//!
+//! Services report them in the `Allow` header of responses (DSP0266
+//! requires it for GET and HEAD). Unlike [`crate::Capabilities`],
+//! which are compiled from the CSDL specification and are the same
+//! for all resources of a type, allowed methods are declared by the
+//! endpoint for the specific resource.
+
+use std::fmt;
+
+/// HTTP method of a Redfish operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HttpMethod {
+    /// Read the resource.
+    Get,
+    /// Create a member of a collection or invoke an action.
+    Post,
+    /// Update the resource.
+    Patch,
+    /// Replace the resource.
+    Put,
+    /// Delete the resource.
+    Delete,
+}
+
+impl HttpMethod {
+    /// All methods known to Redfish clients.
+    pub const ALL: [Self; 5] = [Self::Get, Self::Post, Self::Patch, Self::Put, Self::Delete];
+
+    /// Method name as it appears in HTTP.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Get => "GET",
+            Self::Post => "POST",
+            Self::Patch => "PATCH",
+            Self::Put => "PUT",
+            Self::Delete => "DELETE",
+        }
+    }
+
+    const fn bit(self) -> u8 {
+        match self {
+            Self::Get => 1,
+            Self::Post => 1 << 1,
+            Self::Patch => 1 << 2,
+            Self::Put => 1 << 3,
+            Self::Delete => 1 << 4,
+        }
+    }
+}
+
+impl fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Set of HTTP methods allowed on a resource.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllowedMethods(u8);
+
+impl AllowedMethods {
+    /// Set of all methods.
+    #[must_use]
+    pub const fn all() -> Self {
+        Self(0x1f)
+    }
+
+    /// Parse value of the `Allow` header (for example, `GET, PATCH`).
+    /// Methods that are not used by Redfish clients are ignored.
+    #[must_use]
+    pub fn from_header(value: &str) -> Self {
+        value
+            .split(',')
+            .map(str::trim)
+            .filter_map(|name| {
+                HttpMethod::ALL
+                    .iter()
+                    .copied()
+                    .find(|method| method.as_str().eq_ignore_ascii_case(name))
+            })
+            .fold(Self::default(), Self::with)
+    }
+
+    /// Check if `method` is allowed.
+    #[must_use]
+    pub const fn contains(self, method: HttpMethod) -> bool {
+        self.0 & method.bit() != 0
+    }
+
+    /// Set with `method` added.
+    #[must_use]
+    pub const fn with(self, method: HttpMethod) -> Self {
+        Self(self.0 | method.bit())
+    }
+
+    /// Set with `method` removed.
+    #[must_use]
+    pub const fn without(self, method: HttpMethod) -> Self {
+        Self(self.0 & !method.bit())
+    }
+
+    /// Iterate over allowed methods.
+    pub fn iter(self) -> impl Iterator<Item = HttpMethod> {
+        HttpMethod::ALL
+            .iter()
+            .copied()
+            .filter(move |method| self.contains(*method))
+    }
+}
+
+impl fmt::Display for AllowedMethods {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (n, method) in self.iter().enumerate() {
+            if n > 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(method.as_str())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allow_header_is_parsed() {
+        let allowed = AllowedMethods::from_header("GET,HEAD, patch ,OPTIONS");
+        assert!(allowed.contains(HttpMethod::Get));
+        assert!(allowed.contains(HttpMethod::Patch));
+        assert!(!allowed.contains(HttpMethod::Post));
+        assert!(!allowed.contains(HttpMethod::Delete));
+        assert_eq!(allowed.to_string(), "GET, PATCH");
+        assert_eq!(
+            allowed.without(HttpMethod::Patch),
+            AllowedMethods::from_header("GET")
+        );
+    }
+}
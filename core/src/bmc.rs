@@ -33,13 +33,32 @@
 //!
 //! Operation semantics:
 //! - `get` fetches the entity at the given `@odata.id`.
+//! - `get_if_none_match` fetches the entity only if its `ETag` differs
+//!   from the given one (`If-None-Match`).
 //! - `expand` fetches the entity with the provided `$expand` query.
 //! - `create` typically performs a POST to a collection identified by `id` and
 //!   returns the server-provided representation (`R`).
 //! - `update` typically performs a PATCH on an entity identified by `id` and
-//!   returns the updated representation (`R`).
+//!   returns the updated representation (`R`). `If-Match` handling is
+//!   controlled by [`crate::ETagPolicy`] set via `set_etag_policy`.
+//! - Handling of `null` values of non-nullable properties in responses is
+//!   controlled by [`crate::NullPolicy`] set via `set_null_policy` and
+//!   applied to every response with [`crate::NullChecked`].
+//! - `invalidate_cache` drops client-side cached state, for example
+//!   after the Redfish service restarted.
+//! - `cache_entity` stores a representation returned by a modification
+//!   request, so a following `get` can be served from cache.
+//! - `allowed_methods` asks the service which methods it allows on the
+//!   entity at `id` (`Allow` header).
+//! - `latency_class` reports how fast the service has been responding,
+//!   so callers can send fewer and lighter requests to slow services.
 //! - `delete` removes the entity at `id`.
 //! - `action` posts to an action endpoint (`Action.target`).
+//! - `binary_download`/`binary_upload` transfer raw binary data (for example,
+//!   assembly FRU images) at a service-provided URI.
+//! - `get_json` fetches a JSON document that is not a Redfish resource (for
+//!   example, a message registry file) at a service-provided URI.
+//! - `task_monitor` polls the task monitor of an asynchronous operation.
 //!
 //! Notes for implementors:
 //! - The trait is `Send + Sync` and returns `Send` futures to support use in
@@ -53,24 +72,62 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::query::ExpandQuery;
+use crate::query::FilterQuery;
 use crate::Action;
+use crate::AllowedMethods;
+use crate::BinaryUploadRequest;
 use crate::BoxTryStream;
+use crate::ETagPolicy;
 use crate::EntityTypeRef;
 use crate::Expandable;
-use crate::FilterQuery;
 #[cfg(feature = "update-service-deprecated")]
 use crate::HttpPushUriUpdateRequest;
 use crate::ModificationResponse;
+use crate::NullPolicy;
 use crate::ODataETag;
 use crate::ODataId;
+use crate::RedfishError;
 use crate::SessionCreateResponse;
+use crate::TaskMonitorResponse;
 use std::error::Error as StdError;
+use std::future::ready;
 use std::future::Future;
 use std::sync::Arc;
 
 use crate::MultipartUpdateRequest;
 use crate::UploadReader;
 
+/// Responsiveness of the Redfish service as observed by the client.
+///
+/// Classes are ordered from the fastest to the slowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LatencyClass {
+    /// Service responds faster than the fast threshold.
+    Fast,
+    /// Service responds between the fast and the slow thresholds.
+    Normal,
+    /// Service responds slower than the slow threshold.
+    Slow,
+}
+
+/// JSON document fetched by the default implementation of
+/// [`Bmc::get_json`]. Such documents have no `@odata.id`.
+#[derive(Clone, Deserialize)]
+#[serde(transparent)]
+struct JsonDocument<T>(T);
+
+static NO_ODATA_ID: ODataId = ODataId::empty();
+
+impl<T: Send + Sync> EntityTypeRef for JsonDocument<T> {
+    fn odata_id(&self) -> &ODataId {
+        &NO_ODATA_ID
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
 /// BMC trait defines access to a Baseboard Management Controller using
 /// the Redfish protocol.
 pub trait Bmc: Send + Sync {
@@ -94,6 +151,24 @@ pub trait Bmc: Send + Sync {
         id: &ODataId,
     ) -> impl Future<Output = Result<Arc<T>, Self::Error>> + Send;
 
+    /// Get data of the object unless its `ETag` matches `etag`.
+    /// Returns `None` if the object is not modified.
+    ///
+    /// The default implementation gets the object and compares its
+    /// `ETag`. Implementations that send `If-None-Match` with `etag`
+    /// save transfer of unmodified objects.
+    fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> impl Future<Output = Result<Option<Arc<T>>, Self::Error>> + Send {
+        let get = self.get::<T>(id);
+        async move {
+            let entity = get.await?;
+            Ok((entity.etag() != Some(etag)).then_some(entity))
+        }
+    }
+
     /// Get and filters data of the object (navigation property or entity).
     ///
     /// `T` is structure that is used for return type.
@@ -126,6 +201,10 @@ pub trait Bmc: Send + Sync {
 
     /// Update entity.
     ///
+    /// `etag` is the last known `ETag` of the entity. Implementations
+    /// that send `If-Match` should derive it according to their
+    /// [`ETagPolicy`].
+    ///
     /// `V` is structure that is used for update.
     /// `R` is structure that is used for return type (updated entity).
     fn update<V: Sync + Send + Serialize, R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
@@ -136,7 +215,11 @@ pub trait Bmc: Send + Sync {
     ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send;
 
     /// Delete entity.
-    fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
+    ///
+    /// `R` is structure that is used for the response payload if the
+    /// service returns one (for example, the deleted entity or a
+    /// message object).
+    fn delete<R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
         &self,
         id: &ODataId,
     ) -> impl Future<Output = Result<ModificationResponse<R>, Self::Error>> + Send;
@@ -192,6 +275,178 @@ pub trait Bmc: Send + Sync {
         U: UploadReader,
         R: Send + Sync + for<'de> Deserialize<'de>;
 
+    /// GET raw binary data from a service-provided URI.
+    ///
+    /// `uri` is a service-provided binary data URI, such as
+    /// `AssemblyData.BinaryDataURI`, and should be resolved as a Redfish URI
+    /// reference.
+    ///
+    /// Implementations may reject URI references that violate their outbound
+    /// request policy before transport.
+    ///
+    /// Implementations that cannot transfer binary data return
+    /// `Ok(None)`.
+    fn binary_download(
+        &self,
+        _uri: &str,
+    ) -> impl Future<Output = Result<Option<Vec<u8>>, Self::Error>> + Send {
+        ready(Ok(None))
+    }
+
+    /// GET a JSON document that is not a Redfish resource from a
+    /// service-provided URI, such as a message registry file
+    /// (`MessageRegistryFile.Location.Uri`).
+    ///
+    /// `uri` should be resolved as a Redfish URI reference. Unlike
+    /// [`Self::get`], the document doesn't need `@odata.id`.
+    ///
+    /// Implementations may reject URI references that violate their outbound
+    /// request policy before transport.
+    ///
+    /// The default implementation fetches the document with [`Self::get`].
+    fn get_json<T>(&self, uri: &str) -> impl Future<Output = Result<T, Self::Error>> + Send
+    where
+        T: for<'de> Deserialize<'de> + Clone + Send + Sync + 'static,
+    {
+        let id = ODataId::from(uri.to_owned());
+        async move {
+            self.get::<JsonDocument<T>>(&id)
+                .await
+                .map(|document| Arc::unwrap_or_clone(document).0)
+        }
+    }
+
+    /// PUT a raw binary stream to a service-provided URI.
+    ///
+    /// `uri` is a service-provided binary data URI, such as
+    /// `AssemblyData.BinaryDataURI`, and should be resolved as a Redfish URI
+    /// reference.
+    ///
+    /// Implementations may reject URI references that violate their outbound
+    /// request policy before transport.
+    ///
+    /// Implementations that cannot transfer binary data return
+    /// `Ok(None)`.
+    fn binary_upload<U, R>(
+        &self,
+        _uri: &str,
+        _request: BinaryUploadRequest<U>,
+    ) -> impl Future<Output = Result<Option<ModificationResponse<R>>, Self::Error>> + Send
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        ready(Ok(None))
+    }
+
+    /// Set policy of sending `If-Match` with entity updates.
+    ///
+    /// Implementations that do not send `If-Match` may ignore it.
+    fn set_etag_policy(&self, _policy: ETagPolicy) {}
+
+    /// Set policy of handling `null` values of non-nullable properties
+    /// in responses.
+    ///
+    /// Implementations apply it to every response with
+    /// [`crate::NullChecked::apply`]. Implementations that ignore it
+    /// use the default [`NullPolicy::Permissive`].
+    fn set_null_policy(&self, _policy: NullPolicy) {}
+
+    /// Drop responses and `ETag`s cached by the implementation.
+    ///
+    /// Called when cached state is known to be stale, for example
+    /// after the Redfish service restarted. Implementations without
+    /// cache may ignore it.
+    fn invalidate_cache(&self) {}
+
+    /// Store `entity` returned by a modification request (for
+    /// example, an action that returns the modified resource) as the
+    /// current representation of the entity.
+    ///
+    /// Implementations with cache use it to avoid fetching the entity
+    /// again right after the modification. Implementations without
+    /// cache may ignore it.
+    fn cache_entity<T: EntityTypeRef + 'static>(&self, _entity: &Arc<T>) {}
+
+    /// Methods that the service allows on the entity at `id` as
+    /// declared in the `Allow` header.
+    ///
+    /// Implementations that cannot query them return `Ok(None)`,
+    /// which means that allowed methods are unknown.
+    fn allowed_methods(
+        &self,
+        _id: &ODataId,
+    ) -> impl Future<Output = Result<Option<AllowedMethods>, Self::Error>> + Send {
+        ready(Ok(None))
+    }
+
+    /// Poll the task monitor of an asynchronous operation.
+    ///
+    /// `uri` is the task monitor URI and should be resolved as a
+    /// Redfish URI reference. The monitor returns `202 Accepted` with
+    /// optional `Retry-After` while the operation is running and the
+    /// operation's response once it is finished.
+    ///
+    /// Implementations that cannot read task monitors return
+    /// `Ok(None)`.
+    fn task_monitor(
+        &self,
+        _uri: &str,
+    ) -> impl Future<Output = Result<Option<TaskMonitorResponse>, Self::Error>> + Send {
+        ready(Ok(None))
+    }
+
+    /// Latency class of the service derived from time to the first
+    /// byte of recent responses.
+    ///
+    /// Implementations that do not measure latency return `None`.
+    fn latency_class(&self) -> Option<LatencyClass> {
+        None
+    }
+
+    /// HTTP status code of the error response returned by the Redfish
+    /// service. Implementations that do not keep status codes return
+    /// `None`.
+    fn error_status_code(_error: &Self::Error) -> Option<u16> {
+        None
+    }
+
+    /// Body of the error response returned by the Redfish service.
+    ///
+    /// The body usually carries `@Message.ExtendedInfo` that explains
+    /// why the request was rejected. Implementations that do not keep
+    /// response bodies return `None`.
+    fn error_response_body(_error: &Self::Error) -> Option<&str> {
+        None
+    }
+
+    /// Language of the error response body returned by the Redfish
+    /// service (for example, from `Content-Language` header).
+    ///
+    /// Services with localized message registries render messages in
+    /// the requested language. Implementations that do not know the
+    /// language return `None`.
+    fn error_response_language(_error: &Self::Error) -> Option<&str> {
+        None
+    }
+
+    /// `@odata.type` of the response payload that could not be
+    /// deserialized into the requested type. Implementations that do
+    /// not keep it return `None`.
+    fn error_odata_type(_error: &Self::Error) -> Option<&str> {
+        None
+    }
+
+    /// Redfish error response returned by the service, if the error
+    /// carries one.
+    ///
+    /// Callers use it to branch on message identifiers of
+    /// `@Message.ExtendedInfo`. The default implementation parses
+    /// [`Self::error_response_body`].
+    fn redfish_error(error: &Self::Error) -> Option<RedfishError> {
+        Self::error_response_body(error).and_then(RedfishError::from_body)
+    }
+
     /// Stream data for the URI.
     ///
     /// `uri` should be resolved as a Redfish URI reference.
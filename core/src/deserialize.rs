@@ -13,8 +13,182 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use core::cell::RefCell;
+use core::fmt;
+use serde::de::Error as _;
 use serde::Deserialize;
 use serde::Deserializer;
+use std::collections::HashSet;
+use std::error::Error as StdError;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+thread_local! {
+    // `null` values of non-nullable properties met in the value that
+    // is being deserialized by `NullChecked`. `None` outside of
+    // `NullChecked` deserialization.
+    static NULL_VALUES: RefCell<Option<Vec<NullValue>>> = const { RefCell::new(None) };
+}
+
+// Properties which accepted `null` values were already logged with a
+// warning. Following ones are logged at debug level, so services that
+// return `null` in every response don't flood the log.
+static WARNED_PROPERTIES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+/// Policy of handling `null` values of properties that are not
+/// nullable according to the schema.
+///
+/// Redfish services frequently return `null` for such properties.
+/// While a response is deserialized with [`NullChecked`], generated
+/// types accept them: optional properties become absent (see
+/// [`de_optional_non_nullable`]) and required properties of types
+/// with a default value get it (see [`de_required_non_nullable`]).
+/// The policy decides which of these values are acceptable. It is
+/// applied to the result of every request by the [`crate::Bmc`]
+/// implementation with [`NullChecked::apply`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NullPolicy {
+    /// `null` of optional properties is accepted as absent property
+    /// and a warning is logged. `null` of required properties is a
+    /// deserialization error.
+    #[default]
+    Permissive,
+    /// `null` of required properties is accepted as well and replaced
+    /// with the default value of the property type. Identifiers of
+    /// resources (`Id`, `MemberId`) are never replaced.
+    DefaultRequired,
+    /// `null` is a deserialization error.
+    Strict,
+}
+
+impl NullPolicy {
+    const fn rejects(self, null_value: NullValue) -> bool {
+        match self {
+            Self::Permissive => null_value.required,
+            Self::DefaultRequired => false,
+            Self::Strict => true,
+        }
+    }
+}
+
+// `null` value of non-nullable property. `property` is the path of
+// the property (`Type.Property`).
+#[derive(Debug, Clone, Copy)]
+struct NullValue {
+    property: &'static str,
+    required: bool,
+}
+
+/// Value together with `null` values of non-nullable properties met
+/// during its deserialization.
+///
+/// `null` values are collected only while the value is deserialized,
+/// so the wrapper is applied to the type of a response body.
+#[derive(Debug)]
+pub struct NullChecked<T> {
+    value: T,
+    null_values: Vec<NullValue>,
+}
+
+impl<T> NullChecked<T> {
+    /// Return the value if it is acceptable by `policy`. Accepted
+    /// `null` values are logged: the first one of every property with
+    /// a warning, following ones at debug level.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value contained `null` values of
+    /// non-nullable properties that are rejected by `policy`.
+    pub fn apply(self, policy: NullPolicy) -> Result<T, NullValueError> {
+        let properties = self
+            .null_values
+            .iter()
+            .filter(|v| policy.rejects(**v))
+            .map(|v| v.property)
+            .collect::<Vec<_>>();
+        if properties.is_empty() {
+            for null_value in self.null_values {
+                log_null(null_value.property);
+            }
+            Ok(self.value)
+        } else {
+            Err(NullValueError { properties })
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NullChecked<T> {
+    fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
+        struct Restore(Option<Vec<NullValue>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                NULL_VALUES.with(|v| *v.borrow_mut() = self.0.take());
+            }
+        }
+
+        let restore = Restore(NULL_VALUES.with(|v| v.borrow_mut().replace(Vec::new())));
+        let value = T::deserialize(de)?;
+        let null_values = NULL_VALUES
+            .with(|v| v.borrow_mut().take())
+            .unwrap_or_default();
+        drop(restore);
+        Ok(Self { value, null_values })
+    }
+}
+
+/// Response contained `null` values of non-nullable properties that
+/// are rejected by the [`NullPolicy`] in effect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullValueError {
+    properties: Vec<&'static str>,
+}
+
+impl NullValueError {
+    /// Paths of properties that were `null` (`Type.Property`, for
+    /// example `Status.Health`).
+    #[must_use]
+    pub fn properties(&self) -> &[&'static str] {
+        &self.properties
+    }
+}
+
+impl fmt::Display for NullValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "null value of non-nullable property {}",
+            self.properties.join(", ")
+        )
+    }
+}
+
+impl StdError for NullValueError {}
+
+fn log_null(property: &'static str) {
+    let first = WARNED_PROPERTIES
+        .get_or_init(Mutex::default)
+        .lock()
+        .map_or(true, |mut warned| warned.insert(property));
+    if first {
+        log::warn!("null value of non-nullable property {property} is accepted");
+    } else {
+        log::debug!("null value of non-nullable property {property} is accepted");
+    }
+}
+
+// Record `null` value of non-nullable `property` to report it by
+// `NullChecked`. Returns false outside of `NullChecked`
+// deserialization.
+fn record_null(property: &'static str, required: bool) -> bool {
+    let null_value = NullValue { property, required };
+    NULL_VALUES.with(|v| {
+        v.borrow_mut()
+            .as_mut()
+            .map(|v| v.push(null_value))
+            .is_some()
+    })
+}
 
 /// Deserialize an optional nullable field. nv-redfish models these fields
 /// with `Option<Option<T>>`, where `None` means "no field" and
@@ -44,3 +218,161 @@ where
 {
     Deserialize::deserialize(de)
 }
+
+/// Deserialize an optional non-nullable field. nv-redfish models these
+/// fields with `Option<T>`, where `None` means "no field".
+///
+/// `null` is treated as absent field and reported to [`NullChecked`]
+/// with path of the property (`Type.Property`). Generated code calls
+/// it from a deserializer of the field that provides the path.
+///
+/// # Errors
+///
+/// Returns an error if deserialization of the underlying type fails.
+pub fn de_optional_non_nullable<'de, D, T>(
+    de: D,
+    property: &'static str,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let value = Option::<T>::deserialize(de)?;
+    if value.is_none() && !record_null(property, false) {
+        log_null(property);
+    }
+    Ok(value)
+}
+
+/// Deserialize a required non-nullable field of a type that has a
+/// default value.
+///
+/// Inside of [`NullChecked`] `null` is replaced with the default
+/// value and reported with path of the property (`Type.Property`), so
+/// [`NullPolicy::DefaultRequired`] can accept it.
+///
+/// # Errors
+///
+/// Returns an error if the value is `null` outside of [`NullChecked`]
+/// or deserialization of the underlying type fails.
+pub fn de_required_non_nullable<'de, D, T>(de: D, property: &'static str) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    match Option::<T>::deserialize(de)? {
+        Some(value) => Ok(value),
+        None if record_null(property, true) => Ok(T::default()),
+        None => Err(D::Error::custom(format_args!(
+            "null value of non-nullable property {property}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::de_optional_non_nullable;
+    use super::de_required_non_nullable;
+    use super::NullChecked;
+    use super::NullPolicy;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde_json::json;
+
+    // Resource with deserializers of the same shape as generated ones.
+    #[derive(Debug, Deserialize)]
+    struct Resource {
+        #[serde(rename = "Id")]
+        id: String,
+        #[serde(rename = "Name", deserialize_with = "Resource::de_name")]
+        name: String,
+        #[serde(
+            rename = "Description",
+            default,
+            deserialize_with = "Resource::de_description"
+        )]
+        description: Option<String>,
+    }
+
+    impl Resource {
+        fn de_name<'de, D: Deserializer<'de>>(de: D) -> Result<String, D::Error> {
+            de_required_non_nullable(de, "Resource.Name")
+        }
+
+        fn de_description<'de, D: Deserializer<'de>>(de: D) -> Result<Option<String>, D::Error> {
+            de_optional_non_nullable(de, "Resource.Description")
+        }
+    }
+
+    fn checked(value: serde_json::Value) -> NullChecked<Resource> {
+        serde_json::from_value(value).expect("valid resource")
+    }
+
+    #[test]
+    fn optional_null_is_accepted_without_policy() {
+        let resource: Resource =
+            serde_json::from_value(json!({ "Id": "1", "Name": "n", "Description": null }))
+                .expect("null is tolerated");
+        assert_eq!(resource.id, "1");
+        assert_eq!(resource.description, None);
+        assert!(serde_json::from_value::<Resource>(json!({ "Id": "1", "Name": null })).is_err());
+        assert!(serde_json::from_value::<Resource>(json!({ "Id": null, "Name": "n" })).is_err());
+    }
+
+    #[test]
+    fn permissive_policy_accepts_optional_null() {
+        let resource = checked(json!({ "Id": "1", "Name": "n", "Description": null }))
+            .apply(NullPolicy::Permissive)
+            .expect("null is tolerated");
+        assert_eq!(resource.description, None);
+        let err = checked(json!({ "Id": "1", "Name": null, "Description": null }))
+            .apply(NullPolicy::Permissive)
+            .expect_err("required null is rejected");
+        assert_eq!(err.properties(), ["Resource.Name"]);
+    }
+
+    #[test]
+    fn default_required_policy_replaces_required_null() {
+        let resource = checked(json!({ "Id": "1", "Name": null, "Description": null }))
+            .apply(NullPolicy::DefaultRequired)
+            .expect("null is replaced");
+        assert_eq!(resource.name, "");
+        assert_eq!(resource.description, None);
+        assert!(serde_json::from_value::<NullChecked<Resource>>(json!({ "Id": null })).is_err());
+    }
+
+    #[test]
+    fn strict_policy_rejects_null() {
+        let err = checked(json!({ "Id": "1", "Name": "n", "Description": null }))
+            .apply(NullPolicy::Strict)
+            .expect_err("null is rejected");
+        assert_eq!(err.properties(), ["Resource.Description"]);
+        assert_eq!(
+            err.to_string(),
+            "null value of non-nullable property Resource.Description"
+        );
+        assert!(checked(json!({ "Id": "1", "Name": null }))
+            .apply(NullPolicy::Strict)
+            .is_err());
+        let resource = checked(json!({ "Id": "1", "Name": "n" }))
+            .apply(NullPolicy::Strict)
+            .expect("absent value");
+        assert_eq!(resource.description, None);
+        let resource = checked(json!({ "Id": "1", "Name": "n", "Description": "d" }))
+            .apply(NullPolicy::Strict)
+            .expect("valid value");
+        assert_eq!(resource.description.as_deref(), Some("d"));
+    }
+
+    #[test]
+    fn nulls_are_collected_per_value() {
+        let nested: NullChecked<Vec<NullChecked<Resource>>> = serde_json::from_value(json!([
+            { "Id": "1", "Name": "n", "Description": null },
+            { "Id": "2", "Name": "n" }
+        ]))
+        .expect("valid resources");
+        let mut resources = nested.apply(NullPolicy::Strict).expect("inner nulls");
+        assert!(resources.remove(1).apply(NullPolicy::Strict).is_ok());
+        assert!(resources.remove(0).apply(NullPolicy::Strict).is_err());
+    }
+}
@@ -99,6 +99,12 @@ impl From<EdmDateTimeOffset> for OffsetDateTime {
     }
 }
 
+impl From<SystemTime> for EdmDateTimeOffset {
+    fn from(t: SystemTime) -> Self {
+        Self(t.into())
+    }
+}
+
 impl TryFrom<EdmDateTimeOffset> for SystemTime {
     type Error = Error;
     fn try_from(w: EdmDateTimeOffset) -> Result<Self, Self::Error> {
@@ -332,4 +338,12 @@ mod tests {
             253402300799
         );
     }
+
+    #[test]
+    fn converts_from_system_time() {
+        let time = SystemTime::UNIX_EPOCH + Duration::new(1_614_834_367, 500_000_000);
+        let dt = EdmDateTimeOffset::from(time);
+        assert_eq!(dt.to_string(), "2021-03-04T05:06:07.5Z");
+        assert_eq!(SystemTime::try_from(dt).unwrap(), time);
+    }
 }
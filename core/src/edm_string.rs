@@ -0,0 +1,256 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interned `Edm.String` wrapper
+//!
+//! Large inventories repeat identical strings (`@odata.type`, units,
+//! status values, model names) across many resources and BMCs.
+//! `EdmString` stores deserialized strings in a process-wide intern
+//! table, so equal values share one allocation. The table is split into
+//! shards selected by hash of the string, so threads that parse
+//! payloads concurrently rarely wait for each other. Values that are longer
+//! than [`EdmString::MAX_INTERNED_LEN`] are rarely repeated and are
+//! not put into the table.
+//!
+//! Generated code uses this type for `Edm.String` when it is compiled
+//! with `--intern-strings`. `nv-redfish` itself is compiled without it:
+//! its wrappers expose properties as `String`.
+//!
+//! ```rust
+//! use nv_redfish_core::EdmString;
+//!
+//! let a: EdmString = serde_json::from_str(r#""Enabled""#).unwrap();
+//! let b: EdmString = serde_json::from_str(r#""Enabled""#).unwrap();
+//! assert_eq!(a, "Enabled");
+//! assert!(a.shares_storage(&b));
+//! ```
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use std::array::from_fn;
+use std::borrow::Borrow;
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::hash::Hash as _;
+use std::hash::Hasher as _;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+
+/// Immutable string that shares storage with equal interned strings.
+#[derive(Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdmString(Arc<str>);
+
+impl EdmString {
+    /// Maximum length of string that is put into the intern table.
+    pub const MAX_INTERNED_LEN: usize = 128;
+
+    /// Create string sharing storage with equal interned strings.
+    #[must_use]
+    pub fn interned(v: &str) -> Self {
+        if v.len() > Self::MAX_INTERNED_LEN {
+            return Self(v.into());
+        }
+        let mut table = shard(v).lock().unwrap_or_else(PoisonError::into_inner);
+        let interned = table.get(v).cloned().unwrap_or_else(|| {
+            let v: Arc<str> = v.into();
+            table.insert(v.clone());
+            v
+        });
+        drop(table);
+        Self(interned)
+    }
+
+    /// String slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if both strings share the same storage.
+    #[must_use]
+    pub fn shares_storage(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Remove strings that are not referenced outside of the intern
+    /// table.
+    ///
+    /// Long-running processes should call it periodically (for
+    /// example, after each inventory refresh) so values of retired
+    /// resources don't stay in memory.
+    pub fn purge_unused() {
+        for shard in shards() {
+            shard
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .retain(|v| Arc::strong_count(v) > 1);
+        }
+    }
+
+    /// Number of strings in the intern table.
+    #[must_use]
+    pub fn interned_count() -> usize {
+        shards()
+            .iter()
+            .map(|shard| shard.lock().unwrap_or_else(PoisonError::into_inner).len())
+            .sum()
+    }
+}
+
+/// Number of independently locked parts of the intern table.
+const SHARDS: usize = 32;
+
+type Shard = Mutex<HashSet<Arc<str>>>;
+
+fn shards() -> &'static [Shard; SHARDS] {
+    static TABLE: OnceLock<[Shard; SHARDS]> = OnceLock::new();
+    TABLE.get_or_init(|| from_fn(|_| Mutex::default()))
+}
+
+/// Part of the intern table that keeps `v`.
+fn shard(v: &str) -> &'static Shard {
+    let mut hasher = DefaultHasher::new();
+    v.hash(&mut hasher);
+    // Remainder is less than `SHARDS`, so it always fits `usize`.
+    #[allow(clippy::cast_possible_truncation)]
+    let index = (hasher.finish() % SHARDS as u64) as usize;
+    &shards()[index]
+}
+
+impl Deref for EdmString {
+    type Target = str;
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for EdmString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for EdmString {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for EdmString {
+    fn from(v: &str) -> Self {
+        Self::interned(v)
+    }
+}
+
+impl From<String> for EdmString {
+    fn from(v: String) -> Self {
+        Self::interned(&v)
+    }
+}
+
+impl From<EdmString> for String {
+    fn from(v: EdmString) -> Self {
+        v.0.as_ref().into()
+    }
+}
+
+impl PartialEq<str> for EdmString {
+    fn eq(&self, other: &str) -> bool {
+        &*self.0 == other
+    }
+}
+
+impl PartialEq<&str> for EdmString {
+    fn eq(&self, other: &&str) -> bool {
+        &*self.0 == *other
+    }
+}
+
+impl PartialEq<String> for EdmString {
+    fn eq(&self, other: &String) -> bool {
+        *self.0 == **other
+    }
+}
+
+impl Display for EdmString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&*self.0, f)
+    }
+}
+
+impl Debug for EdmString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Debug::fmt(&*self.0, f)
+    }
+}
+
+impl Serialize for EdmString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for EdmString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Cow::<'de, str>::deserialize(deserializer).map(|v| Self::interned(&v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EdmString;
+    use std::sync::Arc;
+
+    #[test]
+    fn long_strings_are_not_interned() {
+        let long = "x".repeat(EdmString::MAX_INTERNED_LEN + 1);
+        let a = EdmString::interned(&long);
+        let b = EdmString::interned(&long);
+        assert_eq!(a, b);
+        assert!(!a.shares_storage(&b));
+    }
+
+    #[test]
+    fn unused_strings_are_purged() {
+        let a = EdmString::interned("edm-string-purge-test");
+        let weak = Arc::downgrade(&a.0);
+        drop(a);
+        EdmString::purge_unused();
+        assert!(weak.upgrade().is_none());
+        let b = EdmString::interned("edm-string-purge-test-kept");
+        EdmString::purge_unused();
+        assert!(b.shares_storage(&EdmString::interned("edm-string-purge-test-kept")));
+    }
+
+    #[test]
+    fn serialization_is_transparent() {
+        let v: EdmString = serde_json::from_str(r#""Watts""#).expect("valid json");
+        assert_eq!(
+            serde_json::to_string(&v).expect("serializable"),
+            r#""Watts""#
+        );
+        assert_eq!(format!("{v:?}"), r#""Watts""#);
+    }
+}
@@ -21,7 +21,7 @@
 //! - Avoid any knowledge of specific Redfish services, schemas, or OEM semantics.
 //!
 //! Scope (building blocks only)
-//! - Identity and metadata: [`ODataId`], [`ODataETag`]
+//! - Identity and metadata: [`ODataId`], [`ODataETag`], [`ETagPolicy`]
 //! - EDM value wrappers: [`EdmDateTimeOffset`], [`EdmDuration`]
 //! - Navigation properties: [`NavProperty<T>`]
 //! - Generic operation traits: [`Creatable`], [`Updatable`], [`Deletable`]
@@ -65,6 +65,8 @@
 
 /// Action-related types.
 pub mod action;
+/// HTTP methods allowed on a resource.
+pub mod allowed_methods;
 /// BMC trait and credentials.
 pub mod bmc;
 /// Custom deserialization helpers.
@@ -77,18 +79,23 @@ pub mod edm_date_time_offset;
 pub mod edm_duration;
 /// `Edm.PrimitiveType` type.
 pub mod edm_primitive_type;
+/// Interned `Edm.String` type.
+pub mod edm_string;
 /// Navigation property wrapper.
 pub mod nav_property;
 /// Type for `@odata.id` identifier.
 pub mod odata;
 /// Support of redfish queries
 pub mod query;
+/// Redfish error responses.
+pub mod redfish_error;
 /// Upload data types.
 pub mod upload;
 
 use crate::query::ExpandQuery;
 use futures_core::TryStream;
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom as _;
 use std::fmt;
 use std::pin::Pin;
 use std::time::Duration;
@@ -99,12 +106,32 @@ pub use action::Action;
 #[doc(inline)]
 pub use action::ActionError;
 #[doc(inline)]
+pub use action::ActionsAllowableValues;
+#[doc(inline)]
+pub use action::AllowableValues;
+#[doc(inline)]
+pub use allowed_methods::AllowedMethods;
+#[doc(inline)]
+pub use allowed_methods::HttpMethod;
+#[doc(inline)]
 pub use bmc::Bmc;
 #[doc(inline)]
+pub use bmc::LatencyClass;
+#[doc(inline)]
+pub use deserialize::de_optional_non_nullable;
+#[doc(inline)]
 pub use deserialize::de_optional_nullable;
 #[doc(inline)]
+pub use deserialize::de_required_non_nullable;
+#[doc(inline)]
 pub use deserialize::de_required_nullable;
 #[doc(inline)]
+pub use deserialize::NullChecked;
+#[doc(inline)]
+pub use deserialize::NullPolicy;
+#[doc(inline)]
+pub use deserialize::NullValueError;
+#[doc(inline)]
 pub use dynamic_properties::DynamicProperties;
 #[doc(inline)]
 pub use edm_date_time_offset::EdmDateTimeOffset;
@@ -113,22 +140,39 @@ pub use edm_duration::EdmDuration;
 #[doc(inline)]
 pub use edm_primitive_type::EdmPrimitiveType;
 #[doc(inline)]
+pub use edm_string::EdmString;
+#[doc(inline)]
 pub use nav_property::NavProperty;
 #[doc(inline)]
 pub use nav_property::Reference;
 #[doc(inline)]
 pub use nav_property::ReferenceLeaf;
 #[doc(inline)]
+pub use odata::ETagPolicy;
+#[doc(inline)]
 pub use odata::ODataETag;
 #[doc(inline)]
 pub use odata::ODataId;
 #[doc(inline)]
-pub use query::FilterQuery;
+pub use odata::SchemaVersion;
 #[doc(inline)]
+pub use redfish_error::MessageSeverity;
+#[doc(inline)]
+pub use redfish_error::RedfishError;
+#[doc(inline)]
+pub use redfish_error::RedfishMessage;
+/// Deprecated alias of [`query::FilterQuery`].
+#[deprecated(note = "use `nv_redfish_core::query::FilterQuery`")]
+pub type FilterQuery = query::FilterQuery;
+// Kept for compatibility with code that imports the trait from the
+// crate root. Canonical path is `query::ToFilterLiteral`.
+#[doc(hidden)]
 pub use query::ToFilterLiteral;
 #[doc(inline)]
 pub use serde_json::Value as AdditionalProperties;
 #[doc(inline)]
+pub use upload::BinaryUploadRequest;
+#[doc(inline)]
 pub use upload::DataStream;
 #[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
@@ -143,7 +187,6 @@ pub use upload::OemMultipartPartNameError;
 pub use upload::OemMultipartPartReader;
 #[doc(inline)]
 pub use upload::UploadReader;
-#[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
 pub use upload::UploadStream;
 #[doc(inline)]
@@ -159,6 +202,11 @@ pub trait EntityTypeRef: Send + Sync + Sized {
     /// Value of `@odata.etag` field of the Entity.
     fn etag(&self) -> Option<&ODataETag>;
 
+    /// Value of `@odata.type` field of the Entity if it is provided.
+    fn odata_type(&self) -> Option<&str> {
+        None
+    }
+
     /// Refresh the entity by fetching it again from the BMC.
     fn refresh<B: Bmc>(&self, bmc: &B) -> impl Future<Output = Result<Arc<Self>, B::Error>> + Send
     where
@@ -180,6 +228,85 @@ pub trait Expandable: EntityTypeRef + for<'de> Deserialize<'de> + 'static {
     }
 }
 
+/// This trait is assigned by the CSDL compiler to the collections,
+/// i.e. entity types that have `Members` navigation property.
+///
+/// It provides access to `Members@odata.count` and
+/// `Members@odata.nextLink` annotations that define the full size of
+/// the collection when the service returns members in portions.
+pub trait MembersCollection {
+    /// Value of `Members@odata.count` annotation: total number of
+    /// members in the collection.
+    fn members_count(&self) -> Option<i64>;
+
+    /// Value of `Members@odata.nextLink` annotation: reference to the
+    /// next portion of the collection members.
+    fn next_link(&self) -> Option<&ODataId>;
+
+    /// Number of members in this portion of the collection.
+    fn members_len(&self) -> usize;
+
+    /// Returns `true` if the service returned only a part of the
+    /// collection members.
+    fn is_partial(&self) -> bool {
+        self.next_link().is_some()
+            || self
+                .members_count()
+                .and_then(|count| usize::try_from(count).ok())
+                .is_some_and(|count| count > self.members_len())
+    }
+}
+
+/// Schema versions that introduced properties of an entity type.
+///
+/// Implemented by the CSDL compiler for entity types. Generated
+/// structs are tolerant to older schema versions: properties that were
+/// introduced after the initial version are never required. This trait
+/// allows to check at runtime whether the service implements version
+/// of the schema that defines the property.
+pub trait PropertyVersions: EntityTypeRef {
+    /// Version of the schema that introduced `property` (name of the
+    /// property in JSON payload). `None` if the property is not known.
+    fn property_since(property: &str) -> Option<SchemaVersion>;
+
+    /// Version of the schema that deprecated `property` according to
+    /// `Redfish.Revisions` annotation. `None` if the property is not
+    /// deprecated or not known.
+    #[must_use]
+    fn property_deprecated(property: &str) -> Option<SchemaVersion> {
+        let _ = property;
+        None
+    }
+
+    /// Version of the schema implemented by the service for this
+    /// entity. Parsed from `@odata.type`.
+    fn schema_version(&self) -> Option<SchemaVersion> {
+        self.odata_type().and_then(SchemaVersion::from_odata_type)
+    }
+
+    /// Whether the service implements `property` for this entity.
+    ///
+    /// Property is supported if it is known and the entity schema
+    /// version is not older than the version that introduced the
+    /// property. If the entity does not provide `@odata.type` then all
+    /// known properties are considered supported.
+    fn supports(&self, property: &str) -> bool {
+        Self::property_since(property)
+            .is_some_and(|since| self.schema_version().is_none_or(|version| version >= since))
+    }
+
+    /// Whether `property` is deprecated in the schema version
+    /// implemented by the service for this entity. If the entity does
+    /// not provide `@odata.type` then the latest known schema version
+    /// is assumed.
+    fn is_deprecated(&self, property: &str) -> bool {
+        Self::property_deprecated(property).is_some_and(|deprecated| {
+            self.schema_version()
+                .is_none_or(|version| version >= deprecated)
+        })
+    }
+}
+
 /// Boxed fallible stream used by BMC streaming APIs.
 pub type BoxTryStream<T, E> =
     Pin<Box<dyn TryStream<Ok = T, Error = E, Item = Result<T, E>> + Send>>;
@@ -211,6 +338,30 @@ pub struct AsyncTask {
     pub retry_after: Option<Duration>,
 }
 
+/// Result of a completed asynchronous operation read from its task
+/// monitor.
+#[derive(Debug, Default)]
+pub struct AsyncTaskResult {
+    /// `Location` of the resource created or modified by the
+    /// operation.
+    pub location: Option<ODataId>,
+
+    /// Response payload of the operation, if any.
+    pub payload: Option<serde_json::Value>,
+}
+
+/// Response of the task monitor of an asynchronous operation.
+#[derive(Debug)]
+pub enum TaskMonitorResponse {
+    /// Operation is still running (`202 Accepted`).
+    Running {
+        /// Recommended duration to wait before polling again.
+        retry_after: Option<Duration>,
+    },
+    /// Operation is finished and the monitor returned its result.
+    Finished(AsyncTaskResult),
+}
+
 /// Outcome of a mutating Redfish operation.
 #[must_use = "mutating Redfish responses may contain an asynchronous task handle"]
 #[derive(Debug)]
@@ -337,6 +488,62 @@ pub trait Deletable: EntityTypeRef + for<'de> Deserialize<'de> {
     ) -> impl Future<Output = Result<ModificationResponse<Self>, B::Error>> + Send {
         bmc.delete::<Self>(self.odata_id())
     }
+
+    /// Delete current entity capturing the response payload as `R`.
+    ///
+    /// Some services return the deleted entity or a message object
+    /// from DELETE. Use `Self` to inspect the returned representation
+    /// (for example, to detect soft delete) or `serde_json::Value` to
+    /// capture the raw payload.
+    fn delete_with_response<B: Bmc, R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
+        &self,
+        bmc: &B,
+    ) -> impl Future<Output = Result<ModificationResponse<R>, B::Error>> + Send {
+        bmc.delete::<R>(self.odata_id())
+    }
+}
+
+/// Modification capabilities of an entity type as specified by
+/// `Capabilities` annotations in the CSDL specification.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    /// New members can be created in the entity (collection).
+    pub insertable: bool,
+    /// Entity can be updated.
+    pub updatable: bool,
+    /// Entity can be deleted.
+    pub deletable: bool,
+}
+
+/// This trait is assigned to all entity types and provides
+/// capabilities compiled from the CSDL specification.
+pub trait EntityCapabilities {
+    /// Capabilities of the entity type.
+    const CAPABILITIES: Capabilities;
+
+    /// Capabilities of the entity type.
+    #[must_use]
+    fn capabilities(&self) -> Capabilities {
+        Self::CAPABILITIES
+    }
+}
+
+/// This trait is assigned to all entity types and `Links` complex
+/// types and provides access to OEM links (`Links.Oem`).
+pub trait LinksOem {
+    /// Content of the `Links.Oem` object. `None` if the entity type
+    /// doesn't have links or the object is not provided.
+    fn links_oem(&self) -> Option<&AdditionalProperties>;
+}
+
+/// This trait is assigned to all entity types and provides access to
+/// single-valued navigation properties by name.
+pub trait NavigationProperties {
+    /// `@odata.id` of the navigation property `name` (name of the
+    /// property in JSON payload). `None` if the entity type doesn't
+    /// define a single-valued navigation property with this name or
+    /// the service doesn't provide it.
+    fn navigation_property(&self, name: &str) -> Option<&ODataId>;
 }
 
 /// This trait is assigned to updatable entity types to support
@@ -403,6 +610,109 @@ mod tests {
         })
     }
 
+    struct Page {
+        count: Option<i64>,
+        next_link: Option<ODataId>,
+        len: usize,
+    }
+
+    impl MembersCollection for Page {
+        fn members_count(&self) -> Option<i64> {
+            self.count
+        }
+        fn next_link(&self) -> Option<&ODataId> {
+            self.next_link.as_ref()
+        }
+        fn members_len(&self) -> usize {
+            self.len
+        }
+    }
+
+    #[test]
+    fn members_collection_is_partial() {
+        let page = |count, next_link: Option<&str>, len| Page {
+            count,
+            next_link: next_link.map(|v| ODataId::from(v.to_string())),
+            len,
+        };
+        assert!(!page(None, None, 2).is_partial());
+        assert!(!page(Some(2), None, 2).is_partial());
+        assert!(page(Some(5), None, 2).is_partial());
+        assert!(page(None, Some("/redfish/v1/Systems?$skip=2"), 2).is_partial());
+    }
+
+    struct Entity {
+        odata_id: ODataId,
+        odata_type: Option<&'static str>,
+    }
+
+    impl EntityTypeRef for Entity {
+        fn odata_id(&self) -> &ODataId {
+            &self.odata_id
+        }
+        fn etag(&self) -> Option<&ODataETag> {
+            None
+        }
+        fn odata_type(&self) -> Option<&str> {
+            self.odata_type
+        }
+    }
+
+    impl PropertyVersions for Entity {
+        fn property_since(property: &str) -> Option<SchemaVersion> {
+            match property {
+                "Name" => Some(SchemaVersion::new(1, 0, 0)),
+                "Metrics" => Some(SchemaVersion::new(1, 4, 0)),
+                _ => None,
+            }
+        }
+
+        fn property_deprecated(property: &str) -> Option<SchemaVersion> {
+            match property {
+                "Name" => Some(SchemaVersion::new(1, 10, 0)),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn supports_compares_property_and_entity_versions() {
+        let entity = |odata_type| Entity {
+            odata_id: ODataId::from("/redfish/v1/Systems/1/Processors/CPU0".to_string()),
+            odata_type,
+        };
+        let old = entity(Some("#Processor.v1_2_0.Processor"));
+        assert_eq!(old.schema_version(), Some(SchemaVersion::new(1, 2, 0)));
+        assert!(old.supports("Name"));
+        assert!(!old.supports("Metrics"));
+        assert!(!old.supports("Unknown"));
+
+        let new = entity(Some("#Processor.v1_15_0.Processor"));
+        assert!(new.supports("Metrics"));
+
+        let untyped = entity(None);
+        assert!(untyped.supports("Metrics"));
+    }
+
+    #[test]
+    fn is_deprecated_compares_deprecation_and_entity_versions() {
+        let entity = |odata_type| Entity {
+            odata_id: ODataId::from("/redfish/v1/Systems/1/Processors/CPU0".to_string()),
+            odata_type,
+        };
+        let old = entity(Some("#Processor.v1_2_0.Processor"));
+        assert!(!old.is_deprecated("Name"));
+        assert!(!old.is_deprecated("Metrics"));
+
+        let new = entity(Some("#Processor.v1_15_0.Processor"));
+        assert!(new.is_deprecated("Name"));
+        assert!(new.supports("Name"));
+        assert!(!new.is_deprecated("Unknown"));
+
+        let untyped = entity(None);
+        assert!(untyped.is_deprecated("Name"));
+    }
+
     #[test]
     fn map_entity_maps_entity_and_preserves_task_and_empty() -> Result<(), &'static str> {
         assert_entity(
@@ -33,12 +33,12 @@
 //! - OASIS OData 4.01 — navigation properties in CSDL
 //!
 
+use crate::query::FilterQuery;
 use crate::Bmc;
 use crate::Creatable;
 use crate::Deletable;
 use crate::EntityTypeRef;
 use crate::Expandable;
-use crate::FilterQuery;
 use crate::ODataETag;
 use crate::ODataId;
 use crate::Updatable;
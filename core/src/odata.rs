@@ -18,6 +18,7 @@
 //! Minimal wrappers for Redfish/OData identifiers used throughout generated code:
 //! - [`ODataId`]: value of `@odata.id`, the canonical resource path (opaque string)
 //! - [`ODataETag`]: value of `@odata.etag`, the HTTP entity tag (opaque string)
+//! - [`ETagPolicy`]: how entity tags are sent as `If-Match` on updates
 //!
 //! Notes
 //! - These types are intentionally semantic‑unaware; they do not validate content.
@@ -55,6 +56,11 @@ impl ODataId {
         Self("/redfish/v1".into())
     }
 
+    /// Identifier of documents that have no `@odata.id`.
+    pub(crate) const fn empty() -> Self {
+        Self(String::new())
+    }
+
     /// Last segment of `ODataId`.
     ///
     /// # Examples
@@ -91,6 +97,20 @@ impl ODataId {
 
         suffix.is_empty() || suffix.starts_with('/')
     }
+
+    /// Identifier of the resource at `relative` path below this one.
+    ///
+    /// # Examples
+    /// * `"/redfish/v1/Chassis/1" + "Thermal" -> "/redfish/v1/Chassis/1/Thermal"`
+    /// * `"/redfish/v1/Chassis/1/" + "/Oem/Vendor" -> "/redfish/v1/Chassis/1/Oem/Vendor"`
+    #[must_use]
+    pub fn join(&self, relative: &str) -> Self {
+        Self(format!(
+            "{}/{}",
+            self.0.trim_end_matches('/'),
+            relative.trim_start_matches('/')
+        ))
+    }
 }
 
 impl From<String> for ODataId {
@@ -122,6 +142,91 @@ impl Display for ODataETag {
     }
 }
 
+/// Policy that defines how `If-Match` is sent with entity updates.
+///
+/// BMC implementations differ in how they treat `If-Match` on PATCH:
+/// some require it on every request, some reject it, and some accept
+/// only the wildcard `*`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ETagPolicy {
+    /// Always send `If-Match`. Use the entity `ETag` when it is known
+    /// and `*` otherwise.
+    #[default]
+    Required,
+    /// Send `If-Match` only when the entity `ETag` is known.
+    Optional,
+    /// Always send `If-Match: *`, ignoring the entity `ETag`.
+    Star,
+    /// Never send `If-Match`.
+    Disabled,
+}
+
+impl ETagPolicy {
+    /// Value of the `If-Match` header to send for an entity with the
+    /// given `etag`. `None` means that the header must not be sent.
+    #[must_use]
+    pub fn if_match(self, etag: Option<&ODataETag>) -> Option<ODataETag> {
+        let star = || ODataETag::from(String::from("*"));
+        match self {
+            Self::Required => Some(etag.cloned().unwrap_or_else(star)),
+            Self::Optional => etag.cloned(),
+            Self::Star => Some(star()),
+            Self::Disabled => None,
+        }
+    }
+}
+
+/// Version of a Redfish schema (e.g. `v1_5_0`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SchemaVersion {
+    /// Major version.
+    pub major: u16,
+    /// Minor version.
+    pub minor: u16,
+    /// Errata version.
+    pub errata: u16,
+}
+
+impl SchemaVersion {
+    /// Create schema version.
+    #[must_use]
+    pub const fn new(major: u16, minor: u16, errata: u16) -> Self {
+        Self {
+            major,
+            minor,
+            errata,
+        }
+    }
+
+    /// Parse version in namespace format (`v1_5_0`).
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.strip_prefix('v')?.split('_').map(str::parse::<u16>);
+        let version = Self::new(
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+            parts.next()?.ok()?,
+        );
+        parts.next().is_none().then_some(version)
+    }
+
+    /// Parse version from `@odata.type` value (e.g.
+    /// `#Processor.v1_5_0.Processor`).
+    #[must_use]
+    pub fn from_odata_type(odata_type: &str) -> Option<Self> {
+        odata_type
+            .trim_start_matches('#')
+            .split('.')
+            .find_map(Self::parse)
+    }
+}
+
+impl Display for SchemaVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "v{}_{}_{}", self.major, self.minor, self.errata)
+    }
+}
+
 /// Type for retrieving `@odata.type` from a JSON payload.
 pub struct ODataType<'a> {
     /// Namespace of the data type. For example: `["Chassis", "v1_22_0"]`.
@@ -266,4 +371,51 @@ mod tests {
 
         assert!(prefix.is_path_prefix(&id));
     }
+
+    #[test]
+    fn join_appends_relative_path() {
+        let id = ODataId("/redfish/v1/Chassis/1".into());
+        assert_eq!(
+            id.join("Thermal").to_string(),
+            "/redfish/v1/Chassis/1/Thermal"
+        );
+
+        let id = ODataId("/redfish/v1/Chassis/1/".into());
+        assert_eq!(
+            id.join("/Oem/Vendor").to_string(),
+            "/redfish/v1/Chassis/1/Oem/Vendor"
+        );
+    }
+
+    #[test]
+    fn etag_policy_if_match() {
+        let etag = ODataETag("W/\"1\"".into());
+        let star = Some(ODataETag("*".into()));
+        assert_eq!(
+            ETagPolicy::Required.if_match(Some(&etag)),
+            Some(etag.clone())
+        );
+        assert_eq!(ETagPolicy::Required.if_match(None), star);
+        assert_eq!(
+            ETagPolicy::Optional.if_match(Some(&etag)),
+            Some(etag.clone())
+        );
+        assert_eq!(ETagPolicy::Optional.if_match(None), None);
+        assert_eq!(ETagPolicy::Star.if_match(Some(&etag)), star);
+        assert_eq!(ETagPolicy::Disabled.if_match(Some(&etag)), None);
+    }
+
+    #[test]
+    fn schema_version_from_odata_type() {
+        assert_eq!(
+            SchemaVersion::from_odata_type("#Processor.v1_15_2.Processor"),
+            Some(SchemaVersion::new(1, 15, 2))
+        );
+        assert_eq!(
+            SchemaVersion::from_odata_type("#ProcessorCollection.ProcessorCollection"),
+            None
+        );
+        assert!(SchemaVersion::new(1, 2, 0) < SchemaVersion::new(1, 15, 0));
+        assert_eq!(SchemaVersion::new(1, 5, 0).to_string(), "v1_5_0");
+    }
 }
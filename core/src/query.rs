@@ -19,6 +19,9 @@
 //! according to the Redfish specification (DSP0266). These query parameters allow
 //! clients to customize API responses through resource expansion and filtering.
 //!
+//! This module is the canonical location of all query types. `nv-redfish`
+//! re-exports it as `nv_redfish::query`.
+//!
 //! # Query Parameters
 //!
 //! ## Expand Query (`$expand`)
@@ -57,7 +60,7 @@
 //!
 //! Both builders use traits to ensure type safety:
 //!
-//! - [`crate::FilterProperty`]: Types that can be used as filter property paths
+//! - [`FilterProperty`]: Types that can be used as filter property paths
 //! - [`ToFilterLiteral`]: Types that can be converted to filter literal values
 //!
 //! Property paths can be:
@@ -70,6 +73,9 @@
 //! - [Redfish Specification DSP0266](https://redfish.dmtf.org/schemas/DSP0266_1.15.0.html)
 //! - [OData Version 4.0 Protocol](http://docs.oasis-open.org/odata/odata/v4.0/os/part2-url-conventions/odata-v4.0-os-part2-url-conventions.html)
 
+#[doc(inline)]
+pub use crate::FilterProperty;
+
 /// Builder for Redfish `$expand` query parameters according to DSP0266 specification.
 ///
 /// The `$expand` query parameter allows clients to request that the server expand
@@ -292,6 +298,36 @@ impl ExpandQuery {
         }
     }
 
+    /// Expand navigation properties of `other` in addition to this
+    /// query. Unlike [`ExpandQuery::properties`], each property keeps
+    /// its own `$levels`, so collections can be expanded together with
+    /// their members.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use nv_redfish_core::query::ExpandQuery;
+    ///
+    /// let inventory = ExpandQuery::property("Processors")
+    ///     .levels(2)
+    ///     .and(ExpandQuery::property("Memory").levels(2));
+    /// assert_eq!(
+    ///     inventory.to_query_string(),
+    ///     "$expand=Processors($levels=2),Memory($levels=2)"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn and(self, other: Self) -> Self {
+        let Self {
+            expand_expression,
+            levels,
+        } = other;
+        Self {
+            expand_expression: format!("{},{}", self.expression(), expand_expression),
+            levels,
+        }
+    }
+
     /// Set the number of levels to cascade the expand operation.
     ///
     /// The `$levels` parameter controls how deep the expansion goes:
@@ -342,11 +378,15 @@ impl ExpandQuery {
     /// assert_eq!(query.to_query_string(), "$expand=*($levels=1)");
     /// ```
     #[must_use]
-    #[allow(clippy::option_if_let_else)]
     pub fn to_query_string(&self) -> String {
+        format!("$expand={}", self.expression())
+    }
+
+    #[allow(clippy::option_if_let_else)]
+    fn expression(&self) -> String {
         match self.levels {
-            Some(levels) => format!("$expand={}($levels={})", self.expand_expression, levels),
-            None => format!("$expand={}", self.expand_expression),
+            Some(levels) => format!("{}($levels={})", self.expand_expression, levels),
+            None => self.expand_expression.clone(),
         }
     }
 }
@@ -508,7 +548,7 @@ enum LogicalOp {
 
 impl FilterQuery {
     /// Create a new filter with an equality comparison
-    pub fn eq<P: crate::FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
+    pub fn eq<P: FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
         Self {
             expr: Some(FilterExpr::Comparison {
                 property: property.property_path().to_string(),
@@ -520,7 +560,7 @@ impl FilterQuery {
     }
 
     /// Create a new filter with a not-equal comparison
-    pub fn ne<P: crate::FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
+    pub fn ne<P: FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
         Self {
             expr: Some(FilterExpr::Comparison {
                 property: property.property_path().to_string(),
@@ -532,7 +572,7 @@ impl FilterQuery {
     }
 
     /// Create a new filter with a greater-than comparison
-    pub fn gt<P: crate::FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
+    pub fn gt<P: FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
         Self {
             expr: Some(FilterExpr::Comparison {
                 property: property.property_path().to_string(),
@@ -544,7 +584,7 @@ impl FilterQuery {
     }
 
     /// Create a new filter with a greater-than-or-equal comparison
-    pub fn ge<P: crate::FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
+    pub fn ge<P: FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
         Self {
             expr: Some(FilterExpr::Comparison {
                 property: property.property_path().to_string(),
@@ -556,7 +596,7 @@ impl FilterQuery {
     }
 
     /// Create a new filter with a less-than comparison
-    pub fn lt<P: crate::FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
+    pub fn lt<P: FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
         Self {
             expr: Some(FilterExpr::Comparison {
                 property: property.property_path().to_string(),
@@ -568,7 +608,7 @@ impl FilterQuery {
     }
 
     /// Create a new filter with a less-than-or-equal comparison
-    pub fn le<P: crate::FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
+    pub fn le<P: FilterProperty, V: ToFilterLiteral>(property: &P, value: V) -> Self {
         Self {
             expr: Some(FilterExpr::Comparison {
                 property: property.property_path().to_string(),
@@ -614,11 +654,7 @@ impl FilterQuery {
 
     /// Chain an equality comparison (after .`and()` or .`or()`)
     #[must_use]
-    pub fn eq_then<P: crate::FilterProperty, V: ToFilterLiteral>(
-        self,
-        property: &P,
-        value: V,
-    ) -> Self {
+    pub fn eq_then<P: FilterProperty, V: ToFilterLiteral>(self, property: &P, value: V) -> Self {
         let new_expr = FilterExpr::Comparison {
             property: property.property_path().to_string(),
             operator: "eq",
@@ -629,11 +665,7 @@ impl FilterQuery {
 
     /// Chain a not-equal comparison (after .`and()` or .`or()`)
     #[must_use]
-    pub fn ne_then<P: crate::FilterProperty, V: ToFilterLiteral>(
-        self,
-        property: &P,
-        value: V,
-    ) -> Self {
+    pub fn ne_then<P: FilterProperty, V: ToFilterLiteral>(self, property: &P, value: V) -> Self {
         let new_expr = FilterExpr::Comparison {
             property: property.property_path().to_string(),
             operator: "ne",
@@ -644,11 +676,7 @@ impl FilterQuery {
 
     /// Chain a greater-than comparison (after .`and()` or .`or()`)
     #[must_use]
-    pub fn gt_then<P: crate::FilterProperty, V: ToFilterLiteral>(
-        self,
-        property: &P,
-        value: V,
-    ) -> Self {
+    pub fn gt_then<P: FilterProperty, V: ToFilterLiteral>(self, property: &P, value: V) -> Self {
         let new_expr = FilterExpr::Comparison {
             property: property.property_path().to_string(),
             operator: "gt",
@@ -659,11 +687,7 @@ impl FilterQuery {
 
     /// Chain a greater-than-or-equal comparison (after .`and()` or .`or()`)
     #[must_use]
-    pub fn ge_then<P: crate::FilterProperty, V: ToFilterLiteral>(
-        self,
-        property: &P,
-        value: V,
-    ) -> Self {
+    pub fn ge_then<P: FilterProperty, V: ToFilterLiteral>(self, property: &P, value: V) -> Self {
         let new_expr = FilterExpr::Comparison {
             property: property.property_path().to_string(),
             operator: "ge",
@@ -674,11 +698,7 @@ impl FilterQuery {
 
     /// Chain a less-than comparison (after .`and()` or .`or()`)
     #[must_use]
-    pub fn lt_then<P: crate::FilterProperty, V: ToFilterLiteral>(
-        self,
-        property: &P,
-        value: V,
-    ) -> Self {
+    pub fn lt_then<P: FilterProperty, V: ToFilterLiteral>(self, property: &P, value: V) -> Self {
         let new_expr = FilterExpr::Comparison {
             property: property.property_path().to_string(),
             operator: "lt",
@@ -689,11 +709,7 @@ impl FilterQuery {
 
     /// Chain a less-than-or-equal comparison (after .`and()` or .`or()`)
     #[must_use]
-    pub fn le_then<P: crate::FilterProperty, V: ToFilterLiteral>(
-        self,
-        property: &P,
-        value: V,
-    ) -> Self {
+    pub fn le_then<P: FilterProperty, V: ToFilterLiteral>(self, property: &P, value: V) -> Self {
         let new_expr = FilterExpr::Comparison {
             property: property.property_path().to_string(),
             operator: "le",
@@ -725,14 +741,14 @@ impl FilterQuery {
 }
 
 /// Implement `FilterProperty` for `&str`
-impl crate::FilterProperty for &str {
+impl FilterProperty for &str {
     fn property_path(&self) -> &str {
         self
     }
 }
 
 /// Implement `FilterProperty` for `String`
-impl crate::FilterProperty for String {
+impl FilterProperty for String {
     fn property_path(&self) -> &str {
         self.as_str()
     }
@@ -778,6 +794,18 @@ mod tests {
         assert_eq!(query.to_query_string(), "$expand=Thermal,Power($levels=1)");
     }
 
+    #[test]
+    fn test_expand_properties_with_own_levels() {
+        let query = ExpandQuery::property("Processors")
+            .levels(2)
+            .and(ExpandQuery::property("Memory").levels(2))
+            .and(ExpandQuery::property("Storage"));
+        assert_eq!(
+            query.to_query_string(),
+            "$expand=Processors($levels=2),Memory($levels=2),Storage($levels=1)"
+        );
+    }
+
     #[test]
     fn test_expand_with_levels() {
         let query = ExpandQuery::all().levels(3);
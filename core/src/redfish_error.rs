@@ -0,0 +1,283 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redfish error responses.
+//!
+//! Services reject requests with a body whose `error` object carries a
+//! general `code` and `message` together with `@Message.ExtendedInfo`:
+//! messages of message registries that explain the failure (DSP0266,
+//! "Error responses"). [`RedfishError`] is the parsed form of this body
+//! that lets callers branch on message identifiers, for example
+//! `Base.1.0.ResourceAlreadyExists`, instead of matching response text.
+
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+
+/// Error response returned by a Redfish service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedfishError {
+    /// `MessageId` of the general error (`code` of the response).
+    pub code: Option<String>,
+    /// Human-readable message of the general error.
+    pub message: Option<String>,
+    /// Messages of `@Message.ExtendedInfo`.
+    pub extended_info: Vec<RedfishMessage>,
+}
+
+/// Single message of `@Message.ExtendedInfo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedfishMessage {
+    /// Message identifier: `Registry.Major.Minor.MessageKey`.
+    pub message_id: Option<String>,
+    /// Human-readable message.
+    pub message: Option<String>,
+    /// Arguments substituted into the registry message.
+    pub message_args: Vec<JsonValue>,
+    /// Severity of the message.
+    pub severity: Option<MessageSeverity>,
+    /// Recommended action to resolve the problem.
+    pub resolution: Option<String>,
+    /// JSON pointers to properties of the request or resource that the
+    /// message refers to.
+    pub related_properties: Vec<String>,
+}
+
+/// Severity of a Redfish message. Ordered from the least severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MessageSeverity {
+    /// Normal.
+    Ok,
+    /// A condition requires attention.
+    Warning,
+    /// A critical condition requires immediate attention.
+    Critical,
+}
+
+impl MessageSeverity {
+    /// Parse severity as written in messages and message registries
+    /// (`OK`, `Warning`, `Critical`).
+    #[must_use]
+    pub fn parse(v: &str) -> Option<Self> {
+        match v {
+            "OK" => Some(Self::Ok),
+            "Warning" => Some(Self::Warning),
+            "Critical" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+}
+
+impl RedfishError {
+    /// Parse Redfish error response `body`.
+    ///
+    /// Returns `None` if the body is not a Redfish error response.
+    #[must_use]
+    pub fn from_body(body: &str) -> Option<Self> {
+        serde_json::from_str::<ErrorResponse>(body)
+            .ok()
+            .map(|response| Self {
+                code: response.error.code,
+                message: response.error.message,
+                extended_info: response
+                    .error
+                    .extended_info
+                    .into_iter()
+                    .map(RedfishMessage::from)
+                    .collect(),
+            })
+    }
+
+    /// Check if the general error or any of the extended messages has
+    /// identifier `id`. See [`RedfishMessage::is`] for matching rules.
+    #[must_use]
+    pub fn has_message(&self, id: &str) -> bool {
+        self.code
+            .as_deref()
+            .is_some_and(|code| message_id_matches(code, id))
+            || self.extended_info.iter().any(|message| message.is(id))
+    }
+
+    /// Most severe severity of the extended messages.
+    #[must_use]
+    pub fn severity(&self) -> Option<MessageSeverity> {
+        self.extended_info
+            .iter()
+            .filter_map(|message| message.severity)
+            .max()
+    }
+}
+
+impl RedfishMessage {
+    /// Registry prefix of the message identifier
+    /// (`Base.1.8.PropertyNotWritable` -> `Base`).
+    #[must_use]
+    pub fn registry(&self) -> Option<&str> {
+        self.message_id
+            .as_deref()
+            .and_then(|id| id.split_once('.'))
+            .map(|(registry, _)| registry)
+    }
+
+    /// Message key without registry prefix and version
+    /// (`Base.1.8.PropertyNotWritable` -> `PropertyNotWritable`).
+    #[must_use]
+    pub fn message_key(&self) -> Option<&str> {
+        self.message_id
+            .as_deref()
+            .map(|id| id.rsplit_once('.').map_or(id, |(_, key)| key))
+    }
+
+    /// Check if the message has identifier `id`.
+    ///
+    /// Version of the registry is ignored, so
+    /// `Base.1.0.ResourceAlreadyExists` matches the message
+    /// `Base.1.8.ResourceAlreadyExists`. `id` can also omit the
+    /// version (`Base.ResourceAlreadyExists`) or the registry
+    /// (`ResourceAlreadyExists`).
+    #[must_use]
+    pub fn is(&self, id: &str) -> bool {
+        self.message_id
+            .as_deref()
+            .is_some_and(|message_id| message_id_matches(message_id, id))
+    }
+}
+
+/// Compare message identifiers by registry and message key.
+fn message_id_matches(message_id: &str, id: &str) -> bool {
+    let (registry, key) = split_message_id(message_id);
+    let (expected_registry, expected_key) = split_message_id(id);
+    key == expected_key && expected_registry.is_none_or(|expected| registry == Some(expected))
+}
+
+/// Split message identifier to registry prefix and message key.
+fn split_message_id(id: &str) -> (Option<&str>, &str) {
+    id.split_once('.').map_or((None, id), |(registry, rest)| {
+        (
+            Some(registry),
+            rest.rsplit_once('.').map_or(rest, |(_, key)| key),
+        )
+    })
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: ErrorBody,
+}
+
+#[derive(Deserialize)]
+struct ErrorBody {
+    code: Option<String>,
+    message: Option<String>,
+    #[serde(rename = "@Message.ExtendedInfo", default)]
+    extended_info: Vec<ExtendedInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ExtendedInfo {
+    message_id: Option<String>,
+    message: Option<String>,
+    #[serde(default)]
+    message_args: Vec<JsonValue>,
+    // `Severity` is deprecated in favor of `MessageSeverity` but
+    // older services report only it.
+    message_severity: Option<String>,
+    severity: Option<String>,
+    resolution: Option<String>,
+    #[serde(default)]
+    related_properties: Vec<String>,
+}
+
+impl From<ExtendedInfo> for RedfishMessage {
+    fn from(v: ExtendedInfo) -> Self {
+        let severity = v
+            .message_severity
+            .as_deref()
+            .and_then(MessageSeverity::parse)
+            .or_else(|| v.severity.as_deref().and_then(MessageSeverity::parse));
+        Self {
+            message_id: v.message_id,
+            message: v.message,
+            message_args: v.message_args,
+            severity,
+            resolution: v.resolution,
+            related_properties: v.related_properties,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY: &str = r#"{
+        "error": {
+            "code": "Base.1.8.GeneralError",
+            "message": "A general error has occurred.",
+            "@Message.ExtendedInfo": [
+                {
+                    "MessageId": "Base.1.8.ResourceAlreadyExists",
+                    "Message": "The requested resource already exists.",
+                    "MessageArgs": ["UserName", "admin"],
+                    "MessageSeverity": "Critical",
+                    "Resolution": "Do not repeat the create operation.",
+                    "RelatedProperties": ["/UserName"]
+                },
+                {
+                    "MessageId": "Oem.1.0.AuditTrail",
+                    "Severity": "Warning"
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn error_response_is_parsed() {
+        let error = RedfishError::from_body(BODY).expect("Redfish error");
+        assert_eq!(error.code.as_deref(), Some("Base.1.8.GeneralError"));
+        assert_eq!(error.extended_info.len(), 2);
+        let message = &error.extended_info[0];
+        assert_eq!(message.registry(), Some("Base"));
+        assert_eq!(message.message_key(), Some("ResourceAlreadyExists"));
+        assert_eq!(message.severity, Some(MessageSeverity::Critical));
+        assert_eq!(
+            message.resolution.as_deref(),
+            Some("Do not repeat the create operation.")
+        );
+        assert_eq!(message.related_properties, vec!["/UserName".to_string()]);
+        assert_eq!(
+            error.extended_info[1].severity,
+            Some(MessageSeverity::Warning)
+        );
+        assert_eq!(error.severity(), Some(MessageSeverity::Critical));
+    }
+
+    #[test]
+    fn message_id_ignores_registry_version() {
+        let error = RedfishError::from_body(BODY).expect("Redfish error");
+        assert!(error.has_message("Base.1.0.ResourceAlreadyExists"));
+        assert!(error.has_message("Base.ResourceAlreadyExists"));
+        assert!(error.has_message("ResourceAlreadyExists"));
+        assert!(error.has_message("GeneralError"));
+        assert!(!error.has_message("Oem.1.0.ResourceAlreadyExists"));
+        assert!(!error.has_message("Base.1.0.PropertyMissing"));
+    }
+
+    #[test]
+    fn non_redfish_body_is_not_parsed() {
+        assert_eq!(RedfishError::from_body("Internal Server Error"), None);
+        assert_eq!(RedfishError::from_body(r#"{"Name": "x"}"#), None);
+    }
+}
@@ -58,8 +58,7 @@ impl<R> DataStream<R> {
     }
 }
 
-/// Streamed body accepted by deprecated raw upload methods.
-#[cfg(feature = "update-service-deprecated")]
+/// Streamed body accepted by raw upload methods.
 pub struct UploadStream<R> {
     /// Streamed upload data.
     pub reader: R,
@@ -68,7 +67,6 @@ pub struct UploadStream<R> {
     pub content_length: Option<u64>,
 }
 
-#[cfg(feature = "update-service-deprecated")]
 impl<R> UploadStream<R> {
     /// Create a streamed upload body without a known content length.
     #[must_use]
@@ -195,3 +193,15 @@ pub struct HttpPushUriUpdateRequest<U> {
     /// Timeout used only for this upload request.
     pub upload_timeout: Duration,
 }
+
+/// Raw binary upload request data.
+///
+/// Used for service-provided binary endpoints such as
+/// `AssemblyData.BinaryDataURI`.
+pub struct BinaryUploadRequest<U> {
+    /// Streamed binary data.
+    pub upload_stream: UploadStream<U>,
+
+    /// Timeout used only for this upload request.
+    pub upload_timeout: Duration,
+}
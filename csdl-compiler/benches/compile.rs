@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Instruction-count benchmark of full-schema compilation
+//! (gungraun / Valgrind Callgrind).
+//!
+//! Compiles every schema of the bundled DMTF Redfish and SNIA
+//! Swordfish CSDL from the service root, without entity type
+//! restrictions, and generates
+//! Rust code, like `build.rs` of `nv-redfish` does with all features
+//! enabled. Reading of CSDL files and writing of the generated code
+//! are included, so the result is the cost of one build-time
+//! compilation. Valgrind is unix-only, so the whole benchmark is
+//! `cfg(unix)`.
+
+#[cfg(unix)]
+mod unix {
+    use std::collections::HashSet;
+    use std::env::temp_dir;
+    use std::hint::black_box;
+    use std::path::Path;
+    use std::path::PathBuf;
+
+    use gungraun::library_benchmark;
+    use nv_redfish_csdl_compiler::commands::process_command;
+    use nv_redfish_csdl_compiler::commands::Commands;
+    use nv_redfish_csdl_compiler::commands::OutputFormat;
+    use nv_redfish_csdl_compiler::commands::DEFAULT_ROOT;
+    use nv_redfish_schema::glob_redfish_xml;
+    use nv_redfish_schema::glob_swordfish_xml;
+
+    fn file_name(path: &str) -> Option<&str> {
+        Path::new(path).file_name().and_then(|name| name.to_str())
+    }
+
+    /// All bundled schemas. Swordfish duplicates of Redfish schemas
+    /// are skipped, the Redfish ones take precedence.
+    fn bundled_schemas() -> (Vec<String>, PathBuf) {
+        let mut csdls = glob_redfish_xml();
+        assert!(!csdls.is_empty(), "Redfish CSDL bundle must be checked out");
+        let redfish = csdls
+            .iter()
+            .filter_map(|path| file_name(path))
+            .map(ToOwned::to_owned)
+            .collect::<HashSet<_>>();
+        csdls.extend(
+            glob_swordfish_xml()
+                .into_iter()
+                .filter(|path| file_name(path).is_some_and(|name| !redfish.contains(name))),
+        );
+        (csdls, temp_dir().join("nv-redfish-bench-compile.rs"))
+    }
+
+    #[library_benchmark]
+    #[bench::bundled(bundled_schemas())]
+    fn full_schema((csdls, output): (Vec<String>, PathBuf)) -> Vec<String> {
+        let command = Commands::Compile {
+            root: DEFAULT_ROOT.into(),
+            include_root_patterns: vec![],
+            csdls,
+            output,
+            split: false,
+            format: OutputFormat::Rust,
+            entity_type_patterns: vec![],
+            rigid_array_patterns: vec![],
+            intern_strings: false,
+        };
+        black_box(process_command(&command).expect("bundled schemas compile"))
+    }
+}
+
+#[cfg(unix)]
+use unix::full_schema;
+
+#[cfg(unix)]
+gungraun::library_benchmark_group!(
+    name = compile;
+    benchmarks = full_schema
+);
+
+#[cfg(unix)]
+gungraun::main!(library_benchmark_groups = compile);
+
+#[cfg(not(unix))]
+fn main() {}
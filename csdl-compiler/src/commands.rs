@@ -26,7 +26,12 @@
 //!   `EntityTypeFilter` patterns to limit navigation targets.
 //! - Optimize the compiled set and run the Rust generator.
 //! - Pretty-print the resulting syntax and write it to the `output` path.
+//!   With `--split`, `output` is a directory that receives `mod.rs` and
+//!   one file per top-level namespace module.
+//! - With `--format json`, write JSON descriptors of the compiled
+//!   model to `output` instead of Rust code.
 
+use crate::compiler::Compiled;
 use crate::compiler::Config as CompilerConfig;
 use crate::compiler::EntityTypeFilter;
 use crate::compiler::EntityTypeFilterPattern;
@@ -34,21 +39,36 @@ use crate::compiler::PropertyFilter;
 use crate::compiler::PropertyPattern;
 use crate::compiler::SchemaBundle;
 use crate::edmx::Edmx;
+use crate::generator::json::JsonGenerator;
 use crate::generator::rust::Config as GeneratorConfig;
 use crate::generator::rust::RustGenerator;
 use crate::optimizer::optimize;
 use crate::optimizer::Config as OptimizerConfig;
 use crate::Error;
 use clap::Subcommand;
+use clap::ValueEnum;
+use proc_macro2::TokenStream;
 use std::collections::BTreeMap;
+use std::fs::create_dir_all;
 use std::fs::write;
 use std::fs::File;
 use std::io::Read as _;
+use std::path::Path;
 use std::path::PathBuf;
 
 /// Default root singleton to compile.
 pub const DEFAULT_ROOT: &str = "Service";
 
+/// Format of the compiler output.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Rust code.
+    #[default]
+    Rust,
+    /// JSON descriptors of the compiled model.
+    Json,
+}
+
 /// Compiler high-level commands.
 #[derive(Subcommand, Debug)]
 pub enum Commands {
@@ -76,6 +96,14 @@ pub enum Commands {
         /// Output file for generated code.
         #[arg(short, long, default_value = "redfish.rs")]
         output: PathBuf,
+        /// Treat `output` as a directory: write `mod.rs` and a separate
+        /// file for each top-level namespace module. Applies to Rust
+        /// output only.
+        #[arg(long)]
+        split: bool,
+        /// Format of the output.
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
         /// Patterns of entity types to compile when referenced via a
         /// navigation property. If empty, all entity types are compiled.
         ///
@@ -93,6 +121,11 @@ pub enum Commands {
         /// `EthernetInterface.*.EthernetInterface/StaticNameServers` - matches `StaticNameServers` property of `EthernetInterface`
         #[arg(short = 'a', long = "rigid-arrays")]
         rigid_array_patterns: Vec<PropertyPattern>,
+        /// Generate `Edm.String` as `EdmString` that shares storage of
+        /// equal values. Reduces memory of processes that keep many
+        /// resources.
+        #[arg(long)]
+        intern_strings: bool,
     },
     /// Compile OEM CSDL schemas.
     CompileOem {
@@ -106,6 +139,14 @@ pub enum Commands {
         /// Output file for generated code.
         #[arg(short, long, default_value = "redfish.rs")]
         output: PathBuf,
+        /// Treat `output` as a directory: write `mod.rs` and a separate
+        /// file for each top-level namespace module. Applies to Rust
+        /// output only.
+        #[arg(long)]
+        split: bool,
+        /// Format of the output.
+        #[arg(long, value_enum, default_value_t)]
+        format: OutputFormat,
         /// Patterns of entity types to compile when referenced via a
         /// navigation property. If empty, all entity types are compiled.
         ///
@@ -123,6 +164,11 @@ pub enum Commands {
         /// `EthernetInterface.*.EthernetInterface/StaticNameServers` - matches `StaticNameServers` property of `EthernetInterface`
         #[arg(short = 'a', long = "rigid-arrays")]
         rigid_array_patterns: Vec<PropertyPattern>,
+        /// Generate `Edm.String` as `EdmString` that shares storage of
+        /// equal values. Reduces memory of processes that keep many
+        /// resources.
+        #[arg(long)]
+        intern_strings: bool,
     },
 }
 
@@ -139,8 +185,11 @@ pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
             include_root_patterns,
             csdls,
             output,
+            split,
+            format,
             entity_type_patterns,
             rigid_array_patterns,
+            intern_strings,
         } => {
             let root_service = root.parse().map_err(Error::WrongRootService)?;
             if csdls.is_empty() {
@@ -159,23 +208,32 @@ pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
                     },
                 )
                 .map_err(Error::compile_error)?;
+            let schema_types = compiled.type_names();
             let compiled = optimize(compiled, &OptimizerConfig::default());
-            let generator = RustGenerator::new(compiled, GeneratorConfig::default())
-                .map_err(Error::generate_error)?;
-
-            let result = generator.generate().to_string();
-            let syntax_tree = syn::parse_file(&result).map_err(Error::ParseGenerated)?;
-            write(output, prettyplease::unparse(&syntax_tree))
-                .map_err(|e| Error::WriteOutput(output.clone(), e))?;
-            display_output.push(format!("{} file has been written", output.display()));
+            let config = GeneratorConfig {
+                intern_strings: *intern_strings,
+                ..GeneratorConfig::default()
+            };
+            write_output(
+                compiled,
+                schema_types,
+                output,
+                *split,
+                *format,
+                config,
+                &mut display_output,
+            )?;
             Ok(display_output)
         }
         Commands::CompileOem {
             root_csdls,
             resolve_csdls,
             output,
+            split,
+            format,
             entity_type_patterns,
             rigid_array_patterns,
+            intern_strings,
         } => {
             if root_csdls.is_empty() {
                 return Err(Error::AtLeastOneCSDLFileNeeded);
@@ -189,19 +247,80 @@ pub fn process_command(command: &Commands) -> Result<Vec<String>, Error> {
                     rigid_array_filter: PropertyFilter::new(rigid_array_patterns.clone()),
                 })
                 .map_err(Error::compile_error)?;
+            let schema_types = compiled.type_names();
             let compiled = optimize(compiled, &OptimizerConfig::default());
-            let generator = RustGenerator::new(compiled, GeneratorConfig::default())
-                .map_err(Error::generate_error)?;
-            let result = generator.generate().to_string();
-            let syntax_tree = syn::parse_file(&result).map_err(Error::ParseGenerated)?;
-            write(output, prettyplease::unparse(&syntax_tree))
-                .map_err(|e| Error::WriteOutput(output.clone(), e))?;
-            display_output.push(format!("{} file has been written", output.display()));
+            let config = GeneratorConfig {
+                intern_strings: *intern_strings,
+                ..GeneratorConfig::default()
+            };
+            write_output(
+                compiled,
+                schema_types,
+                output,
+                *split,
+                *format,
+                config,
+                &mut display_output,
+            )?;
             Ok(display_output)
         }
     }
 }
 
+fn write_output(
+    compiled: Compiled<'_>,
+    schema_types: Vec<String>,
+    output: &Path,
+    split: bool,
+    format: OutputFormat,
+    config: GeneratorConfig,
+    display_output: &mut Vec<String>,
+) -> Result<(), Error> {
+    match format {
+        OutputFormat::Rust => {
+            let generator = RustGenerator::new(compiled, config)
+                .map_err(Error::generate_error)?
+                .with_schema_types(schema_types);
+            write_generated(generator, output, split, display_output)
+        }
+        OutputFormat::Json => {
+            let json = JsonGenerator::new(compiled)
+                .generate()
+                .map_err(|err| Error::Generate(vec![err.to_string()]))?;
+            write(output, json).map_err(|e| Error::WriteOutput(output.to_path_buf(), e))?;
+            display_output.push(format!("{} file has been written", output.display()));
+            Ok(())
+        }
+    }
+}
+
+fn write_generated(
+    generator: RustGenerator<'_>,
+    output: &Path,
+    split: bool,
+    display_output: &mut Vec<String>,
+) -> Result<(), Error> {
+    if split {
+        create_dir_all(output).map_err(|e| Error::WriteOutput(output.to_path_buf(), e))?;
+        let (root, files) = generator.generate_split();
+        write_pretty(&output.join("mod.rs"), &root)?;
+        for file in files {
+            write_pretty(&output.join(file.file_name), &file.content)?;
+        }
+        display_output.push(format!("{} directory has been written", output.display()));
+    } else {
+        write_pretty(output, &generator.generate())?;
+        display_output.push(format!("{} file has been written", output.display()));
+    }
+    Ok(())
+}
+
+fn write_pretty(output: &Path, tokens: &TokenStream) -> Result<(), Error> {
+    let syntax_tree = syn::parse_file(&tokens.to_string()).map_err(Error::ParseGenerated)?;
+    write(output, prettyplease::unparse(&syntax_tree))
+        .map_err(|e| Error::WriteOutput(output.to_path_buf(), e))
+}
+
 fn read_csdls(root_csdls: &[String], resolve_csdls: &[String]) -> Result<SchemaBundle, Error> {
     let csdls = root_csdls
         .iter()
@@ -34,9 +34,10 @@ use crate::edmx::ParameterName;
 use crate::redfish::annotations::RedfishAnnotations as _;
 use crate::IsNullable;
 use crate::OneOrCollection;
+use serde::Serialize;
 
 /// Compiled action.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Action<'a> {
     /// Root namespace of the schema that defines the action.
     pub defining_namespace: Namespace<'a>,
@@ -44,7 +44,11 @@ use crate::compiler::QualifiedName;
 use crate::compiler::TypeDefinition;
 use crate::edmx::ActionName;
 use crate::redfish::ExcerptCopy;
+use serde::Serialize;
+use serde::Serializer;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::iter::once as iter_once;
@@ -80,24 +84,73 @@ pub enum ForcedUpdateTag {}
 
 /// Compiled outputs from schemas.
 /// Aggregated compilation outputs for a set of schemas.
-#[derive(Default, Debug)]
+///
+/// `Compiled` is a public API of the compiler: tools other than the
+/// Rust generator (documentation generators, validators, emitters for
+/// other languages) can consume it directly or serialize it with
+/// `serde`. Serialized maps and sets are ordered by key, so the
+/// output is reproducible.
+#[derive(Default, Debug, Serialize)]
 pub struct Compiled<'a> {
     /// Compiled complex types by name.
+    #[serde(serialize_with = "serialize_sorted_map")]
     pub complex_types: HashMap<QualifiedName<'a>, ComplexType<'a>>,
     /// Compiled entity types by name.
+    #[serde(serialize_with = "serialize_sorted_map")]
     pub entity_types: HashMap<QualifiedName<'a>, EntityType<'a>>,
     /// Compiled type definitions by name.
+    #[serde(serialize_with = "serialize_sorted_map")]
     pub type_definitions: HashMap<QualifiedName<'a>, TypeDefinition<'a>>,
     /// Compiled enums by name.
+    #[serde(serialize_with = "serialize_sorted_map")]
     pub enum_types: HashMap<QualifiedName<'a>, EnumType<'a>>,
     /// Actions bound to each type.
+    #[serde(serialize_with = "serialize_sorted_actions")]
     pub actions: TypeActions<'a>,
     /// Entity types whose collections are creatable.
+    #[serde(serialize_with = "serialize_sorted_set")]
     pub creatable_entity_types: HashSet<QualifiedName<'a>>,
     /// Excerpt copies of entity types that need to be generated.
+    #[serde(serialize_with = "serialize_sorted_excerpt_copies")]
     pub excerpt_copies: ExcerptCopiesMap<'a>,
 }
 
+fn serialize_sorted_map<K: Ord + Serialize, V: Serialize, S: Serializer>(
+    map: &HashMap<K, V>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+fn serialize_sorted_set<T: Ord + Serialize, S: Serializer>(
+    set: &HashSet<T>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    set.iter().collect::<BTreeSet<_>>().serialize(serializer)
+}
+
+fn serialize_sorted_actions<S: Serializer>(
+    actions: &TypeActions<'_>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    actions
+        .iter()
+        .map(|(qname, actions)| (qname, actions.iter().collect::<BTreeMap<_, _>>()))
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
+fn serialize_sorted_excerpt_copies<S: Serializer>(
+    copies: &ExcerptCopiesMap<'_>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    copies
+        .iter()
+        .map(|(qname, copies)| (qname, copies.iter().collect::<BTreeSet<_>>()))
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
 impl<'a> Compiled<'a> {
     /// Create a compiled structure containing a single compiled
     /// entity type.
@@ -230,6 +283,22 @@ impl<'a> Compiled<'a> {
         );
         forced
     }
+
+    /// Qualified names (`Namespace.Name`, namespace includes version)
+    /// of compiled entity and complex types in sorted order.
+    ///
+    /// Should be collected before optimization because optimizer
+    /// merges versions of the same type.
+    #[must_use]
+    pub fn type_names(&self) -> Vec<String> {
+        self.entity_types
+            .keys()
+            .chain(self.complex_types.keys())
+            .map(ToString::to_string)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
 }
 
 /// Walk each updating type's base chain and record its ancestors in
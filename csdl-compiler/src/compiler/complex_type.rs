@@ -31,9 +31,10 @@ use crate::compiler::TypeInfo;
 use crate::edmx::ComplexType as EdmxComplexType;
 use crate::odata::annotations::Permissions;
 use crate::IsAbstract;
+use serde::Serialize;
 
 /// Compiled complex type.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct ComplexType<'a> {
     /// Fully qualified type name.
     pub name: QualifiedName<'a>,
@@ -27,10 +27,12 @@ use crate::compiler::QualifiedName;
 use crate::compiler::Stack;
 use crate::edmx::entity_type::Key;
 use crate::edmx::EntityType as EdmxEntityType;
+use crate::redfish::annotations::RedfishAnnotations as _;
 use crate::IsAbstract;
+use serde::Serialize;
 
 /// Compiled entity type.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct EntityType<'a> {
     /// Fully qualified type name.
     pub name: QualifiedName<'a>,
@@ -44,6 +46,8 @@ pub struct EntityType<'a> {
     pub odata: OData<'a>,
     /// Whether the type is abstract.
     pub is_abstract: IsAbstract,
+    /// URIs of the resource defined by `Redfish.Uris` annotation.
+    pub uris: &'a [String],
 }
 
 impl<'a> EntityType<'a> {
@@ -87,6 +91,7 @@ impl<'a> EntityType<'a> {
             properties,
             odata: OData::new(MustHaveId::new(true), schema_entity_type),
             is_abstract: schema_entity_type.is_abstract,
+            uris: schema_entity_type.uris(),
         };
         Ok(stack
             .merge(compiled)
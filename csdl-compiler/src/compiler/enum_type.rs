@@ -22,9 +22,10 @@ use crate::edmx::EnumMember as EdmxEnumMember;
 use crate::edmx::EnumMemberName;
 use crate::edmx::EnumType as EdmxEnumType;
 use crate::edmx::EnumUnderlyingType;
+use serde::Serialize;
 
 /// Compiled enumeration type.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct EnumType<'a> {
     /// Fully-qualified type name.
     pub name: QualifiedName<'a>,
@@ -36,7 +37,7 @@ pub struct EnumType<'a> {
     pub odata: OData<'a>,
 }
 /// Compiled member of an enum type.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct EnumMember<'a> {
     /// Name of the member.
     pub name: &'a EnumMemberName,
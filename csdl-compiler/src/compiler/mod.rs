@@ -48,6 +48,10 @@
 //!   types, complex types, enums, type definitions, and actions. It is
 //!   designed to be stable, readable, and straightforward for the
 //!   generator to consume.
+//! - `Compiled` implements `serde::Serialize`, so tools other than
+//!   the Rust generator (documentation generators, validators,
+//!   emitters for other languages) can consume the compiled model
+//!   without invoking the generator.
 
 #![deny(missing_docs)]
 
@@ -94,6 +98,10 @@ pub use compiled::ActionsMap;
 #[doc(inline)]
 pub use compiled::Compiled;
 #[doc(inline)]
+pub use compiled::ExcerptCopiesMap;
+#[doc(inline)]
+pub use compiled::ExcerptCopiesSet;
+#[doc(inline)]
 pub use compiled::ForcedUpdate;
 
 pub use compiled::IsCreatable;
@@ -116,12 +124,16 @@ pub use context::PropertyPattern;
 #[doc(inline)]
 pub use entity_type::EntityType;
 #[doc(inline)]
+pub use enum_type::EnumMember;
+#[doc(inline)]
 pub use enum_type::EnumType;
 #[doc(inline)]
 pub use error::Error;
 #[doc(inline)]
 pub use namespace::Namespace;
 #[doc(inline)]
+pub use namespace::SchemaVersion;
+#[doc(inline)]
 pub use odata::OData;
 #[doc(inline)]
 pub use parameter::Parameter;
@@ -146,6 +158,8 @@ pub use qualified_name::QualifiedName;
 #[doc(inline)]
 pub use redfish::Redfish;
 #[doc(inline)]
+pub use redfish::RedfishProperty;
+#[doc(inline)]
 pub use type_definition::TypeDefinition;
 
 // Trait re-exports
@@ -163,6 +177,7 @@ use crate::edmx::Schema;
 use crate::edmx::SimpleIdentifier;
 use crate::edmx::Type;
 use schema_index::SchemaIndex;
+use serde::Serialize;
 use stack::Stack;
 use tagged_types::TaggedType;
 
@@ -177,12 +192,12 @@ pub type RigidArraySupport = TaggedType<bool, RigidArraySupportTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Copy, Clone)]
-#[transparent(Debug, Deserialize)]
+#[transparent(Debug, Deserialize, Serialize)]
 #[capability(inner_access)]
 pub enum RigidArraySupportTag {}
 
 /// Type class for property attributes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum TypeClass {
     /// Simple type like `Edm.String`, `Edm.Int64` etc.
     SimpleType,
@@ -599,4 +614,59 @@ mod test {
             &"The version of the Redfish service."
         );
     }
+
+    #[test]
+    fn compiled_model_serializes() {
+        let schema = r#"<edmx:Edmx Version="4.0">
+             <edmx:DataServices>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+                 <EntityType Name="ItemOrCollection" Abstract="true"/>
+                 <EntityType Name="Item" BaseType="Resource.ItemOrCollection" Abstract="true"/>
+                 <EntityType Name="Resource" BaseType="Resource.Item" Abstract="true"/>
+                 <EntityType Name="ResourceCollection" BaseType="Resource.ItemOrCollection" Abstract="true"/>
+               </Schema>
+               <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Widget.v1_0_0">
+                 <EntityType Name="Widget">
+                   <Key><PropertyRef Name="Id"/></Key>
+                   <Property Name="Id" Type="Edm.String" Nullable="false"/>
+                   <Property Name="State" Type="Widget.v1_0_0.State">
+                     <Annotation Term="OData.Description" String="The state of the widget."/>
+                   </Property>
+                 </EntityType>
+                 <EnumType Name="State">
+                   <Member Name="Enabled"/>
+                   <Member Name="Disabled"/>
+                 </EnumType>
+               </Schema>
+               <Schema Namespace="Settings">
+                 <ComplexType Name="Settings"/>
+                 <ComplexType Name="PreferredApplyTime"/>
+               </Schema>
+             </edmx:DataServices>
+           </edmx:Edmx>"#;
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(schema).unwrap()],
+            root_set_threshold: None,
+        };
+        let compiled = bundle.compile_all(Config::default()).unwrap();
+        let value = serde_json::to_value(&compiled).unwrap();
+
+        let widget = &value["entity_types"]["Widget.v1_0_0.Widget"];
+        assert_eq!(widget["name"], "Widget.v1_0_0.Widget");
+        assert_eq!(widget["key"], serde_json::json!(["Id"]));
+        let state = &widget["properties"]["properties"][1];
+        assert_eq!(state["name"], "State");
+        assert_eq!(state["ptype"]["One"][1], "Widget.v1_0_0.State");
+        assert_eq!(state["odata"]["description"], "The state of the widget.");
+        assert_eq!(
+            value["enum_types"]["Widget.v1_0_0.State"]["members"][1]["name"],
+            "Disabled"
+        );
+        let recompiled = bundle.compile_all(Config::default()).unwrap();
+        assert_eq!(
+            serde_json::to_string(&compiled).unwrap(),
+            serde_json::to_string(&recompiled).unwrap(),
+            "serialized maps must be ordered"
+        );
+    }
 }
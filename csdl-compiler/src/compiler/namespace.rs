@@ -15,6 +15,8 @@
 
 use crate::edmx::Namespace as EdmxNamespace;
 use crate::edmx::SimpleIdentifier;
+use serde::Serialize;
+use serde::Serializer;
 use std::cmp::Ordering;
 use std::fmt::Debug;
 use std::fmt::Display;
@@ -91,6 +93,17 @@ impl<'a> Namespace<'a> {
         }
     }
 
+    /// Version of the namespace if its last identifier is a schema
+    /// version (e.g. `v1_5_0` in `Processor.v1_5_0`).
+    #[must_use]
+    pub fn version(&self) -> Option<SchemaVersion> {
+        if self.len < 2 {
+            return None;
+        }
+        self.get_id(self.len - 1)
+            .and_then(|id| SchemaVersion::parse(id.inner()))
+    }
+
     /// Whether this namespace is `Edm`.
     #[must_use]
     pub fn is_edm(&self) -> bool {
@@ -98,6 +111,44 @@ impl<'a> Namespace<'a> {
     }
 }
 
+/// Version of a versioned schema namespace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct SchemaVersion {
+    /// Major version.
+    pub major: u16,
+    /// Minor version.
+    pub minor: u16,
+    /// Errata version.
+    pub errata: u16,
+}
+
+impl SchemaVersion {
+    /// Initial version of a schema (`v1_0_0`).
+    pub const INITIAL: Self = Self {
+        major: 1,
+        minor: 0,
+        errata: 0,
+    };
+
+    /// Parse version in namespace format (`v1_5_0`).
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts = s.strip_prefix('v')?.split('_').map(str::parse::<u16>);
+        let version = Self {
+            major: parts.next()?.ok()?,
+            minor: parts.next()?.ok()?,
+            errata: parts.next()?.ok()?,
+        };
+        parts.next().is_none().then_some(version)
+    }
+}
+
+impl Display for SchemaVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "v{}_{}_{}", self.major, self.minor, self.errata)
+    }
+}
+
 impl PartialEq for Namespace<'_> {
     fn eq(&self, other: &Self) -> bool {
         self.len == other.len && self.edmx_ns.ids[..self.len] == other.edmx_ns.ids[..self.len]
@@ -137,6 +188,12 @@ impl Display for Namespace<'_> {
     }
 }
 
+impl Serialize for Namespace<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 impl Debug for Namespace<'_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         Display::fmt(self, f)
@@ -146,6 +203,7 @@ impl Debug for Namespace<'_> {
 #[cfg(test)]
 mod tests {
     use super::Namespace;
+    use super::SchemaVersion;
     use crate::edmx::Namespace as EdmxNamespace;
     use std::str::FromStr as _;
 
@@ -160,4 +218,22 @@ mod tests {
         // Truncating beyond the length is a no-op, not a panic.
         assert_eq!(ns.truncated(9).to_string(), "NvidiaPortMetrics.v1_6_0");
     }
+
+    #[test]
+    fn version_of_versioned_namespace() {
+        let edmx = EdmxNamespace::from_str("Processor.v1_15_2").expect("valid namespace");
+        let ns = Namespace::new(&edmx);
+
+        assert_eq!(
+            ns.version(),
+            Some(SchemaVersion {
+                major: 1,
+                minor: 15,
+                errata: 2
+            })
+        );
+        assert_eq!(ns.root().version(), None);
+        assert!(SchemaVersion::parse("v1_2_0") < SchemaVersion::parse("v1_15_0"));
+        assert_eq!(SchemaVersion::parse("v1_2"), None);
+    }
 }
@@ -23,6 +23,7 @@ use crate::odata::annotations::LongDescriptionRef;
 use crate::odata::annotations::ODataAnnotations;
 use crate::odata::annotations::Permissions;
 use crate::odata::annotations::Updatable;
+use serde::Serialize;
 use tagged_types::TaggedType;
 
 /// Whether the type must include `@odata.id` in generated code.
@@ -30,7 +31,7 @@ pub type MustHaveId = TaggedType<bool, MustHaveIdTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Debug)]
+#[transparent(Debug, Serialize)]
 #[capability(inner_access)]
 pub enum MustHaveIdTag {}
 
@@ -39,12 +40,12 @@ pub type MustHaveType = TaggedType<bool, MustHaveTypeTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Debug)]
+#[transparent(Debug, Serialize)]
 #[capability(inner_access)]
 pub enum MustHaveTypeTag {}
 
 /// `OData` attributes attached to compiled entities.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct OData<'a> {
     /// Whether `@odata.id` must be present.
     pub must_have_id: MustHaveId,
@@ -21,9 +21,10 @@ use crate::compiler::QualifiedName;
 use crate::edmx::ParameterName;
 use crate::IsNullable;
 use crate::IsRequired;
+use serde::Serialize;
 
 /// Compiled action parameter.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct Parameter<'a> {
     /// Name of the parameter.
     pub name: &'a ParameterName,
@@ -39,7 +40,7 @@ pub struct Parameter<'a> {
 
 /// Parameter type. Reuses `CompiledPropertyType`; this may not be an
 /// exact match and could evolve in the future.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub enum ParameterType<'a> {
     /// Entity parameter (navigation target).
     Entity(NavPropertyType<'a>),
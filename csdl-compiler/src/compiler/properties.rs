@@ -25,6 +25,7 @@ use crate::compiler::MustHaveId;
 use crate::compiler::OData;
 use crate::compiler::QualifiedName;
 use crate::compiler::RigidArraySupport;
+use crate::compiler::SchemaVersion;
 use crate::compiler::Stack;
 use crate::compiler::TypeClass;
 use crate::edmx::property::Property as EdmxProperty;
@@ -34,9 +35,10 @@ use crate::edmx::PropertyName;
 use crate::odata::annotations::Permissions;
 use crate::IsNullable;
 use crate::OneOrCollection;
+use serde::Serialize;
 
 /// Combined structural and navigation properties.
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Serialize)]
 pub struct Properties<'a> {
     /// Structural properties.
     pub properties: Vec<Property<'a>>,
@@ -79,11 +81,12 @@ impl<'a> Properties<'a> {
                             rigid_array_support: RigidArraySupport::new(
                                 ctx.config.rigid_array_filter.matches(qtype, &v.name),
                             ),
+                            since: qtype.namespace.version(),
                         });
                         stack.merge(compiled)
                     }
                     PropertyAttrs::NavigationProperty(v) => {
-                        let compiled = Self::compile_nav_property(&mut p, v, ctx, &stack)
+                        let compiled = Self::compile_nav_property(&mut p, qtype, v, ctx, &stack)
                             .map_err(Box::new)
                             .map_err(|e| Error::Property(&sp.name, e))?;
                         stack.merge(compiled)
@@ -117,6 +120,7 @@ impl<'a> Properties<'a> {
 
     fn compile_nav_property(
         p: &mut Self,
+        owner: QualifiedName<'_>,
         v: &'a EdmxNavigationProperty,
         ctx: &Context<'a>,
         stack: &Stack<'a, '_>,
@@ -151,6 +155,7 @@ impl<'a> Properties<'a> {
                     odata: OData::new(MustHaveId::new(false), v),
                     redfish,
                     nullable: v.nullable.unwrap_or(IsNullable::new(false)),
+                    since: owner.namespace.version(),
                 }));
             Ok(compiled)
         } else {
@@ -166,7 +171,7 @@ impl<'a> Properties<'a> {
 }
 
 /// Additional type information used by properties.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize)]
 pub struct TypeInfo {
     /// Class of the type.
     pub class: TypeClass,
@@ -275,7 +280,7 @@ impl<'a> PropertyType<'a> {
 }
 
 /// Structural property.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Property<'a> {
     /// Property identifier.
     pub name: &'a PropertyName,
@@ -293,6 +298,9 @@ pub struct Property<'a> {
     /// practice only handful of properties used as rigid by BMC
     /// implementors. This flag defines
     pub rigid_array_support: RigidArraySupport,
+    /// Version of the schema that introduced the property. `None` if
+    /// the property is defined in unversioned namespace.
+    pub since: Option<SchemaVersion>,
 }
 
 impl<'a> MapType<'a> for Property<'a> {
@@ -317,7 +325,8 @@ impl<'a> NavPropertyType<'a> {
 }
 
 /// Navigation property, either expandable or reference.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[allow(clippy::large_enum_variant)] // Variant is matched by value in public API.
 pub enum NavProperty<'a> {
     /// Expandable navigation property (with known type).
     Expandable(NavPropertyExpandable<'a>),
@@ -337,7 +346,7 @@ impl<'a> NavProperty<'a> {
 }
 
 /// Expandable navigation property details.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct NavPropertyExpandable<'a> {
     /// Property identifier.
     pub name: &'a PropertyName,
@@ -349,6 +358,9 @@ pub struct NavPropertyExpandable<'a> {
     pub redfish: RedfishProperty,
     /// Whether the property is nullable.
     pub nullable: IsNullable,
+    /// Version of the schema that introduced the property. `None` if
+    /// the property is defined in unversioned namespace.
+    pub since: Option<SchemaVersion>,
 }
 
 impl<'a> MapType<'a> for NavProperty<'a> {
@@ -17,6 +17,8 @@ use crate::compiler::Namespace;
 use crate::edmx::Namespace as EdmxNamespace;
 use crate::edmx::QualifiedTypeName;
 use crate::edmx::SimpleIdentifier;
+use serde::Serialize;
+use serde::Serializer;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -55,3 +57,11 @@ impl Display for QualifiedName<'_> {
         write!(f, "{}.{}", self.namespace, self.name)
     }
 }
+
+/// Serialized as a string in `Namespace.Name` form, so it can be
+/// used as a key of serialized maps.
+impl Serialize for QualifiedName<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
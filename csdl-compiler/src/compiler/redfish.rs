@@ -15,16 +15,19 @@
 
 //! Redfish-specific attributes used during code generation.
 
+use crate::compiler::SchemaVersion;
 use crate::redfish::annotations::RedfishAnnotations;
 use crate::redfish::DynamicProperties;
 use crate::redfish::Excerpt;
 use crate::redfish::ExcerptCopy;
+use crate::redfish::RevisionKind;
 use crate::IsExcerptCopyOnly;
 use crate::IsRequired;
 use crate::IsRequiredOnCreate;
+use serde::Serialize;
 
 /// Redfish property attributes attached to compiled entities.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct RedfishProperty {
     /// Whether the property is required.
     pub is_required: IsRequired,
@@ -36,24 +39,39 @@ pub struct RedfishProperty {
     pub excerpt: Option<Excerpt>,
     /// Property is excerpt copy of the resource.
     pub excerpt_copy: Option<ExcerptCopy>,
+    /// Version of the schema that added the property according to
+    /// `Redfish.Revisions` annotation.
+    pub added: Option<SchemaVersion>,
+    /// Version of the schema that deprecated the property according
+    /// to `Redfish.Revisions` annotation.
+    pub deprecated: Option<SchemaVersion>,
 }
 
 impl RedfishProperty {
     /// Create a new instance from an object that provides Redfish
     /// property annotations.
     pub fn new(src: &impl RedfishAnnotations) -> Self {
+        let revisions = src.revisions();
+        let revision = |kind| {
+            revisions
+                .iter()
+                .filter(|r| r.kind == kind)
+                .find_map(|r| SchemaVersion::parse(r.version))
+        };
         Self {
             is_required: src.is_required(),
             is_required_on_create: src.is_required_on_create(),
             is_excerpt_only: src.is_excerpt_only(),
             excerpt: src.excerpt(),
             excerpt_copy: src.excerpt_copy(),
+            added: revision(RevisionKind::Added),
+            deprecated: revision(RevisionKind::Deprecated),
         }
     }
 }
 
 /// Redfish attributes attached to types.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct Redfish<'a> {
     /// Dynamic properties defined for the type.
     pub dynamic_properties: Option<DynamicProperties<'a>>,
@@ -19,9 +19,10 @@ use crate::compiler::Error;
 use crate::compiler::QualifiedName;
 use crate::compiler::TypeInfo;
 use crate::edmx::TypeDefinition as EdmxTypeDefinition;
+use serde::Serialize;
 
 /// Compiled type definition.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct TypeDefinition<'a> {
     /// Fully qualified type name.
     pub name: QualifiedName<'a>,
@@ -82,6 +82,8 @@ pub struct PropertyValue {
     pub string_value: Option<String>,
     #[serde(rename = "@Int")]
     pub int_value: Option<i64>,
+    #[serde(rename = "@EnumMember")]
+    pub enum_member: Option<Box<AnnotationEnumMember>>,
 }
 
 #[derive(Debug)]
@@ -21,6 +21,8 @@ use serde::de::Error as DeError;
 use serde::de::Visitor;
 use serde::Deserialize;
 use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -80,6 +82,12 @@ impl Display for Namespace {
     }
 }
 
+impl Serialize for Namespace {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 impl<'de> Deserialize<'de> for Namespace {
     fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
         struct NsVisitor {}
@@ -99,7 +107,8 @@ impl<'de> Deserialize<'de> for Namespace {
 }
 
 /// 17.2 `SimpleIdentifier`
-#[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize)]
+#[serde(transparent)]
 pub struct SimpleIdentifier(String);
 
 impl SimpleIdentifier {
@@ -23,6 +23,8 @@ use crate::edmx::StructuralProperty;
 use crate::edmx::ValidateError;
 use crate::IsAbstract;
 use serde::Deserialize;
+use serde::Serialize;
+use serde::Serializer;
 
 /// 8.1 Element edm:EntityType
 #[derive(Debug, Deserialize)]
@@ -55,6 +57,13 @@ pub struct Key {
     pub property_ref: Vec<PropertyRef>,
 }
 
+/// Key is serialized as a list of names of key properties.
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.property_ref.iter().map(|v| &v.name))
+    }
+}
+
 /// 8.3 Element edm:PropertyRef
 #[derive(Debug, Deserialize)]
 pub struct PropertyRef {
@@ -23,6 +23,7 @@ use serde::de::Error as DeError;
 use serde::de::Visitor;
 use serde::Deserialize;
 use serde::Deserializer;
+use serde::Serialize;
 use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -32,7 +33,7 @@ use tagged_types::TaggedType;
 pub type EnumMemberName = TaggedType<SimpleIdentifier, EnumMemberNameTag>;
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Eq, PartialEq)]
-#[transparent(Deserialize, FromStr, Debug, Display)]
+#[transparent(Deserialize, FromStr, Debug, Display, Serialize)]
 #[capability(inner_access)]
 pub enum EnumMemberNameTag {}
 
@@ -126,7 +127,7 @@ impl Display for Error {
 }
 
 /// 10.1.2 Attribute `UnderlyingType`
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, Serialize)]
 pub enum EnumUnderlyingType {
     Byte,
     SByte,
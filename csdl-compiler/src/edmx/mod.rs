@@ -127,7 +127,7 @@ pub type ActionName = TaggedType<String, ActionNameTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[transparent(Debug, Display, Deserialize)]
+#[transparent(Debug, Display, Deserialize, Serialize)]
 #[capability(inner_access)]
 pub enum ActionNameTag {}
 
@@ -136,7 +136,7 @@ pub type PropertyName = TaggedType<SimpleIdentifier, PropertyNameTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[transparent(Debug, Display, Deserialize, FromStr)]
+#[transparent(Debug, Display, Deserialize, FromStr, Serialize)]
 #[capability(inner_access)]
 pub enum PropertyNameTag {}
 
@@ -146,7 +146,7 @@ pub type ParameterName = TaggedType<SimpleIdentifier, ParameterNameTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[transparent(Debug, Display, Deserialize)]
+#[transparent(Debug, Display, Deserialize, Serialize)]
 #[capability(inner_access)]
 pub enum ParameterNameTag {}
 
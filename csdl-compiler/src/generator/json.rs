@@ -0,0 +1,623 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! JSON descriptors of compiled structures.
+//!
+//! Alternative generator backend for consumers that are not Rust
+//! code, for example, front-end dashboards built alongside Rust
+//! services. Descriptors are produced from the same compiled model as
+//! the Rust code, so both sides share a single source of schema truth.
+//!
+//! Output is a single JSON document with types keyed by their
+//! qualified names:
+//!
+//! ```json
+//! {
+//!   "entityTypes": { "Chassis.v1_25_0.Chassis": { "properties": [ ... ] } },
+//!   "complexTypes": { ... },
+//!   "enumTypes": { ... },
+//!   "typeDefinitions": { ... }
+//! }
+//! ```
+//!
+//! Keys of all objects are ordered, so the output is reproducible.
+
+use crate::compiler::Action;
+use crate::compiler::Compiled;
+use crate::compiler::ComplexType;
+use crate::compiler::EntityType;
+use crate::compiler::EnumMember;
+use crate::compiler::EnumType;
+use crate::compiler::NavProperty;
+use crate::compiler::OData;
+use crate::compiler::Parameter;
+use crate::compiler::ParameterType;
+use crate::compiler::Properties;
+use crate::compiler::Property;
+use crate::compiler::QualifiedName;
+use crate::compiler::RedfishProperty;
+use crate::compiler::SchemaVersion;
+use crate::compiler::TypeClass;
+use crate::edmx::ActionName;
+use crate::edmx::EnumMemberName;
+use crate::edmx::ParameterName;
+use crate::edmx::PropertyName;
+use crate::odata::annotations::Permissions;
+use crate::OneOrCollection;
+use serde::Serialize;
+use serde_json::Error as JsonError;
+use std::collections::BTreeMap;
+
+/// JSON descriptors generator.
+pub struct JsonGenerator<'a> {
+    compiled: Compiled<'a>,
+}
+
+impl<'a> JsonGenerator<'a> {
+    /// Create new JSON descriptors generator.
+    #[must_use]
+    pub const fn new(compiled: Compiled<'a>) -> Self {
+        Self { compiled }
+    }
+
+    /// Descriptors of all compiled types.
+    #[must_use]
+    pub fn descriptors(&self) -> Descriptors<'_> {
+        let compiled = &self.compiled;
+        Descriptors {
+            entity_types: compiled
+                .entity_types
+                .iter()
+                .map(|(name, v)| (*name, TypeDescriptor::entity_type(v, compiled)))
+                .collect(),
+            complex_types: compiled
+                .complex_types
+                .iter()
+                .map(|(name, v)| (*name, TypeDescriptor::complex_type(v, compiled)))
+                .collect(),
+            enum_types: compiled
+                .enum_types
+                .iter()
+                .map(|(name, v)| (*name, EnumDescriptor::new(v)))
+                .collect(),
+            type_definitions: compiled
+                .type_definitions
+                .iter()
+                .map(|(name, v)| {
+                    (
+                        *name,
+                        TypeDefinitionDescriptor {
+                            underlying_type: v.underlying_type,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Generate pretty-printed JSON document with descriptors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if descriptors cannot be serialized.
+    pub fn generate(&self) -> Result<String, JsonError> {
+        serde_json::to_string_pretty(&self.descriptors())
+    }
+}
+
+/// Descriptors of all compiled types.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Descriptors<'a> {
+    /// Entity types by name.
+    pub entity_types: BTreeMap<QualifiedName<'a>, TypeDescriptor<'a>>,
+    /// Complex types by name.
+    pub complex_types: BTreeMap<QualifiedName<'a>, TypeDescriptor<'a>>,
+    /// Enum types by name.
+    pub enum_types: BTreeMap<QualifiedName<'a>, EnumDescriptor<'a>>,
+    /// Type definitions by name.
+    pub type_definitions: BTreeMap<QualifiedName<'a>, TypeDefinitionDescriptor<'a>>,
+}
+
+/// Descriptor of entity or complex type.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeDescriptor<'a> {
+    /// Base type.
+    pub base: Option<QualifiedName<'a>>,
+    /// Whether the type is abstract.
+    #[serde(rename = "abstract")]
+    pub is_abstract: bool,
+    /// Description of the type.
+    pub description: Option<&'a str>,
+    /// Long description of the type.
+    pub long_description: Option<&'a str>,
+    /// Properties declared by the type. Properties of base types
+    /// are described by the base type descriptors.
+    pub properties: Vec<PropertyDescriptor<'a>>,
+    /// Capabilities of the entity type. Not present for complex
+    /// types.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<Capabilities>,
+    /// Actions bound to the type ordered by name. In Redfish,
+    /// actions are bound to the `Actions` complex type of a resource.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<ActionDescriptor<'a>>,
+}
+
+impl<'a> TypeDescriptor<'a> {
+    fn entity_type(v: &'a EntityType<'a>, compiled: &'a Compiled<'a>) -> Self {
+        Self {
+            base: v.base,
+            is_abstract: v.is_abstract.into_inner(),
+            description: description(&v.odata),
+            long_description: long_description(&v.odata),
+            properties: properties(&v.properties),
+            capabilities: Some(Capabilities {
+                creatable: compiled.creatable_entity_types.contains(&v.name),
+                updatable: v.odata.updatable.is_some_and(|v| v.inner().value),
+                deletable: v.odata.deletable.is_some_and(|v| v.inner().value),
+            }),
+            actions: actions(v.name, compiled),
+        }
+    }
+
+    fn complex_type(v: &'a ComplexType<'a>, compiled: &'a Compiled<'a>) -> Self {
+        Self {
+            base: v.base,
+            is_abstract: v.is_abstract.into_inner(),
+            description: description(&v.odata),
+            long_description: long_description(&v.odata),
+            properties: properties(&v.properties),
+            capabilities: None,
+            actions: actions(v.name, compiled),
+        }
+    }
+}
+
+/// Capabilities of entity type.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    /// Entities of this type can be created in collections.
+    pub creatable: bool,
+    /// Entity can be updated.
+    pub updatable: bool,
+    /// Entity can be deleted.
+    pub deletable: bool,
+}
+
+/// Kind of property or parameter type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyKind {
+    /// Primitive type (`Edm.String`, `Edm.Int64` etc).
+    Primitive,
+    /// Enum type.
+    Enum,
+    /// Type definition.
+    TypeDefinition,
+    /// Complex type.
+    Complex,
+    /// Navigation property to entity type.
+    Navigation,
+    /// Navigation property which target type is not compiled. Only
+    /// name of such property is known.
+    Reference,
+}
+
+impl From<TypeClass> for PropertyKind {
+    fn from(v: TypeClass) -> Self {
+        match v {
+            TypeClass::SimpleType => Self::Primitive,
+            TypeClass::EnumType => Self::Enum,
+            TypeClass::TypeDefinition => Self::TypeDefinition,
+            TypeClass::ComplexType => Self::Complex,
+        }
+    }
+}
+
+/// Descriptor of structural or navigation property.
+#[allow(clippy::struct_excessive_bools)] // Flags of the schema property.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyDescriptor<'a> {
+    /// Name of the property as it appears in payload.
+    pub name: &'a PropertyName,
+    /// Kind of the property type.
+    pub kind: PropertyKind,
+    /// Type of the property. Not present for references.
+    #[serde(rename = "type")]
+    pub ptype: Option<QualifiedName<'a>>,
+    /// Property is a collection of `ptype` values.
+    pub collection: bool,
+    /// Property can be `null`.
+    pub nullable: bool,
+    /// Property is required to be provided by the service.
+    pub required: bool,
+    /// Property is required when an object is created.
+    pub required_on_create: bool,
+    /// Property can be updated by the client.
+    pub writable: bool,
+    /// Property is never returned by the service.
+    pub write_only: bool,
+    /// Description of the property.
+    pub description: Option<&'a str>,
+    /// Long description of the property.
+    pub long_description: Option<&'a str>,
+    /// Version of the schema that added the property (`v1_5_0`).
+    pub since: Option<String>,
+    /// Version of the schema that deprecated the property.
+    pub deprecated: Option<String>,
+}
+
+impl<'a> PropertyDescriptor<'a> {
+    fn property(p: &'a Property<'a>) -> Self {
+        let (typeinfo, ptype) = p.ptype.inner();
+        Self {
+            name: p.name,
+            kind: typeinfo.class.into(),
+            ptype: Some(*ptype),
+            collection: is_collection(&p.ptype),
+            nullable: p.nullable.into_inner(),
+            required: p.redfish.is_required.into_inner(),
+            required_on_create: p.redfish.is_required_on_create.into_inner(),
+            writable: p.odata.permissions_is_write()
+                && typeinfo.permissions.is_none_or(|v| v != Permissions::Read),
+            write_only: p.odata.permissions_is_write_only(),
+            description: description(&p.odata),
+            long_description: long_description(&p.odata),
+            since: since(&p.redfish, p.since),
+            deprecated: p.redfish.deprecated.map(|v| v.to_string()),
+        }
+    }
+
+    fn nav_property(p: &'a NavProperty<'a>) -> Self {
+        match p {
+            NavProperty::Expandable(p) => Self {
+                name: p.name,
+                kind: PropertyKind::Navigation,
+                ptype: Some(p.ptype.name()),
+                collection: is_collection(&p.ptype),
+                nullable: p.nullable.into_inner(),
+                required: p.redfish.is_required.into_inner(),
+                required_on_create: p.redfish.is_required_on_create.into_inner(),
+                writable: p.odata.permissions_is_write(),
+                write_only: p.odata.permissions_is_write_only(),
+                description: description(&p.odata),
+                long_description: long_description(&p.odata),
+                since: since(&p.redfish, p.since),
+                deprecated: p.redfish.deprecated.map(|v| v.to_string()),
+            },
+            NavProperty::Reference(name) => Self {
+                name: name.inner(),
+                kind: PropertyKind::Reference,
+                ptype: None,
+                collection: is_collection(name),
+                nullable: true,
+                required: false,
+                required_on_create: false,
+                writable: false,
+                write_only: false,
+                description: None,
+                long_description: None,
+                since: None,
+                deprecated: None,
+            },
+        }
+    }
+}
+
+/// Descriptor of action bound to entity type.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionDescriptor<'a> {
+    /// Name of the action.
+    pub name: &'a ActionName,
+    /// Parameters of the action (except binding parameter).
+    pub parameters: Vec<ParameterDescriptor<'a>>,
+    /// Type of the return value.
+    pub return_type: Option<ReturnTypeDescriptor<'a>>,
+    /// Description of the action.
+    pub description: Option<&'a str>,
+    /// Long description of the action.
+    pub long_description: Option<&'a str>,
+}
+
+impl<'a> ActionDescriptor<'a> {
+    fn new(v: &'a Action<'a>) -> Self {
+        Self {
+            name: v.name,
+            parameters: v.parameters.iter().map(ParameterDescriptor::new).collect(),
+            return_type: v.return_type.as_ref().map(|v| ReturnTypeDescriptor {
+                rtype: *v.inner(),
+                collection: is_collection(v),
+            }),
+            description: description(&v.odata),
+            long_description: long_description(&v.odata),
+        }
+    }
+}
+
+/// Descriptor of action parameter.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParameterDescriptor<'a> {
+    /// Name of the parameter.
+    pub name: &'a ParameterName,
+    /// Kind of the parameter type.
+    pub kind: PropertyKind,
+    /// Type of the parameter.
+    #[serde(rename = "type")]
+    pub ptype: QualifiedName<'a>,
+    /// Parameter is a collection of `ptype` values.
+    pub collection: bool,
+    /// Parameter can be `null`.
+    pub nullable: bool,
+    /// Parameter is required.
+    pub required: bool,
+    /// Description of the parameter.
+    pub description: Option<&'a str>,
+}
+
+impl<'a> ParameterDescriptor<'a> {
+    fn new(v: &'a Parameter<'a>) -> Self {
+        let (kind, ptype, collection) = match &v.ptype {
+            ParameterType::Entity(t) => (PropertyKind::Navigation, t.name(), is_collection(t)),
+            ParameterType::Type(t) => (t.inner().0.class.into(), t.name(), is_collection(t)),
+        };
+        Self {
+            name: v.name,
+            kind,
+            ptype,
+            collection,
+            nullable: v.nullable.into_inner(),
+            required: v.required.into_inner(),
+            description: description(&v.odata),
+        }
+    }
+}
+
+/// Descriptor of action return type.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnTypeDescriptor<'a> {
+    /// Type of the return value.
+    #[serde(rename = "type")]
+    pub rtype: QualifiedName<'a>,
+    /// Return value is a collection of `rtype` values.
+    pub collection: bool,
+}
+
+/// Descriptor of enum type.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumDescriptor<'a> {
+    /// Description of the enum.
+    pub description: Option<&'a str>,
+    /// Long description of the enum.
+    pub long_description: Option<&'a str>,
+    /// Members of the enum in schema order.
+    pub members: Vec<EnumMemberDescriptor<'a>>,
+}
+
+impl<'a> EnumDescriptor<'a> {
+    fn new(v: &'a EnumType<'a>) -> Self {
+        Self {
+            description: description(&v.odata),
+            long_description: long_description(&v.odata),
+            members: v.members.iter().map(EnumMemberDescriptor::new).collect(),
+        }
+    }
+}
+
+/// Descriptor of enum member.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnumMemberDescriptor<'a> {
+    /// Name of the member as it appears in payload.
+    pub name: &'a EnumMemberName,
+    /// Description of the member.
+    pub description: Option<&'a str>,
+    /// Long description of the member.
+    pub long_description: Option<&'a str>,
+}
+
+impl<'a> EnumMemberDescriptor<'a> {
+    fn new(v: &'a EnumMember<'a>) -> Self {
+        Self {
+            name: v.name,
+            description: description(&v.odata),
+            long_description: long_description(&v.odata),
+        }
+    }
+}
+
+/// Descriptor of type definition.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeDefinitionDescriptor<'a> {
+    /// Primitive type underlying the type definition.
+    pub underlying_type: QualifiedName<'a>,
+}
+
+fn properties<'a>(v: &'a Properties<'a>) -> Vec<PropertyDescriptor<'a>> {
+    v.properties
+        .iter()
+        .map(PropertyDescriptor::property)
+        .chain(
+            v.nav_properties
+                .iter()
+                .map(PropertyDescriptor::nav_property),
+        )
+        .collect()
+}
+
+fn actions<'a>(name: QualifiedName<'a>, compiled: &'a Compiled<'a>) -> Vec<ActionDescriptor<'a>> {
+    let mut actions = compiled
+        .actions
+        .get(&name)
+        .map_or_else(Vec::new, |actions| {
+            actions.values().map(ActionDescriptor::new).collect()
+        });
+    actions.sort_by_key(|v| v.name);
+    actions
+}
+
+fn description<'a>(odata: &OData<'a>) -> Option<&'a str> {
+    odata.description.map(|v| v.into_inner().as_str())
+}
+
+fn long_description<'a>(odata: &OData<'a>) -> Option<&'a str> {
+    odata.long_description.map(|v| v.into_inner().as_str())
+}
+
+fn since(redfish: &RedfishProperty, since: Option<SchemaVersion>) -> Option<String> {
+    redfish.added.or(since).map(|v| v.to_string())
+}
+
+const fn is_collection<T>(v: &OneOrCollection<T>) -> bool {
+    matches!(v, OneOrCollection::Collection(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JsonGenerator;
+    use crate::compiler::Config;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use serde_json::json;
+    use serde_json::Value as JsonValue;
+
+    const SCHEMA: &str = r#"<edmx:Edmx Version="4.0">
+         <edmx:DataServices>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+             <EntityType Name="ItemOrCollection" Abstract="true"/>
+             <EntityType Name="Item" BaseType="Resource.ItemOrCollection" Abstract="true"/>
+             <EntityType Name="Resource" BaseType="Resource.Item" Abstract="true"/>
+             <EntityType Name="ResourceCollection" BaseType="Resource.ItemOrCollection" Abstract="true"/>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Settings">
+             <ComplexType Name="Settings"/>
+             <ComplexType Name="PreferredApplyTime"/>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Widget.v1_0_0">
+             <Action Name="Reset" IsBound="true">
+               <Parameter Name="Widget" Type="Widget.v1_0_0.Actions"/>
+               <Parameter Name="ResetType" Type="Widget.v1_0_0.ResetType" Nullable="false">
+                 <Annotation Term="Redfish.Required"/>
+               </Parameter>
+             </Action>
+             <EntityType Name="Widget" BaseType="Resource.Resource">
+               <Annotation Term="OData.Description" String="A widget."/>
+               <Annotation Term="Capabilities.UpdateRestrictions">
+                 <Record><PropertyValue Property="Updatable" Bool="true"/></Record>
+               </Annotation>
+               <Property Name="State" Type="Widget.v1_0_0.ResetType">
+                 <Annotation Term="OData.Permissions" EnumMember="OData.Permission/Read"/>
+               </Property>
+               <Property Name="Tags" Type="Collection(Edm.String)" Nullable="false">
+                 <Annotation Term="Redfish.Revisions">
+                   <Collection>
+                     <Record>
+                       <PropertyValue Property="Kind" EnumMember="Redfish.RevisionKind/Added"/>
+                       <PropertyValue Property="Version" String="v1_3_0"/>
+                     </Record>
+                     <Record>
+                       <PropertyValue Property="Kind" EnumMember="Redfish.RevisionKind/Deprecated"/>
+                       <PropertyValue Property="Version" String="v1_5_0"/>
+                       <PropertyValue Property="Description" String="Deprecated in favor of Labels."/>
+                     </Record>
+                   </Collection>
+                 </Annotation>
+               </Property>
+               <Property Name="Actions" Type="Widget.v1_0_0.Actions" Nullable="false"/>
+             </EntityType>
+             <ComplexType Name="Actions"/>
+             <EnumType Name="ResetType">
+               <Member Name="On">
+                 <Annotation Term="OData.Description" String="Turn on."/>
+               </Member>
+               <Member Name="Off"/>
+             </EnumType>
+           </Schema>
+         </edmx:DataServices>
+       </edmx:Edmx>"#;
+
+    fn generate() -> JsonValue {
+        let bundle = SchemaBundle {
+            edmx_docs: vec![Edmx::parse(SCHEMA).expect("schema must be valid")],
+            root_set_threshold: None,
+        };
+        let compiled = bundle
+            .compile_all(Config::default())
+            .map_err(|err| err.to_string())
+            .expect("schema must compile");
+        let json = JsonGenerator::new(compiled)
+            .generate()
+            .expect("descriptors must be serialized");
+        serde_json::from_str(&json).expect("descriptors must be valid JSON")
+    }
+
+    #[test]
+    fn descriptors_of_entity_type() {
+        let value = generate();
+        let widget = &value["entityTypes"]["Widget.v1_0_0.Widget"];
+        assert_eq!(widget["base"], "Resource.Resource");
+        assert_eq!(widget["description"], "A widget.");
+        assert_eq!(
+            widget["capabilities"],
+            json!({ "creatable": false, "updatable": true, "deletable": false })
+        );
+        let state = &widget["properties"][0];
+        assert_eq!(state["name"], "State");
+        assert_eq!(state["kind"], "enum");
+        assert_eq!(state["type"], "Widget.v1_0_0.ResetType");
+        assert_eq!(state["writable"], false);
+        assert_eq!(state["since"], "v1_0_0");
+        assert_eq!(state["deprecated"], JsonValue::Null);
+        let tags = &widget["properties"][1];
+        assert_eq!(tags["kind"], "primitive");
+        assert_eq!(tags["type"], "Edm.String");
+        assert_eq!(tags["collection"], true);
+        assert_eq!(tags["nullable"], false);
+        assert_eq!(tags["writable"], true);
+        assert_eq!(tags["since"], "v1_3_0");
+        assert_eq!(tags["deprecated"], "v1_5_0");
+    }
+
+    #[test]
+    fn descriptors_of_actions_and_enums() {
+        let value = generate();
+        let actions = &value["complexTypes"]["Widget.v1_0_0.Actions"]["actions"];
+        assert_eq!(actions[0]["name"], "Reset");
+        assert_eq!(
+            actions[0]["parameters"],
+            json!([{
+                "name": "ResetType",
+                "kind": "enum",
+                "type": "Widget.v1_0_0.ResetType",
+                "collection": false,
+                "nullable": false,
+                "required": true,
+                "description": null,
+            }])
+        );
+        let members = &value["enumTypes"]["Widget.v1_0_0.ResetType"]["members"];
+        assert_eq!(members[0]["name"], "On");
+        assert_eq!(members[0]["description"], "Turn on.");
+        assert_eq!(members[1]["name"], "Off");
+    }
+}
@@ -16,5 +16,8 @@
 /// Generator of rust code.
 pub mod rust;
 
+/// Generator of JSON descriptors.
+pub mod json;
+
 /// manipulate strings to produce patterns suitable for different languages (e.g, `CamelCase` to `snake_case` conversion)
 pub mod casemungler;
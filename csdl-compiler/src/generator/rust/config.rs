@@ -29,6 +29,10 @@ pub struct Config {
     /// Maximum number of parameters that are passed as function
     /// parameter before switching to action struct.
     pub action_fn_max_param_number_threshold: usize,
+
+    /// Map `Edm.String` to `EdmString` that shares storage of equal
+    /// values instead of `String`.
+    pub intern_strings: bool,
 }
 
 impl Default for Config {
@@ -39,6 +43,7 @@ impl Default for Config {
                 "Base".parse().expect("should always be parsed"),
             ),
             action_fn_max_param_number_threshold: 3,
+            intern_strings: false,
         }
     }
 }
@@ -85,6 +85,8 @@ pub use full_type_name::FullTypeName;
 #[doc(inline)]
 pub use mod_def::ModDef;
 #[doc(inline)]
+pub use mod_def::ModFile;
+#[doc(inline)]
 pub use mod_name::ModName;
 #[doc(inline)]
 pub use property_name::StructFieldName;
@@ -122,6 +124,7 @@ impl Display for Error<'_> {
 pub struct RustGenerator<'a> {
     root: ModDef<'a>,
     config: Config,
+    schema_types: Vec<String>,
 }
 
 impl<'a> RustGenerator<'a> {
@@ -154,10 +157,13 @@ impl<'a> RustGenerator<'a> {
             .try_fold(root, |m, (_, t)| {
                 let is_creatable = IsCreatable::new(creatable.contains(&t.name));
                 let forced = ForcedUpdate::new(forced_updates.contains(&t.name));
-                let type_excerpt_copies = excerpt_copies
+                // Excerpt copies are kept in a hash set. Sort them to
+                // generate excerpt structs in the stable order.
+                let mut type_excerpt_copies = excerpt_copies
                     .remove(&t.name)
                     .map(|v| v.into_iter().collect::<Vec<_>>())
                     .unwrap_or_default();
+                type_excerpt_copies.sort();
                 m.add_entity_type(t, is_creatable, type_excerpt_copies, forced, &config)
             })?;
         let root = compiled
@@ -168,13 +174,48 @@ impl<'a> RustGenerator<'a> {
             .enum_types
             .into_iter()
             .try_fold(root, |m, (_, t)| m.add_enum_type(t))?;
-        Ok(Self { root, config })
+        Ok(Self {
+            root,
+            config,
+            schema_types: Vec::new(),
+        })
+    }
+
+    /// Set qualified names of compiled types (see
+    /// [`Compiled::type_names`]) that are generated as `SCHEMA_TYPES`
+    /// constant of the top-level module.
+    #[must_use]
+    pub fn with_schema_types(mut self, schema_types: Vec<String>) -> Self {
+        self.schema_types = schema_types;
+        self
     }
 
     /// Generate Rust code from the collected data.
     #[must_use]
     pub fn generate(self) -> TokenStream {
+        let mut tokens = Self::generate_prelude(&self.config, &self.schema_types);
+        self.root.generate(&mut tokens, &self.config);
+        tokens
+    }
+
+    /// Generate Rust code from the collected data where each
+    /// top-level module (namespace) is placed to a separate file.
+    /// Returned code is the content of the top-level file that
+    /// includes files of the modules.
+    #[must_use]
+    pub fn generate_split(self) -> (TokenStream, Vec<ModFile>) {
+        let mut tokens = Self::generate_prelude(&self.config, &self.schema_types);
+        let files = self.root.generate_split(&mut tokens, &self.config);
+        (tokens, files)
+    }
+
+    fn generate_prelude(config: &Config, schema_types: &[String]) -> TokenStream {
         let mut tokens = TokenStream::new();
+        let edm_string = if config.intern_strings {
+            quote! { nv_redfish_core::EdmString }
+        } else {
+            quote! { ::std::string::String }
+        };
         tokens.extend(quote! {
             /// This code is automatically generated by CSDL generator. DO NOT EDIT!
             const _CSDL_GENERATED_CODE_COMMENT: &str = "";
@@ -188,7 +229,13 @@ impl<'a> RustGenerator<'a> {
                 Creatable,
                 RedfishSettings,
                 Bmc,
+                Capabilities,
+                EntityCapabilities,
+                LinksOem,
+                NavigationProperties,
                 ActionError,
+                ActionsAllowableValues,
+                AllowableValues,
                 Reference,
                 NavProperty,
                 Action,
@@ -197,8 +244,12 @@ impl<'a> RustGenerator<'a> {
                 ReferenceLeaf,
                 AdditionalProperties,
                 DynamicProperties,
+                PropertyVersions,
+                SchemaVersion,
                 ToSnakeCase,
+                de_optional_non_nullable,
                 de_optional_nullable,
+                de_required_non_nullable,
                 de_required_nullable,
             };
 
@@ -222,12 +273,235 @@ impl<'a> RustGenerator<'a> {
                 /// Mapping of `Edm.Int64` type
                 pub type Int64 = i64;
                 /// Mapping of `Edm.String` type
-                pub type String = ::std::string::String;
+                pub type String = #edm_string;
                 /// Mapping of `Edm.PrimitiveType` type
                 pub type PrimitiveType = nv_redfish_core::EdmPrimitiveType;
             }
+
+            /// Qualified names (`Namespace.Name`, namespace includes
+            /// version) of compiled entity and complex types.
+            pub const SCHEMA_TYPES: &[&str] = &[#(#schema_types),*];
         });
-        self.root.generate(&mut tokens, &self.config);
         tokens
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use super::RustGenerator;
+    use crate::compiler::Config as CompilerConfig;
+    use crate::compiler::SchemaBundle;
+    use crate::edmx::Edmx;
+    use crate::optimizer::optimize;
+    use crate::optimizer::Config as OptimizerConfig;
+    use quote::quote;
+
+    // Many types of each kind and excerpt copies with different keys
+    // to make the output sensitive to hash map iteration order.
+    const SCHEMA: &str = r#"<edmx:Edmx Version="4.0">
+         <edmx:DataServices>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource">
+             <EntityType Name="ItemOrCollection" Abstract="true"/>
+             <EntityType Name="Item" BaseType="Resource.ItemOrCollection" Abstract="true"/>
+             <EntityType Name="Resource" BaseType="Resource.Item" Abstract="true"/>
+             <EntityType Name="ResourceCollection" BaseType="Resource.ItemOrCollection" Abstract="true"/>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Resource.v1_0_0">
+             <EntityType Name="Resource" BaseType="Resource.Resource" Abstract="true">
+               <Key><PropertyRef Name="Id"/></Key>
+               <Property Name="Id" Type="Edm.String" Nullable="false">
+                 <Annotation Term="Redfish.Required"/>
+               </Property>
+               <Property Name="Name" Type="Edm.String" Nullable="false">
+                 <Annotation Term="Redfish.Required"/>
+               </Property>
+             </EntityType>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Sensor.v1_0_0">
+             <EntityType Name="Sensor" BaseType="Resource.v1_0_0.Resource">
+               <Annotation Term="Redfish.Uris">
+                 <Collection>
+                   <String>/redfish/v1/Holder/Sensors/{SensorId}</String>
+                 </Collection>
+               </Annotation>
+               <Property Name="Reading" Type="Edm.Double">
+                 <Annotation Term="Redfish.Excerpt"/>
+               </Property>
+               <Property Name="Alpha" Type="Edm.String">
+                 <Annotation Term="Redfish.Excerpt" String="Alpha"/>
+               </Property>
+               <Property Name="Beta" Type="Edm.String">
+                 <Annotation Term="Redfish.Excerpt" String="Beta"/>
+               </Property>
+               <Property Name="Gamma" Type="Edm.String">
+                 <Annotation Term="Redfish.Excerpt" String="Gamma"/>
+               </Property>
+               <Property Name="Kind" Type="Sensor.v1_0_0.Kind"/>
+               <Property Name="Limits" Type="Sensor.v1_0_0.Limits"/>
+             </EntityType>
+             <EnumType Name="Kind">
+               <Member Name="Temperature"/>
+               <Member Name="Power"/>
+             </EnumType>
+             <EnumType Name="Unit">
+               <Member Name="Celsius"/>
+               <Member Name="Watts"/>
+             </EnumType>
+             <ComplexType Name="Limits">
+               <Property Name="Lower" Type="Edm.Double"/>
+               <Property Name="Upper" Type="Edm.Double"/>
+               <Property Name="Unit" Type="Sensor.v1_0_0.Unit"/>
+             </ComplexType>
+             <TypeDefinition Name="Label" UnderlyingType="Edm.String"/>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Holder">
+             <EntityType Name="Holder" BaseType="Resource.v1_0_0.Resource" Abstract="true">
+               <Annotation Term="Redfish.Uris">
+                 <Collection>
+                   <String>/redfish/v1/Holder</String>
+                   <String>/redfish/v1/Managers/{ManagerId}/Holder</String>
+                 </Collection>
+               </Annotation>
+             </EntityType>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Holder.v1_0_0">
+             <EntityType Name="Holder" BaseType="Holder.Holder">
+               <NavigationProperty Name="All" Type="Sensor.v1_0_0.Sensor">
+                 <Annotation Term="Redfish.ExcerptCopy"/>
+               </NavigationProperty>
+               <NavigationProperty Name="Gamma" Type="Sensor.v1_0_0.Sensor">
+                 <Annotation Term="Redfish.ExcerptCopy" String="Gamma"/>
+               </NavigationProperty>
+               <NavigationProperty Name="Alpha" Type="Sensor.v1_0_0.Sensor">
+                 <Annotation Term="Redfish.ExcerptCopy" String="Alpha"/>
+               </NavigationProperty>
+               <NavigationProperty Name="Beta" Type="Sensor.v1_0_0.Sensor">
+                 <Annotation Term="Redfish.ExcerptCopy" String="Beta"/>
+               </NavigationProperty>
+             </EntityType>
+           </Schema>
+           <Schema xmlns="http://docs.oasis-open.org/odata/ns/edm" Namespace="Settings">
+             <ComplexType Name="Settings"/>
+             <ComplexType Name="PreferredApplyTime"/>
+           </Schema>
+         </edmx:DataServices>
+       </edmx:Edmx>"#;
+
+    fn bundle() -> SchemaBundle {
+        SchemaBundle {
+            edmx_docs: vec![Edmx::parse(SCHEMA).expect("schema must be valid")],
+            root_set_threshold: None,
+        }
+    }
+
+    fn generator(bundle: &SchemaBundle) -> RustGenerator<'_> {
+        generator_with_config(bundle, Config::default())
+    }
+
+    fn generator_with_config(bundle: &SchemaBundle, config: Config) -> RustGenerator<'_> {
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("schema must compile");
+        let compiled = optimize(compiled, &OptimizerConfig::default());
+        RustGenerator::new(compiled, config)
+            .map_err(|err| err.to_string())
+            .expect("code must be generated")
+    }
+
+    fn generate() -> String {
+        generator(&bundle()).generate().to_string()
+    }
+
+    #[test]
+    fn generated_code_is_reproducible() {
+        let first = generate();
+        // Each run creates new hash maps with different random state.
+        for _ in 0..8 {
+            assert!(generate() == first, "generated code must not change");
+        }
+    }
+
+    #[test]
+    fn split_generates_file_per_namespace() {
+        let (root, files) = generator(&bundle()).generate_split();
+        let root = root.to_string();
+        let names = files
+            .iter()
+            .map(|f| f.file_name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["holder.rs", "resource.rs", "sensor.rs", "settings.rs"]
+        );
+        for name in names {
+            assert!(root.contains(&format!("include ! (\"{name}\")")));
+        }
+        let sensor = files[2].content.to_string();
+        assert!(sensor.contains("pub struct Sensor"));
+        assert!(!root.contains("pub struct Sensor"));
+    }
+
+    #[test]
+    fn well_known_odata_id_from_redfish_uris() {
+        let code = generate();
+        // Holder URI is inherited from the pruned abstract type,
+        // Sensor URI contains placeholder and is not well-known.
+        assert_eq!(
+            code.matches("WELL_KNOWN_ODATA_ID : & 'static str").count(),
+            1
+        );
+        assert!(code.contains("WELL_KNOWN_ODATA_ID : & 'static str = \"/redfish/v1/Holder\""));
+    }
+
+    #[test]
+    fn null_of_required_identifier_is_not_replaced() {
+        let code = generate();
+        assert!(code.contains(&quote! { #[serde(rename = "Id")] }.to_string()));
+        assert!(code.contains(
+            &quote! { #[serde(rename = "Name", deserialize_with = "Resource::de_name")] }
+                .to_string()
+        ));
+        assert!(
+            code.contains(&quote! { de_required_non_nullable(de, "Resource.Name") }.to_string())
+        );
+    }
+
+    #[test]
+    fn intern_strings_maps_edm_string() {
+        assert!(generate().contains("pub type String = :: std :: string :: String ;"));
+        let config = Config {
+            intern_strings: true,
+            ..Config::default()
+        };
+        let code = generator_with_config(&bundle(), config)
+            .generate()
+            .to_string();
+        assert!(code.contains("pub type String = nv_redfish_core :: EdmString ;"));
+    }
+
+    #[test]
+    fn schema_types_keep_versions_of_optimized_types() {
+        let bundle = bundle();
+        let compiled = bundle
+            .compile_all(CompilerConfig::default())
+            .expect("schema must compile");
+        let schema_types = compiled.type_names();
+        for name in [
+            "Holder.v1_0_0.Holder",
+            "Sensor.v1_0_0.Sensor",
+            "Sensor.v1_0_0.Limits",
+        ] {
+            assert!(schema_types.iter().any(|v| v == name), "{}", name);
+        }
+        let compiled = optimize(compiled, &OptimizerConfig::default());
+        let (root, _) = RustGenerator::new(compiled, Config::default())
+            .map_err(|err| err.to_string())
+            .expect("code must be generated")
+            .with_schema_types(schema_types)
+            .generate_split();
+        let root = root.to_string();
+        assert!(root.contains("pub const SCHEMA_TYPES : & [& str] = & ["));
+        assert!(root.contains("\"Sensor.v1_0_0.Sensor\""));
+    }
+}
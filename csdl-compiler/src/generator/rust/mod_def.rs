@@ -39,24 +39,34 @@ use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use proc_macro2::TokenTree;
 use quote::quote;
-use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
 use std::iter::repeat_n;
+use std::mem::take;
+
+/// Content of a module generated to a separate file.
+#[derive(Debug)]
+pub struct ModFile {
+    /// Name of the file relative to the file of the parent module.
+    pub file_name: String,
+    /// Content of the module.
+    pub content: TokenStream,
+}
 
 #[derive(Debug, Default)]
 pub struct ModDef<'a> {
     name: Option<ModName<'a>>,
     namespace: Option<Namespace<'a>>,
-    typedefs: HashMap<TypeName<'a>, TypeDef<'a>>,
-    enums: HashMap<TypeName<'a>, EnumDef<'a>>,
-    structs: HashMap<TypeName<'a>, StructDef<'a>>,
-    sub_mods: HashMap<ModName<'a>, Self>,
+    typedefs: BTreeMap<TypeName<'a>, TypeDef<'a>>,
+    enums: BTreeMap<TypeName<'a>, EnumDef<'a>>,
+    structs: BTreeMap<TypeName<'a>, StructDef<'a>>,
+    sub_mods: BTreeMap<ModName<'a>, Self>,
     depth: usize,
 }
 
 impl<'a> ModDef<'a> {
     #[must_use]
-    pub fn new(name: ModName<'a>, namespace: Namespace<'a>, depth: usize) -> Self {
+    pub const fn new(name: ModName<'a>, namespace: Namespace<'a>, depth: usize) -> Self {
         Self {
             name: Some(name),
             // A module is shared by every schema version that maps to
@@ -65,10 +75,10 @@ impl<'a> ModDef<'a> {
             // create it would otherwise leak into the doc comment and
             // vary with map iteration order.
             namespace: Some(namespace.truncated(depth + 1)),
-            structs: HashMap::new(),
-            sub_mods: HashMap::new(),
-            enums: HashMap::new(),
-            typedefs: HashMap::new(),
+            structs: BTreeMap::new(),
+            sub_mods: BTreeMap::new(),
+            enums: BTreeMap::new(),
+            typedefs: BTreeMap::new(),
             depth,
         }
     }
@@ -295,6 +305,7 @@ impl<'a> ModDef<'a> {
             };
             let builder = builder
                 .with_properties(t.properties)
+                .with_uris(t.uris)
                 .with_generate_type(gen_types);
             self.add_struct_def(builder.build(config)?)
                 .map_err(Box::new)
@@ -354,52 +365,9 @@ impl<'a> ModDef<'a> {
 
     /// Generate Rust code.
     pub fn generate(self, tokens: &mut TokenStream, config: &Config) {
-        let mut typedefs = self.typedefs.into_values().collect::<Vec<_>>();
-        typedefs.sort_by_key(|v| v.name);
-
-        let mut enums = self.enums.into_values().collect::<Vec<_>>();
-        enums.sort_by_key(|v| v.name);
-
-        let mut sub_mods = self.sub_mods.into_values().collect::<Vec<_>>();
-        sub_mods.sort_by_key(|v| v.name);
-
-        let mut structs = self.structs.into_values().collect::<Vec<_>>();
-        structs.sort_by_key(|v| v.name);
-
-        let generate = |ts: &mut TokenStream| {
-            for t in typedefs {
-                t.generate(ts, config);
-            }
-
-            for t in enums {
-                t.generate(ts, config);
-            }
-
-            for s in structs {
-                s.generate(ts, config);
-            }
-
-            for m in sub_mods {
-                m.generate(ts, config);
-            }
-        };
-
         if let Some(name) = self.name {
-            let top = &config.top_module_alias;
-            let mut content = TokenStream::new();
-            content.extend([
-                Self::generate_ref_to_top_module(self.depth, config),
-                quote! {
-                    use serde::{Serialize, Deserialize};
-                    use #top::{NavProperty, ODataId, ODataETag, de_optional_nullable, de_required_nullable};
-                    use #top::ActionError as _;
-                },
-            ]);
-            generate(&mut content);
-            let doc = self.namespace.map_or_else(
-                || doc::generate(&["Generated schema of root namespace"]),
-                |ns| doc::generate(&[format!("Generated schema of {ns} namespace")]),
-            );
+            let doc = self.doc();
+            let content = self.generate_content(config);
             tokens.extend(quote! {
                 #doc
                 #[allow(unused_imports)]
@@ -408,7 +376,78 @@ impl<'a> ModDef<'a> {
                 }
             });
         } else {
-            generate(tokens);
+            self.generate_items(tokens, config);
+        }
+    }
+
+    /// Generate Rust code where content of each sub-module of this
+    /// module is placed to a separate file. Sub-modules are included
+    /// to this module by `include!` of the file located in the same
+    /// directory.
+    pub fn generate_split(mut self, tokens: &mut TokenStream, config: &Config) -> Vec<ModFile> {
+        let sub_mods = take(&mut self.sub_mods);
+        self.generate_items(tokens, config);
+        sub_mods
+            .into_values()
+            .filter_map(|m| {
+                let name = m.name?;
+                let file_name = format!("{name}.rs");
+                let doc = m.doc();
+                tokens.extend(quote! {
+                    #doc
+                    #[allow(unused_imports)]
+                    pub mod #name {
+                        include!(#file_name);
+                    }
+                });
+                Some(ModFile {
+                    file_name,
+                    content: m.generate_content(config),
+                })
+            })
+            .collect()
+    }
+
+    fn doc(&self) -> TokenStream {
+        self.namespace.map_or_else(
+            || doc::generate(&["Generated schema of root namespace"]),
+            |ns| doc::generate(&[format!("Generated schema of {ns} namespace")]),
+        )
+    }
+
+    // Content of the named module: imports and items.
+    fn generate_content(self, config: &Config) -> TokenStream {
+        let top = &config.top_module_alias;
+        let mut content = TokenStream::new();
+        content.extend([
+            Self::generate_ref_to_top_module(self.depth, config),
+            quote! {
+                use serde::{Serialize, Deserialize};
+                use #top::{NavProperty, ODataId, ODataETag, de_optional_non_nullable, de_optional_nullable, de_required_non_nullable, de_required_nullable};
+                use #top::ActionError as _;
+            },
+        ]);
+        self.generate_items(&mut content, config);
+        content
+    }
+
+    fn generate_items(self, tokens: &mut TokenStream, config: &Config) {
+        // Maps are ordered by name, so generated code doesn't depend on
+        // the order types were added in.
+        for t in self.typedefs.into_values() {
+            t.generate(tokens, config);
+        }
+
+        for t in self.enums.into_values() {
+            t.generate(tokens, config);
+        }
+
+        for s in self.structs.into_values() {
+            s.generate(tokens, config);
+        }
+
+        for m in self.sub_mods.into_values() {
+            m.generate(tokens, config);
         }
     }
 
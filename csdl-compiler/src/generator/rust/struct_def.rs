@@ -23,7 +23,10 @@ use crate::compiler::Properties;
 use crate::compiler::Property;
 use crate::compiler::PropertyType;
 use crate::compiler::QualifiedName;
+use crate::compiler::RedfishProperty;
 use crate::compiler::RigidArraySupport;
+use crate::compiler::SchemaVersion;
+use crate::compiler::TypeClass;
 use crate::generator::rust::doc::format_and_generate as doc_format_and_generate;
 use crate::generator::rust::ActionFullTypeName;
 use crate::generator::rust::ActionName;
@@ -75,8 +78,16 @@ pub struct StructDef<'a> {
     // it in active resource itself.
     need_redfish_settings: bool,
     dynamic_properties: Option<DynamicProperties<'a>>,
+    uris: &'a [String],
 }
 
+/// Name of the property (and its type) with links to other resources.
+const LINKS: &str = "Links";
+/// Name of the property (and its type) with OEM extensions.
+const OEM: &str = "Oem";
+/// Names of properties that identify resources and their members.
+const IDENTIFIERS: [&str; 2] = ["Id", "MemberId"];
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum ImplType {
     Root,
@@ -90,6 +101,17 @@ enum SerializableStructKind {
     Update,
 }
 
+// Struct generated for deserialization. Deserializers of its
+// non-nullable properties are functions of the struct that report
+// `null` values with path of the property (`Type.Property`).
+#[derive(Clone, Copy)]
+struct DeOwner<'s> {
+    // Name of the generated struct.
+    name: &'s str,
+    // Name of the schema type used in paths of properties.
+    type_name: &'s str,
+}
+
 // Action request fields are generated as two coordinated token streams. Keeping
 // them in one value makes it harder to change the serde omission rule without
 // also considering the generated Rust field type.
@@ -125,21 +147,14 @@ impl<'a> StructDef<'a> {
         let odata_etag = Ident::new("odata_etag", Span::call_site());
         let (base_props, impl_type) = self.base_type(&odata_id, &odata_etag, config);
 
-        // Properties token streams:
-        let properties_iter = self.properties.properties.iter().filter_map(|p| {
-            if p.odata.permissions_is_write_only() || p.redfish.is_excerpt_only.into_inner() {
-                None
-            } else {
-                Some(Self::generate_property(p, config))
-            }
-        });
+        let name = self.name;
+        let owner_name = name.to_string();
+        let owner = DeOwner {
+            name: &owner_name,
+            type_name: &owner_name,
+        };
 
-        // Navigation properties token streams:
-        let nav_properties_iter = self
-            .properties
-            .nav_properties
-            .iter()
-            .map(|p| Self::generate_nav_property(p, config));
+        let (properties, de_fns) = self.read_properties(owner, config);
 
         // Action properties token streams:
         let mut actions = self.actions.values().collect::<Vec<_>>();
@@ -180,14 +195,13 @@ impl<'a> StructDef<'a> {
 
         // Combine all together in content
         let all_properties = iter::once(base_props)
-            .chain(properties_iter)
-            .chain(nav_properties_iter)
+            .chain(properties)
+            .chain(iter::once(self.generate_members_annotations()))
             .chain(action_iter)
             .chain(iter::once(additional_properties));
 
         content.extend(all_properties);
 
-        let name = self.name;
         // Note: Manual implementation of Send and Sync is needed to
         // help compiler. It goes through all properties deeper and
         // deepr in the Redfish tree until it hits the recursion
@@ -211,30 +225,22 @@ impl<'a> StructDef<'a> {
                 unsafe impl Sync for #name {}
             },
         ]);
+        Self::generate_de_fns(tokens, &name, de_fns);
 
-        // Additional function that are implemented for type:
-        let entity_type_impl = |fn_id_impl, fn_etag_impl| {
-            quote! {
-                impl #top::EntityTypeRef for #name {
-                    #[inline] fn odata_id(&self) -> &ODataId { #fn_id_impl }
-                    #[inline] fn etag(&self) -> Option<&ODataETag> { #fn_etag_impl }
-                }
-            }
-        };
-
-        tokens.extend(match impl_type {
-            ImplType::Root => entity_type_impl(
-                quote! { &self.#odata_id },
-                quote! { self.#odata_etag.as_ref() },
-            ),
-            ImplType::Child => {
-                entity_type_impl(quote! { self.base.odata_id() }, quote! { self.base.etag() })
-            }
-            ImplType::None => TokenStream::new(),
-        });
+        self.generate_entity_type_ref(tokens, impl_type, &odata_id, &odata_etag, config);
+        self.generate_well_known_odata_id(tokens);
 
         if impl_type != ImplType::None {
             self.generate_entity_type_traits(tokens, impl_type, config);
+            self.generate_property_versions_trait(tokens, config);
+            self.generate_navigation_properties_trait(tokens, config);
+            self.generate_links_oem_trait(tokens, LINKS, config);
+        } else if self.name.to_string() == LINKS {
+            self.generate_links_oem_trait(tokens, OEM, config);
+        }
+
+        if self.is_members_collection() {
+            self.generate_members_collection_trait(tokens, config);
         }
 
         if !actions.is_empty() {
@@ -245,36 +251,428 @@ impl<'a> StructDef<'a> {
             tokens.extend(quote! {
                 impl #name { #content }
             });
+            Self::generate_actions_allowable_values(tokens, name, &actions, config);
         }
     }
 
-    fn generate_excerpt(
+    // Token streams of properties and navigation properties of the
+    // read struct and deserializers of them.
+    fn read_properties(
         &self,
+        owner: DeOwner<'_>,
+        config: &Config,
+    ) -> (Vec<TokenStream>, Vec<TokenStream>) {
+        let properties = self.properties.properties.iter().filter_map(|p| {
+            if p.odata.permissions_is_write_only() || p.redfish.is_excerpt_only.into_inner() {
+                None
+            } else {
+                Some(Self::generate_property(owner, p, config))
+            }
+        });
+        let nav_properties = self
+            .properties
+            .nav_properties
+            .iter()
+            .map(|p| Self::generate_nav_property(owner, p, config));
+        properties.chain(nav_properties).unzip()
+    }
+
+    /// Well-known `@odata.id` is generated only for resources that
+    /// have exactly one URI without placeholders (for example,
+    /// `/redfish/v1/AccountService`).
+    fn well_known_odata_id(&self) -> Option<&'a str> {
+        let mut fixed = self.uris.iter().filter(|uri| !uri.contains('{'));
+        match (fixed.next(), fixed.next()) {
+            (Some(uri), None) => Some(uri),
+            _ => None,
+        }
+    }
+
+    fn generate_well_known_odata_id(&self, tokens: &mut TokenStream) {
+        if let Some(uri) = self.well_known_odata_id() {
+            let name = self.name;
+            tokens.extend(quote! {
+                impl #name {
+                    #[doc = "Well-known `@odata.id` of the resource defined by `Redfish.Uris`."]
+                    pub const WELL_KNOWN_ODATA_ID: &'static str = #uri;
+
+                    #[doc = "Well-known `@odata.id` of the resource as `ODataId`."]
+                    #[must_use]
+                    pub fn well_known_odata_id() -> ODataId {
+                        ODataId::from(Self::WELL_KNOWN_ODATA_ID.to_string())
+                    }
+                }
+            });
+        }
+    }
+
+    fn generate_actions_allowable_values(
         tokens: &mut TokenStream,
+        name: TypeName<'_>,
+        actions: &[&Action],
         config: &Config,
-        excerpt_copy: &ExcerptCopy,
     ) {
-        let mut content = TokenStream::new();
-        let all_properties = self.properties.properties.iter().filter_map(|p| {
-            if !p.odata.permissions_is_write_only()
-                && p.redfish
-                    .excerpt
-                    .as_ref()
-                    .is_some_and(|excerpt| excerpt.matches(excerpt_copy))
-            {
-                Some(Self::generate_property(p, config))
-            } else {
-                None
+        let top = &config.top_module_alias;
+        let count = actions.len();
+        let entries = actions.iter().map(|a| {
+            let rename = Literal::string(&format!("#{}.{}", a.defining_namespace, a.name));
+            let name = ActionName::new(a.name);
+            quote! { (#rename, self.#name.as_ref().map(|a| &a.allowable_values)), }
+        });
+        tokens.extend(quote! {
+            impl #top::ActionsAllowableValues for #name {
+                fn allowable_values(&self, action: &str) -> Option<&#top::AllowableValues> {
+                    let actions: [(&str, Option<&#top::AllowableValues>); #count] = [#(#entries)*];
+                    actions
+                        .iter()
+                        .find(|(name, _)| *name == action)
+                        .and_then(|(_, values)| *values)
+                }
             }
         });
+    }
 
-        content.extend(all_properties);
+    // Access to single-valued navigation properties by name. Excerpt
+    // copies are not references, so they are not included. Properties
+    // inherited from the base type are looked up in the base type.
+    fn generate_navigation_properties_trait(&self, tokens: &mut TokenStream, config: &Config) {
+        let top = &config.top_module_alias;
+        let name = self.name;
+        let entries = self
+            .properties
+            .nav_properties
+            .iter()
+            .filter_map(|p| {
+                let field = StructFieldName::new_property(p.name());
+                let value = match p {
+                    NavProperty::Expandable(p)
+                        if !p.odata.permissions_is_write_only()
+                            && p.redfish.excerpt_copy.is_none()
+                            && matches!(p.ptype, OneOrCollection::One(_)) =>
+                    {
+                        let required =
+                            Self::version_tolerant_required(p.redfish.is_required, p.since);
+                        match (required.into_inner(), p.nullable.into_inner()) {
+                            (true, false) => quote! { Some(self.#field.id()) },
+                            (false, true) => quote! {
+                                self.#field.as_ref().and_then(Option::as_ref).map(NavProperty::id)
+                            },
+                            _ => quote! { self.#field.as_ref().map(NavProperty::id) },
+                        }
+                    }
+                    NavProperty::Reference(OneOrCollection::One(_)) => {
+                        quote! { self.#field.as_ref().map(|v| &v.odata_id) }
+                    }
+                    _ => return None,
+                };
+                let pname = Literal::string(p.name().inner().inner());
+                Some(quote! { (#pname, #value), })
+            })
+            .collect::<Vec<_>>();
+        let count = entries.len();
+        let lookup = if self.base.is_some() {
+            let base = StructFieldName::new_property(&config.base_type_prop_name);
+            quote! {
+                .map_or_else(
+                    || #top::NavigationProperties::navigation_property(&self.#base, name),
+                    |(_, id)| *id,
+                )
+            }
+        } else {
+            quote! { .and_then(|(_, id)| *id) }
+        };
+        tokens.extend(quote! {
+            impl #top::NavigationProperties for #name {
+                fn navigation_property(&self, name: &str) -> Option<&ODataId> {
+                    let properties: [(&str, Option<&ODataId>); #count] = [#(#entries)*];
+                    properties
+                        .iter()
+                        .find(|(pname, _)| *pname == name)
+                        #lookup
+                }
+            }
+        });
+    }
 
+    // `Links.Oem` access. Entity types take it from `Links` property
+    // and `Links` complex types take it from `Oem` property. Both
+    // delegate to the base type if they don't define the property.
+    fn generate_links_oem_trait(&self, tokens: &mut TokenStream, field: &str, config: &Config) {
+        let top = &config.top_module_alias;
+        let name = self.name;
+        let property = self.properties.properties.iter().find(|p| {
+            p.name.inner().inner() == field
+                && p.ptype.name().name.inner() == field
+                && matches!(p.ptype, OneOrCollection::One(_))
+        });
+        let body = property.map_or_else(
+            || self.gen_links_oem_from_base(field, config),
+            |p| {
+                let value = Self::gen_property_ref(p);
+                if field == LINKS {
+                    quote! { #value.and_then(#top::LinksOem::links_oem) }
+                } else {
+                    quote! { #value.map(|v| &v.additional_properties) }
+                }
+            },
+        );
+        tokens.extend(quote! {
+            impl #top::LinksOem for #name {
+                fn links_oem(&self) -> Option<&#top::AdditionalProperties> {
+                    #body
+                }
+            }
+        });
+    }
+
+    // Links of entity types are always inherited from the base type.
+    // Links complex type inherits them only from the base type of the
+    // same name.
+    fn gen_links_oem_from_base(&self, field: &str, config: &Config) -> TokenStream {
+        if self
+            .base
+            .is_some_and(|base| field == LINKS || base.name.inner() == LINKS)
+        {
+            let top = &config.top_module_alias;
+            let base = StructFieldName::new_property(&config.base_type_prop_name);
+            quote! { #top::LinksOem::links_oem(&self.#base) }
+        } else {
+            quote! { None }
+        }
+    }
+
+    // Reference to the value of single-valued property as `Option`.
+    fn gen_property_ref(p: &Property<'_>) -> TokenStream {
+        let name = StructFieldName::new_property(p.name);
+        let required = Self::version_tolerant_required(p.redfish.is_required, p.since);
+        match (required.into_inner(), p.nullable.into_inner()) {
+            (true, false) => quote! { Some(&self.#name) },
+            (false, true) => quote! { self.#name.as_ref().and_then(Option::as_ref) },
+            _ => quote! { self.#name.as_ref() },
+        }
+    }
+
+    fn generate_entity_type_ref(
+        &self,
+        tokens: &mut TokenStream,
+        impl_type: ImplType,
+        odata_id: &Ident,
+        odata_etag: &Ident,
+        config: &Config,
+    ) {
+        let top = &config.top_module_alias;
+        let name = self.name;
+        let must_have_type = *self.odata.must_have_type.inner();
+        let (fn_id_impl, fn_etag_impl, fn_type_impl) = match impl_type {
+            ImplType::Root => (
+                quote! { &self.#odata_id },
+                quote! { self.#odata_etag.as_ref() },
+                if must_have_type {
+                    quote! { Some(self.odata_type.as_str()) }
+                } else {
+                    quote! { self.odata_type.as_deref() }
+                },
+            ),
+            ImplType::Child => (
+                quote! { self.base.odata_id() },
+                quote! { self.base.etag() },
+                if must_have_type {
+                    quote! { Some(self.odata_type.as_str()) }
+                } else {
+                    quote! { self.base.odata_type() }
+                },
+            ),
+            ImplType::None => return,
+        };
+        tokens.extend(quote! {
+            impl #top::EntityTypeRef for #name {
+                #[inline] fn odata_id(&self) -> &ODataId { #fn_id_impl }
+                #[inline] fn etag(&self) -> Option<&ODataETag> { #fn_etag_impl }
+                #[inline] fn odata_type(&self) -> Option<&str> { #fn_type_impl }
+            }
+        });
+    }
+
+    // Versions of the schema that introduced properties of the
+    // entity type. Properties inherited from the base type are
+    // available since initial version of the entity type.
+    fn generate_property_versions_trait(&self, tokens: &mut TokenStream, config: &Config) {
+        let top = &config.top_module_alias;
+        let name = self.name;
+        let version = |version: Option<SchemaVersion>| {
+            let version = version.unwrap_or(SchemaVersion::INITIAL);
+            let (major, minor, errata) = (version.major, version.minor, version.errata);
+            quote! { #top::SchemaVersion::new(#major, #minor, #errata) }
+        };
+        let revisions = self
+            .properties
+            .properties
+            .iter()
+            .filter(|p| !p.odata.permissions_is_write_only())
+            .map(|p| (p.name, &p.redfish, p.since))
+            .chain(
+                self.properties
+                    .nav_properties
+                    .iter()
+                    .filter_map(|p| match p {
+                        NavProperty::Expandable(p) if !p.odata.permissions_is_write_only() => {
+                            Some((p.name, &p.redfish, p.since))
+                        }
+                        _ => None,
+                    }),
+            )
+            .collect::<Vec<_>>();
+        // Explicit `Redfish.Revisions` annotation is more precise
+        // than version of the namespace that defines the property.
+        let entries = revisions.iter().map(|(pname, redfish, since)| {
+            let pname = Literal::string(pname.inner().inner());
+            let since = version(redfish.added.or(*since));
+            quote! { (#pname, #since), }
+        });
+        let fallback = self.base.map_or_else(TokenStream::new, |base| {
+            let typename = FullTypeName::new(base, config);
+            let initial = version(None);
+            quote! {
+                .or_else(|| {
+                    <#typename as #top::PropertyVersions>::property_since(property).and(Some(#initial))
+                })
+            }
+        });
+        // Deprecation versions of base type properties refer to the
+        // schema of the base type, so only own properties are listed.
+        let deprecated = revisions
+            .iter()
+            .filter_map(|(pname, redfish, _)| {
+                redfish.deprecated.map(|deprecated| {
+                    let pname = Literal::string(pname.inner().inner());
+                    let deprecated = version(Some(deprecated));
+                    quote! { (#pname, #deprecated), }
+                })
+            })
+            .collect::<Vec<_>>();
+        let property_deprecated = if deprecated.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! {
+                fn property_deprecated(property: &str) -> Option<#top::SchemaVersion> {
+                    const DEPRECATED: &[(&str, #top::SchemaVersion)] = &[#(#deprecated)*];
+                    DEPRECATED
+                        .iter()
+                        .find(|(name, _)| *name == property)
+                        .map(|(_, deprecated)| *deprecated)
+                }
+            }
+        };
+        tokens.extend(quote! {
+            impl #top::PropertyVersions for #name {
+                fn property_since(property: &str) -> Option<#top::SchemaVersion> {
+                    const SINCE: &[(&str, #top::SchemaVersion)] = &[#(#entries)*];
+                    SINCE
+                        .iter()
+                        .find(|(name, _)| *name == property)
+                        .map(|(_, since)| *since)
+                        #fallback
+                }
+                #property_deprecated
+            }
+        });
+    }
+
+    // Collection is an entity type with `Members` collection
+    // navigation property. `Members@odata.count` and
+    // `Members@odata.nextLink` annotations are not defined in CSDL,
+    // so they are added for such types explicitly.
+    fn is_members_collection(&self) -> bool {
+        self.properties.nav_properties.iter().any(|p| match p {
+            NavProperty::Expandable(v) => {
+                v.name.inner().inner() == "Members"
+                    && matches!(v.ptype, OneOrCollection::Collection(_))
+            }
+            NavProperty::Reference(_) => false,
+        })
+    }
+
+    fn generate_members_annotations(&self) -> TokenStream {
+        if self.is_members_collection() {
+            quote! {
+                /// Total number of members in the collection. It can be
+                /// greater than number of members in this response.
+                #[serde(rename="Members@odata.count", default)]
+                pub members_count: Option<i64>,
+                /// Reference to the next portion of the collection members.
+                #[serde(rename="Members@odata.nextLink", default)]
+                pub next_link: Option<ODataId>,
+            }
+        } else {
+            TokenStream::new()
+        }
+    }
+
+    fn generate_members_collection_trait(&self, tokens: &mut TokenStream, config: &Config) {
+        let top = &config.top_module_alias;
+        let name = self.name;
+        tokens.extend(quote! {
+            impl #top::MembersCollection for #name {
+                #[inline] fn members_count(&self) -> Option<i64> { self.members_count }
+                #[inline] fn next_link(&self) -> Option<&ODataId> { self.next_link.as_ref() }
+                #[inline] fn members_len(&self) -> usize { self.members.len() }
+            }
+        });
+    }
+
+    fn generate_excerpt(
+        &self,
+        tokens: &mut TokenStream,
+        config: &Config,
+        excerpt_copy: &ExcerptCopy,
+    ) {
         let name = self.name.for_excerpt_copy(excerpt_copy);
+        let owner_name = name.to_string();
+        let type_name = self.name.to_string();
+        let owner = DeOwner {
+            name: &owner_name,
+            type_name: &type_name,
+        };
+        let (properties, de_fns): (Vec<_>, Vec<_>) = self
+            .properties
+            .properties
+            .iter()
+            .filter_map(|p| {
+                if !p.odata.permissions_is_write_only()
+                    && p.redfish
+                        .excerpt
+                        .as_ref()
+                        .is_some_and(|excerpt| excerpt.matches(excerpt_copy))
+                {
+                    Some(Self::generate_property(owner, p, config))
+                } else {
+                    None
+                }
+            })
+            .unzip();
+
+        let mut content = TokenStream::new();
+        content.extend(properties);
+
         tokens.extend([quote! {
             #[derive(Deserialize, Debug)]
             pub struct #name { #content }
         }]);
+        Self::generate_de_fns(tokens, &name, de_fns);
+    }
+
+    // Deserializers of non-nullable properties of the struct `name`.
+    fn generate_de_fns(
+        tokens: &mut TokenStream,
+        name: &impl ToTokens,
+        de_fns: impl IntoIterator<Item = TokenStream>,
+    ) {
+        let de_fns = de_fns.into_iter().collect::<TokenStream>();
+        if !de_fns.is_empty() {
+            tokens.extend(quote! {
+                impl #name { #de_fns }
+            });
+        }
     }
 
     fn base_type(
@@ -299,13 +697,24 @@ impl<'a> StructDef<'a> {
                     // MustHaveId only for the root elements in type hierarchy. This requirements by code
                     // generation. Generator needs to add @odata.id field to the struct.
                     // If we will add odata.id on each level it may break deserialization.
+                    // @odata.type is optional for the root because some
+                    // BMCs do not provide it.
+                    let root_odata_type = if *self.odata.must_have_type.inner() {
+                        maybe_odata_type.clone()
+                    } else {
+                        quote! {
+                            /// Type of the resource
+                            #[serde(rename="@odata.type", default)]
+                            pub odata_type: Option<String>,
+                        }
+                    };
                     (
                         quote! {
                             #[serde(rename="@odata.id")]
                             pub #odata_id: ODataId,
                             #[serde(rename="@odata.etag")]
                             pub #odata_etag: Option<ODataETag>,
-                            #maybe_odata_type
+                            #root_odata_type
                             #[serde(rename = "@Redfish.Settings")]
                             pub redfish_settings: Option<#top::settings::Settings>,
                             #[serde(rename = "@Redfish.SettingsApplyTime")]
@@ -524,60 +933,193 @@ impl<'a> StructDef<'a> {
         ]);
     }
 
-    fn generate_property(p: &Property<'_>, config: &Config) -> TokenStream {
+    // Returns property token stream and its deserializer.
+    fn generate_property(
+        owner: DeOwner<'_>,
+        p: &Property<'_>,
+        config: &Config,
+    ) -> (TokenStream, TokenStream) {
         let doc = doc_format_and_generate(p.name, &p.odata);
-        let (serde, field_type) = Self::gen_de_struct_field(
+        let since_doc = Self::gen_revisions_doc(&p.redfish, p.since);
+        let name = StructFieldName::new_property(p.name);
+        let (serde, field_type, de_fn) = Self::gen_de_struct_field(
+            owner,
+            name,
             &p.ptype,
             FullTypeName::new(p.ptype.name(), config),
-            Literal::string(p.name.inner().inner()),
+            p.name.inner().inner(),
             p.nullable,
-            p.redfish.is_required,
+            Self::version_tolerant_required(p.redfish.is_required, p.since),
             p.rigid_array_support,
+            Self::null_has_default(p),
         );
-        let name = StructFieldName::new_property(p.name);
-        quote! {
-            #doc #serde
+        let property = quote! {
+            #doc #since_doc #serde
             pub #name: #field_type,
+        };
+        (property, de_fn)
+    }
+
+    // Documentation of properties that were introduced after initial
+    // version of the schema or deprecated.
+    fn gen_revisions_doc(redfish: &RedfishProperty, since: Option<SchemaVersion>) -> TokenStream {
+        let since = redfish
+            .added
+            .or(since)
+            .filter(|v| *v != SchemaVersion::INITIAL)
+            .map_or_else(TokenStream::new, |v| {
+                let doc = format!("Since `{v}`.");
+                quote! {
+                    #[doc = ""]
+                    #[doc = #doc]
+                }
+            });
+        let deprecated = redfish.deprecated.map_or_else(TokenStream::new, |v| {
+            let doc = format!("Deprecated in `{v}`.");
+            quote! {
+                #[doc = ""]
+                #[doc = #doc]
+            }
+        });
+        quote! { #since #deprecated }
+    }
+
+    // Properties that were introduced after initial version of the
+    // schema are absent in payloads of services that implement older
+    // versions. Such properties are never required, so the same
+    // struct can be used with services of all versions.
+    fn version_tolerant_required(required: IsRequired, since: Option<SchemaVersion>) -> IsRequired {
+        if since.is_some_and(|v| v != SchemaVersion::INITIAL) {
+            IsRequired::new(false)
+        } else {
+            required
+        }
+    }
+
+    // `null` of the required non-nullable property may be replaced
+    // with the default value of its type. Identifiers are never
+    // replaced, so resources don't get empty `Id`.
+    fn null_has_default(p: &Property<'_>) -> bool {
+        Self::has_default(&p.ptype) && !IDENTIFIERS.contains(&p.name.inner().inner().as_str())
+    }
+
+    // Rust type of the property implements `Default`. Collections
+    // and primitive types of the `Edm` namespace that are mapped to
+    // Rust primitives have default value.
+    fn has_default(ptype: &PropertyType<'_>) -> bool {
+        match ptype {
+            OneOrCollection::Collection(_) => true,
+            OneOrCollection::One((typeinfo, name)) => {
+                typeinfo.class == TypeClass::SimpleType
+                    && name.namespace.is_edm()
+                    && matches!(
+                        name.name.as_ref(),
+                        "Boolean" | "Decimal" | "Double" | "Guid" | "Int64" | "String"
+                    )
+            }
         }
     }
 
-    // Returns serde annotation and field type token streams.
+    // Returns serde annotation, field type and deserializer token
+    // streams.
+    #[allow(clippy::too_many_arguments)]
     fn gen_de_struct_field<T>(
+        owner: DeOwner<'_>,
+        field: StructFieldName<'_>,
         cardinality: &OneOrCollection<T>,
         ftype: impl ToTokens,
-        rename: impl ToTokens,
+        rename: &str,
         nullable: IsNullable,
         required: IsRequired,
         rigid_array_support: RigidArraySupport,
-    ) -> (TokenStream, TokenStream) {
-        (
-            Self::gen_de_struct_field_serde_annot(rename, nullable, required),
-            Self::gen_de_struct_field_type(
-                cardinality,
-                ftype,
-                nullable,
-                required,
-                rigid_array_support,
-            ),
-        )
+        has_default: bool,
+    ) -> (TokenStream, TokenStream, TokenStream) {
+        let (serde, de_fn) = Self::gen_de_struct_field_serde_annot(
+            owner,
+            field,
+            rename,
+            nullable,
+            required,
+            has_default,
+        );
+        let field_type = Self::gen_de_struct_field_type(
+            cardinality,
+            ftype,
+            nullable,
+            required,
+            rigid_array_support,
+        );
+        (serde, field_type, de_fn)
     }
 
+    // Returns serde annotation and deserializer token streams.
     fn gen_de_struct_field_serde_annot(
-        rename: impl ToTokens,
+        owner: DeOwner<'_>,
+        field: StructFieldName<'_>,
+        rename: &str,
         nullable: IsNullable,
         required: IsRequired,
-    ) -> TokenStream {
+        has_default: bool,
+    ) -> (TokenStream, TokenStream) {
+        let rename_lit = Literal::string(rename);
         if required.into_inner() && nullable.into_inner() {
-            quote! { #[serde(rename=#rename, deserialize_with="de_required_nullable")] }
+            let serde =
+                quote! { #[serde(rename=#rename_lit, deserialize_with="de_required_nullable")] };
+            (serde, TokenStream::new())
+        } else if required.into_inner() && has_default {
+            let (path, de_fn) = Self::gen_non_nullable_de_fn(owner, field, rename, true);
+            let serde = quote! { #[serde(rename=#rename_lit, deserialize_with=#path)] };
+            (serde, de_fn)
         } else if required.into_inner() {
-            quote! { #[serde(rename=#rename)] }
+            (quote! { #[serde(rename=#rename_lit)] }, TokenStream::new())
         } else if nullable.into_inner() {
-            quote! { #[serde(rename=#rename, default, deserialize_with="de_optional_nullable")] }
+            let serde = quote! {
+                #[serde(rename=#rename_lit, default, deserialize_with="de_optional_nullable")]
+            };
+            (serde, TokenStream::new())
         } else {
-            quote! { #[serde(rename=#rename, default)] }
+            let (path, de_fn) = Self::gen_non_nullable_de_fn(owner, field, rename, false);
+            let serde = quote! { #[serde(rename=#rename_lit, default, deserialize_with=#path)] };
+            (serde, de_fn)
         }
     }
 
+    // Deserializer of non-nullable property `rename` that reports
+    // `null` with path of the property. Returns path of the function
+    // for `deserialize_with` and the function.
+    fn gen_non_nullable_de_fn(
+        owner: DeOwner<'_>,
+        field: StructFieldName<'_>,
+        rename: &str,
+        required: bool,
+    ) -> (Literal, TokenStream) {
+        let fn_name = Ident::new(&format!("de_{field}"), Span::call_site());
+        let path = Literal::string(&format!("{}::{fn_name}", owner.name));
+        let property = Literal::string(&format!("{}.{rename}", owner.type_name));
+        let de_fn = if required {
+            quote! {
+                fn #fn_name<'de, D, T>(de: D) -> ::core::result::Result<T, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                    T: Deserialize<'de> + Default,
+                {
+                    de_required_non_nullable(de, #property)
+                }
+            }
+        } else {
+            quote! {
+                fn #fn_name<'de, D, T>(de: D) -> ::core::result::Result<Option<T>, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                    T: Deserialize<'de>,
+                {
+                    de_optional_non_nullable(de, #property)
+                }
+            }
+        };
+        (path, de_fn)
+    }
+
     // Returns the Rust field type token stream.
     fn gen_de_struct_field_type<T>(
         cardinality: &OneOrCollection<T>,
@@ -618,15 +1160,22 @@ impl<'a> StructDef<'a> {
         }
     }
 
-    fn generate_nav_property(p: &NavProperty<'_>, config: &Config) -> TokenStream {
+    // Returns navigation property token stream and its deserializer.
+    fn generate_nav_property(
+        owner: DeOwner<'_>,
+        p: &NavProperty<'_>,
+        config: &Config,
+    ) -> (TokenStream, TokenStream) {
         let name = StructFieldName::new_property(p.name());
-        let rename = Literal::string(p.name().inner().inner());
-        let (doc, serde, prop_type) = match p {
+        let rename = p.name().inner().inner();
+        let (doc, serde, prop_type, de_fn) = match p {
             NavProperty::Expandable(p) => {
                 if p.odata.permissions_is_write_only() {
-                    return TokenStream::new();
+                    return (TokenStream::new(), TokenStream::new());
                 }
                 let doc = doc_format_and_generate(p.ptype.name(), &p.odata);
+                let since_doc = Self::gen_revisions_doc(&p.redfish, p.since);
+                let doc = quote! { #doc #since_doc };
                 let ptype = p.redfish.excerpt_copy.as_ref().map_or_else(
                     || {
                         let full_type = FullTypeName::new(p.ptype.name(), config);
@@ -638,36 +1187,43 @@ impl<'a> StructDef<'a> {
                             .to_token_stream()
                     },
                 );
-                let (sa, t) = Self::gen_de_struct_field(
+                let (sa, t, de_fn) = Self::gen_de_struct_field(
+                    owner,
+                    name,
                     &p.ptype,
                     ptype,
                     rename,
                     p.nullable,
-                    p.redfish.is_required,
+                    Self::version_tolerant_required(p.redfish.is_required, p.since),
                     RigidArraySupport::new(false),
+                    matches!(p.ptype, OneOrCollection::Collection(_)),
                 );
-                (doc, sa, t)
+                (doc, sa, t, de_fn)
             }
             NavProperty::Reference(r) => {
                 let doc = TokenStream::new();
                 let top = &config.top_module_alias;
                 let ptype = quote! { #top::ReferenceLeaf };
-                let (sa, t) = Self::gen_de_struct_field(
+                let (sa, t, de_fn) = Self::gen_de_struct_field(
+                    owner,
+                    name,
                     r,
                     ptype,
                     rename,
                     IsNullable::new(false),
                     IsRequired::new(false),
                     RigidArraySupport::new(false),
+                    false,
                 );
-                (doc, sa, t)
+                (doc, sa, t, de_fn)
             }
         };
-        quote! {
+        let property = quote! {
             #doc
             #serde
             pub #name: #prop_type,
-        }
+        };
+        (property, de_fn)
     }
 
     fn generate_action_parameter(p: &Parameter<'_>, config: &Config) -> TokenStream {
@@ -836,6 +1392,19 @@ impl<'a> StructDef<'a> {
                 impl #top::Creatable<#create_name, #result_name> for #name {}
             });
         }
+
+        let insertable = self.odata.insertable.is_some_and(|v| v.inner().value);
+        let updatable = self.odata.updatable.is_some_and(|v| v.inner().value);
+        let deletable = self.odata.deletable.is_some_and(|v| v.inner().value);
+        tokens.extend(quote! {
+            impl #top::EntityCapabilities for #name {
+                const CAPABILITIES: #top::Capabilities = #top::Capabilities {
+                    insertable: #insertable,
+                    updatable: #updatable,
+                    deletable: #deletable,
+                };
+            }
+        });
     }
 
     fn generate_action_function(content: &mut TokenStream, a: &Action, config: &Config) {
@@ -950,6 +1519,7 @@ impl<'a> StructDefBuilder<'a> {
             create_type: None,
             need_redfish_settings: false,
             dynamic_properties: None,
+            uris: &[],
         })
     }
 
@@ -1009,6 +1579,13 @@ impl<'a> StructDefBuilder<'a> {
         self
     }
 
+    /// Setup URIs of the resource (defined by `Redfish.Uris`).
+    #[must_use]
+    pub const fn with_uris(mut self, uris: &'a [String]) -> Self {
+        self.0.uris = uris;
+        self
+    }
+
     /// # Errors
     ///
     /// Returns error if struct definition cannot be generated by the
@@ -1,4 +1,7 @@
+use super::DeOwner;
 use super::StructDef;
+use crate::edmx::PropertyName;
+use crate::generator::rust::StructFieldName;
 use crate::IsNullable;
 use crate::IsRequired;
 use crate::OneOrCollection;
@@ -119,6 +122,58 @@ fn action_parameter_field_generation_combinations() {
     }
 }
 
+#[test]
+fn non_nullable_property_reports_null_with_path() {
+    let name = PropertyName::new("TestProp".parse().expect("valid identifier"));
+    let owner = DeOwner {
+        name: "TestTypeExcerpt",
+        type_name: "TestType",
+    };
+    let annotation = |required: bool, has_default: bool| {
+        StructDef::gen_de_struct_field_serde_annot(
+            owner,
+            StructFieldName::new_property(&name),
+            "TestProp",
+            IsNullable::new(false),
+            IsRequired::new(required),
+            has_default,
+        )
+    };
+
+    let (serde, de_fn) = annotation(true, true);
+    assert_token_eq(
+        &serde,
+        &quote! { #[serde(rename = "TestProp", deserialize_with = "TestTypeExcerpt::de_test_prop")] },
+        "required with default",
+        "serde annotation",
+    );
+    assert!(de_fn
+        .to_string()
+        .contains(&quote! { de_required_non_nullable(de, "TestType.TestProp") }.to_string()));
+
+    let (serde, de_fn) = annotation(true, false);
+    assert_token_eq(
+        &serde,
+        &quote! { #[serde(rename = "TestProp")] },
+        "required without default",
+        "serde annotation",
+    );
+    assert!(de_fn.is_empty());
+
+    let (serde, de_fn) = annotation(false, true);
+    assert_token_eq(
+        &serde,
+        &quote! {
+            #[serde(rename = "TestProp", default, deserialize_with = "TestTypeExcerpt::de_test_prop")]
+        },
+        "optional",
+        "serde annotation",
+    );
+    assert!(de_fn
+        .to_string()
+        .contains(&quote! { de_optional_non_nullable(de, "TestType.TestProp") }.to_string()));
+}
+
 fn assert_token_eq(actual: &TokenStream, expected: &TokenStream, case: &str, field: &str) {
     assert_eq!(actual.to_string(), expected.to_string(), "{case}: {field}");
 }
@@ -25,6 +25,9 @@
 //!   `Compiled` (`compiler`); optionally optimize the set (`optimizer`)
 //! - Generate: produce Rust modules and types (`generator`)
 //!
+//! The compiled model is a public API: it can be consumed directly or
+//! serialized with `serde` by tools that don't need Rust code.
+//!
 //! Key features
 //! - Understands `OData` annotations (permissions, insert/update/delete)
 //! - Handles Redfish specifics (required flags, settings, actions)
@@ -87,7 +90,7 @@ pub type IsNullable = TaggedType<bool, IsNullableTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Copy, Clone)]
-#[transparent(Debug, Deserialize)]
+#[transparent(Debug, Deserialize, Serialize)]
 #[capability(inner_access)]
 pub enum IsNullableTag {}
 
@@ -96,7 +99,7 @@ pub type IsRequired = TaggedType<bool, IsRequiredTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Display, Debug)]
+#[transparent(Display, Debug, Serialize)]
 #[capability(inner_access)]
 pub enum IsRequiredTag {}
 
@@ -105,7 +108,7 @@ pub type IsRequiredOnCreate = TaggedType<bool, IsRequiredOnCreateTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Display, Debug)]
+#[transparent(Display, Debug, Serialize)]
 #[capability(inner_access)]
 pub enum IsRequiredOnCreateTag {}
 
@@ -114,7 +117,7 @@ pub type IsExcerptCopyOnly = TaggedType<bool, IsExcerptCopyOnlyTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Display, Debug)]
+#[transparent(Display, Debug, Serialize)]
 #[capability(inner_access)]
 pub enum IsExcerptCopyOnlyTag {}
 
@@ -123,7 +126,7 @@ pub type IsAbstract = TaggedType<bool, IsAbstractTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Display, Debug, Deserialize)]
+#[transparent(Display, Debug, Deserialize, Serialize)]
 #[capability(inner_access)]
 pub enum IsAbstractTag {}
 
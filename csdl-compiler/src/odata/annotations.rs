@@ -26,6 +26,7 @@ use crate::edmx::Namespace;
 use crate::edmx::NavigationProperty;
 use crate::edmx::Parameter;
 use crate::edmx::StructuralProperty;
+use serde::Serialize;
 use tagged_types::TaggedType;
 
 /// A brief description of a model element.
@@ -33,7 +34,7 @@ pub type DescriptionRef<'a> = TaggedType<&'a String, DescriptionTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Display, Debug)]
+#[transparent(Display, Debug, Serialize)]
 #[capability(inner_access, cloned)]
 pub enum DescriptionTag {}
 
@@ -42,7 +43,7 @@ pub type LongDescriptionRef<'a> = TaggedType<&'a String, LongDescriptionTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Display, Debug)]
+#[transparent(Display, Debug, Serialize)]
 #[capability(inner_access, cloned)]
 pub enum LongDescriptionTag {}
 
@@ -52,12 +53,12 @@ pub type AdditionalProperties = TaggedType<bool, AdditionalPropertiesTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Display, Debug)]
+#[transparent(Display, Debug, Serialize)]
 #[capability(inner_access, cloned)]
 pub enum AdditionalPropertiesTag {}
 
 /// Capabilities of Enity type
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct Capability<'a> {
     pub value: bool,
     pub description: Option<DescriptionRef<'a>>,
@@ -68,7 +69,7 @@ pub type Insertable<'a> = TaggedType<Capability<'a>, InsertableTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Debug)]
+#[transparent(Debug, Serialize)]
 #[capability(inner_access)]
 pub enum InsertableTag {}
 
@@ -77,7 +78,7 @@ pub type Updatable<'a> = TaggedType<Capability<'a>, UpdatableTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Debug)]
+#[transparent(Debug, Serialize)]
 #[capability(inner_access)]
 pub enum UpdatableTag {}
 
@@ -86,12 +87,12 @@ pub type Deletable<'a> = TaggedType<Capability<'a>, DeletableTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
 #[implement(Clone, Copy)]
-#[transparent(Debug)]
+#[transparent(Debug, Serialize)]
 #[capability(inner_access)]
 pub enum DeletableTag {}
 
 /// Permissions for accessing a resource.
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Permissions {
     #[default]
     Read,
@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use serde::Serialize;
 use std::fmt::Debug;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
@@ -21,6 +22,7 @@ use std::fmt::Result as FmtResult;
 ///
 /// This is common construction in compiler when we need to describe
 /// singleton or collection of items of specific type.
+#[derive(Serialize)]
 pub enum OneOrCollection<T> {
     One(T),
     Collection(T),
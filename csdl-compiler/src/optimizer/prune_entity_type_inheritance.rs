@@ -93,11 +93,15 @@ pub fn prune_entity_type_inheritance<'a>(input: Compiled<'a>, config: &Config) -
                 let mut base = v.base;
                 let mut properties = vec![v.properties];
                 let mut odata = v.odata;
+                let mut uris = v.uris;
                 while let Some(next_base) = base {
                     if let Some(parent) = remove.remove(&next_base) {
                         properties.push(parent.properties);
                         base = parent.base;
                         merge_odata(&mut odata, parent.odata);
+                        if uris.is_empty() {
+                            uris = parent.uris;
+                        }
                     } else {
                         break;
                     }
@@ -111,6 +115,7 @@ pub fn prune_entity_type_inheritance<'a>(input: Compiled<'a>, config: &Config) -
                         properties: Properties::rev_join(properties),
                         odata,
                         is_abstract: v.is_abstract,
+                        uris,
                     },
                 )
             })
@@ -16,7 +16,7 @@
 //! Remove empty entity types optimization
 //!
 //! Compiler can remove enity types that doesn't have any properties
-//! and navigation properties and key. Redfish schema introduces
+//! and navigation properties, key and URIs. Redfish schema introduces
 //! plenty of such types. They are definitely not needed for code
 //! generation.
 
@@ -86,7 +86,7 @@ pub fn remove_empty_entity_types<'a>(input: Compiled<'a>, config: &Config) -> Co
 }
 
 const fn et_is_empty(et: &EntityType<'_>) -> bool {
-    et.properties.is_empty() && et.key.is_none() && et.odata.is_empty()
+    et.properties.is_empty() && et.key.is_none() && et.odata.is_empty() && et.uris.is_empty()
 }
 
 fn collect_et_replacements<'a>(input: &Compiled<'a>, config: &Config) -> Replacements<'a> {
@@ -15,6 +15,7 @@
 
 use crate::edmx::Annotation;
 use crate::edmx::ComplexType;
+use crate::edmx::EntityType;
 use crate::edmx::NavigationProperty;
 use crate::edmx::Parameter;
 use crate::edmx::StructuralProperty;
@@ -22,6 +23,8 @@ use crate::redfish::DynamicProperties;
 use crate::redfish::Excerpt;
 use crate::redfish::ExcerptCopy;
 use crate::redfish::ExcerptKey;
+use crate::redfish::Revision;
+use crate::redfish::RevisionKind;
 use crate::IsExcerptCopyOnly;
 use crate::IsRequired;
 use crate::IsRequiredOnCreate;
@@ -125,6 +128,52 @@ pub trait RedfishAnnotations {
                 })
             })
     }
+
+    /// Returns URIs of the resource defined by `Redfish.Uris`
+    /// annotation. Empty if annotation is not present.
+    fn uris(&self) -> &[String] {
+        self.annotations()
+            .iter()
+            .find(|a| a.is_redfish_annotation("Uris"))
+            .and_then(|v| v.collection.as_ref())
+            .map_or(&[], |collection| collection.strings.as_slice())
+    }
+
+    /// Returns revisions defined by `Redfish.Revisions` annotation.
+    /// Records with unknown kind or without version are skipped.
+    fn revisions(&self) -> Vec<Revision<'_>> {
+        self.annotations()
+            .iter()
+            .find(|a| a.is_redfish_annotation("Revisions"))
+            .and_then(|v| v.collection.as_ref())
+            .map_or_else(Vec::new, |collection| {
+                collection
+                    .record
+                    .iter()
+                    .filter_map(|record| {
+                        let kind = record
+                            .property_value("Kind")
+                            .and_then(|k| k.enum_member.as_ref())
+                            .and_then(|k| match k.mname.inner().inner().as_str() {
+                                "Added" => Some(RevisionKind::Added),
+                                "Modified" => Some(RevisionKind::Modified),
+                                "Deprecated" => Some(RevisionKind::Deprecated),
+                                _ => None,
+                            })?;
+                        let version = record
+                            .property_value("Version")
+                            .and_then(|v| v.string_value.as_ref())?;
+                        Some(Revision {
+                            kind,
+                            version,
+                            description: record
+                                .property_value("Description")
+                                .and_then(|d| d.string_value.as_ref()),
+                        })
+                    })
+                    .collect()
+            })
+    }
 }
 
 impl RedfishAnnotations for StructuralProperty {
@@ -145,6 +194,12 @@ impl RedfishAnnotations for Parameter {
     }
 }
 
+impl RedfishAnnotations for EntityType {
+    fn annotations(&self) -> &Vec<Annotation> {
+        &self.annotations
+    }
+}
+
 impl RedfishAnnotations for ComplexType {
     fn annotations(&self) -> &Vec<Annotation> {
         &self.annotations
@@ -13,6 +13,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use serde::Serialize;
+use serde::Serializer;
+use std::collections::BTreeSet;
 use std::collections::HashSet;
 use tagged_types::TaggedType;
 
@@ -20,18 +23,25 @@ use tagged_types::TaggedType;
 pub type ExcerptKey = TaggedType<String, ExcerptKeyTag>;
 #[doc(hidden)]
 #[derive(tagged_types::Tag)]
-#[implement(Clone, Copy, Hash, PartialEq, Eq)]
-#[transparent(Display, Debug)]
+#[implement(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[transparent(Display, Debug, Serialize)]
 #[capability(inner_access)]
 pub enum ExcerptKeyTag {}
 
 /// Defines excerpt status of the property.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub enum Excerpt {
     /// Property is included in any excerpt copy.
     All,
     /// Property is include in copies with the specified keys.
-    Keys(HashSet<ExcerptKey>),
+    Keys(#[serde(serialize_with = "serialize_sorted")] HashSet<ExcerptKey>),
+}
+
+fn serialize_sorted<S: Serializer>(
+    keys: &HashSet<ExcerptKey>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    keys.iter().collect::<BTreeSet<_>>().serialize(serializer)
 }
 
 impl Excerpt {
@@ -53,7 +63,7 @@ impl Excerpt {
 /// contains. `AllKeys` defines that all attribures marked as Excerpt
 /// shall be included. If specific key is defined then only attributes
 /// marked with `ExcerptKey` must be included.
-#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+#[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize)]
 pub enum ExcerptCopy {
     AllKeys,
     Key(ExcerptKey),
@@ -30,9 +30,34 @@ pub use excerpt::ExcerptKey;
 #[doc(inline)]
 pub use excerpt::ExcerptCopy;
 
+use serde::Serialize;
+
 /// Dynamic properties defined by the type.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize)]
 pub struct DynamicProperties<'a> {
     pub pattern: &'a String,
     pub ptype: &'a String,
 }
+
+/// Kind of the schema revision (`Redfish.RevisionKind`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum RevisionKind {
+    /// Definition was added.
+    Added,
+    /// Definition was modified.
+    Modified,
+    /// Definition was deprecated.
+    Deprecated,
+}
+
+/// Revision of the definition defined by `Redfish.Revisions`
+/// annotation.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Revision<'a> {
+    /// Kind of the revision.
+    pub kind: RevisionKind,
+    /// Version of the schema in namespace format (`v1_5_0`).
+    pub version: &'a String,
+    /// Description of the revision.
+    pub description: Option<&'a String>,
+}
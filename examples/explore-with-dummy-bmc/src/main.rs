@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use futures_util::StreamExt;
 use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::query::FilterQuery;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::HttpPushUriUpdateRequest;
 use nv_redfish_core::{
@@ -501,7 +502,7 @@ impl Bmc for MockBmc {
     async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de>>(
         &self,
         _id: &ODataId,
-        _query: nv_redfish_core::FilterQuery,
+        _query: FilterQuery,
     ) -> Result<Arc<T>, Error> {
         todo!("unimplimented")
     }
@@ -577,7 +578,7 @@ impl Bmc for MockBmc {
         })
     }
 
-    async fn delete<R: EntityTypeRef + for<'de> Deserialize<'de>>(
+    async fn delete<R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
         &self,
         _id: &ODataId,
     ) -> Result<ModificationResponse<R>, Self::Error> {
@@ -0,0 +1,125 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Account lifecycle: list, create, change password and delete an
+//! account.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::account::ManagerAccountCreate;
+use nv_redfish::account::ManagerAccountUpdate;
+use nv_redfish::core::ModificationResponse;
+use serde_json::json;
+use serde_json::to_value;
+use serde_json::Value as JsonValue;
+
+use crate::fixtures::collection;
+use crate::fixtures::missing;
+use crate::fixtures::service_root;
+use crate::fixtures::unexpected;
+use crate::fixtures::Bmc;
+use crate::fixtures::Expect;
+use crate::fixtures::ODATA_ID;
+use crate::fixtures::ODATA_TYPE;
+
+const ACCOUNT_SERVICE_PATH: &str = "/redfish/v1/AccountService";
+const ACCOUNTS_PATH: &str = "/redfish/v1/AccountService/Accounts";
+const ACCOUNTS_DATA_TYPE: &str = "#ManagerAccountCollection.ManagerAccountCollection";
+const ACCOUNT_DATA_TYPE: &str = "#ManagerAccount.v1_3_0.ManagerAccount";
+
+pub async fn run() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(
+        &bmc,
+        json!({ "AccountService": { ODATA_ID: ACCOUNT_SERVICE_PATH } }),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        ACCOUNT_SERVICE_PATH,
+        json!({
+            ODATA_ID: ACCOUNT_SERVICE_PATH,
+            ODATA_TYPE: "#AccountService.v1_5_0.AccountService",
+            "Id": "AccountService",
+            "Name": "Account Service",
+            "Accounts": { ODATA_ID: ACCOUNTS_PATH }
+        }),
+    ));
+    let account_service = root
+        .account_service()
+        .await?
+        .ok_or_else(|| missing("AccountService"))?;
+
+    bmc.expect(Expect::expand(
+        ACCOUNTS_PATH,
+        collection(
+            ACCOUNTS_PATH,
+            ACCOUNTS_DATA_TYPE,
+            vec![account("1", "admin", "Administrator")],
+        ),
+    ));
+    let accounts = account_service
+        .accounts()
+        .await?
+        .ok_or_else(|| missing("Accounts"))?;
+    for account in accounts.all_accounts_data().await? {
+        println!("accounts: existing {:?}", account.user_name());
+    }
+
+    let create = ManagerAccountCreate::builder(
+        "initial-password".into(),
+        "operator".into(),
+        "Operator".into(),
+    )
+    .build();
+    bmc.expect(Expect::create(
+        ACCOUNTS_PATH,
+        to_value(&create)?,
+        account("2", "operator", "Operator"),
+    ));
+    let ModificationResponse::Entity(account) = accounts.create_account(create).await? else {
+        return Err(unexpected("account is not returned on create"));
+    };
+    println!("accounts: created {:?}", account.user_name());
+
+    let account_id = format!("{ACCOUNTS_PATH}/2");
+    let update = ManagerAccountUpdate::builder()
+        .with_password("rotated-password".into())
+        .build();
+    bmc.expect(Expect::update_empty(&account_id, to_value(&update)?));
+    account.update(&update).await?;
+    println!("accounts: password of {:?} is changed", account.user_name());
+
+    bmc.expect(Expect::delete(&account_id));
+    account.delete().await?;
+    println!("accounts: deleted {:?}", account.user_name());
+
+    bmc.assert_consumed();
+    Ok(())
+}
+
+fn account(id: &str, user_name: &str, role_id: &str) -> JsonValue {
+    json!({
+        ODATA_ID: format!("{ACCOUNTS_PATH}/{id}"),
+        ODATA_TYPE: ACCOUNT_DATA_TYPE,
+        "Id": id,
+        "Name": "User Account",
+        "Enabled": true,
+        "AccountTypes": ["Redfish"],
+        "UserName": user_name,
+        "RoleId": role_id
+    })
+}
@@ -0,0 +1,115 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Boot override: boot the system once from a UEFI boot option.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::computer_system::boot_option::UefiDevicePath;
+use nv_redfish::computer_system::BootSourceOverrideEnabled;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::fixtures::collection;
+use crate::fixtures::missing;
+use crate::fixtures::service_root;
+use crate::fixtures::Bmc;
+use crate::fixtures::Expect;
+use crate::fixtures::ODATA_ID;
+use crate::fixtures::ODATA_TYPE;
+
+const SYSTEMS_PATH: &str = "/redfish/v1/Systems";
+const SYSTEM_PATH: &str = "/redfish/v1/Systems/System-1";
+const BOOT_OPTIONS_PATH: &str = "/redfish/v1/Systems/System-1/BootOptions";
+const PXE_DEVICE_PATH: &str =
+    "PciRoot(0x0)/Pci(0x1,0x0)/Pci(0x0,0x0)/MAC(B83FD2000001,0x1)/IPv4(0.0.0.0)";
+
+pub async fn run() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(&bmc, json!({ "Systems": { ODATA_ID: SYSTEMS_PATH } })).await?;
+
+    bmc.expect(Expect::expand(
+        SYSTEMS_PATH,
+        collection(
+            SYSTEMS_PATH,
+            "#ComputerSystemCollection.ComputerSystemCollection",
+            vec![json!({
+                ODATA_ID: SYSTEM_PATH,
+                ODATA_TYPE: "#ComputerSystem.v1_20_0.ComputerSystem",
+                "Id": "System-1",
+                "Name": "System-1",
+                "Boot": {
+                    "BootOptions": { ODATA_ID: BOOT_OPTIONS_PATH },
+                    "BootOrder": ["Boot0000", "Boot0001"]
+                }
+            })],
+        ),
+    ));
+    let system = root
+        .systems()
+        .await?
+        .ok_or_else(|| missing("Systems"))?
+        .members()
+        .await?
+        .pop()
+        .ok_or_else(|| missing("System-1"))?;
+    println!("boot-override: boot order {:?}", system.boot_order());
+
+    // Device path is validated against boot options before the
+    // override is sent.
+    bmc.expect(Expect::expand(
+        BOOT_OPTIONS_PATH,
+        collection(
+            BOOT_OPTIONS_PATH,
+            "#BootOptionCollection.BootOptionCollection",
+            vec![
+                boot_option("Boot0000", "HD(1,GPT,5F2C3A10-0000-0000-0000-000000000000)"),
+                boot_option("Boot0001", PXE_DEVICE_PATH),
+            ],
+        ),
+    ));
+    bmc.expect(Expect::update_empty(
+        SYSTEM_PATH,
+        json!({
+            "Boot": {
+                "BootSourceOverrideTarget": "UefiTarget",
+                "BootSourceOverrideEnabled": "Once",
+                "UefiTargetBootSourceOverride": PXE_DEVICE_PATH
+            }
+        }),
+    ));
+    system
+        .set_uefi_target_boot_override(
+            UefiDevicePath::new(PXE_DEVICE_PATH.into()),
+            BootSourceOverrideEnabled::Once,
+        )
+        .await?;
+    println!("boot-override: next boot from {PXE_DEVICE_PATH}");
+
+    bmc.assert_consumed();
+    Ok(())
+}
+
+fn boot_option(id: &str, device_path: &str) -> JsonValue {
+    json!({
+        ODATA_ID: format!("{BOOT_OPTIONS_PATH}/{id}"),
+        ODATA_TYPE: "#BootOption.v1_0_4.BootOption",
+        "Id": id,
+        "Name": id,
+        "BootOptionReference": id,
+        "UefiDevicePath": device_path
+    })
+}
@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Event subscription: register a push receiver for resource events
+//! and remove it.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::core::ModificationResponse;
+use nv_redfish::event_service::EventDestinationProtocol;
+use nv_redfish::event_service::SubscriptionFilter;
+use serde_json::json;
+
+use crate::fixtures::collection;
+use crate::fixtures::missing;
+use crate::fixtures::service_root;
+use crate::fixtures::unexpected;
+use crate::fixtures::Bmc;
+use crate::fixtures::Expect;
+use crate::fixtures::ODATA_ID;
+use crate::fixtures::ODATA_TYPE;
+
+const EVENT_SERVICE_PATH: &str = "/redfish/v1/EventService";
+const SUBSCRIPTIONS_PATH: &str = "/redfish/v1/EventService/Subscriptions";
+const SUBSCRIPTION_PATH: &str = "/redfish/v1/EventService/Subscriptions/1";
+const DESTINATION: &str = "https://collector.example.com/events";
+
+pub async fn run() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(
+        &bmc,
+        json!({ "EventService": { ODATA_ID: EVENT_SERVICE_PATH } }),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        EVENT_SERVICE_PATH,
+        json!({
+            ODATA_ID: EVENT_SERVICE_PATH,
+            ODATA_TYPE: "#EventService.v1_10_0.EventService",
+            "Id": "EventService",
+            "Name": "Event Service",
+            "Subscriptions": { ODATA_ID: SUBSCRIPTIONS_PATH }
+        }),
+    ));
+    let event_service = root
+        .event_service()
+        .await?
+        .ok_or_else(|| missing("EventService"))?;
+
+    bmc.expect(Expect::get(
+        SUBSCRIPTIONS_PATH,
+        collection(
+            SUBSCRIPTIONS_PATH,
+            "#EventDestinationCollection.EventDestinationCollection",
+            vec![],
+        ),
+    ));
+    let subscriptions = event_service
+        .subscriptions()
+        .await?
+        .ok_or_else(|| missing("Subscriptions"))?;
+
+    bmc.expect(Expect::create(
+        SUBSCRIPTIONS_PATH,
+        json!({
+            "Destination": DESTINATION,
+            "Protocol": "Redfish",
+            "RegistryPrefixes": ["ResourceEvent"]
+        }),
+        json!({
+            ODATA_ID: SUBSCRIPTION_PATH,
+            ODATA_TYPE: "#EventDestination.v1_14_0.EventDestination",
+            "Id": "1",
+            "Name": "Subscription 1",
+            "Destination": DESTINATION,
+            "Protocol": "Redfish"
+        }),
+    ));
+    let ModificationResponse::Entity(subscription) = subscriptions
+        .create_subscription(
+            DESTINATION,
+            EventDestinationProtocol::Redfish,
+            SubscriptionFilter::new().registry_prefix("ResourceEvent"),
+        )
+        .await?
+    else {
+        return Err(unexpected("subscription is not returned on create"));
+    };
+    println!(
+        "event-subscription: events are sent to {:?}",
+        subscription.destination()
+    );
+
+    bmc.expect(Expect::delete(SUBSCRIPTION_PATH));
+    subscription.delete().await?;
+    println!("event-subscription: deleted {SUBSCRIPTION_PATH}");
+
+    bmc.assert_consumed();
+    Ok(())
+}
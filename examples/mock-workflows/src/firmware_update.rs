@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Firmware update: start `SimpleUpdate` and poll the task monitor of
+//! the returned task until it completes.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+use std::time::Duration;
+
+use nv_redfish::core::AsyncTask;
+use nv_redfish::core::AsyncTaskResult;
+use nv_redfish::core::ModificationResponse;
+use nv_redfish::update_service::SimpleUpdateParameters;
+use nv_redfish::update_service::TransferProtocolType;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use tokio::time::sleep;
+
+use crate::fixtures::missing;
+use crate::fixtures::odata_id;
+use crate::fixtures::service_root;
+use crate::fixtures::unexpected;
+use crate::fixtures::Bmc;
+use crate::fixtures::Expect;
+use crate::fixtures::ODATA_ID;
+use crate::fixtures::ODATA_TYPE;
+
+const UPDATE_SERVICE_PATH: &str = "/redfish/v1/UpdateService";
+const SIMPLE_UPDATE_PATH: &str = "/redfish/v1/UpdateService/Actions/UpdateService.SimpleUpdate";
+const TASK_SERVICE_PATH: &str = "/redfish/v1/TaskService";
+const TASK_PATH: &str = "/redfish/v1/TaskService/Tasks/7";
+const TASK_MONITOR_PATH: &str = "/redfish/v1/TaskService/TaskMonitors/7";
+const BMC_FIRMWARE_PATH: &str = "/redfish/v1/UpdateService/FirmwareInventory/BMC";
+const IMAGE_URI: &str = "https://images.example.com/bmc.fwpkg";
+
+pub async fn run() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(
+        &bmc,
+        json!({
+            "UpdateService": { ODATA_ID: UPDATE_SERVICE_PATH },
+            "Tasks": { ODATA_ID: TASK_SERVICE_PATH }
+        }),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        UPDATE_SERVICE_PATH,
+        json!({
+            ODATA_ID: UPDATE_SERVICE_PATH,
+            ODATA_TYPE: "#UpdateService.v1_9_0.UpdateService",
+            "Id": "UpdateService",
+            "Name": "Update Service",
+            "Actions": {
+                "#UpdateService.SimpleUpdate": {
+                    "target": SIMPLE_UPDATE_PATH
+                }
+            }
+        }),
+    ));
+    let update_service = root
+        .update_service()
+        .await?
+        .ok_or_else(|| missing("UpdateService"))?;
+
+    bmc.expect(Expect::action_task(
+        SIMPLE_UPDATE_PATH,
+        json!({
+            "ImageURI": IMAGE_URI,
+            "TransferProtocol": "HTTPS",
+            "Targets": [BMC_FIRMWARE_PATH]
+        }),
+        AsyncTask {
+            location: odata_id(TASK_PATH).into(),
+            retry_after: Some(Duration::from_millis(10)),
+        },
+    ));
+    let response = update_service
+        .simple_update_with_parameters(&SimpleUpdateParameters {
+            image_uri: Some(IMAGE_URI.into()),
+            transfer_protocol: Some(TransferProtocolType::Https),
+            targets: Some(vec![BMC_FIRMWARE_PATH.into()]),
+            username: None,
+            password: None,
+            force_update: None,
+            stage: None,
+            local_image: None,
+            exclude_targets: None,
+        })
+        .await?;
+    let ModificationResponse::Task(task) = response else {
+        return Err(unexpected("update is expected to run as a task"));
+    };
+    println!("firmware-update: started task {}", task.location.0);
+
+    bmc.expect(Expect::get(
+        TASK_SERVICE_PATH,
+        json!({
+            ODATA_ID: TASK_SERVICE_PATH,
+            ODATA_TYPE: "#TaskService.v1_1_4.TaskService",
+            "Id": "TaskService",
+            "Name": "Task Service",
+            "Tasks": { ODATA_ID: "/redfish/v1/TaskService/Tasks" }
+        }),
+    ));
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| missing("TaskService"))?;
+    let monitor = task_service.task_monitor(task)?;
+
+    bmc.expect(Expect::get(TASK_PATH, task_payload("Running", 40)));
+    bmc.expect(Expect::get(TASK_PATH, task_payload("Running", 80)));
+    bmc.expect(Expect::task_monitor_running(
+        TASK_MONITOR_PATH,
+        Some(Duration::from_millis(10)),
+    ));
+    bmc.expect(Expect::task_monitor_finished(
+        TASK_MONITOR_PATH,
+        AsyncTaskResult {
+            location: Some(odata_id(BMC_FIRMWARE_PATH)),
+            payload: None,
+        },
+    ));
+    bmc.expect(Expect::get(TASK_PATH, task_payload("Completed", 100)));
+    let progress = monitor.task().await?;
+    println!(
+        "firmware-update: task is {:?} ({:?}%)",
+        progress.state(),
+        progress.percent_complete()
+    );
+    let result = monitor
+        .wait_for_completion(Duration::from_millis(10), sleep)
+        .await?;
+    let location = result
+        .location
+        .ok_or_else(|| unexpected("update result is expected to have Location"))?;
+    println!("firmware-update: task is completed, updated {location}");
+
+    bmc.assert_consumed();
+    Ok(())
+}
+
+fn task_payload(state: &str, percent_complete: i64) -> JsonValue {
+    json!({
+        ODATA_ID: TASK_PATH,
+        ODATA_TYPE: "#Task.v1_4_3.Task",
+        "Id": "7",
+        "Name": "BMC firmware update",
+        "TaskState": state,
+        "PercentComplete": percent_complete,
+        "TaskMonitor": TASK_MONITOR_PATH
+    })
+}
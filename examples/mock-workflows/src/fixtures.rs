@@ -0,0 +1,95 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Payloads served by the mock BMC in all workflows.
+
+use std::error::Error as StdError;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::sync::Arc;
+
+use nv_redfish::core::ODataId;
+use nv_redfish::ServiceRoot;
+use nv_redfish_bmc_mock::Bmc as MockBmc;
+use nv_redfish_bmc_mock::Expect as MockExpect;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+pub type Bmc = MockBmc<IoError>;
+pub type Expect = MockExpect<IoError>;
+
+pub const ODATA_ID: &str = "@odata.id";
+pub const ODATA_TYPE: &str = "@odata.type";
+
+const SERVICE_ROOT_PATH: &str = "/redfish/v1";
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+
+/// Expect the service root with `links` to services and create
+/// `ServiceRoot` on top of the mock.
+pub async fn service_root(
+    bmc: &Arc<Bmc>,
+    links: JsonValue,
+) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    let mut root = json!({
+        ODATA_ID: SERVICE_ROOT_PATH,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "Root Service",
+        "ProtocolFeaturesSupported": {
+            "ExpandQuery": {
+                "NoLinks": true
+            }
+        },
+        "Links": {
+            "Sessions": {
+                ODATA_ID: "/redfish/v1/SessionService/Sessions"
+            }
+        }
+    });
+    if let (Some(root), JsonValue::Object(links)) = (root.as_object_mut(), links) {
+        root.extend(links);
+    }
+    bmc.expect(Expect::get(SERVICE_ROOT_PATH, root));
+    Ok(ServiceRoot::new(Arc::clone(bmc)).await?)
+}
+
+/// Collection payload with `members`.
+pub fn collection(id: &str, data_type: &str, members: Vec<JsonValue>) -> JsonValue {
+    json!({
+        ODATA_ID: id,
+        ODATA_TYPE: data_type,
+        "Name": "Collection",
+        "Members": members
+    })
+}
+
+pub fn odata_id(id: &str) -> ODataId {
+    ODataId::from(id.to_string())
+}
+
+/// Error reported when the service doesn't behave as the workflow
+/// expects.
+pub fn unexpected(what: &str) -> Box<dyn StdError> {
+    IoError::new(
+        ErrorKind::InvalidData,
+        format!("unexpected response: {what}"),
+    )
+    .into()
+}
+
+/// Error reported when a resource is not exposed by the service.
+pub fn missing(what: &str) -> Box<dyn StdError> {
+    IoError::new(ErrorKind::NotFound, format!("{what} is not available")).into()
+}
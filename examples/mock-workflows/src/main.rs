@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Write workflows of `nv-redfish` run against the mock BMC.
+//!
+//! Each workflow sets up expected requests and responses of the mock,
+//! drives the public API and checks that all expected requests were
+//! made. The example exits with an error if any workflow fails, so
+//! it is run in CI to guard the APIs against regressions.
+
+mod accounts;
+mod boot_override;
+mod event_subscription;
+mod firmware_update;
+mod fixtures;
+
+use std::error::Error as StdError;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn StdError>> {
+    accounts::run().await?;
+    firmware_update::run().await?;
+    boot_override::run().await?;
+    event_subscription::run().await?;
+    Ok(())
+}
@@ -15,6 +15,7 @@
 
 use nv_redfish_csdl_compiler::commands::process_command;
 use nv_redfish_csdl_compiler::commands::Commands;
+use nv_redfish_csdl_compiler::commands::OutputFormat;
 use nv_redfish_csdl_compiler::Error;
 use nv_redfish_schema::glob_oem_xml;
 use nv_redfish_schema::glob_redfish_xml;
@@ -34,6 +35,8 @@ fn run() -> Result<(), Error> {
 
     process_command(&Commands::CompileOem {
         output: out_dir().join("redfish_oem_contoso.rs"),
+        split: false,
+        format: OutputFormat::Rust,
         root_csdls,
         resolve_csdls,
         entity_type_patterns: ["ServiceRoot.*.*", "LogEntry.*"]
@@ -42,6 +45,7 @@ fn run() -> Result<(), Error> {
             .collect::<Result<Vec<_>, _>>()
             .expect("must be successfuly parsed"),
         rigid_array_patterns: vec![],
+        intern_strings: false,
     })?;
     Ok(())
 }
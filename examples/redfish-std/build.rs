@@ -15,6 +15,7 @@
 
 use nv_redfish_csdl_compiler::commands::process_command;
 use nv_redfish_csdl_compiler::commands::Commands;
+use nv_redfish_csdl_compiler::commands::OutputFormat;
 use nv_redfish_csdl_compiler::commands::DEFAULT_ROOT;
 use nv_redfish_csdl_compiler::Error;
 use nv_redfish_schema::glob_redfish_xml;
@@ -62,6 +63,8 @@ fn run() -> Result<(), Error> {
         .collect::<Result<Vec<_>, _>>()
         .expect("must be successfuly parsed"),
         output: out_dir().join("redfish.rs"),
+        split: false,
+        format: OutputFormat::Rust,
         csdls,
         entity_type_patterns: [
             "ServiceRoot.*.*",
@@ -89,6 +92,7 @@ fn run() -> Result<(), Error> {
         .collect::<Result<Vec<_>, _>>()
         .expect("must be successfuly parsed"),
         rigid_array_patterns: vec![],
+        intern_strings: false,
     })?;
     Ok(())
 }
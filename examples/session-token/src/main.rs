@@ -14,14 +14,16 @@
 // limitations under the License.
 
 use clap::Parser;
+use nv_redfish::bmc_http::credentials::FileCredentials;
 use nv_redfish::bmc_http::reqwest::Client;
 use nv_redfish::bmc_http::reqwest::ClientParams;
 use nv_redfish::bmc_http::BmcCredentials;
 use nv_redfish::bmc_http::CacheSettings;
+use nv_redfish::bmc_http::CredentialsProvider as _;
 use nv_redfish::bmc_http::HttpBmc;
-use nv_redfish::session_service::SessionCreate;
 use nv_redfish::ServiceRoot;
 use std::error::Error as StdError;
+use std::path::PathBuf;
 use std::sync::Arc;
 use url::Url;
 
@@ -31,11 +33,16 @@ struct Args {
     #[arg(long)]
     bmc: Url,
 
-    #[arg(long)]
-    username: String,
+    #[arg(long, required_unless_present = "credentials_file")]
+    username: Option<String>,
+
+    #[arg(long, required_unless_present = "credentials_file")]
+    password: Option<String>,
 
+    /// JSON file with credentials of BMC endpoints, used instead of
+    /// `--username` and `--password`.
     #[arg(long)]
-    password: String,
+    credentials_file: Option<PathBuf>,
 
     #[arg(long, default_value_t = false)]
     insecure: bool,
@@ -50,11 +57,16 @@ struct Args {
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn StdError>> {
     let args = Args::parse();
+    let credentials = match (args.credentials_file, args.username, args.password) {
+        (Some(path), _, _) => FileCredentials::new(path).require_credentials(&args.bmc)?,
+        (None, Some(username), Some(password)) => BmcCredentials::new(username, password),
+        _ => return Err("either credentials file or username and password are required".into()),
+    };
     let client = Client::with_params(ClientParams::new().accept_invalid_certs(args.insecure))?;
     let bmc = Arc::new(HttpBmc::new(
         client,
         args.bmc,
-        BmcCredentials::new(args.username.clone(), args.password.clone()),
+        credentials.clone(),
         CacheSettings::default(),
     ));
 
@@ -68,8 +80,9 @@ async fn main() -> Result<(), Box<dyn StdError>> {
         .await?
         .ok_or("SessionService did not expose Sessions collection")?;
     let session = sessions
-        .create_session(&SessionCreate::builder(args.username, args.password).build())
-        .await?;
+        .create_session_with_credentials(&credentials)
+        .await?
+        .ok_or("Session requires username and password")?;
     let token = session
         .auth_token()
         .ok_or("Session creation response did not include X-Auth-Token")?
@@ -111,15 +111,11 @@ async fn main() -> Result<(), Box<dyn StdError>> {
         .with_targets(args.targets)
         .build();
 
-    let response = update_service
-        .multipart_update_from_reader::<_, _, serde_json::Value>(
-            &parameters,
-            update_stream,
-            Duration::from_secs(1800),
-        )
+    let task = update_service
+        .simple_update_multipart(&parameters, update_stream, Duration::from_secs(1800))
         .await?;
 
-    println!("{response:#?}");
+    println!("Update task: {}", task.location.0);
 
     Ok(())
 }
@@ -0,0 +1,36 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzz `EdmDuration` parsing. Durations come from BMC payloads, so
+//! parser must reject malformed input without panics. Accepted values
+//! must survive display and parse round trip.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nv_redfish_core::EdmDuration;
+use std::str::from_utf8;
+use std::str::FromStr as _;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = from_utf8(data) else {
+        return;
+    };
+    if let Ok(duration) = EdmDuration::from_str(s) {
+        let canonical = duration.to_string();
+        let reparsed = EdmDuration::from_str(&canonical).expect("canonical form is parsed");
+        assert_eq!(reparsed.to_string(), canonical);
+    }
+});
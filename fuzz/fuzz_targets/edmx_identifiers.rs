@@ -0,0 +1,38 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzz parsing of EDMX identifiers (`SimpleIdentifier`,
+//! `QualifiedName`, `TypeName`). Accepted identifiers must be
+//! displayed exactly as they were parsed.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nv_redfish_csdl_compiler::edmx::QualifiedName;
+use nv_redfish_csdl_compiler::edmx::SimpleIdentifier;
+use nv_redfish_csdl_compiler::edmx::TypeName;
+use std::str::from_utf8;
+use std::str::FromStr as _;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = from_utf8(data) else {
+        return;
+    };
+    if let Ok(id) = SimpleIdentifier::from_str(s) {
+        assert_eq!(id.to_string(), s);
+    }
+    let _ = QualifiedName::from_str(s);
+    let _ = TypeName::from_str(s);
+});
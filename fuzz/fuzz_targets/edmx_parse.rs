@@ -0,0 +1,30 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzz EDMX (CSDL XML) parsing and validation. Vendor OEM schemas
+//! are not under control of the project, so malformed documents must
+//! be reported as errors.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nv_redfish_csdl_compiler::edmx::Edmx;
+use std::str::from_utf8;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = from_utf8(data) {
+        let _ = Edmx::parse(s);
+    }
+});
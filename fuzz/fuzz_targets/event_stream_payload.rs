@@ -0,0 +1,28 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fuzz deserialization of SSE payloads of the event service. It
+//! applies JSON payload patches for vendor quirks (missing identity
+//! fields, compact timestamp offsets, etc.) before typed
+//! deserialization, so patches must tolerate arbitrary JSON.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nv_redfish::event_service::EventStreamPayload;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = serde_json::from_slice::<EventStreamPayload>(data);
+});
@@ -0,0 +1,128 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Instruction-count benchmarks for payload deserialization
+//! (gungraun / Valgrind Callgrind).
+//!
+//! `expanded_collection` deserializes `$expand`ed chassis collections,
+//! the largest responses a client usually receives. `metric_report`
+//! deserializes SSE metric reports through `EventStreamPayload`: the
+//! payload is read into `JsonValue`, patched and converted into the
+//! typed value, which is the path every read patch of this crate takes.
+//! Reports without identity fields measure the cost of the patch itself.
+//!
+//! Payloads are built in setup and deserialized values are returned
+//! from the benchmark, so neither JSON generation nor drop is measured.
+//! Valgrind is unix-only, so the whole benchmark is `cfg(unix)`.
+
+#[cfg(unix)]
+mod unix {
+    use std::hint::black_box;
+
+    use gungraun::library_benchmark;
+    use nv_redfish::event_service::EventStreamPayload;
+    use nv_redfish::schema::chassis_collection::ChassisCollection;
+    use serde_json::json;
+    use serde_json::Value;
+
+    fn chassis(i: usize) -> Value {
+        json!({
+            "@odata.id": format!("/redfish/v1/Chassis/{i}"),
+            "@odata.type": "#Chassis.v1_23_0.Chassis",
+            "Id": i.to_string(),
+            "Name": format!("Chassis {i}"),
+            "ChassisType": "RackMount",
+            "Manufacturer": "Contoso",
+            "Model": "Model 1",
+            "SerialNumber": format!("SN{i:08}"),
+            "PartNumber": "PN-0001",
+            "PowerState": "On",
+            "Status": { "State": "Enabled", "Health": "OK", "HealthRollup": "OK" },
+            "Sensors": { "@odata.id": format!("/redfish/v1/Chassis/{i}/Sensors") },
+            "PowerSubsystem": { "@odata.id": format!("/redfish/v1/Chassis/{i}/PowerSubsystem") },
+            "Links": {
+                "ManagedBy": [{ "@odata.id": "/redfish/v1/Managers/BMC" }],
+                "ComputerSystems": [{ "@odata.id": "/redfish/v1/Systems/1" }]
+            }
+        })
+    }
+
+    fn expanded_chassis_collection(n: usize) -> String {
+        let members = (0..n).map(chassis).collect::<Vec<_>>();
+        json!({
+            "@odata.id": "/redfish/v1/Chassis",
+            "@odata.type": "#ChassisCollection.ChassisCollection",
+            "Name": "Chassis Collection",
+            "Members": members,
+            "Members@odata.count": n
+        })
+        .to_string()
+    }
+
+    fn metric_report(values: usize, with_identity: bool) -> String {
+        let values = (0..values)
+            .map(|i| {
+                json!({
+                    "MetricId": "Reading",
+                    "MetricValue": (i * 10).to_string(),
+                    "Timestamp": "2026-01-01T00:00:00+00:00",
+                    "MetricProperty": format!("/redfish/v1/Chassis/1/Sensors/{i}#/Reading")
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut report = json!({
+            "@odata.type": "#MetricReport.v1_4_2.MetricReport",
+            "MetricReportDefinition": {
+                "@odata.id": "/redfish/v1/TelemetryService/MetricReportDefinitions/Platform"
+            },
+            "MetricValues": values
+        });
+        if with_identity {
+            report["@odata.id"] = json!("/redfish/v1/TelemetryService/MetricReports/Platform");
+            report["Id"] = json!("Platform");
+            report["Name"] = json!("Platform");
+        }
+        report.to_string()
+    }
+
+    #[library_benchmark]
+    #[bench::members_16(expanded_chassis_collection(16))]
+    #[bench::members_256(expanded_chassis_collection(256))]
+    fn expanded_collection(payload: String) -> ChassisCollection {
+        black_box(serde_json::from_str(&payload).expect("valid collection"))
+    }
+
+    #[library_benchmark]
+    #[bench::with_identity(metric_report(256, true))]
+    #[bench::restored_identity(metric_report(256, false))]
+    fn metric_report_payload(payload: String) -> EventStreamPayload {
+        black_box(serde_json::from_str(&payload).expect("valid metric report"))
+    }
+}
+
+#[cfg(unix)]
+use unix::{expanded_collection, metric_report_payload};
+
+#[cfg(unix)]
+gungraun::library_benchmark_group!(
+    name = deserialize;
+    benchmarks = expanded_collection, metric_report_payload
+);
+
+#[cfg(unix)]
+gungraun::main!(library_benchmark_groups = deserialize);
+
+#[cfg(not(unix))]
+fn main() {}
@@ -15,17 +15,20 @@
 
 use nv_redfish_csdl_compiler::commands::process_command;
 use nv_redfish_csdl_compiler::commands::Commands;
+use nv_redfish_csdl_compiler::commands::OutputFormat;
 use nv_redfish_csdl_compiler::commands::DEFAULT_ROOT;
 use nv_redfish_csdl_compiler::features_manifest::FeaturesManifest;
 use nv_redfish_schema::cargo_feature_enabled;
+use nv_redfish_schema::ensure_schemas_exist;
 use nv_redfish_schema::oem_schema;
 use nv_redfish_schema::out_dir;
 use nv_redfish_schema::redfish_schema;
 use nv_redfish_schema::rerun_for;
 use nv_redfish_schema::run_with_big_stack;
 use nv_redfish_schema::swordfish_schema;
+use nv_redfish_schema::REDFISH_CSDL_DIR_ENV;
 use std::error::Error as StdError;
-use std::fs::File;
+use std::fs::write;
 use std::path::PathBuf;
 
 fn main() -> Result<(), String> {
@@ -36,6 +39,7 @@ fn run() -> Result<(), Box<dyn StdError>> {
     let features_manifest = PathBuf::from("features.toml");
     let manifest = FeaturesManifest::read(&features_manifest)?;
     rerun_for([&features_manifest]);
+    println!("cargo:rerun-if-env-changed={REDFISH_CSDL_DIR_ENV}");
 
     let redfish_csdl: [&str; 5] = [
         "Settings_v1.xml",
@@ -80,11 +84,14 @@ fn run() -> Result<(), Box<dyn StdError>> {
         .collect::<Vec<_>>();
 
     rerun_for(&csdls);
+    ensure_schemas_exist(&csdls)?;
 
     process_command(&Commands::Compile {
         root: DEFAULT_ROOT.into(),
         include_root_patterns: features.root_patterns.into_iter().cloned().collect(),
-        output: out_dir.join("redfish.rs"),
+        output: out_dir.join("redfish"),
+        split: true,
+        format: OutputFormat::Rust,
         csdls,
         entity_type_patterns: service_root_patterns
             .iter()
@@ -92,6 +99,7 @@ fn run() -> Result<(), Box<dyn StdError>> {
             .cloned()
             .collect(),
         rigid_array_patterns: features.rigid_array_patterns.into_iter().cloned().collect(),
+        intern_strings: false,
     })?;
 
     // ================================================================================
@@ -112,8 +120,9 @@ fn run() -> Result<(), Box<dyn StdError>> {
 
         let output = out_dir.join(format!("oem-{v}.rs"));
         if vendor_features.is_empty() {
-            // Just create empty output file:
-            File::create(output)?;
+            // Nothing is compiled, only metadata of compiled types
+            // is expected by the crate:
+            write(output, "pub const SCHEMA_TYPES: &[&str] = &[];\n")?;
             continue;
         }
 
@@ -133,13 +142,17 @@ fn run() -> Result<(), Box<dyn StdError>> {
             .collect::<Vec<_>>();
 
         rerun_for(root_csdls.iter().chain(resolve_csdls.iter()));
+        ensure_schemas_exist(&resolve_csdls)?;
 
         process_command(&Commands::CompileOem {
             output,
+            split: false,
+            format: OutputFormat::Rust,
             root_csdls,
             resolve_csdls,
             entity_type_patterns: patterns.into_iter().cloned().collect(),
             rigid_array_patterns: vec![],
+            intern_strings: false,
         })?;
     }
     Ok(())
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Certificates of a manager account.
+//!
+//! Certificates in the `ManagerAccount.Certificates` collection are
+//! used for client certificate authentication of the account.
+
+use crate::schema::certificate::Certificate as CertificateSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+/// Certificate that belongs to a manager account.
+pub struct AccountCertificate<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<CertificateSchema>,
+}
+
+impl<B: Bmc> AccountCertificate<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<CertificateSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get raw certificate schema data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<CertificateSchema> {
+        self.data.clone()
+    }
+
+    /// Remove this certificate from the account.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the certificate returned by the
+    ///   server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deleting the certificate fails.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .delete::<NavProperty<CertificateSchema>>(self.data.odata_id())
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await
+    }
+}
+
+impl<B: Bmc> Resource for AccountCertificate<B> {
+    type Entity = CertificateSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+}
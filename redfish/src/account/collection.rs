@@ -24,6 +24,9 @@
 //!   - Default: create a new `ManagerAccount` resource.
 //!   - Slot-defined mode: reuse the first available disabled slot,
 //!     honoring `min_slot` when configured.
+//!   - Service accounts for automation: `create_service_account`
+//!     creates a Redfish-only account that does not require password
+//!     change and bypasses multi-factor authentication.
 //!
 //! Configuration:
 //! - `account`: controls read patching via `read_patch_fn`.
@@ -31,6 +34,8 @@
 //!   - `min_slot`: minimum numeric slot id considered.
 //!   - `hide_disabled`: omit disabled accounts from `all_accounts_data`.
 //!   - `disable_account_on_delete`: prefer disabling over deletion.
+//! - `service_account`: properties of `ManagerAccount` that can be
+//!   used for service accounts creation.
 //!
 //! Other:
 //! - `odata_id()` returns the collection `@odata.id` (typically
@@ -40,8 +45,11 @@
 
 use crate::account::Account;
 use crate::account::AccountConfig;
+use crate::account::AccountTypes;
 use crate::account::ManagerAccountCreate;
 use crate::account::ManagerAccountUpdate;
+use crate::account::MfaBypassType;
+use crate::account::MfaBypassUpdate;
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::CreateWithPatch;
 use crate::patch_support::ReadPatchFn;
@@ -51,10 +59,14 @@ use crate::schema::resource::ResourceCollection;
 use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EdmDateTimeOffset;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataId;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
 use std::sync::Arc;
 
 /// Configuration for slot-defined user accounts.
@@ -73,6 +85,42 @@ pub struct SlotDefinedConfig {
     pub disable_account_on_delete: bool,
 }
 
+/// Configuration for service accounts creation.
+///
+/// Defines which `ManagerAccount` properties besides user name,
+/// password and role are sent when a service account is created.
+#[derive(Clone)]
+pub struct ServiceAccountConfig {
+    /// Platform accepts `AccountTypes` and `PasswordChangeRequired`
+    /// properties in create requests.
+    pub extended_properties: bool,
+    /// Multi-factor authentication is enabled in the account
+    /// service, so service accounts must bypass it.
+    pub mfa_bypass: bool,
+}
+
+/// Password policy of a service account.
+#[derive(Clone)]
+pub enum ServiceAccountPasswordPolicy {
+    /// Password never expires.
+    NeverExpires(String),
+    /// Password expires at specified time.
+    ExpiresAt(String, EdmDateTimeOffset),
+}
+
+impl Debug for ServiceAccountPasswordPolicy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::NeverExpires(_) => f.debug_tuple("NeverExpires").field(&"<redacted>").finish(),
+            Self::ExpiresAt(_, expiration) => f
+                .debug_tuple("ExpiresAt")
+                .field(&"<redacted>")
+                .field(expiration)
+                .finish(),
+        }
+    }
+}
+
 /// Configuration for account collection behavior.
 ///
 /// Combines per-account settings and optional slot-defined mode that changes
@@ -83,6 +131,8 @@ pub struct Config {
     pub account: AccountConfig,
     /// Configuration for slot-defined user accounts.
     pub slot_defined_user_accounts: Option<SlotDefinedConfig>,
+    /// Configuration for service accounts creation.
+    pub service_account: ServiceAccountConfig,
 }
 
 /// Account collection.
@@ -100,8 +150,18 @@ impl<B: Bmc> CollectionWithPatch<ManagerAccountCollection, ManagerAccount, B>
     fn convert_patched(
         base: ResourceCollection,
         members: Vec<NavProperty<ManagerAccount>>,
+        members_count: Option<i64>,
+        next_link: Option<ODataId>,
     ) -> ManagerAccountCollection {
-        ManagerAccountCollection { base, members }
+        ManagerAccountCollection {
+            base,
+            members,
+            members_count,
+            next_link,
+        }
+    }
+    fn collection_members(collection: &ManagerAccountCollection) -> &[NavProperty<ManagerAccount>] {
+        &collection.members
     }
 }
 
@@ -221,6 +281,50 @@ impl<B: Bmc> AccountCollection<B> {
         }
     }
 
+    /// Create a new service account for automation.
+    ///
+    /// Service account is an account used by non-interactive clients:
+    /// it is restricted to Redfish access, does not require password
+    /// change and bypasses multi-factor authentication when the
+    /// account service provides it. Properties that are not supported
+    /// by the platform are not sent. Vendor-specific creation workflow
+    /// is the same as in [`Self::create_account`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating a new account fails.
+    pub async fn create_service_account(
+        &self,
+        user_name: String,
+        role_id: String,
+        password_policy: ServiceAccountPasswordPolicy,
+    ) -> Result<ModificationResponse<Account<B>>, Error<B>> {
+        let (password, expiration) = match password_policy {
+            ServiceAccountPasswordPolicy::NeverExpires(password) => (password, None),
+            ServiceAccountPasswordPolicy::ExpiresAt(password, expiration) => {
+                (password, Some(expiration))
+            }
+        };
+        let mut create = ManagerAccountCreate::builder(password, user_name, role_id);
+        let cfg = &self.config.service_account;
+        if cfg.extended_properties {
+            create = create
+                .with_account_types(vec![AccountTypes::Redfish])
+                .with_password_change_required(false);
+        }
+        if let Some(expiration) = expiration {
+            create = create.with_password_expiration(expiration);
+        }
+        if cfg.mfa_bypass {
+            create = create.with_mfa_bypass(
+                MfaBypassUpdate::builder()
+                    .with_bypass_types(vec![MfaBypassType::All])
+                    .build(),
+            );
+        }
+        self.create_account(create.build()).await
+    }
+
     /// Retrieve account data.
     ///
     /// This method does not update the collection itself. It only
@@ -231,20 +335,18 @@ impl<B: Bmc> AccountCollection<B> {
     /// Returns an error if retrieving account data fails. This can
     /// occur if the account collection was not expanded.
     pub async fn all_accounts_data(&self) -> Result<Vec<Account<B>>, Error<B>> {
-        let mut result = Vec::with_capacity(self.collection.members.len());
+        let mut result = self
+            .bmc
+            .fan_out(&self.collection.members, |m| {
+                Account::new(&self.bmc, m, &self.config.account)
+            })
+            .await?;
         if let Some(cfg) = &self.config.slot_defined_user_accounts {
             // For slot-defined account configuration, disabled accounts may be hidden
             // to make it appear as if they were not created. This behavior is
             // controlled by the `hide_disabled` configuration parameter.
-            for m in &self.collection.members {
-                let account = Account::new(&self.bmc, m, &self.config.account).await?;
-                if !cfg.hide_disabled || account.is_enabled() {
-                    result.push(account);
-                }
-            }
-        } else {
-            for m in &self.collection.members {
-                result.push(Account::new(&self.bmc, m, &self.config.account).await?);
+            if cfg.hide_disabled {
+                result.retain(Account::is_enabled);
             }
         }
         Ok(result)
@@ -17,10 +17,14 @@
 //!
 //! Provides `Account`, an ergonomic handle over a Redfish `ManagerAccount`:
 //! - Read raw data with `raw()`
+//! - Inspect account types and multi-factor authentication bypass
+//!   with `account_types()` and `mfa_bypass_types()`
 //! - Update fields via `update()`, or use helpers `update_password()` and
 //!   `update_user_name()`
 //! - Delete the account with `delete()`; optionally disable instead of deleting
 //!   when configured
+//! - Manage public keys with `keys()` / `add_key()` and certificates with
+//!   `certificates()` / `add_certificate()`
 //!
 //! Configuration:
 //! - `Config::read_patch_fn`: apply read-time JSON patches for vendor
@@ -32,10 +36,18 @@
 //! `AccountCollection`) and do not create accounts on the BMC by themselves.
 //! Use the collection to create new accounts.
 
+use crate::account::AccountCertificate;
+use crate::account::AccountKey;
+use crate::account::AccountTypes;
+use crate::account::CertificateCreate;
+use crate::account::KeyCreate;
 use crate::account::ManagerAccountUpdate;
+use crate::account::MfaBypassType;
 use crate::patch_support::Payload;
 use crate::patch_support::ReadPatchFn;
 use crate::patch_support::UpdateWithPatch;
+use crate::schema::certificate::Certificate as CertificateSchema;
+use crate::schema::key::Key as KeySchema;
 use crate::schema::manager_account::ManagerAccount;
 use crate::Error;
 use crate::NvBmc;
@@ -110,12 +122,35 @@ impl<B: Bmc> Account<B> {
         self.data.clone()
     }
 
+    /// User name of the account.
+    #[must_use]
+    pub fn user_name(&self) -> Option<&str> {
+        self.data.user_name.as_deref()
+    }
+
     /// Account is enabled.
     #[must_use]
     pub fn is_enabled(&self) -> bool {
         self.data.enabled.is_none_or(identity)
     }
 
+    /// Account types allowed for the account.
+    #[must_use]
+    pub fn account_types(&self) -> &[AccountTypes] {
+        self.data.account_types.as_deref().unwrap_or_default()
+    }
+
+    /// Multi-factor authentication types bypassed by the account.
+    #[must_use]
+    pub fn mfa_bypass_types(&self) -> &[MfaBypassType] {
+        self.data
+            .mfa_bypass
+            .as_ref()
+            .and_then(|v| v.bypass_types.as_ref())
+            .and_then(Option::as_deref)
+            .unwrap_or_default()
+    }
+
     /// Update the account.
     ///
     /// Returns one of the following modification outcomes:
@@ -219,10 +254,124 @@ impl<B: Bmc> Account<B> {
                 .await
         }
     }
+
+    /// Public keys of the account.
+    ///
+    /// Returns `Ok(None)` when the account does not expose the `Keys`
+    /// collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving keys fails.
+    pub async fn keys(&self) -> Result<Option<Vec<AccountKey<B>>>, Error<B>> {
+        if let Some(collection_ref) = &self.data.keys {
+            let collection = self.bmc.expand_property(collection_ref).await?;
+            let mut items = Vec::with_capacity(collection.members.len());
+            for m in &collection.members {
+                items.push(AccountKey::new(&self.bmc, m).await?);
+            }
+            Ok(Some(items))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Add a public key to the account.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the added key.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the account does not expose a `Keys` collection
+    /// - creating the key fails
+    pub async fn add_key(
+        &self,
+        create: &KeyCreate,
+    ) -> Result<ModificationResponse<AccountKey<B>>, Error<B>> {
+        let collection_ref = self
+            .data
+            .keys
+            .as_ref()
+            .ok_or(Error::AccountKeysNotAvailable)?;
+        self.bmc
+            .as_ref()
+            .create::<_, NavProperty<KeySchema>>(collection_ref.id(), create)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { AccountKey::new(&self.bmc, &nav).await })
+            .await
+    }
+
+    /// Certificates of the account.
+    ///
+    /// Returns `Ok(None)` when the account does not expose the
+    /// `Certificates` collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving certificates fails.
+    pub async fn certificates(&self) -> Result<Option<Vec<AccountCertificate<B>>>, Error<B>> {
+        if let Some(collection_ref) = &self.data.certificates {
+            let collection = self.bmc.expand_property(collection_ref).await?;
+            let mut items = Vec::with_capacity(collection.members.len());
+            for m in &collection.members {
+                items.push(AccountCertificate::new(&self.bmc, m).await?);
+            }
+            Ok(Some(items))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Add a certificate to the account.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the added certificate.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the account does not expose a `Certificates` collection
+    /// - creating the certificate fails
+    pub async fn add_certificate(
+        &self,
+        create: &CertificateCreate,
+    ) -> Result<ModificationResponse<AccountCertificate<B>>, Error<B>> {
+        let collection_ref = self
+            .data
+            .certificates
+            .as_ref()
+            .ok_or(Error::AccountCertificatesNotAvailable)?;
+        self.bmc
+            .as_ref()
+            .create::<_, NavProperty<CertificateSchema>>(collection_ref.id(), create)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(
+                |nav| async move { AccountCertificate::new(&self.bmc, &nav).await },
+            )
+            .await
+    }
 }
 
 impl<B: Bmc> Resource for Account<B> {
+    type Entity = ManagerAccount;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
 }
@@ -0,0 +1,91 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Public keys of a manager account.
+//!
+//! Some implementations use keys in the `ManagerAccount.Keys`
+//! collection for SSH public key authentication of the account.
+
+use crate::schema::key::Key as KeySchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+/// Public key that belongs to a manager account.
+pub struct AccountKey<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<KeySchema>,
+}
+
+impl<B: Bmc> AccountKey<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<KeySchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get raw key schema data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<KeySchema> {
+        self.data.clone()
+    }
+
+    /// Remove this key from the account.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the key returned by the
+    ///   server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deleting the key fails.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .delete::<NavProperty<KeySchema>>(self.data.odata_id())
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await
+    }
+}
+
+impl<B: Bmc> Resource for AccountKey<B> {
+    type Entity = KeySchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+}
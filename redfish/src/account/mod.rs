@@ -20,8 +20,13 @@
 //! This module provides ergonomic wrappers around the generated Redfish
 //! AccountService model:
 //! - `AccountService`: entry point to manage accounts
-//! - `AccountCollection`: access and create `ManagerAccount` members
+//! - `AccountCollection`: access and create `ManagerAccount` members,
+//!   including service accounts for automation
 //! - `Account`: operate on an individual `ManagerAccount`
+//! - `AccountKey`, `AccountCertificate`: per-account public keys and
+//!   certificates
+//! - `PasswordPolicy`: password rules of the service and generation of
+//!   passwords that comply with them
 //!
 //! Vendor compatibility
 //! - Some implementations omit fields marked as `Redfish.Required`.
@@ -29,10 +34,16 @@
 //!   behavior compatible across vendors (for example, defaulting `AccountTypes`).
 //!
 
+/// Certificates of an account.
+mod certificate;
 /// Collection of accounts.
 mod collection;
 /// Account inside account service.
 mod item;
+/// Public keys of an account.
+mod key;
+/// Password policy of the account service.
+mod password;
 
 use crate::patch_support::JsonValue;
 use crate::patch_support::ReadPatchFn;
@@ -41,8 +52,13 @@ use crate::Error;
 use crate::NvBmc;
 use crate::ServiceRoot;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
 use std::sync::Arc;
 
+#[doc(inline)]
+pub use crate::schema::certificate::CertificateCreate;
+#[doc(inline)]
+pub use crate::schema::key::KeyCreate;
 #[doc(inline)]
 pub use crate::schema::manager_account::AccountTypes;
 #[doc(inline)]
@@ -50,11 +66,29 @@ pub use crate::schema::manager_account::ManagerAccountCreate;
 #[doc(inline)]
 pub use crate::schema::manager_account::ManagerAccountUpdate;
 #[doc(inline)]
+pub use crate::schema::manager_account::MfaBypass;
+#[doc(inline)]
+pub use crate::schema::manager_account::MfaBypassType;
+#[doc(inline)]
+pub use crate::schema::manager_account::MfaBypassUpdate;
+#[doc(inline)]
+pub use certificate::AccountCertificate;
+#[doc(inline)]
 pub use item::Account;
+#[doc(inline)]
+pub use key::AccountKey;
+#[doc(inline)]
+pub use password::CharacterClass;
+#[doc(inline)]
+pub use password::PasswordPolicy;
 
 #[doc(inline)]
 pub use collection::AccountCollection;
 #[doc(inline)]
+pub(crate) use collection::ServiceAccountConfig;
+#[doc(inline)]
+pub use collection::ServiceAccountPasswordPolicy;
+#[doc(inline)]
 pub(crate) use collection::SlotDefinedConfig;
 #[doc(inline)]
 pub(crate) use item::Config as AccountConfig;
@@ -76,6 +110,15 @@ impl<B: Bmc> AccountService<B> {
         let Some(service_nav) = root.root.account_service.as_ref() else {
             return Ok(None);
         };
+        Self::from_nav(bmc, service_nav).await.map(Some)
+    }
+
+    /// Create account service from navigation property. Used for
+    /// account services of remote managers in aggregation services.
+    pub(crate) async fn from_nav(
+        bmc: &NvBmc<B>,
+        service_nav: &NavProperty<SchemaAccountService>,
+    ) -> Result<Self, Error<B>> {
         let service = service_nav.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
 
         let mut patches = Vec::new();
@@ -90,7 +133,7 @@ impl<B: Bmc> AccountService<B> {
             Some(account_read_patch_fn)
         };
         let slot_defined_user_accounts = bmc.quirks.slot_defined_user_accounts();
-        Ok(Some(Self {
+        Ok(Self {
             collection_config: collection::Config {
                 account: AccountConfig {
                     read_patch_fn: account_read_patch_fn,
@@ -99,10 +142,14 @@ impl<B: Bmc> AccountService<B> {
                         .is_some_and(|cfg| cfg.disable_account_on_delete),
                 },
                 slot_defined_user_accounts,
+                service_account: ServiceAccountConfig {
+                    extended_properties: !bmc.quirks.account_create_basic_properties_only(),
+                    mfa_bypass: multi_factor_auth_enabled(&service),
+                },
             },
             service,
             bmc: bmc.clone(),
-        }))
+        })
     }
 
     /// Get the raw schema data for this account service.
@@ -114,6 +161,36 @@ impl<B: Bmc> AccountService<B> {
         self.service.clone()
     }
 
+    /// Password policy of the service.
+    ///
+    /// Length limits come from `MinPasswordLength` and
+    /// `MaxPasswordLength`. Redfish does not advertise complexity
+    /// rules, so all character classes are required; use
+    /// `PasswordPolicy::with_required_classes` for BMCs with known
+    /// rules.
+    #[must_use]
+    pub fn password_policy(&self) -> PasswordPolicy {
+        let length = |v: Option<i64>| v.and_then(|v| usize::try_from(v).ok());
+        PasswordPolicy::new(
+            length(self.service.min_password_length),
+            length(self.service.max_password_length),
+        )
+    }
+
+    /// Generate random password that is accepted by the service.
+    ///
+    /// Useful for automated credential rotation: the password
+    /// satisfies `password_policy()`, so account updates are not
+    /// rejected by vendor-specific password checks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the policy of the service cannot be
+    /// satisfied or the random source fails.
+    pub fn generate_compliant_password(&self) -> Result<String, Error<B>> {
+        self.password_policy().generate()
+    }
+
     /// Get the accounts collection.
     ///
     /// Uses `$expand` to retrieve members in a single request when supported.
@@ -136,6 +213,24 @@ impl<B: Bmc> AccountService<B> {
     }
 }
 
+// `MultiFactorAuth` is reported with all methods disabled by services
+// that support multi-factor authentication but don't use it.
+fn multi_factor_auth_enabled(service: &SchemaAccountService) -> bool {
+    service.multi_factor_auth.as_ref().is_some_and(|mfa| {
+        [
+            mfa.secur_id.as_ref().and_then(|v| v.enabled),
+            mfa.google_authenticator.as_ref().and_then(|v| v.enabled),
+            mfa.microsoft_authenticator.as_ref().and_then(|v| v.enabled),
+            mfa.client_certificate.as_ref().and_then(|v| v.enabled),
+            mfa.one_time_passcode.as_ref().and_then(|v| v.enabled),
+            mfa.time_based_one_time_password
+                .as_ref()
+                .and_then(|v| v.enabled),
+        ]
+        .contains(&Some(true))
+    })
+}
+
 // `AccountTypes` is marked as `Redfish.Required`, but some systems
 // ignore this requirement. The account service replaces its value with
 // a reasonable default (see below).
@@ -0,0 +1,238 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Password policy of the account service and generation of
+//! passwords that comply with it.
+//!
+//! Redfish advertises length limits of passwords
+//! (`MinPasswordLength`, `MaxPasswordLength`) but has no standard
+//! property for complexity rules. Many BMCs require characters of
+//! several classes, so by default generated passwords contain
+//! characters of all [`CharacterClass`] values. The set of required
+//! classes can be changed for BMCs with known rules.
+
+use crate::Error;
+use getrandom::getrandom;
+use getrandom::Error as RandomError;
+use nv_redfish_core::Bmc;
+use std::mem::size_of;
+
+/// Length of generated password when the service does not restrict
+/// it.
+const DEFAULT_LENGTH: usize = 16;
+
+/// Characters used when no character class is required.
+const ALPHANUMERIC: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// Class of characters of a password.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CharacterClass {
+    /// Lowercase ASCII letters.
+    Lowercase,
+    /// Uppercase ASCII letters.
+    Uppercase,
+    /// ASCII digits.
+    Digit,
+    /// Special characters. Only characters that are accepted by
+    /// BMCs with restricted special character sets are used (no
+    /// quotes, backslashes, or spaces).
+    Special,
+}
+
+impl CharacterClass {
+    /// All character classes.
+    pub const ALL: [Self; 4] = [Self::Lowercase, Self::Uppercase, Self::Digit, Self::Special];
+
+    const fn characters(self) -> &'static [u8] {
+        match self {
+            Self::Lowercase => b"abcdefghijklmnopqrstuvwxyz",
+            Self::Uppercase => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+            Self::Digit => b"0123456789",
+            Self::Special => b"!#%+-.=@_",
+        }
+    }
+}
+
+/// Password policy of the account service.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    required_classes: Vec<CharacterClass>,
+}
+
+impl PasswordPolicy {
+    /// Create password policy with length limits. All character
+    /// classes are required.
+    #[must_use]
+    pub fn new(min_length: Option<usize>, max_length: Option<usize>) -> Self {
+        Self {
+            min_length,
+            max_length,
+            required_classes: CharacterClass::ALL.to_vec(),
+        }
+    }
+
+    /// Replace required character classes of the policy.
+    #[must_use]
+    pub fn with_required_classes(mut self, classes: &[CharacterClass]) -> Self {
+        let mut classes = classes.to_vec();
+        classes.sort_unstable();
+        classes.dedup();
+        self.required_classes = classes;
+        self
+    }
+
+    /// Minimum length of password accepted by the service.
+    #[must_use]
+    pub const fn min_length(&self) -> Option<usize> {
+        self.min_length
+    }
+
+    /// Maximum length of password accepted by the service.
+    #[must_use]
+    pub const fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Character classes that must be present in password.
+    #[must_use]
+    pub fn required_classes(&self) -> &[CharacterClass] {
+        &self.required_classes
+    }
+
+    /// Generate random password that complies with the policy.
+    ///
+    /// The password is 16 characters long unless length limits of
+    /// the policy require otherwise. It contains at
+    /// least one character of each required class. Characters are
+    /// taken from the operating system random source.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::PasswordGenerationFailed` if the policy cannot
+    /// be satisfied or the random source fails.
+    pub fn generate<B: Bmc>(&self) -> Result<String, Error<B>> {
+        self.try_generate().map_err(Error::PasswordGenerationFailed)
+    }
+
+    fn try_generate(&self) -> Result<String, String> {
+        let min = self
+            .min_length
+            .unwrap_or_default()
+            .max(self.required_classes.len());
+        let max = self.max_length.unwrap_or(usize::MAX);
+        if min > max {
+            return Err(format!(
+                "password policy requires at least {min} characters but allows at most {max}"
+            ));
+        }
+        let length = DEFAULT_LENGTH.clamp(min, max);
+        let pool = if self.required_classes.is_empty() {
+            ALPHANUMERIC.to_vec()
+        } else {
+            self.required_classes
+                .iter()
+                .flat_map(|class| class.characters().iter().copied())
+                .collect()
+        };
+        let random_error = |err: RandomError| format!("random source failed: {err}");
+        let mut password = Vec::with_capacity(length);
+        for class in &self.required_classes {
+            password.push(pick(class.characters()).map_err(random_error)?);
+        }
+        while password.len() < length {
+            password.push(pick(&pool).map_err(random_error)?);
+        }
+        // Fisher-Yates shuffle, so required characters are not
+        // always at the beginning.
+        for i in (1..password.len()).rev() {
+            let j = random_below(i + 1).map_err(random_error)?;
+            password.swap(i, j);
+        }
+        Ok(password.into_iter().map(char::from).collect())
+    }
+}
+
+fn pick(characters: &[u8]) -> Result<u8, RandomError> {
+    random_below(characters.len()).map(|i| characters[i])
+}
+
+/// Uniformly distributed random number in `0..bound`. `bound` must
+/// not be zero.
+fn random_below(bound: usize) -> Result<usize, RandomError> {
+    // Values above the largest multiple of `bound` are rejected to
+    // avoid modulo bias.
+    let zone = usize::MAX - usize::MAX % bound;
+    loop {
+        let mut bytes = [0; size_of::<usize>()];
+        getrandom(&mut bytes)?;
+        let value = usize::from_ne_bytes(bytes);
+        if value < zone {
+            return Ok(value % bound);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CharacterClass;
+    use super::PasswordPolicy;
+
+    fn has_class(password: &str, class: CharacterClass) -> bool {
+        password.bytes().any(|c| class.characters().contains(&c))
+    }
+
+    #[test]
+    fn generated_password_contains_all_required_classes() {
+        let password = PasswordPolicy::new(None, None)
+            .try_generate()
+            .expect("policy is satisfiable");
+        assert_eq!(password.len(), 16);
+        for class in CharacterClass::ALL {
+            assert!(has_class(&password, class));
+        }
+    }
+
+    #[test]
+    fn generated_password_respects_length_limits() {
+        let long = PasswordPolicy::new(Some(20), Some(64))
+            .try_generate()
+            .expect("policy is satisfiable");
+        assert_eq!(long.len(), 20);
+        let short = PasswordPolicy::new(Some(4), Some(8))
+            .try_generate()
+            .expect("policy is satisfiable");
+        assert_eq!(short.len(), 8);
+    }
+
+    #[test]
+    fn generated_password_uses_only_allowed_classes() {
+        let password = PasswordPolicy::new(None, None)
+            .with_required_classes(&[CharacterClass::Digit, CharacterClass::Lowercase])
+            .try_generate()
+            .expect("policy is satisfiable");
+        assert!(!has_class(&password, CharacterClass::Special));
+        assert!(!has_class(&password, CharacterClass::Uppercase));
+    }
+
+    #[test]
+    fn unsatisfiable_policy_is_rejected() {
+        assert!(PasswordPolicy::new(None, Some(3)).try_generate().is_err());
+        assert!(PasswordPolicy::new(Some(10), Some(8))
+            .try_generate()
+            .is_err());
+    }
+}
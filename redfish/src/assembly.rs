@@ -27,10 +27,14 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+use nv_redfish_core::BinaryUploadRequest;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
+use nv_redfish_core::UploadReader;
+use nv_redfish_core::UploadStream;
 use std::sync::Arc;
+use std::time::Duration;
 
 #[doc(hidden)]
 pub enum AssemblyTag {}
@@ -83,26 +87,32 @@ impl<B: Bmc> Assembly<B> {
     /// Returns error if this assembly was not expanded by initial get
     /// and then function failed to get data of the assembly.
     pub async fn assemblies(&self) -> Result<Vec<AssemblyData<B>>, Error<B>> {
-        let mut result = Vec::new();
         if let Some(assemblies) = &self.data.assemblies {
-            for m in assemblies {
-                result.push(AssemblyData::new(&self.bmc, m).await?);
-            }
+            self.bmc
+                .fan_out(assemblies, |m| AssemblyData::new(&self.bmc, m))
+                .await
+        } else {
+            Ok(Vec::new())
         }
-        Ok(result)
     }
 }
 
 impl<B: Bmc> Resource for Assembly<B> {
+    type Entity = AssemblySchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
 }
 
 /// Assembly data.
 pub struct AssemblyData<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<AssemblyDataSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> AssemblyData<B> {
@@ -115,8 +125,8 @@ impl<B: Bmc> AssemblyData<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -156,4 +166,67 @@ impl<B: Bmc> AssemblyData<B> {
                 .map(SerialNumber::new),
         }
     }
+
+    /// Download binary image of the assembly (for example, FRU data)
+    /// from `BinaryDataURI`.
+    ///
+    /// Returns `Ok(None)` when the assembly does not provide
+    /// `BinaryDataURI`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the BMC doesn't support binary data
+    /// transfer ([`Error::BinaryTransferNotSupported`]) or the
+    /// download fails.
+    pub async fn download_binary_data(&self) -> Result<Option<Vec<u8>>, Error<B>> {
+        let Some(uri) = self.binary_data_uri() else {
+            return Ok(None);
+        };
+        self.bmc
+            .as_ref()
+            .binary_download(uri)
+            .await
+            .map_err(Error::Bmc)?
+            .ok_or(Error::BinaryTransferNotSupported)
+            .map(Some)
+    }
+
+    /// Upload binary image of the assembly to `BinaryDataURI`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `BinaryDataURI` is absent, the BMC doesn't
+    /// support binary data transfer
+    /// ([`Error::BinaryTransferNotSupported`]) or the upload fails.
+    pub async fn upload_binary_data<U>(
+        &self,
+        upload_stream: UploadStream<U>,
+        upload_timeout: Duration,
+    ) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        U: UploadReader,
+    {
+        let uri = self
+            .binary_data_uri()
+            .ok_or(Error::AssemblyBinaryDataUriNotAvailable)?;
+        self.bmc
+            .as_ref()
+            .binary_upload(
+                uri,
+                BinaryUploadRequest {
+                    upload_stream,
+                    upload_timeout,
+                },
+            )
+            .await
+            .map_err(Error::Bmc)?
+            .ok_or(Error::BinaryTransferNotSupported)
+    }
+
+    fn binary_data_uri(&self) -> Option<&str> {
+        self.data
+            .binary_data_uri
+            .as_ref()
+            .and_then(Option::as_deref)
+    }
 }
@@ -17,12 +17,20 @@
 //! is built on top of core BMC.
 
 use crate::bmc_quirks::BmcQuirks;
+use crate::fan_out::try_fan_out;
+use crate::fan_out::DEFAULT_FAN_OUT_LIMIT;
 use crate::protocol_features::ExpandQueryFeatures;
+use crate::schema::service_root::ServiceRoot as SchemaServiceRoot;
 use crate::ProtocolFeatures;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::ETagPolicy;
+use nv_redfish_core::LatencyClass;
+use nv_redfish_core::NullPolicy;
+use std::future::Future;
 use std::sync::Arc;
 
 #[cfg(feature = "impl-nv-bmc-expand")]
+use crate::schema_types;
 use crate::Error;
 #[cfg(feature = "impl-nv-bmc-expand")]
 use nv_redfish_core::query::ExpandQuery;
@@ -30,19 +38,46 @@ use nv_redfish_core::query::ExpandQuery;
 use nv_redfish_core::Expandable;
 #[cfg(feature = "impl-nv-bmc-expand")]
 use nv_redfish_core::NavProperty;
+#[cfg(feature = "impl-nv-bmc-expand")]
+use nv_redfish_core::ODataId;
+
+/// HTTP status codes of services that reject `$expand` query they
+/// don't support.
+#[cfg(feature = "impl-nv-bmc-expand")]
+const EXPAND_REJECTED_STATUS_CODES: [u16; 2] = [400, 501];
 
+/// BMC together with configuration that nv-redfish takes in account
+/// when it accesses the service: protocol features and quirks of the
+/// platform.
+///
+/// Created by [`NvBmcBuilder`] and passed to
+/// [`crate::ServiceRoot::with_bmc`].
 pub struct NvBmc<B: Bmc> {
     bmc: Arc<B>,
     protocol_features: Arc<ProtocolFeatures>,
     pub(crate) quirks: Arc<BmcQuirks>,
+    #[allow(dead_code)] // feature-enabled field
+    pub(crate) aggregation_routing: bool,
+    fan_out_limit: usize,
+    adapt_to_slow_bmc: bool,
 }
 
 impl<B: Bmc> NvBmc<B> {
-    pub(crate) fn new(bmc: Arc<B>, protocol_features: ProtocolFeatures, quirks: BmcQuirks) -> Self {
+    pub(crate) fn new(
+        bmc: Arc<B>,
+        protocol_features: ProtocolFeatures,
+        quirks: BmcQuirks,
+        aggregation_routing: bool,
+        fan_out_limit: usize,
+        adapt_to_slow_bmc: bool,
+    ) -> Self {
         Self {
             bmc,
             protocol_features: protocol_features.into(),
             quirks: quirks.into(),
+            aggregation_routing,
+            fan_out_limit,
+            adapt_to_slow_bmc,
         }
     }
 
@@ -51,6 +86,9 @@ impl<B: Bmc> NvBmc<B> {
             bmc,
             protocol_features: self.protocol_features,
             quirks: self.quirks,
+            aggregation_routing: self.aggregation_routing,
+            fan_out_limit: self.fan_out_limit,
+            adapt_to_slow_bmc: self.adapt_to_slow_bmc,
         }
     }
 
@@ -58,16 +96,61 @@ impl<B: Bmc> NvBmc<B> {
         Self {
             bmc: self.bmc,
             protocol_features: ProtocolFeatures {
-                expand: ExpandQueryFeatures {
-                    expand_all: false,
-                    no_links: false,
-                },
+                expand: ExpandQueryFeatures::default(),
+                ..(*self.protocol_features).clone()
             }
             .into(),
             quirks: self.quirks,
+            aggregation_routing: self.aggregation_routing,
+            fan_out_limit: self.fan_out_limit,
+            adapt_to_slow_bmc: self.adapt_to_slow_bmc,
         }
     }
 
+    pub(crate) fn shared(&self) -> Arc<B> {
+        self.bmc.clone()
+    }
+
+    /// Protocol features that are used to access the service.
+    #[must_use]
+    pub fn protocol_features(&self) -> &ProtocolFeatures {
+        &self.protocol_features
+    }
+
+    /// Maximum number of requests that are sent concurrently when
+    /// members of a collection or sub-resources are fetched.
+    ///
+    /// Requests are sent one by one while the BMC is slow and
+    /// [`NvBmcBuilder::adapt_to_slow_bmc`] is set.
+    #[must_use]
+    pub fn fan_out_limit(&self) -> usize {
+        if self.is_slow() {
+            1
+        } else {
+            self.fan_out_limit
+        }
+    }
+
+    /// The BMC is classified as slow and requests to it are reduced.
+    #[must_use]
+    pub fn is_slow(&self) -> bool {
+        self.adapt_to_slow_bmc && self.bmc.latency_class() == Some(LatencyClass::Slow)
+    }
+
+    /// Run `f` for every item of `items` with at most
+    /// [`Self::fan_out_limit`] requests in flight. Results are in the
+    /// order of `items`.
+    #[allow(dead_code)] // feature-enabled func
+    pub(crate) async fn fan_out<I, F, Fut, T>(&self, items: I, f: F) -> Result<Vec<T>, Error<B>>
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> Fut,
+        Fut: Future<Output = Result<T, Error<B>>>,
+    {
+        try_fan_out(self.fan_out_limit(), items, f).await
+    }
+
+    /// Underlying BMC.
     #[allow(dead_code)] // feature-enabled func
     pub fn as_ref(&self) -> &B {
         self.bmc.as_ref()
@@ -84,7 +167,14 @@ impl<B: Bmc> NvBmc<B> {
     where
         T: Expandable,
     {
-        let optimal_query = if self.protocol_features.expand.no_links {
+        let avoided = nav
+            .id()
+            .last_segment()
+            .is_some_and(|class| self.quirks.expand_avoided(class));
+        let optimal_query = if avoided {
+            // Expanded payloads of this resource class are broken.
+            None
+        } else if self.protocol_features.expand.no_links {
             // Prefer no links expand.
             Some(ExpandQuery::no_links())
         } else if self.protocol_features.expand.expand_all {
@@ -95,13 +185,58 @@ impl<B: Bmc> NvBmc<B> {
         if let Some(optimal_query) = optimal_query {
             nav.expand(self.bmc.as_ref(), optimal_query)
                 .await
-                .map_err(Error::Bmc)?
+                .map_err(schema_types::bmc_error)?
                 .get(self.bmc.as_ref())
                 .await
-                .map_err(Error::Bmc)
+                .map_err(schema_types::bmc_error)
         } else {
             // if query is not suported.
-            nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)
+            nav.get(self.bmc.as_ref())
+                .await
+                .map_err(schema_types::bmc_error)
+        }
+    }
+
+    /// Get entity `id` with collections `properties` expanded
+    /// together with their members in a single request.
+    ///
+    /// Returns `Ok(None)` when the service doesn't support two levels
+    /// of `$expand`, rejects the query (responds with 400 or 501) or
+    /// is slow (see [`Self::is_slow`]). Callers then fetch collections
+    /// one by one. Properties of resource classes with broken expanded
+    /// payloads are not expanded.
+    #[cfg(feature = "impl-nv-bmc-expand")]
+    pub(crate) async fn expand_properties<T>(
+        &self,
+        id: &ODataId,
+        properties: &[&str],
+    ) -> Result<Option<Arc<T>>, Error<B>>
+    where
+        T: Expandable,
+    {
+        if !self.protocol_features.supports_expand_levels(2) || self.is_slow() {
+            // Multi-level expand of a slow service is likely to time out.
+            return Ok(None);
+        }
+        let Some(query) = properties
+            .iter()
+            .filter(|property| !self.quirks.expand_avoided(property))
+            .map(|property| ExpandQuery::property(*property).levels(2))
+            .reduce(ExpandQuery::and)
+        else {
+            return Ok(None);
+        };
+        match self.bmc.expand(id, query).await {
+            Ok(expanded) => Ok(Some(expanded)),
+            // Expansion of named properties is optional for services, so
+            // rejection of the query is not an error.
+            Err(error)
+                if B::error_status_code(&error)
+                    .is_some_and(|status| EXPAND_REJECTED_STATUS_CODES.contains(&status)) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(schema_types::bmc_error(error)),
         }
     }
 }
@@ -114,6 +249,189 @@ impl<B: Bmc> Clone for NvBmc<B> {
             bmc: self.bmc.clone(),
             protocol_features: self.protocol_features.clone(),
             quirks: self.quirks.clone(),
+            aggregation_routing: self.aggregation_routing,
+            fan_out_limit: self.fan_out_limit,
+            adapt_to_slow_bmc: self.adapt_to_slow_bmc,
+        }
+    }
+}
+
+/// Builder of [`NvBmc`] with explicit configuration of quirks, expand
+/// strategy and cache behavior.
+///
+/// [`crate::ServiceRoot::new`] detects the configuration from the
+/// service root. The builder allows to start from the detected
+/// configuration ([`Self::from_root`]) or from the plain one
+/// ([`Self::new`]) and override parts of it.
+pub struct NvBmcBuilder<B: Bmc> {
+    bmc: Arc<B>,
+    protocol_features: ProtocolFeatures,
+    quirks: BmcQuirks,
+    etag_policy: Option<ETagPolicy>,
+    null_policy: Option<NullPolicy>,
+    invalidate_cache: bool,
+    aggregation_routing: bool,
+    fan_out_limit: usize,
+    adapt_to_slow_bmc: bool,
+}
+
+impl<B: Bmc> NvBmcBuilder<B> {
+    /// Create builder of BMC without quirks and without usage of
+    /// optional protocol features.
+    #[must_use]
+    pub fn new(bmc: Arc<B>) -> Self {
+        Self {
+            bmc,
+            protocol_features: ProtocolFeatures::default(),
+            quirks: BmcQuirks::none(),
+            etag_policy: None,
+            null_policy: None,
+            invalidate_cache: false,
+            aggregation_routing: true,
+            fan_out_limit: DEFAULT_FAN_OUT_LIMIT,
+            adapt_to_slow_bmc: false,
+        }
+    }
+
+    /// Create builder with configuration detected from the service
+    /// `root`: protocol features advertised by the service and quirks
+    /// of the platform.
+    #[must_use]
+    pub fn from_root(bmc: Arc<B>, root: &SchemaServiceRoot) -> Self {
+        let quirks = BmcQuirks::new(root);
+        let mut protocol_features = root
+            .protocol_features_supported
+            .as_ref()
+            .map(ProtocolFeatures::new)
+            .unwrap_or_default();
+
+        if quirks.expand_is_not_working_properly() {
+            protocol_features.expand = ExpandQueryFeatures::default();
+        }
+
+        Self {
+            protocol_features,
+            quirks,
+            ..Self::new(bmc)
+        }
+    }
+
+    /// Set quirks of the platform. Quirks also define read patches
+    /// that are applied to payloads of buggy implementations, so
+    /// [`BmcQuirks::none`] turns patching off.
+    #[must_use]
+    pub fn quirks(mut self, quirks: BmcQuirks) -> Self {
+        self.quirks = quirks;
+        self
+    }
+
+    /// Set protocol features that are used to choose expand strategy.
+    #[must_use]
+    pub const fn protocol_features(mut self, protocol_features: ProtocolFeatures) -> Self {
+        self.protocol_features = protocol_features;
+        self
+    }
+
+    /// Do not use `$expand` query. Members of collections are
+    /// retrieved by individual requests.
+    #[must_use]
+    pub fn restrict_expand(mut self) -> Self {
+        self.protocol_features.expand = ExpandQueryFeatures::default();
+        self
+    }
+
+    /// Set policy of sending `If-Match` with PATCH requests. The
+    /// policy is set on the underlying [`Bmc`] when the builder is
+    /// built.
+    #[must_use]
+    pub const fn etag_policy(mut self, policy: ETagPolicy) -> Self {
+        self.etag_policy = Some(policy);
+        self
+    }
+
+    /// Set policy of handling `null` values of non-nullable
+    /// properties in BMC responses. The policy is set on the
+    /// underlying [`Bmc`] when the builder is built.
+    ///
+    /// Responses cached by the BMC were checked with the previous
+    /// policy; combine with [`Self::invalidate_cache`] to check them
+    /// again.
+    #[must_use]
+    pub const fn null_policy(mut self, policy: NullPolicy) -> Self {
+        self.null_policy = Some(policy);
+        self
+    }
+
+    /// Drop responses cached by the BMC when the builder is built, so
+    /// the new configuration is applied to fresh payloads.
+    #[must_use]
+    pub const fn invalidate_cache(mut self) -> Self {
+        self.invalidate_cache = true;
+        self
+    }
+
+    /// Do not route operations on aggregated resources to element
+    /// managers that are authoritative for them (see
+    /// `ServiceRoot::element_manager_for`). Such operations
+    /// are issued to the aggregation service itself.
+    #[must_use]
+    pub const fn disable_aggregation_routing(mut self) -> Self {
+        self.aggregation_routing = false;
+        self
+    }
+
+    /// Set maximum number of requests that are sent concurrently
+    /// when members of a collection or sub-resources of an entity
+    /// are fetched (for example, by `SystemCollection::members`).
+    /// Zero is treated as one. Default is
+    /// [`crate::fan_out::DEFAULT_FAN_OUT_LIMIT`].
+    #[must_use]
+    pub const fn fan_out_limit(mut self, limit: usize) -> Self {
+        self.fan_out_limit = limit;
+        self
+    }
+
+    /// Reduce load on the BMC while it is classified as
+    /// [`LatencyClass::Slow`] by [`Bmc::latency_class`]: members and
+    /// sub-resources are fetched one by one and multi-level `$expand`
+    /// is not used, so individual requests don't time out.
+    ///
+    /// Classification is checked on every operation, so the BMC gets
+    /// the configured behavior back when it recovers.
+    #[must_use]
+    pub const fn adapt_to_slow_bmc(mut self) -> Self {
+        self.adapt_to_slow_bmc = true;
+        self
+    }
+
+    /// Keep settings of `bmc` that don't depend on the service root.
+    pub(crate) const fn settings_of(mut self, bmc: &NvBmc<B>) -> Self {
+        self.aggregation_routing = bmc.aggregation_routing;
+        self.fan_out_limit = bmc.fan_out_limit;
+        self.adapt_to_slow_bmc = bmc.adapt_to_slow_bmc;
+        self
+    }
+
+    /// Build BMC. Policies are set on the underlying [`Bmc`] and
+    /// apply to every user of it.
+    #[must_use]
+    pub fn build(self) -> NvBmc<B> {
+        if let Some(policy) = self.etag_policy {
+            self.bmc.set_etag_policy(policy);
+        }
+        if let Some(policy) = self.null_policy {
+            self.bmc.set_null_policy(policy);
+        }
+        if self.invalidate_cache {
+            self.bmc.invalidate_cache();
         }
+        NvBmc::new(
+            self.bmc,
+            self.protocol_features,
+            self.quirks,
+            self.aggregation_routing,
+            self.fan_out_limit,
+            self.adapt_to_slow_bmc,
+        )
     }
 }
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::schema::service_root::ServiceRoot;
+use std::collections::BTreeSet;
 
 #[cfg(feature = "accounts")]
 use crate::account::SlotDefinedConfig as SlotDefinedUserAccountsConfig;
@@ -21,8 +22,12 @@ use crate::account::SlotDefinedConfig as SlotDefinedUserAccountsConfig;
 /// Object that provides quirks of individual platforms. On first root
 /// retrieval we classify platform and then apply specific workarounds
 /// for each individual platform class.
+///
+/// Used by [`crate::NvBmcBuilder`] to configure workarounds
+/// explicitly.
 pub struct BmcQuirks {
     platform: Option<Platform>,
+    expand_avoided: BTreeSet<String>,
 }
 
 // Platform shouldn't be considered as vendor. Actually it is class of
@@ -40,6 +45,8 @@ enum Platform {
 }
 
 impl BmcQuirks {
+    /// Quirks of the platform classified by the service `root`.
+    #[must_use]
     pub fn new(root: &ServiceRoot) -> Self {
         let vendor_str = root.vendor.as_ref().and_then(Option::as_deref);
         let redfish_version_str = root.redfish_version.as_deref();
@@ -66,7 +73,39 @@ impl BmcQuirks {
             None if redfish_version_str == Some("1.9.0") => Some(Platform::Anonymous1_9_0),
             _ => None,
         };
-        Self { platform }
+        Self {
+            platform,
+            expand_avoided: BTreeSet::new(),
+        }
+    }
+
+    /// No quirks: the service is expected to follow the
+    /// specification.
+    #[must_use]
+    pub const fn none() -> Self {
+        Self {
+            platform: None,
+            expand_avoided: BTreeSet::new(),
+        }
+    }
+
+    /// Do not use `$expand` for collections of `resource_class` even
+    /// if the service advertises expand support. Members of these
+    /// collections are retrieved by individual requests.
+    ///
+    /// Resource class is the last segment of the collection path (for
+    /// example, `Chassis` or `Memory`). Useful for services that
+    /// return corrupted expanded payloads for some resources only.
+    #[must_use]
+    pub fn avoid_expand<S: Into<String>>(mut self, resource_class: S) -> Self {
+        self.expand_avoided.insert(resource_class.into());
+        self
+    }
+
+    /// Check if `$expand` must not be used for collections of
+    /// `resource_class`.
+    pub(crate) fn expand_avoided(&self, resource_class: &str) -> bool {
+        self.expand_avoided.contains(resource_class)
     }
 
     // Account type is required according to schema specification
@@ -78,6 +117,15 @@ impl BmcQuirks {
         self.platform == Some(Platform::Hpe)
     }
 
+    // Some implementations do not support `ManagerAccount`
+    // properties besides user name, password, password expiration,
+    // role and enabled state (for example, they do not report
+    // `AccountTypes`) and reject create requests that contain them.
+    #[cfg(feature = "accounts")]
+    pub(crate) fn account_create_basic_properties_only(&self) -> bool {
+        self.platform == Some(Platform::Hpe)
+    }
+
     // In some implementations BMC cannot create / delete Redfish
     // accounts but have pre-created accounts (slots). Workflow is as
     // following: to "create" new account user should update
@@ -227,4 +275,22 @@ impl BmcQuirks {
             Some(Platform::AmiViking | Platform::AmiGb300)
         )
     }
+
+    /// Fan `Control` resources are published for monitoring only and
+    /// fan speed is overridden through `FanSpeedsPercent` of chassis
+    /// `EnvironmentMetrics`.
+    #[cfg(feature = "controls")]
+    pub(crate) fn fan_speed_via_environment_metrics(&self) -> bool {
+        self.platform == Some(Platform::Nvidia)
+    }
+
+    /// Virtual media `InsertMedia` action expects credentials of the
+    /// network share in `Username` and `Password` parameters instead
+    /// of `UserName` and `Password` and doesn't support
+    /// `TransferProtocolType` and `TransferMethod` parameters. The
+    /// protocol is derived from the URI scheme of the image.
+    #[cfg(feature = "virtual-media")]
+    pub(crate) fn insert_media_legacy_credentials(&self) -> bool {
+        self.platform == Some(Platform::Hpe)
+    }
 }
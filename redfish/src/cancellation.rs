@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cooperative cancellation of long-running operations.
+//!
+//! Long-running helpers of this crate have `_cancellable` variants that
+//! accept a [`CancellationToken`] and return [`Error::Cancelled`]
+//! promptly after the token is cancelled:
+//!
+//! - `TaskMonitor::wait_for_completion_cancellable`;
+//! - `ComputerSystem::wait_for_boot_complete_cancellable`;
+//! - `UpdatePlan::run_cancellable`;
+//! - `ServiceRoot::collect_hardware_ids_cancellable`.
+//!
+//! Any other operation of the crate can be made cancellable with
+//! [`run_until_cancelled`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! use nv_redfish::cancellation::run_until_cancelled;
+//! use nv_redfish::cancellation::CancellationToken;
+//!
+//! let token = CancellationToken::new();
+//! // `token.cancel()` may be called from a shutdown handler.
+//! let chassis = run_until_cancelled(&token, root.chassis()).await?;
+//! ```
+
+use crate::Error;
+use nv_redfish_core::Bmc;
+use std::future::Future;
+
+#[doc(inline)]
+pub use tokio_util::sync::CancellationToken;
+
+/// Run `future` until it completes or `token` is cancelled.
+///
+/// Cancellation is checked before `future` is polled, so an already
+/// cancelled token never starts the operation.
+///
+/// # Errors
+///
+/// Returns [`Error::Cancelled`] if the token is cancelled before the
+/// future completes. Otherwise returns the result of the future.
+pub async fn run_until_cancelled<B, T, F>(
+    token: &CancellationToken,
+    future: F,
+) -> Result<T, Error<B>>
+where
+    B: Bmc,
+    F: Future<Output = Result<T, Error<B>>>,
+{
+    token
+        .run_until_cancelled(future)
+        .await
+        .unwrap_or(Err(Error::Cancelled))
+}
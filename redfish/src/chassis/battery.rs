@@ -0,0 +1,208 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::schema::battery::Battery as BatterySchema;
+use crate::schema::battery::ChargeState;
+use crate::schema::battery_metrics::BatteryMetrics;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use std::convert::identity;
+use std::sync::Arc;
+
+#[cfg(feature = "sensors")]
+use crate::extract_sensor_uris;
+#[cfg(feature = "sensors")]
+use crate::sensor::SensorLink;
+
+/// Represents a battery in a chassis power subsystem.
+///
+/// Provides access to battery information and associated metrics/sensors.
+pub struct Battery<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<BatterySchema>,
+}
+
+/// Readings reported by battery metrics.
+///
+/// Each value is `None` when the battery does not report it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BatteryReadings {
+    /// Input voltage (V).
+    pub input_voltage: Option<f64>,
+    /// Input current (A).
+    pub input_current_amps: Option<f64>,
+    /// Stored energy (Wh).
+    pub stored_energy_watt_hours: Option<f64>,
+    /// Stored charge (Ah).
+    pub stored_charge_amp_hours: Option<f64>,
+    /// Charge level (%).
+    pub charge_percent: Option<f64>,
+    /// Temperature (°C).
+    pub temperature_celsius: Option<f64>,
+}
+
+impl<B: Bmc> Battery<B> {
+    /// Create a new battery handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<BatterySchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this battery.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<BatterySchema> {
+        self.data.clone()
+    }
+
+    /// Firmware version of the battery.
+    #[must_use]
+    pub fn firmware_version(&self) -> Option<&str> {
+        self.data.firmware_version.as_deref()
+    }
+
+    /// Charge state of the battery.
+    #[must_use]
+    pub fn charge_state(&self) -> Option<ChargeState> {
+        self.data.charge_state.and_then(identity)
+    }
+
+    /// Predicted remaining life of the battery as percentage of its
+    /// original capacity (`StateOfHealthPercent`).
+    #[must_use]
+    pub fn state_of_health_percent(&self) -> Option<f64> {
+        self.data
+            .state_of_health_percent
+            .as_ref()
+            .and_then(|v| v.reading.and_then(identity))
+    }
+
+    /// Get battery metrics.
+    ///
+    /// Returns `Ok(None)` when the battery does not provide metrics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching metrics data fails.
+    pub async fn metrics(&self) -> Result<Option<Arc<BatteryMetrics>>, Error<B>> {
+        if let Some(metrics_ref) = &self.data.metrics {
+            metrics_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get readings of battery metrics.
+    ///
+    /// Returns `Ok(None)` when the battery does not provide metrics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching metrics data fails.
+    pub async fn readings(&self) -> Result<Option<BatteryReadings>, Error<B>> {
+        Ok(self.metrics().await?.map(|m| BatteryReadings {
+            input_voltage: m
+                .input_voltage
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            input_current_amps: m
+                .input_current_amps
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            stored_energy_watt_hours: m
+                .stored_energy_watt_hours
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            stored_charge_amp_hours: m
+                .stored_charge_amp_hours
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            charge_percent: m
+                .charge_percent
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            temperature_celsius: m
+                .temperature_celsius
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+        }))
+    }
+
+    /// Get the metrics sensors for this battery.
+    ///
+    /// Returns a vector of `Sensor<B>` obtained from metrics, if available.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if get of metrics failed.
+    #[cfg(feature = "sensors")]
+    pub async fn metrics_sensor_links(&self) -> Result<Vec<SensorLink<B>>, Error<B>> {
+        let sensor_refs = self.metrics().await?.map_or_else(Vec::new, |m| {
+            extract_sensor_uris!(m,
+                single: input_voltage,
+                single: input_current_amps,
+                single: stored_energy_watt_hours,
+                single: stored_charge_amp_hours,
+                single: charge_percent,
+                single: temperature_celsius,
+                vec: output_voltages,
+                vec: output_current_amps,
+                vec: cell_voltages
+            )
+        });
+
+        Ok(sensor_refs
+            .into_iter()
+            .map(|r| SensorLink::new(&self.bmc, r))
+            .collect())
+    }
+}
+
+impl<B: Bmc> Resource for Battery<B> {
+    type Entity = BatterySchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::bmc_quirks::BmcQuirks;
+use crate::durable_id::DurableId;
 use crate::entity_link::FromLink;
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
@@ -29,6 +30,8 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::bmc::Bmc;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
@@ -37,22 +40,36 @@ use std::sync::Arc;
 
 #[cfg(feature = "assembly")]
 use crate::assembly::Assembly;
+#[cfg(feature = "batteries")]
+use crate::chassis::Battery;
+#[cfg(feature = "power-report")]
+use crate::chassis::ChassisPowerReadings;
 #[cfg(feature = "network-adapters")]
 use crate::chassis::NetworkAdapter;
 #[cfg(feature = "network-adapters")]
 use crate::chassis::NetworkAdapterCollection;
 #[cfg(feature = "power")]
 use crate::chassis::Power;
+#[cfg(feature = "power-report")]
+use crate::chassis::PowerSource;
+#[cfg(feature = "power-supplies")]
+use crate::chassis::PowerSubsystem;
 #[cfg(feature = "power-supplies")]
 use crate::chassis::PowerSupply;
 #[cfg(feature = "thermal")]
 use crate::chassis::Thermal;
+#[cfg(feature = "thermal")]
+use crate::chassis::ThermalSubsystem;
 #[cfg(feature = "controls")]
 use crate::control::extract_environment_power_limit_control;
 #[cfg(feature = "controls")]
 use crate::control::Control;
 #[cfg(feature = "controls")]
 use crate::control::ControlCollection;
+#[cfg(feature = "environment-metrics")]
+use crate::environment_metrics::reset_environment_metrics;
+#[cfg(feature = "controls")]
+use crate::environment_metrics::set_fan_speeds_percent;
 #[cfg(feature = "log-services")]
 use crate::log_service::LogService;
 #[cfg(all(feature = "oem-liteon", feature = "power-supplies"))]
@@ -66,8 +83,12 @@ use crate::schema::sensor::Sensor as SchemaSensor;
 #[cfg(feature = "sensors")]
 use crate::sensor::extract_environment_sensors;
 #[cfg(feature = "sensors")]
+use crate::sensor::Sensor;
+#[cfg(feature = "sensors")]
 use crate::sensor::SensorLink;
-#[cfg(feature = "oem-nvidia-baseboard")]
+#[cfg(feature = "sensors")]
+use crate::sensor::SensorType;
+#[cfg(any(feature = "oem-nvidia-baseboard", feature = "power-report"))]
 use std::convert::identity;
 
 #[doc(hidden)]
@@ -209,6 +230,18 @@ impl<B: Bmc> Chassis<B> {
         }
     }
 
+    /// Durable identifier of the chassis: `UUID`, manufacturer and
+    /// serial number, or normalized `@odata.id` if neither is
+    /// reported.
+    #[must_use]
+    pub fn durable_id(&self) -> DurableId {
+        DurableId::new(
+            self.data.uuid.flatten(),
+            &self.hardware_id(),
+            self.odata_id(),
+        )
+    }
+
     /// Get assembly of this chassis
     ///
     /// Returns `Ok(None)` when the assembly link is absent.
@@ -235,15 +268,110 @@ impl<B: Bmc> Chassis<B> {
     /// Returns an error if fetching power supply data fails.
     #[cfg(feature = "power-supplies")]
     pub async fn power_supplies(&self) -> Result<Vec<PowerSupply<B>>, Error<B>> {
+        if let Some(ps) = &self.data.power_subsystem {
+            PowerSubsystem::new(&self.bmc, ps)
+                .await?
+                .power_supplies()
+                .await
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Get power subsystem of this chassis.
+    ///
+    /// Uses `PowerSubsystem` (modern API) when available and falls
+    /// back to legacy `Power` resource otherwise (requires `power`
+    /// feature). Returns `Ok(None)` if neither is present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching power subsystem data fails.
+    #[cfg(feature = "power-supplies")]
+    pub async fn power_subsystem(&self) -> Result<Option<PowerSubsystem<B>>, Error<B>> {
+        if let Some(ps) = &self.data.power_subsystem {
+            return PowerSubsystem::new(&self.bmc, ps).await.map(Some);
+        }
+        #[cfg(feature = "power")]
+        if let Some(power_ref) = &self.data.power {
+            return PowerSubsystem::new_legacy(&self.bmc, power_ref)
+                .await
+                .map(Some);
+        }
+        Ok(None)
+    }
+
+    /// Power readings of this chassis for power accounting.
+    ///
+    /// Power and energy come from `EnvironmentMetrics`. When the
+    /// metrics don't report power, input power of power supplies of
+    /// `PowerSubsystem` is summed instead. Capacity comes from
+    /// `PowerSubsystem`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching environment metrics, power
+    /// subsystem or power supply data fails.
+    #[cfg(feature = "power-report")]
+    pub async fn power_readings(&self) -> Result<ChassisPowerReadings, Error<B>> {
+        let mut readings = ChassisPowerReadings::default();
+        if let Some(env_ref) = &self.data.environment_metrics {
+            let metrics = env_ref.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+            readings.power_watts = metrics
+                .power_watts
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity));
+            readings.energy_kwh = metrics
+                .energyk_wh
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity));
+            if readings.power_watts.is_some() {
+                readings.power_source = Some(PowerSource::EnvironmentMetrics);
+            }
+        }
         if let Some(ps) = &self.data.power_subsystem {
             let ps = ps.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
-            if let Some(supplies) = &ps.power_supplies {
-                let supplies = &self.bmc.expand_property(supplies).await?.members;
-                let mut power_supplies = Vec::with_capacity(supplies.len());
-                for power_supply in supplies {
-                    power_supplies.push(PowerSupply::new(&self.bmc, power_supply).await?);
+            readings.capacity_watts = ps.capacity_watts.and_then(identity);
+            if readings.power_watts.is_none() {
+                if let Some(supplies) = &ps.power_supplies {
+                    let supplies = &self.bmc.expand_property(supplies).await?.members;
+                    let mut total = None;
+                    for power_supply in supplies {
+                        let input_power = PowerSupply::new(&self.bmc, power_supply)
+                            .await?
+                            .readings()
+                            .await?
+                            .and_then(|v| v.input_power_watts);
+                        if let Some(input_power) = input_power {
+                            total = Some(total.unwrap_or(0.0) + input_power);
+                        }
+                    }
+                    readings.power_watts = total;
+                    readings.power_source = total.map(|_| PowerSource::PowerSupplies);
                 }
-                return Ok(power_supplies);
+            }
+        }
+        Ok(readings)
+    }
+
+    /// Get batteries from this chassis.
+    ///
+    /// Batteries are fetched from `PowerSubsystem`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching battery data fails.
+    #[cfg(feature = "batteries")]
+    pub async fn batteries(&self) -> Result<Vec<Battery<B>>, Error<B>> {
+        if let Some(ps) = &self.data.power_subsystem {
+            let ps = ps.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+            if let Some(batteries) = &ps.batteries {
+                let batteries = &self.bmc.expand_property(batteries).await?.members;
+                let mut result = Vec::with_capacity(batteries.len());
+                for battery in batteries {
+                    result.push(Battery::new(&self.bmc, battery).await?);
+                }
+                return Ok(result);
             }
         }
 
@@ -300,6 +428,100 @@ impl<B: Bmc> Chassis<B> {
             .map(Some)
     }
 
+    /// Get controls of this chassis that regulate fan speed.
+    ///
+    /// Returns an empty list when the controls link is absent or none
+    /// of the controls regulate fans.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching controls data fails.
+    #[cfg(feature = "controls")]
+    pub async fn fan_speed_controls(&self) -> Result<Vec<Control<B>>, Error<B>> {
+        Ok(self
+            .controls()
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(Control::is_fan_speed_control)
+            .collect())
+    }
+
+    /// Override speed of all fans of this chassis with a fixed
+    /// percentage.
+    ///
+    /// Fan speed controls of the chassis are used when they exist (see
+    /// [`Control::set_fan_speed`]). Otherwise, and on platforms that
+    /// publish fan controls for monitoring only, `FanSpeedsPercent` of
+    /// chassis `EnvironmentMetrics` is overridden. Use
+    /// [`Self::revert_fans_to_automatic`] to return fan regulation to
+    /// the BMC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `percent` is outside of the allowed range
+    /// - the chassis provides no way to control fan speed
+    /// - updating controls or environment metrics fails
+    #[cfg(feature = "controls")]
+    pub async fn set_fan_speed(&self, percent: f64) -> Result<(), Error<B>> {
+        if !self.bmc.quirks.fan_speed_via_environment_metrics() {
+            let controls = self.fan_speed_controls().await?;
+            if !controls.is_empty() {
+                for control in controls {
+                    control.set_fan_speed(percent).await?;
+                }
+                return Ok(());
+            }
+        }
+        if !(0.0..=100.0).contains(&percent) {
+            return Err(Error::FanSpeedOutOfRange {
+                requested: percent,
+                min: 0.0,
+                max: 100.0,
+            });
+        }
+        self.set_fan_speeds_percent(Some(percent)).await
+    }
+
+    /// Return all fans of this chassis to automatic regulation by the
+    /// BMC.
+    ///
+    /// Counterpart of [`Self::set_fan_speed`] that uses the same
+    /// routing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chassis provides no way to control fan
+    /// speed or updating controls or environment metrics fails.
+    #[cfg(feature = "controls")]
+    pub async fn revert_fans_to_automatic(&self) -> Result<(), Error<B>> {
+        if !self.bmc.quirks.fan_speed_via_environment_metrics() {
+            let controls = self.fan_speed_controls().await?;
+            if !controls.is_empty() {
+                for control in controls {
+                    control.revert_to_automatic().await?;
+                }
+                return Ok(());
+            }
+        }
+        self.set_fan_speeds_percent(None).await
+    }
+
+    #[cfg(feature = "controls")]
+    async fn set_fan_speeds_percent(&self, percent: Option<f64>) -> Result<(), Error<B>> {
+        let not_available = || Error::FanSpeedControlNotAvailable(self.odata_id().clone());
+        let metrics_ref = self
+            .data
+            .environment_metrics
+            .as_ref()
+            .ok_or_else(not_available)?;
+        set_fan_speeds_percent(&self.bmc, metrics_ref, percent)
+            .await?
+            .map(|_| ())
+            .ok_or_else(not_available)
+    }
+
     /// Get legacy Thermal resource (for older BMCs).
     ///
     /// Returns the deprecated `Chassis/Thermal` resource if available.
@@ -318,6 +540,28 @@ impl<B: Bmc> Chassis<B> {
         }
     }
 
+    /// Get thermal subsystem of this chassis.
+    ///
+    /// Uses `ThermalSubsystem` (modern API) when available and falls
+    /// back to legacy `Thermal` resource otherwise. Returns `Ok(None)`
+    /// if neither is present.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching thermal subsystem data fails.
+    #[cfg(feature = "thermal")]
+    pub async fn thermal_subsystem(&self) -> Result<Option<ThermalSubsystem<B>>, Error<B>> {
+        if let Some(ts) = &self.data.thermal_subsystem {
+            ThermalSubsystem::new(&self.bmc, ts).await.map(Some)
+        } else if let Some(thermal_ref) = &self.data.thermal {
+            ThermalSubsystem::new_legacy(&self.bmc, thermal_ref)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get network adapter resources
     ///
     /// Returns the `Chassis/NetworkAdapter` resources if available, and `Ok(None)` when
@@ -402,6 +646,27 @@ impl<B: Bmc> Chassis<B> {
         extract_environment_power_limit_control(&self.bmc, env_ref).await
     }
 
+    /// Reset environment metrics of this chassis.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chassis does not have environment metrics,
+    /// the metrics do not support the `ResetMetrics` action or if
+    /// invoking the action fails.
+    #[cfg(feature = "environment-metrics")]
+    pub async fn reset_environment_metrics(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let env_ref = self
+            .data
+            .environment_metrics
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        reset_environment_metrics(&self.bmc, env_ref).await
+    }
+
     /// Get the sensors collection for this chassis.
     ///
     /// Returns all available sensors associated with the chassis, and `Ok(None)`
@@ -430,6 +695,49 @@ impl<B: Bmc> Chassis<B> {
         }
     }
 
+    /// Get sensors of this chassis with their readings.
+    ///
+    /// Uses `$expand` to retrieve sensors in a single request when
+    /// supported. Returns `Ok(None)` when the sensors link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching sensors data fails.
+    #[cfg(feature = "sensors")]
+    pub async fn sensors(&self) -> Result<Option<Vec<Sensor<B>>>, Error<B>> {
+        let Some(sensors_collection) = &self.data.sensors else {
+            return Ok(None);
+        };
+        let members = &self.bmc.expand_property(sensors_collection).await?.members;
+        let mut sensors = Vec::with_capacity(members.len());
+        for sensor in members {
+            sensors.push(Sensor::new(&self.bmc, sensor).await?);
+        }
+        Ok(Some(sensors))
+    }
+
+    /// Get sensors of this chassis that report readings of the
+    /// specified type.
+    ///
+    /// Returns an empty list when the sensors link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching sensors data fails.
+    #[cfg(feature = "sensors")]
+    pub async fn sensors_by_type(
+        &self,
+        sensor_type: SensorType,
+    ) -> Result<Vec<Sensor<B>>, Error<B>> {
+        Ok(self
+            .sensors()
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|sensor| sensor.reading_type() == Some(sensor_type))
+            .collect())
+    }
+
     /// Get `PCIe` devices for this computer system.
     ///
     /// Returns `Ok(None)` when the `PCIeDevices` link is absent.
@@ -467,9 +775,20 @@ impl<B: Bmc> Chassis<B> {
 }
 
 impl<B: Bmc> Resource for Chassis<B> {
+    type Entity = ChassisSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
 
 impl<B: Bmc> FromLink<B> for Chassis<B> {
@@ -15,18 +15,32 @@
 
 mod item;
 
+#[cfg(feature = "batteries")]
+mod battery;
 #[cfg(feature = "network-adapters")]
 mod network_adapter;
 #[cfg(feature = "power")]
 mod power;
+#[cfg(feature = "power-report")]
+mod power_report;
+#[cfg(feature = "power-supplies")]
+mod power_subsystem;
 #[cfg(feature = "power-supplies")]
 mod power_supply;
 #[cfg(feature = "thermal")]
 mod thermal;
+#[cfg(feature = "thermal")]
+mod thermal_subsystem;
 
 use nv_redfish_core::Bmc;
 use std::sync::Arc;
 
+#[doc(inline)]
+#[cfg(feature = "batteries")]
+pub use battery::Battery;
+#[doc(inline)]
+#[cfg(feature = "batteries")]
+pub use battery::BatteryReadings;
 #[doc(inline)]
 pub use item::Chassis;
 #[doc(inline)]
@@ -59,13 +73,44 @@ pub use network_adapter::SerialNumber as NetworkAdapterSerialNumber;
 #[cfg(feature = "power")]
 pub use power::Power;
 #[doc(inline)]
+#[cfg(feature = "power-report")]
+pub use power_report::ChassisPower;
+#[doc(inline)]
+#[cfg(feature = "power-report")]
+pub use power_report::ChassisPowerReadings;
+#[doc(inline)]
+#[cfg(feature = "power-report")]
+pub use power_report::PowerReport;
+#[doc(inline)]
+#[cfg(feature = "power-report")]
+pub use power_report::PowerSource;
+#[doc(inline)]
+#[cfg(feature = "power-supplies")]
+pub use power_subsystem::PowerSubsystem;
+#[doc(inline)]
+#[cfg(feature = "power-supplies")]
+pub use power_subsystem::PowerSupplyReading;
+#[doc(inline)]
 #[cfg(feature = "power-supplies")]
 pub use power_supply::PowerSupply;
 #[doc(inline)]
+#[cfg(feature = "power-supplies")]
+pub use power_supply::PowerSupplyReadings;
+#[doc(inline)]
 #[cfg(feature = "thermal")]
 pub use thermal::Thermal;
+#[doc(inline)]
+#[cfg(feature = "thermal")]
+pub use thermal_subsystem::FanReading;
+#[doc(inline)]
+#[cfg(feature = "thermal")]
+pub use thermal_subsystem::TemperatureReading;
+#[doc(inline)]
+#[cfg(feature = "thermal")]
+pub use thermal_subsystem::ThermalSubsystem;
 
 use crate::core::NavProperty;
+use crate::core::ODataId;
 use crate::entity_link::EntityLink;
 use crate::patch_support::CollectionWithPatch;
 use crate::resource::Resource as _;
@@ -103,9 +148,12 @@ impl<B: Bmc> ChassisCollection<B> {
             .await
             .map(Some)
         } else if bmc.quirks.bug_missing_root_nav_properties() {
-            bmc.expand_property(&NavProperty::new_reference(
-                format!("{}/Chassis", root.odata_id()).into(),
-            ))
+            Self::expand_collection(
+                bmc,
+                &NavProperty::new_reference(format!("{}/Chassis", root.odata_id()).into()),
+                item_config.read_patch_fn.as_ref(),
+                None,
+            )
             .await
             .map(Some)
         } else {
@@ -125,12 +173,11 @@ impl<B: Bmc> ChassisCollection<B> {
     ///
     /// Returns an error if fetching collection data fails.
     pub async fn members(&self) -> Result<Vec<Chassis<B>>, Error<B>> {
-        let mut chassis_members = Vec::new();
-        for chassis in &self.collection.members {
-            chassis_members.push(Chassis::new(&self.bmc, chassis).await?);
-        }
-
-        Ok(chassis_members)
+        self.bmc
+            .fan_out(&self.collection.members, |chassis| {
+                Chassis::new(&self.bmc, chassis)
+            })
+            .await
     }
 }
 
@@ -140,7 +187,17 @@ impl<B: Bmc> CollectionWithPatch<ChassisCollectionSchema, ChassisSchema, B>
     fn convert_patched(
         base: ResourceCollection,
         members: Vec<NavProperty<ChassisSchema>>,
+        members_count: Option<i64>,
+        next_link: Option<ODataId>,
     ) -> ChassisCollectionSchema {
-        ChassisCollectionSchema { base, members }
+        ChassisCollectionSchema {
+            base,
+            members,
+            members_count,
+            next_link,
+        }
+    }
+    fn collection_members(collection: &ChassisCollectionSchema) -> &[NavProperty<ChassisSchema>] {
+        &collection.members
     }
 }
@@ -15,6 +15,9 @@
 
 //! Network adapters
 
+#[cfg(feature = "fabric-adapters")]
+use crate::computer_system::Port;
+use crate::durable_id::DurableId;
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
@@ -26,8 +29,11 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
 use std::sync::Arc;
 
 #[cfg(feature = "network-device-functions")]
@@ -60,11 +66,11 @@ impl<B: Bmc> NetworkAdapterCollection<B> {
     ///
     /// Returns an error if fetching manager data fails.
     pub async fn members(&self) -> Result<Vec<NetworkAdapter<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(NetworkAdapter::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+        self.bmc
+            .fan_out(&self.collection.members, |m| {
+                NetworkAdapter::new(&self.bmc, m)
+            })
+            .await
     }
 }
 
@@ -144,6 +150,63 @@ impl<B: Bmc> NetworkAdapter<B> {
         }
     }
 
+    /// Durable identifier of the network adapter: manufacturer and serial
+    /// number, or normalized `@odata.id` if serial number is not
+    /// reported.
+    #[must_use]
+    pub fn durable_id(&self) -> DurableId {
+        DurableId::new(None, &self.hardware_id(), self.odata_id())
+    }
+
+    /// Firmware package version of the adapter. Taken from the first
+    /// controller that reports it.
+    #[must_use]
+    pub fn firmware_package_version(&self) -> Option<&str> {
+        self.data
+            .controllers
+            .iter()
+            .flatten()
+            .find_map(|controller| controller.firmware_package_version.as_ref()?.as_deref())
+    }
+
+    /// `OData` identifiers of PCIe devices that implement controllers
+    /// of the adapter (`Controllers/Links/PCIeDevices`).
+    #[cfg(feature = "pcie-devices")]
+    #[must_use]
+    pub fn pcie_device_ids(&self) -> Vec<&ODataId> {
+        self.data
+            .controllers
+            .iter()
+            .flatten()
+            .filter_map(|controller| controller.links.as_ref()?.pcie_devices.as_ref())
+            .flatten()
+            .map(NavProperty::id)
+            .collect()
+    }
+
+    /// Get ports of this adapter.
+    ///
+    /// Returns `Ok(None)` when the ports link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching port data fails.
+    #[cfg(feature = "fabric-adapters")]
+    pub async fn ports(&self) -> Result<Option<Vec<Port<B>>>, Error<B>> {
+        if let Some(ports_ref) = &self.data.ports {
+            let ports_collection = self.bmc.expand_property(ports_ref).await?;
+
+            let mut ports = Vec::new();
+            for m in &ports_collection.members {
+                ports.push(Port::new(&self.bmc, m).await?);
+            }
+
+            Ok(Some(ports))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get network device functions for this adapter.
     ///
     /// Returns `Ok(None)` when the network device functions link is absent.
@@ -166,7 +229,18 @@ impl<B: Bmc> NetworkAdapter<B> {
 }
 
 impl<B: Bmc> Resource for NetworkAdapter<B> {
+    type Entity = NetworkAdapterSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -63,7 +63,13 @@ impl<B: Bmc> Power<B> {
 }
 
 impl<B: Bmc> Resource for Power<B> {
+    type Entity = PowerSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
 }
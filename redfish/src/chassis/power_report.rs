@@ -0,0 +1,162 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Power usage aggregation across chassis.
+
+use crate::chassis::Chassis;
+use crate::Error;
+use crate::Resource as _;
+use futures_util::future::join_all;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::ODataId;
+
+/// Source of power reading of a chassis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    /// `PowerWatts` of `EnvironmentMetrics` of the chassis.
+    EnvironmentMetrics,
+    /// Sum of input power of power supplies of `PowerSubsystem`.
+    PowerSupplies,
+}
+
+/// Power readings of a chassis.
+///
+/// Each value is `None` when the chassis does not report it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ChassisPowerReadings {
+    /// Instantaneous power consumption (W).
+    pub power_watts: Option<f64>,
+    /// Source of `power_watts`.
+    pub power_source: Option<PowerSource>,
+    /// Energy consumed since the last reset of the counter (kWh).
+    pub energy_kwh: Option<f64>,
+    /// Power capacity of the power subsystem (W).
+    pub capacity_watts: Option<f64>,
+}
+
+/// Power readings of a chassis in [`PowerReport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChassisPower {
+    /// Chassis.
+    pub chassis: ODataId,
+    /// Readings of the chassis.
+    pub readings: ChassisPowerReadings,
+}
+
+/// Aggregated power usage of a set of chassis, for example of all
+/// chassis of a rack.
+///
+/// Readings of all chassis are collected concurrently. Failure of one
+/// chassis does not fail the report: the error is recorded in
+/// `failures` and aggregates are computed over the remaining chassis.
+pub struct PowerReport<B: Bmc> {
+    /// Readings of chassis that were collected successfully.
+    pub chassis: Vec<ChassisPower>,
+    /// Chassis whose readings could not be collected.
+    pub failures: Vec<(ODataId, Error<B>)>,
+}
+
+impl<B: Bmc> PowerReport<B> {
+    /// Collect power readings of `chassis`.
+    pub async fn collect(chassis: &[Chassis<B>]) -> Self {
+        let results = join_all(chassis.iter().map(Chassis::power_readings)).await;
+        let mut report = Self {
+            chassis: Vec::with_capacity(chassis.len()),
+            failures: Vec::new(),
+        };
+        for (chassis, result) in chassis.iter().zip(results) {
+            let chassis = chassis.odata_id().clone();
+            match result {
+                Ok(readings) => report.chassis.push(ChassisPower { chassis, readings }),
+                Err(err) => report.failures.push((chassis, err)),
+            }
+        }
+        report
+    }
+
+    /// All chassis were collected and each of them reported power.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+            && self
+                .chassis
+                .iter()
+                .all(|chassis| chassis.readings.power_watts.is_some())
+    }
+
+    /// Total instantaneous power consumption (W) of chassis that
+    /// reported it.
+    #[must_use]
+    pub fn total_power_watts(&self) -> f64 {
+        self.chassis
+            .iter()
+            .filter_map(|chassis| chassis.readings.power_watts)
+            .sum()
+    }
+
+    /// Total power capacity (W) of chassis that reported it.
+    #[must_use]
+    pub fn total_capacity_watts(&self) -> f64 {
+        self.chassis
+            .iter()
+            .filter_map(|chassis| chassis.readings.capacity_watts)
+            .sum()
+    }
+
+    /// Total energy (kWh) reported by energy counters of chassis.
+    /// Counters are reset independently, so the value is meaningful
+    /// only for accounting with [`Self::interval_energy_kwh`].
+    #[must_use]
+    pub fn total_energy_kwh(&self) -> f64 {
+        self.chassis
+            .iter()
+            .filter_map(|chassis| chassis.readings.energy_kwh)
+            .sum()
+    }
+
+    /// Energy (kWh) consumed by each chassis since the `previous`
+    /// report.
+    ///
+    /// Only chassis that reported energy in both reports are
+    /// included. Chassis whose counter decreased (the counter was
+    /// reset between reports) are excluded because consumption
+    /// before the reset is unknown.
+    #[must_use]
+    pub fn interval_energy_kwh(&self, previous: &Self) -> Vec<(ODataId, f64)> {
+        self.chassis
+            .iter()
+            .filter_map(|chassis| {
+                let current = chassis.readings.energy_kwh?;
+                let before = previous
+                    .chassis
+                    .iter()
+                    .find(|v| v.chassis == chassis.chassis)?
+                    .readings
+                    .energy_kwh?;
+                (current >= before).then(|| (chassis.chassis.clone(), current - before))
+            })
+            .collect()
+    }
+
+    /// Total energy (kWh) consumed by chassis since the `previous`
+    /// report (see [`Self::interval_energy_kwh`]).
+    #[must_use]
+    pub fn total_interval_energy_kwh(&self, previous: &Self) -> f64 {
+        self.interval_energy_kwh(previous)
+            .iter()
+            .map(|(_, energy)| energy)
+            .sum()
+    }
+}
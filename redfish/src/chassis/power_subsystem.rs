@@ -0,0 +1,207 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Power subsystem of a chassis with fallback to legacy `Power`.
+
+use crate::chassis::PowerSupply;
+use crate::chassis::PowerSupplyReadings;
+use crate::schema::power_subsystem::PowerSubsystem as PowerSubsystemSchema;
+use crate::Error;
+use crate::NvBmc;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use std::convert::identity;
+use std::sync::Arc;
+
+#[cfg(feature = "power")]
+use crate::schema::power::Power as PowerSchema;
+#[cfg(feature = "power")]
+use crate::schema::power::PowerSupply as LegacyPowerSupplySchema;
+
+/// Readings of a power supply.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerSupplyReading {
+    /// `OData` identifier of the `PowerSupply` resource or of the
+    /// member of legacy `Power` resource.
+    pub id: ODataId,
+    /// Name of the power supply.
+    pub name: Option<String>,
+    /// Input and output readings. Legacy `Power` resource reports
+    /// only input voltage, input power and output power.
+    pub readings: PowerSupplyReadings,
+}
+
+enum PowerData {
+    Subsystem(Arc<PowerSubsystemSchema>),
+    #[cfg(feature = "power")]
+    Legacy(Arc<PowerSchema>),
+}
+
+/// Power subsystem of a chassis.
+///
+/// Backed by `PowerSubsystem` or, for older BMCs that don't provide
+/// it, by the legacy `Power` resource (requires `power` feature).
+/// Readings are exposed in the same form for both.
+pub struct PowerSubsystem<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: PowerData,
+}
+
+impl<B: Bmc> PowerSubsystem<B> {
+    /// Create power subsystem handle from `PowerSubsystem`.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<PowerSubsystemSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data: PowerData::Subsystem(data),
+            })
+    }
+
+    /// Create power subsystem handle from legacy `Power`.
+    #[cfg(feature = "power")]
+    pub(crate) async fn new_legacy(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<PowerSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data: PowerData::Legacy(data),
+            })
+    }
+
+    /// Subsystem is backed by the legacy `Power` resource.
+    #[must_use]
+    pub const fn is_legacy(&self) -> bool {
+        !matches!(self.data, PowerData::Subsystem(_))
+    }
+
+    /// Raw `PowerSubsystem` schema data. `None` for legacy subsystem.
+    #[must_use]
+    pub fn raw(&self) -> Option<Arc<PowerSubsystemSchema>> {
+        match &self.data {
+            PowerData::Subsystem(data) => Some(data.clone()),
+            #[cfg(feature = "power")]
+            PowerData::Legacy(_) => None,
+        }
+    }
+
+    /// Raw legacy `Power` schema data. `None` if the subsystem is
+    /// backed by `PowerSubsystem`.
+    #[cfg(feature = "power")]
+    #[must_use]
+    pub fn raw_legacy(&self) -> Option<Arc<PowerSchema>> {
+        match &self.data {
+            PowerData::Subsystem(_) => None,
+            PowerData::Legacy(data) => Some(data.clone()),
+        }
+    }
+
+    /// Total power capacity of the subsystem (W). Not reported by
+    /// legacy subsystem.
+    #[must_use]
+    pub fn capacity_watts(&self) -> Option<f64> {
+        match &self.data {
+            PowerData::Subsystem(data) => data.capacity_watts.and_then(identity),
+            #[cfg(feature = "power")]
+            PowerData::Legacy(_) => None,
+        }
+    }
+
+    /// Power supplies of the chassis. Empty for legacy subsystem:
+    /// use [`Self::power_supply_readings`] to get readings of both.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching power supplies fails.
+    pub async fn power_supplies(&self) -> Result<Vec<PowerSupply<B>>, Error<B>> {
+        let supplies = match &self.data {
+            PowerData::Subsystem(data) => data.power_supplies.as_ref(),
+            #[cfg(feature = "power")]
+            PowerData::Legacy(_) => None,
+        };
+        let Some(supplies) = supplies else {
+            return Ok(Vec::new());
+        };
+        let supplies = &self.bmc.expand_property(supplies).await?.members;
+        let mut result = Vec::with_capacity(supplies.len());
+        for power_supply in supplies {
+            result.push(PowerSupply::new(&self.bmc, power_supply).await?);
+        }
+        Ok(result)
+    }
+
+    /// Power supplies of the chassis with their readings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching power supplies or their metrics
+    /// fails.
+    pub async fn power_supply_readings(&self) -> Result<Vec<PowerSupplyReading>, Error<B>> {
+        match &self.data {
+            PowerData::Subsystem(_) => {
+                let supplies = self.power_supplies().await?;
+                let mut result = Vec::with_capacity(supplies.len());
+                for power_supply in supplies {
+                    let data = power_supply.raw();
+                    result.push(PowerSupplyReading {
+                        id: data.odata_id().clone(),
+                        name: Some(data.base.name.clone()),
+                        readings: power_supply.readings().await?.unwrap_or_default(),
+                    });
+                }
+                Ok(result)
+            }
+            #[cfg(feature = "power")]
+            PowerData::Legacy(data) => {
+                let mut result = Vec::new();
+                for power_supply in data.power_supplies.iter().flatten() {
+                    let power_supply = power_supply
+                        .get(self.bmc.as_ref())
+                        .await
+                        .map_err(Error::Bmc)?;
+                    result.push(legacy_power_supply_reading(&power_supply));
+                }
+                Ok(result)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "power")]
+fn legacy_power_supply_reading(power_supply: &LegacyPowerSupplySchema) -> PowerSupplyReading {
+    PowerSupplyReading {
+        id: power_supply.odata_id().clone(),
+        name: power_supply.name.clone().and_then(identity),
+        readings: PowerSupplyReadings {
+            input_voltage: power_supply.line_input_voltage.and_then(identity),
+            input_power_watts: power_supply.power_input_watts.and_then(identity),
+            output_power_watts: power_supply
+                .power_output_watts
+                .and_then(identity)
+                .or_else(|| power_supply.last_power_output_watts.and_then(identity)),
+            ..PowerSupplyReadings::default()
+        },
+    }
+}
@@ -20,9 +20,12 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use std::convert::identity;
 use std::sync::Arc;
 
 #[cfg(feature = "sensors")]
@@ -31,8 +34,6 @@ use crate::extract_sensor_uris;
 use crate::oem::delta::DeltaPowerSupply;
 #[cfg(feature = "sensors")]
 use crate::sensor::SensorLink;
-#[cfg(feature = "oem-delta")]
-use std::convert::identity;
 
 /// Represents a power supply in a chassis.
 ///
@@ -42,6 +43,25 @@ pub struct PowerSupply<B: Bmc> {
     data: Arc<PowerSupplySchema>,
 }
 
+/// Input and output readings reported by power supply metrics.
+///
+/// Each value is `None` when the power supply does not report it.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PowerSupplyReadings {
+    /// Input voltage (V).
+    pub input_voltage: Option<f64>,
+    /// Input current (A).
+    pub input_current_amps: Option<f64>,
+    /// Input power (W).
+    pub input_power_watts: Option<f64>,
+    /// Total output power (W).
+    pub output_power_watts: Option<f64>,
+    /// Consumed energy (kWh).
+    pub energy_kwh: Option<f64>,
+    /// Temperature (°C).
+    pub temperature_celsius: Option<f64>,
+}
+
 impl<B: Bmc> PowerSupply<B> {
     /// Create a new power supply handle.
     pub(crate) async fn new(
@@ -66,6 +86,12 @@ impl<B: Bmc> PowerSupply<B> {
         self.data.clone()
     }
 
+    /// Firmware version of the power supply.
+    #[must_use]
+    pub fn firmware_version(&self) -> Option<&str> {
+        self.data.firmware_version.as_deref()
+    }
+
     /// Reset this power supply.
     ///
     /// # Errors
@@ -116,6 +142,42 @@ impl<B: Bmc> PowerSupply<B> {
         }
     }
 
+    /// Get input and output readings of power supply metrics.
+    ///
+    /// Returns `Ok(None)` when the power supply does not provide metrics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching metrics data fails.
+    pub async fn readings(&self) -> Result<Option<PowerSupplyReadings>, Error<B>> {
+        Ok(self.metrics().await?.map(|m| PowerSupplyReadings {
+            input_voltage: m
+                .input_voltage
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            input_current_amps: m
+                .input_current_amps
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            input_power_watts: m
+                .input_power_watts
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            output_power_watts: m
+                .output_power_watts
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            energy_kwh: m
+                .energyk_wh
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+            temperature_celsius: m
+                .temperature_celsius
+                .as_ref()
+                .and_then(|v| v.reading.and_then(identity)),
+        }))
+    }
+
     /// Get the metrics sensors for this power supply.
     ///
     /// Returns a vector of `Sensor<B>` obtained from metrics metrics, if available.
@@ -180,7 +242,18 @@ impl<B: Bmc> PowerSupply<B> {
 }
 
 impl<B: Bmc> Resource for PowerSupply<B> {
+    type Entity = PowerSupplySchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -18,6 +18,8 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::marker::PhantomData;
@@ -64,7 +66,18 @@ impl<B: Bmc> Thermal<B> {
 }
 
 impl<B: Bmc> Resource for Thermal<B> {
+    type Entity = ThermalSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -0,0 +1,231 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Thermal subsystem of a chassis with fallback to legacy `Thermal`.
+
+use crate::schema::thermal::Fan as LegacyFanSchema;
+use crate::schema::thermal::ReadingUnits;
+use crate::schema::thermal::Temperature as LegacyTemperatureSchema;
+use crate::schema::thermal::Thermal as ThermalSchema;
+use crate::schema::thermal_subsystem::ThermalSubsystem as ThermalSubsystemSchema;
+use crate::Error;
+use crate::NvBmc;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use std::convert::identity;
+use std::sync::Arc;
+
+/// Speed reading of a fan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FanReading {
+    /// `OData` identifier of the `Fan` resource or of the member of
+    /// legacy `Thermal` resource.
+    pub id: ODataId,
+    /// Name of the fan.
+    pub name: Option<String>,
+    /// Fan speed (%).
+    pub speed_percent: Option<f64>,
+    /// Fan speed (RPM).
+    pub speed_rpm: Option<f64>,
+}
+
+/// Temperature reading of a chassis sensor.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemperatureReading {
+    /// `OData` identifier of the sensor that provides the reading.
+    pub sensor: Option<ODataId>,
+    /// Name of the sensor. Only legacy `Thermal` resource names
+    /// temperature readings.
+    pub name: Option<String>,
+    /// Temperature (°C).
+    pub reading_celsius: Option<f64>,
+}
+
+enum ThermalData {
+    Subsystem(Arc<ThermalSubsystemSchema>),
+    Legacy(Arc<ThermalSchema>),
+}
+
+/// Thermal subsystem of a chassis.
+///
+/// Backed by `ThermalSubsystem` or, for older BMCs that don't
+/// provide it, by the legacy `Thermal` resource. Readings are
+/// exposed in the same form for both.
+pub struct ThermalSubsystem<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: ThermalData,
+}
+
+impl<B: Bmc> ThermalSubsystem<B> {
+    /// Create thermal subsystem handle from `ThermalSubsystem`.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<ThermalSubsystemSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data: ThermalData::Subsystem(data),
+            })
+    }
+
+    /// Create thermal subsystem handle from legacy `Thermal`.
+    pub(crate) async fn new_legacy(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<ThermalSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data: ThermalData::Legacy(data),
+            })
+    }
+
+    /// Subsystem is backed by the legacy `Thermal` resource.
+    #[must_use]
+    pub const fn is_legacy(&self) -> bool {
+        matches!(self.data, ThermalData::Legacy(_))
+    }
+
+    /// Raw `ThermalSubsystem` schema data. `None` for legacy
+    /// subsystem.
+    #[must_use]
+    pub fn raw(&self) -> Option<Arc<ThermalSubsystemSchema>> {
+        match &self.data {
+            ThermalData::Subsystem(data) => Some(data.clone()),
+            ThermalData::Legacy(_) => None,
+        }
+    }
+
+    /// Raw legacy `Thermal` schema data. `None` if the subsystem is
+    /// backed by `ThermalSubsystem`.
+    #[must_use]
+    pub fn raw_legacy(&self) -> Option<Arc<ThermalSchema>> {
+        match &self.data {
+            ThermalData::Subsystem(_) => None,
+            ThermalData::Legacy(data) => Some(data.clone()),
+        }
+    }
+
+    /// Fans of the chassis with their speed readings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching fans fails.
+    pub async fn fans(&self) -> Result<Vec<FanReading>, Error<B>> {
+        let mut result = Vec::new();
+        match &self.data {
+            ThermalData::Subsystem(data) => {
+                if let Some(fans) = &data.fans {
+                    let fans = &self.bmc.expand_property(fans).await?.members;
+                    result.reserve(fans.len());
+                    for fan in fans {
+                        let fan = fan.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+                        let speed = fan.speed_percent.as_ref();
+                        result.push(FanReading {
+                            id: fan.odata_id().clone(),
+                            name: Some(fan.base.name.clone()),
+                            speed_percent: speed.and_then(|v| v.reading.and_then(identity)),
+                            speed_rpm: speed.and_then(|v| v.speed_rpm.and_then(identity)),
+                        });
+                    }
+                }
+            }
+            ThermalData::Legacy(data) => {
+                for fan in data.fans.iter().flatten() {
+                    let fan = fan.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+                    result.push(legacy_fan_reading(&fan));
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Temperature readings of the chassis.
+    ///
+    /// Readings come from `ThermalMetrics` of `ThermalSubsystem` or
+    /// from `Temperatures` of legacy `Thermal`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching thermal metrics fails.
+    pub async fn temperatures(&self) -> Result<Vec<TemperatureReading>, Error<B>> {
+        match &self.data {
+            ThermalData::Subsystem(data) => {
+                let Some(metrics) = &data.thermal_metrics else {
+                    return Ok(Vec::new());
+                };
+                let metrics = metrics.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+                Ok(metrics
+                    .temperature_readings_celsius
+                    .iter()
+                    .flatten()
+                    .map(|excerpt| TemperatureReading {
+                        sensor: excerpt
+                            .data_source_uri
+                            .clone()
+                            .and_then(identity)
+                            .map(ODataId::from),
+                        name: None,
+                        reading_celsius: excerpt.reading.and_then(identity),
+                    })
+                    .collect())
+            }
+            ThermalData::Legacy(data) => {
+                let mut result = Vec::new();
+                for temperature in data.temperatures.iter().flatten() {
+                    let temperature = temperature
+                        .get(self.bmc.as_ref())
+                        .await
+                        .map_err(Error::Bmc)?;
+                    result.push(legacy_temperature_reading(&temperature));
+                }
+                Ok(result)
+            }
+        }
+    }
+}
+
+#[allow(clippy::cast_precision_loss)] // Fan speeds are far below 2^52.
+fn legacy_fan_reading(fan: &LegacyFanSchema) -> FanReading {
+    let reading = fan.reading.and_then(identity).map(|v| v as f64);
+    // `ReadingUnits` was added after the first version of the
+    // schema where readings were reported in RPM.
+    let percent = fan.reading_units.and_then(identity) == Some(ReadingUnits::Percent);
+    FanReading {
+        id: fan.odata_id().clone(),
+        name: fan
+            .name
+            .clone()
+            .and_then(identity)
+            .or_else(|| fan.fan_name.clone().and_then(identity)),
+        speed_percent: reading.filter(|_| percent),
+        speed_rpm: reading.filter(|_| !percent),
+    }
+}
+
+fn legacy_temperature_reading(temperature: &LegacyTemperatureSchema) -> TemperatureReading {
+    TemperatureReading {
+        sensor: Some(temperature.odata_id().clone()),
+        name: temperature.name.clone().and_then(identity),
+        reading_celsius: temperature.reading_celsius.and_then(identity),
+    }
+}
@@ -31,5 +31,5 @@
 #[allow(clippy::too_long_first_doc_paragraph)]
 #[allow(missing_docs)]
 pub mod redfish {
-    include!(concat!(env!("OUT_DIR"), "/redfish.rs"));
+    include!(concat!(env!("OUT_DIR"), "/redfish/mod.rs"));
 }
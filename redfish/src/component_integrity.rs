@@ -0,0 +1,287 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Component integrity (SPDM/TPM attestation) of the service.
+//!
+//! Redfish `ComponentIntegrity` resources report the result of
+//! authentication of components (including the BMC itself) and link
+//! certificate chains that components presented. This module turns
+//! them into [`AttestationEvidence`] that is validated by a
+//! user-supplied [`AttestationPolicy`].
+//!
+//! [`ServiceRoot::new_attested`] runs the policy when the client
+//! connects, so no handle that can modify the service is returned
+//! before the endpoint is trusted.
+//!
+//! [`ServiceRoot::new_attested`]: crate::ServiceRoot::new_attested
+
+use crate::schema::certificate::Certificate as CertificateSchema;
+use crate::schema::component_integrity::ComponentIntegrity as ComponentIntegritySchema;
+use crate::schema::component_integrity_collection::ComponentIntegrityCollection as ComponentIntegrityCollectionSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::component_integrity::ComponentIntegrityType;
+#[doc(inline)]
+pub use crate::schema::component_integrity::VerificationStatus;
+
+/// Component integrity collection.
+///
+/// Provides functions to access collection members.
+pub struct ComponentIntegrityCollection<B: Bmc> {
+    bmc: NvBmc<B>,
+    collection: Arc<ComponentIntegrityCollectionSchema>,
+}
+
+impl<B: Bmc> ComponentIntegrityCollection<B> {
+    /// Create a new component integrity collection handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<ComponentIntegrityCollectionSchema>,
+    ) -> Result<Self, Error<B>> {
+        let collection = bmc.expand_property(nav).await?;
+        Ok(Self {
+            bmc: bmc.clone(),
+            collection,
+        })
+    }
+
+    /// List all component integrity resources of the service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching component integrity data fails.
+    pub async fn members(&self) -> Result<Vec<ComponentIntegrity<B>>, Error<B>> {
+        let mut members = Vec::new();
+        for m in &self.collection.members {
+            members.push(ComponentIntegrity::new(&self.bmc, m).await?);
+        }
+        Ok(members)
+    }
+
+    /// Collect attestation evidence of all members of the collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching component integrity data or
+    /// certificates fails.
+    pub async fn evidence(&self) -> Result<Vec<AttestationEvidence>, Error<B>> {
+        let mut evidence = Vec::new();
+        for m in self.members().await? {
+            evidence.push(m.evidence().await?);
+        }
+        Ok(evidence)
+    }
+}
+
+/// Component integrity.
+///
+/// Provides functions to access authentication results and
+/// certificates of the component.
+pub struct ComponentIntegrity<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<ComponentIntegritySchema>,
+}
+
+impl<B: Bmc> ComponentIntegrity<B> {
+    /// Create a new component integrity handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<ComponentIntegritySchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this component integrity.
+    #[must_use]
+    pub fn raw(&self) -> Arc<ComponentIntegritySchema> {
+        self.data.clone()
+    }
+
+    /// Security technology used to attest the component.
+    #[must_use]
+    pub fn integrity_type(&self) -> ComponentIntegrityType {
+        self.data.component_integrity_type
+    }
+
+    /// Whether attestation of the component is enabled. `None` means
+    /// that the service hasn't reported it.
+    #[must_use]
+    pub fn enabled(&self) -> Option<bool> {
+        self.data.component_integrity_enabled
+    }
+
+    /// URI of the resource that represents the attested component.
+    #[must_use]
+    pub fn target_component_uri(&self) -> Option<&str> {
+        self.data.target_component_uri.as_deref()
+    }
+
+    /// Result of the identity authentication of the component
+    /// performed by the service.
+    #[must_use]
+    pub fn verification_status(&self) -> Option<VerificationStatus> {
+        let spdm = self
+            .data
+            .spdm
+            .as_ref()
+            .and_then(|spdm| spdm.identity_authentication.as_ref())
+            .and_then(|auth| auth.verification_status)
+            .flatten();
+        let tpm = || {
+            self.data
+                .tpm
+                .as_ref()
+                .and_then(|tpm| tpm.identity_authentication.as_ref())
+                .and_then(|auth| auth.verification_status)
+                .flatten()
+        };
+        spdm.or_else(tpm)
+    }
+
+    /// Certificate chain presented by the component in PEM format.
+    /// `None` if the service doesn't link the component certificate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the certificate fails.
+    pub async fn certificate_chain(&self) -> Result<Option<String>, Error<B>> {
+        let Some(nav) = self.component_certificate() else {
+            return Ok(None);
+        };
+        let certificate = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+        Ok(certificate.certificate_string.clone().flatten())
+    }
+
+    /// Collect attestation evidence of the component.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the certificate fails.
+    pub async fn evidence(&self) -> Result<AttestationEvidence, Error<B>> {
+        Ok(AttestationEvidence {
+            component: self.data.odata_id().clone(),
+            integrity_type: self.integrity_type(),
+            target_component_uri: self.target_component_uri().map(ToString::to_string),
+            verification_status: self.verification_status(),
+            certificate_chain: self.certificate_chain().await?,
+        })
+    }
+
+    fn component_certificate(&self) -> Option<&NavProperty<CertificateSchema>> {
+        self.data
+            .spdm
+            .as_ref()
+            .and_then(|spdm| spdm.identity_authentication.as_ref())
+            .and_then(|auth| auth.component_certificate.as_ref())
+            .or_else(|| {
+                self.data
+                    .tpm
+                    .as_ref()
+                    .and_then(|tpm| tpm.identity_authentication.as_ref())
+                    .and_then(|auth| auth.component_certificate.as_ref())
+            })
+    }
+}
+
+impl<B: Bmc> Resource for ComponentIntegrity<B> {
+    type Entity = ComponentIntegritySchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+/// Attestation evidence of one component reported by the service.
+#[derive(Debug, Clone)]
+pub struct AttestationEvidence {
+    /// `ComponentIntegrity` resource that provided the evidence.
+    pub component: ODataId,
+    /// Security technology used to attest the component.
+    pub integrity_type: ComponentIntegrityType,
+    /// URI of the resource that represents the attested component.
+    pub target_component_uri: Option<String>,
+    /// Result of the identity authentication reported by the service.
+    pub verification_status: Option<VerificationStatus>,
+    /// Certificate chain presented by the component in PEM format.
+    pub certificate_chain: Option<String>,
+}
+
+/// Policy that decides whether the service can be trusted.
+///
+/// The policy receives evidence of all components reported by the
+/// service (empty if the service doesn't implement
+/// `ComponentIntegrity`) and returns the reason of rejection if the
+/// service must not be trusted. Closures with the same signature
+/// implement this trait.
+///
+/// # Example
+///
+/// ```ignore
+/// let policy = |evidence: &[AttestationEvidence]| {
+///     evidence
+///         .iter()
+///         .find(|e| e.target_component_uri.as_deref() == Some("/redfish/v1/Managers/BMC"))
+///         .filter(|e| e.verification_status == Some(VerificationStatus::Success))
+///         .and_then(|e| e.certificate_chain.as_deref())
+///         .filter(|chain| trusted_roots.validate(chain))
+///         .map(|_| ())
+///         .ok_or_else(|| "BMC identity is not verified".to_string())
+/// };
+/// let root = ServiceRoot::new_attested(bmc, &policy).await?;
+/// ```
+pub trait AttestationPolicy {
+    /// Verify evidence reported by the service.
+    ///
+    /// # Errors
+    ///
+    /// Returns the reason of rejection if the evidence doesn't
+    /// satisfy the policy.
+    fn verify(&self, evidence: &[AttestationEvidence]) -> Result<(), String>;
+}
+
+impl<F> AttestationPolicy for F
+where
+    F: Fn(&[AttestationEvidence]) -> Result<(), String>,
+{
+    fn verify(&self, evidence: &[AttestationEvidence]) -> Result<(), String> {
+        self(evidence)
+    }
+}
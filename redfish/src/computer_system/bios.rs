@@ -19,16 +19,30 @@ use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EdmPrimitiveType;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
-use std::marker::PhantomData;
+use nv_redfish_core::RedfishSettings as _;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
+/// BIOS attribute of HPE servers that reports Server Configuration
+/// Lock.
+const HPE_SERVER_CONFIG_LOCK_ATTRIBUTE: &str = "ServerConfigLockStatus";
+
+#[derive(Serialize)]
+struct BiosAttributesUpdate<'a> {
+    #[serde(rename = "Attributes")]
+    attributes: &'a BTreeMap<String, EdmPrimitiveType>,
+}
+
 /// BIOS.
 ///
 /// Provides functions to access BIOS functions.
 pub struct Bios<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<BiosSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> Bios<B> {
@@ -41,8 +55,8 @@ impl<B: Bmc> Bios<B> {
             .await
             .map_err(crate::Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -61,6 +75,107 @@ impl<B: Bmc> Bios<B> {
             .and_then(|attributes| attributes.dynamic_properties.get(name))
             .map(|v| BiosAttributeRef::new(v.as_ref()))
     }
+
+    /// All BIOS attributes with non-null values.
+    #[must_use]
+    pub fn attributes(&self) -> BTreeMap<String, EdmPrimitiveType> {
+        self.data
+            .attributes
+            .iter()
+            .flat_map(|attributes| attributes.dynamic_properties.iter())
+            .filter_map(|(name, v)| v.clone().map(|v| (name.clone(), v)))
+            .collect()
+    }
+
+    /// Identifier of the attribute registry that describes BIOS
+    /// attributes.
+    #[must_use]
+    pub fn attribute_registry_id(&self) -> Option<&str> {
+        self.data
+            .attribute_registry
+            .as_ref()
+            .and_then(Option::as_deref)
+    }
+
+    /// Attribute values written to the settings object
+    /// (`@Redfish.Settings`) that are not applied yet. Returns `None`
+    /// if the service doesn't provide a separate settings object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving the settings object fails.
+    pub async fn pending_attributes(
+        &self,
+    ) -> Result<Option<BTreeMap<String, EdmPrimitiveType>>, Error<B>> {
+        let Some(settings) = self.data.settings_object() else {
+            return Ok(None);
+        };
+        if settings.odata_id() == self.data.odata_id() {
+            return Ok(None);
+        }
+        let data = settings.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
+        Ok(Some(
+            Self {
+                bmc: self.bmc.clone(),
+                data,
+            }
+            .attributes(),
+        ))
+    }
+
+    /// Check if configuration of the system is locked by a vendor
+    /// lockdown mode reported by BIOS: Server Configuration Lock of
+    /// HPE servers.
+    ///
+    /// Returns `None` when BIOS doesn't report lockdown state.
+    #[must_use]
+    pub fn is_configuration_locked(&self) -> Option<bool> {
+        self.attribute(HPE_SERVER_CONFIG_LOCK_ATTRIBUTE)?
+            .str_value()
+            .map(|v| v == "Enabled")
+    }
+
+    /// Update BIOS attributes.
+    ///
+    /// Attributes are written to the settings object
+    /// (`@Redfish.Settings`) when the service provides it; new values
+    /// are applied on the next system reset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - configuration of the system is locked
+    ///   ([`Error::ConfigurationLocked`], see
+    ///   [`Self::is_configuration_locked`])
+    /// - updating the BIOS fails
+    pub async fn set_attributes(
+        &self,
+        attributes: &BTreeMap<String, EdmPrimitiveType>,
+    ) -> Result<ModificationResponse<()>, Error<B>> {
+        self.ensure_configuration_unlocked()?;
+        let settings = self.data.settings_object();
+        let update_odata = settings
+            .as_ref()
+            .map_or_else(|| self.data.odata_id(), |settings| settings.odata_id());
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<BiosSchema>>(
+                update_odata,
+                None,
+                &BiosAttributesUpdate { attributes },
+            )
+            .await
+            .map_err(|err| Error::update_failed(update_odata, err))
+            .map(|response| response.map_entity(|_| ()))
+    }
+
+    pub(crate) fn ensure_configuration_unlocked(&self) -> Result<(), Error<B>> {
+        if self.is_configuration_locked() == Some(true) {
+            Err(Error::ConfigurationLocked(self.data.odata_id().clone()))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 /// Reference to a BIOS attribute.
@@ -156,7 +156,13 @@ impl<B: Bmc> BootOption<B> {
 }
 
 impl<B: Bmc> Resource for BootOption<B> {
+    type Entity = BootOptionSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
 }
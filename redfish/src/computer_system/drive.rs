@@ -15,14 +15,24 @@
 
 //! Single physical drive for a system, including links to associated volumes.
 
+use crate::durable_id::DurableId;
+use crate::hardware_id::HardwareIdRef;
+use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
+use crate::hardware_id::Model as HardwareIdModel;
+use crate::hardware_id::PartNumber as HardwareIdPartNumber;
+use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::schema::drive::Drive as DriveSchema;
 use crate::schema::drive_metrics::DriveMetrics;
+use crate::schema::storage_controller_metrics::NvmeSmartMetrics;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
+use std::convert::identity;
 use std::sync::Arc;
 
 #[cfg(feature = "controls")]
@@ -34,6 +44,100 @@ use crate::sensor::extract_environment_sensors;
 #[cfg(feature = "sensors")]
 use crate::sensor::SensorLink;
 
+#[doc(hidden)]
+pub enum DriveTag {}
+
+/// Drive manufacturer.
+pub type Manufacturer<T> = HardwareIdManufacturer<T, DriveTag>;
+
+/// Drive model.
+pub type Model<T> = HardwareIdModel<T, DriveTag>;
+
+/// Drive part number.
+pub type PartNumber<T> = HardwareIdPartNumber<T, DriveTag>;
+
+/// Drive serial number.
+pub type SerialNumber<T> = HardwareIdSerialNumber<T, DriveTag>;
+
+/// Health counters of a drive reported in `DriveMetrics`.
+///
+/// NVMe values come from the SMART / Health Information log of the
+/// drive (`NVMeSMART`). Useful for forecasting of drive failures.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DriveHealth {
+    /// Number of unrecovered data integrity errors detected by the
+    /// NVMe controller (`MediaAndDataIntegrityErrors`).
+    pub media_errors: Option<f64>,
+    /// Vendor-specific estimate of the percentage of the NVMe drive
+    /// life used. Can exceed 100.
+    pub percentage_used: Option<f64>,
+    /// Remaining spare capacity of the NVMe drive in percents.
+    pub available_spare_percent: Option<f64>,
+    /// Spare capacity in percents below which the NVMe drive reports
+    /// critical warning.
+    pub available_spare_threshold_percent: Option<f64>,
+    /// Composite temperature of the NVMe drive in degrees Celsius.
+    pub composite_temperature_celsius: Option<f64>,
+    /// Number of unsafe shutdowns of the NVMe drive.
+    pub unsafe_shutdowns: Option<f64>,
+    /// NVMe critical warning is active: spare capacity is worn out,
+    /// media is in read-only mode, the subsystem is degraded or the
+    /// power backup failed.
+    pub critical_warning: Option<bool>,
+    /// Number of hours the drive has been powered on.
+    pub power_on_hours: Option<f64>,
+    /// Total number of bad blocks of the drive.
+    pub bad_block_count: Option<i64>,
+    /// Number of uncorrectable read errors.
+    pub uncorrectable_read_errors: Option<i64>,
+    /// Number of uncorrectable write errors.
+    pub uncorrectable_write_errors: Option<i64>,
+}
+
+impl DriveHealth {
+    fn new(metrics: &DriveMetrics) -> Self {
+        let smart = metrics.nvme_smart.as_ref();
+        let smart_value =
+            |f: fn(&NvmeSmartMetrics) -> Option<Option<f64>>| smart.and_then(f).and_then(identity);
+        Self {
+            media_errors: smart_value(|s| s.media_and_data_integrity_errors),
+            percentage_used: smart_value(|s| s.percentage_used),
+            available_spare_percent: smart_value(|s| s.available_spare_percent),
+            available_spare_threshold_percent: smart_value(|s| s.available_spare_threshold_percent),
+            composite_temperature_celsius: smart_value(|s| s.composite_temperature_celsius),
+            unsafe_shutdowns: smart_value(|s| s.unsafe_shutdowns),
+            critical_warning: smart.and_then(|s| s.critical_warnings.as_ref()).map(|w| {
+                [
+                    w.spare_capacity_worn_out,
+                    w.media_in_read_only,
+                    w.overall_subsystem_degraded,
+                    w.power_backup_failed,
+                ]
+                .iter()
+                .any(|v| *v == Some(Some(true)))
+            }),
+            power_on_hours: metrics
+                .power_on_hours
+                .and_then(identity)
+                .or_else(|| smart_value(|s| s.power_on_hours)),
+            bad_block_count: metrics.bad_block_count.and_then(identity),
+            uncorrectable_read_errors: metrics.uncorrectable_io_read_error_count.and_then(identity),
+            uncorrectable_write_errors: metrics
+                .uncorrectable_io_write_error_count
+                .and_then(identity),
+        }
+    }
+
+    /// Remaining spare capacity of the NVMe drive is below the
+    /// threshold. `None` if the drive doesn't report spare capacity.
+    #[must_use]
+    pub fn spare_below_threshold(&self) -> Option<bool> {
+        self.available_spare_percent
+            .zip(self.available_spare_threshold_percent)
+            .map(|(spare, threshold)| spare < threshold)
+    }
+}
+
 /// Represents a drive (disk) in a storage controller.
 ///
 /// Provides access to drive information and associated metrics/sensors.
@@ -66,6 +170,45 @@ impl<B: Bmc> Drive<B> {
         self.data.clone()
     }
 
+    /// Get hardware identifier of the drive.
+    #[must_use]
+    pub fn hardware_id(&self) -> HardwareIdRef<'_, DriveTag> {
+        HardwareIdRef {
+            manufacturer: self
+                .data
+                .manufacturer
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Manufacturer::new),
+            model: self
+                .data
+                .model
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Model::new),
+            part_number: self
+                .data
+                .part_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(PartNumber::new),
+            serial_number: self
+                .data
+                .serial_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(SerialNumber::new),
+        }
+    }
+
+    /// Durable identifier of the drive: manufacturer and serial
+    /// number, or normalized `@odata.id` if serial number is not
+    /// reported.
+    #[must_use]
+    pub fn durable_id(&self) -> DurableId {
+        DurableId::new(None, &self.hardware_id(), self.odata_id())
+    }
+
     /// Get drive metrics.
     ///
     /// Returns the drive's performance and state metrics if available.
@@ -87,6 +230,21 @@ impl<B: Bmc> Drive<B> {
         }
     }
 
+    /// Get health counters of the drive (NVMe SMART data, error and
+    /// bad block counts).
+    ///
+    /// Returns `Ok(None)` when the drive does not have metrics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching metrics data fails.
+    pub async fn health(&self) -> Result<Option<DriveHealth>, Error<B>> {
+        Ok(self
+            .metrics()
+            .await?
+            .map(|metrics| DriveHealth::new(&metrics)))
+    }
+
     /// Get the environment sensors for this drive.
     ///
     /// Returns a vector of `Sensor<B>` obtained from environment metrics, if available.
@@ -126,7 +284,18 @@ impl<B: Bmc> Drive<B> {
 }
 
 impl<B: Bmc> Resource for Drive<B> {
+    type Entity = DriveSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -0,0 +1,242 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fabric adapters of a computer system and their ports.
+//!
+//! DPU and SmartNIC class devices (for example, BlueField) present
+//! their network function topology as `FabricAdapters` of the host
+//! computer system. Each adapter exposes `Ports` with link state,
+//! speed and protocol.
+
+use crate::hardware_id::HardwareIdRef;
+use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
+use crate::hardware_id::Model as HardwareIdModel;
+use crate::hardware_id::PartNumber as HardwareIdPartNumber;
+use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
+use crate::schema::fabric_adapter::FabricAdapter as FabricAdapterSchema;
+use crate::schema::port::Port as PortSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::port::LinkState;
+#[doc(inline)]
+pub use crate::schema::port::LinkStatus;
+#[doc(inline)]
+pub use crate::schema::protocol::Protocol;
+
+#[doc(hidden)]
+pub enum FabricAdapterTag {}
+
+/// Fabric adapter manufacturer.
+pub type Manufacturer<T> = HardwareIdManufacturer<T, FabricAdapterTag>;
+
+/// Fabric adapter model.
+pub type Model<T> = HardwareIdModel<T, FabricAdapterTag>;
+
+/// Fabric adapter part number.
+pub type PartNumber<T> = HardwareIdPartNumber<T, FabricAdapterTag>;
+
+/// Fabric adapter serial number.
+pub type SerialNumber<T> = HardwareIdSerialNumber<T, FabricAdapterTag>;
+
+/// Represents a fabric adapter of a computer system.
+///
+/// Provides access to adapter information and its ports.
+pub struct FabricAdapter<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<FabricAdapterSchema>,
+}
+
+impl<B: Bmc> FabricAdapter<B> {
+    /// Create a new fabric adapter handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<FabricAdapterSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this fabric adapter.
+    #[must_use]
+    pub fn raw(&self) -> Arc<FabricAdapterSchema> {
+        self.data.clone()
+    }
+
+    /// Get hardware identifier of the fabric adapter.
+    #[must_use]
+    pub fn hardware_id(&self) -> HardwareIdRef<'_, FabricAdapterTag> {
+        HardwareIdRef {
+            manufacturer: self
+                .data
+                .manufacturer
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Manufacturer::new),
+            model: self
+                .data
+                .model
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Model::new),
+            part_number: self
+                .data
+                .part_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(PartNumber::new),
+            serial_number: self
+                .data
+                .serial_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(SerialNumber::new),
+        }
+    }
+
+    /// Get ports of this fabric adapter.
+    ///
+    /// Returns `Ok(None)` when the ports link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching port data fails.
+    pub async fn ports(&self) -> Result<Option<Vec<Port<B>>>, Error<B>> {
+        if let Some(ports_ref) = &self.data.ports {
+            let ports_collection = self.bmc.expand_property(ports_ref).await?;
+
+            let mut ports = Vec::new();
+            for m in &ports_collection.members {
+                ports.push(Port::new(&self.bmc, m).await?);
+            }
+
+            Ok(Some(ports))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<B: Bmc> Resource for FabricAdapter<B> {
+    type Entity = FabricAdapterSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+/// Represents a port of a fabric adapter.
+pub struct Port<B: Bmc> {
+    data: Arc<PortSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> Port<B> {
+    /// Create a new port handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<PortSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                data,
+                _marker: PhantomData,
+            })
+    }
+
+    /// Get the raw schema data for this port.
+    #[must_use]
+    pub fn raw(&self) -> Arc<PortSchema> {
+        self.data.clone()
+    }
+
+    /// Label of the port as it is printed on the device.
+    #[must_use]
+    pub fn port_id(&self) -> Option<&str> {
+        self.data.port_id.as_ref().and_then(Option::as_deref)
+    }
+
+    /// Desired link state of the port (enabled or disabled).
+    #[must_use]
+    pub fn link_state(&self) -> Option<LinkState> {
+        self.data.link_state.flatten()
+    }
+
+    /// Status of the link (for example, up or down).
+    #[must_use]
+    pub fn link_status(&self) -> Option<LinkStatus> {
+        self.data.link_status.flatten()
+    }
+
+    /// Current speed of the port in Gbit/s.
+    #[must_use]
+    pub fn current_speed_gbps(&self) -> Option<f64> {
+        self.data.current_speed_gbps.flatten()
+    }
+
+    /// Maximum speed of the port in Gbit/s.
+    #[must_use]
+    pub fn max_speed_gbps(&self) -> Option<f64> {
+        self.data.max_speed_gbps.flatten()
+    }
+
+    /// Protocol used by the port.
+    #[must_use]
+    pub fn protocol(&self) -> Option<Protocol> {
+        self.data.port_protocol.flatten()
+    }
+}
+
+impl<B: Bmc> Resource for Port<B> {
+    type Entity = PortSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
@@ -13,11 +13,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "cancellation")]
+use crate::cancellation::run_until_cancelled;
+#[cfg(feature = "cancellation")]
+use crate::cancellation::CancellationToken;
+use crate::computer_system::operating_system::OperatingSystem;
 use crate::core::Bmc;
+use crate::core::EdmDateTimeOffset;
 use crate::core::EntityTypeRef as _;
 use crate::core::ModificationResponse;
 use crate::core::NavProperty;
+use crate::core::ODataId;
 use crate::core::RedfishSettings as _;
+use crate::durable_id::DurableId;
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
@@ -25,23 +33,38 @@ use crate::hardware_id::PartNumber as HardwareIdPartNumber;
 use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::patch_support::Payload;
 use crate::patch_support::ReadPatchFn;
+use crate::resource::Health;
 use crate::resource::PowerState;
 use crate::resource::ResetType;
+use crate::schema::computer_system::BootProgressTypes;
 use crate::schema::computer_system::ComputerSystem as ComputerSystemSchema;
+use crate::schema::computer_system::HostingRole;
+use crate::schema::computer_system::SystemType;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+use crate::ResourceStatusSchema;
+use futures_util::Stream;
+use futures_util::StreamExt as _;
+use serde_json::Value as JsonValue;
 
 use serde::Serialize;
 use std::convert::identity;
 use std::sync::Arc;
+use std::time::SystemTime;
 use tagged_types::TaggedType;
 
+#[cfg(feature = "boot-options")]
+use crate::computer_system::boot_option::UefiDevicePath;
 #[cfg(feature = "bios")]
 use crate::computer_system::Bios;
 #[cfg(feature = "boot-options")]
 use crate::computer_system::BootOptionCollection;
+#[cfg(feature = "fabric-adapters")]
+use crate::computer_system::FabricAdapter;
+#[cfg(feature = "graphics-controllers")]
+use crate::computer_system::GraphicsController;
 #[cfg(feature = "memory")]
 use crate::computer_system::Memory;
 #[cfg(feature = "processors")]
@@ -50,6 +73,10 @@ use crate::computer_system::Processor;
 use crate::computer_system::SecureBoot;
 #[cfg(feature = "storages")]
 use crate::computer_system::Storage;
+#[cfg(feature = "usb-controllers")]
+use crate::computer_system::UsbController;
+#[cfg(any(feature = "processors", feature = "memory", feature = "storages"))]
+use crate::core::Expandable;
 #[cfg(feature = "ethernet-interfaces")]
 use crate::ethernet_interface::EthernetInterfaceCollection;
 #[cfg(feature = "log-services")]
@@ -58,6 +85,11 @@ use crate::log_service::LogService;
 use crate::oem::lenovo::computer_system::LenovoComputerSystem;
 #[cfg(feature = "oem-nvidia-bluefield")]
 use crate::oem::nvidia::bluefield::nvidia_computer_system::NvidiaComputerSystem;
+#[cfg(feature = "boot-options")]
+use crate::schema::computer_system::BootSource;
+
+#[doc(inline)]
+pub use crate::schema::computer_system::BootSourceOverrideEnabled;
 
 #[doc(hidden)]
 pub enum ComputerSystemTag {}
@@ -98,12 +130,94 @@ struct BootPatch {
     boot_order: Vec<BootOptionReference<String>>,
 }
 
+#[cfg(feature = "boot-options")]
+#[derive(Serialize)]
+struct UefiTargetBootPatch {
+    #[serde(rename = "BootSourceOverrideTarget")]
+    target: BootSource,
+    #[serde(rename = "BootSourceOverrideEnabled")]
+    enabled: BootSourceOverrideEnabled,
+    #[serde(rename = "UefiTargetBootSourceOverride")]
+    uefi_target: UefiDevicePath<String>,
+}
+
 #[derive(Serialize)]
-struct ComputerSystemBootOrderUpdate {
+struct ComputerSystemBootUpdate<T> {
     #[serde(rename = "Boot")]
-    boot: BootPatch,
+    boot: T,
+}
+
+/// Summary of the processors of a computer system.
+#[derive(Clone, Debug)]
+pub struct ProcessorSummary<'a> {
+    /// Number of physical processors in the system.
+    pub count: Option<i64>,
+    /// Processor model for the primary or majority of processors.
+    pub model: Option<&'a str>,
+    /// Number of processor cores in the system.
+    pub core_count: Option<i64>,
+    /// Health of processors (rollup if provided).
+    pub health: Option<Health>,
 }
 
+/// Summary of the memory of a computer system.
+#[derive(Clone, Debug)]
+pub struct MemorySummary {
+    /// Amount of configured system general purpose volatile (RAM)
+    /// memory as measured in gibibytes.
+    pub total_system_memory_gib: Option<f64>,
+    /// Health of memory (rollup if provided).
+    pub health: Option<Health>,
+}
+
+/// Rolled-up summary of a computer system.
+#[derive(Clone, Debug)]
+pub struct SystemSummary<'a> {
+    /// Power state of the system.
+    pub power_state: Option<PowerState>,
+    /// Health of the system (rollup if provided).
+    pub health: Option<Health>,
+    /// Processor summary.
+    pub processors: Option<ProcessorSummary<'a>>,
+    /// Memory summary.
+    pub memory: Option<MemorySummary>,
+}
+
+/// Boot progress of a computer system.
+#[derive(Clone, Debug)]
+pub struct BootProgress<'a> {
+    /// Last boot progress state that was reported by the system.
+    pub last_state: Option<BootProgressTypes>,
+    /// Date and time when the last boot state was updated.
+    pub last_state_time: Option<EdmDateTimeOffset>,
+    /// Seconds the system spent in the last boot between
+    /// `PrimaryProcessorInitializationStarted` and `OSRunning` or
+    /// `SystemHardwareInitializationComplete` if the OS is not
+    /// monitored.
+    pub last_boot_time_seconds: Option<f64>,
+    /// OEM-specific last state when `last_state` is `OEM`.
+    pub oem_last_state: Option<&'a str>,
+}
+
+impl BootProgress<'_> {
+    /// The system finished POST: hardware initialization is
+    /// complete, OS boot started or OS is running.
+    #[must_use]
+    pub const fn is_post_complete(&self) -> bool {
+        matches!(
+            self.last_state,
+            Some(
+                BootProgressTypes::SystemHardwareInitializationComplete
+                    | BootProgressTypes::OsBootStarted
+                    | BootProgressTypes::OsRunning
+            )
+        )
+    }
+}
+
+/// Name of the parameter of the `Reset` action.
+const RESET_TYPE_PARAMETER: &str = "ResetType";
+
 /// Represents a computer system in the BMC.
 ///
 /// Provides access to system information and sub-resources such as processors.
@@ -111,6 +225,7 @@ pub struct ComputerSystem<B: Bmc> {
     #[allow(dead_code)] // feature-enabled...
     bmc: NvBmc<B>,
     data: Arc<ComputerSystemSchema>,
+    read_patch_fn: Option<ReadPatchFn>,
 }
 
 impl<B: Bmc> ComputerSystem<B> {
@@ -128,6 +243,46 @@ impl<B: Bmc> ComputerSystem<B> {
         .map(|data| Self {
             bmc: bmc.clone(),
             data,
+            read_patch_fn: read_patch_fn.cloned(),
+        })
+    }
+
+    /// Get the system with its processors, memory and storage in a
+    /// single request.
+    ///
+    /// When the service supports two levels of `$expand`, the system
+    /// is requested once with `Processors`, `Memory` and `Storage`
+    /// expanded, and [`Self::processors`], [`Self::memory_modules`]
+    /// and [`Self::storage_controllers`] of the returned handle use
+    /// the expanded data without sending requests. Otherwise, or when
+    /// the service rejects the query, the returned handle shares data
+    /// of this one and collections are fetched on access.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expanded request fails for a reason
+    /// other than rejection of the query (400 or 501).
+    pub async fn with_expanded_inventory(&self) -> Result<Self, Error<B>> {
+        let properties: &[&str] = &[
+            #[cfg(feature = "processors")]
+            "Processors",
+            #[cfg(feature = "memory")]
+            "Memory",
+            #[cfg(feature = "storages")]
+            "Storage",
+        ];
+        // Read patches are applied to plain GET payloads only.
+        let expanded = if self.read_patch_fn.is_none() {
+            self.bmc
+                .expand_properties(self.data.odata_id(), properties)
+                .await?
+        } else {
+            None
+        };
+        Ok(Self {
+            bmc: self.bmc.clone(),
+            data: expanded.unwrap_or_else(|| self.data.clone()),
+            read_patch_fn: self.read_patch_fn.clone(),
         })
     }
 
@@ -171,6 +326,18 @@ impl<B: Bmc> ComputerSystem<B> {
         }
     }
 
+    /// Durable identifier of the computer system: `UUID`, manufacturer and
+    /// serial number, or normalized `@odata.id` if neither is
+    /// reported.
+    #[must_use]
+    pub fn durable_id(&self) -> DurableId {
+        DurableId::new(
+            self.data.uuid.flatten(),
+            &self.hardware_id(),
+            self.odata_id(),
+        )
+    }
+
     /// The manufacturer SKU for this system.
     #[must_use]
     pub fn sku(&self) -> Option<Sku<&str>> {
@@ -188,12 +355,198 @@ impl<B: Bmc> ComputerSystem<B> {
         self.data.power_state.and_then(identity)
     }
 
+    /// Type of this system (`SystemType`): physical, virtual, DPU,
+    /// partition of a physical system and so on.
+    #[must_use]
+    pub fn system_type(&self) -> Option<SystemType> {
+        self.data.system_type
+    }
+
+    /// Hosting roles of this system (`HostingRoles`), for example
+    /// bare-metal server or virtual machine server.
+    #[must_use]
+    pub fn hosting_roles(&self) -> &[HostingRole] {
+        self.data.hosting_roles.as_deref().unwrap_or_default()
+    }
+
+    /// Get operating system running on this system.
+    ///
+    /// Returns `Ok(None)` when the `OperatingSystem` link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching operating system data fails.
+    pub async fn operating_system(&self) -> Result<Option<OperatingSystem<B>>, Error<B>> {
+        if let Some(os_ref) = &self.data.operating_system {
+            OperatingSystem::new(&self.bmc, os_ref).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Boot progress of this system.
+    ///
+    /// Returns `None` when the system does not report `BootProgress`.
+    #[must_use]
+    pub fn boot_progress(&self) -> Option<BootProgress<'_>> {
+        self.data
+            .boot_progress
+            .as_ref()
+            .map(|progress| BootProgress {
+                last_state: progress.last_state.and_then(identity),
+                last_state_time: progress.last_state_time.and_then(identity),
+                last_boot_time_seconds: progress.last_boot_time_seconds.and_then(identity),
+                oem_last_state: progress.oem_last_state.as_ref().and_then(Option::as_deref),
+            })
+    }
+
+    /// Wait until the system is powered on and finished POST.
+    ///
+    /// The system is fetched once and then on every item of `ticks`
+    /// (for example, ticks of an interval timer) until it reports
+    /// power state `On` and boot progress after POST (see
+    /// [`BootProgress::is_post_complete`]). Systems that don't report
+    /// `BootProgress.LastState` are considered booted as soon as they
+    /// are powered on.
+    ///
+    /// Call it on the system read before the reset: services keep
+    /// reporting boot progress of the previous boot for a while after
+    /// the reset. When `self` reports complete boot, boot progress is
+    /// trusted only after `LastState` (or `LastStateTime`) differs from
+    /// the one of `self` or the system was seen powered off.
+    ///
+    /// Returns the system in the state where boot is complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - fetching the system fails
+    /// - `ticks` end before the boot is complete
+    ///   ([`Error::BootNotComplete`])
+    pub async fn wait_for_boot_complete<S: Stream>(&self, ticks: S) -> Result<Self, Error<B>> {
+        let nav = NavProperty::new_reference(self.data.odata_id().clone());
+        let stale = self
+            .boot_progress()
+            .filter(|progress| progress.last_state.is_some() && self.is_boot_complete())
+            .map(|progress| boot_state(&progress));
+        let mut boot_started = stale.is_none();
+        let mut ticks = Box::pin(ticks);
+        loop {
+            let system = Self::new(&self.bmc, &nav, self.read_patch_fn.as_ref()).await?;
+            boot_started = boot_started
+                || system.power_state() != Some(PowerState::On)
+                || system.boot_progress().map(|progress| boot_state(&progress)) != stale;
+            if boot_started && system.is_boot_complete() {
+                return Ok(system);
+            }
+            if ticks.next().await.is_none() {
+                return Err(Error::BootNotComplete(nav.id().clone()));
+            }
+        }
+    }
+
+    /// Wait until the system is powered on and finished POST like
+    /// [`Self::wait_for_boot_complete`] or `token` is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] if `token` is cancelled and errors
+    /// of [`Self::wait_for_boot_complete`] otherwise.
+    #[cfg(feature = "cancellation")]
+    pub async fn wait_for_boot_complete_cancellable<S: Stream>(
+        &self,
+        ticks: S,
+        token: &CancellationToken,
+    ) -> Result<Self, Error<B>> {
+        run_until_cancelled(token, self.wait_for_boot_complete(ticks)).await
+    }
+
+    fn is_boot_complete(&self) -> bool {
+        self.power_state() == Some(PowerState::On)
+            && self
+                .boot_progress()
+                .filter(|progress| progress.last_state.is_some())
+                .is_none_or(|progress| progress.is_post_complete())
+    }
+
+    /// Summary of processors of this system.
+    ///
+    /// Returns `None` when the system does not report `ProcessorSummary`.
+    #[must_use]
+    pub fn processor_summary(&self) -> Option<ProcessorSummary<'_>> {
+        self.data
+            .processor_summary
+            .as_ref()
+            .map(|summary| ProcessorSummary {
+                count: summary.count.and_then(identity),
+                model: summary.model.as_ref().and_then(Option::as_deref),
+                core_count: summary.core_count.and_then(identity),
+                health: summary.status.as_ref().and_then(status_health),
+            })
+    }
+
+    /// Summary of memory of this system.
+    ///
+    /// Returns `None` when the system does not report `MemorySummary`.
+    #[must_use]
+    pub fn memory_summary(&self) -> Option<MemorySummary> {
+        self.data
+            .memory_summary
+            .as_ref()
+            .map(|summary| MemorySummary {
+                total_system_memory_gib: summary.total_system_memory_gi_b.and_then(identity),
+                health: summary.status.as_ref().and_then(status_health),
+            })
+    }
+
+    /// Rolled-up summary of this system.
+    ///
+    /// Combines power state, system health, processor and memory
+    /// summaries so inventory tools don't need to walk the
+    /// `Processors` and `Memory` collections.
+    #[must_use]
+    pub fn summary(&self) -> SystemSummary<'_> {
+        SystemSummary {
+            power_state: self.power_state(),
+            health: self.data.status.as_ref().and_then(status_health),
+            processors: self.processor_summary(),
+            memory: self.memory_summary(),
+        }
+    }
+
+    /// Reset types allowed by the service
+    /// (`ResetType@Redfish.AllowableValues` of the `Reset` action).
+    ///
+    /// Returns `None` when the system does not support the `Reset`
+    /// action or the service doesn't restrict reset types. Values
+    /// unknown to this crate are skipped.
+    #[must_use]
+    pub fn allowed_reset_types(&self) -> Option<Vec<ResetType>> {
+        self.data
+            .actions
+            .as_ref()?
+            .reset
+            .as_ref()?
+            .allowed(RESET_TYPE_PARAMETER)
+            .map(|allowed| {
+                allowed
+                    .iter()
+                    .filter_map(|v| serde_json::from_value(JsonValue::String(v.clone())).ok())
+                    .collect()
+            })
+    }
+
     /// Reset this computer system.
     ///
+    /// The reset type is checked against
+    /// `ResetType@Redfish.AllowableValues` of the action before the
+    /// request is sent.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the system does not support the `Reset` action or
-    /// if invoking the action fails.
+    /// Returns an error if the system does not support the `Reset` action,
+    /// the reset type is not allowed by the service
+    /// ([`Error::ResetTypeNotAllowed`]) or if invoking the action fails.
     pub async fn reset(
         &self,
         reset_type: Option<ResetType>,
@@ -207,8 +560,20 @@ impl<B: Bmc> ComputerSystem<B> {
             .as_ref()
             .ok_or(Error::ActionNotAvailable)?;
 
-        if actions.reset.is_none() {
-            return Err(Error::ActionNotAvailable);
+        let action = actions.reset.as_ref().ok_or(Error::ActionNotAvailable)?;
+        if let Some(reset_type) = reset_type {
+            if !action
+                .allowable_values
+                .is_allowed(RESET_TYPE_PARAMETER, &reset_type)
+            {
+                return Err(Error::ResetTypeNotAllowed {
+                    requested: reset_type,
+                    allowed: action
+                        .allowed(RESET_TYPE_PARAMETER)
+                        .map(<[String]>::to_vec)
+                        .unwrap_or_default(),
+                });
+            }
         }
 
         actions
@@ -245,12 +610,19 @@ impl<B: Bmc> ComputerSystem<B> {
     ///
     /// # Errors
     ///
-    /// Returns an error if updating the system fails.
+    /// Returns an error if:
+    /// - configuration of the system is locked by a lockdown mode
+    ///   reported by BIOS ([`Error::ConfigurationLocked`])
+    /// - updating the system fails
     pub async fn set_boot_order(
         &self,
         boot_order: Vec<BootOptionReference<String>>,
     ) -> Result<ModificationResponse<Self>, Error<B>> {
-        let update = ComputerSystemBootOrderUpdate {
+        #[cfg(feature = "bios")]
+        if let Some(bios) = self.bios().await? {
+            bios.ensure_configuration_unlocked()?;
+        }
+        let update = ComputerSystemBootUpdate {
             boot: BootPatch { boot_order },
         };
 
@@ -260,11 +632,68 @@ impl<B: Bmc> ComputerSystem<B> {
             .as_ref()
             .map_or_else(|| self.data.odata_id(), |settings| settings.odata_id());
 
+        self.update_boot(update_odata, &update).await
+    }
+
+    /// Override boot source with the UEFI boot option identified by
+    /// its device path.
+    ///
+    /// Sets `BootSourceOverrideTarget` to `UefiTarget` together with
+    /// `UefiTargetBootSourceOverride`, so the system boots to a
+    /// precise device rather than to a device class. Use
+    /// `BootSourceOverrideEnabled::Once` for one-shot boot.
+    ///
+    /// The device path is validated against `UefiDevicePath` of the
+    /// system boot options before the update is sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the system does not expose boot options,
+    /// if no boot option has the requested device path, or if
+    /// updating the system fails.
+    #[cfg(feature = "boot-options")]
+    pub async fn set_uefi_target_boot_override(
+        &self,
+        uefi_device_path: UefiDevicePath<String>,
+        enabled: BootSourceOverrideEnabled,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        let boot_options = self
+            .boot_options()
+            .await?
+            .ok_or(Error::BootOptionsNotAvailable)?
+            .members()
+            .await?;
+        let known = boot_options.iter().any(|option| {
+            option
+                .uefi_device_path()
+                .is_some_and(|path| *path.inner() == uefi_device_path.inner().as_str())
+        });
+        if !known {
+            return Err(Error::UefiTargetNotInBootOptions(
+                uefi_device_path.into_inner(),
+            ));
+        }
+
+        let update = ComputerSystemBootUpdate {
+            boot: UefiTargetBootPatch {
+                target: BootSource::UefiTarget,
+                enabled,
+                uefi_target: uefi_device_path,
+            },
+        };
+        self.update_boot(self.data.odata_id(), &update).await
+    }
+
+    async fn update_boot<T: Serialize + Sync + Send>(
+        &self,
+        update_odata: &ODataId,
+        update: &T,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
         self.bmc
             .as_ref()
-            .update::<_, NavProperty<ComputerSystemSchema>>(update_odata, None, &update)
+            .update::<_, NavProperty<ComputerSystemSchema>>(update_odata, None, update)
             .await
-            .map_err(Error::Bmc)?
+            .map_err(|err| Error::update_failed(update_odata, err))?
             .try_map_entity_async(|nav| async move {
                 let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
 
@@ -303,14 +732,14 @@ impl<B: Bmc> ComputerSystem<B> {
     #[cfg(feature = "processors")]
     pub async fn processors(&self) -> Result<Option<Vec<Processor<B>>>, Error<B>> {
         if let Some(processors_ref) = &self.data.processors {
-            let processors_collection = self.bmc.expand_property(processors_ref).await?;
-
-            let mut processors = Vec::new();
-            for m in &processors_collection.members {
-                processors.push(Processor::new(&self.bmc, m).await?);
-            }
+            let processors_collection = self.expanded_collection(processors_ref).await?;
 
-            Ok(Some(processors))
+            self.bmc
+                .fan_out(&processors_collection.members, |m| {
+                    Processor::new(&self.bmc, m)
+                })
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -343,14 +772,12 @@ impl<B: Bmc> ComputerSystem<B> {
     #[cfg(feature = "storages")]
     pub async fn storage_controllers(&self) -> Result<Option<Vec<Storage<B>>>, Error<B>> {
         if let Some(storage_ref) = &self.data.storage {
-            let storage_collection = self.bmc.expand_property(storage_ref).await?;
-
-            let mut storage_controllers = Vec::new();
-            for m in &storage_collection.members {
-                storage_controllers.push(Storage::new(&self.bmc, m).await?);
-            }
+            let storage_collection = self.expanded_collection(storage_ref).await?;
 
-            Ok(Some(storage_controllers))
+            self.bmc
+                .fan_out(&storage_collection.members, |m| Storage::new(&self.bmc, m))
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -367,14 +794,89 @@ impl<B: Bmc> ComputerSystem<B> {
     #[cfg(feature = "memory")]
     pub async fn memory_modules(&self) -> Result<Option<Vec<Memory<B>>>, Error<B>> {
         if let Some(memory_ref) = &self.data.memory {
-            let memory_collection = self.bmc.expand_property(memory_ref).await?;
+            let memory_collection = self.expanded_collection(memory_ref).await?;
 
-            let mut memory_modules = Vec::new();
-            for m in &memory_collection.members {
-                memory_modules.push(Memory::new(&self.bmc, m).await?);
+            self.bmc
+                .fan_out(&memory_collection.members, |m| Memory::new(&self.bmc, m))
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get fabric adapters associated with this system.
+    ///
+    /// Fetches the fabric adapter collection and returns a list of
+    /// [`FabricAdapter`] handles. Returns `Ok(None)` when the fabric
+    /// adapters link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching fabric adapter data fails.
+    #[cfg(feature = "fabric-adapters")]
+    pub async fn fabric_adapters(&self) -> Result<Option<Vec<FabricAdapter<B>>>, Error<B>> {
+        if let Some(fabric_adapters_ref) = &self.data.fabric_adapters {
+            let fabric_adapters_collection = self.bmc.expand_property(fabric_adapters_ref).await?;
+
+            let mut fabric_adapters = Vec::new();
+            for m in &fabric_adapters_collection.members {
+                fabric_adapters.push(FabricAdapter::new(&self.bmc, m).await?);
+            }
+
+            Ok(Some(fabric_adapters))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get graphics controllers associated with this system.
+    ///
+    /// Fetches the graphics controller collection and returns a list
+    /// of [`GraphicsController`] handles. Returns `Ok(None)` when the
+    /// graphics controllers link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching graphics controller data fails.
+    #[cfg(feature = "graphics-controllers")]
+    pub async fn graphics_controllers(
+        &self,
+    ) -> Result<Option<Vec<GraphicsController<B>>>, Error<B>> {
+        if let Some(controllers_ref) = &self.data.graphics_controllers {
+            let controllers_collection = self.bmc.expand_property(controllers_ref).await?;
+
+            let mut controllers = Vec::new();
+            for m in &controllers_collection.members {
+                controllers.push(GraphicsController::new(&self.bmc, m).await?);
+            }
+
+            Ok(Some(controllers))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get USB controllers associated with this system.
+    ///
+    /// Fetches the USB controller collection and returns a list of
+    /// [`UsbController`] handles. Returns `Ok(None)` when the USB
+    /// controllers link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching USB controller data fails.
+    #[cfg(feature = "usb-controllers")]
+    pub async fn usb_controllers(&self) -> Result<Option<Vec<UsbController<B>>>, Error<B>> {
+        if let Some(controllers_ref) = &self.data.usb_controllers {
+            let controllers_collection = self.bmc.expand_property(controllers_ref).await?;
+
+            let mut controllers = Vec::new();
+            for m in &controllers_collection.members {
+                controllers.push(UsbController::new(&self.bmc, m).await?);
             }
 
-            Ok(Some(memory_modules))
+            Ok(Some(controllers))
         } else {
             Ok(None)
         }
@@ -474,10 +976,52 @@ impl<B: Bmc> ComputerSystem<B> {
     pub fn oem_lenovo(&self) -> Result<Option<LenovoComputerSystem<B>>, Error<B>> {
         LenovoComputerSystem::new(&self.bmc, &self.data)
     }
+
+    /// Collections expanded by [`Self::with_expanded_inventory`] are
+    /// used as is.
+    #[cfg(any(feature = "processors", feature = "memory", feature = "storages"))]
+    async fn expanded_collection<T: Expandable>(
+        &self,
+        nav: &NavProperty<T>,
+    ) -> Result<Arc<T>, Error<B>> {
+        match nav {
+            NavProperty::Expanded(_) => nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc),
+            NavProperty::Reference(_) => self.bmc.expand_property(nav).await,
+        }
+    }
+}
+
+/// State of the boot reported by `progress` that changes when the
+/// system boots again.
+fn boot_state(progress: &BootProgress<'_>) -> (Option<BootProgressTypes>, Option<SystemTime>) {
+    (
+        progress.last_state,
+        progress
+            .last_state_time
+            .and_then(|time| SystemTime::try_from(time).ok()),
+    )
+}
+
+// Prefer rollup health because summaries describe a group of devices.
+fn status_health(status: &ResourceStatusSchema) -> Option<Health> {
+    status
+        .health_rollup
+        .and_then(identity)
+        .or_else(|| status.health.and_then(identity))
 }
 
 impl<B: Bmc> Resource for ComputerSystem<B> {
+    type Entity = ComputerSystemSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -15,12 +15,21 @@
 
 //! Memory device, such as a DIMM, and its configuration.
 
+use crate::core::ModificationResponse;
+use crate::durable_id::DurableId;
+use crate::hardware_id::HardwareIdRef;
+use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
+use crate::hardware_id::Model as HardwareIdModel;
+use crate::hardware_id::PartNumber as HardwareIdPartNumber;
+use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::schema::memory::Memory as MemorySchema;
 use crate::schema::memory_metrics::MemoryMetrics;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
@@ -29,11 +38,30 @@ use std::sync::Arc;
 use crate::control::extract_environment_power_limit_control;
 #[cfg(feature = "controls")]
 use crate::control::Control;
+#[cfg(feature = "environment-metrics")]
+use crate::environment_metrics::reset_environment_metrics;
+#[cfg(feature = "oem-nvidia-hgx")]
+use crate::oem::nvidia::hgx::NvidiaGpuMemoryMetrics;
 #[cfg(feature = "sensors")]
 use crate::sensor::extract_environment_sensors;
 #[cfg(feature = "sensors")]
 use crate::sensor::SensorLink;
 
+#[doc(hidden)]
+pub enum MemoryTag {}
+
+/// Memory manufacturer.
+pub type Manufacturer<T> = HardwareIdManufacturer<T, MemoryTag>;
+
+/// Memory model.
+pub type Model<T> = HardwareIdModel<T, MemoryTag>;
+
+/// Memory part number.
+pub type PartNumber<T> = HardwareIdPartNumber<T, MemoryTag>;
+
+/// Memory serial number.
+pub type SerialNumber<T> = HardwareIdSerialNumber<T, MemoryTag>;
+
 /// Represents a memory module (DIMM) in a computer system.
 ///
 /// Provides access to memory module information and associated metrics/sensors.
@@ -66,6 +94,45 @@ impl<B: Bmc> Memory<B> {
         self.data.clone()
     }
 
+    /// Get hardware identifier of the memory module.
+    #[must_use]
+    pub fn hardware_id(&self) -> HardwareIdRef<'_, MemoryTag> {
+        HardwareIdRef {
+            manufacturer: self
+                .data
+                .manufacturer
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Manufacturer::new),
+            model: self
+                .data
+                .model
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Model::new),
+            part_number: self
+                .data
+                .part_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(PartNumber::new),
+            serial_number: self
+                .data
+                .serial_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(SerialNumber::new),
+        }
+    }
+
+    /// Durable identifier of the memory module: manufacturer and serial
+    /// number, or normalized `@odata.id` if serial number is not
+    /// reported.
+    #[must_use]
+    pub fn durable_id(&self) -> DurableId {
+        DurableId::new(None, &self.hardware_id(), self.odata_id())
+    }
+
     /// Get memory metrics.
     ///
     /// Returns the memory module's performance and state metrics if available.
@@ -87,6 +154,54 @@ impl<B: Bmc> Memory<B> {
         }
     }
 
+    /// Clear the current period of memory module metrics.
+    ///
+    /// Resets interval counters of `MemoryMetrics`, e.g. after each
+    /// scrape of a monitoring pipeline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the memory module does not have metrics, the metrics
+    /// do not support the `ClearCurrentPeriod` action or if invoking the
+    /// action fails.
+    pub async fn clear_metrics_current_period(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let metrics = self.metrics().await?.ok_or(Error::ActionNotAvailable)?;
+        let actions = metrics.actions.as_ref().ok_or(Error::ActionNotAvailable)?;
+
+        if actions.clear_current_period.is_none() {
+            return Err(Error::ActionNotAvailable);
+        }
+
+        actions
+            .clear_current_period(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+    }
+
+    /// Reset environment metrics of this memory module.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the memory module does not have environment metrics,
+    /// the metrics do not support the `ResetMetrics` action or if
+    /// invoking the action fails.
+    #[cfg(feature = "environment-metrics")]
+    pub async fn reset_environment_metrics(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let env_ref = self
+            .data
+            .environment_metrics
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        reset_environment_metrics(&self.bmc, env_ref).await
+    }
+
     /// Get the environment sensors for this memory.
     ///
     /// Returns a vector of `Sensor<B>` obtained from environment metrics, if available.    /// # Errors
@@ -123,10 +238,40 @@ impl<B: Bmc> Memory<B> {
 
         extract_environment_power_limit_control(&self.bmc, env_ref).await
     }
+
+    /// NVIDIA HGX GPU memory metrics OEM extension
+    ///
+    /// Returns `Ok(None)` when the memory does not have metrics or metrics
+    /// do not include NVIDIA GPU memory OEM extension data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching metrics or NVIDIA OEM data parsing fails.
+    #[cfg(feature = "oem-nvidia-hgx")]
+    pub async fn oem_nvidia_hgx_metrics(
+        &self,
+    ) -> Result<Option<NvidiaGpuMemoryMetrics<B>>, Error<B>> {
+        if let Some(metrics) = self.metrics().await? {
+            NvidiaGpuMemoryMetrics::new(&metrics)
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<B: Bmc> Resource for Memory<B> {
+    type Entity = MemorySchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
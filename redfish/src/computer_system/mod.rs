@@ -16,7 +16,8 @@
 //! Computer System entities and collections.
 //!
 //! This module provides types for working with Redfish ComputerSystem resources
-//! and their sub-resources like processors, storage, memory, and drives.
+//! and their sub-resources like processors, storage, memory, drives, and
+//! fabric adapters.
 
 mod item;
 
@@ -26,14 +27,25 @@ pub mod bios;
 pub mod boot_option;
 #[cfg(feature = "storages")]
 pub mod drive;
+#[cfg(feature = "fabric-adapters")]
+pub mod fabric_adapter;
+#[cfg(feature = "graphics-controllers")]
+pub mod graphics_controller;
 #[cfg(feature = "memory")]
 pub mod memory;
+pub mod operating_system;
 #[cfg(feature = "processors")]
 pub mod processor;
 #[cfg(feature = "secure-boot")]
 pub mod secure_boot;
 #[cfg(feature = "storages")]
 pub mod storage;
+#[cfg(feature = "storages")]
+pub mod storage_controller;
+#[cfg(feature = "usb-controllers")]
+pub mod usb_controller;
+#[cfg(feature = "storages")]
+pub mod volume;
 
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::FilterFn;
@@ -48,13 +60,30 @@ use crate::NvBmc;
 use crate::ServiceRoot;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
 use std::convert::identity;
 use std::sync::Arc;
 
+#[doc(inline)]
+pub use crate::schema::computer_system::BootProgressTypes;
+#[doc(inline)]
+pub use crate::schema::computer_system::HostingRole;
+#[doc(inline)]
+pub use crate::schema::computer_system::SystemType;
 #[doc(inline)]
 pub use item::BootOptionReference;
 #[doc(inline)]
+pub use item::BootProgress;
+#[doc(inline)]
+pub use item::BootSourceOverrideEnabled;
+#[doc(inline)]
 pub use item::ComputerSystem;
+#[doc(inline)]
+pub use item::MemorySummary;
+#[doc(inline)]
+pub use item::ProcessorSummary;
+#[doc(inline)]
+pub use item::SystemSummary;
 
 #[doc(inline)]
 #[cfg(feature = "bios")]
@@ -69,6 +98,18 @@ pub use boot_option::BootOptionCollection;
 #[cfg(feature = "storages")]
 pub use drive::Drive;
 #[doc(inline)]
+#[cfg(feature = "storages")]
+pub use drive::DriveHealth;
+#[doc(inline)]
+#[cfg(feature = "fabric-adapters")]
+pub use fabric_adapter::FabricAdapter;
+#[doc(inline)]
+#[cfg(feature = "fabric-adapters")]
+pub use fabric_adapter::Port;
+#[doc(inline)]
+#[cfg(feature = "graphics-controllers")]
+pub use graphics_controller::GraphicsController;
+#[doc(inline)]
 #[cfg(feature = "memory")]
 pub use memory::Memory;
 #[doc(inline)]
@@ -83,6 +124,15 @@ pub use secure_boot::SecureBootCurrentBootType;
 #[doc(inline)]
 #[cfg(feature = "storages")]
 pub use storage::Storage;
+#[doc(inline)]
+#[cfg(feature = "storages")]
+pub use storage_controller::StorageController;
+#[doc(inline)]
+#[cfg(feature = "usb-controllers")]
+pub use usb_controller::UsbController;
+#[doc(inline)]
+#[cfg(feature = "storages")]
+pub use volume::Volume;
 
 /// Computer system collection.
 ///
@@ -129,9 +179,12 @@ impl<B: Bmc> SystemCollection<B> {
             .await
             .map(Some)
         } else if bmc.quirks.bug_missing_root_nav_properties() {
-            bmc.expand_property(&NavProperty::new_reference(
-                format!("{}/Systems", root.odata_id()).into(),
-            ))
+            Self::expand_collection(
+                bmc,
+                &NavProperty::new_reference(format!("{}/Systems", root.odata_id()).into()),
+                read_patch_fn.as_ref(),
+                filters_fn.as_ref(),
+            )
             .await
             .map(Some)
         } else {
@@ -152,11 +205,11 @@ impl<B: Bmc> SystemCollection<B> {
     ///
     /// Returns an error if fetching system data fails.
     pub async fn members(&self) -> Result<Vec<ComputerSystem<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(ComputerSystem::new(&self.bmc, m, self.read_patch_fn.as_ref()).await?);
-        }
-        Ok(members)
+        self.bmc
+            .fan_out(&self.collection.members, |m| {
+                ComputerSystem::new(&self.bmc, m, self.read_patch_fn.as_ref())
+            })
+            .await
     }
 }
 
@@ -166,8 +219,20 @@ impl<B: Bmc> CollectionWithPatch<ComputerSystemCollectionSchema, ComputerSystemS
     fn convert_patched(
         base: ResourceCollection,
         members: Vec<NavProperty<ComputerSystemSchema>>,
+        members_count: Option<i64>,
+        next_link: Option<ODataId>,
     ) -> ComputerSystemCollectionSchema {
-        ComputerSystemCollectionSchema { base, members }
+        ComputerSystemCollectionSchema {
+            base,
+            members,
+            members_count,
+            next_link,
+        }
+    }
+    fn collection_members(
+        collection: &ComputerSystemCollectionSchema,
+    ) -> &[NavProperty<ComputerSystemSchema>] {
+        &collection.members
     }
 }
 
@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Operating system of a computer system.
+
+use crate::schema::operating_system::OperatingSystem as OperatingSystemSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use std::convert::identity;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::operating_system::OperatingSystemTypes;
+#[doc(inline)]
+pub use crate::schema::operating_system::VirtualMachineEngineTypes;
+
+/// Operating system running on a computer system.
+///
+/// Reports the type of the operating system and virtual machine
+/// engines it hosts.
+pub struct OperatingSystem<B: Bmc> {
+    data: Arc<OperatingSystemSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> OperatingSystem<B> {
+    /// Create a new operating system handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<OperatingSystemSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                data,
+                _marker: PhantomData,
+            })
+    }
+
+    /// Get the raw schema data for this operating system.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<OperatingSystemSchema> {
+        self.data.clone()
+    }
+
+    /// Type of the operating system.
+    #[must_use]
+    pub fn os_type(&self) -> Option<OperatingSystemTypes> {
+        self.data.r#type.and_then(identity)
+    }
+
+    /// Types of virtual machine engines hosted by the operating
+    /// system. Empty if the operating system doesn't host virtual
+    /// machines.
+    #[must_use]
+    pub fn virtual_machine_engines(&self) -> Vec<VirtualMachineEngineTypes> {
+        self.data
+            .virtual_machine_engines
+            .iter()
+            .flatten()
+            .filter_map(|engine| engine.r#type.and_then(identity))
+            .collect()
+    }
+}
+
+impl<B: Bmc> Resource for OperatingSystem<B> {
+    type Entity = OperatingSystemSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
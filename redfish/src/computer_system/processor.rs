@@ -15,12 +15,21 @@
 
 //! Processor and its configuration.
 
+use crate::core::ModificationResponse;
+use crate::durable_id::DurableId;
+use crate::hardware_id::HardwareIdRef;
+use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
+use crate::hardware_id::Model as HardwareIdModel;
+use crate::hardware_id::PartNumber as HardwareIdPartNumber;
+use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::schema::processor::Processor as ProcessorSchema;
 use crate::schema::processor_metrics::ProcessorMetrics;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
@@ -29,13 +38,32 @@ use std::sync::Arc;
 use crate::control::extract_environment_power_limit_control;
 #[cfg(feature = "controls")]
 use crate::control::Control;
+#[cfg(feature = "environment-metrics")]
+use crate::environment_metrics::reset_environment_metrics;
 #[cfg(feature = "sensors")]
 use crate::extract_sensor_uris;
+#[cfg(feature = "oem-nvidia-hgx")]
+use crate::oem::nvidia::hgx::NvidiaGpu;
 #[cfg(feature = "sensors")]
 use crate::sensor::extract_environment_sensors;
 #[cfg(feature = "sensors")]
 use crate::sensor::SensorLink;
 
+#[doc(hidden)]
+pub enum ProcessorTag {}
+
+/// Processor manufacturer.
+pub type Manufacturer<T> = HardwareIdManufacturer<T, ProcessorTag>;
+
+/// Processor model.
+pub type Model<T> = HardwareIdModel<T, ProcessorTag>;
+
+/// Processor part number.
+pub type PartNumber<T> = HardwareIdPartNumber<T, ProcessorTag>;
+
+/// Processor serial number.
+pub type SerialNumber<T> = HardwareIdSerialNumber<T, ProcessorTag>;
+
 /// Represents a processor in a computer system.
 ///
 /// Provides access to processor information and associated metrics/sensors.
@@ -68,6 +96,45 @@ impl<B: Bmc> Processor<B> {
         self.data.clone()
     }
 
+    /// Get hardware identifier of the processor.
+    #[must_use]
+    pub fn hardware_id(&self) -> HardwareIdRef<'_, ProcessorTag> {
+        HardwareIdRef {
+            manufacturer: self
+                .data
+                .manufacturer
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Manufacturer::new),
+            model: self
+                .data
+                .model
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Model::new),
+            part_number: self
+                .data
+                .part_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(PartNumber::new),
+            serial_number: self
+                .data
+                .serial_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(SerialNumber::new),
+        }
+    }
+
+    /// Durable identifier of the processor: manufacturer and serial
+    /// number, or normalized `@odata.id` if serial number is not
+    /// reported.
+    #[must_use]
+    pub fn durable_id(&self) -> DurableId {
+        DurableId::new(None, &self.hardware_id(), self.odata_id())
+    }
+
     /// Get processor metrics.
     ///
     /// Returns the processor's performance and state metrics if available.
@@ -89,6 +156,54 @@ impl<B: Bmc> Processor<B> {
         }
     }
 
+    /// Clear the current period of processor metrics.
+    ///
+    /// Resets interval counters of `ProcessorMetrics`, e.g. after each
+    /// scrape of a monitoring pipeline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the processor does not have metrics, the metrics
+    /// do not support the `ClearCurrentPeriod` action or if invoking the
+    /// action fails.
+    pub async fn clear_metrics_current_period(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let metrics = self.metrics().await?.ok_or(Error::ActionNotAvailable)?;
+        let actions = metrics.actions.as_ref().ok_or(Error::ActionNotAvailable)?;
+
+        if actions.clear_current_period.is_none() {
+            return Err(Error::ActionNotAvailable);
+        }
+
+        actions
+            .clear_current_period(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+    }
+
+    /// Reset environment metrics of this processor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the processor does not have environment metrics,
+    /// the metrics do not support the `ResetMetrics` action or if
+    /// invoking the action fails.
+    #[cfg(feature = "environment-metrics")]
+    pub async fn reset_environment_metrics(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let env_ref = self
+            .data
+            .environment_metrics
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        reset_environment_metrics(&self.bmc, env_ref).await
+    }
+
     /// Get the environment sensors for this processor.
     ///
     /// Returns a vector of `Sensor<B>` obtained from environment metrics, if available.
@@ -154,10 +269,33 @@ impl<B: Bmc> Processor<B> {
             .map(|r| SensorLink::new(&self.bmc, r))
             .collect())
     }
+
+    /// NVIDIA HGX GPU OEM extension
+    ///
+    /// Returns `Ok(None)` when the processor does not include NVIDIA GPU OEM extension data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if NVIDIA OEM data parsing fails.
+    #[cfg(feature = "oem-nvidia-hgx")]
+    pub fn oem_nvidia_hgx_gpu(&self) -> Result<Option<NvidiaGpu<B>>, Error<B>> {
+        NvidiaGpu::new(&self.bmc, &self.data)
+    }
 }
 
 impl<B: Bmc> Resource for Processor<B> {
+    type Entity = ProcessorSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -16,11 +16,15 @@
 //! Storage subsystem and its respective properties.
 
 use crate::computer_system::Drive;
+use crate::computer_system::StorageController;
+use crate::computer_system::Volume;
 use crate::schema::storage::Storage as StorageSchema;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
@@ -68,12 +72,56 @@ impl<B: Bmc> Storage<B> {
     /// - Fetching drive data fails
     pub async fn drives(&self) -> Result<Option<Vec<Drive<B>>>, Error<B>> {
         if let Some(drives_ref) = &self.data.drives {
-            let mut drives = Vec::new();
-            for d in drives_ref {
-                drives.push(Drive::new(&self.bmc, d).await?);
-            }
+            self.bmc
+                .fan_out(drives_ref, |d| Drive::new(&self.bmc, d))
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get controllers of this storage subsystem.
+    ///
+    /// Returns `Ok(None)` when the controllers link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching controllers data fails.
+    pub async fn controllers(&self) -> Result<Option<Vec<StorageController<B>>>, Error<B>> {
+        if let Some(controllers_ref) = &self.data.controllers {
+            let collection = controllers_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)?;
+            self.bmc
+                .fan_out(&collection.members, |m| {
+                    StorageController::new(&self.bmc, m)
+                })
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
 
-            Ok(Some(drives))
+    /// Get volumes of this storage subsystem.
+    ///
+    /// Returns `Ok(None)` when the volumes link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching volumes data fails.
+    pub async fn volumes(&self) -> Result<Option<Vec<Volume<B>>>, Error<B>> {
+        if let Some(volumes_ref) = &self.data.volumes {
+            let collection = volumes_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)?;
+            self.bmc
+                .fan_out(&collection.members, |m| Volume::new(&self.bmc, m))
+                .await
+                .map(Some)
         } else {
             Ok(None)
         }
@@ -81,7 +129,18 @@ impl<B: Bmc> Storage<B> {
 }
 
 impl<B: Bmc> Resource for Storage<B> {
+    type Entity = StorageSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -0,0 +1,110 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage controller of a storage subsystem, including NVMe
+//! controller properties.
+
+use crate::schema::storage_controller::StorageController as StorageControllerSchema;
+use crate::schema::storage_controller_metrics::StorageControllerMetrics;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::storage_controller::NvmeControllerProperties;
+
+/// Represents a controller of a storage subsystem.
+///
+/// Provides access to controller information, NVMe controller
+/// properties and controller metrics.
+pub struct StorageController<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<StorageControllerSchema>,
+}
+
+impl<B: Bmc> StorageController<B> {
+    /// Create a new storage controller handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<StorageControllerSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this storage controller.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<StorageControllerSchema> {
+        self.data.clone()
+    }
+
+    /// NVMe-specific properties of the controller (controller type,
+    /// NVMe version, queue sizes). `None` for non-NVMe controllers.
+    #[must_use]
+    pub fn nvme_properties(&self) -> Option<&NvmeControllerProperties> {
+        self.data.nvme_controller_properties.as_ref()
+    }
+
+    /// Get controller metrics.
+    ///
+    /// For NVMe controllers metrics include SMART data of the
+    /// controller.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching metrics data fails.
+    pub async fn metrics(&self) -> Result<Option<Arc<StorageControllerMetrics>>, Error<B>> {
+        if let Some(metrics_ref) = &self.data.metrics {
+            metrics_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<B: Bmc> Resource for StorageController<B> {
+    type Entity = StorageControllerSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! USB controllers of a computer system.
+//!
+//! Workstation-class and VDI hosts report USB host controllers as
+//! `USBControllers` of the computer system.
+
+use crate::hardware_id::HardwareIdRef;
+use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
+use crate::hardware_id::Model as HardwareIdModel;
+use crate::hardware_id::PartNumber as HardwareIdPartNumber;
+use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
+use crate::pcie_device::PcieDevice;
+use crate::schema::usb_controller::UsbController as UsbControllerSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use std::sync::Arc;
+
+#[doc(hidden)]
+pub enum UsbControllerTag {}
+
+/// USB controller manufacturer.
+pub type Manufacturer<T> = HardwareIdManufacturer<T, UsbControllerTag>;
+
+/// USB controller model.
+pub type Model<T> = HardwareIdModel<T, UsbControllerTag>;
+
+/// USB controller part number.
+pub type PartNumber<T> = HardwareIdPartNumber<T, UsbControllerTag>;
+
+/// USB controller serial number.
+pub type SerialNumber<T> = HardwareIdSerialNumber<T, UsbControllerTag>;
+
+/// Represents a USB controller of a computer system.
+pub struct UsbController<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<UsbControllerSchema>,
+}
+
+impl<B: Bmc> UsbController<B> {
+    /// Create a new USB controller handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<UsbControllerSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this USB controller.
+    #[must_use]
+    pub fn raw(&self) -> Arc<UsbControllerSchema> {
+        self.data.clone()
+    }
+
+    /// Get hardware identifier of the USB controller.
+    #[must_use]
+    pub fn hardware_id(&self) -> HardwareIdRef<'_, UsbControllerTag> {
+        HardwareIdRef {
+            manufacturer: self
+                .data
+                .manufacturer
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Manufacturer::new),
+            model: self
+                .data
+                .model
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(Model::new),
+            part_number: self
+                .data
+                .part_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(PartNumber::new),
+            serial_number: self
+                .data
+                .serial_number
+                .as_ref()
+                .and_then(Option::as_deref)
+                .map(SerialNumber::new),
+        }
+    }
+
+    /// `OData` identifier of the PCIe device that implements this
+    /// controller (`Links.PCIeDevice`).
+    #[must_use]
+    pub fn pcie_device_id(&self) -> Option<&ODataId> {
+        self.data
+            .links
+            .as_ref()
+            .and_then(|links| links.pcie_device.as_ref())
+            .map(NavProperty::id)
+    }
+
+    /// Get the PCIe device that implements this controller. Its
+    /// functions are listed by the PCIe device.
+    ///
+    /// Returns `Ok(None)` when the PCIe device link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching PCIe device data fails.
+    pub async fn pcie_device(&self) -> Result<Option<PcieDevice<B>>, Error<B>> {
+        if let Some(pcie_device_ref) = self
+            .data
+            .links
+            .as_ref()
+            .and_then(|links| links.pcie_device.as_ref())
+        {
+            PcieDevice::new(&self.bmc, pcie_device_ref).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<B: Bmc> Resource for UsbController<B> {
+    type Entity = UsbControllerSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
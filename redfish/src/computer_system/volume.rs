@@ -0,0 +1,85 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Volume of a storage subsystem, including NVMe namespace
+//! properties.
+
+use crate::schema::volume::Volume as VolumeSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::volume::NvmeNamespaceProperties;
+
+/// Represents a volume of a storage subsystem.
+pub struct Volume<B: Bmc> {
+    data: Arc<VolumeSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> Volume<B> {
+    /// Create a new volume handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<VolumeSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                data,
+                _marker: PhantomData,
+            })
+    }
+
+    /// Get the raw schema data for this volume.
+    #[must_use]
+    pub fn raw(&self) -> Arc<VolumeSchema> {
+        self.data.clone()
+    }
+
+    /// NVMe namespace properties of the volume (namespace identifier,
+    /// LBA formats, NVMe version). `None` if the volume is not an NVMe
+    /// namespace.
+    #[must_use]
+    pub fn nvme_namespace_properties(&self) -> Option<&NvmeNamespaceProperties> {
+        self.data.nvme_namespace_properties.as_ref()
+    }
+}
+
+impl<B: Bmc> Resource for Volume<B> {
+    type Entity = VolumeSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
@@ -0,0 +1,659 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Snapshot-based configuration backup and restore.
+//!
+//! [`ConfigBackup::capture`] collects writable configuration of a BMC
+//! into a portable document that implements `serde` traits:
+//! - metadata of accounts (user name, role, enabled state; passwords
+//!   are never reported by Redfish and are not part of the backup),
+//! - host name, NTP servers and enabled state of protocols of the
+//!   manager network protocol resource,
+//! - persistent boot order of the computer system together with the
+//!   boot options it references,
+//! - BIOS attributes,
+//! - event subscriptions.
+//!
+//! [`ConfigBackup::restore`] re-applies the document to a replacement
+//! BMC of the same model. It captures the current configuration of the
+//! target, computes changes with [`ConfigBackup::diff`] and applies only
+//! them, so restore is safe to repeat. Vendor specifics are handled by
+//! the typed APIs used to apply changes: accounts of services with
+//! slot-defined accounts are created in free slots, and BIOS attributes
+//! are written to the settings object when the service provides it.
+//!
+//! Restore doesn't write what the target can't or doesn't need to
+//! accept:
+//! - BIOS attributes that the attribute registry of the target marks
+//!   as read-only or immutable are skipped,
+//! - BIOS attributes are compared with values pending in the settings
+//!   object, so values written by an earlier restore are not written
+//!   again before the reset,
+//! - boot option references of the boot order are remapped to boot
+//!   options of the target with the same UEFI device path or display
+//!   name; boot order is skipped if any of its options can't be found
+//!   on the target.
+
+use crate::account::AccountService;
+use crate::account::AccountTypes;
+use crate::account::ManagerAccountCreate;
+use crate::account::ManagerAccountUpdate;
+use crate::computer_system::Bios;
+use crate::computer_system::BootOptionReference;
+use crate::computer_system::ComputerSystem;
+use crate::event_service::EventDestinationProtocol;
+use crate::event_service::EventSubscriptionCollection;
+use crate::manager::Manager;
+use crate::schema::manager_network_protocol::ManagerNetworkProtocol as ManagerNetworkProtocolSchema;
+use crate::schema::manager_network_protocol::Protocol as ProtocolSchema;
+use crate::Error;
+use crate::Resource as _;
+use crate::ServiceRoot;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EdmPrimitiveType;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Portable snapshot of writable configuration of a BMC.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfigBackup {
+    /// Manufacturer of the computer system.
+    pub manufacturer: Option<String>,
+    /// Model of the computer system. Backup is restored only to
+    /// systems of the same model.
+    pub model: Option<String>,
+    /// Accounts of the account service.
+    pub accounts: Vec<AccountBackup>,
+    /// Settings of the manager network protocol resource.
+    pub network_protocol: Option<NetworkProtocolBackup>,
+    /// Persistent boot order of the computer system.
+    pub boot_order: Option<Vec<String>>,
+    /// Boot options of the computer system. Used to find boot options
+    /// of the boot order on the target system.
+    #[serde(default)]
+    pub boot_options: Vec<BootOptionBackup>,
+    /// BIOS attributes.
+    pub bios_attributes: BTreeMap<String, EdmPrimitiveType>,
+    /// Event subscriptions.
+    pub event_subscriptions: Vec<SubscriptionBackup>,
+}
+
+/// Account metadata in configuration backup.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountBackup {
+    /// User name of the account.
+    pub user_name: String,
+    /// Role of the account.
+    pub role_id: Option<String>,
+    /// Account is enabled.
+    pub enabled: Option<bool>,
+    /// Account types allowed for the account.
+    pub account_types: Vec<AccountTypes>,
+}
+
+/// Manager network protocol settings in configuration backup.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkProtocolBackup {
+    /// Host name of the manager.
+    pub host_name: Option<String>,
+    /// NTP servers.
+    pub ntp_servers: Option<Vec<String>>,
+    /// Enabled state of protocols by Redfish property name (for
+    /// example `IPMI` or `SSH`).
+    pub protocols: BTreeMap<String, bool>,
+}
+
+/// Boot option in configuration backup.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BootOptionBackup {
+    /// Reference of the boot option in the boot order.
+    pub reference: String,
+    /// Display name of the boot option.
+    pub display_name: Option<String>,
+    /// UEFI device path of the boot option.
+    pub uefi_device_path: Option<String>,
+}
+
+/// Event subscription in configuration backup.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubscriptionBackup {
+    /// URI of the event receiver.
+    pub destination: String,
+    /// Protocol used to send events.
+    pub protocol: Option<EventDestinationProtocol>,
+    /// Client-supplied context of the subscription.
+    pub context: Option<String>,
+}
+
+/// Change of configuration required to restore a backup.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigChange {
+    /// Account is missing and is created with a generated password.
+    CreateAccount(AccountBackup),
+    /// Role or enabled state of the account differs.
+    UpdateAccount(AccountBackup),
+    /// Network protocol settings that differ.
+    NetworkProtocol(NetworkProtocolBackup),
+    /// Boot order differs.
+    BootOrder(Vec<String>),
+    /// BIOS attributes that differ.
+    BiosAttributes(BTreeMap<String, EdmPrimitiveType>),
+    /// Event subscription is missing.
+    CreateSubscription(SubscriptionBackup),
+}
+
+/// Result of configuration restore.
+#[derive(Default)]
+pub struct RestoreReport {
+    /// Changes that were applied.
+    pub changes: Vec<ConfigChange>,
+    /// Generated passwords of created accounts: user name and
+    /// password. Credentials have to be distributed to account
+    /// owners.
+    pub account_passwords: Vec<(String, String)>,
+}
+
+// Protocols of `ManagerNetworkProtocol` with enabled state that is
+// part of the backup.
+const PROTOCOLS: [&str; 8] = [
+    "HTTP",
+    "HTTPS",
+    "IPMI",
+    "KVMIP",
+    "NTP",
+    "SNMP",
+    "SSH",
+    "VirtualMedia",
+];
+
+impl ConfigBackup {
+    /// Capture configuration of the BMC.
+    ///
+    /// Resources that are not provided by the service are left empty
+    /// in the backup.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving any of resources fails.
+    pub async fn capture<B: Bmc>(
+        root: &ServiceRoot<B>,
+        manager: &Manager<B>,
+        system: &ComputerSystem<B>,
+    ) -> Result<Self, Error<B>> {
+        Self::capture_with_bios(root, manager, system)
+            .await
+            .map(|(backup, _)| backup)
+    }
+
+    // Capture configuration and keep BIOS handle for restore.
+    async fn capture_with_bios<B: Bmc>(
+        root: &ServiceRoot<B>,
+        manager: &Manager<B>,
+        system: &ComputerSystem<B>,
+    ) -> Result<(Self, Option<Bios<B>>), Error<B>> {
+        let hardware_id = system.hardware_id();
+        let accounts = if let Some(service) = root.account_service().await? {
+            capture_accounts(&service).await?
+        } else {
+            Vec::new()
+        };
+        let network_protocol = manager
+            .network_protocol()
+            .await?
+            .map(|protocol| capture_network_protocol(&protocol.raw()));
+        let bios = system.bios().await?;
+        let bios_attributes = bios.as_ref().map(Bios::attributes).unwrap_or_default();
+        let boot_options = capture_boot_options(system).await?;
+        let event_subscriptions = if let Some(subscriptions) = subscriptions(root).await? {
+            capture_subscriptions(&subscriptions).await?
+        } else {
+            Vec::new()
+        };
+        let backup = Self {
+            manufacturer: hardware_id.manufacturer.map(|v| v.to_string()),
+            model: hardware_id.model.map(|v| v.to_string()),
+            accounts,
+            network_protocol,
+            boot_order: system
+                .boot_order()
+                .map(|order| order.iter().map(ToString::to_string).collect()),
+            boot_options,
+            bios_attributes,
+            event_subscriptions,
+        };
+        Ok((backup, bios))
+    }
+
+    /// Changes required to turn `current` configuration into this
+    /// one. Configuration present only in `current` is kept, so
+    /// accounts and subscriptions are never deleted.
+    #[must_use]
+    pub fn diff(&self, current: &Self) -> Vec<ConfigChange> {
+        let mut changes = Vec::new();
+        for account in &self.accounts {
+            match current
+                .accounts
+                .iter()
+                .find(|v| v.user_name == account.user_name)
+            {
+                None => changes.push(ConfigChange::CreateAccount(account.clone())),
+                Some(v) if v.role_id != account.role_id || v.enabled != account.enabled => {
+                    changes.push(ConfigChange::UpdateAccount(account.clone()));
+                }
+                Some(_) => (),
+            }
+        }
+        if let Some(network_protocol) = &self.network_protocol {
+            let current = current.network_protocol.clone().unwrap_or_default();
+            let diff = NetworkProtocolBackup {
+                host_name: network_protocol
+                    .host_name
+                    .clone()
+                    .filter(|v| current.host_name.as_ref() != Some(v)),
+                ntp_servers: network_protocol
+                    .ntp_servers
+                    .clone()
+                    .filter(|v| current.ntp_servers.as_ref() != Some(v)),
+                protocols: network_protocol
+                    .protocols
+                    .iter()
+                    .filter(|(name, enabled)| current.protocols.get(*name) != Some(enabled))
+                    .map(|(name, enabled)| (name.clone(), *enabled))
+                    .collect(),
+            };
+            if diff != NetworkProtocolBackup::default() {
+                changes.push(ConfigChange::NetworkProtocol(diff));
+            }
+        }
+        if let Some(boot_order) = self.remap_boot_order(current) {
+            if current.boot_order.as_ref() != Some(&boot_order) {
+                changes.push(ConfigChange::BootOrder(boot_order));
+            }
+        }
+        let bios_attributes = self
+            .bios_attributes
+            .iter()
+            .filter(|(name, v)| current.bios_attributes.get(*name) != Some(v))
+            .map(|(name, v)| (name.clone(), v.clone()))
+            .collect::<BTreeMap<_, _>>();
+        if !bios_attributes.is_empty() {
+            changes.push(ConfigChange::BiosAttributes(bios_attributes));
+        }
+        for subscription in &self.event_subscriptions {
+            if !current
+                .event_subscriptions
+                .iter()
+                .any(|v| v.destination == subscription.destination)
+            {
+                changes.push(ConfigChange::CreateSubscription(subscription.clone()));
+            }
+        }
+        changes
+    }
+
+    // Boot order with references of boot options of `current`. Boot
+    // option described in the backup is found by UEFI device path or,
+    // if the path is not known, by display name. Undescribed reference
+    // is kept if `current` boot order has it. Returns `None` if any
+    // boot option can't be found.
+    fn remap_boot_order(&self, current: &Self) -> Option<Vec<String>> {
+        self.boot_order
+            .as_ref()?
+            .iter()
+            .map(|reference| {
+                let option = self.boot_options.iter().find(|v| &v.reference == reference);
+                let found = match option {
+                    Some(BootOptionBackup {
+                        uefi_device_path: Some(path),
+                        ..
+                    }) => current
+                        .boot_options
+                        .iter()
+                        .find(|v| v.uefi_device_path.as_ref() == Some(path)),
+                    Some(BootOptionBackup {
+                        display_name: Some(name),
+                        ..
+                    }) => current
+                        .boot_options
+                        .iter()
+                        .find(|v| v.display_name.as_ref() == Some(name)),
+                    _ => {
+                        return current
+                            .boot_order
+                            .iter()
+                            .flatten()
+                            .find(|v| *v == reference)
+                            .cloned();
+                    }
+                };
+                found.map(|v| v.reference.clone())
+            })
+            .collect()
+    }
+
+    /// Restore configuration to the BMC.
+    ///
+    /// Created accounts get passwords generated according to the
+    /// password policy of the service; they are returned in the
+    /// report. BIOS attributes take effect after the next reset of
+    /// the system. BIOS attributes are filtered with the attribute
+    /// registry of the target when the service hosts it (see
+    /// `ServiceRoot::attribute_registry`); otherwise all attributes
+    /// of the backup are restored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backup was captured from a different
+    /// model, if the manager configuration is locked, if a resource
+    /// required by a change is not provided by the service, or if
+    /// applying any change fails. Changes applied before the failure
+    /// are kept; repeated restore applies only the rest.
+    pub async fn restore<B: Bmc>(
+        &self,
+        root: &ServiceRoot<B>,
+        manager: &Manager<B>,
+        system: &ComputerSystem<B>,
+    ) -> Result<RestoreReport, Error<B>> {
+        let (mut current, bios) = Self::capture_with_bios(root, manager, system).await?;
+        if (&current.manufacturer, &current.model) != (&self.manufacturer, &self.model) {
+            return Err(Error::ConfigBackupModelMismatch {
+                backup: self.model.clone(),
+                target: current.model,
+            });
+        }
+        if manager.is_configuration_locked().await? == Some(true) {
+            return Err(Error::ConfigurationLocked(manager.odata_id().clone()));
+        }
+        let mut backup = self.clone();
+        if let Some(bios) = bios.filter(|_| !self.bios_attributes.is_empty()) {
+            if let Some(registry) = bios.attribute_registry_id() {
+                if let Some(registry) = root.attribute_registry(registry, "en").await? {
+                    backup
+                        .bios_attributes
+                        .retain(|name, _| registry.is_writable(name));
+                }
+            }
+            if let Some(pending) = bios.pending_attributes().await? {
+                current.bios_attributes.extend(pending);
+            }
+        }
+        let mut report = RestoreReport::default();
+        for change in backup.diff(&current) {
+            if let Some(password) = apply_change(&change, root, manager, system).await? {
+                report.account_passwords.push(password);
+            }
+            report.changes.push(change);
+        }
+        Ok(report)
+    }
+}
+
+#[derive(Serialize)]
+struct ProtocolPatch<'a> {
+    #[serde(rename = "ProtocolEnabled", skip_serializing_if = "Option::is_none")]
+    protocol_enabled: Option<bool>,
+    #[serde(rename = "NTPServers", skip_serializing_if = "Option::is_none")]
+    ntp_servers: Option<&'a [String]>,
+}
+
+#[derive(Serialize)]
+struct NetworkProtocolPatch<'a> {
+    #[serde(rename = "HostName", skip_serializing_if = "Option::is_none")]
+    host_name: Option<&'a str>,
+    #[serde(flatten)]
+    protocols: BTreeMap<&'a str, ProtocolPatch<'a>>,
+}
+
+impl<'a> NetworkProtocolPatch<'a> {
+    fn new(settings: &'a NetworkProtocolBackup) -> Self {
+        let mut protocols = settings
+            .protocols
+            .iter()
+            .map(|(name, enabled)| {
+                (
+                    name.as_str(),
+                    ProtocolPatch {
+                        protocol_enabled: Some(*enabled),
+                        ntp_servers: None,
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        if let Some(servers) = &settings.ntp_servers {
+            protocols
+                .entry("NTP")
+                .or_insert(ProtocolPatch {
+                    protocol_enabled: None,
+                    ntp_servers: None,
+                })
+                .ntp_servers = Some(servers);
+        }
+        Self {
+            host_name: settings.host_name.as_deref(),
+            protocols,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct SubscriptionCreate<'a> {
+    #[serde(rename = "Destination")]
+    destination: &'a str,
+    #[serde(rename = "Protocol", skip_serializing_if = "Option::is_none")]
+    protocol: Option<EventDestinationProtocol>,
+    #[serde(rename = "Context", skip_serializing_if = "Option::is_none")]
+    context: Option<&'a str>,
+}
+
+// Apply a change of configuration. Returns user name and password of
+// created account.
+async fn apply_change<B: Bmc>(
+    change: &ConfigChange,
+    root: &ServiceRoot<B>,
+    manager: &Manager<B>,
+    system: &ComputerSystem<B>,
+) -> Result<Option<(String, String)>, Error<B>> {
+    match change {
+        ConfigChange::CreateAccount(account) => {
+            let service = account_service(root).await?;
+            let password = service.generate_compliant_password()?;
+            let mut create = ManagerAccountCreate::builder(
+                password.clone(),
+                account.user_name.clone(),
+                account.role_id.clone().unwrap_or_default(),
+            )
+            .with_account_types(account.account_types.clone());
+            if let Some(enabled) = account.enabled {
+                create = create.with_enabled(enabled);
+            }
+            service
+                .accounts()
+                .await?
+                .ok_or(Error::ConfigBackupResourceNotAvailable("Accounts"))?
+                .create_account(create.build())
+                .await?;
+            return Ok(Some((account.user_name.clone(), password)));
+        }
+        ConfigChange::UpdateAccount(account) => {
+            let accounts = account_service(root)
+                .await?
+                .accounts()
+                .await?
+                .ok_or(Error::ConfigBackupResourceNotAvailable("Accounts"))?
+                .all_accounts_data()
+                .await?;
+            let mut update = ManagerAccountUpdate::builder();
+            if let Some(role_id) = &account.role_id {
+                update = update.with_role_id(role_id.clone());
+            }
+            if let Some(enabled) = account.enabled {
+                update = update.with_enabled(enabled);
+            }
+            if let Some(target) = accounts
+                .iter()
+                .find(|v| v.user_name() == Some(account.user_name.as_str()))
+            {
+                target.update(&update.build()).await?;
+            }
+        }
+        ConfigChange::NetworkProtocol(settings) => {
+            manager
+                .network_protocol()
+                .await?
+                .ok_or(Error::ConfigBackupResourceNotAvailable("NetworkProtocol"))?
+                .patch(&NetworkProtocolPatch::new(settings))
+                .await?;
+        }
+        ConfigChange::BootOrder(boot_order) => {
+            system
+                .set_boot_order(
+                    boot_order
+                        .iter()
+                        .cloned()
+                        .map(BootOptionReference::new)
+                        .collect(),
+                )
+                .await?;
+        }
+        ConfigChange::BiosAttributes(attributes) => {
+            system
+                .bios()
+                .await?
+                .ok_or(Error::ConfigBackupResourceNotAvailable("Bios"))?
+                .set_attributes(attributes)
+                .await?;
+        }
+        ConfigChange::CreateSubscription(subscription) => {
+            subscriptions(root)
+                .await?
+                .ok_or(Error::ConfigBackupResourceNotAvailable("Subscriptions"))?
+                .create_with(&SubscriptionCreate {
+                    destination: &subscription.destination,
+                    protocol: subscription.protocol,
+                    context: subscription.context.as_deref(),
+                })
+                .await?;
+        }
+    }
+    Ok(None)
+}
+
+async fn account_service<B: Bmc>(root: &ServiceRoot<B>) -> Result<AccountService<B>, Error<B>> {
+    root.account_service()
+        .await?
+        .ok_or(Error::ConfigBackupResourceNotAvailable("AccountService"))
+}
+
+async fn subscriptions<B: Bmc>(
+    root: &ServiceRoot<B>,
+) -> Result<Option<EventSubscriptionCollection<B>>, Error<B>> {
+    match root.event_service().await? {
+        Some(service) => service.subscriptions().await,
+        None => Ok(None),
+    }
+}
+
+async fn capture_accounts<B: Bmc>(
+    service: &AccountService<B>,
+) -> Result<Vec<AccountBackup>, Error<B>> {
+    let Some(accounts) = service.accounts().await? else {
+        return Ok(Vec::new());
+    };
+    Ok(accounts
+        .all_accounts_data()
+        .await?
+        .iter()
+        .filter_map(|account| {
+            // Empty slots of services with slot-defined accounts have
+            // no user name.
+            let user_name = account.user_name().filter(|v| !v.is_empty())?;
+            let raw = account.raw();
+            Some(AccountBackup {
+                user_name: user_name.into(),
+                role_id: raw.role_id.clone(),
+                enabled: raw.enabled,
+                account_types: account.account_types().to_vec(),
+            })
+        })
+        .collect())
+}
+
+fn capture_network_protocol(data: &ManagerNetworkProtocolSchema) -> NetworkProtocolBackup {
+    let protocol = |name: &str| -> Option<&ProtocolSchema> {
+        match name {
+            "HTTP" => data.http.as_ref(),
+            "HTTPS" => data.https.as_ref(),
+            "IPMI" => data.ipmi.as_ref(),
+            "KVMIP" => data.kvmip.as_ref(),
+            "SNMP" => data.snmp.as_ref(),
+            "SSH" => data.ssh.as_ref(),
+            "VirtualMedia" => data.virtual_media.as_ref(),
+            _ => None,
+        }
+    };
+    let ntp = data.ntp.as_ref();
+    let protocols = PROTOCOLS
+        .iter()
+        .filter_map(|name| {
+            let enabled = if *name == "NTP" {
+                ntp.and_then(|v| v.protocol_enabled).flatten()
+            } else {
+                protocol(name).and_then(|v| v.protocol_enabled).flatten()
+            };
+            enabled.map(|enabled| ((*name).to_string(), enabled))
+        })
+        .collect();
+    NetworkProtocolBackup {
+        host_name: data.host_name.clone().flatten(),
+        ntp_servers: ntp.and_then(|v| v.ntp_servers.clone()).flatten(),
+        protocols,
+    }
+}
+
+async fn capture_boot_options<B: Bmc>(
+    system: &ComputerSystem<B>,
+) -> Result<Vec<BootOptionBackup>, Error<B>> {
+    let Some(options) = system.boot_options().await? else {
+        return Ok(Vec::new());
+    };
+    Ok(options
+        .members()
+        .await?
+        .iter()
+        .map(|option| BootOptionBackup {
+            reference: option.boot_reference().to_string(),
+            display_name: option.display_name().map(|v| v.to_string()),
+            uefi_device_path: option.uefi_device_path().map(|v| v.to_string()),
+        })
+        .collect())
+}
+
+async fn capture_subscriptions<B: Bmc>(
+    subscriptions: &EventSubscriptionCollection<B>,
+) -> Result<Vec<SubscriptionBackup>, Error<B>> {
+    Ok(subscriptions
+        .members()
+        .await?
+        .iter()
+        .filter_map(|subscription| {
+            Some(SubscriptionBackup {
+                destination: subscription.destination()?.into(),
+                protocol: subscription.protocol(),
+                context: subscription.context().map(Into::into),
+            })
+        })
+        .collect())
+}
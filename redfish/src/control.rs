@@ -37,16 +37,30 @@
 //!     ModificationResponse::Empty => {}
 //! }
 //! ```
+//!
+//! Fan speed can be overridden for all fans of a chassis and then
+//! returned to BMC regulation. Chassis routes the request to controls
+//! that regulate fans or to `FanSpeedsPercent` of its environment
+//! metrics depending on the platform:
+//!
+//! ```ignore
+//! chassis.set_fan_speed(80.0).await?;
+//! // ... run thermal test ...
+//! chassis.revert_fans_to_automatic().await?;
+//! ```
 
 use std::sync::Arc;
 
 use crate::schema::control::Control as ControlSchema;
 #[cfg(feature = "chassis")]
 use crate::schema::control_collection::ControlCollection as ControlCollectionSchema;
+use crate::schema::physical_context::PhysicalContext;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
@@ -196,16 +210,93 @@ impl<B: Bmc> Control<B> {
             .as_ref()
             .update::<_, NavProperty<ControlSchema>>(self.data.odata_id(), self.data.etag(), update)
             .await
-            .map_err(Error::Bmc)?
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))?
             .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
             .await
     }
+
+    /// Check whether this control regulates fan speed.
+    ///
+    /// Fan speed controls are percent controls with `Fan` physical
+    /// context.
+    #[must_use]
+    pub fn is_fan_speed_control(&self) -> bool {
+        self.data.physical_context == Some(PhysicalContext::Fan)
+            && self.data.control_type == Some(ControlType::Percent)
+    }
+
+    /// Override fan speed with a fixed percentage.
+    ///
+    /// The requested value is validated against the 0-100 percent range
+    /// and the control's `AllowableMin`/`AllowableMax` before the
+    /// control is switched to `Override` mode. Use
+    /// [`Self::revert_to_automatic`] to return fan regulation to the BMC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - this control does not regulate fan speed
+    /// - `percent` is outside of the allowed range
+    /// - updating the control fails
+    pub async fn set_fan_speed(
+        &self,
+        percent: f64,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        if !self.is_fan_speed_control() {
+            return Err(Error::ControlNotFanSpeed);
+        }
+        let min = self
+            .data
+            .allowable_min
+            .flatten()
+            .map_or(0.0, |min| min.max(0.0));
+        let max = self
+            .data
+            .allowable_max
+            .flatten()
+            .map_or(100.0, |max| max.min(100.0));
+        if !(min..=max).contains(&percent) {
+            return Err(Error::FanSpeedOutOfRange {
+                requested: percent,
+                min,
+                max,
+            });
+        }
+        let update = ControlUpdate::builder()
+            .with_control_mode(ControlMode::Override)
+            .with_set_point(percent)
+            .build();
+        self.update(&update).await
+    }
+
+    /// Return control to automatic regulation by the BMC.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if updating the control fails.
+    pub async fn revert_to_automatic(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        let update = ControlUpdate::builder()
+            .with_control_mode(ControlMode::Automatic)
+            .build();
+        self.update(&update).await
+    }
 }
 
 impl<B: Bmc> Resource for Control<B> {
+    type Entity = ControlSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
 
 #[cfg(any(
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use nv_redfish_core::EdmGuid;
+use nv_redfish_core::ODataId;
+
+use crate::hardware_id::HardwareIdRef;
+
+/// Durable identifier of a resource usable as a database key.
+///
+/// Some vendors renumber collection members after firmware update,
+/// so `@odata.id` of the same hardware changes. The identifier prefers
+/// properties of the hardware itself: `UUID`, then manufacturer and
+/// serial number. Normalized `@odata.id` is used only when neither is
+/// reported. Placeholder values (nil UUID, empty or `N/A` serial
+/// numbers) are ignored.
+///
+/// Identifiers of resources of different types may coincide (for
+/// example, chassis and computer system often report the same serial
+/// number), so keys should be scoped by the type of the resource.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum DurableId {
+    /// `UUID` of the resource.
+    Uuid(EdmGuid),
+    /// Serial number assigned by the manufacturer.
+    SerialNumber {
+        /// Manufacturer of the hardware.
+        manufacturer: Option<String>,
+        /// Serial number of the hardware.
+        serial_number: String,
+    },
+    /// Normalized `@odata.id` of the resource.
+    ODataId(String),
+}
+
+impl DurableId {
+    pub(crate) fn new<Tag>(
+        uuid: Option<EdmGuid>,
+        hardware_id: &HardwareIdRef<'_, Tag>,
+        odata_id: &ODataId,
+    ) -> Self {
+        if let Some(uuid) = uuid.filter(|uuid| !is_placeholder_uuid(uuid)) {
+            return Self::Uuid(uuid);
+        }
+        let serial_number = hardware_id
+            .serial_number
+            .map(|v| v.into_inner().trim())
+            .filter(|v| !is_placeholder(v));
+        if let Some(serial_number) = serial_number {
+            return Self::SerialNumber {
+                manufacturer: hardware_id
+                    .manufacturer
+                    .map(|v| v.into_inner().trim())
+                    .filter(|v| !is_placeholder(v))
+                    .map(ToString::to_string),
+                serial_number: serial_number.to_string(),
+            };
+        }
+        Self::ODataId(normalize_odata_id(&odata_id.to_string()))
+    }
+}
+
+impl fmt::Display for DurableId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Uuid(uuid) => write!(f, "uuid:{uuid}"),
+            Self::SerialNumber {
+                manufacturer,
+                serial_number,
+            } => write!(
+                f,
+                "sn:{}:{serial_number}",
+                manufacturer.as_deref().unwrap_or_default()
+            ),
+            Self::ODataId(id) => write!(f, "odata:{id}"),
+        }
+    }
+}
+
+fn is_placeholder_uuid(uuid: &EdmGuid) -> bool {
+    uuid.is_nil() || uuid.as_bytes().iter().all(|b| *b == 0xff)
+}
+
+// Values that BMCs report when the property is not programmed.
+fn is_placeholder(v: &str) -> bool {
+    const PLACEHOLDERS: &[&str] = &[
+        "n/a",
+        "na",
+        "none",
+        "null",
+        "unknown",
+        "not specified",
+        "not available",
+        "default string",
+        "to be filled by o.e.m.",
+    ];
+    v.is_empty()
+        || v.chars().all(|c| c == '0')
+        || PLACEHOLDERS
+            .iter()
+            .any(|placeholder| v.eq_ignore_ascii_case(placeholder))
+}
+
+// Query, fragment, repeated and trailing slashes don't change
+// the resource.
+fn normalize_odata_id(id: &str) -> String {
+    let path = id.split(['?', '#']).next().unwrap_or_default();
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    format!("/{}", segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_odata_id;
+    use super::DurableId;
+    use crate::hardware_id::HardwareIdRef;
+    use crate::hardware_id::Manufacturer;
+    use crate::hardware_id::SerialNumber;
+    use nv_redfish_core::EdmGuid;
+    use nv_redfish_core::ODataId;
+
+    enum Tag {}
+
+    fn hardware_id<'a>(
+        manufacturer: Option<&'a str>,
+        serial_number: Option<&'a str>,
+    ) -> HardwareIdRef<'a, Tag> {
+        HardwareIdRef {
+            manufacturer: manufacturer.map(Manufacturer::new),
+            model: None,
+            part_number: None,
+            serial_number: serial_number.map(SerialNumber::new),
+        }
+    }
+
+    #[test]
+    fn durable_id_prefers_uuid_then_serial_number() {
+        let id = ODataId::from("/redfish/v1/Systems/1/".to_string());
+        let uuid = "38947555-7742-3448-3784-823347823834"
+            .parse::<EdmGuid>()
+            .expect("valid uuid");
+        let hw = hardware_id(Some("Contoso"), Some(" SN123 "));
+        assert_eq!(DurableId::new(Some(uuid), &hw, &id), DurableId::Uuid(uuid));
+        assert_eq!(
+            DurableId::new(Some(EdmGuid::nil()), &hw, &id).to_string(),
+            "sn:Contoso:SN123"
+        );
+        let hw = hardware_id(Some("N/A"), Some("SN123"));
+        assert_eq!(DurableId::new(None, &hw, &id).to_string(), "sn::SN123");
+        for serial_number in ["", "0000000", "Not Specified", "To Be Filled By O.E.M."] {
+            let hw = hardware_id(Some("Contoso"), Some(serial_number));
+            assert_eq!(
+                DurableId::new(None, &hw, &id),
+                DurableId::ODataId("/redfish/v1/Systems/1".into())
+            );
+        }
+    }
+
+    #[test]
+    fn odata_id_is_normalized() {
+        assert_eq!(
+            normalize_odata_id("/redfish/v1//Chassis/1/?$expand=.#/Members"),
+            "/redfish/v1/Chassis/1"
+        );
+    }
+}
@@ -76,6 +76,11 @@ impl<B: Bmc, T: EntityTypeRef> EntityLink<B, T> {
     pub const fn nav(&self) -> &NavProperty<T> {
         &self.nav
     }
+
+    /// BMC used to access the entity.
+    pub(crate) const fn bmc(&self) -> &NvBmc<B> {
+        &self.bmc
+    }
 }
 
 impl<B, T> EntityLink<B, T>
@@ -130,6 +135,27 @@ where
             .await
             .map_err(Error::Bmc)
     }
+
+    /// Delete this entity capturing the response payload as `R`.
+    ///
+    /// Some services return the deleted entity or a message object
+    /// from DELETE. Use `T` to inspect the returned representation
+    /// (for example, to detect soft delete) or `serde_json::Value` to
+    /// log the raw payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deleting the entity fails.
+    pub async fn delete_with_response<R>(&self) -> Result<ModificationResponse<R>, Error<B>>
+    where
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        self.bmc
+            .as_ref()
+            .delete(self.odata_id())
+            .await
+            .map_err(Error::Bmc)
+    }
 }
 
 /// Trait for full wrapper types that can be constructed from an entity link.
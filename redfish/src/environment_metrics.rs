@@ -0,0 +1,97 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for `EnvironmentMetrics` shared by resources that link it.
+
+use crate::core::ModificationResponse;
+use crate::schema::environment_metrics::EnvironmentMetrics;
+use crate::Error;
+use crate::NvBmc;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+
+#[cfg(feature = "controls")]
+use nv_redfish_core::EntityTypeRef as _;
+#[cfg(feature = "controls")]
+use serde::Serialize;
+
+/// Invoke `EnvironmentMetrics.ResetMetrics` of the referenced metrics.
+pub async fn reset_environment_metrics<B: Bmc>(
+    bmc: &NvBmc<B>,
+    metrics_ref: &NavProperty<EnvironmentMetrics>,
+) -> Result<ModificationResponse<()>, Error<B>>
+where
+    B::Error: nv_redfish_core::ActionError,
+{
+    let metrics = metrics_ref.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+    let actions = metrics.actions.as_ref().ok_or(Error::ActionNotAvailable)?;
+
+    if actions.reset_metrics.is_none() {
+        return Err(Error::ActionNotAvailable);
+    }
+
+    actions
+        .reset_metrics(bmc.as_ref())
+        .await
+        .map_err(Error::Bmc)
+}
+
+#[cfg(feature = "controls")]
+#[derive(Serialize)]
+struct FanSpeedsPercentUpdate<'a> {
+    #[serde(rename = "FanSpeedsPercent")]
+    fan_speeds_percent: Vec<FanSpeedOverride<'a>>,
+}
+
+#[cfg(feature = "controls")]
+#[derive(Serialize)]
+struct FanSpeedOverride<'a> {
+    #[serde(rename = "DeviceName", skip_serializing_if = "Option::is_none")]
+    device_name: Option<&'a str>,
+    #[serde(rename = "Reading")]
+    reading: Option<f64>,
+}
+
+/// Override `FanSpeedsPercent` of all fans reported by the referenced
+/// metrics with `percent`, or return them to BMC regulation when
+/// `percent` is `None`.
+///
+/// Returns `Ok(None)` when the metrics don't report fan speeds.
+#[cfg(feature = "controls")]
+pub async fn set_fan_speeds_percent<B: Bmc>(
+    bmc: &NvBmc<B>,
+    metrics_ref: &NavProperty<EnvironmentMetrics>,
+    percent: Option<f64>,
+) -> Result<Option<ModificationResponse<()>>, Error<B>> {
+    let metrics = metrics_ref.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+    let fans = metrics.fan_speeds_percent.as_deref().unwrap_or_default();
+    if fans.is_empty() {
+        return Ok(None);
+    }
+    let update = FanSpeedsPercentUpdate {
+        fan_speeds_percent: fans
+            .iter()
+            .map(|fan| FanSpeedOverride {
+                device_name: fan.device_name.as_ref().and_then(Option::as_deref),
+                reading: percent,
+            })
+            .collect(),
+    };
+    bmc.as_ref()
+        .update::<_, NavProperty<EnvironmentMetrics>>(metrics.odata_id(), metrics.etag(), &update)
+        .await
+        .map(|response| Some(response.map_entity(|_| ())))
+        .map_err(|err| Error::update_failed(metrics.odata_id(), err))
+}
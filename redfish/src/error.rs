@@ -13,7 +13,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(feature = "managers")]
+use crate::manager::LicensedFeature;
+use crate::registries;
+use crate::registries::MessageRegistries;
+use crate::resource::ResetType;
+#[cfg(any(feature = "task-service", feature = "update-plan"))]
+use crate::schema::task::TaskState;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::RedfishError;
 use serde_json::Error as JsonError;
 use std::error::Error as StdError;
 use std::fmt::Debug;
@@ -21,6 +29,10 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 use std::fmt::Result as FmtResult;
 
+/// HTTP status of an update rejected because `If-Match` does not match
+/// the current `ETag` of the entity.
+const PRECONDITION_FAILED: u16 = 412;
+
 /// Redfish Errors.
 pub enum Error<B: Bmc> {
     /// Errors generated by BMC access.
@@ -29,14 +41,147 @@ pub enum Error<B: Bmc> {
     /// `slot_defined_user_accounts` feature.
     #[cfg(feature = "accounts")]
     AccountSlotNotAvailable,
+    /// Account does not expose the `Keys` collection.
+    #[cfg(feature = "accounts")]
+    AccountKeysNotAvailable,
+    /// Account does not expose the `Certificates` collection.
+    #[cfg(feature = "accounts")]
+    AccountCertificatesNotAvailable,
+    /// Password that complies with the password policy cannot be
+    /// generated. Contains the reason.
+    #[cfg(feature = "accounts")]
+    PasswordGenerationFailed(String),
     /// Action not available for this resource
     ActionNotAvailable,
+    /// BMC implementation doesn't support binary data transfer.
+    BinaryTransferNotSupported,
+    /// Reset type is not in `ResetType@Redfish.AllowableValues` of
+    /// the reset action.
+    ResetTypeNotAllowed {
+        /// Requested reset type.
+        requested: ResetType,
+        /// Reset types allowed by the service.
+        allowed: Vec<String>,
+    },
+    /// Attestation policy rejected evidence reported by the service.
+    /// Contains the reason of rejection.
+    #[cfg(feature = "component-integrity")]
+    AttestationFailed(String),
+    /// Monitoring of the computer system ended before it was powered
+    /// on and finished POST.
+    #[cfg(feature = "computer-systems")]
+    BootNotComplete(nv_redfish_core::ODataId),
+    /// Configuration is locked by a vendor lockdown mode. Contains the
+    /// resource that reported the lock (manager or BIOS).
+    #[cfg(any(feature = "managers", feature = "bios"))]
+    ConfigurationLocked(nv_redfish_core::ODataId),
+    /// Manager feature requires a license that is not installed on
+    /// the service.
+    #[cfg(feature = "managers")]
+    FeatureNotLicensed {
+        /// Feature that was requested.
+        feature: LicensedFeature,
+        /// Name of the license that enables the feature.
+        license: String,
+    },
+    /// Configuration backup was captured from a system of a different
+    /// model than the restore target.
+    #[cfg(feature = "config-backup")]
+    ConfigBackupModelMismatch {
+        /// Model of the system in the backup.
+        backup: Option<String>,
+        /// Model of the target system.
+        target: Option<String>,
+    },
+    /// Resource required to restore configuration backup is not
+    /// provided by the service. Contains the name of the resource.
+    #[cfg(feature = "config-backup")]
+    ConfigBackupResourceNotAvailable(&'static str),
+    /// Resource required by a provisioning step is not provided by
+    /// the service. Contains the name of the resource.
+    #[cfg(feature = "provision")]
+    ProvisionResourceNotAvailable(&'static str),
+    /// Account with the user name is not found by a provisioning step.
+    #[cfg(feature = "provision")]
+    ProvisionAccountNotFound(String),
+    /// Step of an update plan depends on a step that is not in the
+    /// plan.
+    #[cfg(feature = "update-plan")]
+    UpdatePlanUnknownDependency {
+        /// Name of the step.
+        step: String,
+        /// Name of the missing dependency.
+        dependency: String,
+    },
+    /// Update plan contains more than one step with the same name.
+    #[cfg(feature = "update-plan")]
+    UpdatePlanDuplicateStep(String),
+    /// Dependencies of update plan steps form a cycle. Contains the
+    /// name of a step of the cycle.
+    #[cfg(feature = "update-plan")]
+    UpdatePlanCycle(String),
+    /// Resource required by an update plan is not provided by the
+    /// service. Contains the name of the resource.
+    #[cfg(feature = "update-plan")]
+    UpdatePlanResourceNotAvailable(&'static str),
+    /// Task of an update step finished unsuccessfully.
+    #[cfg(feature = "update-plan")]
+    UpdateTaskFailed {
+        /// Task of the update.
+        task: nv_redfish_core::ODataId,
+        /// Final state of the task.
+        state: Option<TaskState>,
+    },
+    /// Computer system does not expose the `BootOptions` collection.
+    #[cfg(feature = "boot-options")]
+    BootOptionsNotAvailable,
+    /// UEFI device path is not provided by any boot option of the
+    /// computer system. Contains the requested device path.
+    #[cfg(feature = "boot-options")]
+    UefiTargetNotInBootOptions(String),
+    /// Collection returned more pages than are read following
+    /// `Members@odata.nextLink`. Contains the collection.
+    CollectionTooManyPages(nv_redfish_core::ODataId),
+    /// Resource does not provide the single-valued navigation
+    /// property. Contains the resource and name of the property.
+    NavigationPropertyNotAvailable(nv_redfish_core::ODataId, String),
+    /// Operation was cancelled by `CancellationToken`.
+    #[cfg(feature = "cancellation")]
+    Cancelled,
+    /// Control does not regulate fan speed.
+    #[cfg(feature = "controls")]
+    ControlNotFanSpeed,
+    /// Requested fan speed is outside of the range allowed by control.
+    #[cfg(feature = "controls")]
+    FanSpeedOutOfRange {
+        /// Requested fan speed in percent.
+        requested: f64,
+        /// Minimal allowed fan speed in percent.
+        min: f64,
+        /// Maximal allowed fan speed in percent.
+        max: f64,
+    },
+    /// Chassis provides neither fan speed controls nor
+    /// `FanSpeedsPercent` in its environment metrics.
+    #[cfg(feature = "controls")]
+    FanSpeedControlNotAvailable(nv_redfish_core::ODataId),
+    /// Bound of the log entries time window cannot be encoded in the
+    /// query or compared with system time.
+    #[cfg(feature = "log-services")]
+    LogEntriesTimeBoundInvalid(nv_redfish_core::EdmDateTimeOffset),
+    /// Assembly does not provide `BinaryDataURI`
+    #[cfg(feature = "assembly")]
+    AssemblyBinaryDataUriNotAvailable,
     /// Event service does not provide `ServerSentEventUri`
     #[cfg(feature = "event-service")]
     EventServiceServerSentEventUriNotAvailable,
     /// Update service does not provide `MultipartHttpPushUri`
     #[cfg(feature = "update-service")]
     UpdateServiceMultipartHttpPushUriNotAvailable,
+    /// Update service accepted the multipart upload but did not
+    /// return a task.
+    #[cfg(feature = "update-service")]
+    UpdateServiceTaskNotReturned,
     /// Update service does not provide `HttpPushUri`
     #[cfg(feature = "update-service-deprecated")]
     UpdateServiceHttpPushUriNotAvailable,
@@ -51,29 +196,277 @@ pub enum Error<B: Bmc> {
         /// Expected TaskService Tasks collection path.
         task_collection: nv_redfish_core::ODataId,
     },
+    /// Task monitor ran out of events and polling ticks before the
+    /// task finished.
+    #[cfg(feature = "task-service")]
+    TaskNotFinished(nv_redfish_core::ODataId),
+    /// Task finished in a state other than `Completed`.
+    #[cfg(feature = "task-service")]
+    TaskFailed {
+        /// The task.
+        task: nv_redfish_core::ODataId,
+        /// Final state of the task.
+        state: Option<TaskState>,
+        /// Messages reported by the task.
+        messages: Vec<String>,
+    },
     /// Metric definitions are not available for telemetry service
     #[cfg(feature = "telemetry-service")]
     MetricDefinitionsNotAvailable,
     /// Metric report definitions are not available for telemetry service
     #[cfg(feature = "telemetry-service")]
     MetricReportDefinitionsNotAvailable,
+    /// Functionality is not available because the cargo feature is not
+    /// compiled in. Contains name of the feature.
+    #[cfg(feature = "stubs")]
+    FeatureNotCompiled(&'static str),
+    /// Update of the entity was rejected with `412 Precondition
+    /// Failed` because the entity was modified concurrently. Returned
+    /// after retries of the update (if enabled in the BMC client) are
+    /// exhausted.
+    Conflict {
+        /// The updated entity.
+        id: nv_redfish_core::ODataId,
+        /// Error returned by the BMC for the last attempt.
+        error: B::Error,
+    },
     /// JSON parse error.
     Json(JsonError),
+    /// Payload cannot be parsed and its `@odata.type` is not compiled
+    /// into the crate: schema version is newer than the compiled one
+    /// or namespace belongs to a disabled feature.
+    UnknownSchemaType {
+        /// `@odata.type` of the payload.
+        odata_type: String,
+        /// Compiled type of the same namespace and name with the
+        /// nearest version.
+        nearest_compiled: Option<String>,
+    },
+}
+
+impl<B: Bmc> Error<B> {
+    /// Redfish error response returned by the service when it
+    /// rejected the request.
+    #[must_use]
+    pub fn redfish_error(&self) -> Option<RedfishError> {
+        if let Self::Bmc(err) | Self::Conflict { error: err, .. } = self {
+            B::redfish_error(err)
+        } else {
+            None
+        }
+    }
+
+    /// Error for `err` returned by the BMC for update of entity `id`.
+    ///
+    /// Returns [`Self::Conflict`] if the service rejected the update
+    /// with `412 Precondition Failed` and [`Self::Bmc`] otherwise.
+    #[allow(dead_code)] // feature-enabled func
+    pub(crate) fn update_failed(id: &nv_redfish_core::ODataId, err: B::Error) -> Self {
+        if B::error_status_code(&err) == Some(PRECONDITION_FAILED) {
+            Self::Conflict {
+                id: id.clone(),
+                error: err,
+            }
+        } else {
+            Self::Bmc(err)
+        }
+    }
+
+    /// Check if the service rejected the request with message `id`,
+    /// for example `Base.1.0.ResourceAlreadyExists`. Version of the
+    /// message registry is ignored (see
+    /// [`nv_redfish_core::RedfishMessage::is`]).
+    #[must_use]
+    pub fn has_redfish_message(&self, id: &str) -> bool {
+        self.redfish_error()
+            .is_some_and(|error| error.has_message(id))
+    }
+
+    /// Language of the error response returned by the service, if it
+    /// was reported.
+    #[must_use]
+    pub fn response_language(&self) -> Option<&str> {
+        if let Self::Bmc(err) | Self::Conflict { error: err, .. } = self {
+            B::error_response_language(err)
+        } else {
+            None
+        }
+    }
+
+    /// Recovery guidance for the request rejected by the service.
+    ///
+    /// Messages of the error response are resolved with `registries`
+    /// (for example, registries of the service downloaded in the
+    /// operator's language by `ServiceRoot::message_registries`), so
+    /// guidance uses the localized text and resolution of the
+    /// registry. Messages that are not defined by `registries`, or all
+    /// messages when `registries` is `None`, use built-in guidance for
+    /// well-known `Base` registry messages and the text provided by
+    /// the service otherwise.
+    #[must_use]
+    pub fn recovery_guidance(&self, registries: Option<&MessageRegistries>) -> Vec<String> {
+        if let Self::Bmc(err) | Self::Conflict { error: err, .. } = self {
+            B::error_response_body(err)
+                .map(|body| {
+                    registries::recovery_guidance(body, B::error_response_language(err), registries)
+                })
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        }
+    }
 }
 
 impl<B: Bmc> Display for Error<B> {
     #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
-            Self::Bmc(err) => write!(f, "BMC error: {err}"),
+            Self::Bmc(err) => {
+                write!(f, "BMC error: {err}")?;
+                let guidance = self.recovery_guidance(None);
+                if !guidance.is_empty() {
+                    write!(f, " (recovery: {})", guidance.join("; "))?;
+                }
+                Ok(())
+            }
+            Self::Conflict { id, error } => write!(
+                f,
+                "Update of {id} conflicts with concurrent modification: {error}"
+            ),
             Self::Json(err) => write!(f, "JSON error: {err}"),
+            Self::UnknownSchemaType {
+                odata_type,
+                nearest_compiled,
+            } => {
+                write!(f, "Payload of type {odata_type} is not supported: type is not compiled")?;
+                if let Some(nearest) = nearest_compiled {
+                    write!(f, " (nearest compiled type: {nearest})")?;
+                }
+                Ok(())
+            }
             #[cfg(feature = "accounts")]
             Self::AccountSlotNotAvailable => {
                 write!(f, "Free account slot is not found")
             }
+            #[cfg(feature = "accounts")]
+            Self::AccountKeysNotAvailable => {
+                write!(f, "Account keys are not available")
+            }
+            #[cfg(feature = "accounts")]
+            Self::AccountCertificatesNotAvailable => {
+                write!(f, "Account certificates are not available")
+            }
+            #[cfg(feature = "accounts")]
+            Self::PasswordGenerationFailed(reason) => {
+                write!(f, "Password generation failed: {reason}")
+            }
             Self::ActionNotAvailable => {
                 write!(f, "Action is not available for this resource")
             }
+            Self::BinaryTransferNotSupported => {
+                write!(f, "BMC does not support binary data transfer")
+            }
+            Self::ResetTypeNotAllowed { requested, allowed } => {
+                write!(
+                    f,
+                    "Reset type {requested:?} is not allowed by the service (allowed: {})",
+                    allowed.join(", ")
+                )
+            }
+            #[cfg(feature = "component-integrity")]
+            Self::AttestationFailed(reason) => {
+                write!(f, "Attestation of the service failed: {reason}")
+            }
+            #[cfg(feature = "computer-systems")]
+            Self::BootNotComplete(system) => {
+                write!(f, "System {system} did not finish boot before monitoring ended")
+            }
+            #[cfg(any(feature = "managers", feature = "bios"))]
+            Self::ConfigurationLocked(resource) => {
+                write!(f, "Configuration of {resource} is locked by lockdown mode")
+            }
+            #[cfg(feature = "managers")]
+            Self::FeatureNotLicensed { feature, license } => {
+                write!(f, "{feature} requires license that is not installed: {license}")
+            }
+            #[cfg(feature = "config-backup")]
+            Self::ConfigBackupModelMismatch { backup, target } => {
+                write!(
+                    f,
+                    "Configuration backup of model {backup:?} cannot be restored to model {target:?}"
+                )
+            }
+            #[cfg(feature = "config-backup")]
+            Self::ConfigBackupResourceNotAvailable(resource) => {
+                write!(f, "Resource required to restore configuration is not available: {resource}")
+            }
+            #[cfg(feature = "provision")]
+            Self::ProvisionResourceNotAvailable(resource) => {
+                write!(f, "Resource required for provisioning is not available: {resource}")
+            }
+            #[cfg(feature = "provision")]
+            Self::ProvisionAccountNotFound(user_name) => {
+                write!(f, "Account {user_name} is not found")
+            }
+            #[cfg(feature = "update-plan")]
+            Self::UpdatePlanUnknownDependency { step, dependency } => {
+                write!(f, "Update step {step} depends on unknown step {dependency}")
+            }
+            #[cfg(feature = "update-plan")]
+            Self::UpdatePlanDuplicateStep(step) => {
+                write!(f, "Update plan contains step {step} more than once")
+            }
+            #[cfg(feature = "update-plan")]
+            Self::UpdatePlanCycle(step) => {
+                write!(f, "Update step {step} is part of a dependency cycle")
+            }
+            #[cfg(feature = "update-plan")]
+            Self::UpdatePlanResourceNotAvailable(resource) => {
+                write!(f, "Resource required for update is not available: {resource}")
+            }
+            #[cfg(feature = "update-plan")]
+            Self::UpdateTaskFailed { task, state } => {
+                write!(f, "Update task {task} finished unsuccessfully: {state:?}")
+            }
+            #[cfg(feature = "boot-options")]
+            Self::BootOptionsNotAvailable => {
+                write!(f, "Boot options are not available")
+            }
+            #[cfg(feature = "boot-options")]
+            Self::UefiTargetNotInBootOptions(path) => {
+                write!(f, "UEFI device path {path} is not found in boot options")
+            }
+            Self::CollectionTooManyPages(id) => {
+                write!(f, "Collection {id} returned too many pages")
+            }
+            Self::NavigationPropertyNotAvailable(id, name) => {
+                write!(f, "Resource {id} does not provide navigation property {name}")
+            }
+            #[cfg(feature = "cancellation")]
+            Self::Cancelled => write!(f, "Operation was cancelled"),
+            #[cfg(feature = "controls")]
+            Self::ControlNotFanSpeed => write!(f, "Control does not regulate fan speed"),
+            #[cfg(feature = "controls")]
+            Self::FanSpeedOutOfRange {
+                requested,
+                min,
+                max,
+            } => write!(
+                f,
+                "Fan speed {requested}% is outside of allowed range {min}%..={max}%"
+            ),
+            #[cfg(feature = "controls")]
+            Self::FanSpeedControlNotAvailable(id) => {
+                write!(f, "Chassis {id} does not provide fan speed control")
+            }
+            #[cfg(feature = "log-services")]
+            Self::LogEntriesTimeBoundInvalid(bound) => {
+                write!(f, "Log entries time bound {bound:?} is invalid")
+            }
+            #[cfg(feature = "assembly")]
+            Self::AssemblyBinaryDataUriNotAvailable => {
+                write!(f, "Assembly does not provide BinaryDataURI")
+            }
             #[cfg(feature = "event-service")]
             Self::EventServiceServerSentEventUriNotAvailable => {
                 write!(f, "Event service does not provide ServerSentEventUri")
@@ -82,6 +475,10 @@ impl<B: Bmc> Display for Error<B> {
             Self::UpdateServiceMultipartHttpPushUriNotAvailable => {
                 write!(f, "Update service does not provide MultipartHttpPushUri")
             }
+            #[cfg(feature = "update-service")]
+            Self::UpdateServiceTaskNotReturned => {
+                write!(f, "Update service did not return task of the update")
+            }
             #[cfg(feature = "update-service-deprecated")]
             Self::UpdateServiceHttpPushUriNotAvailable => {
                 write!(f, "Update service does not provide HttpPushUri")
@@ -98,6 +495,22 @@ impl<B: Bmc> Display for Error<B> {
                 f,
                 "Task location {task_location} is not in TaskService Tasks collection {task_collection}"
             ),
+            #[cfg(feature = "task-service")]
+            Self::TaskNotFinished(task) => {
+                write!(f, "Task {task} did not finish before monitoring ended")
+            }
+            #[cfg(feature = "task-service")]
+            Self::TaskFailed {
+                task,
+                state,
+                messages,
+            } => {
+                write!(f, "Task {task} finished unsuccessfully: {state:?}")?;
+                if !messages.is_empty() {
+                    write!(f, " ({})", messages.join("; "))?;
+                }
+                Ok(())
+            }
             #[cfg(feature = "telemetry-service")]
             Self::MetricDefinitionsNotAvailable => {
                 write!(f, "Metric definitions are not available")
@@ -106,6 +519,10 @@ impl<B: Bmc> Display for Error<B> {
             Self::MetricReportDefinitionsNotAvailable => {
                 write!(f, "Metric report definitions are not available")
             }
+            #[cfg(feature = "stubs")]
+            Self::FeatureNotCompiled(feature) => {
+                write!(f, "Feature {feature} is not compiled in")
+            }
         }
     }
 }
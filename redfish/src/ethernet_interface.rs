@@ -16,16 +16,23 @@
 //! Ethernet interfaces
 //!
 
-use crate::mac_address::MacAddress;
+use crate::ip_address;
+use crate::ip_address::IpAddrParseError;
+use crate::mac_address;
+use crate::mac_address::MacAddr;
+use crate::mac_address::MacAddrParseError;
 use crate::schema::ethernet_interface::EthernetInterface as EthernetInterfaceSchema;
 use crate::schema::ethernet_interface_collection::EthernetInterfaceCollection as EthernetInterfaceCollectionSchema;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use std::marker::PhantomData;
+use std::net::IpAddr;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -59,11 +66,11 @@ impl<B: Bmc> EthernetInterfaceCollection<B> {
     ///
     /// Returns an error if fetching manager data fails.
     pub async fn members(&self) -> Result<Vec<EthernetInterface<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(EthernetInterface::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+        self.bmc
+            .fan_out(&self.collection.members, |m| {
+                EthernetInterface::new(&self.bmc, m)
+            })
+            .await
     }
 }
 
@@ -129,26 +136,60 @@ impl<B: Bmc> EthernetInterface<B> {
             .copied()
     }
 
-    /// MAC address of the interface.
+    /// Current speed of the interface (Mbit/s).
+    #[must_use]
+    pub fn speed_mbps(&self) -> Option<i64> {
+        self.data.speed_mbps.flatten()
+    }
+
+    /// MAC address of the interface. `Err` keeps the reported value
+    /// that is not a valid MAC address.
     #[must_use]
-    pub fn mac_address(&self) -> Option<MacAddress<'_>> {
+    pub fn mac_address(&self) -> Option<Result<MacAddr, MacAddrParseError>> {
         self.data
             .mac_address
             .as_ref()
-            .and_then(Option::as_ref)
-            .map(String::as_str)
-            .map(MacAddress::new)
+            .and_then(Option::as_deref)
+            .and_then(mac_address::parse_reported)
     }
 
-    /// Permanent MAC address of the interface.
+    /// Permanent MAC address of the interface. `Err` keeps the
+    /// reported value that is not a valid MAC address.
     #[must_use]
-    pub fn permanent_mac_address(&self) -> Option<MacAddress<'_>> {
+    pub fn permanent_mac_address(&self) -> Option<Result<MacAddr, MacAddrParseError>> {
         self.data
             .permanent_mac_address
             .as_ref()
-            .and_then(Option::as_ref)
-            .map(String::as_str)
-            .map(MacAddress::new)
+            .and_then(Option::as_deref)
+            .and_then(mac_address::parse_reported)
+    }
+
+    /// IPv4 addresses assigned to the interface. Entries without
+    /// address are skipped; `Err` keeps the reported value that is not
+    /// a valid IP address.
+    #[must_use]
+    pub fn ipv4_addresses(&self) -> Vec<Result<IpAddr, IpAddrParseError>> {
+        self.data
+            .ipv4addresses
+            .iter()
+            .flatten()
+            .filter_map(|v| v.address.as_ref().and_then(Option::as_deref))
+            .filter_map(ip_address::parse_reported)
+            .collect()
+    }
+
+    /// IPv6 addresses assigned to the interface. Entries without
+    /// address are skipped; `Err` keeps the reported value that is not
+    /// a valid IP address.
+    #[must_use]
+    pub fn ipv6_addresses(&self) -> Vec<Result<IpAddr, IpAddrParseError>> {
+        self.data
+            .ipv6addresses
+            .iter()
+            .flatten()
+            .filter_map(|v| v.address.as_ref().and_then(Option::as_deref))
+            .filter_map(ip_address::parse_reported)
+            .collect()
     }
 
     /// UEFI device path for the interface.
@@ -164,7 +205,18 @@ impl<B: Bmc> EthernetInterface<B> {
 }
 
 impl<B: Bmc> Resource for EthernetInterface<B> {
+    type Entity = EthernetInterfaceSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -0,0 +1,429 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! De-duplication and ordering of SSE payloads.
+//!
+//! Some BMCs re-send events after SSE reconnects or deliver metric
+//! reports out of order. Both problems are handled on raw (patched)
+//! JSON payloads before they are deserialized:
+//!
+//! - De-duplication remembers keys of the most recent payloads. Key
+//!   of an event is built from `EventId` of its records (or
+//!   `MessageId` and `EventTimestamp` when `EventId` is absent). Key of
+//!   a metric report is its `Id` and `Timestamp`.
+//! - Ordering keeps a bounded buffer of metric reports sorted by
+//!   `Timestamp` and releases the oldest report when the buffer is
+//!   full or the stream ends. Reports that are older than an already
+//!   released report are dropped, so released reports are monotonic.
+//! - Optionally, buffered reports are released when they have waited
+//!   for the configured delay, so a quiet stream doesn't hold reports
+//!   indefinitely. The delay is checked on every payload and on every
+//!   item of the ticks stream supplied by the caller.
+
+use futures_core::Stream;
+use futures_util::future;
+use futures_util::stream;
+use futures_util::stream::BoxStream;
+use futures_util::StreamExt as _;
+use nv_redfish_core::odata::ODataType;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EdmDateTimeOffset;
+use serde_json::Value as JsonValue;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::convert::TryFrom as _;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+/// Options of event stream delivery.
+///
+/// Both features are disabled by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventStreamOptions {
+    /// Number of most recent payload keys remembered to drop
+    /// duplicates. `None` disables de-duplication.
+    pub dedup_window: Option<usize>,
+    /// Maximum number of metric reports buffered to restore order by
+    /// `Timestamp`. `None` disables ordering.
+    pub reorder_buffer: Option<usize>,
+    /// Maximum time a metric report waits in the ordering buffer.
+    /// `None` keeps reports until the buffer is full or the stream
+    /// ends.
+    pub flush_after: Option<Duration>,
+}
+
+impl EventStreamOptions {
+    /// Create options with de-duplication and ordering disabled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop payloads that repeat one of `window` most recent payloads.
+    #[must_use]
+    pub const fn dedup_window(mut self, window: usize) -> Self {
+        self.dedup_window = Some(window);
+        self
+    }
+
+    /// Buffer up to `size` metric reports to deliver them ordered by
+    /// `Timestamp`.
+    #[must_use]
+    pub const fn reorder_buffer(mut self, size: usize) -> Self {
+        self.reorder_buffer = Some(size);
+        self
+    }
+
+    /// Release buffered metric reports that have waited for `delay`
+    /// even if the ordering buffer is not full. Takes effect only
+    /// together with [`Self::reorder_buffer`].
+    #[must_use]
+    pub const fn flush_after(mut self, delay: Duration) -> Self {
+        self.flush_after = Some(delay);
+        self
+    }
+
+    const fn is_enabled(&self) -> bool {
+        self.dedup_window.is_some() || self.reorder_buffer.is_some()
+    }
+}
+
+/// Apply de-duplication and ordering to the stream of payloads.
+///
+/// Every item of `ticks` releases metric reports that have waited in
+/// the ordering buffer longer than `flush_after` of `options`. The
+/// stream ends when `inner` ends, regardless of `ticks`.
+pub fn apply<E, S>(
+    inner: BoxTryStream<JsonValue, E>,
+    options: EventStreamOptions,
+    ticks: S,
+) -> BoxTryStream<JsonValue, E>
+where
+    E: Send + 'static,
+    S: Stream + Send + 'static,
+{
+    if !options.is_enabled() {
+        return inner;
+    }
+    let inner = inner
+        .map(|item| Input::Payload(Some(item)))
+        .chain(stream::once(future::ready(Input::Payload(None))));
+    let ticks = ticks.map(|_| Input::Tick);
+    let state = State {
+        inner: stream::select(inner, ticks).boxed(),
+        dedup: options.dedup_window.map(DedupWindow::new),
+        reorder: options.reorder_buffer.map(ReorderBuffer::new),
+        flush_after: options.flush_after,
+        ready: VecDeque::new(),
+        done: false,
+    };
+    Box::pin(stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(payload) = state.ready.pop_front() {
+                return Some((Ok(payload), state));
+            }
+            if state.done {
+                return None;
+            }
+            match state.inner.next().await {
+                Some(Input::Payload(Some(Ok(payload)))) => {
+                    state.push(payload);
+                    state.flush_expired();
+                }
+                Some(Input::Payload(Some(Err(err)))) => return Some((Err(err), state)),
+                Some(Input::Tick) => state.flush_expired(),
+                Some(Input::Payload(None)) | None => {
+                    state.done = true;
+                    if let Some(reorder) = &mut state.reorder {
+                        state.ready.extend(reorder.drain());
+                    }
+                }
+            }
+        }
+    }))
+}
+
+enum Input<E> {
+    // `None` marks the end of the payload stream.
+    Payload(Option<Result<JsonValue, E>>),
+    Tick,
+}
+
+struct State<E> {
+    inner: BoxStream<'static, Input<E>>,
+    dedup: Option<DedupWindow>,
+    reorder: Option<ReorderBuffer>,
+    flush_after: Option<Duration>,
+    ready: VecDeque<JsonValue>,
+    done: bool,
+}
+
+impl<E> State<E> {
+    fn flush_expired(&mut self) {
+        if let (Some(reorder), Some(delay)) = (&mut self.reorder, self.flush_after) {
+            self.ready
+                .extend(reorder.release_expired(Instant::now(), delay));
+        }
+    }
+
+    fn push(&mut self, payload: JsonValue) {
+        let is_report = is_metric_report(&payload);
+        if let Some(dedup) = &mut self.dedup {
+            let key = if is_report {
+                metric_report_key(&payload)
+            } else {
+                event_key(&payload)
+            };
+            if key.is_some_and(|key| dedup.is_duplicate(key)) {
+                return;
+            }
+        }
+        match (
+            &mut self.reorder,
+            is_report.then(|| timestamp(&payload)).flatten(),
+        ) {
+            (Some(reorder), Some(timestamp)) => {
+                self.ready.extend(reorder.push(timestamp, payload));
+            }
+            _ => self.ready.push_back(payload),
+        }
+    }
+}
+
+struct DedupWindow {
+    capacity: usize,
+    order: VecDeque<String>,
+    seen: HashSet<String>,
+}
+
+impl DedupWindow {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: VecDeque::with_capacity(capacity),
+            seen: HashSet::with_capacity(capacity),
+        }
+    }
+
+    fn is_duplicate(&mut self, key: String) -> bool {
+        if self.seen.contains(&key) {
+            return true;
+        }
+        if self.capacity == 0 {
+            return false;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(key.clone());
+        self.order.push_back(key);
+        false
+    }
+}
+
+struct ReorderBuffer {
+    capacity: usize,
+    // Sorted by timestamp. Reports with equal timestamps keep arrival
+    // order. Arrival instant is kept for time-based flush.
+    buffer: VecDeque<(SystemTime, Instant, JsonValue)>,
+    last_released: Option<SystemTime>,
+}
+
+impl ReorderBuffer {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            buffer: VecDeque::with_capacity(capacity),
+            last_released: None,
+        }
+    }
+
+    fn push(&mut self, timestamp: SystemTime, payload: JsonValue) -> Option<JsonValue> {
+        if self.last_released.is_some_and(|last| timestamp < last) {
+            // Too late: newer report is already delivered.
+            return None;
+        }
+        let pos = self.buffer.partition_point(|(t, _, _)| *t <= timestamp);
+        self.buffer
+            .insert(pos, (timestamp, Instant::now(), payload));
+        if self.buffer.len() > self.capacity {
+            self.release()
+        } else {
+            None
+        }
+    }
+
+    fn release(&mut self) -> Option<JsonValue> {
+        self.buffer.pop_front().map(|(timestamp, _, payload)| {
+            self.last_released = Some(timestamp);
+            payload
+        })
+    }
+
+    // Release reports that have waited for `delay` together with all
+    // reports that precede them by timestamp to keep released reports
+    // monotonic.
+    fn release_expired(&mut self, now: Instant, delay: Duration) -> Vec<JsonValue> {
+        let Some(last) = self
+            .buffer
+            .iter()
+            .rposition(|(_, arrived, _)| now.saturating_duration_since(*arrived) >= delay)
+        else {
+            return Vec::new();
+        };
+        (0..=last).filter_map(|_| self.release()).collect()
+    }
+
+    fn drain(&mut self) -> Vec<JsonValue> {
+        let mut released = Vec::with_capacity(self.buffer.len());
+        while let Some(payload) = self.release() {
+            released.push(payload);
+        }
+        released
+    }
+}
+
+fn is_metric_report(payload: &JsonValue) -> bool {
+    ODataType::parse_from(payload).is_some_and(|t| t.type_name == "MetricReport")
+}
+
+fn timestamp(payload: &JsonValue) -> Option<SystemTime> {
+    payload
+        .get("Timestamp")
+        .and_then(JsonValue::as_str)
+        .and_then(|v| v.parse::<EdmDateTimeOffset>().ok())
+        .and_then(|v| SystemTime::try_from(v).ok())
+}
+
+fn metric_report_key(payload: &JsonValue) -> Option<String> {
+    let id = payload.get("Id").and_then(JsonValue::as_str)?;
+    let timestamp = payload.get("Timestamp").and_then(JsonValue::as_str)?;
+    Some(format!("MetricReport:{id}@{timestamp}"))
+}
+
+fn event_key(payload: &JsonValue) -> Option<String> {
+    let records = payload.get("Events").and_then(JsonValue::as_array)?;
+    let keys = records
+        .iter()
+        .map(|record| {
+            record
+                .get("EventId")
+                .and_then(JsonValue::as_str)
+                .map(|id| format!("EventId:{id}"))
+                .or_else(|| {
+                    let message_id = record.get("MessageId").and_then(JsonValue::as_str)?;
+                    let timestamp = record.get("EventTimestamp").and_then(JsonValue::as_str)?;
+                    Some(format!("MessageId:{message_id}@{timestamp}"))
+                })
+        })
+        .collect::<Option<Vec<_>>>()?;
+    (!keys.is_empty()).then(|| keys.join(";"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::FutureExt as _;
+    use futures_util::TryStreamExt as _;
+    use serde_json::json;
+
+    fn event(event_id: &str) -> JsonValue {
+        json!({
+            "@odata.type": "#Event.v1_6_0.Event",
+            "Id": event_id,
+            "Events": [{ "EventId": event_id, "MessageId": "Base.1.0.Success" }]
+        })
+    }
+
+    fn report(timestamp: &str) -> JsonValue {
+        json!({
+            "@odata.type": "#MetricReport.v1_3_0.MetricReport",
+            "Id": "Power",
+            "Timestamp": timestamp
+        })
+    }
+
+    fn run(payloads: Vec<JsonValue>, options: EventStreamOptions) -> Vec<JsonValue> {
+        let inner: BoxTryStream<JsonValue, ()> =
+            Box::pin(stream::iter(payloads.into_iter().map(Ok)));
+        apply(inner, options, stream::empty::<()>())
+            .try_collect::<Vec<_>>()
+            .now_or_never()
+            .expect("stream of ready items")
+            .expect("no errors")
+    }
+
+    #[test]
+    fn drops_duplicates_within_window() {
+        let output = run(
+            vec![event("1"), event("2"), event("1"), event("3"), event("1")],
+            EventStreamOptions::new().dedup_window(2),
+        );
+        // Third "1" is outside of the window of two most recent events.
+        assert_eq!(output, vec![event("1"), event("2"), event("3"), event("1")]);
+    }
+
+    #[test]
+    fn orders_metric_reports_by_timestamp() {
+        let output = run(
+            vec![
+                report("2026-01-01T00:00:02Z"),
+                report("2026-01-01T00:00:01Z"),
+                event("1"),
+                report("2026-01-01T00:00:04Z"),
+                report("2026-01-01T00:00:03Z"),
+                report("2026-01-01T00:00:00Z"),
+            ],
+            EventStreamOptions::new().reorder_buffer(2),
+        );
+        assert_eq!(
+            output,
+            vec![
+                event("1"),
+                report("2026-01-01T00:00:01Z"),
+                report("2026-01-01T00:00:02Z"),
+                report("2026-01-01T00:00:03Z"),
+                report("2026-01-01T00:00:04Z"),
+            ]
+        );
+    }
+
+    #[test]
+    fn releases_reports_waiting_longer_than_flush_delay() {
+        let delay = Duration::from_secs(30);
+        let mut buffer = ReorderBuffer::new(10);
+        let before = Instant::now();
+        for payload in [
+            report("2026-01-01T00:00:02Z"),
+            report("2026-01-01T00:00:01Z"),
+        ] {
+            let timestamp = timestamp(&payload).expect("valid timestamp");
+            assert!(buffer.push(timestamp, payload).is_none());
+        }
+
+        assert!(buffer.release_expired(before, delay).is_empty());
+        assert_eq!(
+            buffer.release_expired(Instant::now() + delay, delay),
+            vec![
+                report("2026-01-01T00:00:01Z"),
+                report("2026-01-01T00:00:02Z")
+            ]
+        );
+    }
+}
@@ -0,0 +1,329 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Collection membership changes.
+//!
+//! Membership of a collection is tracked by `ResourceEvent` registry
+//! messages (`ResourceCreated` and `ResourceRemoved`) whose
+//! `OriginOfCondition` is a member of the collection. Events are
+//! filtered on the client side. Periodic re-read of the collection
+//! (diffing with known members) covers BMCs that don't deliver these
+//! events or don't provide the event stream at all.
+
+use crate::event_service::EventService;
+use crate::event_service::EventStreamOptions;
+use crate::member_sync::fetch_member_ids;
+use crate::Error;
+use crate::NvBmc;
+use futures_util::future;
+use futures_util::stream;
+use futures_util::stream::BoxStream;
+use futures_util::Stream;
+use futures_util::StreamExt as _;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+
+/// Messages of the `ResourceEvent` registry reporting a new resource.
+/// Some services use `ResourceAdded` instead of `ResourceCreated`.
+const MEMBER_ADDED_MESSAGES: [&str; 2] = ["ResourceCreated", "ResourceAdded"];
+
+/// Message of the `ResourceEvent` registry reporting removed resource.
+const MEMBER_REMOVED_MESSAGE: &str = "ResourceRemoved";
+
+/// Change of collection membership.
+#[derive(Debug)]
+pub enum MembershipChange<T: EntityTypeRef> {
+    /// Member was added to the collection.
+    MemberAdded(NavProperty<T>),
+    /// Member was removed from the collection.
+    MemberRemoved(ODataId),
+}
+
+impl<B: Bmc> EventService<B> {
+    /// Stream of membership changes of the `collection` (for example,
+    /// `/redfish/v1/AccountService/Accounts`).
+    ///
+    /// Members are read once when the stream is opened and only
+    /// subsequent changes are reported. Changes are detected by
+    /// `ResourceCreated` / `ResourceRemoved` events about members of
+    /// the collection. Each item of `fallback` (for example, ticks of
+    /// an interval timer) re-reads the collection and reports the
+    /// difference with known members, so changes are detected even
+    /// if the BMC doesn't deliver these events or the event stream
+    /// cannot be opened or breaks. Events about the collection itself
+    /// also cause re-read of the collection.
+    ///
+    /// The stream ends when both the event stream and `fallback` end.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the initial read of the collection fails.
+    /// Errors of subsequent reads are delivered as stream items.
+    pub async fn membership_changes<T, S>(
+        &self,
+        collection: &ODataId,
+        fallback: S,
+    ) -> Result<BoxTryStream<MembershipChange<T>, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+        T: EntityTypeRef + Send + Sync + 'static,
+        S: Stream + Send + 'static,
+    {
+        let members = fetch_member_ids(&self.bmc, collection)
+            .await?
+            .into_iter()
+            .collect();
+
+        // Event stream is optional: without it the collection is
+        // re-read on fallback ticks only.
+        let events = self
+            .json_events(EventStreamOptions::default())
+            .await
+            .map_or_else(|_| stream::empty().boxed(), |events| events.boxed())
+            .take_while(|payload| future::ready(payload.is_ok()))
+            .filter_map(|payload| future::ready(payload.ok().map(Trigger::Event)));
+        let triggers = stream::select(events, fallback.map(|_| Trigger::Resync)).boxed();
+
+        let state = State {
+            bmc: self.bmc.clone(),
+            collection: collection.clone(),
+            members,
+            triggers,
+            ready: VecDeque::new(),
+        };
+        Ok(Box::pin(stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(change) = state.ready.pop_front() {
+                    return Some((Ok(change), state));
+                }
+                match state.triggers.next().await? {
+                    Trigger::Event(payload) => {
+                        if state.apply_event(&payload) {
+                            if let Err(err) = state.resync().await {
+                                return Some((Err(err), state));
+                            }
+                        }
+                    }
+                    Trigger::Resync => {
+                        if let Err(err) = state.resync().await {
+                            return Some((Err(err), state));
+                        }
+                    }
+                }
+            }
+        })))
+    }
+}
+
+enum Trigger {
+    Event(JsonValue),
+    Resync,
+}
+
+struct State<B: Bmc, T: EntityTypeRef> {
+    bmc: NvBmc<B>,
+    collection: ODataId,
+    members: BTreeSet<ODataId>,
+    triggers: BoxStream<'static, Trigger>,
+    ready: VecDeque<MembershipChange<T>>,
+}
+
+impl<B: Bmc, T: EntityTypeRef> State<B, T> {
+    /// Apply records of the event payload. Returns `true` if the
+    /// collection needs to be re-read.
+    fn apply_event(&mut self, payload: &JsonValue) -> bool {
+        let collection = self.collection.to_string();
+        let mut resync = false;
+        for record in records(payload) {
+            match classify(record, &collection) {
+                Some(RecordKind::Added(id)) => self.add(id),
+                Some(RecordKind::Removed(id)) => self.remove(id),
+                Some(RecordKind::Collection) => resync = true,
+                None => {}
+            }
+        }
+        resync
+    }
+
+    /// Re-read the collection and report the difference with known
+    /// members.
+    async fn resync(&mut self) -> Result<(), Error<B>> {
+        let actual = fetch_member_ids(&self.bmc, &self.collection).await?;
+        let removed = self
+            .members
+            .iter()
+            .filter(|id| !actual.contains(id))
+            .cloned()
+            .collect::<Vec<_>>();
+        for id in removed {
+            self.remove(id);
+        }
+        for id in actual {
+            self.add(id);
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, id: ODataId) {
+        if !self.members.contains(&id) {
+            self.members.insert(id.clone());
+            self.ready
+                .push_back(MembershipChange::MemberAdded(NavProperty::new_reference(
+                    id,
+                )));
+        }
+    }
+
+    fn remove(&mut self, id: ODataId) {
+        if self.members.remove(&id) {
+            self.ready.push_back(MembershipChange::MemberRemoved(id));
+        }
+    }
+}
+
+/// Event records of the event payload.
+fn records(payload: &JsonValue) -> impl Iterator<Item = &JsonValue> {
+    payload
+        .get("Events")
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+}
+
+/// Event record relevant to the collection membership.
+#[derive(Debug, PartialEq, Eq)]
+enum RecordKind {
+    Added(ODataId),
+    Removed(ODataId),
+    /// Resource is created in or removed from the collection but
+    /// the member is not specified.
+    Collection,
+}
+
+fn classify(record: &JsonValue, collection: &str) -> Option<RecordKind> {
+    let message = record
+        .get("MessageId")
+        .and_then(JsonValue::as_str)
+        .and_then(|id| id.rsplit('.').next())?;
+    let is_added = MEMBER_ADDED_MESSAGES.contains(&message);
+    if !is_added && message != MEMBER_REMOVED_MESSAGE {
+        return None;
+    }
+    let origin = record
+        .get("OriginOfCondition")
+        .and_then(|origin| origin.get("@odata.id"))
+        .and_then(JsonValue::as_str)?
+        .trim_end_matches('/');
+    let collection = collection.trim_end_matches('/');
+    if origin == collection {
+        return Some(RecordKind::Collection);
+    }
+    let (parent, _) = origin.rsplit_once('/')?;
+    (parent == collection).then(|| {
+        let id = ODataId::from(origin.to_string());
+        if is_added {
+            RecordKind::Added(id)
+        } else {
+            RecordKind::Removed(id)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::classify;
+    use super::RecordKind;
+    use nv_redfish_core::ODataId;
+    use serde_json::json;
+
+    const ACCOUNTS: &str = "/redfish/v1/AccountService/Accounts";
+
+    fn record(message_id: &str, origin: &str) -> serde_json::Value {
+        json!({
+            "MessageId": message_id,
+            "OriginOfCondition": { "@odata.id": origin }
+        })
+    }
+
+    fn id(v: &str) -> ODataId {
+        ODataId::from(v.to_string())
+    }
+
+    #[test]
+    fn membership_records_are_classified() {
+        let member = "/redfish/v1/AccountService/Accounts/3";
+        assert_eq!(
+            classify(
+                &record("ResourceEvent.1.2.ResourceCreated", member),
+                ACCOUNTS
+            ),
+            Some(RecordKind::Added(id(member)))
+        );
+        assert_eq!(
+            classify(&record("ResourceEvent.1.0.ResourceAdded", member), ACCOUNTS),
+            Some(RecordKind::Added(id(member)))
+        );
+        assert_eq!(
+            classify(
+                &record("ResourceEvent.1.2.ResourceRemoved", member),
+                ACCOUNTS
+            ),
+            Some(RecordKind::Removed(id(member)))
+        );
+        assert_eq!(
+            classify(
+                &record("ResourceEvent.1.2.ResourceCreated", ACCOUNTS),
+                ACCOUNTS
+            ),
+            Some(RecordKind::Collection)
+        );
+    }
+
+    #[test]
+    fn unrelated_records_are_ignored() {
+        let member = "/redfish/v1/AccountService/Accounts/3";
+        assert_eq!(
+            classify(
+                &record("ResourceEvent.1.2.ResourceChanged", member),
+                ACCOUNTS
+            ),
+            None
+        );
+        assert_eq!(
+            classify(
+                &record(
+                    "ResourceEvent.1.2.ResourceCreated",
+                    "/redfish/v1/AccountService/Accounts/3/Keys/1"
+                ),
+                ACCOUNTS
+            ),
+            None
+        );
+        assert_eq!(
+            classify(
+                &json!({ "MessageId": "ResourceEvent.1.2.ResourceCreated" }),
+                ACCOUNTS
+            ),
+            None
+        );
+    }
+}
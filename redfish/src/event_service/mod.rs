@@ -16,33 +16,68 @@
 //! Event Service entities and helpers.
 //!
 //! This module provides typed access to Redfish `EventService`.
+//! [`EventService::membership_changes`] tracks members added to and
+//! removed from collections.
 
+mod delivery;
+mod membership;
 mod patch;
+mod subscription;
 
 use crate::patch_support::ReadPatchFn;
 use crate::schema::event_service::EventService as EventServiceSchema;
+use crate::schema::event_service::EventServiceSubmitTestEventAction;
+use crate::schema::resource::Health;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use crate::ServiceRoot;
+use futures_core::Stream;
 use futures_util::future;
+use futures_util::stream;
 use futures_util::TryStreamExt as _;
 use nv_redfish_core::odata::ODataType;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
 use serde::de;
 use serde::Deserialize;
 use serde::Deserializer;
 use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
+#[doc(inline)]
+pub use delivery::EventStreamOptions;
+#[doc(inline)]
+pub use membership::MembershipChange;
+#[doc(inline)]
+pub use subscription::DeliveryRetryPolicy;
+#[doc(inline)]
+pub use subscription::EventDestinationCreate;
+#[doc(inline)]
+pub use subscription::EventDestinationProtocol;
+#[doc(inline)]
+pub use subscription::EventSubscription;
+#[doc(inline)]
+pub use subscription::EventSubscriptionCollection;
+#[doc(inline)]
+pub use subscription::SubscriptionConfig;
+#[doc(inline)]
+pub use subscription::SubscriptionFilter;
+
 #[doc(inline)]
 pub use crate::schema::metric_report::MetricReport;
 
 #[doc(inline)]
 pub use crate::schema::event::Event;
 
+/// `MessageId` of events submitted by [`EventService::test_event`].
+pub const TEST_EVENT_MESSAGE_ID: &str = "Base.1.0.Success";
+
 /// SSE payload that can contain either an `EventRecord` or a `MetricReport`.
 #[derive(Debug)]
 pub enum EventStreamPayload {
@@ -62,6 +97,9 @@ impl<'de> Deserialize<'de> for EventStreamPayload {
             .ok_or_else(|| de::Error::missing_field("missing @odata.type in SSE payload"))?;
 
         if odata_type.type_name == "MetricReport" {
+            // Streamed metric reports are often shorter than the
+            // resource, so missing identity is restored first.
+            let value = patch::patch_metric_report_identity(value);
             let payload =
                 serde_json::from_value::<MetricReport>(value).map_err(de::Error::custom)?;
             Ok(Self::MetricReport(payload))
@@ -137,6 +175,120 @@ impl<B: Bmc> EventService<B> {
         self.data.clone()
     }
 
+    /// Get the collection of push subscriptions (`Subscriptions`).
+    ///
+    /// Returns `Ok(None)` when the event service does not expose the
+    /// collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the collection fails.
+    pub async fn subscriptions(&self) -> Result<Option<EventSubscriptionCollection<B>>, Error<B>> {
+        if let Some(collection_ref) = &self.data.subscriptions {
+            EventSubscriptionCollection::new(&self.bmc, collection_ref)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Submit a test event (`SubmitTestEvent` action).
+    ///
+    /// The service sends the event to all matching subscriptions, so
+    /// delivery can be validated end to end after subscriptions are
+    /// created. The event has [`TEST_EVENT_MESSAGE_ID`] message and
+    /// the event service as origin of condition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event service does not support the
+    /// `SubmitTestEvent` action or if invoking the action fails.
+    pub async fn test_event(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        self.run_submit_test_event(&EventServiceSubmitTestEventAction {
+            message: Some("Test event".into()),
+            ..self.test_event_params(TEST_EVENT_MESSAGE_ID.into(), None, None)
+        })
+        .await
+    }
+
+    /// Submit event with `message_id` (`SubmitTestEvent` action).
+    ///
+    /// Lets monitoring pipelines verify the alert path from the BMC
+    /// to the receiver on demand with messages they alert on.
+    /// `origin_of_condition` defaults to the event service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the event service does not support the
+    /// `SubmitTestEvent` action or if invoking the action fails.
+    pub async fn submit_test_event(
+        &self,
+        message_id: String,
+        origin_of_condition: Option<&ODataId>,
+        severity: Option<Health>,
+    ) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        self.run_submit_test_event(&self.test_event_params(
+            message_id,
+            origin_of_condition,
+            severity,
+        ))
+        .await
+    }
+
+    fn test_event_params(
+        &self,
+        message_id: String,
+        origin_of_condition: Option<&ODataId>,
+        severity: Option<Health>,
+    ) -> EventServiceSubmitTestEventAction {
+        EventServiceSubmitTestEventAction {
+            event_group_id: None,
+            event_id: None,
+            event_timestamp: None,
+            event_type: None,
+            message: None,
+            message_args: None,
+            message_id,
+            message_severity: severity,
+            origin_of_condition: Some(
+                origin_of_condition
+                    .unwrap_or_else(|| self.odata_id())
+                    .to_string(),
+            ),
+            severity: None,
+        }
+    }
+
+    async fn run_submit_test_event(
+        &self,
+        params: &EventServiceSubmitTestEventAction,
+    ) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let actions = self
+            .data
+            .actions
+            .as_ref()
+            .ok_or(Error::ActionNotAvailable)?;
+
+        if actions.submit_test_event.is_none() {
+            return Err(Error::ActionNotAvailable);
+        }
+
+        actions
+            .submit_test_event(self.bmc.as_ref(), params)
+            .await
+            .map_err(Error::Bmc)
+    }
+
     /// Open an SSE stream of Redfish event payloads.
     ///
     /// Payload kind is selected by `@odata.type`:
@@ -153,6 +305,86 @@ impl<B: Bmc> EventService<B> {
     where
         B: 'static,
         B::Error: 'static,
+    {
+        self.events_with_options(EventStreamOptions::default())
+            .await
+    }
+
+    /// Open an SSE stream of Redfish event payloads with de-duplication
+    /// and ordering configured by `options`.
+    ///
+    /// See [`EventStreamOptions`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::events`].
+    pub async fn events_with_options(
+        &self,
+        options: EventStreamOptions,
+    ) -> Result<BoxTryStream<EventStreamPayload, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+    {
+        self.events_with_ticks(options, stream::empty::<()>()).await
+    }
+
+    /// Open an SSE stream of Redfish event payloads with de-duplication
+    /// and ordering configured by `options`.
+    ///
+    /// Every item of `ticks` (for example, ticks of an interval timer)
+    /// releases metric reports that have waited in the ordering buffer
+    /// longer than `flush_after` of `options`, so reports are
+    /// delivered even when the stream is quiet.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::events`].
+    pub async fn events_with_ticks<S>(
+        &self,
+        options: EventStreamOptions,
+        ticks: S,
+    ) -> Result<BoxTryStream<EventStreamPayload, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+        S: Stream + Send + 'static,
+    {
+        let stream = self
+            .json_events_with_ticks(options, ticks)
+            .await?
+            .and_then(|patched| {
+                future::ready(
+                    serde_json::from_value::<EventStreamPayload>(patched).map_err(Error::Json),
+                )
+            });
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Open an SSE stream of patched, de-duplicated and ordered
+    /// payloads before they are deserialized.
+    pub(crate) async fn json_events(
+        &self,
+        options: EventStreamOptions,
+    ) -> Result<BoxTryStream<JsonValue, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+    {
+        self.json_events_with_ticks(options, stream::empty::<()>())
+            .await
+    }
+
+    async fn json_events_with_ticks<S>(
+        &self,
+        options: EventStreamOptions,
+        ticks: S,
+    ) -> Result<BoxTryStream<JsonValue, Error<B>>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+        S: Stream + Send + 'static,
     {
         let stream_uri = self
             .data
@@ -168,29 +400,37 @@ impl<B: Bmc> EventService<B> {
             .map_err(Error::Bmc)?;
 
         let sse_read_patches = self.sse_read_patches.clone();
-        let stream = stream.map_err(Error::Bmc).and_then(move |payload| {
-            let patched = sse_read_patches
+        let stream = stream.map_err(Error::Bmc).map_ok(move |payload| {
+            sse_read_patches
                 .iter()
-                .fold(payload, |acc, patch| patch(acc));
-
-            future::ready(
-                serde_json::from_value::<EventStreamPayload>(patched).map_err(Error::Json),
-            )
+                .fold(payload, |acc, patch| patch(acc))
         });
 
-        Ok(Box::pin(stream))
+        Ok(delivery::apply(Box::pin(stream), options, ticks))
     }
 }
 
 impl<B: Bmc> Resource for EventService<B> {
+    type Entity = EventServiceSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::EventStreamPayload;
+    use nv_redfish_core::EntityTypeRef as _;
 
     #[test]
     fn event_stream_payload_deserializes_event_record() {
@@ -261,4 +501,33 @@ mod tests {
             serde_json::from_value(value).expect("metric report payload must deserialize");
         assert!(matches!(payload, EventStreamPayload::MetricReport(_)));
     }
+
+    #[test]
+    fn event_stream_payload_tolerates_metric_report_without_identity() {
+        let value = serde_json::json!({
+            "@odata.type": "#MetricReport.v1_3_0.MetricReport",
+            "Id": "AvgPlatformPowerUsage",
+            "MetricValues": [
+                {
+                    "MetricId": "AverageConsumedWatts",
+                    "MetricValue": "100",
+                    "Timestamp": "2016-11-08T12:25:00-05:00",
+                    "MetricProperty": "/redfish/v1/Chassis/Tray_1/Power#/0/PowerConsumedWatts"
+                }
+            ]
+        });
+
+        let payload: EventStreamPayload =
+            serde_json::from_value(value).expect("metric report payload must deserialize");
+        let report = match payload {
+            EventStreamPayload::MetricReport(report) => Some(report),
+            EventStreamPayload::Event(_) => None,
+        }
+        .expect("metric report payload expected");
+        assert_eq!(report.base.name, "AvgPlatformPowerUsage");
+        assert_eq!(
+            report.odata_id().to_string(),
+            "/redfish/v1/TelemetryService/MetricReports/AvgPlatformPowerUsage"
+        );
+    }
 }
@@ -22,6 +22,7 @@ use serde_json::map::Map as JsonMap;
 use serde_json::Value as JsonValue;
 
 const SSE_EVENT_BASE_ID: &str = "/redfish/v1/EventService/SSE";
+const METRIC_REPORTS_BASE_ID: &str = "/redfish/v1/TelemetryService/MetricReports";
 
 pub(super) type EventRecordPatchFn = fn(&mut JsonMap<String, JsonValue>, usize);
 
@@ -41,6 +42,50 @@ pub(super) fn patch_missing_event_odata_id(mut value: JsonValue) -> JsonValue {
     value
 }
 
+/// Metric reports delivered in SSE payloads sometimes omit
+/// `@odata.id`, `Id` or `Name` that are required for the resource.
+/// Identifier is restored from any available identity of the report:
+/// `Id`, `@odata.id` or `MetricReportDefinition` link.
+pub(super) fn patch_metric_report_identity(mut value: JsonValue) -> JsonValue {
+    let Some(payload) = value.as_object_mut() else {
+        return value;
+    };
+
+    let last_segment = |v: Option<&JsonValue>| {
+        v.and_then(JsonValue::as_str)
+            .and_then(|id| id.trim_end_matches('/').rsplit('/').next())
+            .filter(|id| !id.is_empty())
+            .map(ToOwned::to_owned)
+    };
+    let Some(id) = payload
+        .get("Id")
+        .and_then(JsonValue::as_str)
+        .map(ToOwned::to_owned)
+        .or_else(|| last_segment(payload.get("@odata.id")))
+        .or_else(|| {
+            last_segment(
+                payload
+                    .get("MetricReportDefinition")
+                    .and_then(|v| v.get("@odata.id")),
+            )
+        })
+    else {
+        return value;
+    };
+
+    if !payload.contains_key("@odata.id") {
+        let generated_id = format!("{METRIC_REPORTS_BASE_ID}/{id}");
+        payload.insert("@odata.id".to_string(), JsonValue::String(generated_id));
+    }
+    if !payload.contains_key("Name") {
+        payload.insert("Name".to_string(), JsonValue::String(id.clone()));
+    }
+    payload
+        .entry("Id".to_string())
+        .or_insert(JsonValue::String(id));
+    value
+}
+
 pub(super) fn patch_event_records(
     mut value: JsonValue,
     patches: &[EventRecordPatchFn],
@@ -146,6 +191,7 @@ fn fix_timestamp_offset(input: &str) -> Option<String> {
 mod tests {
     use super::fix_timestamp_offset;
     use super::patch_event_records;
+    use super::patch_metric_report_identity;
     use super::patch_missing_event_record_member_id;
     use super::patch_missing_event_type_to_unsupported;
     use super::EventRecordPatchFn;
@@ -223,4 +269,29 @@ mod tests {
             .and_then(serde_json::Value::as_str);
         assert_eq!(member_id, Some("88"));
     }
+
+    #[test]
+    fn restores_metric_report_identity_from_definition() {
+        let payload = patch_metric_report_identity(json!({
+            "@odata.type": "#MetricReport.v1_3_0.MetricReport",
+            "MetricReportDefinition": {
+                "@odata.id": "/redfish/v1/TelemetryService/MetricReportDefinitions/PlatformPower"
+            },
+            "MetricValues": []
+        }));
+
+        assert_eq!(
+            payload,
+            json!({
+                "@odata.id": "/redfish/v1/TelemetryService/MetricReports/PlatformPower",
+                "@odata.type": "#MetricReport.v1_3_0.MetricReport",
+                "Id": "PlatformPower",
+                "Name": "PlatformPower",
+                "MetricReportDefinition": {
+                    "@odata.id": "/redfish/v1/TelemetryService/MetricReportDefinitions/PlatformPower"
+                },
+                "MetricValues": []
+            })
+        );
+    }
 }
@@ -0,0 +1,397 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Push subscriptions of the event service (`EventDestination`).
+
+use crate::schema::event_destination::EventDestination as EventDestinationSchema;
+use crate::schema::event_destination_collection::EventDestinationCollection as EventDestinationCollectionSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::ReferenceLeaf;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[doc(inline)]
+pub use crate::schema::event_destination::DeliveryRetryPolicy;
+#[doc(inline)]
+pub use crate::schema::event_destination::EventDestinationCreate;
+#[doc(inline)]
+pub use crate::schema::event_destination::EventDestinationProtocol;
+
+/// Filter of events that are sent to a push subscription.
+///
+/// Empty filter subscribes to all events. Each configured property
+/// narrows the events: for example, only events of the `ResourceEvent`
+/// registry originating from the given resources.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SubscriptionFilter {
+    #[serde(rename = "RegistryPrefixes", skip_serializing_if = "Vec::is_empty")]
+    registry_prefixes: Vec<String>,
+    #[serde(rename = "MessageIds", skip_serializing_if = "Vec::is_empty")]
+    message_ids: Vec<String>,
+    #[serde(rename = "ResourceTypes", skip_serializing_if = "Vec::is_empty")]
+    resource_types: Vec<String>,
+    #[serde(rename = "OriginResources", skip_serializing_if = "Vec::is_empty")]
+    origin_resources: Vec<ReferenceLeaf>,
+    #[serde(
+        rename = "SubordinateResources",
+        skip_serializing_if = "Option::is_none"
+    )]
+    subordinate_resources: Option<bool>,
+}
+
+impl SubscriptionFilter {
+    /// Create filter that subscribes to all events.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send events of messages of the registry with `prefix` (for
+    /// example, `ResourceEvent`).
+    #[must_use]
+    pub fn registry_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.registry_prefixes.push(prefix.into());
+        self
+    }
+
+    /// Send events with `message_id` (for example,
+    /// `ResourceEvent.1.0.ResourceCreated`).
+    #[must_use]
+    pub fn message_id(mut self, message_id: impl Into<String>) -> Self {
+        self.message_ids.push(message_id.into());
+        self
+    }
+
+    /// Send events about resources of `resource_type` (for example,
+    /// `Chassis`).
+    #[must_use]
+    pub fn resource_type(mut self, resource_type: impl Into<String>) -> Self {
+        self.resource_types.push(resource_type.into());
+        self
+    }
+
+    /// Send events originating from the resource `odata_id`.
+    #[must_use]
+    pub fn origin_resource(mut self, odata_id: ODataId) -> Self {
+        self.origin_resources.push(ReferenceLeaf { odata_id });
+        self
+    }
+
+    /// Also send events originating from subordinate resources of
+    /// origin resources.
+    #[must_use]
+    pub const fn subordinate_resources(mut self, subordinate: bool) -> Self {
+        self.subordinate_resources = Some(subordinate);
+        self
+    }
+}
+
+/// Configuration of a new push subscription.
+///
+/// Unlike [`EventDestinationCreate`], covers only the delivery
+/// settings that are needed to keep a subscription healthy: retry
+/// policy, heartbeat and certificate verification. Settings that are
+/// not configured are not sent, so the service applies its defaults.
+#[derive(Debug, Clone, Serialize)]
+pub struct SubscriptionConfig {
+    #[serde(rename = "Destination")]
+    destination: String,
+    #[serde(rename = "Protocol")]
+    protocol: EventDestinationProtocol,
+    #[serde(rename = "Context", skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+    #[serde(
+        rename = "DeliveryRetryPolicy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    delivery_retry_policy: Option<DeliveryRetryPolicy>,
+    #[serde(rename = "SendHeartbeat", skip_serializing_if = "Option::is_none")]
+    send_heartbeat: Option<bool>,
+    #[serde(
+        rename = "HeartbeatIntervalMinutes",
+        skip_serializing_if = "Option::is_none"
+    )]
+    heartbeat_interval_minutes: Option<u64>,
+    #[serde(rename = "VerifyCertificate", skip_serializing_if = "Option::is_none")]
+    verify_certificate: Option<bool>,
+    #[serde(flatten)]
+    filter: SubscriptionFilter,
+}
+
+impl SubscriptionConfig {
+    /// Create configuration of subscription that sends events to
+    /// `destination` using `protocol`.
+    #[must_use]
+    pub fn new(destination: impl Into<String>, protocol: EventDestinationProtocol) -> Self {
+        Self {
+            destination: destination.into(),
+            protocol,
+            context: None,
+            delivery_retry_policy: None,
+            send_heartbeat: None,
+            heartbeat_interval_minutes: None,
+            verify_certificate: None,
+            filter: SubscriptionFilter::new(),
+        }
+    }
+
+    /// Client-supplied context that is sent with events.
+    #[must_use]
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    /// Behavior of the service when delivery of an event fails.
+    #[must_use]
+    pub const fn delivery_retry_policy(mut self, policy: DeliveryRetryPolicy) -> Self {
+        self.delivery_retry_policy = Some(policy);
+        self
+    }
+
+    /// Send heartbeat events with `interval`, so the receiver can
+    /// detect that the subscription stopped delivering events.
+    /// Redfish specifies the interval in whole minutes: `interval` is
+    /// rounded up and is at least one minute.
+    #[must_use]
+    pub const fn heartbeat(mut self, interval: Duration) -> Self {
+        let minutes = interval.as_secs().div_ceil(60);
+        self.send_heartbeat = Some(true);
+        self.heartbeat_interval_minutes = Some(if minutes == 0 { 1 } else { minutes });
+        self
+    }
+
+    /// Verify certificate of the destination when events are sent
+    /// over TLS.
+    #[must_use]
+    pub const fn verify_certificate(mut self, verify: bool) -> Self {
+        self.verify_certificate = Some(verify);
+        self
+    }
+
+    /// Send only events that match `filter`.
+    #[must_use]
+    pub fn filter(mut self, filter: SubscriptionFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+/// Collection of push subscriptions of the event service.
+pub struct EventSubscriptionCollection<B: Bmc> {
+    bmc: NvBmc<B>,
+    collection: Arc<EventDestinationCollectionSchema>,
+}
+
+impl<B: Bmc> EventSubscriptionCollection<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<EventDestinationCollectionSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|collection| Self {
+                bmc: bmc.clone(),
+                collection,
+            })
+    }
+
+    /// `OData` identifier of the subscriptions collection.
+    ///
+    /// Typically `/redfish/v1/EventService/Subscriptions`.
+    #[must_use]
+    pub fn odata_id(&self) -> &ODataId {
+        self.collection.as_ref().odata_id()
+    }
+
+    /// Get subscriptions of the collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching any subscription fails.
+    pub async fn members(&self) -> Result<Vec<EventSubscription<B>>, Error<B>> {
+        let mut members = Vec::with_capacity(self.collection.members.len());
+        for m in &self.collection.members {
+            members.push(EventSubscription::new(&self.bmc, m).await?);
+        }
+        Ok(members)
+    }
+
+    /// Create a new subscription.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the created subscription.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating the subscription fails.
+    pub async fn create(
+        &self,
+        create: &EventDestinationCreate,
+    ) -> Result<ModificationResponse<EventSubscription<B>>, Error<B>> {
+        self.create_with(create).await
+    }
+
+    /// Create a new subscription with delivery settings of `config`.
+    ///
+    /// Outcomes are the same as of [`Self::create`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating the subscription fails.
+    pub async fn subscribe(
+        &self,
+        config: &SubscriptionConfig,
+    ) -> Result<ModificationResponse<EventSubscription<B>>, Error<B>> {
+        self.create_with(config).await
+    }
+
+    /// Register receiver at `destination` for events that match
+    /// `filter`.
+    ///
+    /// Shortcut of [`Self::subscribe`] for subscriptions with default
+    /// delivery settings. Outcomes are the same as of [`Self::create`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating the subscription fails.
+    pub async fn create_subscription(
+        &self,
+        destination: impl Into<String> + Send,
+        protocol: EventDestinationProtocol,
+        filter: SubscriptionFilter,
+    ) -> Result<ModificationResponse<EventSubscription<B>>, Error<B>> {
+        self.subscribe(&SubscriptionConfig::new(destination, protocol).filter(filter))
+            .await
+    }
+
+    pub(crate) async fn create_with<T: Serialize + Sync + Send>(
+        &self,
+        create: &T,
+    ) -> Result<ModificationResponse<EventSubscription<B>>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .create::<_, NavProperty<EventDestinationSchema>>(self.odata_id(), create)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(
+                |nav| async move { EventSubscription::new(&self.bmc, &nav).await },
+            )
+            .await
+    }
+}
+
+/// Push subscription of the event service.
+pub struct EventSubscription<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<EventDestinationSchema>,
+}
+
+impl<B: Bmc> EventSubscription<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<EventDestinationSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+            })
+    }
+
+    /// Get the raw schema data for this subscription.
+    #[must_use]
+    pub fn raw(&self) -> Arc<EventDestinationSchema> {
+        self.data.clone()
+    }
+
+    /// URI of the event receiver.
+    #[must_use]
+    pub fn destination(&self) -> Option<&str> {
+        self.data.destination.as_deref()
+    }
+
+    /// Protocol used to send events to the destination.
+    #[must_use]
+    pub fn protocol(&self) -> Option<EventDestinationProtocol> {
+        self.data.protocol
+    }
+
+    /// Client-supplied context of the subscription that is sent
+    /// with events.
+    #[must_use]
+    pub fn context(&self) -> Option<&str> {
+        self.data.context.as_deref()
+    }
+
+    /// Delete the subscription, so the service stops sending events
+    /// to the destination.
+    ///
+    /// Returns one of the following modification outcomes:
+    ///
+    /// - `ModificationResponse::Entity` contains the subscription
+    ///   returned by the server.
+    /// - `ModificationResponse::Task` identifies an asynchronous operation.
+    /// - `ModificationResponse::Empty` reports synchronous success without a
+    ///   response body.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if deletion fails.
+    pub async fn delete(&self) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .delete::<NavProperty<EventDestinationSchema>>(self.data.odata_id())
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await
+    }
+}
+
+impl<B: Bmc> Resource for EventSubscription<B> {
+    type Entity = EventDestinationSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
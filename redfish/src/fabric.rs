@@ -0,0 +1,213 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fabrics and their switches.
+//!
+//! This module provides typed access to Redfish `Fabrics` collection
+//! and to switches of each fabric. For example, NVSwitch devices of
+//! HGX baseboards are published as switches of the NVLink fabric.
+
+use crate::core::NavProperty;
+use crate::schema::fabric::Fabric as FabricSchema;
+use crate::schema::fabric_collection::FabricCollection as FabricCollectionSchema;
+use crate::schema::switch::Switch as SwitchSchema;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use crate::ServiceRoot;
+use nv_redfish_core::Bmc;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+#[cfg(feature = "oem-nvidia-hgx")]
+use crate::oem::nvidia::hgx::NvidiaSwitch;
+
+#[doc(inline)]
+pub use crate::schema::protocol::Protocol;
+
+/// Fabric collection.
+///
+/// Provides functions to access `Fabrics` members.
+pub struct FabricCollection<B: Bmc> {
+    bmc: NvBmc<B>,
+    collection: Arc<FabricCollectionSchema>,
+}
+
+impl<B: Bmc> FabricCollection<B> {
+    /// Create a new fabric collection handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        root: &ServiceRoot<B>,
+    ) -> Result<Option<Self>, Error<B>> {
+        let Some(nav) = &root.root.fabrics else {
+            return Ok(None);
+        };
+
+        let collection = bmc.expand_property(nav).await?;
+
+        Ok(Some(Self {
+            bmc: bmc.clone(),
+            collection,
+        }))
+    }
+
+    /// List all fabrics available in this collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching fabric data fails.
+    pub async fn members(&self) -> Result<Vec<Fabric<B>>, Error<B>> {
+        let mut members = Vec::with_capacity(self.collection.members.len());
+        for member in &self.collection.members {
+            members.push(Fabric::new(&self.bmc, member).await?);
+        }
+
+        Ok(members)
+    }
+}
+
+/// Fabric.
+///
+/// Provides access to fabric information and its switches.
+pub struct Fabric<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<FabricSchema>,
+}
+
+impl<B: Bmc> Fabric<B> {
+    async fn new(bmc: &NvBmc<B>, nav: &NavProperty<FabricSchema>) -> Result<Self, Error<B>> {
+        let data = nav.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+        Ok(Self {
+            bmc: bmc.clone(),
+            data,
+        })
+    }
+
+    /// Get the raw schema data for this fabric.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<FabricSchema> {
+        self.data.clone()
+    }
+
+    /// Protocol of the fabric.
+    #[must_use]
+    pub fn fabric_type(&self) -> Option<Protocol> {
+        self.data.fabric_type.flatten()
+    }
+
+    /// Get switches of this fabric.
+    ///
+    /// Returns `Ok(None)` when the fabric does not expose `Switches`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching switch data fails.
+    pub async fn switches(&self) -> Result<Option<Vec<Switch<B>>>, Error<B>> {
+        let Some(switches_ref) = &self.data.switches else {
+            return Ok(None);
+        };
+
+        let switches_collection = self.bmc.expand_property(switches_ref).await?;
+        let mut switches = Vec::with_capacity(switches_collection.members.len());
+        for m in &switches_collection.members {
+            switches.push(Switch::new(&self.bmc, m).await?);
+        }
+
+        Ok(Some(switches))
+    }
+}
+
+impl<B: Bmc> Resource for Fabric<B> {
+    type Entity = FabricSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+/// Switch of a fabric.
+pub struct Switch<B: Bmc> {
+    data: Arc<SwitchSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> Switch<B> {
+    async fn new(bmc: &NvBmc<B>, nav: &NavProperty<SwitchSchema>) -> Result<Self, Error<B>> {
+        let data = nav.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+        Ok(Self {
+            data,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Get the raw schema data for this switch.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<SwitchSchema> {
+        self.data.clone()
+    }
+
+    /// Protocol of the switch.
+    #[must_use]
+    pub fn switch_type(&self) -> Option<Protocol> {
+        self.data.switch_type.flatten()
+    }
+
+    /// NVIDIA HGX NVSwitch OEM extension
+    ///
+    /// Returns `Ok(None)` when the switch does not include NVIDIA NVSwitch OEM extension data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if NVIDIA OEM data parsing fails.
+    #[cfg(feature = "oem-nvidia-hgx")]
+    pub fn oem_nvidia_hgx_switch(&self) -> Result<Option<NvidiaSwitch<B>>, Error<B>> {
+        NvidiaSwitch::new(&self.data)
+    }
+}
+
+impl<B: Bmc> Resource for Switch<B> {
+    type Entity = SwitchSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_util::stream;
+use futures_util::StreamExt as _;
+use futures_util::TryStreamExt as _;
+use std::future::Future;
+
+/// Number of requests that nv-redfish sends concurrently when it
+/// fetches members of a collection or sub-resources of an entity.
+///
+/// Members are fetched one by one unless configured otherwise with
+/// [`crate::NvBmcBuilder::fan_out_limit`]: some BMCs fail when they
+/// receive concurrent requests.
+pub const DEFAULT_FAN_OUT_LIMIT: usize = 1;
+
+/// Run `f` for every item of `items` with at most `limit` futures in
+/// flight and collect results in the order of `items`.
+///
+/// Order of results does not depend on the order in which the
+/// futures complete. The first error is returned and the futures
+/// that are still in flight are dropped. `limit` of zero is treated
+/// as one.
+///
+/// # Errors
+///
+/// Returns the first error returned by `f`.
+pub async fn try_fan_out<I, F, Fut, T, E>(limit: usize, items: I, f: F) -> Result<Vec<T>, E>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    stream::iter(items)
+        .map(f)
+        .buffered(limit.max(1))
+        .try_collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_channel::oneshot;
+    use futures_util::task::noop_waker_ref;
+    use std::cell::Cell;
+    use std::pin::pin;
+    use std::task::Context;
+    use std::task::Poll;
+
+    #[test]
+    fn results_follow_input_order() {
+        let started = Cell::new(0);
+        let (senders, receivers): (Vec<_>, Vec<_>) =
+            (0..4).map(|_| oneshot::channel::<usize>()).unzip();
+        let mut fan_out = pin!(try_fan_out(2, receivers, |receiver| {
+            started.set(started.get() + 1);
+            receiver
+        }));
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        assert!(fan_out.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(started.get(), 2);
+
+        // Complete requests in reverse order: results are held until
+        // the first request completes and no more requests start.
+        for (value, sender) in senders.into_iter().enumerate().rev() {
+            sender.send(value * 10).expect("receiver is alive");
+            let poll = fan_out.as_mut().poll(&mut cx);
+            if value == 0 {
+                assert_eq!(poll, Poll::Ready(Ok(vec![0, 10, 20, 30])));
+            } else {
+                assert!(poll.is_pending());
+                assert_eq!(started.get(), 2);
+            }
+        }
+    }
+
+    #[test]
+    fn first_error_is_returned() {
+        let mut fan_out = pin!(try_fan_out(0, 0..4, |i| async move {
+            if i == 2 {
+                Err(i)
+            } else {
+                Ok(i)
+            }
+        }));
+        let mut cx = Context::from_waker(noop_waker_ref());
+
+        assert_eq!(fan_out.as_mut().poll(&mut cx), Poll::Ready(Err(2)));
+    }
+}
@@ -15,6 +15,7 @@
 
 use std::marker::PhantomData;
 
+use nv_redfish_core::ODataId;
 use tagged_types::TaggedType;
 
 /// Type for hardware manufacturers.
@@ -141,3 +142,66 @@ impl<Tag> HardwareIdRef<'_, Tag> {
         }
     }
 }
+
+impl<Tag> HardwareIdRef<'_, Tag> {
+    /// Transform to owned `HardwareId` tagged by another module.
+    pub(crate) fn retagged<NewTag>(&self) -> HardwareId<NewTag> {
+        HardwareId {
+            manufacturer: self
+                .manufacturer
+                .map(|v| Manufacturer::new(v.into_inner().to_owned())),
+            model: self.model.map(|v| Model::new(v.into_inner().to_owned())),
+            part_number: self
+                .part_number
+                .map(|v| PartNumber::new(v.into_inner().to_owned())),
+            serial_number: self
+                .serial_number
+                .map(|v| SerialNumber::new(v.into_inner().to_owned())),
+        }
+    }
+}
+
+/// Kind of hardware in the hardware inventory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HardwareKind {
+    /// Chassis.
+    Chassis,
+    /// Computer system.
+    ComputerSystem,
+    /// Drive attached to storage of the computer system.
+    Drive,
+    /// Memory module of the computer system.
+    Memory,
+    /// Network adapter of the chassis.
+    NetworkAdapter,
+    /// Processor of the computer system.
+    Processor,
+}
+
+#[doc(hidden)]
+pub enum HardwareInventoryTag {}
+
+/// Single entry of the flat hardware inventory.
+#[derive(Clone)]
+pub struct HardwareInventoryItem {
+    /// Kind of the hardware.
+    pub kind: HardwareKind,
+    /// Identifier of the resource that describes the hardware.
+    pub odata_id: ODataId,
+    /// Hardware identifier.
+    pub hardware_id: HardwareId<HardwareInventoryTag>,
+}
+
+impl HardwareInventoryItem {
+    pub(crate) fn new<Tag>(
+        kind: HardwareKind,
+        odata_id: &ODataId,
+        hardware_id: HardwareIdRef<'_, Tag>,
+    ) -> Self {
+        Self {
+            kind,
+            odata_id: odata_id.clone(),
+            hardware_id: hardware_id.retagged(),
+        }
+    }
+}
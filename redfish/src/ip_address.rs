@@ -0,0 +1,167 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::error::Error as StdError;
+use std::fmt;
+use std::net::IpAddr;
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+
+/// IP address returned by the crate.
+///
+/// Holds the value exactly as reported by the server. Use
+/// [`IpAddress::parse`] to get validated [`IpAddr`].
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct IpAddress<'a>(&'a str);
+
+impl IpAddress<'_> {
+    /// Create new IP address.
+    #[must_use]
+    pub const fn new(v: &str) -> IpAddress<'_> {
+        IpAddress(v)
+    }
+
+    /// String representation of IP address.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.0
+    }
+
+    /// Parse reported value into [`IpAddr`].
+    ///
+    /// Besides standard notation, parsing tolerates formatting found
+    /// in BMC responses: surrounding whitespace, zero padded IPv4
+    /// octets (`010.000.001.002`), bracketed IPv6 addresses, IPv6
+    /// zone index (`fe80::1%eth0`) and prefix length suffix
+    /// (`10.0.1.2/24`). Zone index and prefix length are dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not an IP address.
+    pub fn parse(&self) -> Result<IpAddr, IpAddrParseError> {
+        let error = || IpAddrParseError {
+            value: self.0.to_owned(),
+        };
+        let value = self.0.trim();
+        let value = match value.rsplit_once('/') {
+            Some((address, prefix))
+                if !prefix.is_empty() && prefix.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                address
+            }
+            Some(_) => return Err(error()),
+            None => value,
+        };
+        let value = value
+            .strip_prefix('[')
+            .and_then(|v| v.strip_suffix(']'))
+            .unwrap_or(value);
+        if value.contains(':') {
+            let address = value.split_once('%').map_or(value, |(address, _)| address);
+            address
+                .parse::<Ipv6Addr>()
+                .map(IpAddr::V6)
+                .map_err(|_| error())
+        } else {
+            parse_ipv4(value).map(IpAddr::V4).ok_or_else(error)
+        }
+    }
+}
+
+impl fmt::Display for IpAddress<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Parse reported IP address `value`. Blank value means that the
+/// address is not reported.
+pub(crate) fn parse_reported(value: &str) -> Option<Result<IpAddr, IpAddrParseError>> {
+    (!value.trim().is_empty()).then(|| IpAddress::new(value).parse())
+}
+
+/// Error returned when a value is not a valid IP address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpAddrParseError {
+    /// Rejected value.
+    pub value: String,
+}
+
+impl fmt::Display for IpAddrParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid IP address: {:?}", self.value)
+    }
+}
+
+impl StdError for IpAddrParseError {}
+
+/// Dotted decimal IPv4 address. Unlike `Ipv4Addr::from_str` accepts
+/// zero padded octets.
+fn parse_ipv4(value: &str) -> Option<Ipv4Addr> {
+    let mut octets = [0; 4];
+    let mut parts = value.split('.');
+    for octet in &mut octets {
+        let part = parts.next()?;
+        if part.is_empty() || part.len() > 3 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        *octet = part.parse().ok()?;
+    }
+    parts.next().is_none().then_some(Ipv4Addr::from(octets))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IpAddress;
+    use std::net::IpAddr;
+    use std::net::Ipv4Addr;
+    use std::net::Ipv6Addr;
+
+    #[test]
+    fn vendor_formats_are_accepted() {
+        let v4 = IpAddr::V4(Ipv4Addr::new(10, 0, 1, 2));
+        for value in ["10.0.1.2", " 10.0.1.2 ", "010.000.001.002", "10.0.1.2/24"] {
+            assert_eq!(IpAddress::new(value).parse(), Ok(v4));
+        }
+        let v6 = IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+        for value in [
+            "fe80::1",
+            "FE80::0001",
+            "[fe80::1]",
+            "fe80::1%eth0",
+            "fe80::1/64",
+        ] {
+            assert_eq!(IpAddress::new(value).parse(), Ok(v6));
+        }
+    }
+
+    #[test]
+    fn malformed_values_are_rejected() {
+        for value in [
+            "",
+            "10.0.1",
+            "10.0.1.2.3",
+            "10.0.1.256",
+            "10.0.1.0002",
+            "10.0.-1.2",
+            "10.0.1.2/",
+            "10.0.1.2/x",
+            "fe80:::1",
+            "[fe80::1",
+        ] {
+            assert!(IpAddress::new(value).parse().is_err(), "{:?}", value);
+        }
+    }
+}
@@ -82,15 +82,51 @@ pub mod resource;
 /// Number).
 pub mod hardware_id;
 
+/// Stable identifiers of resources across reboots and firmware
+/// updates.
+pub mod durable_id;
+
 /// MAC addresses returned by the crate.
 pub mod mac_address;
 
+/// IP addresses returned by the crate.
+pub mod ip_address;
+
+/// Bounded concurrent fetching of collection members and
+/// sub-resources.
+pub mod fan_out;
+
+/// Incremental sync of collection members by their ETags.
+pub mod member_sync;
+
+/// Message registries and resolution of messages.
+pub mod registries;
+
+/// BMC wrapper that avoids operations not supported by the endpoint.
+pub mod probing;
+
+/// BMC wrapper that rejects modifications.
+pub mod read_only;
+
+/// Detection of Redfish service restarts.
+pub mod service_restart;
+
+/// Service root shared between tasks and refreshed in place.
+pub mod shared_root;
+
+/// Cooperative cancellation of long-running operations.
+#[cfg(feature = "cancellation")]
+pub mod cancellation;
+
 /// Accounts Service.
 #[cfg(feature = "accounts")]
 pub mod account;
 /// Chassis.
 #[cfg(feature = "chassis")]
 pub mod chassis;
+/// Component integrity (SPDM/TPM attestation).
+#[cfg(feature = "component-integrity")]
+pub mod component_integrity;
 /// Computer System.
 #[cfg(feature = "computer-systems")]
 pub mod computer_system;
@@ -112,9 +148,15 @@ pub mod ethernet_interface;
 /// Event Service.
 #[cfg(feature = "event-service")]
 pub mod event_service;
+/// Fabrics and switches.
+#[cfg(feature = "fabrics")]
+pub mod fabric;
 /// Host interfaces.
 #[cfg(feature = "host-interfaces")]
 pub mod host_interface;
+/// License Service.
+#[cfg(feature = "license-service")]
+pub mod license_service;
 /// Log Service.
 #[cfg(feature = "log-services")]
 pub mod log_service;
@@ -139,6 +181,22 @@ pub mod task_service;
 #[cfg(feature = "telemetry-service")]
 pub mod telemetry_service;
 
+/// Configuration backup and restore.
+#[cfg(feature = "config-backup")]
+pub mod config_backup;
+
+/// Per-port summary of network interface controllers.
+#[cfg(feature = "nic-summary")]
+pub mod nic_summary;
+
+/// Out-of-box provisioning.
+#[cfg(feature = "provision")]
+pub mod provision;
+
+/// Orchestration of multi-step updates.
+#[cfg(feature = "update-plan")]
+pub mod update_plan;
+
 /// Individual OEM extensions support.
 #[cfg(feature = "oem")]
 pub mod oem;
@@ -153,7 +211,7 @@ pub(crate) mod patch_support;
 pub mod entity_link;
 
 /// Redfish protocol features.
-pub(crate) mod protocol_features;
+pub mod protocol_features;
 
 /// Bmc wrapper used in nv-redfish.
 pub(crate) mod bmc;
@@ -161,9 +219,24 @@ pub(crate) mod bmc;
 /// BMC quirks support.
 pub(crate) mod bmc_quirks;
 
+/// Diagnostics of payloads of types that are not compiled.
+pub(crate) mod schema_types;
+
+/// Stubs of accessors for disabled features.
+#[cfg(feature = "stubs")]
+mod stubs;
+
+/// Environment metrics helpers.
+#[cfg(feature = "environment-metrics")]
+pub(crate) mod environment_metrics;
+
 #[doc(inline)]
 pub use nv_redfish_core as core;
 
+/// OData query builders (`$expand`, `$filter`).
+#[doc(inline)]
+pub use nv_redfish_core::query;
+
 #[cfg(feature = "bmc-http")]
 #[doc(inline)]
 pub use nv_redfish_bmc_http as bmc_http;
@@ -171,23 +244,34 @@ pub use nv_redfish_bmc_http as bmc_http;
 #[doc(inline)]
 pub use compiled_schema::redfish as schema;
 
+#[doc(inline)]
+pub use bmc::NvBmc;
+#[doc(inline)]
+pub use bmc::NvBmcBuilder;
+#[doc(inline)]
+pub use bmc_quirks::BmcQuirks;
 #[doc(inline)]
 pub use error::Error;
 #[doc(inline)]
 pub use nv_redfish_core::Bmc;
 #[doc(inline)]
+pub use probing::ProbingBmc;
+#[doc(inline)]
+pub use probing::ProbingError;
+#[doc(inline)]
 pub use protocol_features::ProtocolFeatures;
 #[doc(inline)]
+pub use read_only::ReadOnlyBmc;
+#[doc(inline)]
+pub use read_only::ReadOnlyError;
+#[doc(inline)]
 pub use resource::Resource;
 #[doc(inline)]
 pub use service_root::ServiceRoot;
-
 #[doc(inline)]
-#[cfg(feature = "resource-status")]
-pub use resource::ResourceProvidesStatus;
+pub use shared_root::SharedServiceRoot;
 
 #[cfg(feature = "resource-status")]
 pub(crate) use crate::schema::resource::Status as ResourceStatusSchema;
 
 pub(crate) use crate::schema::resource::Resource as ResourceSchema;
-pub(crate) use bmc::NvBmc;
@@ -0,0 +1,173 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! License Service entities and helpers.
+//!
+//! This module provides typed access to Redfish `LicenseService` and
+//! licenses installed on the service.
+
+use crate::schema::license::License as LicenseSchema;
+use crate::schema::license_service::LicenseService as LicenseServiceSchema;
+use crate::schema::resource::State;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use crate::ServiceRoot;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// License service.
+///
+/// Provides access to licenses installed on the service.
+pub struct LicenseService<B: Bmc> {
+    data: Arc<LicenseServiceSchema>,
+    bmc: NvBmc<B>,
+}
+
+impl<B: Bmc> LicenseService<B> {
+    /// Create a new license service handle.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        root: &ServiceRoot<B>,
+    ) -> Result<Option<Self>, Error<B>> {
+        if let Some(service_ref) = &root.root.license_service {
+            let data = service_ref.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+            Ok(Some(Self {
+                data,
+                bmc: bmc.clone(),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Get the raw schema data for this license service.
+    #[must_use]
+    pub fn raw(&self) -> Arc<LicenseServiceSchema> {
+        self.data.clone()
+    }
+
+    /// Get `Vec<License>` installed on the service.
+    ///
+    /// Returns `Ok(None)` when the service does not expose a
+    /// `Licenses` collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving the collection or its members
+    /// fails.
+    pub async fn licenses(&self) -> Result<Option<Vec<License<B>>>, Error<B>> {
+        if let Some(collection_ref) = &self.data.licenses {
+            let collection = self.bmc.expand_property(collection_ref).await?;
+
+            let mut items = Vec::with_capacity(collection.members.len());
+            for m in &collection.members {
+                items.push(License::new(&self.bmc, m).await?);
+            }
+
+            Ok(Some(items))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl<B: Bmc> Resource for LicenseService<B> {
+    type Entity = LicenseServiceSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+}
+
+/// License installed on the service.
+pub struct License<B: Bmc> {
+    data: Arc<LicenseSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> License<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<LicenseSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                data,
+                _marker: PhantomData,
+            })
+    }
+
+    /// Get the raw schema data for this license.
+    #[must_use]
+    pub fn raw(&self) -> Arc<LicenseSchema> {
+        self.data.clone()
+    }
+
+    /// The license is in effect. Licenses that don't report their
+    /// state are considered to be in effect; expired or not yet
+    /// activated licenses are reported in a state other than
+    /// `Enabled`.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.data
+            .status
+            .as_ref()
+            .and_then(|status| status.state)
+            .flatten()
+            .is_none_or(|state| state == State::Enabled)
+    }
+
+    /// The license authorizes `device`. Licenses that don't list
+    /// authorized devices apply to the whole service.
+    #[must_use]
+    pub fn authorizes(&self, device: &ODataId) -> bool {
+        self.data
+            .links
+            .as_ref()
+            .and_then(|links| links.authorized_devices.as_ref())
+            .filter(|devices| !devices.is_empty())
+            .is_none_or(|devices| devices.iter().any(|authorized| authorized.id() == device))
+    }
+}
+
+impl<B: Bmc> Resource for License<B> {
+    type Entity = LicenseSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
@@ -19,15 +19,26 @@
 //! and their log entries.
 
 use crate::schema::log_entry::LogEntry;
+use crate::schema::log_entry_collection::LogEntryCollection;
 use crate::schema::log_service::LogService as LogServiceSchema;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+use futures_util::stream;
+use nv_redfish_core::query::FilterQuery;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::EdmDateTimeOffset;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use std::convert::TryFrom as _;
+use std::fmt::Write as _;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 /// Log service.
 ///
@@ -88,7 +99,7 @@ impl<B: Bmc> LogService<B> {
     /// - Filtering log entries data fails
     pub async fn filter_entries(
         &self,
-        filter: nv_redfish_core::FilterQuery,
+        filter: FilterQuery,
     ) -> Result<Option<Vec<Arc<LogEntry>>>, Error<B>> {
         if let Some(entries_ref) = &self.data.entries {
             let entries_collection = entries_ref
@@ -104,6 +115,128 @@ impl<B: Bmc> LogService<B> {
         }
     }
 
+    /// List log entries created in time window from `start`
+    /// (inclusive) to `end` (exclusive).
+    ///
+    /// If the service supports `$filter` query, the window is applied
+    /// by the service on `Created` property. Otherwise, entries are
+    /// filtered on the client side. In both cases pages of the result
+    /// are retrieved one by one following `Members@odata.nextLink`.
+    /// Requests are sent sequentially, so pulling entries from many
+    /// BMCs doesn't multiply load on any of them.
+    ///
+    /// Entries without `Created` property are not included.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `start` or `end` cannot be encoded in the query or compared
+    ///   with system time ([`Error::LogEntriesTimeBoundInvalid`])
+    /// - fetching log entries data fails
+    pub async fn entries_between(
+        &self,
+        start: EdmDateTimeOffset,
+        end: EdmDateTimeOffset,
+    ) -> Result<Option<Vec<Arc<LogEntry>>>, Error<B>> {
+        let Some(entries_ref) = &self.data.entries else {
+            return Ok(None);
+        };
+        let start_time = system_time(start)?;
+        let end_time = system_time(end)?;
+        let mut page = if self.bmc.protocol_features().supports_filter() {
+            let filter = FilterQuery::ge(&"Created", encode_bound(start)?)
+                .and()
+                .lt_then(&"Created", encode_bound(end)?);
+            entries_ref
+                .filter(self.bmc.as_ref(), filter)
+                .await
+                .map_err(Error::Bmc)?
+        } else {
+            self.bmc.expand_property(entries_ref).await?
+        };
+        let in_window = |created: SystemTime| start_time <= created && created < end_time;
+        let mut entries = Vec::new();
+        loop {
+            for entry in self.expand_entries(&page.members).await? {
+                let created = entry
+                    .created
+                    .and_then(|created| SystemTime::try_from(created).ok());
+                if created.is_some_and(in_window) {
+                    entries.push(entry);
+                }
+            }
+            let Some(next_link) = &page.next_link else {
+                break;
+            };
+            let next = NavProperty::<LogEntryCollection>::new_reference(next_link.clone());
+            page = self.bmc.expand_property(&next).await?;
+        }
+        Ok(Some(entries))
+    }
+
+    /// Stream log entries page by page.
+    ///
+    /// A page of the collection is retrieved when entries of the
+    /// previous one are consumed, following `Members@odata.nextLink`,
+    /// so very large logs (for example, SEL) are not retrieved in a
+    /// single response. When `page_size` is set and the service
+    /// supports `$top` and `$skip` queries, pages of `page_size`
+    /// entries are requested; otherwise the service chooses the page
+    /// size.
+    ///
+    /// Returns `None` when the log service does not have a log entries
+    /// collection. An error of page retrieval is delivered as a stream
+    /// item and ends the stream.
+    #[must_use]
+    pub fn entries_stream(
+        &self,
+        page_size: Option<usize>,
+    ) -> Option<BoxTryStream<Arc<LogEntry>, Error<B>>>
+    where
+        B: 'static,
+        B::Error: 'static,
+    {
+        let entries_id = self.data.entries.as_ref()?.id().clone();
+        let page_size =
+            page_size.filter(|size| *size > 0 && self.bmc.protocol_features().supports_top_skip());
+        let first = page_size.map_or_else(
+            || entries_id.clone(),
+            |size| format!("{entries_id}?$top={size}").into(),
+        );
+        let state = EntriesPages {
+            bmc: self.bmc.clone(),
+            entries_id,
+            page_size,
+            next: Some(first),
+            retrieved: 0,
+            page: None,
+            position: 0,
+        };
+        Some(Box::pin(stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(entry_ref) = state
+                    .page
+                    .as_ref()
+                    .and_then(|page| page.members.get(state.position))
+                {
+                    let entry = entry_ref.get(state.bmc.as_ref()).await.map_err(Error::Bmc);
+                    state.position += 1;
+                    return Some((entry, state));
+                }
+                let next = NavProperty::<LogEntryCollection>::new_reference(state.next.take()?);
+                match state.bmc.expand_property(&next).await {
+                    Ok(page) => {
+                        state.retrieved += page.members.len();
+                        state.next = state.next_page(&page);
+                        state.page = Some(page);
+                        state.position = 0;
+                    }
+                    Err(err) => return Some((Err(err), state)),
+                }
+            }
+        })))
+    }
+
     /// Clear all log entries.
     ///
     /// # Arguments
@@ -148,8 +281,63 @@ impl<B: Bmc> LogService<B> {
     }
 }
 
+/// `bound` of the time window as system time.
+fn system_time<B: Bmc>(bound: EdmDateTimeOffset) -> Result<SystemTime, Error<B>> {
+    SystemTime::try_from(bound).map_err(|_| Error::LogEntriesTimeBoundInvalid(bound))
+}
+
+/// `bound` of the time window encoded for `$filter` query.
+fn encode_bound<B: Bmc>(bound: EdmDateTimeOffset) -> Result<String, Error<B>> {
+    let mut encoded = String::new();
+    write!(encoded, "{bound}").map_err(|_| Error::LogEntriesTimeBoundInvalid(bound))?;
+    Ok(encoded)
+}
+
+struct EntriesPages<B: Bmc> {
+    bmc: NvBmc<B>,
+    entries_id: ODataId,
+    page_size: Option<usize>,
+    next: Option<ODataId>,
+    retrieved: usize,
+    page: Option<Arc<LogEntryCollection>>,
+    position: usize,
+}
+
+impl<B: Bmc> EntriesPages<B> {
+    // `Members@odata.nextLink` takes precedence. Without it, a full
+    // page requested with `$top` means that more entries may follow.
+    fn next_page(&self, page: &LogEntryCollection) -> Option<ODataId> {
+        if let Some(next_link) = &page.next_link {
+            return Some(next_link.clone());
+        }
+        let page_size = self.page_size?;
+        let more = page.members.len() == page_size
+            && page.members_count.is_none_or(|count| {
+                usize::try_from(count).is_ok_and(|count| self.retrieved < count)
+            });
+        more.then(|| {
+            format!(
+                "{}?$skip={}&$top={}",
+                self.entries_id, self.retrieved, page_size
+            )
+            .into()
+        })
+    }
+}
+
 impl<B: Bmc> Resource for LogService<B> {
+    type Entity = LogServiceSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -13,13 +13,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::error::Error as StdError;
 use std::fmt;
+use std::str::FromStr;
 
 /// MAC address returned by the crate.
 ///
-/// nv-redfish is not opionated about format of the MAC addresses. So,
-/// it returns whatever server returns. This type is only introduced
-/// to reduce number of untyped &str returned by functions.
+/// Holds the value exactly as reported by the server. BMCs are not
+/// consistent about MAC address format (`AA:BB:CC:DD:EE:FF`,
+/// `aa-bb-cc-dd-ee-ff`, `aabbccddeeff`, ...), use [`MacAddress::parse`]
+/// to get validated and normalized [`MacAddr`].
 #[derive(Clone, Copy, Debug)]
 #[repr(transparent)]
 pub struct MacAddress<'a>(&'a str);
@@ -36,6 +39,16 @@ impl MacAddress<'_> {
     pub const fn as_str(&self) -> &str {
         self.0
     }
+
+    /// Parse reported value into [`MacAddr`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a MAC address in any of
+    /// the formats accepted by [`MacAddr`].
+    pub fn parse(&self) -> Result<MacAddr, MacAddrParseError> {
+        self.0.parse()
+    }
 }
 
 impl fmt::Display for MacAddress<'_> {
@@ -43,3 +56,138 @@ impl fmt::Display for MacAddress<'_> {
         self.0.fmt(f)
     }
 }
+
+/// Validated 48-bit MAC address.
+///
+/// Parsing accepts groups of two hex digits separated by `:` or `-`,
+/// groups of four hex digits separated by `.` and twelve hex digits
+/// without separators, in any case. Display always produces lower
+/// case colon separated form, so two values reported in different
+/// formats are equal and print the same way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MacAddr([u8; 6]);
+
+impl MacAddr {
+    /// Create MAC address from its octets.
+    #[must_use]
+    pub const fn new(octets: [u8; 6]) -> Self {
+        Self(octets)
+    }
+
+    /// Octets of the MAC address.
+    #[must_use]
+    pub const fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    fn from(octets: [u8; 6]) -> Self {
+        Self(octets)
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = MacAddrParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || MacAddrParseError {
+            value: s.to_owned(),
+        };
+        let value = s.trim();
+        let (group_len, separator) = match value.find([':', '-', '.']) {
+            Some(index @ (2 | 4)) => (index, value[index..].chars().next()),
+            Some(_) => return Err(error()),
+            None => (12, None),
+        };
+        if group_len == 4 && separator != Some('.') {
+            return Err(error());
+        }
+        let groups = value.split(|c| Some(c) == separator).collect::<Vec<_>>();
+        if groups.len() != 12 / group_len || groups.iter().any(|g| g.len() != group_len) {
+            return Err(error());
+        }
+        let digits = groups.concat();
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+        let mut octets = [0; 6];
+        for (octet, pair) in octets.iter_mut().zip(digits.as_bytes().chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| error())?;
+            *octet = u8::from_str_radix(pair, 16).map_err(|_| error())?;
+        }
+        Ok(Self(octets))
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, octet) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(":")?;
+            }
+            write!(f, "{octet:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse reported MAC address `value`. Blank value means that the
+/// address is not reported.
+pub(crate) fn parse_reported(value: &str) -> Option<Result<MacAddr, MacAddrParseError>> {
+    (!value.trim().is_empty()).then(|| value.parse())
+}
+
+/// Error returned when a value is not a valid MAC address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacAddrParseError {
+    /// Rejected value.
+    pub value: String,
+}
+
+impl fmt::Display for MacAddrParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid MAC address: {:?}", self.value)
+    }
+}
+
+impl StdError for MacAddrParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::MacAddr;
+    use super::MacAddress;
+
+    #[test]
+    fn vendor_formats_are_normalized() {
+        for value in [
+            "B8:3F:D2:00:0A:01",
+            "b8-3f-d2-00-0a-01",
+            "b83fd2000a01",
+            "B83F.D200.0A01",
+            " b8:3f:d2:00:0a:01 ",
+        ] {
+            let mac = MacAddress::new(value).parse().expect("valid MAC address");
+            assert_eq!(mac, MacAddr::new([0xb8, 0x3f, 0xd2, 0x00, 0x0a, 0x01]));
+            assert_eq!(mac.to_string(), "b8:3f:d2:00:0a:01");
+        }
+    }
+
+    #[test]
+    fn malformed_values_are_rejected() {
+        for value in [
+            "",
+            "b8:3f:d2:00:0a",
+            "b8:3f:d2:00:0a:01:02",
+            "b8:3f-d2:00:0a:01",
+            "b8:3f:d2:00:0a:0g",
+            "b8:3f:d2:00:a:001",
+            "b83fd2000a0",
+            "+8:3f:d2:00:0a:01",
+            "b83f:d200:0a01",
+            "00:00:00:00:00:00:",
+        ] {
+            assert!(value.parse::<MacAddr>().is_err(), "{:?}", value);
+        }
+    }
+}
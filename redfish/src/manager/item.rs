@@ -16,17 +16,42 @@
 use crate::resource::ResetType;
 use crate::schema::manager::Manager as ManagerSchema;
 use crate::schema::manager::ResetToDefaultsType as ManagerResetToDefaultsType;
+use crate::service_root::LinkId;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
+#[cfg(feature = "license-service")]
+use crate::ServiceRoot;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EdmDateTimeOffset;
+use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
+use serde::Serialize;
+use std::convert::TryFrom as _;
+#[cfg(any(feature = "license-service", feature = "oem-hpe"))]
+use std::iter;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
+#[cfg(feature = "license-service")]
+use tagged_types::TaggedType;
 
+#[cfg(any(feature = "license-service", feature = "oem-hpe"))]
+use super::license::license_state;
+#[cfg(feature = "license-service")]
+use super::license::LicenseState;
+use super::license::LicensedFeature;
 #[cfg(feature = "manager-network-protocol")]
 use super::network_protocol::ManagerNetworkProtocol;
+#[cfg(feature = "virtual-media")]
+use super::virtual_media::VirtualMedia;
+#[cfg(feature = "accounts")]
+use crate::account::AccountService;
 #[cfg(feature = "ethernet-interfaces")]
 use crate::ethernet_interface::EthernetInterfaceCollection;
 #[cfg(feature = "host-interfaces")]
@@ -44,6 +69,41 @@ use crate::oem::lenovo::manager::LenovoManager;
 #[cfg(feature = "oem-supermicro")]
 use crate::oem::supermicro::manager::SupermicroManager;
 
+/// Difference between the manager clock and local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockSkew {
+    /// Manager clock is ahead of local time.
+    Ahead(Duration),
+    /// Manager clock is behind local time.
+    Behind(Duration),
+}
+
+impl ClockSkew {
+    /// Absolute value of the skew.
+    #[must_use]
+    pub const fn magnitude(&self) -> Duration {
+        match self {
+            Self::Ahead(d) | Self::Behind(d) => *d,
+        }
+    }
+}
+
+/// Way to correct manager clock skew.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ClockCorrection {
+    /// Set `DateTime` of the manager to local time.
+    SetDateTime,
+    /// Enable NTP on the manager with the specified servers.
+    #[cfg(feature = "manager-network-protocol")]
+    Ntp(Vec<String>),
+}
+
+#[derive(Serialize)]
+struct ManagerDateTimeUpdate {
+    #[serde(rename = "DateTime")]
+    date_time: EdmDateTimeOffset,
+}
+
 /// Represents a manager (BMC) in the system.
 ///
 /// Provides access to manager information and associated services.
@@ -53,6 +113,17 @@ pub struct Manager<B: Bmc> {
     data: Arc<ManagerSchema>,
 }
 
+// Implement Clone manually to avoid requiring B: Clone; cloning only
+// needs Arc/NvBmc clones.
+impl<B: Bmc> Clone for Manager<B> {
+    fn clone(&self) -> Self {
+        Self {
+            bmc: self.bmc.clone(),
+            data: self.data.clone(),
+        }
+    }
+}
+
 impl<B: Bmc> Manager<B> {
     /// Create a new manager handle.
     pub(crate) async fn new(
@@ -77,6 +148,305 @@ impl<B: Bmc> Manager<B> {
         self.data.clone()
     }
 
+    /// Current date and time reported by the manager.
+    ///
+    /// The value was captured when the manager resource was fetched.
+    #[must_use]
+    pub fn date_time(&self) -> Option<EdmDateTimeOffset> {
+        self.data.date_time.flatten()
+    }
+
+    /// Skew of the manager clock relative to `now`.
+    ///
+    /// `now` should be taken close to the moment when the manager
+    /// resource was fetched, otherwise the skew includes the age of
+    /// the data. Returns `None` if the manager doesn't report its
+    /// date and time.
+    #[must_use]
+    pub fn clock_skew(&self, now: SystemTime) -> Option<ClockSkew> {
+        let manager_time = SystemTime::try_from(self.date_time()?).ok()?;
+        Some(match manager_time.duration_since(now) {
+            Ok(ahead) => ClockSkew::Ahead(ahead),
+            Err(behind) => ClockSkew::Behind(behind.duration()),
+        })
+    }
+
+    /// Measure skew of the manager clock.
+    ///
+    /// The manager is fetched again and local time is taken right
+    /// before and after the request; the skew is measured against the
+    /// midpoint, so it is precise up to half of the request round
+    /// trip. Returns the re-fetched manager together with the skew,
+    /// which is `None` if the manager doesn't report its date and time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if retrieving manager data fails.
+    pub async fn measure_clock_skew(&self) -> Result<(Self, Option<ClockSkew>), Error<B>> {
+        let before = SystemTime::now();
+        let manager = Self::new(
+            &self.bmc,
+            &NavProperty::new_reference(self.data.odata_id().clone()),
+        )
+        .await?;
+        let after = SystemTime::now();
+        let now = before + after.duration_since(before).unwrap_or_default() / 2;
+        let skew = manager.clock_skew(now);
+        Ok((manager, skew))
+    }
+
+    /// Set date and time of the manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - configuration of the manager is locked
+    ///   ([`Error::ConfigurationLocked`])
+    /// - updating the manager fails
+    pub async fn set_date_time(
+        &self,
+        date_time: EdmDateTimeOffset,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.ensure_configuration_unlocked().await?;
+        let update = ManagerDateTimeUpdate { date_time };
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<ManagerSchema>>(
+                self.data.odata_id(),
+                self.data.etag(),
+                &update,
+            )
+            .await
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))?
+            .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
+            .await
+    }
+
+    /// Check if configuration of the manager is locked by a vendor
+    /// lockdown mode: System Lockdown of Dell iDRAC or `SysLockdown`
+    /// of Supermicro.
+    ///
+    /// Manager operations that change configuration check the lock
+    /// before sending requests and fail with
+    /// [`Error::ConfigurationLocked`] instead of a generic HTTP error
+    /// of the service.
+    ///
+    /// Returns `Ok(None)` when the manager doesn't report lockdown
+    /// state or support of its vendor is not compiled in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching OEM lockdown data fails.
+    #[cfg_attr(
+        not(any(feature = "oem-dell-attributes", feature = "oem-supermicro")),
+        allow(clippy::unused_async) // lockdown state is fetched by OEM features only
+    )]
+    pub async fn is_configuration_locked(&self) -> Result<Option<bool>, Error<B>> {
+        #[cfg(feature = "oem-dell-attributes")]
+        if let Some(locked) = self
+            .oem_dell_attributes()
+            .await?
+            .and_then(|attributes| attributes.system_lockdown())
+        {
+            return Ok(Some(locked));
+        }
+        #[cfg(feature = "oem-supermicro")]
+        if let Some(supermicro) = self.oem_supermicro()? {
+            if let Some(locked) = supermicro
+                .sys_lockdown()
+                .await?
+                .and_then(|lockdown| lockdown.sys_lockdown_enabled())
+            {
+                return Ok(Some(locked));
+            }
+        }
+        Ok(None)
+    }
+
+    pub(crate) async fn ensure_configuration_unlocked(&self) -> Result<(), Error<B>> {
+        if self.is_configuration_locked().await? == Some(true) {
+            Err(Error::ConfigurationLocked(self.data.odata_id().clone()))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Name of the license that the manager lacks to provide
+    /// `feature`.
+    ///
+    /// Active licenses of the license service that authorize the
+    /// manager are checked first. License of HPE iLO reported in
+    /// vendor data of the manager is checked when the service has no
+    /// license service or none of its licenses belongs to a known
+    /// product.
+    ///
+    /// Returns `Ok(None)` when the feature is licensed, license state
+    /// is not reported or support of the license source is not
+    /// compiled in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - retrieving licenses of the license service fails
+    /// - parsing OEM license data fails
+    #[cfg_attr(
+        not(any(feature = "license-service", feature = "oem-hpe")),
+        allow(unused_variables, clippy::unused_async) // license state sources are feature-enabled
+    )]
+    pub async fn missing_license(
+        &self,
+        feature: LicensedFeature,
+    ) -> Result<Option<&'static str>, Error<B>> {
+        #[cfg(feature = "license-service")]
+        match self.license_service_state(feature).await? {
+            LicenseState::Unknown => {}
+            state => return Ok(state.missing_license()),
+        }
+        #[cfg(feature = "oem-hpe")]
+        if let Some(license) = self
+            .oem_hpe()?
+            .as_ref()
+            .and_then(HpeManager::license_string)
+        {
+            return Ok(license_state(iter::once(license), feature).missing_license());
+        }
+        Ok(None)
+    }
+
+    /// Ensure that `feature` is licensed on the manager.
+    ///
+    /// Call before using virtual media or remote console to get
+    /// [`Error::FeatureNotLicensed`] instead of a vendor specific
+    /// HTTP error of the service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the feature is not licensed ([`Error::FeatureNotLicensed`])
+    /// - retrieving license state fails (see [`Self::missing_license`])
+    pub async fn ensure_feature_licensed(&self, feature: LicensedFeature) -> Result<(), Error<B>> {
+        self.missing_license(feature)
+            .await?
+            .map_or(Ok(()), |license| {
+                Err(Error::FeatureNotLicensed {
+                    feature,
+                    license: license.into(),
+                })
+            })
+    }
+
+    #[cfg(feature = "license-service")]
+    async fn license_service_state(
+        &self,
+        feature: LicensedFeature,
+    ) -> Result<LicenseState, Error<B>> {
+        let root = ServiceRoot::with_bmc(self.bmc.clone()).await?;
+        let licenses = match root.license_service().await? {
+            Some(service) => service.licenses().await?.unwrap_or_default(),
+            None => Vec::new(),
+        };
+        let manager = self.data.odata_id();
+        Ok(license_state(
+            licenses
+                .iter()
+                .filter(|license| license.is_active() && license.authorizes(manager))
+                .flat_map(|license| {
+                    iter::once(license.name().into_inner())
+                        .chain(license.description().map(TaggedType::into_inner))
+                }),
+            feature,
+        ))
+    }
+
+    /// Check if this manager is a manager for the computer system
+    /// identified by `system` (the system is listed in
+    /// `Links.ManagerForServers`).
+    #[must_use]
+    pub fn is_manager_for_server(&self, system: &ODataId) -> bool {
+        self.data
+            .links
+            .as_ref()
+            .and_then(|links| links.manager_for_servers.as_ref())
+            .is_some_and(|servers| servers.iter().any(|server| server.link_id() == system))
+    }
+
+    /// Check if this manager is authoritative for the resource
+    /// identified by `resource`: the resource is, or is contained in,
+    /// a computer system, chassis or switch listed in
+    /// `Links.ManagerForServers`, `Links.ManagerForChassis` or
+    /// `Links.ManagerForSwitches`.
+    #[must_use]
+    pub fn manages(&self, resource: &ODataId) -> bool {
+        let Some(links) = self.data.links.as_ref() else {
+            return false;
+        };
+        let resource = resource.to_string();
+        let contains = |id: &ODataId| {
+            let id = id.to_string();
+            resource
+                .strip_prefix(&id)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        };
+        links
+            .manager_for_servers
+            .iter()
+            .flatten()
+            .map(LinkId::link_id)
+            .chain(
+                links
+                    .manager_for_chassis
+                    .iter()
+                    .flatten()
+                    .map(LinkId::link_id),
+            )
+            .chain(
+                links
+                    .manager_for_switches
+                    .iter()
+                    .flatten()
+                    .map(LinkId::link_id),
+            )
+            .any(contains)
+    }
+
+    /// Check if this manager represents a remote (element) manager in
+    /// an aggregation service: it reports `RemoteRedfishServiceUri` or
+    /// `RemoteAccountService`.
+    #[must_use]
+    pub fn is_element_manager(&self) -> bool {
+        self.remote_redfish_service_uri().is_some() || self.data.remote_account_service.is_some()
+    }
+
+    /// URI of the Redfish service of the remote manager that this
+    /// manager represents in an aggregation service.
+    #[must_use]
+    pub fn remote_redfish_service_uri(&self) -> Option<&str> {
+        self.data
+            .remote_redfish_service_uri
+            .as_ref()
+            .and_then(Option::as_deref)
+    }
+
+    /// Get the account service of the remote manager that this
+    /// manager represents in an aggregation service.
+    ///
+    /// Returns `Ok(None)` when the remote account service link is
+    /// absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the account service fails.
+    #[cfg(feature = "accounts")]
+    pub async fn remote_account_service(&self) -> Result<Option<AccountService<B>>, Error<B>> {
+        if let Some(service_ref) = &self.data.remote_account_service {
+            AccountService::from_nav(&self.bmc, service_ref)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get the network protocol resource associated with this manager.
     ///
     /// Returns `Ok(None)` when the network protocol link is absent.
@@ -87,7 +457,7 @@ impl<B: Bmc> Manager<B> {
     #[cfg(feature = "manager-network-protocol")]
     pub async fn network_protocol(&self) -> Result<Option<ManagerNetworkProtocol<B>>, Error<B>> {
         if let Some(network_protocol_ref) = &self.data.network_protocol {
-            ManagerNetworkProtocol::new(&self.bmc, network_protocol_ref)
+            ManagerNetworkProtocol::new(&self.bmc, self, network_protocol_ref)
                 .await
                 .map(Some)
         } else {
@@ -129,7 +499,8 @@ impl<B: Bmc> Manager<B> {
     /// # Errors
     ///
     /// Returns an error if the manager does not support the `ResetToDefaults`
-    /// action or if invoking the action fails.
+    /// action, configuration of the manager is locked
+    /// ([`Error::ConfigurationLocked`]) or if invoking the action fails.
     pub async fn reset_to_defaults(
         &self,
         reset_type: ManagerResetToDefaultsType,
@@ -146,6 +517,7 @@ impl<B: Bmc> Manager<B> {
         if actions.reset_to_defaults.is_none() {
             return Err(Error::ActionNotAvailable);
         }
+        self.ensure_configuration_unlocked().await?;
 
         actions
             .reset_to_defaults(self.bmc.as_ref(), Some(reset_type))
@@ -217,6 +589,33 @@ impl<B: Bmc> Manager<B> {
         }
     }
 
+    /// Get virtual media devices of this manager.
+    ///
+    /// Returns `Ok(None)` when the virtual media link is absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching virtual media data fails.
+    #[cfg(feature = "virtual-media")]
+    pub async fn virtual_media(&self) -> Result<Option<Vec<VirtualMedia<B>>>, Error<B>> {
+        if let Some(virtual_media_ref) = &self.data.virtual_media {
+            let missing_license = self.missing_license(LicensedFeature::VirtualMedia).await?;
+            let collection = virtual_media_ref
+                .get(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc)?;
+
+            let mut virtual_media = Vec::new();
+            for m in &collection.members {
+                virtual_media.push(VirtualMedia::new(&self.bmc, m, missing_license).await?);
+            }
+
+            Ok(Some(virtual_media))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Get Dell Manager attributes for this manager.
     ///
     /// Returns `Ok(None)` when the manager does not include `Oem.Dell`.
@@ -279,7 +678,18 @@ impl<B: Bmc> Manager<B> {
 }
 
 impl<B: Bmc> Resource for Manager<B> {
+    type Entity = ManagerSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -0,0 +1,132 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! License state of manager features.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+
+/// Manager feature that vendors may enable by a license.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LicensedFeature {
+    /// Virtual media.
+    VirtualMedia,
+    /// Graphical remote console (KVM).
+    RemoteConsole,
+}
+
+impl Display for LicensedFeature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::VirtualMedia => write!(f, "Virtual media"),
+            Self::RemoteConsole => write!(f, "Remote console"),
+        }
+    }
+}
+
+/// Result of checking installed licenses for a feature.
+#[cfg(any(feature = "license-service", feature = "oem-hpe"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LicenseState {
+    /// None of the installed licenses belongs to a known licensing
+    /// scheme of the manager product.
+    Unknown,
+    /// A license that enables the feature is installed.
+    Licensed,
+    /// Licenses of the product are installed, but none of them enables
+    /// the feature. Contains the name of the license to install.
+    Missing(&'static str),
+}
+
+#[cfg(any(feature = "license-service", feature = "oem-hpe"))]
+impl LicenseState {
+    pub(crate) const fn missing_license(self) -> Option<&'static str> {
+        match self {
+            Self::Missing(license) => Some(license),
+            Self::Unknown | Self::Licensed => None,
+        }
+    }
+}
+
+/// Licensing of a manager product: licenses are named after the
+/// product and their edition.
+#[cfg(any(feature = "license-service", feature = "oem-hpe"))]
+struct LicenseScheme {
+    /// Product name that all licenses of the scheme contain.
+    product: &'static str,
+    /// Editions that enable virtual media and graphical remote
+    /// console.
+    editions: &'static [&'static str],
+    /// License to report when no edition that enables features is
+    /// installed.
+    required: &'static str,
+}
+
+/// Virtual media and graphical remote console are enabled by the same
+/// editions in all known schemes: Dell iDRAC Express, HPE iLO Standard
+/// and Lenovo XClarity Controller Standard lack both.
+#[cfg(any(feature = "license-service", feature = "oem-hpe"))]
+const LICENSE_SCHEMES: &[LicenseScheme] = &[
+    LicenseScheme {
+        product: "iDRAC",
+        editions: &["Enterprise", "Datacenter"],
+        required: "iDRAC Enterprise",
+    },
+    LicenseScheme {
+        product: "iLO",
+        editions: &["Advanced", "Essentials", "Scale-Out"],
+        required: "iLO Advanced",
+    },
+    LicenseScheme {
+        product: "XClarity Controller",
+        editions: &["Advanced", "Enterprise", "Premier", "Platinum"],
+        required: "XClarity Controller Advanced",
+    },
+];
+
+/// Check names of installed licenses for a license that enables
+/// `feature`.
+#[cfg(any(feature = "license-service", feature = "oem-hpe"))]
+pub(crate) fn license_state<'a, I>(licenses: I, feature: LicensedFeature) -> LicenseState
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let licenses = licenses.into_iter().collect::<Vec<_>>();
+    match feature {
+        LicensedFeature::VirtualMedia | LicensedFeature::RemoteConsole => LICENSE_SCHEMES
+            .iter()
+            .find_map(|scheme| {
+                let mut product_licenses = licenses
+                    .iter()
+                    .filter(|license| license.contains(scheme.product))
+                    .peekable();
+                product_licenses.peek()?;
+                Some(
+                    if product_licenses.any(|license| {
+                        scheme
+                            .editions
+                            .iter()
+                            .any(|edition| license.contains(edition))
+                    }) {
+                        LicenseState::Licensed
+                    } else {
+                        LicenseState::Missing(scheme.required)
+                    },
+                )
+            })
+            .unwrap_or(LicenseState::Unknown),
+    }
+}
@@ -18,10 +18,14 @@
 //! This module provides types for working with Redfish Manager resources.
 
 mod item;
+mod license;
 #[cfg(feature = "manager-network-protocol")]
 mod network_protocol;
+#[cfg(feature = "virtual-media")]
+mod virtual_media;
 
 use crate::core::NavProperty;
+use crate::core::ODataId;
 use crate::patch_support::CollectionWithPatch;
 use crate::patch_support::FilterFn;
 use crate::patch_support::JsonValue;
@@ -36,9 +40,26 @@ use nv_redfish_core::Bmc;
 use std::convert::identity;
 use std::sync::Arc;
 
+pub use item::ClockCorrection;
+pub use item::ClockSkew;
 pub use item::Manager;
+pub use license::LicensedFeature;
+#[cfg(feature = "manager-network-protocol")]
+pub use network_protocol::CertificateCreate;
 #[cfg(feature = "manager-network-protocol")]
 pub use network_protocol::ManagerNetworkProtocol;
+#[cfg(feature = "manager-network-protocol")]
+pub use network_protocol::ManagerNetworkProtocolUpdate;
+#[cfg(feature = "virtual-media")]
+pub use virtual_media::InsertMedia;
+#[cfg(feature = "virtual-media")]
+pub use virtual_media::TransferCredentials;
+#[cfg(feature = "virtual-media")]
+pub use virtual_media::TransferMethod;
+#[cfg(feature = "virtual-media")]
+pub use virtual_media::TransferProtocolType;
+#[cfg(feature = "virtual-media")]
+pub use virtual_media::VirtualMedia;
 
 #[doc(inline)]
 pub use crate::schema::manager::ResetToDefaultsType as ManagerResetToDefaultsType;
@@ -74,9 +95,12 @@ impl<B: Bmc> ManagerCollection<B> {
                 .await
                 .map(Some)
         } else if bmc.quirks.bug_missing_root_nav_properties() {
-            bmc.expand_property(&NavProperty::new_reference(
-                format!("{}/Managers", root.odata_id()).into(),
-            ))
+            Self::expand_collection(
+                bmc,
+                &NavProperty::new_reference(format!("{}/Managers", root.odata_id()).into()),
+                None,
+                filters_fn.as_ref(),
+            )
             .await
             .map(Some)
         } else {
@@ -96,11 +120,9 @@ impl<B: Bmc> ManagerCollection<B> {
     ///
     /// Returns an error if fetching manager data fails.
     pub async fn members(&self) -> Result<Vec<Manager<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(Manager::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+        self.bmc
+            .fan_out(&self.collection.members, |m| Manager::new(&self.bmc, m))
+            .await
     }
 }
 
@@ -110,7 +132,17 @@ impl<B: Bmc> CollectionWithPatch<ManagerCollectionSchema, ManagerSchema, B>
     fn convert_patched(
         base: ResourceCollection,
         members: Vec<NavProperty<ManagerSchema>>,
+        members_count: Option<i64>,
+        next_link: Option<ODataId>,
     ) -> ManagerCollectionSchema {
-        ManagerCollectionSchema { base, members }
+        ManagerCollectionSchema {
+            base,
+            members,
+            members_count,
+            next_link,
+        }
+    }
+    fn collection_members(collection: &ManagerCollectionSchema) -> &[NavProperty<ManagerSchema>] {
+        &collection.members
     }
 }
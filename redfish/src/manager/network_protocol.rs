@@ -14,31 +14,63 @@
 // limitations under the License.
 //! Manager network protocol resource.
 
-use std::marker::PhantomData;
 use std::sync::Arc;
 
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
 use nv_redfish_core::{Bmc, NavProperty};
+use serde::Serialize;
 
+use super::item::Manager;
+use crate::schema::certificate::Certificate as CertificateSchema;
+use crate::schema::certificate_collection::CertificateCollection as CertificateCollectionSchema;
 use crate::schema::manager_network_protocol::ManagerNetworkProtocol as ManagerNetworkProtocolSchema;
 use crate::{Error, NvBmc};
 
+#[doc(inline)]
+pub use crate::schema::certificate::CertificateCreate;
+#[doc(inline)]
+pub use crate::schema::manager_network_protocol::ManagerNetworkProtocolUpdate;
+
+#[derive(Serialize)]
+struct NtpPatch {
+    #[serde(rename = "ProtocolEnabled")]
+    protocol_enabled: bool,
+    #[serde(rename = "NTPServers")]
+    ntp_servers: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ManagerNetworkProtocolNtpUpdate {
+    #[serde(rename = "NTP")]
+    ntp: NtpPatch,
+}
+
 /// Network protocol configuration associated with a manager.
+///
+/// Changes of the configuration fail with
+/// [`Error::ConfigurationLocked`] when configuration of the manager is
+/// locked (see [`Manager::is_configuration_locked`]).
 pub struct ManagerNetworkProtocol<B: Bmc> {
+    bmc: NvBmc<B>,
+    manager: Manager<B>,
     data: Arc<ManagerNetworkProtocolSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> ManagerNetworkProtocol<B> {
     pub(crate) async fn new(
         bmc: &NvBmc<B>,
+        manager: &Manager<B>,
         nav: &NavProperty<ManagerNetworkProtocolSchema>,
     ) -> Result<Self, Error<B>> {
         nav.get(bmc.as_ref())
             .await
             .map_err(Error::Bmc)
             .map(|data| Self {
+                bmc: bmc.clone(),
+                manager: manager.clone(),
                 data,
-                _marker: PhantomData,
             })
     }
 
@@ -47,4 +79,111 @@ impl<B: Bmc> ManagerNetworkProtocol<B> {
     pub fn raw(&self) -> Arc<ManagerNetworkProtocolSchema> {
         self.data.clone()
     }
+
+    /// Update the network protocol resource: host name, NTP settings
+    /// or enabled state of protocols.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if configuration of the manager is locked
+    /// ([`Error::ConfigurationLocked`]) or updating the network
+    /// protocol resource fails.
+    pub async fn update(
+        &self,
+        update: &ManagerNetworkProtocolUpdate,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.patch(update).await
+    }
+
+    /// Certificates of the HTTPS service of the manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the certificates fails.
+    pub async fn https_certificates(&self) -> Result<Vec<Arc<CertificateSchema>>, Error<B>> {
+        let collection = self
+            .bmc
+            .expand_property(&self.https_certificates_nav())
+            .await?;
+        let mut certificates = Vec::with_capacity(collection.members.len());
+        for m in &collection.members {
+            certificates.push(m.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?);
+        }
+        Ok(certificates)
+    }
+
+    /// Add a certificate to the HTTPS service of the manager, for
+    /// example a TLS certificate signed by the CA of the
+    /// organization.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if configuration of the manager is locked
+    /// ([`Error::ConfigurationLocked`]) or creating the certificate
+    /// fails.
+    pub async fn add_https_certificate(
+        &self,
+        create: &CertificateCreate,
+    ) -> Result<ModificationResponse<Arc<CertificateSchema>>, Error<B>> {
+        self.manager.ensure_configuration_unlocked().await?;
+        self.bmc
+            .as_ref()
+            .create::<_, NavProperty<CertificateSchema>>(self.https_certificates_nav().id(), create)
+            .await
+            .map_err(Error::Bmc)?
+            .try_map_entity_async(|nav| async move {
+                nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)
+            })
+            .await
+    }
+
+    // Certificates of HTTPS are not reachable from all versions of
+    // the schema, so URI defined by Redfish for the collection is
+    // used.
+    fn https_certificates_nav(&self) -> NavProperty<CertificateCollectionSchema> {
+        NavProperty::new_reference(ODataId::from(format!(
+            "{}/HTTPS/Certificates",
+            self.data.odata_id()
+        )))
+    }
+
+    /// Enable NTP on the manager and use `servers` as time sources.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if configuration of the manager is locked
+    /// ([`Error::ConfigurationLocked`]) or updating the network
+    /// protocol resource fails.
+    pub async fn enable_ntp(
+        &self,
+        servers: Vec<String>,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        let update = ManagerNetworkProtocolNtpUpdate {
+            ntp: NtpPatch {
+                protocol_enabled: true,
+                ntp_servers: servers,
+            },
+        };
+        self.patch(&update).await
+    }
+
+    pub(crate) async fn patch<T: Serialize + Sync + Send>(
+        &self,
+        update: &T,
+    ) -> Result<ModificationResponse<Self>, Error<B>> {
+        self.manager.ensure_configuration_unlocked().await?;
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<ManagerNetworkProtocolSchema>>(
+                self.data.odata_id(),
+                self.data.etag(),
+                update,
+            )
+            .await
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))?
+            .try_map_entity_async(
+                |nav| async move { Self::new(&self.bmc, &self.manager, &nav).await },
+            )
+            .await
+    }
 }
@@ -0,0 +1,305 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Virtual media resource of a manager.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::sync::Arc;
+
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use serde::Serialize;
+
+use super::license::LicensedFeature;
+use crate::schema::virtual_media::VirtualMedia as VirtualMediaSchema;
+use crate::schema::virtual_media::VirtualMediaInsertMediaAction;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+
+#[doc(inline)]
+pub use crate::schema::virtual_media::TransferMethod;
+#[doc(inline)]
+pub use crate::schema::virtual_media::TransferProtocolType;
+
+/// Credentials used by the service to access the network share that
+/// holds the image.
+#[derive(Clone)]
+pub struct TransferCredentials {
+    /// User name for the network share.
+    pub user_name: String,
+    /// Password for the network share.
+    pub password: String,
+}
+
+impl Debug for TransferCredentials {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("TransferCredentials")
+            .field("user_name", &self.user_name)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Parameters of the `InsertMedia` action.
+#[derive(Clone, Debug)]
+pub struct InsertMedia {
+    /// URI of the image, for example `nfs://server/export/image.iso`
+    /// or `smb://server/share/image.iso`.
+    pub image: String,
+    /// Network protocol used to transfer the image. `None` lets the
+    /// service derive it from the scheme of `image`.
+    pub transfer_protocol_type: Option<TransferProtocolType>,
+    /// How the service accesses the image: stream it from the
+    /// network share or upload it to the manager. `None` keeps the
+    /// service default.
+    pub transfer_method: Option<TransferMethod>,
+    /// Credentials of the network share.
+    pub credentials: Option<TransferCredentials>,
+    /// Whether the media is write protected. `None` keeps the service
+    /// default.
+    pub write_protected: Option<bool>,
+}
+
+impl InsertMedia {
+    /// Insert image at `image` URI with service defaults.
+    #[must_use]
+    pub const fn new(image: String) -> Self {
+        Self {
+            image,
+            transfer_protocol_type: None,
+            transfer_method: None,
+            credentials: None,
+            write_protected: None,
+        }
+    }
+}
+
+// Parameters of `InsertMedia` for services that predate `UserName`
+// parameter of the action.
+#[derive(Serialize)]
+struct LegacyInsertMedia {
+    #[serde(rename = "Image")]
+    image: String,
+    #[serde(rename = "Inserted")]
+    inserted: bool,
+    #[serde(rename = "WriteProtected", skip_serializing_if = "Option::is_none")]
+    write_protected: Option<bool>,
+    #[serde(rename = "Username", skip_serializing_if = "Option::is_none")]
+    user_name: Option<String>,
+    #[serde(rename = "Password", skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EjectMediaUpdate {
+    #[serde(rename = "Image")]
+    image: Option<String>,
+    #[serde(rename = "Inserted")]
+    inserted: bool,
+}
+
+/// Virtual media device of a manager.
+pub struct VirtualMedia<B: Bmc> {
+    bmc: NvBmc<B>,
+    data: Arc<VirtualMediaSchema>,
+    missing_license: Option<&'static str>,
+}
+
+impl<B: Bmc> VirtualMedia<B> {
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<VirtualMediaSchema>,
+        missing_license: Option<&'static str>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                bmc: bmc.clone(),
+                data,
+                missing_license,
+            })
+    }
+
+    /// Get the raw schema data for the virtual media resource.
+    #[must_use]
+    pub fn raw(&self) -> Arc<VirtualMediaSchema> {
+        self.data.clone()
+    }
+
+    /// URI of the current image.
+    #[must_use]
+    pub fn image(&self) -> Option<&str> {
+        self.data.image.as_ref().and_then(Option::as_deref)
+    }
+
+    /// Whether the image is inserted.
+    #[must_use]
+    pub fn inserted(&self) -> Option<bool> {
+        self.data.inserted.flatten()
+    }
+
+    /// Whether the media is write protected.
+    #[must_use]
+    pub fn write_protected(&self) -> Option<bool> {
+        self.data.write_protected.flatten()
+    }
+
+    /// Insert image into the virtual media.
+    ///
+    /// Uses the `InsertMedia` action. Services that don't provide
+    /// the action (implementations that predate it) get the image
+    /// with PATCH of `Image` and `Inserted` properties instead.
+    ///
+    /// Some services expect credentials in `Username` and `Password`
+    /// parameters and don't support `TransferProtocolType` and
+    /// `TransferMethod`. For these services credentials are sent with
+    /// the parameter names they expect and the protocol is derived by
+    /// the service from the scheme of `image`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - virtual media is not licensed on the manager
+    ///   ([`Error::FeatureNotLicensed`])
+    /// - invoking the action or updating the virtual media fails
+    pub async fn insert_media(
+        &self,
+        media: InsertMedia,
+    ) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        self.ensure_licensed()?;
+
+        let action = self
+            .data
+            .actions
+            .as_ref()
+            .and_then(|actions| actions.insert_media.as_ref());
+        let (user_name, password) = media.credentials.map(|c| (c.user_name, c.password)).unzip();
+
+        if self.bmc.quirks.insert_media_legacy_credentials() {
+            let params = LegacyInsertMedia {
+                image: media.image,
+                inserted: true,
+                write_protected: media.write_protected,
+                user_name,
+                password,
+            };
+            return match action {
+                Some(action) => action
+                    .run_with(self.bmc.as_ref(), &params)
+                    .await
+                    .map_err(Error::Bmc),
+                None => self.update(&params).await,
+            };
+        }
+
+        let params = VirtualMediaInsertMediaAction {
+            image: media.image,
+            inserted: Some(true),
+            write_protected: media.write_protected,
+            user_name,
+            password,
+            transfer_protocol_type: media.transfer_protocol_type,
+            transfer_method: media.transfer_method,
+        };
+        match action {
+            Some(action) => action
+                .run(self.bmc.as_ref(), &params)
+                .await
+                .map_err(Error::Bmc),
+            // Parameters of the action are properties of the virtual
+            // media with the same names.
+            None => self.update(&params).await,
+        }
+    }
+
+    /// Eject image from the virtual media.
+    ///
+    /// Uses the `EjectMedia` action. Services that don't provide the
+    /// action get PATCH of `Image` to `null` and `Inserted` to `false`
+    /// instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - virtual media is not licensed on the manager
+    ///   ([`Error::FeatureNotLicensed`])
+    /// - invoking the action or updating the virtual media fails
+    pub async fn eject_media(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        self.ensure_licensed()?;
+
+        match self.data.actions.as_ref() {
+            Some(actions) if actions.eject_media.is_some() => actions
+                .eject_media(self.bmc.as_ref())
+                .await
+                .map_err(Error::Bmc),
+            _ => {
+                self.update(&EjectMediaUpdate {
+                    image: None,
+                    inserted: false,
+                })
+                .await
+            }
+        }
+    }
+
+    fn ensure_licensed(&self) -> Result<(), Error<B>> {
+        self.missing_license.map_or(Ok(()), |license| {
+            Err(Error::FeatureNotLicensed {
+                feature: LicensedFeature::VirtualMedia,
+                license: license.into(),
+            })
+        })
+    }
+
+    async fn update<V: Serialize + Sync + Send>(
+        &self,
+        update: &V,
+    ) -> Result<ModificationResponse<()>, Error<B>> {
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<VirtualMediaSchema>>(
+                self.data.odata_id(),
+                self.data.etag(),
+                update,
+            )
+            .await
+            .map(|response| response.map_entity(|_| ()))
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))
+    }
+}
+
+impl<B: Bmc> Resource for VirtualMedia<B> {
+    type Entity = VirtualMediaSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+}
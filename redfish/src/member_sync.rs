@@ -0,0 +1,223 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Incremental sync of collection members.
+//!
+//! Redfish doesn't define delta queries of collections, so changes of
+//! members are detected by their ETags. The collection is read to find
+//! added and removed members and every member is read again: known
+//! members are read with their ETags from the sync state in
+//! `If-None-Match` (see `Bmc::get_if_none_match`), so members that
+//! didn't change are answered with `304 Not Modified` without a
+//! payload. Members that are listed by the collection but are already
+//! deleted (`404 Not Found`) are reported as removed.
+
+use crate::Error;
+use crate::NvBmc;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Members of the collection and their ETags observed by the last
+/// sync.
+///
+/// Default (empty) state reports all members of the collection as
+/// added.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemberSyncState {
+    etags: BTreeMap<ODataId, Option<ODataETag>>,
+}
+
+impl MemberSyncState {
+    /// Number of known members.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.etags.len()
+    }
+
+    /// Returns `true` if no members are known.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.etags.is_empty()
+    }
+
+    /// Returns `true` if `id` is a known member.
+    #[must_use]
+    pub fn contains(&self, id: &ODataId) -> bool {
+        self.etags.contains_key(id)
+    }
+
+    /// ETag of the member observed by the last sync. `None` if the
+    /// member is not known or the service didn't provide its ETag.
+    #[must_use]
+    pub fn etag(&self, id: &ODataId) -> Option<&ODataETag> {
+        self.etags.get(id).and_then(Option::as_ref)
+    }
+}
+
+/// Changes of collection members since the previous sync.
+#[derive(Debug)]
+pub struct MemberChanges<T> {
+    /// Members that were not known by the previous sync.
+    pub added: Vec<Arc<T>>,
+    /// Known members whose ETag changed. Members without ETag are
+    /// always reported because their changes cannot be detected.
+    pub modified: Vec<Arc<T>>,
+    /// Known members that are no longer in the collection or are
+    /// not found.
+    pub removed: Vec<ODataId>,
+    /// State to pass to the next sync.
+    pub state: MemberSyncState,
+}
+
+impl<T> MemberChanges<T> {
+    /// Returns `true` if members didn't change since the previous
+    /// sync.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.modified.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Read members of the `collection` and compare them with `previous`
+/// state. Members are read with the fan-out limit of the BMC and
+/// reported in the order of the collection.
+pub(crate) async fn sync_members<B, T>(
+    bmc: &NvBmc<B>,
+    collection: &ODataId,
+    previous: &MemberSyncState,
+) -> Result<MemberChanges<T>, Error<B>>
+where
+    B: Bmc,
+    T: EntityTypeRef + for<'de> Deserialize<'de> + 'static,
+{
+    let ids = fetch_member_ids(bmc, collection).await?;
+    let members = bmc
+        .fan_out(&ids, |id| async move {
+            let result = match previous.etag(id) {
+                Some(etag) => bmc
+                    .as_ref()
+                    .get_if_none_match::<T>(id, etag)
+                    .await
+                    .map(|member| member.map_or(Member::NotModified, Member::Read)),
+                None => bmc.as_ref().get::<T>(id).await.map(Member::Read),
+            };
+            match result {
+                Err(err) if B::error_status_code(&err) == Some(404) => Ok(Member::NotFound),
+                result => result.map_err(Error::Bmc),
+            }
+        })
+        .await?;
+
+    let mut added = Vec::new();
+    let mut modified = Vec::new();
+    let mut etags = BTreeMap::new();
+    for (id, member) in ids.into_iter().zip(members) {
+        let member = match member {
+            Member::Read(member) => member,
+            Member::NotModified => {
+                let etag = previous.etag(&id).cloned();
+                etags.insert(id, etag);
+                continue;
+            }
+            Member::NotFound => continue,
+        };
+        let etag = member.etag().cloned();
+        match previous.etags.get(&id) {
+            None => added.push(member),
+            Some(known) if etag.is_none() || *known != etag => modified.push(member),
+            Some(_) => {}
+        }
+        etags.insert(id, etag);
+    }
+    let removed = previous
+        .etags
+        .keys()
+        .filter(|id| !etags.contains_key(id))
+        .cloned()
+        .collect();
+    Ok(MemberChanges {
+        added,
+        modified,
+        removed,
+        state: MemberSyncState { etags },
+    })
+}
+
+/// Member read by the sync.
+enum Member<T> {
+    /// Member payload.
+    Read(Arc<T>),
+    /// ETag of the member matches the sync state.
+    NotModified,
+    /// Member is listed by the collection but is not found.
+    NotFound,
+}
+
+/// Identifiers of the collection members in the order provided by
+/// the service, following `Members@odata.nextLink`. A link to a page
+/// that was already read ends the collection.
+pub(crate) async fn fetch_member_ids<B: Bmc>(
+    bmc: &NvBmc<B>,
+    collection: &ODataId,
+) -> Result<Vec<ODataId>, Error<B>> {
+    let mut ids = Vec::new();
+    let mut visited = HashSet::new();
+    let mut next_link = Some(collection.clone());
+    while let Some(link) = next_link {
+        if !visited.insert(link.clone()) {
+            break;
+        }
+        let page = bmc
+            .as_ref()
+            .get::<MemberPage>(&link)
+            .await
+            .map_err(Error::Bmc)?;
+        ids.extend(page.members.iter().map(|member| member.odata_id.clone()));
+        next_link = page.next_link.clone();
+    }
+    Ok(ids)
+}
+
+/// Page of the collection reduced to identifiers of its members.
+#[derive(Deserialize)]
+struct MemberPage {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+    #[serde(rename = "Members", default)]
+    members: Vec<MemberId>,
+    #[serde(rename = "Members@odata.nextLink", default)]
+    next_link: Option<ODataId>,
+}
+
+#[derive(Deserialize)]
+struct MemberId {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+}
+
+impl EntityTypeRef for MemberPage {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
@@ -22,8 +22,12 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
+#[cfg(feature = "fabric-adapters")]
+use nv_redfish_core::ODataId;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
@@ -91,6 +95,17 @@ impl<B: Bmc> NetworkDeviceFunction<B> {
         self.data.clone()
     }
 
+    /// The currently configured MAC address of this function.
+    #[must_use]
+    pub fn ethernet_mac_address(&self) -> Option<MacAddress<'_>> {
+        self.data
+            .ethernet
+            .as_ref()
+            .and_then(|eth| eth.mac_address.as_ref())
+            .and_then(Option::as_deref)
+            .map(MacAddress::new)
+    }
+
     /// The permanent MAC address assigned to this function.
     pub fn ethernet_permanent_mac_address(&self) -> Option<MacAddress<'_>> {
         self.data
@@ -100,10 +115,33 @@ impl<B: Bmc> NetworkDeviceFunction<B> {
             .and_then(Option::as_deref)
             .map(MacAddress::new)
     }
+
+    /// `OData` identifier of the port assigned to this function
+    /// (`Links.PhysicalPortAssignment`).
+    #[cfg(feature = "fabric-adapters")]
+    #[must_use]
+    pub fn physical_port_id(&self) -> Option<&ODataId> {
+        self.data
+            .links
+            .as_ref()
+            .and_then(|links| links.physical_port_assignment.as_ref())
+            .map(NavProperty::id)
+    }
 }
 
 impl<B: Bmc> Resource for NetworkDeviceFunction<B> {
+    type Entity = NetworkDeviceFunctionSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
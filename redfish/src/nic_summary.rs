@@ -0,0 +1,296 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-port summary of network interface controllers.
+//!
+//! Network interface controllers are described by several parts of
+//! the Redfish model: `NetworkAdapters` of chassis (hardware and
+//! firmware), their `NetworkDeviceFunctions` (ports and their MAC
+//! addresses), `PCIeDevices` of chassis (firmware when the adapter
+//! doesn't report it) and `EthernetInterfaces` of computer systems
+//! (link state and speed as seen by the host).
+//!
+//! [`NicSummary::collect`] merges them into one entry per port.
+//! Ports of network adapters (`Ports`) are the entries; their network
+//! device functions are attached by `Links.PhysicalPortAssignment`.
+//! Functions without port and adapters without ports produce entries
+//! of their own. Ethernet interfaces are matched with entries by MAC
+//! address (parsed, so formatting differences between the sources
+//! don't matter); interfaces without match (for example, on BMCs that
+//! don't expose network adapters) produce entries without adapter
+//! data.
+
+use crate::chassis::Chassis;
+use crate::chassis::NetworkAdapter;
+use crate::computer_system::ComputerSystem;
+use crate::ethernet_interface::EthernetInterface;
+use crate::ethernet_interface::LinkStatus as InterfaceLinkStatus;
+use crate::mac_address;
+use crate::mac_address::MacAddr;
+use crate::mac_address::MacAddrParseError;
+use crate::mac_address::MacAddress;
+use crate::Error;
+use crate::Resource as _;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::ODataId;
+use std::collections::HashMap;
+
+#[doc(inline)]
+pub use crate::computer_system::fabric_adapter::LinkStatus;
+
+/// Summary of one port of a network interface controller.
+///
+/// Each value is `None` when no source reports it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NicPort {
+    /// Network adapter that provides the port.
+    pub adapter: Option<ODataId>,
+    /// Port of the network adapter.
+    pub port: Option<ODataId>,
+    /// Network device function that represents the port.
+    pub network_device_function: Option<ODataId>,
+    /// Ethernet interface of the computer system attached to the port.
+    pub ethernet_interface: Option<ODataId>,
+    /// Computer system attached to the port.
+    pub system: Option<ODataId>,
+    /// Manufacturer of the adapter.
+    pub manufacturer: Option<String>,
+    /// Model of the adapter.
+    pub model: Option<String>,
+    /// Firmware version of the adapter.
+    pub firmware_version: Option<String>,
+    /// Currently configured MAC address. `Err` keeps the reported
+    /// value that is not a valid MAC address.
+    pub mac_address: Option<Result<MacAddr, MacAddrParseError>>,
+    /// Permanent MAC address. `Err` keeps the reported value that is
+    /// not a valid MAC address.
+    pub permanent_mac_address: Option<Result<MacAddr, MacAddrParseError>>,
+    /// Link speed (Mbit/s).
+    pub speed_mbps: Option<i64>,
+    /// Link status. Reported by the port of the adapter or, without
+    /// it, by the ethernet interface.
+    pub link_status: Option<LinkStatus>,
+}
+
+impl NicPort {
+    /// Link of the port is up.
+    #[must_use]
+    pub fn is_link_up(&self) -> bool {
+        self.link_status == Some(LinkStatus::LinkUp)
+    }
+
+    /// Link of the port is reported as down.
+    #[must_use]
+    pub fn is_link_down(&self) -> bool {
+        matches!(
+            self.link_status,
+            Some(LinkStatus::LinkDown | LinkStatus::NoLink)
+        )
+    }
+
+    /// Ports and interfaces are matched by valid MAC addresses only.
+    fn matches(&self, interface: &EthernetInterface<impl Bmc>) -> bool {
+        let port_macs = [&self.permanent_mac_address, &self.mac_address];
+        let interface_macs = [interface.permanent_mac_address(), interface.mac_address()];
+        port_macs
+            .iter()
+            .filter_map(|mac| mac.as_ref()?.as_ref().ok())
+            .any(|port_mac| {
+                interface_macs
+                    .iter()
+                    .filter_map(|mac| mac.as_ref()?.as_ref().ok())
+                    .any(|mac| mac == port_mac)
+            })
+    }
+}
+
+/// Per-port summary of network interface controllers of a node.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NicSummary {
+    /// Ports of network interface controllers.
+    pub ports: Vec<NicPort>,
+}
+
+impl NicSummary {
+    /// Collect ports of network adapters of `chassis` and attach
+    /// ethernet interfaces of `systems` to them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching any of the resources fails.
+    pub async fn collect<B: Bmc>(
+        systems: &[ComputerSystem<B>],
+        chassis: &[Chassis<B>],
+    ) -> Result<Self, Error<B>> {
+        let mut ports = Vec::new();
+        for chassis in chassis {
+            collect_adapter_ports(chassis, &mut ports).await?;
+        }
+        for system in systems {
+            let Some(interfaces) = system.ethernet_interfaces().await? else {
+                continue;
+            };
+            for interface in interfaces.members().await? {
+                attach_interface(system.odata_id(), &interface, &mut ports);
+            }
+        }
+        Ok(Self { ports })
+    }
+
+    /// Ports whose link is reported as down. Ports without reported
+    /// link status are not included.
+    pub fn link_down(&self) -> impl Iterator<Item = &NicPort> {
+        self.ports.iter().filter(|port| port.is_link_down())
+    }
+}
+
+async fn collect_adapter_ports<B: Bmc>(
+    chassis: &Chassis<B>,
+    ports: &mut Vec<NicPort>,
+) -> Result<(), Error<B>> {
+    let Some(adapters) = chassis.network_adapters().await? else {
+        return Ok(());
+    };
+    // PCIe devices are fetched only if some adapter doesn't report
+    // its firmware.
+    let mut pcie_firmware = None;
+    for adapter in adapters {
+        let firmware_version = match adapter.firmware_package_version() {
+            Some(version) => Some(version.to_owned()),
+            None => {
+                if pcie_firmware.is_none() {
+                    pcie_firmware = Some(pcie_firmware_versions(chassis).await?);
+                }
+                pcie_firmware.as_ref().and_then(|versions| {
+                    adapter
+                        .pcie_device_ids()
+                        .into_iter()
+                        .find_map(|id| versions.get(id).cloned())
+                })
+            }
+        };
+        let adapter_port = adapter_port(&adapter, firmware_version);
+        let first = ports.len();
+        for port in adapter.ports().await?.unwrap_or_default() {
+            ports.push(NicPort {
+                port: Some(port.odata_id().clone()),
+                link_status: port.link_status(),
+                ..adapter_port.clone()
+            });
+        }
+        let functions = match adapter.network_device_functions().await? {
+            Some(functions) => functions.members().await?,
+            None => Vec::new(),
+        };
+        for function in functions {
+            let mac_address = parse_mac(function.ethernet_mac_address());
+            let permanent_mac_address = parse_mac(function.ethernet_permanent_mac_address());
+            let assigned = function.physical_port_id().and_then(|id| {
+                ports.iter_mut().skip(first).find(|port| {
+                    port.port.as_ref() == Some(id) && port.network_device_function.is_none()
+                })
+            });
+            if let Some(port) = assigned {
+                port.network_device_function = Some(function.odata_id().clone());
+                port.mac_address = mac_address;
+                port.permanent_mac_address = permanent_mac_address;
+            } else {
+                ports.push(NicPort {
+                    network_device_function: Some(function.odata_id().clone()),
+                    mac_address,
+                    permanent_mac_address,
+                    ..adapter_port.clone()
+                });
+            }
+        }
+        if ports.len() == first {
+            ports.push(adapter_port);
+        }
+    }
+    Ok(())
+}
+
+fn adapter_port<B: Bmc>(adapter: &NetworkAdapter<B>, firmware_version: Option<String>) -> NicPort {
+    let hardware_id = adapter.hardware_id();
+    NicPort {
+        adapter: Some(adapter.odata_id().clone()),
+        manufacturer: hardware_id.manufacturer.map(|v| v.into_inner().to_owned()),
+        model: hardware_id.model.map(|v| v.into_inner().to_owned()),
+        firmware_version,
+        ..NicPort::default()
+    }
+}
+
+async fn pcie_firmware_versions<B: Bmc>(
+    chassis: &Chassis<B>,
+) -> Result<HashMap<ODataId, String>, Error<B>> {
+    let Some(devices) = chassis.pcie_devices().await? else {
+        return Ok(HashMap::new());
+    };
+    Ok(devices
+        .members()
+        .await?
+        .into_iter()
+        .filter_map(|device| {
+            let version = device.firmware_version()?.into_inner().to_owned();
+            Some((device.odata_id().clone(), version))
+        })
+        .collect())
+}
+
+fn attach_interface<B: Bmc>(
+    system: &ODataId,
+    interface: &EthernetInterface<B>,
+    ports: &mut Vec<NicPort>,
+) {
+    let index = ports
+        .iter()
+        .position(|port| port.ethernet_interface.is_none() && port.matches(interface));
+    let port = if let Some(index) = index {
+        ports.get_mut(index)
+    } else {
+        ports.push(NicPort::default());
+        ports.last_mut()
+    };
+    let Some(port) = port else {
+        return;
+    };
+    port.ethernet_interface = Some(interface.odata_id().clone());
+    port.system = Some(system.clone());
+    port.speed_mbps = interface.speed_mbps();
+    port.link_status = port
+        .link_status
+        .or_else(|| interface.link_status().and_then(interface_link_status));
+    port.mac_address = interface.mac_address().or_else(|| port.mac_address.take());
+    port.permanent_mac_address = port
+        .permanent_mac_address
+        .take()
+        .or_else(|| interface.permanent_mac_address());
+}
+
+/// Link status of the ethernet interface as link status of the port.
+const fn interface_link_status(status: InterfaceLinkStatus) -> Option<LinkStatus> {
+    match status {
+        InterfaceLinkStatus::LinkUp => Some(LinkStatus::LinkUp),
+        InterfaceLinkStatus::LinkDown => Some(LinkStatus::LinkDown),
+        InterfaceLinkStatus::NoLink => Some(LinkStatus::NoLink),
+        InterfaceLinkStatus::UnsupportedValue => None,
+    }
+}
+
+/// Parse reported MAC address. Empty value is not reported.
+fn parse_mac(mac: Option<MacAddress<'_>>) -> Option<Result<MacAddr, MacAddrParseError>> {
+    mac_address::parse_reported(mac?.as_str())
+}
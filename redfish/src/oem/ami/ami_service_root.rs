@@ -18,6 +18,7 @@
 use crate::core::Bmc;
 use crate::oem::ami::schema::ami_service_root::AmiServiceRoot as AmiServiceRootSchema;
 use crate::schema::service_root::ServiceRoot as ServiceRootSchema;
+use crate::schema_types;
 use crate::Error;
 use crate::NvBmc;
 use std::marker::PhantomData;
@@ -48,7 +49,8 @@ impl<B: Bmc> AmiServiceRoot<B> {
             .as_ref()
             .and_then(|oem| oem.additional_properties.get("Ami"))
         {
-            let data = Arc::new(serde_json::from_value(oem.clone()).map_err(Error::Json)?);
+            let data = schema_types::from_value(oem.clone())?;
+            let data = Arc::new(data);
             Ok(Some(Self {
                 data,
                 _marker: PhantomData,
@@ -32,6 +32,9 @@ use crate::Error;
 #[cfg(feature = "managers")]
 use crate::NvBmc;
 
+/// Attribute of iDRAC that reports System Lockdown mode.
+const SYSTEM_LOCKDOWN_ATTRIBUTE: &str = "Lockdown.1.SystemLockdown";
+
 /// Dell OEM Attributes.
 pub struct DellAttributes<B: Bmc> {
     data: Arc<DellAttributesSchema>,
@@ -87,6 +90,16 @@ impl<B: Bmc> DellAttributes<B> {
             .and_then(|attributes| attributes.dynamic_properties.get(name))
             .map(|v| DellAttributeRef::new(v.as_ref()))
     }
+
+    /// System Lockdown mode of iDRAC. When it is enabled, the
+    /// configuration of the server cannot be changed.
+    ///
+    /// Returns `None` when the attribute is not reported.
+    #[must_use]
+    pub fn system_lockdown(&self) -> Option<bool> {
+        let lockdown = self.attribute(SYSTEM_LOCKDOWN_ATTRIBUTE)?;
+        lockdown.str_value().map(|v| v == "Enabled")
+    }
 }
 
 /// Reference to a BIOS attribute.
@@ -17,6 +17,7 @@
 
 use crate::oem::hpe::schema::hpei_lo_service_ext::HpeiLoServiceExt as HpeiLoServiceExtSchema;
 use crate::schema::service_root::ServiceRoot as ServiceRootSchema;
+use crate::schema_types;
 use crate::Error;
 use nv_redfish_core::Bmc;
 use std::marker::PhantomData;
@@ -44,7 +45,8 @@ impl<B: Bmc> HpeiLoServiceExt<B> {
             .as_ref()
             .and_then(|oem| oem.additional_properties.get("Hpe"))
         {
-            let data = Arc::new(serde_json::from_value(oem.clone()).map_err(Error::Json)?);
+            let data = schema_types::from_value(oem.clone())?;
+            let data = Arc::new(data);
             Ok(Some(Self {
                 data,
                 _bmc: PhantomData,
@@ -17,6 +17,7 @@
 
 use crate::oem::hpe::schema::hpei_lo::HpeiLo as HpeManagerSchema;
 use crate::schema::manager::Manager as ManagerSchema;
+use crate::schema_types;
 use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
@@ -44,7 +45,8 @@ impl<B: Bmc> HpeManager<B> {
             .as_ref()
             .and_then(|oem| oem.additional_properties.get("Hpe"))
         {
-            let data = Arc::new(serde_json::from_value(oem.clone()).map_err(Error::Json)?);
+            let data = schema_types::from_value(oem.clone())?;
+            let data = Arc::new(data);
             Ok(Some(Self {
                 data,
                 _bmc: bmc.clone(),
@@ -65,4 +67,14 @@ impl<B: Bmc> HpeManager<B> {
     pub fn virtual_nic_enabled(&self) -> Option<bool> {
         self.data.virtual_nic_enabled
     }
+
+    /// Name of the installed iLO license, for example `iLO Advanced`
+    /// or `iLO Standard`.
+    #[must_use]
+    pub fn license_string(&self) -> Option<&str> {
+        self.data
+            .license
+            .as_ref()
+            .and_then(|license| license.license_string.as_deref())
+    }
 }
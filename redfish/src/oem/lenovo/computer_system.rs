@@ -18,6 +18,7 @@
 use crate::core::Bmc;
 use crate::oem::lenovo::schema::lenovo_computer_system::LenovoSystemProperties as LenovoSystemPropertiesSchema;
 use crate::schema::computer_system::ComputerSystem as ComputerSystemSchema;
+use crate::schema_types;
 use crate::Error;
 use crate::NvBmc;
 use std::convert::identity;
@@ -54,7 +55,8 @@ impl<B: Bmc> LenovoComputerSystem<B> {
             .as_ref()
             .and_then(|oem| oem.additional_properties.get("Lenovo"))
         {
-            let data = Arc::new(serde_json::from_value(oem.clone()).map_err(Error::Json)?);
+            let data = schema_types::from_value(oem.clone())?;
+            let data = Arc::new(data);
             Ok(Some(Self {
                 data,
                 _marker: PhantomData,
@@ -20,6 +20,7 @@ use crate::oem::lenovo::schema::lenovo_manager::v1_0_0::LenovoManagerProperties
 use crate::oem::lenovo::schema::lenovo_manager::LenovoManagerProperties as LenovoManagerPropertiesSchema;
 use crate::oem::lenovo::security_service::LenovoSecurityService;
 use crate::schema::manager::Manager as ManagerSchema;
+use crate::schema_types;
 use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
@@ -65,7 +66,8 @@ impl<B: Bmc> LenovoManager<B> {
             .as_ref()
             .and_then(|oem| oem.additional_properties.get("Lenovo"))
         {
-            let data = Arc::new(serde_json::from_value(oem.clone()).map_err(Error::Json)?);
+            let data = schema_types::from_value(oem.clone())?;
+            let data = Arc::new(data);
             Ok(Some(Self {
                 data,
                 bmc: bmc.clone(),
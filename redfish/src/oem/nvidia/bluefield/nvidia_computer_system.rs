@@ -15,6 +15,9 @@
 
 //! Support NVIDIA Bluefield ComputerSystem OEM extension.
 
+use crate::mac_address;
+use crate::mac_address::MacAddr;
+use crate::mac_address::MacAddrParseError;
 use crate::oem::nvidia::bluefield::schema::nvidia_computer_system::NvidiaComputerSystem as NvidiaComputerSystemSchema;
 use crate::patch_support::JsonValue;
 use crate::patch_support::Payload;
@@ -22,9 +25,9 @@ use crate::schema::resource::Oem as ResourceOemSchema;
 use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
 use serde::Deserialize;
-use std::marker::PhantomData;
 use std::sync::Arc;
 use tagged_types::TaggedType;
 
@@ -34,6 +37,8 @@ struct Oem {
     nvidia: Option<NavProperty<NvidiaComputerSystemSchema>>,
 }
 
+#[doc(inline)]
+pub use crate::oem::nvidia::bluefield::schema::nvidia_computer_system::HostPrivilegeLevel;
 #[doc(inline)]
 pub use crate::oem::nvidia::bluefield::schema::nvidia_computer_system::Mode;
 
@@ -50,8 +55,8 @@ pub enum BaseMacTag {}
 ///
 /// Provides access to system information and sub-resources such as processors.
 pub struct NvidiaComputerSystem<B: Bmc> {
+    bmc: NvBmc<B>,
     data: Arc<NvidiaComputerSystemSchema>,
-    _marker: PhantomData<B>,
 }
 
 impl<B: Bmc> NvidiaComputerSystem<B> {
@@ -75,8 +80,8 @@ impl<B: Bmc> NvidiaComputerSystem<B> {
             .await
             .map(|data| {
                 Some(Self {
+                    bmc: bmc.clone(),
                     data,
-                    _marker: PhantomData,
                 })
             })
         } else {
@@ -94,9 +99,18 @@ impl<B: Bmc> NvidiaComputerSystem<B> {
     }
 
     /// Get base MAC address of the device.
+    ///
+    /// Bluefield reports it without separators (`1070fd010203`), the
+    /// address is parsed so it compares and prints the same way as MAC
+    /// addresses reported by other resources. `Err` keeps the reported
+    /// value that is not a valid MAC address.
     #[must_use]
-    pub fn base_mac(&self) -> Option<BaseMac<&str>> {
-        self.data.base_mac.as_deref().map(BaseMac::new)
+    pub fn base_mac(&self) -> Option<Result<BaseMac<MacAddr>, MacAddrParseError>> {
+        self.data
+            .base_mac
+            .as_deref()
+            .and_then(mac_address::parse_reported)
+            .map(|mac| mac.map(BaseMac::new))
     }
 
     /// Get mode of the Bluefield device.
@@ -107,6 +121,71 @@ impl<B: Bmc> NvidiaComputerSystem<B> {
     pub fn mode(&self) -> Option<Mode> {
         self.data.mode
     }
+
+    /// Get privilege level of the host on the Bluefield device.
+    ///
+    /// `Restricted` means that the host cannot change firmware and
+    /// configuration of the device.
+    #[must_use]
+    pub fn host_privilege_level(&self) -> Option<HostPrivilegeLevel> {
+        self.data.host_privilege_level
+    }
+
+    /// Switch the Bluefield device to the requested mode.
+    ///
+    /// New mode takes effect after the next power cycle of the host.
+    /// Use [`Self::nic_firmware_reset`] or power cycle the host to
+    /// apply it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the device does not provide mode switching action
+    /// - the BMC returns an error for the action
+    pub async fn set_mode(&self, mode: Mode) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let actions = self
+            .data
+            .actions
+            .as_ref()
+            .filter(|actions| actions.set.is_some())
+            .ok_or(Error::ActionNotAvailable)?;
+
+        actions
+            .set(self.bmc.as_ref(), mode)
+            .await
+            .map_err(Error::Bmc)
+    }
+
+    /// Reset NIC firmware of the Bluefield device.
+    ///
+    /// Activates newly installed NIC firmware and configuration
+    /// (including pending mode change) without host power cycle.
+    /// The BMC may return a task that tracks the reset.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - the device does not provide NIC firmware reset action
+    /// - the BMC returns an error for the action
+    pub async fn nic_firmware_reset(&self) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        let actions = self
+            .data
+            .actions
+            .as_ref()
+            .filter(|actions| actions.nic_firmware_reset.is_some())
+            .ok_or(Error::ActionNotAvailable)?;
+
+        actions
+            .nic_firmware_reset(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+    }
 }
 
 // This patch is needed to fix response without `@odata.id` field.
@@ -0,0 +1,77 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support of NVIDIA HGX/DGX baseboard OEM extensions to Redfish.
+//!
+//! HGX baseboards extend GPU processors and GPU memory metrics with
+//! NVIDIA-specific data: Multi-Instance GPU mode, NVLink count and
+//! row-remapping counters. ECC mode of GPU memory is switched through
+//! the standard `MemorySummary` of the GPU processor and is exposed by
+//! `NvidiaGpu` to keep all GPU controls in one place.
+//!
+//! NVSwitch resources of HGX baseboards are published as switches of
+//! the NVLink fabric under `Fabrics` and are extended with isolation
+//! mode and NVLink count (requires `fabrics` feature).
+
+mod compiled_schema;
+
+#[cfg(any(feature = "processors", feature = "memory", feature = "fabrics"))]
+use crate::schema::resource::Oem as ResourceOemSchema;
+#[cfg(any(feature = "processors", feature = "memory", feature = "fabrics"))]
+use nv_redfish_core::odata::ODataType;
+
+/// NVIDIA HGX OEM Schema.
+pub use compiled_schema::redfish as schema;
+
+/// NVIDIA HGX GPU processor OEM support.
+#[cfg(feature = "processors")]
+pub mod nvidia_gpu;
+
+/// NVIDIA HGX GPU memory metrics OEM support.
+#[cfg(feature = "memory")]
+pub mod nvidia_gpu_memory_metrics;
+
+/// NVIDIA HGX NVSwitch OEM support.
+#[cfg(feature = "fabrics")]
+pub mod nvidia_switch;
+
+#[cfg(feature = "processors")]
+#[doc(inline)]
+pub use nvidia_gpu::NvidiaGpu;
+#[cfg(feature = "memory")]
+#[doc(inline)]
+pub use nvidia_gpu_memory_metrics::NvidiaGpuMemoryMetrics;
+#[cfg(feature = "memory")]
+#[doc(inline)]
+pub use nvidia_gpu_memory_metrics::RowRemappingCounters;
+#[cfg(feature = "fabrics")]
+#[doc(inline)]
+pub use nvidia_switch::NvidiaSwitch;
+#[cfg(feature = "fabrics")]
+#[doc(inline)]
+pub use nvidia_switch::SwitchIsolationMode;
+
+/// Check that `Oem.Nvidia` object has `@odata.type` of `namespace`
+/// and `type_name` (any version of namespace is accepted).
+#[cfg(any(feature = "processors", feature = "memory", feature = "fabrics"))]
+fn is_nvidia_oem_type(oem: &ResourceOemSchema, namespace: &str, type_name: &str) -> bool {
+    oem.additional_properties
+        .get("Nvidia")
+        .and_then(ODataType::parse_from)
+        .is_some_and(|odata_type| {
+            odata_type.type_name == type_name
+                && odata_type.namespace.first().copied() == Some(namespace)
+        })
+}
@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support NVIDIA HGX GPU processor OEM extension.
+
+use crate::computer_system::Processor;
+use crate::oem::nvidia::hgx::is_nvidia_oem_type;
+use crate::oem::nvidia::hgx::schema::nvidia_processor::NvidiaGpu as NvidiaGpuSchema;
+use crate::schema::processor::MemorySummaryUpdate;
+use crate::schema::processor::Processor as ProcessorSchema;
+use crate::schema::processor::ProcessorUpdate;
+use crate::Error;
+use crate::NvBmc;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
+use serde::Deserialize;
+use std::convert::identity;
+use std::sync::Arc;
+
+/// Represents a NVIDIA HGX extension of a GPU processor.
+///
+/// Provides access to NVIDIA-specific GPU data and to GPU memory ECC
+/// mode.
+pub struct NvidiaGpu<B: Bmc> {
+    bmc: NvBmc<B>,
+    processor: Arc<ProcessorSchema>,
+    data: Arc<NvidiaGpuSchema>,
+}
+
+impl<B: Bmc> NvidiaGpu<B> {
+    /// Create a new GPU handle.
+    ///
+    /// Returns `Ok(None)` when the processor does not include NVIDIA GPU
+    /// OEM data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing NVIDIA GPU OEM data fails.
+    pub(crate) fn new(
+        bmc: &NvBmc<B>,
+        processor: &Arc<ProcessorSchema>,
+    ) -> Result<Option<Self>, Error<B>> {
+        let Some(oem) = processor.base.base.oem.as_ref() else {
+            return Ok(None);
+        };
+        if !is_nvidia_oem_type(oem, "NvidiaProcessor", "NvidiaGPU") {
+            return Ok(None);
+        }
+        let oem: GpuOem =
+            serde_json::from_value(oem.additional_properties.clone()).map_err(Error::Json)?;
+        Ok(Some(Self {
+            bmc: bmc.clone(),
+            processor: processor.clone(),
+            data: oem.nvidia.into(),
+        }))
+    }
+
+    /// Get the raw schema data for this NVIDIA GPU.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<NvidiaGpuSchema> {
+        self.data.clone()
+    }
+
+    /// Indicates whether Multi-Instance GPU mode is enabled.
+    #[must_use]
+    pub fn mig_mode_enabled(&self) -> Option<bool> {
+        self.data.mig_mode_enabled.and_then(identity)
+    }
+
+    /// Indicates whether the PCIe reference clock is enabled.
+    #[must_use]
+    pub fn pcie_reference_clock_enabled(&self) -> Option<bool> {
+        self.data.pcie_reference_clock_enabled.and_then(identity)
+    }
+
+    /// The number of NVLink links of the GPU.
+    #[must_use]
+    pub fn nvlink_count(&self) -> Option<i64> {
+        self.data.nv_link_count.and_then(identity)
+    }
+
+    /// Indicates whether ECC mode of the GPU memory is enabled.
+    #[must_use]
+    pub fn ecc_mode_enabled(&self) -> Option<bool> {
+        self.processor
+            .memory_summary
+            .as_ref()
+            .and_then(|summary| summary.ecc_mode_enabled)
+            .and_then(identity)
+    }
+
+    /// Enable or disable ECC mode of the GPU memory.
+    ///
+    /// GPU applies new ECC mode after the next GPU reset. Returned
+    /// processor reflects the state reported by the BMC right after
+    /// the update.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if updating the processor fails.
+    pub async fn set_ecc_mode_enabled(
+        &self,
+        enabled: bool,
+    ) -> Result<ModificationResponse<Processor<B>>, Error<B>> {
+        let update = ProcessorUpdate::builder()
+            .with_memory_summary(
+                MemorySummaryUpdate::builder()
+                    .with_ecc_mode_enabled(enabled)
+                    .build(),
+            )
+            .build();
+        self.bmc
+            .as_ref()
+            .update::<_, NavProperty<ProcessorSchema>>(
+                self.processor.odata_id(),
+                self.processor.etag(),
+                &update,
+            )
+            .await
+            .map_err(|err| Error::update_failed(self.processor.odata_id(), err))?
+            .try_map_entity_async(|nav| async move { Processor::new(&self.bmc, &nav).await })
+            .await
+    }
+}
+
+#[derive(Deserialize)]
+struct GpuOem {
+    #[serde(rename = "Nvidia")]
+    nvidia: NvidiaGpuSchema,
+}
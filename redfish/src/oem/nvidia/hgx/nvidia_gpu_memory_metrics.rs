@@ -0,0 +1,109 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support NVIDIA HGX GPU memory metrics OEM extension.
+
+use crate::oem::nvidia::hgx::is_nvidia_oem_type;
+use crate::oem::nvidia::hgx::schema::nvidia_memory_metrics::NvidiaGpuMemoryMetrics as NvidiaGpuMemoryMetricsSchema;
+use crate::schema::memory_metrics::MemoryMetrics as MemoryMetricsSchema;
+use crate::Error;
+use nv_redfish_core::Bmc;
+use serde::Deserialize;
+use std::convert::identity;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// Row-remapping counters of the GPU memory.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RowRemappingCounters {
+    /// Rows remapped due to correctable errors.
+    pub correctable: Option<i64>,
+    /// Rows remapped due to uncorrectable errors.
+    pub uncorrectable: Option<i64>,
+}
+
+/// Represents a NVIDIA HGX extension of GPU memory metrics.
+pub struct NvidiaGpuMemoryMetrics<B: Bmc> {
+    data: Arc<NvidiaGpuMemoryMetricsSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> NvidiaGpuMemoryMetrics<B> {
+    /// Create a new GPU memory metrics handle.
+    ///
+    /// Returns `Ok(None)` when memory metrics do not include NVIDIA GPU
+    /// memory metrics OEM data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing NVIDIA GPU memory metrics OEM data fails.
+    pub(crate) fn new(metrics: &MemoryMetricsSchema) -> Result<Option<Self>, Error<B>> {
+        let Some(oem) = metrics.base.base.oem.as_ref() else {
+            return Ok(None);
+        };
+        if !is_nvidia_oem_type(oem, "NvidiaMemoryMetrics", "NvidiaGPUMemoryMetrics") {
+            return Ok(None);
+        }
+        let oem: MetricsOem =
+            serde_json::from_value(oem.additional_properties.clone()).map_err(Error::Json)?;
+        Ok(Some(Self {
+            data: oem.nvidia.into(),
+            _marker: PhantomData,
+        }))
+    }
+
+    /// Get the raw schema data for this NVIDIA GPU memory metrics.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<NvidiaGpuMemoryMetricsSchema> {
+        self.data.clone()
+    }
+
+    /// Indicates whether row remapping has failed.
+    #[must_use]
+    pub fn row_remapping_failed(&self) -> Option<bool> {
+        self.data.row_remapping_failed.and_then(identity)
+    }
+
+    /// Indicates whether row remapping is pending GPU reset.
+    #[must_use]
+    pub fn row_remapping_pending(&self) -> Option<bool> {
+        self.data.row_remapping_pending.and_then(identity)
+    }
+
+    /// Row-remapping counters.
+    ///
+    /// Returns `None` when the BMC does not report counters.
+    #[must_use]
+    pub fn row_remapping(&self) -> Option<RowRemappingCounters> {
+        self.data
+            .row_remapping
+            .as_ref()
+            .map(|counters| RowRemappingCounters {
+                correctable: counters.correctable_row_remapping_count.and_then(identity),
+                uncorrectable: counters
+                    .uncorrectable_row_remapping_count
+                    .and_then(identity),
+            })
+    }
+}
+
+#[derive(Deserialize)]
+struct MetricsOem {
+    #[serde(rename = "Nvidia")]
+    nvidia: NvidiaGpuMemoryMetricsSchema,
+}
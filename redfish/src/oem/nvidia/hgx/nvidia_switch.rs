@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Support NVIDIA HGX NVSwitch OEM extension.
+
+use crate::oem::nvidia::hgx::is_nvidia_oem_type;
+use crate::oem::nvidia::hgx::schema::nvidia_switch::NvidiaSwitch as NvidiaSwitchSchema;
+use crate::schema::switch::Switch as SwitchSchema;
+use crate::Error;
+use nv_redfish_core::Bmc;
+use serde::Deserialize;
+use std::convert::identity;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::oem::nvidia::hgx::schema::nvidia_switch::SwitchIsolationMode;
+
+/// Represents a NVIDIA HGX extension of a NVSwitch.
+pub struct NvidiaSwitch<B: Bmc> {
+    data: Arc<NvidiaSwitchSchema>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Bmc> NvidiaSwitch<B> {
+    /// Create a new NVSwitch handle.
+    ///
+    /// Returns `Ok(None)` when the switch does not include NVIDIA
+    /// NVSwitch OEM data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing NVIDIA NVSwitch OEM data fails.
+    pub(crate) fn new(switch: &SwitchSchema) -> Result<Option<Self>, Error<B>> {
+        let Some(oem) = switch.base.base.oem.as_ref() else {
+            return Ok(None);
+        };
+        if !is_nvidia_oem_type(oem, "NvidiaSwitch", "NvidiaSwitch") {
+            return Ok(None);
+        }
+        let oem: SwitchOem =
+            serde_json::from_value(oem.additional_properties.clone()).map_err(Error::Json)?;
+        Ok(Some(Self {
+            data: oem.nvidia.into(),
+            _marker: PhantomData,
+        }))
+    }
+
+    /// Get the raw schema data for this NVIDIA NVSwitch.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<NvidiaSwitchSchema> {
+        self.data.clone()
+    }
+
+    /// Isolation mode of the NVSwitch.
+    #[must_use]
+    pub fn isolation_mode(&self) -> Option<SwitchIsolationMode> {
+        self.data.switch_isolation_mode.and_then(identity)
+    }
+
+    /// Indicates whether the PCIe reference clock is enabled.
+    #[must_use]
+    pub fn pcie_reference_clock_enabled(&self) -> Option<bool> {
+        self.data.pcie_reference_clock_enabled.and_then(identity)
+    }
+
+    /// The number of NVLink links of the NVSwitch.
+    #[must_use]
+    pub fn nvlink_count(&self) -> Option<i64> {
+        self.data.nv_link_count.and_then(identity)
+    }
+}
+
+#[derive(Deserialize)]
+struct SwitchOem {
+    #[serde(rename = "Nvidia")]
+    nvidia: NvidiaSwitchSchema,
+}
@@ -20,3 +20,6 @@ pub mod bluefield;
 
 #[cfg(feature = "oem-nvidia-baseboard")]
 pub mod baseboard;
+
+#[cfg(feature = "oem-nvidia-hgx")]
+pub mod hgx;
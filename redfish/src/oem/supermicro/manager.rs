@@ -19,6 +19,7 @@ use crate::oem::supermicro::kcs_interface::KcsInterface;
 use crate::oem::supermicro::schema::smc_manager_extensions::Manager as SupermicroManagerSchema;
 use crate::oem::supermicro::sys_lockdown::SysLockdown;
 use crate::schema::manager::Manager as ManagerSchema;
+use crate::schema_types;
 use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
@@ -46,7 +47,8 @@ impl<B: Bmc> SupermicroManager<B> {
             .as_ref()
             .and_then(|oem| oem.additional_properties.get("Supermicro"))
         {
-            let data = Arc::new(serde_json::from_value(oem.clone()).map_err(Error::Json)?);
+            let data = schema_types::from_value(oem.clone())?;
+            let data = Arc::new(data);
             Ok(Some(Self {
                 bmc: bmc.clone(),
                 data,
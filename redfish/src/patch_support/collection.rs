@@ -25,10 +25,12 @@ use crate::NvBmc;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::Expandable;
+use nv_redfish_core::MembersCollection;
 use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataETag;
 use nv_redfish_core::ODataId;
 use serde::Deserialize;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 #[cfg(feature = "patch-collection-create")]
@@ -43,14 +45,25 @@ use serde::Serialize;
 /// BMC implementation produces payloads that are not aligned with the
 /// CSDL schema.
 ///
+/// Collections returned by the service in portions are read following
+/// `Members@odata.nextLink`, so members are never silently truncated.
+///
 /// Example of usage is in `AccountCollection` implementation.
 pub trait CollectionWithPatch<T, M, B>
 where
-    T: Expandable + 'static,
+    T: Expandable + MembersCollection + 'static,
     M: EntityTypeRef + for<'de> Deserialize<'de>,
     B: Bmc,
 {
-    fn convert_patched(base: ResourceCollection, members: Vec<NavProperty<M>>) -> T;
+    fn convert_patched(
+        base: ResourceCollection,
+        members: Vec<NavProperty<M>>,
+        members_count: Option<i64>,
+        next_link: Option<ODataId>,
+    ) -> T;
+
+    /// Members of `collection`.
+    fn collection_members(collection: &T) -> &[NavProperty<M>];
 
     async fn expand_collection(
         bmc: &NvBmc<B>,
@@ -62,16 +75,100 @@ where
             // Patches are not free so we keep separate branch for
             // patched collections only having this cost on systems
             // that requires to pay the price.
-            let patched_collection_ref = NavProperty::<Collection>::new_reference(nav.id().clone());
-            let collection = bmc.expand_property(&patched_collection_ref).await?;
-            let patch_fn = patch_fn.map(AsRef::as_ref);
-            let filter_fn = filter_fn.map(AsRef::as_ref);
-            let members = collection.members(patch_fn, filter_fn)?;
-            Ok(Arc::new(Self::convert_patched(collection.base(), members)))
+            Self::expand_pages(bmc, nav, patch_fn, filter_fn).await
         } else {
-            bmc.expand_property(nav).await
+            let collection = bmc.expand_property(nav).await?;
+            let Some(next_link) = collection.next_link().cloned() else {
+                return Ok(collection);
+            };
+            // Service returned only the first portion of the
+            // members. The rest of pages are joined using the patched
+            // collection representation because members of the
+            // generated one cannot be moved out of `Arc`.
+            let first_page = Self::collection_members(&collection).to_vec();
+            let (mut base, members, members_count) =
+                read_pages(bmc, nav.id(), next_link, first_page, None, None).await?;
+            base.base.odata_etag = collection.etag().cloned();
+            Ok(Arc::new(Self::convert_patched(
+                base,
+                members,
+                members_count.or_else(|| collection.members_count()),
+                None,
+            )))
+        }
+    }
+
+    /// Expand the collection following `Members@odata.nextLink` and
+    /// join members of all pages.
+    async fn expand_pages(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<T>,
+        patch_fn: Option<&ReadPatchFn>,
+        filter_fn: Option<&FilterFn>,
+    ) -> Result<Arc<T>, Error<B>> {
+        let (base, members, members_count) = read_pages(
+            bmc,
+            nav.id(),
+            nav.id().clone(),
+            Vec::new(),
+            patch_fn,
+            filter_fn,
+        )
+        .await?;
+        Ok(Arc::new(Self::convert_patched(
+            base,
+            members,
+            members_count,
+            None,
+        )))
+    }
+}
+
+/// Maximum number of pages of a collection that are read following
+/// `Members@odata.nextLink`.
+const MAX_PAGES: usize = 1024;
+
+// Read pages of collection `id` starting from `link` and join their
+// members with `members` that were read before. Base of the joined
+// collection is taken from the first page that is read. A link to the
+// collection itself or to a page that was already read ends the
+// collection.
+async fn read_pages<M, B>(
+    bmc: &NvBmc<B>,
+    id: &ODataId,
+    link: ODataId,
+    mut members: Vec<NavProperty<M>>,
+    patch_fn: Option<&ReadPatchFn>,
+    filter_fn: Option<&FilterFn>,
+) -> Result<(ResourceCollection, Vec<NavProperty<M>>, Option<i64>), Error<B>>
+where
+    M: EntityTypeRef + for<'de> Deserialize<'de>,
+    B: Bmc,
+{
+    let patch_fn = patch_fn.map(AsRef::as_ref);
+    let filter_fn = filter_fn.map(AsRef::as_ref);
+    let first = bmc
+        .expand_property(&NavProperty::<Collection>::new_reference(link.clone()))
+        .await?;
+    members.extend(first.members(patch_fn, filter_fn)?);
+    let mut visited = HashSet::from([id.clone(), link]);
+    let mut next_link = first.next_link.clone();
+    let mut pages = 1;
+    while let Some(link) = next_link {
+        if !visited.insert(link.clone()) {
+            break;
+        }
+        pages += 1;
+        if pages > MAX_PAGES {
+            return Err(Error::CollectionTooManyPages(id.clone()));
         }
+        let page = bmc
+            .expand_property(&NavProperty::<Collection>::new_reference(link))
+            .await?;
+        members.extend(page.members(patch_fn, filter_fn)?);
+        next_link = page.next_link.clone();
     }
+    Ok((first.base(id), members, first.members_count))
 }
 
 /// Trait that allows creating a collection member and patching the
@@ -114,6 +211,10 @@ struct Collection {
     base: ResourceCollection,
     #[serde(rename = "Members")]
     members: Vec<Payload>,
+    #[serde(rename = "Members@odata.count", default)]
+    members_count: Option<i64>,
+    #[serde(rename = "Members@odata.nextLink", default)]
+    next_link: Option<ODataId>,
 }
 
 impl Collection {
@@ -140,11 +241,18 @@ impl Collection {
         .try_map_entity(|payload| payload.to_target::<V, B, _>(&f))
     }
 
-    fn base(&self) -> ResourceCollection {
+    // Base of collection `id` that this page belongs to. ETag of a
+    // page other than the collection itself is not kept.
+    fn base(&self, id: &ODataId) -> ResourceCollection {
         ResourceCollection {
             base: ItemOrCollection {
-                odata_id: self.base.base.odata_id.clone(),
-                odata_etag: self.base.base.odata_etag.clone(),
+                odata_id: id.clone(),
+                odata_etag: self
+                    .base
+                    .base
+                    .odata_etag
+                    .clone()
+                    .filter(|_| &self.base.base.odata_id == id),
                 // Don't support `@Redfish.Settings /
                 // @Redfish.SettingsApplyTime` for patched
                 // collection...
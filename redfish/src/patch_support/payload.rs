@@ -14,6 +14,7 @@
 // limitations under the License.
 
 use crate::patch_support::JsonValue;
+use crate::schema_types;
 use crate::Error;
 use nv_redfish_core::Bmc;
 use serde::Deserialize;
@@ -66,7 +67,7 @@ where
             self.entity_ref()
                 .update(self.bmc(), update)
                 .await
-                .map_err(Error::Bmc)
+                .map_err(|err| Error::update_failed(self.entity_ref().odata_id(), err))
         }
     }
 }
@@ -118,7 +119,7 @@ impl Payload {
             // Do not apply patches to the references.
             serde_json::from_value(self.0.clone()).map_err(Error::Json)
         } else {
-            serde_json::from_value(f(self.0.clone())).map_err(Error::Json)
+            schema_types::from_value(f(self.0.clone()))
         }
     }
 
@@ -128,7 +129,7 @@ impl Payload {
         T: for<'de> Deserialize<'de>,
         B: Bmc,
     {
-        serde_json::from_value(self.0.clone()).map_err(Error::Json)
+        schema_types::from_value(self.0.clone())
     }
 
     /// Apply function `f` to the payload and then try to deserialize to the
@@ -206,7 +207,7 @@ impl Updator<'_> {
     {
         bmc.update::<U, Payload>(self.odata_id(), self.etag(), update)
             .await
-            .map_err(Error::Bmc)?
+            .map_err(|err| Error::update_failed(self.odata_id(), err))?
             .try_map_entity(|payload| payload.to_target::<T, B, _>(&patch_fn))
     }
 }
@@ -16,6 +16,7 @@
 //! PCIe devices
 //!
 
+use crate::durable_id::DurableId;
 use crate::hardware_id::HardwareIdRef;
 use crate::hardware_id::Manufacturer as HardwareIdManufacturer;
 use crate::hardware_id::Model as HardwareIdModel;
@@ -24,16 +25,27 @@ use crate::hardware_id::SerialNumber as HardwareIdSerialNumber;
 use crate::schema::pcie_device::PcieDevice as PcieDeviceSchema;
 #[cfg(feature = "chassis")]
 use crate::schema::pcie_device_collection::PcieDeviceCollection as PcieDeviceCollectionSchema;
-#[cfg(feature = "chassis")]
+#[cfg(any(
+    feature = "chassis",
+    feature = "graphics-controllers",
+    feature = "usb-controllers"
+))]
 use crate::Error;
-#[cfg(feature = "chassis")]
+#[cfg(any(
+    feature = "chassis",
+    feature = "graphics-controllers",
+    feature = "usb-controllers"
+))]
 use crate::NvBmc;
 use crate::Resource;
-use crate::ResourceProvidesStatus;
 use crate::ResourceSchema;
 use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
-#[cfg(feature = "chassis")]
+#[cfg(any(
+    feature = "chassis",
+    feature = "graphics-controllers",
+    feature = "usb-controllers"
+))]
 use nv_redfish_core::NavProperty;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -68,11 +80,9 @@ impl<B: Bmc> PcieDeviceCollection<B> {
     ///
     /// Returns an error if fetching manager data fails.
     pub async fn members(&self) -> Result<Vec<PcieDevice<B>>, Error<B>> {
-        let mut members = Vec::new();
-        for m in &self.collection.members {
-            members.push(PcieDevice::new(&self.bmc, m).await?);
-        }
-        Ok(members)
+        self.bmc
+            .fan_out(&self.collection.members, |m| PcieDevice::new(&self.bmc, m))
+            .await
     }
 }
 
@@ -110,7 +120,11 @@ pub struct PcieDevice<B: Bmc> {
 
 impl<B: Bmc> PcieDevice<B> {
     /// Create a new log service handle.
-    #[cfg(feature = "chassis")]
+    #[cfg(any(
+        feature = "chassis",
+        feature = "graphics-controllers",
+        feature = "usb-controllers"
+    ))]
     pub(crate) async fn new(
         bmc: &NvBmc<B>,
         nav: &NavProperty<PcieDeviceSchema>,
@@ -161,6 +175,14 @@ impl<B: Bmc> PcieDevice<B> {
         }
     }
 
+    /// Durable identifier of the PCIe device: manufacturer and serial
+    /// number, or normalized `@odata.id` if serial number is not
+    /// reported.
+    #[must_use]
+    pub fn durable_id(&self) -> DurableId {
+        DurableId::new(None, &self.hardware_id(), self.odata_id())
+    }
+
     /// The version of firmware for this PCIe device.
     #[must_use]
     pub fn firmware_version(&self) -> Option<FirmwareVersion<&str>> {
@@ -174,12 +196,16 @@ impl<B: Bmc> PcieDevice<B> {
 }
 
 impl<B: Bmc> Resource for PcieDevice<B> {
+    type Entity = PcieDeviceSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
-}
 
-impl<B: Bmc> ResourceProvidesStatus for PcieDevice<B> {
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
     fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
         self.data.status.as_ref()
     }
@@ -26,6 +26,8 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use crate::ServiceRoot;
 use nv_redfish_core::Bmc;
 use std::marker::PhantomData;
@@ -88,9 +90,20 @@ impl<B: Bmc> PowerEquipment<B> {
 }
 
 impl<B: Bmc> Resource for PowerEquipment<B> {
+    type Entity = PowerEquipmentSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
 
 /// Power shelf collection.
@@ -160,7 +173,18 @@ impl<B: Bmc> PowerShelf<B> {
 }
 
 impl<B: Bmc> Resource for PowerShelf<B> {
+    type Entity = PowerDistributionSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -0,0 +1,589 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Capabilities declared by the endpoint
+//!
+//! [`ProbingBmc`] wraps any [`Bmc`] and keeps a map of operations that
+//! the endpoint declares for its resources:
+//! - HTTP methods from the `Allow` header and apply times from
+//!   `@Redfish.OperationApplyTimeSupport` annotations of the resource
+//!   and of its actions, recorded by [`ProbingBmc::probe`];
+//! - methods rejected by the service with HTTP 405 (Method Not
+//!   Allowed) or 501 (Not Implemented).
+//!
+//! Modifications that the endpoint declared unsupported are rejected
+//! with [`ProbingError::NotSupportedByEndpoint`] before anything is sent to
+//! the BMC, so high-level wrappers built on top of it do not issue
+//! requests that are known to fail. Resources that were not probed are
+//! not restricted until the service rejects a method.
+//!
+//! ```ignore
+//! let bmc = Arc::new(ProbingBmc::new(Arc::new(http_bmc)));
+//! bmc.probe(&system_id).await?;
+//! let root = ServiceRoot::new(bmc).await?;
+//! ```
+
+use crate::Error;
+use futures_util::TryStreamExt as _;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::query::FilterQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::ActionError;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::BinaryUploadRequest;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ETagPolicy;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::HttpMethod;
+use nv_redfish_core::LatencyClass;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::NullPolicy;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::RedfishError;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::TaskMonitorResponse;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::to_value;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+
+/// HTTP status codes that are reported by the Redfish service when
+/// the method is not supported by the resource.
+const NOT_SUPPORTED_STATUS_CODES: [u16; 2] = [405, 501];
+
+/// Annotation of the request payload that asks for the apply time of
+/// the operation.
+const OPERATION_APPLY_TIME: &str = "@Redfish.OperationApplyTime";
+
+/// Error of [`ProbingBmc`].
+#[derive(Debug)]
+pub enum ProbingError<E> {
+    /// Error of the wrapped BMC.
+    Bmc(E),
+    /// Endpoint declared that it doesn't support the operation on the
+    /// resource.
+    NotSupportedByEndpoint {
+        /// Resource or action target of the operation.
+        id: ODataId,
+        /// HTTP method of the operation.
+        method: HttpMethod,
+        /// Requested apply time if it is not supported by the
+        /// resource.
+        apply_time: Option<String>,
+    },
+}
+
+impl<E: Display> Display for ProbingError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Bmc(err) => write!(f, "BMC error: {err}"),
+            Self::NotSupportedByEndpoint {
+                id,
+                method,
+                apply_time,
+            } => {
+                write!(f, "{method} of {id} is not supported by the endpoint")?;
+                if let Some(apply_time) = apply_time {
+                    write!(f, " with apply time {apply_time}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<E: StdError + 'static> StdError for ProbingError<E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Bmc(err) => Some(err),
+            Self::NotSupportedByEndpoint { .. } => None,
+        }
+    }
+}
+
+impl<E: ActionError> ActionError for ProbingError<E> {
+    fn not_supported() -> Self {
+        Self::Bmc(E::not_supported())
+    }
+}
+
+/// Operations that the endpoint declares for a resource.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EndpointCapabilities {
+    /// Methods allowed on the resource. `None` if the endpoint
+    /// didn't declare them.
+    pub methods: Option<AllowedMethods>,
+    /// Values of `@Redfish.OperationApplyTime` accepted by create and
+    /// action requests (for example, `Immediate`, `OnReset`). `None`
+    /// if the endpoint didn't declare them.
+    pub apply_times: Option<Vec<String>>,
+}
+
+impl EndpointCapabilities {
+    fn check<E>(
+        &self,
+        id: &ODataId,
+        method: HttpMethod,
+        apply_time: Option<&str>,
+    ) -> Result<(), ProbingError<E>> {
+        let method_allowed = self.methods.is_none_or(|methods| methods.contains(method));
+        let apply_time_supported = match (apply_time, &self.apply_times) {
+            (Some(apply_time), Some(supported)) => supported.iter().any(|v| v == apply_time),
+            _ => true,
+        };
+        if method_allowed && apply_time_supported {
+            Ok(())
+        } else {
+            Err(ProbingError::NotSupportedByEndpoint {
+                id: id.clone(),
+                method,
+                apply_time: apply_time.map(ToOwned::to_owned),
+            })
+        }
+    }
+}
+
+/// BMC that avoids operations the endpoint declares unsupported.
+pub struct ProbingBmc<B: Bmc> {
+    bmc: Arc<B>,
+    capabilities: RwLock<HashMap<ODataId, EndpointCapabilities>>,
+}
+
+impl<B: Bmc> ProbingBmc<B> {
+    /// Create probing BMC on top of `bmc`.
+    #[must_use]
+    pub fn new(bmc: Arc<B>) -> Self {
+        Self {
+            bmc,
+            capabilities: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Wrapped BMC.
+    #[must_use]
+    pub const fn inner(&self) -> &Arc<B> {
+        &self.bmc
+    }
+
+    /// Capabilities recorded for the resource `id`. `None` if the
+    /// resource was not probed and no method was rejected on it.
+    #[must_use]
+    pub fn capabilities(&self, id: &ODataId) -> Option<EndpointCapabilities> {
+        self.capabilities
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(id)
+            .cloned()
+    }
+
+    /// Probe the resource `id`: read methods allowed by the endpoint
+    /// and apply times supported by the resource and its actions, and
+    /// record them in the capability map.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the resource cannot be retrieved.
+    pub async fn probe(&self, id: &ODataId) -> Result<EndpointCapabilities, Error<B>> {
+        let methods = self.bmc.allowed_methods(id).await.map_err(Error::Bmc)?;
+        let resource = self
+            .bmc
+            .get::<ProbedResource>(id)
+            .await
+            .map_err(Error::Bmc)?;
+        let capabilities = EndpointCapabilities {
+            methods,
+            apply_times: resource
+                .apply_time_support
+                .as_ref()
+                .map(|v| v.supported_values.clone()),
+        };
+
+        let mut map = self
+            .capabilities
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        for action in resource.actions.values() {
+            if let (Some(target), Some(support)) = (&action.target, &action.apply_time_support) {
+                map.entry(target.clone()).or_default().apply_times =
+                    Some(support.supported_values.clone());
+            }
+        }
+        map.insert(id.clone(), capabilities.clone());
+        drop(map);
+        Ok(capabilities)
+    }
+
+    fn check<V: Serialize>(
+        &self,
+        id: &ODataId,
+        method: HttpMethod,
+        body: Option<&V>,
+    ) -> Result<(), ProbingError<B::Error>> {
+        let Some(capabilities) = self.capabilities(id) else {
+            return Ok(());
+        };
+        // Request body is inspected only when the endpoint declared
+        // apply times of the resource.
+        let apply_time = capabilities
+            .apply_times
+            .as_ref()
+            .and(body)
+            .and_then(|body| to_value(body).ok())
+            .and_then(|body| {
+                body.get(OPERATION_APPLY_TIME)
+                    .and_then(JsonValue::as_str)
+                    .map(ToOwned::to_owned)
+            });
+        capabilities.check(id, method, apply_time.as_deref())
+    }
+
+    /// Convert rejection of the method by the service to
+    /// [`ProbingError::NotSupportedByEndpoint`] and record it.
+    fn rejected(
+        &self,
+        id: &ODataId,
+        method: HttpMethod,
+        error: B::Error,
+    ) -> ProbingError<B::Error> {
+        if !B::error_status_code(&error).is_some_and(|s| NOT_SUPPORTED_STATUS_CODES.contains(&s)) {
+            return ProbingError::Bmc(error);
+        }
+        let mut map = self
+            .capabilities
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        let capabilities = map.entry(id.clone()).or_default();
+        capabilities.methods = Some(
+            capabilities
+                .methods
+                .unwrap_or_else(AllowedMethods::all)
+                .without(method),
+        );
+        drop(map);
+        ProbingError::NotSupportedByEndpoint {
+            id: id.clone(),
+            method,
+            apply_time: None,
+        }
+    }
+}
+
+impl<B: Bmc + 'static> Bmc for ProbingBmc<B>
+where
+    B::Error: 'static,
+{
+    type Error = ProbingError<B::Error>;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.bmc.expand(id, query).await.map_err(ProbingError::Bmc)
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.bmc.get(id).await.map_err(ProbingError::Bmc)
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        self.bmc
+            .get_if_none_match(id, etag)
+            .await
+            .map_err(ProbingError::Bmc)
+    }
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.bmc.filter(id, query).await.map_err(ProbingError::Bmc)
+    }
+
+    async fn create<V: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.check(id, HttpMethod::Post, Some(query))?;
+        self.bmc
+            .create(id, query)
+            .await
+            .map_err(|err| self.rejected(id, HttpMethod::Post, err))
+    }
+
+    async fn create_session<
+        V: Send + Sync + Serialize,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<SessionCreateResponse<R>, Self::Error> {
+        self.bmc
+            .create_session(id, query)
+            .await
+            .map_err(ProbingError::Bmc)
+    }
+
+    async fn update<
+        V: Sync + Send + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.check(id, HttpMethod::Patch, None::<&V>)?;
+        self.bmc
+            .update(id, etag, update)
+            .await
+            .map_err(|err| self.rejected(id, HttpMethod::Patch, err))
+    }
+
+    async fn delete<R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
+        &self,
+        id: &ODataId,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.check(id, HttpMethod::Delete, None::<&()>)?;
+        self.bmc
+            .delete(id)
+            .await
+            .map_err(|err| self.rejected(id, HttpMethod::Delete, err))
+    }
+
+    async fn allowed_methods(&self, id: &ODataId) -> Result<Option<AllowedMethods>, Self::Error> {
+        self.bmc
+            .allowed_methods(id)
+            .await
+            .map_err(ProbingError::Bmc)
+    }
+
+    async fn action<
+        T: Send + Sync + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        action: &Action<T, R>,
+        params: &T,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        let id = ODataId::from(action.target.as_str().to_owned());
+        self.check(&id, HttpMethod::Post, Some(params))?;
+        self.bmc
+            .action(action, params)
+            .await
+            .map_err(|err| self.rejected(&id, HttpMethod::Post, err))
+    }
+
+    async fn multipart_update<U, V, R>(
+        &self,
+        uri: &str,
+        request: MultipartUpdateRequest<'_, U, V>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+        V: Send + Sync + Serialize,
+    {
+        let id = ODataId::from(uri.to_owned());
+        self.check(&id, HttpMethod::Post, None::<&V>)?;
+        self.bmc
+            .multipart_update(uri, request)
+            .await
+            .map_err(|err| self.rejected(&id, HttpMethod::Post, err))
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn http_push_uri_update<U, R>(
+        &self,
+        uri: &str,
+        request: HttpPushUriUpdateRequest<U>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        let id = ODataId::from(uri.to_owned());
+        self.check(&id, HttpMethod::Post, None::<&()>)?;
+        self.bmc
+            .http_push_uri_update(uri, request)
+            .await
+            .map_err(|err| self.rejected(&id, HttpMethod::Post, err))
+    }
+
+    async fn binary_download(&self, uri: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.bmc
+            .binary_download(uri)
+            .await
+            .map_err(ProbingError::Bmc)
+    }
+
+    async fn get_json<T>(&self, uri: &str) -> Result<T, Self::Error>
+    where
+        T: for<'de> Deserialize<'de> + Clone + Send + Sync + 'static,
+    {
+        self.bmc.get_json(uri).await.map_err(ProbingError::Bmc)
+    }
+
+    async fn task_monitor(&self, uri: &str) -> Result<Option<TaskMonitorResponse>, Self::Error> {
+        self.bmc.task_monitor(uri).await.map_err(ProbingError::Bmc)
+    }
+
+    async fn binary_upload<U, R>(
+        &self,
+        uri: &str,
+        request: BinaryUploadRequest<U>,
+    ) -> Result<Option<ModificationResponse<R>>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        let id = ODataId::from(uri.to_owned());
+        self.check(&id, HttpMethod::Put, None::<&()>)?;
+        self.bmc
+            .binary_upload(uri, request)
+            .await
+            .map_err(|err| self.rejected(&id, HttpMethod::Put, err))
+    }
+
+    fn set_etag_policy(&self, policy: ETagPolicy) {
+        self.bmc.set_etag_policy(policy);
+    }
+
+    fn set_null_policy(&self, policy: NullPolicy) {
+        self.bmc.set_null_policy(policy);
+    }
+
+    fn invalidate_cache(&self) {
+        self.bmc.invalidate_cache();
+    }
+
+    fn cache_entity<T: EntityTypeRef + 'static>(&self, entity: &Arc<T>) {
+        self.bmc.cache_entity(entity);
+    }
+
+    fn latency_class(&self) -> Option<LatencyClass> {
+        self.bmc.latency_class()
+    }
+
+    fn error_status_code(error: &Self::Error) -> Option<u16> {
+        if let ProbingError::Bmc(error) = error {
+            B::error_status_code(error)
+        } else {
+            None
+        }
+    }
+
+    fn error_response_body(error: &Self::Error) -> Option<&str> {
+        if let ProbingError::Bmc(error) = error {
+            B::error_response_body(error)
+        } else {
+            None
+        }
+    }
+
+    fn error_response_language(error: &Self::Error) -> Option<&str> {
+        if let ProbingError::Bmc(error) = error {
+            B::error_response_language(error)
+        } else {
+            None
+        }
+    }
+
+    fn error_odata_type(error: &Self::Error) -> Option<&str> {
+        if let ProbingError::Bmc(error) = error {
+            B::error_odata_type(error)
+        } else {
+            None
+        }
+    }
+
+    fn redfish_error(error: &Self::Error) -> Option<RedfishError> {
+        if let ProbingError::Bmc(error) = error {
+            B::redfish_error(error)
+        } else {
+            None
+        }
+    }
+
+    async fn stream<T: Sized + for<'de> Deserialize<'de> + Send + 'static>(
+        &self,
+        uri: &str,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        let stream = self.bmc.stream(uri).await.map_err(ProbingError::Bmc)?;
+        Ok(Box::pin(stream.map_err(ProbingError::Bmc)))
+    }
+}
+
+/// Part of the resource payload that declares capabilities.
+#[derive(Deserialize)]
+struct ProbedResource {
+    #[serde(rename = "@odata.id")]
+    odata_id: ODataId,
+    #[serde(rename = "@Redfish.OperationApplyTimeSupport")]
+    apply_time_support: Option<ApplyTimeSupport>,
+    // Includes `Oem` object that is not an action; it has no target
+    // and is skipped.
+    #[serde(rename = "Actions", default)]
+    actions: HashMap<String, ProbedAction>,
+}
+
+impl EntityTypeRef for ProbedResource {
+    fn odata_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
+#[derive(Deserialize)]
+struct ProbedAction {
+    target: Option<ODataId>,
+    #[serde(rename = "@Redfish.OperationApplyTimeSupport")]
+    apply_time_support: Option<ApplyTimeSupport>,
+}
+
+#[derive(Deserialize)]
+struct ApplyTimeSupport {
+    #[serde(rename = "SupportedValues", default)]
+    supported_values: Vec<String>,
+}
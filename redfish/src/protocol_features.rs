@@ -14,17 +14,30 @@
 // limitations under the License.
 
 //! Redfish protocol features
+//!
+//! Features advertised by the service in `ProtocolFeaturesSupported`
+//! of the service root. Callers can consult them before constructing
+//! queries to know what the endpoint supports.
 
 use crate::schema::service_root::Expand;
 use crate::schema::service_root::ProtocolFeaturesSupported;
 use std::convert::identity;
+use std::convert::TryFrom as _;
 
 /// Defines features supported by Redfish protocol. Provides helpers
 /// to write code that takes features in account.
-#[derive(Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ProtocolFeatures {
     /// Expand query features support.
     pub expand: ExpandQueryFeatures,
+    /// Indicates `$filter` support by the Server.
+    pub filter: bool,
+    /// Indicates `only` support by the Server.
+    pub only_member: bool,
+    /// Indicates `$select` support by the Server.
+    pub select: bool,
+    /// Indicates `$top` and `$skip` support by the Server.
+    pub top_skip: bool,
 }
 
 impl ProtocolFeatures {
@@ -36,16 +49,68 @@ impl ProtocolFeatures {
                 .as_ref()
                 .map(ExpandQueryFeatures::new)
                 .unwrap_or_default(),
+            filter: f.filter_query.is_some_and(identity),
+            only_member: f.only_member_query.is_some_and(identity),
+            select: f.select_query.is_some_and(identity),
+            top_skip: f.top_skip_query.is_some_and(identity),
         }
     }
+
+    /// Check if `$expand` query with `$levels` equal to `n` is
+    /// supported by the Server.
+    ///
+    /// Single level is supported if any form of expand is
+    /// supported. More levels require `Levels` support and `n` not
+    /// exceeding `MaxLevels`.
+    #[must_use]
+    pub fn supports_expand_levels(&self, n: u64) -> bool {
+        let expand = &self.expand;
+        if n <= 1 {
+            expand.expand_all || expand.no_links || expand.links
+        } else {
+            expand.levels && expand.max_levels.is_some_and(|max| n <= max)
+        }
+    }
+
+    /// Check if `$filter` query is supported by the Server.
+    #[must_use]
+    pub const fn supports_filter(&self) -> bool {
+        self.filter
+    }
+
+    /// Check if `only` query is supported by the Server.
+    #[must_use]
+    pub const fn supports_only(&self) -> bool {
+        self.only_member
+    }
+
+    /// Check if `$select` query is supported by the Server.
+    #[must_use]
+    pub const fn supports_select(&self) -> bool {
+        self.select
+    }
+
+    /// Check if `$top` and `$skip` queries are supported by the
+    /// Server.
+    #[must_use]
+    pub const fn supports_top_skip(&self) -> bool {
+        self.top_skip
+    }
 }
 
 /// Expand query support.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ExpandQueryFeatures {
     /// Indicates '*' support by the Server.
     pub expand_all: bool,
     /// Indicates '.' support by the Server.
     pub no_links: bool,
+    /// Indicates '~' support by the Server.
+    pub links: bool,
+    /// Indicates `$levels` support by the Server.
+    pub levels: bool,
+    /// Maximum value of `$levels` supported by the Server.
+    pub max_levels: Option<u64>,
 }
 
 // We want to have explicit defaults. Not language one. They are the
@@ -56,15 +121,21 @@ impl Default for ExpandQueryFeatures {
         Self {
             expand_all: false,
             no_links: false,
+            links: false,
+            levels: false,
+            max_levels: None,
         }
     }
 }
 
 impl ExpandQueryFeatures {
-    pub fn new(f: &Expand) -> Self {
+    pub(crate) fn new(f: &Expand) -> Self {
         Self {
             expand_all: f.expand_all.is_some_and(identity),
             no_links: f.no_links.is_some_and(identity),
+            links: f.links.is_some_and(identity),
+            levels: f.levels.is_some_and(identity),
+            max_levels: f.max_levels.and_then(|v| u64::try_from(v).ok()),
         }
     }
 }
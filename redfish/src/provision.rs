@@ -0,0 +1,383 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Out-of-box provisioning of a BMC.
+//!
+//! [`ProvisionPlan`] performs the first-contact sequence of a new BMC
+//! using typed APIs of this crate. Canonical order of steps is:
+//! 1. Change the default password ([`ProvisionPlan::change_password`]).
+//! 2. Set host name, NTP and network protocol policy
+//!    ([`ProvisionPlan::network_protocol`]).
+//! 3. Upload TLS certificate of the HTTPS service
+//!    ([`ProvisionPlan::https_certificate`]).
+//! 4. Create service account for automation
+//!    ([`ProvisionPlan::service_account`]).
+//! 5. Configure event subscription
+//!    ([`ProvisionPlan::event_subscription`]).
+//!
+//! Steps are executed in the order they were added to the plan.
+//! Execution stops at the first failed step and every executed step
+//! is reported in [`ProvisionReport`].
+//!
+//! Steps are idempotent: certificates, service accounts and
+//! subscriptions that already exist are reported as
+//! [`StepOutcome::AlreadyApplied`], and updates are safe to repeat.
+//! A failed run can be continued with [`ProvisionPlan::resume`] that
+//! skips steps completed by the previous run. This matters for the
+//! password change: it cannot be detected and services with password
+//! history may reject repeated change.
+//!
+//! Note that the BMC client must stay authenticated after the
+//! password change: use session authentication or update credentials
+//! of the client before the following steps.
+//!
+//! ```ignore
+//! let plan = ProvisionPlan::new()
+//!     .change_password("root".into(), new_password)
+//!     .network_protocol(
+//!         ManagerNetworkProtocolUpdate::builder()
+//!             .with_host_name("bmc-r12-u07".into())
+//!             .build(),
+//!     )
+//!     .service_account("automation".into(), "Administrator".into(), policy);
+//! let report = plan.run(&root, &manager).await;
+//! if let Some((step, err)) = &report.failure {
+//!     // Fix the problem, then `plan.resume(&root, &manager, report.completed_steps())`.
+//! }
+//! ```
+
+use crate::account::Account;
+use crate::account::ServiceAccountPasswordPolicy;
+use crate::event_service::EventDestinationCreate;
+use crate::manager::CertificateCreate;
+use crate::manager::Manager;
+use crate::manager::ManagerNetworkProtocol;
+use crate::manager::ManagerNetworkProtocolUpdate;
+use crate::Error;
+use crate::ServiceRoot;
+use nv_redfish_core::Bmc;
+
+/// Kind of a provisioning step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProvisionStepKind {
+    /// Change password of an existing account.
+    ChangePassword,
+    /// Update of the manager network protocol resource.
+    NetworkProtocol,
+    /// Upload of a TLS certificate of the HTTPS service.
+    HttpsCertificate,
+    /// Creation of a service account.
+    ServiceAccount,
+    /// Creation of an event subscription.
+    EventSubscription,
+}
+
+/// Outcome of a provisioning step.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// Step modified the service.
+    Applied,
+    /// Service is already in the state requested by the step.
+    AlreadyApplied,
+    /// Step was completed by a previous run and is not executed.
+    Skipped,
+}
+
+/// Report of a provisioning step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StepReport {
+    /// Kind of the step.
+    pub step: ProvisionStepKind,
+    /// Outcome of the step.
+    pub outcome: StepOutcome,
+}
+
+/// Report of a provisioning run.
+pub struct ProvisionReport<B: Bmc> {
+    /// Reports of steps that completed successfully in plan order.
+    pub steps: Vec<StepReport>,
+    /// Step that failed together with the error. Steps after the
+    /// failed one are not executed.
+    pub failure: Option<(ProvisionStepKind, Error<B>)>,
+}
+
+impl<B: Bmc> ProvisionReport<B> {
+    /// All steps of the plan are completed.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.failure.is_none()
+    }
+
+    /// Number of steps completed by this run and previous runs. Pass
+    /// it to [`ProvisionPlan::resume`] to continue after a failure.
+    #[must_use]
+    pub const fn completed_steps(&self) -> usize {
+        self.steps.len()
+    }
+}
+
+enum ProvisionStep {
+    ChangePassword {
+        user_name: String,
+        password: String,
+    },
+    NetworkProtocol(Box<ManagerNetworkProtocolUpdate>),
+    HttpsCertificate(Box<CertificateCreate>),
+    ServiceAccount {
+        user_name: String,
+        role_id: String,
+        password_policy: ServiceAccountPasswordPolicy,
+    },
+    EventSubscription(Box<EventDestinationCreate>),
+}
+
+impl ProvisionStep {
+    const fn kind(&self) -> ProvisionStepKind {
+        match self {
+            Self::ChangePassword { .. } => ProvisionStepKind::ChangePassword,
+            Self::NetworkProtocol(_) => ProvisionStepKind::NetworkProtocol,
+            Self::HttpsCertificate(_) => ProvisionStepKind::HttpsCertificate,
+            Self::ServiceAccount { .. } => ProvisionStepKind::ServiceAccount,
+            Self::EventSubscription(_) => ProvisionStepKind::EventSubscription,
+        }
+    }
+
+    async fn apply<B: Bmc>(
+        &self,
+        root: &ServiceRoot<B>,
+        manager: &Manager<B>,
+    ) -> Result<StepOutcome, Error<B>> {
+        match self {
+            Self::ChangePassword {
+                user_name,
+                password,
+            } => {
+                let account = find_account(root, user_name)
+                    .await?
+                    .ok_or_else(|| Error::ProvisionAccountNotFound(user_name.clone()))?;
+                account.update_password(password.clone()).await?;
+                Ok(StepOutcome::Applied)
+            }
+            Self::NetworkProtocol(update) => {
+                network_protocol(manager).await?.update(update).await?;
+                Ok(StepOutcome::Applied)
+            }
+            Self::HttpsCertificate(create) => {
+                let protocol = network_protocol(manager).await?;
+                let requested = create.certificate_string.trim();
+                let certificates = protocol.https_certificates().await?;
+                let installed = certificates.iter().any(|certificate| {
+                    certificate
+                        .certificate_string
+                        .as_ref()
+                        .and_then(Option::as_deref)
+                        .is_some_and(|v| v.trim() == requested)
+                });
+                if installed {
+                    return Ok(StepOutcome::AlreadyApplied);
+                }
+                protocol.add_https_certificate(create).await?;
+                Ok(StepOutcome::Applied)
+            }
+            Self::ServiceAccount {
+                user_name,
+                role_id,
+                password_policy,
+            } => {
+                if find_account(root, user_name).await?.is_some() {
+                    return Ok(StepOutcome::AlreadyApplied);
+                }
+                root.account_service()
+                    .await?
+                    .ok_or(Error::ProvisionResourceNotAvailable("AccountService"))?
+                    .accounts()
+                    .await?
+                    .ok_or(Error::ProvisionResourceNotAvailable("Accounts"))?
+                    .create_service_account(
+                        user_name.clone(),
+                        role_id.clone(),
+                        password_policy.clone(),
+                    )
+                    .await?;
+                Ok(StepOutcome::Applied)
+            }
+            Self::EventSubscription(create) => {
+                let subscriptions = root
+                    .event_service()
+                    .await?
+                    .ok_or(Error::ProvisionResourceNotAvailable("EventService"))?
+                    .subscriptions()
+                    .await?
+                    .ok_or(Error::ProvisionResourceNotAvailable("Subscriptions"))?;
+                let subscribed = subscriptions.members().await?.iter().any(|subscription| {
+                    subscription.destination() == Some(create.destination.as_str())
+                });
+                if subscribed {
+                    return Ok(StepOutcome::AlreadyApplied);
+                }
+                subscriptions.create(create).await?;
+                Ok(StepOutcome::Applied)
+            }
+        }
+    }
+}
+
+/// Plan of out-of-box provisioning of a BMC.
+///
+/// See [module documentation](self) for details.
+#[derive(Default)]
+pub struct ProvisionPlan {
+    steps: Vec<ProvisionStep>,
+}
+
+impl ProvisionPlan {
+    /// Create an empty plan.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change password of the existing account `user_name`, usually
+    /// the account with the default password.
+    #[must_use]
+    pub fn change_password(mut self, user_name: String, password: String) -> Self {
+        self.steps.push(ProvisionStep::ChangePassword {
+            user_name,
+            password,
+        });
+        self
+    }
+
+    /// Update the network protocol resource of the manager: host
+    /// name, NTP servers and enabled state of protocols.
+    #[must_use]
+    pub fn network_protocol(mut self, update: ManagerNetworkProtocolUpdate) -> Self {
+        self.steps
+            .push(ProvisionStep::NetworkProtocol(Box::new(update)));
+        self
+    }
+
+    /// Upload TLS certificate of the HTTPS service of the manager.
+    /// The step is skipped if the certificate is already installed.
+    #[must_use]
+    pub fn https_certificate(mut self, create: CertificateCreate) -> Self {
+        self.steps
+            .push(ProvisionStep::HttpsCertificate(Box::new(create)));
+        self
+    }
+
+    /// Create service account for automation (see
+    /// [`crate::account::AccountCollection::create_service_account`]).
+    /// The step is skipped if an account with `user_name` exists.
+    #[must_use]
+    pub fn service_account(
+        mut self,
+        user_name: String,
+        role_id: String,
+        password_policy: ServiceAccountPasswordPolicy,
+    ) -> Self {
+        self.steps.push(ProvisionStep::ServiceAccount {
+            user_name,
+            role_id,
+            password_policy,
+        });
+        self
+    }
+
+    /// Create event subscription. The step is skipped if a
+    /// subscription with the same destination exists.
+    #[must_use]
+    pub fn event_subscription(mut self, create: EventDestinationCreate) -> Self {
+        self.steps
+            .push(ProvisionStep::EventSubscription(Box::new(create)));
+        self
+    }
+
+    /// Kinds of steps of the plan in execution order.
+    #[must_use]
+    pub fn steps(&self) -> Vec<ProvisionStepKind> {
+        self.steps.iter().map(ProvisionStep::kind).collect()
+    }
+
+    /// Run all steps of the plan against the service of `root`.
+    /// Manager specific steps are applied to `manager`.
+    pub async fn run<B: Bmc>(
+        &self,
+        root: &ServiceRoot<B>,
+        manager: &Manager<B>,
+    ) -> ProvisionReport<B> {
+        self.resume(root, manager, 0).await
+    }
+
+    /// Run the plan skipping the first `completed_steps` steps that
+    /// were completed by a previous run (see
+    /// [`ProvisionReport::completed_steps`]).
+    pub async fn resume<B: Bmc>(
+        &self,
+        root: &ServiceRoot<B>,
+        manager: &Manager<B>,
+        completed_steps: usize,
+    ) -> ProvisionReport<B> {
+        let mut report = ProvisionReport {
+            steps: Vec::with_capacity(self.steps.len()),
+            failure: None,
+        };
+        for (index, step) in self.steps.iter().enumerate() {
+            let outcome = if index < completed_steps {
+                StepOutcome::Skipped
+            } else {
+                match step.apply(root, manager).await {
+                    Ok(outcome) => outcome,
+                    Err(err) => {
+                        report.failure = Some((step.kind(), err));
+                        break;
+                    }
+                }
+            };
+            report.steps.push(StepReport {
+                step: step.kind(),
+                outcome,
+            });
+        }
+        report
+    }
+}
+
+async fn find_account<B: Bmc>(
+    root: &ServiceRoot<B>,
+    user_name: &str,
+) -> Result<Option<Account<B>>, Error<B>> {
+    let accounts = root
+        .account_service()
+        .await?
+        .ok_or(Error::ProvisionResourceNotAvailable("AccountService"))?
+        .accounts()
+        .await?
+        .ok_or(Error::ProvisionResourceNotAvailable("Accounts"))?
+        .all_accounts_data()
+        .await?;
+    Ok(accounts
+        .into_iter()
+        .find(|account| account.user_name() == Some(user_name)))
+}
+
+async fn network_protocol<B: Bmc>(
+    manager: &Manager<B>,
+) -> Result<ManagerNetworkProtocol<B>, Error<B>> {
+    manager
+        .network_protocol()
+        .await?
+        .ok_or(Error::ProvisionResourceNotAvailable("NetworkProtocol"))
+}
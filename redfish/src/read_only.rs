@@ -0,0 +1,344 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Read-only BMC
+//!
+//! [`ReadOnlyBmc`] wraps any [`Bmc`] and rejects all operations that
+//! modify the Redfish service (create, update, delete, actions and
+//! uploads) with [`ReadOnlyError::ReadOnlyMode`] before anything is sent to
+//! the BMC. Monitoring deployments can use it to be incapable of
+//! modifying hardware regardless of privileges of the BMC account.
+//!
+//! Read operations (get, expand, filter, binary download and
+//! streams) are forwarded to the wrapped BMC and its errors are
+//! reported as [`ReadOnlyError::Bmc`]. Session creation is forwarded as well
+//! because it is required for token authentication and doesn't
+//! modify hardware.
+//!
+//! ```ignore
+//! let bmc = Arc::new(ReadOnlyBmc::new(Arc::new(http_bmc)));
+//! let root = ServiceRoot::new(bmc).await?;
+//! ```
+
+use futures_util::TryStreamExt as _;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::query::FilterQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::ActionError;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::BinaryUploadRequest;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ETagPolicy;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::LatencyClass;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::NullPolicy;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::RedfishError;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::TaskMonitorResponse;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use std::error::Error as StdError;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::fmt::Result as FmtResult;
+use std::sync::Arc;
+
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+
+/// Error of [`ReadOnlyBmc`].
+#[derive(Debug)]
+pub enum ReadOnlyError<E> {
+    /// Error of the wrapped BMC.
+    Bmc(E),
+    /// Operation modifies the Redfish service and is rejected.
+    ReadOnlyMode,
+}
+
+impl<E: Display> Display for ReadOnlyError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Bmc(err) => write!(f, "BMC error: {err}"),
+            Self::ReadOnlyMode => {
+                write!(f, "Operation is rejected because BMC is in read-only mode")
+            }
+        }
+    }
+}
+
+impl<E: StdError + 'static> StdError for ReadOnlyError<E> {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Bmc(err) => Some(err),
+            Self::ReadOnlyMode => None,
+        }
+    }
+}
+
+impl<E: ActionError> ActionError for ReadOnlyError<E> {
+    fn not_supported() -> Self {
+        Self::Bmc(E::not_supported())
+    }
+}
+
+/// BMC that rejects all modifications of the Redfish service.
+pub struct ReadOnlyBmc<B: Bmc> {
+    bmc: Arc<B>,
+}
+
+impl<B: Bmc> ReadOnlyBmc<B> {
+    /// Create read-only BMC on top of `bmc`.
+    #[must_use]
+    pub const fn new(bmc: Arc<B>) -> Self {
+        Self { bmc }
+    }
+
+    /// Wrapped BMC.
+    #[must_use]
+    pub const fn inner(&self) -> &Arc<B> {
+        &self.bmc
+    }
+}
+
+impl<B: Bmc + 'static> Bmc for ReadOnlyBmc<B>
+where
+    B::Error: 'static,
+{
+    type Error = ReadOnlyError<B::Error>;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.bmc.expand(id, query).await.map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.bmc.get(id).await.map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        self.bmc
+            .get_if_none_match(id, etag)
+            .await
+            .map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.bmc.filter(id, query).await.map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn create<V: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+        _query: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(ReadOnlyError::ReadOnlyMode)
+    }
+
+    async fn create_session<
+        V: Send + Sync + Serialize,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<SessionCreateResponse<R>, Self::Error> {
+        self.bmc
+            .create_session(id, query)
+            .await
+            .map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn update<
+        V: Sync + Send + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        _id: &ODataId,
+        _etag: Option<&ODataETag>,
+        _update: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(ReadOnlyError::ReadOnlyMode)
+    }
+
+    async fn delete<R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
+        &self,
+        _id: &ODataId,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(ReadOnlyError::ReadOnlyMode)
+    }
+
+    async fn allowed_methods(&self, id: &ODataId) -> Result<Option<AllowedMethods>, Self::Error> {
+        self.bmc
+            .allowed_methods(id)
+            .await
+            .map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn action<
+        T: Send + Sync + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        _action: &Action<T, R>,
+        _params: &T,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        Err(ReadOnlyError::ReadOnlyMode)
+    }
+
+    async fn multipart_update<U, V, R>(
+        &self,
+        _uri: &str,
+        _request: MultipartUpdateRequest<'_, U, V>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+        V: Send + Sync + Serialize,
+    {
+        Err(ReadOnlyError::ReadOnlyMode)
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn http_push_uri_update<U, R>(
+        &self,
+        _uri: &str,
+        _request: HttpPushUriUpdateRequest<U>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        Err(ReadOnlyError::ReadOnlyMode)
+    }
+
+    async fn binary_download(&self, uri: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.bmc
+            .binary_download(uri)
+            .await
+            .map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn get_json<T>(&self, uri: &str) -> Result<T, Self::Error>
+    where
+        T: for<'de> Deserialize<'de> + Clone + Send + Sync + 'static,
+    {
+        self.bmc.get_json(uri).await.map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn task_monitor(&self, uri: &str) -> Result<Option<TaskMonitorResponse>, Self::Error> {
+        self.bmc.task_monitor(uri).await.map_err(ReadOnlyError::Bmc)
+    }
+
+    async fn binary_upload<U, R>(
+        &self,
+        _uri: &str,
+        _request: BinaryUploadRequest<U>,
+    ) -> Result<Option<ModificationResponse<R>>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        Err(ReadOnlyError::ReadOnlyMode)
+    }
+
+    fn set_etag_policy(&self, policy: ETagPolicy) {
+        self.bmc.set_etag_policy(policy);
+    }
+
+    fn set_null_policy(&self, policy: NullPolicy) {
+        self.bmc.set_null_policy(policy);
+    }
+
+    fn invalidate_cache(&self) {
+        self.bmc.invalidate_cache();
+    }
+
+    fn cache_entity<T: EntityTypeRef + 'static>(&self, entity: &Arc<T>) {
+        self.bmc.cache_entity(entity);
+    }
+
+    fn latency_class(&self) -> Option<LatencyClass> {
+        self.bmc.latency_class()
+    }
+
+    fn error_status_code(error: &Self::Error) -> Option<u16> {
+        if let ReadOnlyError::Bmc(error) = error {
+            B::error_status_code(error)
+        } else {
+            None
+        }
+    }
+
+    fn error_response_body(error: &Self::Error) -> Option<&str> {
+        if let ReadOnlyError::Bmc(error) = error {
+            B::error_response_body(error)
+        } else {
+            None
+        }
+    }
+
+    fn error_response_language(error: &Self::Error) -> Option<&str> {
+        if let ReadOnlyError::Bmc(error) = error {
+            B::error_response_language(error)
+        } else {
+            None
+        }
+    }
+
+    fn error_odata_type(error: &Self::Error) -> Option<&str> {
+        if let ReadOnlyError::Bmc(error) = error {
+            B::error_odata_type(error)
+        } else {
+            None
+        }
+    }
+
+    fn redfish_error(error: &Self::Error) -> Option<RedfishError> {
+        if let ReadOnlyError::Bmc(error) = error {
+            B::redfish_error(error)
+        } else {
+            None
+        }
+    }
+
+    async fn stream<T: Sized + for<'de> Deserialize<'de> + Send + 'static>(
+        &self,
+        uri: &str,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        let stream = self.bmc.stream(uri).await.map_err(ReadOnlyError::Bmc)?;
+        Ok(Box::pin(stream.map_err(ReadOnlyError::Bmc)))
+    }
+}
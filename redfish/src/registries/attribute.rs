@@ -0,0 +1,206 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Attribute registry files.
+//!
+//! An attribute registry describes attributes of resources with
+//! dynamic set of properties such as `Bios`: their types, allowed
+//! values and whether they can be changed by a client.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+
+#[cfg(feature = "message-registries")]
+use crate::registries::registry_uri;
+#[cfg(feature = "message-registries")]
+use crate::schema::message_registry_file_collection::MessageRegistryFileCollection as MessageRegistryFileCollectionSchema;
+#[cfg(feature = "message-registries")]
+use crate::Error;
+#[cfg(feature = "message-registries")]
+use crate::NvBmc;
+#[cfg(feature = "message-registries")]
+use crate::ServiceRoot;
+#[cfg(feature = "message-registries")]
+use nv_redfish_core::Bmc;
+#[cfg(feature = "message-registries")]
+use nv_redfish_core::NavProperty;
+
+/// Attribute registry that describes attributes of resources like
+/// BIOS.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RegistryFile")]
+pub struct AttributeRegistry {
+    id: String,
+    attributes: HashMap<String, Attribute>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Attribute {
+    read_only: bool,
+    immutable: bool,
+}
+
+impl AttributeRegistry {
+    /// Parse registry file from its JSON representation.
+    ///
+    /// Malformed attribute entries are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `json` is not an attribute registry.
+    pub fn from_slice(json: &[u8]) -> Result<Self, JsonError> {
+        serde_json::from_slice(json)
+    }
+
+    /// Identifier of the registry.
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Check if the registry defines attribute `name`.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.attributes.contains_key(name)
+    }
+
+    /// Check if attribute `name` can be written: it is defined by the
+    /// registry and is neither read-only nor immutable.
+    #[must_use]
+    pub fn is_writable(&self, name: &str) -> bool {
+        self.attributes
+            .get(name)
+            .is_some_and(|attribute| !attribute.read_only && !attribute.immutable)
+    }
+
+    /// Download attribute registry `registry` referenced by the
+    /// registry file collection of the service. Returns `None` if the
+    /// service doesn't host the registry.
+    #[cfg(feature = "message-registries")]
+    pub(crate) async fn fetch<B: Bmc>(
+        bmc: &NvBmc<B>,
+        root: &ServiceRoot<B>,
+        registry: &str,
+        language: &str,
+    ) -> Result<Option<Self>, Error<B>> {
+        let Some(collection_id) = root.registries_id() else {
+            return Ok(None);
+        };
+        let collection_ref = NavProperty::<MessageRegistryFileCollectionSchema>::new_reference(
+            collection_id.clone(),
+        );
+        let collection = bmc.expand_property(&collection_ref).await?;
+        for member in &collection.members {
+            let file = member.get(bmc.as_ref()).await.map_err(Error::Bmc)?;
+            if file.base.id != registry && file.registry != registry {
+                continue;
+            }
+            let Some(uri) = registry_uri(&file, language) else {
+                return Ok(None);
+            };
+            return bmc
+                .as_ref()
+                .get_json::<Self>(uri)
+                .await
+                .map(Some)
+                .map_err(Error::Bmc);
+        }
+        Ok(None)
+    }
+}
+
+#[derive(Deserialize)]
+struct RegistryFile {
+    #[serde(rename = "Id")]
+    id: String,
+    #[serde(rename = "RegistryEntries", default)]
+    entries: RegistryEntries,
+}
+
+impl From<RegistryFile> for AttributeRegistry {
+    fn from(file: RegistryFile) -> Self {
+        let attributes = file
+            .entries
+            .attributes
+            .into_iter()
+            .filter_map(|attribute| serde_json::from_value::<RegistryAttribute>(attribute).ok())
+            .map(|attribute| {
+                (
+                    attribute.name,
+                    Attribute {
+                        read_only: attribute.read_only.unwrap_or(false),
+                        immutable: attribute.immutable.unwrap_or(false),
+                    },
+                )
+            })
+            .collect();
+        Self {
+            id: file.id,
+            attributes,
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+struct RegistryEntries {
+    #[serde(rename = "Attributes", default)]
+    attributes: Vec<JsonValue>,
+}
+
+#[derive(Deserialize)]
+struct RegistryAttribute {
+    #[serde(rename = "AttributeName")]
+    name: String,
+    #[serde(rename = "ReadOnly")]
+    read_only: Option<bool>,
+    #[serde(rename = "Immutable")]
+    immutable: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AttributeRegistry;
+
+    #[test]
+    fn read_only_and_immutable_attributes_are_not_writable() {
+        let json = serde_json::json!({
+            "@odata.type": "#AttributeRegistry.v1_3_0.AttributeRegistry",
+            "Id": "BiosAttributeRegistry.1.0.0",
+            "Name": "BIOS Attribute Registry",
+            "Language": "en",
+            "RegistryVersion": "1.0.0",
+            "OwningEntity": "Contoso",
+            "RegistryEntries": {
+                "Attributes": [
+                    { "AttributeName": "BootMode", "Type": "Enumeration" },
+                    { "AttributeName": "SystemSerial", "Type": "String", "ReadOnly": true },
+                    { "AttributeName": "CpuCores", "Type": "Integer", "Immutable": true },
+                    { "Type": "String" }
+                ]
+            }
+        });
+        let registry =
+            AttributeRegistry::from_slice(json.to_string().as_bytes()).expect("valid registry");
+        assert_eq!(registry.id(), "BiosAttributeRegistry.1.0.0");
+        assert!(registry.is_writable("BootMode"));
+        assert!(registry.contains("SystemSerial"));
+        assert!(!registry.is_writable("SystemSerial"));
+        assert!(!registry.is_writable("CpuCores"));
+        assert!(!registry.is_writable("Unknown"));
+    }
+}
@@ -0,0 +1,313 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Recovery guidance for rejected requests.
+//!
+//! Redfish services explain rejected requests with
+//! `@Message.ExtendedInfo` messages that reference entries of message
+//! registries. This module turns these messages into short recovery
+//! guidance that is attached to [`crate::Error`].
+//!
+//! When message registries of the service are available, messages are
+//! resolved with them, so guidance uses the text and resolution of the
+//! registry in the language it was downloaded in. Otherwise built-in
+//! guidance for well-known `Base` registry messages is used. Built-in
+//! guidance is English: when the service rendered messages from a
+//! localized registry in another language, the text provided by the
+//! service is preferred so guidance matches the operator's locale.
+
+use crate::registries::MessageRegistries;
+use nv_redfish_core::RedfishError;
+use nv_redfish_core::RedfishMessage;
+use serde_json::Value as JsonValue;
+
+/// Message argument as it should be shown to the user.
+fn arg(info: &RedfishMessage, index: usize) -> String {
+    match info.message_args.get(index) {
+        Some(JsonValue::String(v)) => v.clone(),
+        Some(v) => v.to_string(),
+        None => "<unknown>".into(),
+    }
+}
+
+/// Guidance for the well-known `Base` registry messages.
+fn base_guidance(info: &RedfishMessage) -> Option<String> {
+    let guidance = match info.message_key()? {
+        "PropertyNotWritable" => format!("property {} is read-only", arg(info, 0)),
+        "PropertyUnknown" => format!("property {} is not known to the service", arg(info, 0)),
+        "PropertyMissing" => format!("required property {} is missing", arg(info, 0)),
+        "PropertyValueNotInList" => format!(
+            "value {} is not in AllowableValues of property {}",
+            arg(info, 0),
+            arg(info, 1)
+        ),
+        "PropertyValueTypeError" => format!(
+            "value {} has wrong type for property {}",
+            arg(info, 0),
+            arg(info, 1)
+        ),
+        "PropertyValueFormatError" => format!(
+            "value {} has wrong format for property {}",
+            arg(info, 0),
+            arg(info, 1)
+        ),
+        "PropertyValueOutOfRange" => format!(
+            "value {} is out of range of property {}",
+            arg(info, 0),
+            arg(info, 1)
+        ),
+        "ActionNotSupported" => format!("action {} is not supported", arg(info, 0)),
+        "ActionParameterMissing" => {
+            format!(
+                "action {} requires parameter {}",
+                arg(info, 0),
+                arg(info, 1)
+            )
+        }
+        "ActionParameterNotSupported" => format!(
+            "parameter {} is not supported by action {}",
+            arg(info, 0),
+            arg(info, 1)
+        ),
+        "ActionParameterValueNotInList" => format!(
+            "value {} is not in AllowableValues of parameter {} of action {}",
+            arg(info, 0),
+            arg(info, 1),
+            arg(info, 2)
+        ),
+        "InsufficientPrivilege" => "insufficient privilege for the operation".into(),
+        "PreconditionFailed" => "ETag of the resource is outdated, re-read it".into(),
+        _ => return None,
+    };
+    Some(guidance)
+}
+
+/// Guidance composed of message and resolution provided by the
+/// service.
+fn service_guidance(info: &RedfishMessage) -> Option<String> {
+    match (info.message.as_deref(), info.resolution.as_deref()) {
+        (Some(message), Some(resolution)) => Some(format!("{message} {resolution}")),
+        (Some(message), None) => Some(message.into()),
+        (None, Some(resolution)) => Some(resolution.into()),
+        (None, None) => None,
+    }
+}
+
+/// Guidance composed of message and resolution defined by the
+/// registry that resolves the message.
+fn registry_guidance(info: &RedfishMessage, registries: &MessageRegistries) -> Option<String> {
+    let resolved = registries.resolve_message(info)?;
+    Some(match resolved.resolution {
+        Some(resolution) => format!("{} {resolution}", resolved.message),
+        None => resolved.message,
+    })
+}
+
+fn guidance(
+    info: &RedfishMessage,
+    localized: bool,
+    registries: Option<&MessageRegistries>,
+) -> Option<String> {
+    let builtin = || {
+        if localized {
+            service_guidance(info).or_else(|| base_guidance(info))
+        } else {
+            base_guidance(info).or_else(|| service_guidance(info))
+        }
+    };
+    registries
+        .and_then(|registries| registry_guidance(info, registries))
+        .or_else(builtin)
+}
+
+/// Check if messages in `language` come from a localized registry,
+/// that is, the language is known and it is not English.
+fn is_localized(language: Option<&str>) -> bool {
+    language
+        .and_then(|tag| tag.split([',', '-', '_']).next())
+        .map(str::trim)
+        .is_some_and(|primary| !primary.is_empty() && !primary.eq_ignore_ascii_case("en"))
+}
+
+/// Recovery guidance built from the Redfish error response `body`
+/// written in `language`. Messages are resolved with `registries` when
+/// provided.
+///
+/// Returns empty vector if the body is not a Redfish error response or
+/// it does not contain any extended information.
+pub fn recovery_guidance(
+    body: &str,
+    language: Option<&str>,
+    registries: Option<&MessageRegistries>,
+) -> Vec<String> {
+    let localized = is_localized(language);
+    RedfishError::from_body(body)
+        .map(|error| {
+            error
+                .extended_info
+                .iter()
+                .filter_map(|info| guidance(info, localized, registries))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::recovery_guidance;
+    use crate::registries::MessageRegistries;
+    use crate::registries::MessageRegistry;
+
+    #[test]
+    fn base_registry_messages_are_resolved() {
+        let body = r#"{
+            "error": {
+                "code": "Base.1.8.GeneralError",
+                "message": "A general error has occurred.",
+                "@Message.ExtendedInfo": [
+                    {
+                        "MessageId": "Base.1.8.PropertyNotWritable",
+                        "Message": "The property AssetTag is a read only property.",
+                        "MessageArgs": ["AssetTag"]
+                    },
+                    {
+                        "MessageId": "Base.1.8.PropertyValueNotInList",
+                        "MessageArgs": ["Fast", "IndicatorLED"]
+                    }
+                ]
+            }
+        }"#;
+        assert_eq!(
+            recovery_guidance(body, None, None),
+            vec![
+                "property AssetTag is read-only".to_string(),
+                "value Fast is not in AllowableValues of property IndicatorLED".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_messages_fall_back_to_message_and_resolution() {
+        let body = r#"{
+            "error": {
+                "code": "Base.1.8.GeneralError",
+                "message": "A general error has occurred.",
+                "@Message.ExtendedInfo": [
+                    {
+                        "MessageId": "Oem.1.0.FirmwareLocked",
+                        "Message": "Firmware is locked.",
+                        "Resolution": "Unlock firmware and resubmit the request."
+                    }
+                ]
+            }
+        }"#;
+        assert_eq!(
+            recovery_guidance(body, None, None),
+            vec!["Firmware is locked. Unlock firmware and resubmit the request.".to_string()]
+        );
+    }
+
+    #[test]
+    fn non_redfish_body_has_no_guidance() {
+        assert!(recovery_guidance("Internal Server Error", None, None).is_empty());
+    }
+
+    #[test]
+    fn localized_messages_are_preferred_over_builtin_guidance() {
+        let body = r#"{
+            "error": {
+                "code": "Base.1.8.GeneralError",
+                "message": "Ein allgemeiner Fehler ist aufgetreten.",
+                "@Message.ExtendedInfo": [
+                    {
+                        "MessageId": "Base.1.8.PropertyNotWritable",
+                        "Message": "Die Eigenschaft AssetTag ist schreibgeschützt.",
+                        "MessageArgs": ["AssetTag"]
+                    },
+                    {
+                        "MessageId": "Base.1.8.PropertyMissing",
+                        "MessageArgs": ["UserName"]
+                    }
+                ]
+            }
+        }"#;
+        assert_eq!(
+            recovery_guidance(body, Some("de-DE"), None),
+            vec![
+                "Die Eigenschaft AssetTag ist schreibgeschützt.".to_string(),
+                "required property UserName is missing".to_string(),
+            ]
+        );
+        assert_eq!(
+            recovery_guidance(body, Some("en-US"), None),
+            vec![
+                "property AssetTag is read-only".to_string(),
+                "required property UserName is missing".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn messages_are_resolved_with_localized_registry() {
+        let registry = serde_json::json!({
+            "@odata.type": "#MessageRegistry.v1_6_0.MessageRegistry",
+            "Id": "Base.1.8.0",
+            "Name": "Base Message Registry",
+            "Language": "de",
+            "RegistryPrefix": "Base",
+            "RegistryVersion": "1.8.0",
+            "OwningEntity": "DMTF",
+            "Messages": {
+                "PropertyMissing": {
+                    "Description": "Indicates that a required property was not supplied.",
+                    "Message": "Die erforderliche Eigenschaft %1 fehlt.",
+                    "Severity": "Warning",
+                    "NumberOfArgs": 1,
+                    "ParamTypes": ["string"],
+                    "Resolution": "Eigenschaft angeben und Anfrage wiederholen."
+                }
+            }
+        });
+        let mut registries = MessageRegistries::new();
+        registries.insert(
+            MessageRegistry::from_slice(registry.to_string().as_bytes()).expect("valid registry"),
+        );
+        let body = r#"{
+            "error": {
+                "code": "Base.1.8.GeneralError",
+                "message": "A general error has occurred.",
+                "@Message.ExtendedInfo": [
+                    {
+                        "MessageId": "Base.1.8.PropertyMissing",
+                        "Message": "The property UserName is a required property.",
+                        "MessageArgs": ["UserName"]
+                    },
+                    {
+                        "MessageId": "Base.1.8.PropertyNotWritable",
+                        "MessageArgs": ["AssetTag"]
+                    }
+                ]
+            }
+        }"#;
+        assert_eq!(
+            recovery_guidance(body, Some("en"), Some(&registries)),
+            vec![
+                "Die erforderliche Eigenschaft UserName fehlt. Eigenschaft angeben und Anfrage wiederholen."
+                    .to_string(),
+                "property AssetTag is read-only".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,370 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message registries.
+//!
+//! Redfish services describe events and rejected requests with
+//! messages that reference entries of message registries: `MessageId`
+//! (for example, `Base.1.8.PropertyNotWritable`) together with
+//! `MessageArgs`. [`MessageRegistries`] keeps registry files and
+//! resolves such messages into text, severity and resolution defined
+//! by the registry. With `message-registries` feature the registries
+//! are downloaded from the service by
+//! `ServiceRoot::message_registries`.
+//!
+//! Messages of error responses are turned into short recovery
+//! guidance that is attached to [`crate::Error`]: with registries of
+//! the service when they are available (see
+//! [`crate::Error::recovery_guidance`]), and with built-in guidance
+//! for well-known `Base` registry messages otherwise.
+
+mod attribute;
+mod guidance;
+mod registry;
+
+use crate::registries::registry::arg_text;
+use crate::registries::registry::version_numbers;
+use nv_redfish_core::MessageSeverity;
+use nv_redfish_core::RedfishMessage;
+
+#[cfg(feature = "message-registries")]
+use crate::schema::message_registry_file::MessageRegistryFile as MessageRegistryFileSchema;
+#[cfg(feature = "message-registries")]
+use crate::schema::message_registry_file_collection::MessageRegistryFileCollection as MessageRegistryFileCollectionSchema;
+#[cfg(feature = "message-registries")]
+use crate::Error;
+#[cfg(feature = "message-registries")]
+use crate::NvBmc;
+#[cfg(feature = "message-registries")]
+use crate::ServiceRoot;
+#[cfg(feature = "message-registries")]
+use nv_redfish_core::Bmc;
+#[cfg(feature = "message-registries")]
+use nv_redfish_core::NavProperty;
+
+pub(crate) use guidance::recovery_guidance;
+
+#[doc(inline)]
+pub use attribute::AttributeRegistry;
+#[doc(inline)]
+pub use registry::MessageRegistry;
+
+/// Message resolved with a message registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedMessage {
+    /// Identifier of the message in the resolving registry:
+    /// `Registry.Major.Minor.MessageKey`.
+    pub message_id: String,
+    /// Message text with arguments substituted.
+    pub message: String,
+    /// Severity of the message defined by the registry.
+    pub severity: Option<MessageSeverity>,
+    /// Recommended action to resolve the condition.
+    pub resolution: Option<String>,
+}
+
+/// Registry file of the service that could not be downloaded or
+/// parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryFailure {
+    /// Identifier of the registry file resource or URI of the file.
+    pub uri: String,
+    /// Description of the error.
+    pub error: String,
+}
+
+/// Set of message registries that resolves messages.
+///
+/// The set is a cache of registry files: once downloaded (or loaded
+/// by the application with [`Self::insert`]), messages are resolved
+/// without requests to the service.
+#[derive(Debug, Clone, Default)]
+pub struct MessageRegistries {
+    registries: Vec<MessageRegistry>,
+    failures: Vec<RegistryFailure>,
+}
+
+impl MessageRegistries {
+    /// Create empty set of registries.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `registry` to the set. Registry with the same identifier is
+    /// replaced.
+    pub fn insert(&mut self, registry: MessageRegistry) {
+        self.registries.retain(|v| v.id() != registry.id());
+        self.registries.push(registry);
+    }
+
+    /// Registries of the set.
+    #[must_use]
+    pub fn registries(&self) -> &[MessageRegistry] {
+        &self.registries
+    }
+
+    /// Registry files of the service that were skipped because they
+    /// could not be downloaded or parsed.
+    #[must_use]
+    pub fn failures(&self) -> &[RegistryFailure] {
+        &self.failures
+    }
+
+    /// Resolve message `message_id` with arguments `args`.
+    ///
+    /// The message is looked up in registries with the prefix of
+    /// `message_id` that define it. Registry of the same `Major.Minor`
+    /// version is preferred, then the latest registry of the same major
+    /// version, then the latest registry. Returns `None` if no registry
+    /// defines the message.
+    #[must_use]
+    pub fn resolve<S: AsRef<str>>(&self, message_id: &str, args: &[S]) -> Option<ResolvedMessage> {
+        let (prefix, rest) = message_id.split_once('.')?;
+        let (version, key) = rest.rsplit_once('.').unwrap_or(("", rest));
+        let version = version_numbers(version);
+        self.registries
+            .iter()
+            .filter(|registry| registry.prefix() == prefix && registry.contains(key))
+            .max_by_key(|registry| {
+                let registry_version = version_numbers(registry.version());
+                (
+                    !version.is_empty() && registry_version.get(..2) == version.get(..2),
+                    !version.is_empty() && registry_version.first() == version.first(),
+                    registry_version,
+                )
+            })
+            .and_then(|registry| registry.resolve(key, args))
+    }
+
+    /// Resolve `message` of an error response. See [`Self::resolve`].
+    #[must_use]
+    pub fn resolve_message(&self, message: &RedfishMessage) -> Option<ResolvedMessage> {
+        let args = message
+            .message_args
+            .iter()
+            .map(arg_text)
+            .collect::<Vec<_>>();
+        self.resolve(message.message_id.as_deref()?, &args)
+    }
+
+    /// Download registry files referenced by the registry file
+    /// collection of the service. Failure of one file doesn't fail
+    /// the set: the file is skipped and recorded in
+    /// [`Self::failures`].
+    #[cfg(feature = "message-registries")]
+    pub(crate) async fn fetch<B: Bmc>(
+        bmc: &NvBmc<B>,
+        root: &ServiceRoot<B>,
+        language: &str,
+    ) -> Result<Option<Self>, Error<B>> {
+        let Some(collection_id) = root.registries_id() else {
+            return Ok(None);
+        };
+        let collection_ref = NavProperty::<MessageRegistryFileCollectionSchema>::new_reference(
+            collection_id.clone(),
+        );
+        let collection = bmc.expand_property(&collection_ref).await?;
+        let files = bmc
+            .fan_out(&collection.members, |m| async move {
+                Ok(m.get(bmc.as_ref())
+                    .await
+                    .map_err(|err| failure(m.id(), &Error::<B>::Bmc(err))))
+            })
+            .await?;
+        let mut failures = Vec::new();
+        let mut uris = Vec::new();
+        for file in files {
+            match file {
+                Ok(file) => uris.extend(registry_uri(&file, language).map(String::from)),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        let downloaded = bmc
+            .fan_out(&uris, |uri| async move {
+                Ok(bmc
+                    .as_ref()
+                    .get_json::<MessageRegistry>(uri)
+                    .await
+                    .map_err(|err| failure(uri, &Error::<B>::Bmc(err))))
+            })
+            .await?;
+        let mut registries = Vec::new();
+        for registry in downloaded {
+            match registry {
+                Ok(registry) => registries.push(registry),
+                Err(failure) => failures.push(failure),
+            }
+        }
+        Ok(Some(Self {
+            registries,
+            failures,
+        }))
+    }
+}
+
+#[cfg(feature = "message-registries")]
+fn failure<B: Bmc>(uri: impl ToString, error: &Error<B>) -> RegistryFailure {
+    RegistryFailure {
+        uri: uri.to_string(),
+        error: error.to_string(),
+    }
+}
+
+/// URI of the registry file hosted by the service in `language`, in
+/// English if the file is not available in `language`, or in any
+/// language otherwise. Files that are only published externally are
+/// skipped.
+#[cfg(feature = "message-registries")]
+pub(crate) fn registry_uri<'a>(
+    file: &'a MessageRegistryFileSchema,
+    language: &str,
+) -> Option<&'a str> {
+    let hosted = || {
+        file.location.iter().filter_map(|location| {
+            location
+                .uri
+                .as_deref()
+                .map(|uri| (location.language.as_deref(), uri))
+        })
+    };
+    let in_language = |language: &str| {
+        hosted().find(|(location_language, _)| {
+            location_language.is_some_and(|v| same_language(v, language))
+        })
+    };
+    in_language(language)
+        .or_else(|| in_language("en"))
+        .or_else(|| hosted().next())
+        .map(|(_, uri)| uri)
+}
+
+/// Compare primary language subtags of language tags `a` and `b`.
+#[cfg(feature = "message-registries")]
+fn same_language(a: &str, b: &str) -> bool {
+    let primary = |tag: &str| tag.split(['-', '_']).next().unwrap_or_default().trim();
+    primary(a).eq_ignore_ascii_case(primary(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry(version: &str, message: &str) -> MessageRegistry {
+        let json = serde_json::json!({
+            "@odata.type": "#MessageRegistry.v1_6_0.MessageRegistry",
+            "Id": format!("Base.{version}"),
+            "Name": "Base Message Registry",
+            "Language": "en",
+            "RegistryPrefix": "Base",
+            "RegistryVersion": version,
+            "OwningEntity": "DMTF",
+            "Messages": {
+                "PropertyValueNotInList": {
+                    "Description": "Indicates that a property was given the correct value type but the value of that property was not supported.",
+                    "Message": message,
+                    "Severity": "Critical",
+                    "MessageSeverity": "Warning",
+                    "NumberOfArgs": 2,
+                    "ParamTypes": ["string", "string"],
+                    "Resolution": "Choose a value from the enumeration list."
+                },
+                "Broken": { "Description": "Message without text." }
+            }
+        });
+        MessageRegistry::from_slice(json.to_string().as_bytes()).expect("valid registry")
+    }
+
+    #[test]
+    fn messages_are_resolved_with_arguments() {
+        let mut registries = MessageRegistries::new();
+        registries.insert(registry(
+            "1.8.0",
+            "The value %1 for the property %2 is not in the list of acceptable values. %3 100%",
+        ));
+        assert!(!registries.registries()[0].contains("Broken"));
+
+        let resolved = registries
+            .resolve("Base.1.8.PropertyValueNotInList", &["Fast", "IndicatorLED"])
+            .expect("message is resolved");
+        assert_eq!(resolved.message_id, "Base.1.8.PropertyValueNotInList");
+        assert_eq!(
+            resolved.message,
+            "The value Fast for the property IndicatorLED is not in the list of acceptable values. %3 100%"
+        );
+        assert_eq!(resolved.severity, Some(MessageSeverity::Warning));
+        assert_eq!(
+            resolved.resolution.as_deref(),
+            Some("Choose a value from the enumeration list.")
+        );
+
+        let message = RedfishMessage {
+            message_id: Some("Base.1.8.PropertyValueNotInList".into()),
+            message: None,
+            message_args: vec!["Fast".into(), 1.into()],
+            severity: None,
+            resolution: None,
+            related_properties: Vec::new(),
+        };
+        assert_eq!(
+            registries
+                .resolve_message(&message)
+                .map(|resolved| resolved.message),
+            Some(
+                "The value Fast for the property 1 is not in the list of acceptable values. %3 100%"
+                    .into()
+            )
+        );
+        assert_eq!(
+            registries.resolve("Task.1.0.PropertyValueNotInList", &["Fast"]),
+            None
+        );
+        assert_eq!(registries.resolve("Base.1.8.Unknown", &["Fast"]), None);
+    }
+
+    #[test]
+    fn registry_of_closest_version_is_preferred() {
+        let mut registries = MessageRegistries::new();
+        registries.insert(registry("1.2.0", "v1.2"));
+        registries.insert(registry("1.16.0", "v1.16"));
+        registries.insert(registry("2.0.0", "v2.0"));
+        // Registry with the same identifier is replaced.
+        registries.insert(registry("1.8.0", "v1.8 old"));
+        registries.insert(registry("1.8.0", "v1.8"));
+        assert_eq!(registries.registries().len(), 4);
+
+        let resolve = |id: &str| {
+            registries
+                .resolve(id, &[] as &[&str])
+                .map(|resolved| resolved.message)
+        };
+        assert_eq!(
+            resolve("Base.1.8.PropertyValueNotInList").as_deref(),
+            Some("v1.8")
+        );
+        assert_eq!(
+            resolve("Base.1.10.PropertyValueNotInList").as_deref(),
+            Some("v1.16")
+        );
+        assert_eq!(
+            resolve("Base.3.0.PropertyValueNotInList").as_deref(),
+            Some("v2.0")
+        );
+        assert_eq!(
+            resolve("Base.PropertyValueNotInList").as_deref(),
+            Some("v2.0")
+        );
+    }
+}
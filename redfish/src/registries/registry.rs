@@ -0,0 +1,222 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message registry files.
+//!
+//! A registry file defines messages of one registry prefix and version
+//! (DSP0266, "Message registries"): text with `%1`..`%N` placeholders
+//! of message arguments, severity and recommended resolution.
+
+use std::collections::HashMap;
+
+use nv_redfish_core::MessageSeverity;
+use serde::Deserialize;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+
+use super::ResolvedMessage;
+
+/// Message registry file, for example `Base.1.8.0`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(from = "RegistryFile")]
+pub struct MessageRegistry {
+    id: String,
+    prefix: String,
+    version: String,
+    language: Option<String>,
+    messages: HashMap<String, Message>,
+}
+
+#[derive(Debug, Clone)]
+struct Message {
+    text: String,
+    severity: Option<MessageSeverity>,
+    resolution: Option<String>,
+}
+
+impl MessageRegistry {
+    /// Parse registry file from its JSON representation.
+    ///
+    /// Malformed messages are skipped, so one broken entry of a
+    /// vendor registry doesn't hide the rest of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `json` is not a message registry.
+    pub fn from_slice(json: &[u8]) -> Result<Self, JsonError> {
+        serde_json::from_slice(json)
+    }
+
+    /// Identifier of the registry (`Base.1.8.0`).
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Registry prefix (`Base`).
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Version of the registry (`1.8.0`).
+    #[must_use]
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Language of messages of the registry.
+    #[must_use]
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    /// Check if the registry defines message `key`.
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    /// Resolve message `key` of the registry with arguments `args`.
+    ///
+    /// Placeholders without corresponding argument are kept as is.
+    #[must_use]
+    pub fn resolve<S: AsRef<str>>(&self, key: &str, args: &[S]) -> Option<ResolvedMessage> {
+        self.messages.get(key).map(|message| ResolvedMessage {
+            message_id: format!("{}.{}.{key}", self.prefix, major_minor(&self.version)),
+            message: substitute(&message.text, args),
+            severity: message.severity,
+            resolution: message.resolution.clone(),
+        })
+    }
+}
+
+/// `Major.Minor` part of registry version, as it appears in message
+/// identifiers.
+fn major_minor(version: &str) -> &str {
+    version
+        .match_indices('.')
+        .nth(1)
+        .map_or(version, |(pos, _)| &version[..pos])
+}
+
+/// Numeric components of registry version. Components that are not
+/// numbers count as zero.
+pub(super) fn version_numbers(version: &str) -> Vec<u32> {
+    version
+        .split('.')
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().unwrap_or_default())
+        .collect()
+}
+
+/// Message argument as it should be shown to the user.
+pub(super) fn arg_text(arg: &JsonValue) -> String {
+    match arg {
+        JsonValue::String(v) => v.clone(),
+        v => v.to_string(),
+    }
+}
+
+/// Replace `%N` placeholders of `text` with 1-based arguments `args`.
+fn substitute<S: AsRef<str>>(text: &str, args: &[S]) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('%') {
+        result.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        let digits = after
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(after.len());
+        let arg = after[..digits]
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|index| args.get(index));
+        if let Some(arg) = arg {
+            result.push_str(arg.as_ref());
+            rest = &after[digits..];
+        } else {
+            result.push('%');
+            rest = after;
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[derive(Deserialize)]
+struct RegistryFile {
+    #[serde(rename = "Id")]
+    id: String,
+    #[serde(rename = "RegistryPrefix")]
+    registry_prefix: String,
+    #[serde(rename = "RegistryVersion")]
+    registry_version: String,
+    #[serde(rename = "Language")]
+    language: Option<String>,
+    #[serde(rename = "Messages", default)]
+    messages: HashMap<String, JsonValue>,
+}
+
+impl From<RegistryFile> for MessageRegistry {
+    fn from(file: RegistryFile) -> Self {
+        let messages = file
+            .messages
+            .into_iter()
+            .filter_map(|(key, message)| {
+                serde_json::from_value::<RegistryMessage>(message)
+                    .ok()
+                    .map(|message| (key, Message::from(message)))
+            })
+            .collect();
+        Self {
+            id: file.id,
+            prefix: file.registry_prefix,
+            version: file.registry_version,
+            language: file.language,
+            messages,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RegistryMessage {
+    #[serde(rename = "Message")]
+    message: String,
+    #[serde(rename = "MessageSeverity")]
+    message_severity: Option<String>,
+    // `Severity` is deprecated in favor of `MessageSeverity` but
+    // older registries only have it.
+    #[serde(rename = "Severity")]
+    severity: Option<String>,
+    #[serde(rename = "Resolution")]
+    resolution: Option<String>,
+}
+
+impl From<RegistryMessage> for Message {
+    fn from(v: RegistryMessage) -> Self {
+        let severity = v
+            .message_severity
+            .as_deref()
+            .and_then(MessageSeverity::parse)
+            .or_else(|| v.severity.as_deref().and_then(MessageSeverity::parse));
+        Self {
+            text: v.message,
+            severity,
+            resolution: v.resolution,
+        }
+    }
+}
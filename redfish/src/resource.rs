@@ -15,9 +15,22 @@
 
 //! Redfish resource
 
-use crate::core::EntityTypeRef as _;
+use crate::core::Bmc;
+use crate::core::Capabilities;
+use crate::core::EntityCapabilities;
+use crate::core::EntityTypeRef;
+use crate::core::LinksOem;
+use crate::core::NavigationProperties;
 use crate::core::ODataId;
+use crate::schema_types;
+use crate::Error;
+use crate::NvBmc;
 use crate::ResourceSchema;
+use serde::Deserialize;
+use serde_json::Error as JsonError;
+use serde_json::Value as JsonValue;
+use std::future::Future;
+use std::sync::Arc;
 use tagged_types::TaggedType;
 
 #[cfg(feature = "oem")]
@@ -25,6 +38,8 @@ use crate::oem::OemIdentifier;
 #[cfg(feature = "resource-status")]
 use crate::ResourceStatusSchema;
 #[cfg(feature = "resource-status")]
+use std::cmp::Ordering;
+#[cfg(feature = "resource-status")]
 use std::convert::identity;
 
 #[doc(inline)]
@@ -82,9 +97,94 @@ pub enum ResourceDescriptionTag {}
 
 /// Represents Redfish Resource base type.
 pub trait Resource {
+    /// Entity type of the resource generated from the CSDL
+    /// specification.
+    type Entity: EntityCapabilities + LinksOem + NavigationProperties;
+
     /// Required function. Must be implemented for Redfish resources.
     fn resource_ref(&self) -> &ResourceSchema;
 
+    /// Required function. Payload of the resource as the generated
+    /// entity type.
+    fn entity_ref(&self) -> &Self::Entity;
+
+    /// Capabilities (insertable, updatable, deletable) of the resource
+    /// compiled from the CSDL specification. Allows generic tooling to
+    /// skip operations that are not supported by the resource type.
+    fn capabilities(&self) -> Capabilities {
+        self.entity_ref().capabilities()
+    }
+
+    /// Content of the `Links.Oem` property of the resource. `None` if
+    /// the resource type doesn't define `Links` or the service doesn't
+    /// provide OEM links.
+    fn links_oem(&self) -> Option<&JsonValue> {
+        self.entity_ref().links_oem()
+    }
+
+    /// `@odata.id` of the single-valued navigation property `name`
+    /// (name of the property in JSON payload) as defined by the CSDL
+    /// specification. `None` if the resource type doesn't define the
+    /// property or the service doesn't provide it.
+    fn navigation_property(&self, name: &str) -> Option<&ODataId> {
+        self.entity_ref().navigation_property(name)
+    }
+
+    /// Fetch the sub-resource of the resource referenced by the
+    /// single-valued navigation property `name` (for example,
+    /// `"EnvironmentMetrics"`) as `T`.
+    ///
+    /// This is an escape hatch for sub-resources that don't have a
+    /// high-level wrapper yet. The sub-resource is requested through
+    /// `bmc`, so errors are reported the same way as by the wrappers.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NavigationPropertyNotAvailable`] if the
+    /// resource doesn't provide the property, and an error if the
+    /// sub-resource cannot be retrieved or deserialized into `T`.
+    fn follow<T, B>(
+        &self,
+        bmc: &NvBmc<B>,
+        name: &str,
+    ) -> impl Future<Output = Result<Arc<T>, Error<B>>> + Send
+    where
+        T: EntityTypeRef + for<'de> Deserialize<'de> + Send + Sync + 'static,
+        B: Bmc,
+    {
+        let id = self.navigation_property(name).cloned().ok_or_else(|| {
+            Error::NavigationPropertyNotAvailable(self.odata_id().clone(), name.to_owned())
+        });
+        async move {
+            bmc.as_ref()
+                .get::<T>(&id?)
+                .await
+                .map_err(schema_types::bmc_error)
+        }
+    }
+
+    /// OEM links of the `vendor` (for example, `Nvidia`) deserialized
+    /// into a user-provided type. Links to other resources can be
+    /// represented in `T` as [`crate::core::NavProperty`] of the
+    /// target entity type.
+    ///
+    /// Returns `Ok(None)` if the service doesn't provide OEM links of
+    /// the vendor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if OEM links of the vendor cannot be
+    /// deserialized into `T`.
+    fn oem_links<T>(&self, vendor: &str) -> Result<Option<T>, JsonError>
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        self.links_oem()
+            .and_then(|oem| oem.get(vendor))
+            .map(T::deserialize)
+            .transpose()
+    }
+
     /// Identifier of the resource.
     fn id(&self) -> ResourceIdRef<'_> {
         ResourceIdRef::new(&self.resource_ref().id)
@@ -114,6 +214,19 @@ pub trait Resource {
     fn odata_id(&self) -> &ODataId {
         self.resource_ref().odata_id()
     }
+
+    /// `Status` property of the resource. Implemented by resources
+    /// whose type defines `Status`, others return `None`.
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        None
+    }
+
+    /// Status of the resource if it is provided.
+    #[cfg(feature = "resource-status")]
+    fn status(&self) -> Option<Status> {
+        self.resource_status_ref().map(Status::from)
+    }
 }
 
 #[cfg(feature = "oem")]
@@ -128,7 +241,7 @@ pub(crate) fn oem_id_from_resource(r: &ResourceSchema) -> Option<&str> {
 
 /// The status and health of a resource and its children.
 #[cfg(feature = "resource-status")]
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Status {
     /// The state of the resource.
     pub state: Option<State>,
@@ -138,19 +251,97 @@ pub struct Status {
     pub health_rollup: Option<Health>,
 }
 
-/// Represents Redfish resource that provides it's status.
 #[cfg(feature = "resource-status")]
-pub trait ResourceProvidesStatus {
-    /// Required function. Must be implemented for Redfish resources
-    /// that provides resource status.
-    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema>;
+impl Status {
+    /// The worst of `health` and `health_rollup`.
+    #[must_use]
+    pub fn worst_health(self) -> Option<Health> {
+        self.health.max(self.health_rollup)
+    }
 
-    /// Status of the resource if it is provided.
-    fn status(&self) -> Option<Status> {
-        self.resource_status_ref().map(|status| Status {
+    /// Combine statuses of two resources keeping the worst value of
+    /// each field. Absent values don't affect the result, so statuses
+    /// of a group of resources can be aggregated starting from
+    /// `Status::default()`.
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            state: self.state.max(other.state),
+            health: self.health.max(other.health),
+            health_rollup: self.health_rollup.max(other.health_rollup),
+        }
+    }
+}
+
+#[cfg(feature = "resource-status")]
+impl From<&ResourceStatusSchema> for Status {
+    fn from(status: &ResourceStatusSchema) -> Self {
+        Self {
             state: status.state.and_then(identity),
             health: status.health.and_then(identity),
             health_rollup: status.health_rollup.and_then(identity),
-        })
+        }
+    }
+}
+
+/// Health is ordered by severity: `Ok < Warning < Critical`. Values
+/// that are not supported by the compiled schema are ordered between
+/// `Warning` and `Critical`.
+#[cfg(feature = "resource-status")]
+impl Ord for Health {
+    fn cmp(&self, other: &Self) -> Ordering {
+        health_severity(*self).cmp(&health_severity(*other))
+    }
+}
+
+#[cfg(feature = "resource-status")]
+impl PartialOrd for Health {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "resource-status")]
+const fn health_severity(health: Health) -> u8 {
+    match health {
+        Health::Ok => 0,
+        Health::Warning => 1,
+        Health::UnsupportedValue => 2,
+        Health::Critical => 3,
+    }
+}
+
+/// State is ordered by availability of the resource: `Enabled` is the
+/// lowest, then states of a functional resource in transition or in
+/// standby, then values that are not known to this crate, then states
+/// of a resource that is not available, and `Absent` is the highest.
+#[cfg(feature = "resource-status")]
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        state_severity(*self).cmp(&state_severity(*other))
+    }
+}
+
+#[cfg(feature = "resource-status")]
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "resource-status")]
+const fn state_severity(state: State) -> u8 {
+    match state {
+        State::Enabled => 0,
+        State::StandbySpare
+        | State::Starting
+        | State::InTest
+        | State::Updating
+        | State::Deferring
+        | State::Quiesced => 1,
+        State::Disabled | State::StandbyOffline | State::UnavailableOffline => 3,
+        State::Absent => 4,
+        // Unsupported values and states added by newer schemas.
+        _ => 2,
     }
 }
@@ -0,0 +1,182 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Diagnostics of payloads of types that are not compiled.
+//!
+//! Generated schema keeps qualified names of compiled types
+//! (`SCHEMA_TYPES`). When a payload cannot be parsed, its
+//! `@odata.type` is checked against them: type that the crate didn't
+//! compile (schema version newer than compiled one, namespace of
+//! disabled vendor feature) is reported as
+//! [`Error::UnknownSchemaType`] with the nearest compiled type rather
+//! than as a generic JSON error.
+//!
+//! Payloads of types that are not compiled are often still parsed
+//! successfully (newer versions only add properties), so the type is
+//! reported only when parsing fails. `@odata.type` is taken before
+//! deserializing, both for payloads parsed by the crate
+//! ([`from_value`]) and for payloads that the BMC failed to parse
+//! ([`bmc_error`], see `Bmc::error_odata_type`).
+
+use crate::Error;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::SchemaVersion;
+use serde::de::DeserializeOwned;
+use serde_json::Value as JsonValue;
+
+/// Compiled types of DMTF schema and of enabled OEM extensions.
+const COMPILED: &[&[&str]] = &[
+    crate::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-ami")]
+    crate::oem::ami::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-dell")]
+    crate::oem::dell::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-delta")]
+    crate::oem::delta::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-hpe")]
+    crate::oem::hpe::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-lenovo")]
+    crate::oem::lenovo::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-liteon")]
+    crate::oem::liteon::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-supermicro")]
+    crate::oem::supermicro::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-nvidia-baseboard")]
+    crate::oem::nvidia::baseboard::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-nvidia-bluefield")]
+    crate::oem::nvidia::bluefield::schema::SCHEMA_TYPES,
+    #[cfg(feature = "oem-nvidia-hgx")]
+    crate::oem::nvidia::hgx::schema::SCHEMA_TYPES,
+];
+
+/// Deserialize `payload`.
+///
+/// Returns [`Error::UnknownSchemaType`] if parsing fails and
+/// `@odata.type` of the payload is not compiled, and [`Error::Json`]
+/// otherwise.
+#[allow(dead_code)] // feature-enabled func
+pub(crate) fn from_value<T, B>(payload: JsonValue) -> Result<T, Error<B>>
+where
+    T: DeserializeOwned,
+    B: Bmc,
+{
+    let unknown = payload
+        .get("@odata.type")
+        .and_then(JsonValue::as_str)
+        .and_then(unknown_schema_type);
+    serde_json::from_value(payload).map_err(|err| unknown.unwrap_or(Error::Json(err)))
+}
+
+/// Error for `err` returned by the BMC.
+///
+/// Returns [`Error::UnknownSchemaType`] if the BMC failed to parse a
+/// payload whose `@odata.type` is not compiled, and [`Error::Bmc`]
+/// otherwise.
+#[allow(dead_code)] // feature-enabled func
+pub(crate) fn bmc_error<B: Bmc>(err: B::Error) -> Error<B> {
+    B::error_odata_type(&err)
+        .and_then(unknown_schema_type)
+        .unwrap_or(Error::Bmc(err))
+}
+
+/// [`Error::UnknownSchemaType`] if `odata_type` is not compiled.
+fn unknown_schema_type<B: Bmc>(odata_type: &str) -> Option<Error<B>> {
+    let compiled = COMPILED.iter().copied().flatten().copied();
+    lookup(compiled, odata_type)
+        .err()
+        .map(|nearest| Error::UnknownSchemaType {
+            odata_type: odata_type.to_owned(),
+            nearest_compiled: nearest.map(ToOwned::to_owned),
+        })
+}
+
+/// Look up `odata_type` (`#Namespace.v1_2_0.Name`) in `compiled`
+/// types.
+///
+/// Returns `Err` with the nearest compiled type if the type is not
+/// compiled. Nearest type has the same name and namespace and the
+/// highest version that is not newer than the requested one (or the
+/// oldest version if all compiled versions are newer).
+fn lookup<'a>(
+    compiled: impl Iterator<Item = &'a str> + Clone,
+    odata_type: &str,
+) -> Result<(), Option<&'a str>> {
+    let odata_type = odata_type.trim_start_matches('#');
+    if compiled.clone().any(|name| name == odata_type) {
+        return Ok(());
+    }
+    let (base, version) = split_version(odata_type);
+    let candidates = compiled.filter_map(|name| {
+        let (candidate_base, candidate_version) = split_version(name);
+        (candidate_base == base).then_some((candidate_version, name))
+    });
+    let (older, newer): (Vec<_>, Vec<_>) =
+        candidates.partition(|(candidate, _)| version.is_none() || *candidate <= version);
+    let nearest = older
+        .into_iter()
+        .max()
+        .or_else(|| newer.into_iter().min())
+        .map(|(_, name)| name);
+    Err(nearest)
+}
+
+/// Split qualified type name into name without version segment of
+/// the namespace and the version.
+fn split_version(name: &str) -> (Vec<&str>, Option<SchemaVersion>) {
+    let base = name
+        .split('.')
+        .filter(|segment| SchemaVersion::parse(segment).is_none())
+        .collect();
+    (base, SchemaVersion::from_odata_type(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lookup;
+
+    const COMPILED: &[&str] = &[
+        "Chassis.Chassis",
+        "Chassis.v1_0_0.Chassis",
+        "Chassis.v1_20_0.Chassis",
+        "Chassis.v1_25_0.Chassis",
+        "ChassisCollection.ChassisCollection",
+    ];
+
+    #[test]
+    fn compiled_types_are_known() {
+        let compiled = COMPILED.iter().copied();
+        assert_eq!(lookup(compiled.clone(), "#Chassis.v1_20_0.Chassis"), Ok(()));
+        assert_eq!(
+            lookup(compiled, "#ChassisCollection.ChassisCollection"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn nearest_compiled_version_is_reported() {
+        let compiled = COMPILED.iter().copied();
+        assert_eq!(
+            lookup(compiled.clone(), "#Chassis.v1_30_0.Chassis"),
+            Err(Some("Chassis.v1_25_0.Chassis"))
+        );
+        assert_eq!(
+            lookup(compiled.clone(), "#Chassis.v1_22_1.Chassis"),
+            Err(Some("Chassis.v1_20_0.Chassis"))
+        );
+        assert_eq!(
+            lookup(compiled, "#HpeiLOChassis.v2_0_0.HpeiLOChassis"),
+            Err(None)
+        );
+    }
+}
@@ -18,7 +18,8 @@
 //! This module provides a unified interface for accessing sensor data from
 //! Redfish entities that support modern sensor links. The `HasSensors` trait
 //! is implemented by entities that have associated sensors, and provides access
-//! to a `Sensor` handle for sensor data retrieval.
+//! to a `Sensor` handle for sensor data retrieval. `Sensor` exposes the
+//! reading, its units and type, and thresholds of the sensor.
 //!
 //! # Modern vs Legacy Approach
 //!
@@ -27,12 +28,25 @@
 //! `Chassis/Power` and `Chassis/Thermal`, use those explicit endpoints instead.
 
 use crate::entity_link::EntityLink;
+use crate::entity_link::FromLink;
 use crate::schema::environment_metrics::EnvironmentMetrics;
 use crate::schema::sensor::Sensor as SchemaSensor;
+use crate::schema::sensor::Threshold;
 use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataId;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+#[doc(inline)]
+pub use crate::schema::sensor::ReadingType as SensorType;
 
 /// Extracts sensor URIs from metric fields and creates sensor navigation properties.
 ///
@@ -75,6 +89,120 @@ macro_rules! extract_sensor_uris {
 /// Link for accessing sensor.
 pub type SensorLink<B> = EntityLink<B, SchemaSensor>;
 
+/// Sensor with its current reading.
+pub struct Sensor<B: Bmc> {
+    data: Arc<SchemaSensor>,
+    _bmc: PhantomData<B>,
+}
+
+/// Threshold readings of a sensor.
+///
+/// Each value is `None` when the sensor does not define the
+/// threshold.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SensorThresholds {
+    /// Reading above normal range.
+    pub upper_caution: Option<f64>,
+    /// Reading above normal range that requires attention.
+    pub upper_critical: Option<f64>,
+    /// Reading above normal range that is fatal.
+    pub upper_fatal: Option<f64>,
+    /// Reading below normal range.
+    pub lower_caution: Option<f64>,
+    /// Reading below normal range that requires attention.
+    pub lower_critical: Option<f64>,
+    /// Reading below normal range that is fatal.
+    pub lower_fatal: Option<f64>,
+}
+
+impl<B: Bmc> Sensor<B> {
+    /// Fetch the sensor.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<SchemaSensor>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|data| Self {
+                data,
+                _bmc: PhantomData,
+            })
+    }
+
+    /// Get the raw schema data for this sensor.
+    ///
+    /// Returns an `Arc` to the underlying schema, allowing cheap cloning
+    /// and sharing of the data.
+    #[must_use]
+    pub fn raw(&self) -> Arc<SchemaSensor> {
+        self.data.clone()
+    }
+
+    /// Type of the reading (`ReadingType`).
+    #[must_use]
+    pub fn reading_type(&self) -> Option<SensorType> {
+        self.data.reading_type.flatten()
+    }
+
+    /// Current reading of the sensor in [`Self::reading_units`].
+    #[must_use]
+    pub fn reading(&self) -> Option<f64> {
+        self.data.reading.flatten()
+    }
+
+    /// Units of the reading (for example, `Cel`, `W` or `RPM`).
+    #[must_use]
+    pub fn reading_units(&self) -> Option<&str> {
+        self.data.reading_units.as_ref()?.as_deref()
+    }
+
+    /// Thresholds defined for the sensor.
+    #[must_use]
+    pub fn thresholds(&self) -> SensorThresholds {
+        let Some(thresholds) = &self.data.thresholds else {
+            return SensorThresholds::default();
+        };
+        let reading = |t: &Option<Threshold>| t.as_ref().and_then(|t| t.reading.flatten());
+        SensorThresholds {
+            upper_caution: reading(&thresholds.upper_caution),
+            upper_critical: reading(&thresholds.upper_critical),
+            upper_fatal: reading(&thresholds.upper_fatal),
+            lower_caution: reading(&thresholds.lower_caution),
+            lower_critical: reading(&thresholds.lower_critical),
+            lower_fatal: reading(&thresholds.lower_fatal),
+        }
+    }
+}
+
+impl<B: Bmc> Resource for Sensor<B> {
+    type Entity = SchemaSensor;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
+}
+
+impl<B: Bmc> FromLink<B> for Sensor<B> {
+    type Schema = SchemaSensor;
+
+    fn from_link(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<Self::Schema>,
+    ) -> impl Future<Output = Result<Self, Error<B>>> + Send {
+        Self::new(bmc, nav)
+    }
+}
+
 /// Collect sensor refs from URIs
 pub(crate) fn collect_sensors(
     uris: impl IntoIterator<Item = String>,
@@ -0,0 +1,568 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Detection of Redfish service restarts.
+//!
+//! After the BMC reboots, cached responses and `ETag`s, sessions and
+//! task monitors of long-lived clients become invalid. [`RestartMonitor`]
+//! detects the restart by a changed `UUID` of the service root or by
+//! session rejection (HTTP 401 or 410). When the restart is detected
+//! the monitor drops the cache of the BMC (see
+//! [`Bmc::invalidate_cache`]), re-establishes the session if it is
+//! configured, reads the service root again and sends
+//! [`ServiceEvent::ServiceRestarted`] to all subscribers.
+//!
+//! [`RestartDetectingBmc`] runs the detection automatically: requests
+//! rejected with HTTP 401 or 410 trigger recovery and are sent again
+//! once, and after requests that failed without HTTP response (the
+//! BMC was unreachable) the `UUID` of the service root is checked
+//! before the next request.
+//!
+//! ```ignore
+//! let monitor = Arc::new(RestartMonitor::new(&root).with_session_renewal(create, install));
+//! let mut events = monitor.subscribe();
+//! let bmc = Arc::new(RestartDetectingBmc::new(monitor.clone()));
+//! let root = ServiceRoot::new(bmc).await?;
+//! ```
+//!
+//! Applications that use the BMC directly can run the detection with
+//! [`RestartMonitor::check`] and [`RestartMonitor::check_error`].
+
+use crate::Error;
+use crate::ServiceRoot;
+use futures_channel::mpsc::unbounded;
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_channel::mpsc::UnboundedSender;
+use futures_util::lock::Mutex as AsyncMutex;
+use nv_redfish_core::query::ExpandQuery;
+use nv_redfish_core::query::FilterQuery;
+use nv_redfish_core::Action;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::BinaryUploadRequest;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::BoxTryStream;
+use nv_redfish_core::ETagPolicy;
+use nv_redfish_core::EdmGuid;
+use nv_redfish_core::EntityTypeRef;
+use nv_redfish_core::Expandable;
+use nv_redfish_core::LatencyClass;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::MultipartUpdateRequest;
+use nv_redfish_core::NullPolicy;
+use nv_redfish_core::ODataETag;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::RedfishError;
+use nv_redfish_core::SessionCreateResponse;
+use nv_redfish_core::TaskMonitorResponse;
+use nv_redfish_core::UploadReader;
+use serde::Deserialize;
+use serde::Serialize;
+use std::future::Future;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[cfg(feature = "session-service")]
+use crate::schema::session::Session as SessionSchema;
+#[cfg(feature = "session-service")]
+use crate::session_service::SessionCreate;
+#[cfg(feature = "update-service-deprecated")]
+use nv_redfish_core::HttpPushUriUpdateRequest;
+
+/// HTTP status codes that are reported by the Redfish service when
+/// the session of the client is not known anymore.
+const SESSION_REJECTED_STATUS_CODES: [u16; 2] = [401, 410];
+
+/// Evidence of the Redfish service restart.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RestartEvidence {
+    /// `UUID` of the service root differs from the previously observed
+    /// one.
+    UuidChanged {
+        /// Previously observed `UUID`.
+        previous: Option<EdmGuid>,
+        /// Current `UUID`.
+        current: Option<EdmGuid>,
+    },
+    /// Service rejected the client session. Contains HTTP status code.
+    SessionRejected(u16),
+}
+
+/// Event of the Redfish service observed by the client.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ServiceEvent {
+    /// Redfish service restarted. Cache of the BMC was dropped and the
+    /// session was re-established if it is configured.
+    ServiceRestarted(RestartEvidence),
+}
+
+#[cfg(feature = "session-service")]
+struct SessionRenewal {
+    create: SessionCreate,
+    install: Box<dyn Fn(&str) + Send + Sync>,
+}
+
+/// Monitor of the Redfish service restarts.
+pub struct RestartMonitor<B: Bmc> {
+    bmc: Arc<B>,
+    uuid: Mutex<Option<EdmGuid>>,
+    subscribers: Mutex<Vec<UnboundedSender<ServiceEvent>>>,
+    #[cfg(feature = "session-service")]
+    session_renewal: Option<SessionRenewal>,
+    /// Serializes detection and recovery.
+    recovery: AsyncMutex<()>,
+    /// Number of recoveries. Requests that failed before a concurrent
+    /// recovery are sent again without another one.
+    generation: AtomicU64,
+    /// Request failed without HTTP response, `UUID` of the service root
+    /// is checked before the next request.
+    suspected: AtomicBool,
+}
+
+impl<B: Bmc> RestartMonitor<B> {
+    /// Create monitor of the service that provided `root`.
+    #[must_use]
+    pub fn new(root: &ServiceRoot<B>) -> Self {
+        Self {
+            bmc: root.shared_bmc(),
+            uuid: Mutex::new(root.root.uuid),
+            subscribers: Mutex::new(Vec::new()),
+            #[cfg(feature = "session-service")]
+            session_renewal: None,
+            recovery: AsyncMutex::new(()),
+            generation: AtomicU64::new(0),
+            suspected: AtomicBool::new(false),
+        }
+    }
+
+    /// Re-establish the session when the restart is detected.
+    ///
+    /// A new session is created with `create` in the `Sessions`
+    /// collection linked from the service root. `install` receives
+    /// the new session token and is expected to pass it to the BMC
+    /// (for example, with `HttpBmc::set_credentials`).
+    #[cfg(feature = "session-service")]
+    #[must_use]
+    pub fn with_session_renewal<F>(self, create: SessionCreate, install: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        Self {
+            session_renewal: Some(SessionRenewal {
+                create,
+                install: Box::new(install),
+            }),
+            ..self
+        }
+    }
+
+    /// Subscribe to events of the service.
+    ///
+    /// Dropping the receiver unsubscribes it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the internal subscribers lock is poisoned. This should
+    /// not occur in normal operation.
+    #[allow(clippy::panic)] // See panics section.
+    pub fn subscribe(&self) -> UnboundedReceiver<ServiceEvent> {
+        let (sender, receiver) = unbounded();
+        self.subscribers.lock().expect("poisoned").push(sender);
+        receiver
+    }
+
+    /// Read the service root and check if the service restarted.
+    ///
+    /// Returns the service root read after recovery if the restart is
+    /// detected, `None` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the service root, or recovery
+    /// after the restart fails.
+    pub async fn check(&self) -> Result<Option<ServiceRoot<B>>, Error<B>> {
+        let _recovery = self.recovery.lock().await;
+        self.check_uuid().await
+    }
+
+    #[allow(clippy::panic)] // See subscribe Panic doc.
+    async fn check_uuid(&self) -> Result<Option<ServiceRoot<B>>, Error<B>> {
+        let root = ServiceRoot::new(self.bmc.clone()).await?;
+        let current = root.root.uuid;
+        let previous = *self.uuid.lock().expect("poisoned");
+        if previous == current {
+            return Ok(None);
+        }
+        self.recover(RestartEvidence::UuidChanged { previous, current })
+            .await
+            .map(Some)
+    }
+
+    /// Check if `error` of the request to the service reports
+    /// rejection of the client session (HTTP 401 or 410).
+    ///
+    /// Such errors are also reported for invalid credentials, so
+    /// applications should call it only for requests that used to
+    /// succeed. Returns the service root read after recovery if the
+    /// session was rejected, `None` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if recovery after the restart fails.
+    pub async fn check_error(&self, error: &Error<B>) -> Result<Option<ServiceRoot<B>>, Error<B>> {
+        match error {
+            Error::Bmc(error) => match session_rejected::<B>(error) {
+                Some(evidence) => {
+                    let _recovery = self.recovery.lock().await;
+                    self.recover(evidence).await.map(Some)
+                }
+                None => Ok(None),
+            },
+            _ => Ok(None),
+        }
+    }
+
+    /// Check the `UUID` of the service root if a previous request
+    /// failed without HTTP response. Used by [`RestartDetectingBmc`]
+    /// before requests.
+    async fn verify_suspected(&self) {
+        if !self.suspected.load(Ordering::Acquire) {
+            return;
+        }
+        let _recovery = self.recovery.lock().await;
+        if self.suspected.swap(false, Ordering::AcqRel) && self.check_uuid().await.is_err() {
+            // The service is still unreachable.
+            self.suspected.store(true, Ordering::Release);
+        }
+    }
+
+    /// Handle `error` of the request sent at recovery `generation`.
+    /// Returns `true` if the service was recovered after the request
+    /// and the request should be sent again.
+    async fn recover_after(&self, error: &B::Error, generation: u64) -> bool {
+        let Some(evidence) = session_rejected::<B>(error) else {
+            if B::error_status_code(error).is_none() && B::error_response_body(error).is_none() {
+                self.suspected.store(true, Ordering::Release);
+            }
+            return false;
+        };
+        let _recovery = self.recovery.lock().await;
+        if self.generation.load(Ordering::Acquire) != generation {
+            // Recovered by a concurrent request.
+            return true;
+        }
+        self.recover(evidence).await.is_ok()
+    }
+
+    #[allow(clippy::panic)] // See subscribe Panic doc.
+    async fn recover(&self, evidence: RestartEvidence) -> Result<ServiceRoot<B>, Error<B>> {
+        self.bmc.invalidate_cache();
+        let root = ServiceRoot::new(self.bmc.clone()).await?;
+        #[cfg(feature = "session-service")]
+        if let Some(renewal) = &self.session_renewal {
+            let response = self
+                .bmc
+                .create_session::<_, SessionSchema>(
+                    root.root.links.sessions.odata_id(),
+                    &renewal.create,
+                )
+                .await
+                .map_err(Error::Bmc)?;
+            (renewal.install)(&response.auth_token);
+        }
+        *self.uuid.lock().expect("poisoned") = root.root.uuid;
+        self.generation.fetch_add(1, Ordering::AcqRel);
+
+        let event = ServiceEvent::ServiceRestarted(evidence);
+        self.subscribers
+            .lock()
+            .expect("poisoned")
+            .retain(|subscriber| subscriber.unbounded_send(event.clone()).is_ok());
+        Ok(root)
+    }
+}
+
+/// Evidence of the restart reported by `error`, if the service
+/// rejected the client session.
+fn session_rejected<B: Bmc>(error: &B::Error) -> Option<RestartEvidence> {
+    B::error_status_code(error)
+        .filter(|status| SESSION_REJECTED_STATUS_CODES.contains(status))
+        .map(RestartEvidence::SessionRejected)
+}
+
+/// BMC that detects Redfish service restarts automatically.
+///
+/// Requests are forwarded to the BMC monitored by [`RestartMonitor`].
+/// Requests rejected with HTTP 401 or 410 trigger recovery (see
+/// [`RestartMonitor::check_error`]) and are sent again once when it
+/// succeeds. Uploads are not sent again because their body is already
+/// consumed. Such rejections are also reported for invalid
+/// credentials, in which case the original error is returned after
+/// recovery attempt.
+///
+/// After a request failed without HTTP response, the `UUID` of the
+/// service root is checked (see [`RestartMonitor::check`]) before the
+/// next request.
+pub struct RestartDetectingBmc<B: Bmc> {
+    monitor: Arc<RestartMonitor<B>>,
+}
+
+impl<B: Bmc> RestartDetectingBmc<B> {
+    /// Create BMC that forwards requests to the BMC of `monitor`.
+    #[must_use]
+    pub const fn new(monitor: Arc<RestartMonitor<B>>) -> Self {
+        Self { monitor }
+    }
+
+    /// Monitor of the service restarts.
+    #[must_use]
+    pub const fn monitor(&self) -> &Arc<RestartMonitor<B>> {
+        &self.monitor
+    }
+
+    fn bmc(&self) -> &B {
+        self.monitor.bmc.as_ref()
+    }
+
+    async fn run<T, F, Fut>(&self, request: F) -> Result<T, B::Error>
+    where
+        F: Fn() -> Fut + Send,
+        Fut: Future<Output = Result<T, B::Error>> + Send,
+    {
+        self.monitor.verify_suspected().await;
+        let generation = self.monitor.generation.load(Ordering::Acquire);
+        let err = match request().await {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        if self.monitor.recover_after(&err, generation).await {
+            request().await
+        } else {
+            Err(err)
+        }
+    }
+
+    async fn run_once<T, Fut>(&self, request: Fut) -> Result<T, B::Error>
+    where
+        Fut: Future<Output = Result<T, B::Error>> + Send,
+    {
+        self.monitor.verify_suspected().await;
+        let generation = self.monitor.generation.load(Ordering::Acquire);
+        let err = match request.await {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+        self.monitor.recover_after(&err, generation).await;
+        Err(err)
+    }
+}
+
+impl<B: Bmc + 'static> Bmc for RestartDetectingBmc<B>
+where
+    B::Error: 'static,
+{
+    type Error = B::Error;
+
+    async fn expand<T: Expandable>(
+        &self,
+        id: &ODataId,
+        query: ExpandQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.run(|| self.bmc().expand(id, query.clone())).await
+    }
+
+    async fn get<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.run(|| self.bmc().get(id)).await
+    }
+
+    async fn get_if_none_match<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        etag: &ODataETag,
+    ) -> Result<Option<Arc<T>>, Self::Error> {
+        self.run(|| self.bmc().get_if_none_match(id, etag)).await
+    }
+
+    async fn filter<T: EntityTypeRef + for<'de> Deserialize<'de> + 'static>(
+        &self,
+        id: &ODataId,
+        query: FilterQuery,
+    ) -> Result<Arc<T>, Self::Error> {
+        self.run(|| self.bmc().filter(id, query.clone())).await
+    }
+
+    async fn create<V: Send + Sync + Serialize, R: Send + Sync + for<'de> Deserialize<'de>>(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.run(|| self.bmc().create(id, query)).await
+    }
+
+    async fn create_session<
+        V: Send + Sync + Serialize,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        query: &V,
+    ) -> Result<SessionCreateResponse<R>, Self::Error> {
+        // Rejected session creation reports invalid credentials, not
+        // the restart.
+        self.bmc().create_session(id, query).await
+    }
+
+    async fn update<
+        V: Sync + Send + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        id: &ODataId,
+        etag: Option<&ODataETag>,
+        update: &V,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.run(|| self.bmc().update(id, etag, update)).await
+    }
+
+    async fn delete<R: Send + Sync + Sized + for<'de> Deserialize<'de>>(
+        &self,
+        id: &ODataId,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.run(|| self.bmc().delete(id)).await
+    }
+
+    async fn allowed_methods(&self, id: &ODataId) -> Result<Option<AllowedMethods>, Self::Error> {
+        self.run(|| self.bmc().allowed_methods(id)).await
+    }
+
+    async fn action<
+        T: Send + Sync + Serialize,
+        R: Send + Sync + Sized + for<'de> Deserialize<'de>,
+    >(
+        &self,
+        action: &Action<T, R>,
+        params: &T,
+    ) -> Result<ModificationResponse<R>, Self::Error> {
+        self.run(|| self.bmc().action(action, params)).await
+    }
+
+    async fn multipart_update<U, V, R>(
+        &self,
+        uri: &str,
+        request: MultipartUpdateRequest<'_, U, V>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+        V: Send + Sync + Serialize,
+    {
+        self.run_once(self.bmc().multipart_update(uri, request))
+            .await
+    }
+
+    #[cfg(feature = "update-service-deprecated")]
+    async fn http_push_uri_update<U, R>(
+        &self,
+        uri: &str,
+        request: HttpPushUriUpdateRequest<U>,
+    ) -> Result<ModificationResponse<R>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        self.run_once(self.bmc().http_push_uri_update(uri, request))
+            .await
+    }
+
+    async fn binary_download(&self, uri: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+        self.run(|| self.bmc().binary_download(uri)).await
+    }
+
+    async fn get_json<T>(&self, uri: &str) -> Result<T, Self::Error>
+    where
+        T: for<'de> Deserialize<'de> + Clone + Send + Sync + 'static,
+    {
+        self.run(|| self.bmc().get_json(uri)).await
+    }
+
+    async fn task_monitor(&self, uri: &str) -> Result<Option<TaskMonitorResponse>, Self::Error> {
+        self.run(|| self.bmc().task_monitor(uri)).await
+    }
+
+    async fn binary_upload<U, R>(
+        &self,
+        uri: &str,
+        request: BinaryUploadRequest<U>,
+    ) -> Result<Option<ModificationResponse<R>>, Self::Error>
+    where
+        U: UploadReader,
+        R: Send + Sync + for<'de> Deserialize<'de>,
+    {
+        self.run_once(self.bmc().binary_upload(uri, request)).await
+    }
+
+    fn set_etag_policy(&self, policy: ETagPolicy) {
+        self.bmc().set_etag_policy(policy);
+    }
+
+    fn set_null_policy(&self, policy: NullPolicy) {
+        self.bmc().set_null_policy(policy);
+    }
+
+    fn invalidate_cache(&self) {
+        self.bmc().invalidate_cache();
+    }
+
+    fn cache_entity<T: EntityTypeRef + 'static>(&self, entity: &Arc<T>) {
+        self.bmc().cache_entity(entity);
+    }
+
+    fn latency_class(&self) -> Option<LatencyClass> {
+        self.bmc().latency_class()
+    }
+
+    fn error_status_code(error: &Self::Error) -> Option<u16> {
+        B::error_status_code(error)
+    }
+
+    fn error_response_body(error: &Self::Error) -> Option<&str> {
+        B::error_response_body(error)
+    }
+
+    fn error_response_language(error: &Self::Error) -> Option<&str> {
+        B::error_response_language(error)
+    }
+
+    fn error_odata_type(error: &Self::Error) -> Option<&str> {
+        B::error_odata_type(error)
+    }
+
+    fn redfish_error(error: &Self::Error) -> Option<RedfishError> {
+        B::redfish_error(error)
+    }
+
+    async fn stream<T: Sized + for<'de> Deserialize<'de> + Send + 'static>(
+        &self,
+        uri: &str,
+    ) -> Result<BoxTryStream<T, Self::Error>, Self::Error> {
+        self.run(|| self.bmc().stream(uri)).await
+    }
+}
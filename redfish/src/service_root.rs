@@ -15,17 +15,36 @@
 
 use std::sync::Arc;
 
-use crate::bmc_quirks::BmcQuirks;
+#[cfg(all(
+    feature = "cancellation",
+    any(feature = "chassis", feature = "computer-systems")
+))]
+use crate::cancellation::run_until_cancelled;
+#[cfg(all(
+    feature = "cancellation",
+    any(feature = "chassis", feature = "computer-systems")
+))]
+use crate::cancellation::CancellationToken;
 use crate::core::Bmc;
+use crate::core::EntityTypeRef;
 use crate::core::NavProperty;
+use crate::core::ODataETag;
 use crate::core::ODataId;
+use crate::core::ReferenceLeaf;
+use crate::member_sync;
+use crate::member_sync::MemberChanges;
+use crate::member_sync::MemberSyncState;
 use crate::schema::service_root::ServiceRoot as SchemaServiceRoot;
 use crate::Error;
 use crate::NvBmc;
+use crate::NvBmcBuilder;
 use crate::ProtocolFeatures;
 use crate::Resource;
 use crate::ResourceSchema;
 
+use serde::Deserialize;
+use serde::Deserializer;
+use serde_json::Value as JsonValue;
 use tagged_types::TaggedType;
 
 #[cfg(feature = "accounts")]
@@ -34,10 +53,28 @@ use crate::account::AccountService;
 use crate::chassis::ChassisCollection;
 #[cfg(feature = "chassis")]
 use crate::chassis::ChassisLink;
+#[cfg(feature = "component-integrity")]
+use crate::component_integrity::AttestationPolicy;
+#[cfg(feature = "component-integrity")]
+use crate::component_integrity::ComponentIntegrityCollection;
 #[cfg(feature = "computer-systems")]
 use crate::computer_system::SystemCollection;
 #[cfg(feature = "event-service")]
 use crate::event_service::EventService;
+#[cfg(feature = "fabrics")]
+use crate::fabric::FabricCollection;
+#[cfg(any(feature = "chassis", feature = "computer-systems"))]
+use crate::hardware_id::HardwareInventoryItem;
+#[cfg(any(feature = "chassis", feature = "computer-systems"))]
+use crate::hardware_id::HardwareKind;
+#[cfg(feature = "license-service")]
+use crate::license_service::LicenseService;
+#[cfg(feature = "managers")]
+use crate::manager::ClockCorrection;
+#[cfg(feature = "managers")]
+use crate::manager::ClockSkew;
+#[cfg(feature = "managers")]
+use crate::manager::Manager;
 #[cfg(feature = "managers")]
 use crate::manager::ManagerCollection;
 #[cfg(feature = "oem-ami")]
@@ -46,6 +83,10 @@ use crate::oem::ami::AmiServiceRoot;
 use crate::oem::hpe::HpeiLoServiceExt;
 #[cfg(feature = "power-equipment")]
 use crate::power_equipment::PowerEquipment;
+#[cfg(feature = "message-registries")]
+use crate::registries::AttributeRegistry;
+#[cfg(feature = "message-registries")]
+use crate::registries::MessageRegistries;
 #[cfg(feature = "session-service")]
 use crate::session_service::SessionService;
 #[cfg(feature = "task-service")]
@@ -54,6 +95,14 @@ use crate::task_service::TaskService;
 use crate::telemetry_service::TelemetryService;
 #[cfg(feature = "update-service")]
 use crate::update_service::UpdateService;
+#[cfg(feature = "message-registries")]
+use std::collections::HashMap;
+#[cfg(feature = "message-registries")]
+use std::sync::Mutex;
+#[cfg(feature = "managers")]
+use std::time::Duration;
+#[cfg(feature = "managers")]
+use std::time::SystemTime;
 
 /// The vendor or manufacturer associated with Redfish service.
 pub type Vendor<T> = TaggedType<T, VendorTag>;
@@ -82,12 +131,68 @@ pub type RedfishVersion<'a> = TaggedType<&'a str, RedfishVersionTag>;
 #[capability(inner_access, cloned)]
 pub enum RedfishVersionTag {}
 
+/// Resource module features and whether they are compiled in.
+const MODULES: &[(&str, bool)] = &[
+    ("accounts", cfg!(feature = "accounts")),
+    ("assembly", cfg!(feature = "assembly")),
+    ("batteries", cfg!(feature = "batteries")),
+    ("bios", cfg!(feature = "bios")),
+    ("boot-options", cfg!(feature = "boot-options")),
+    ("chassis", cfg!(feature = "chassis")),
+    ("component-integrity", cfg!(feature = "component-integrity")),
+    ("computer-systems", cfg!(feature = "computer-systems")),
+    ("controls", cfg!(feature = "controls")),
+    ("environment-metrics", cfg!(feature = "environment-metrics")),
+    ("ethernet-interfaces", cfg!(feature = "ethernet-interfaces")),
+    ("event-service", cfg!(feature = "event-service")),
+    ("fabric-adapters", cfg!(feature = "fabric-adapters")),
+    ("fabrics", cfg!(feature = "fabrics")),
+    (
+        "graphics-controllers",
+        cfg!(feature = "graphics-controllers"),
+    ),
+    ("host-interfaces", cfg!(feature = "host-interfaces")),
+    ("license-service", cfg!(feature = "license-service")),
+    ("log-services", cfg!(feature = "log-services")),
+    ("managers", cfg!(feature = "managers")),
+    (
+        "manager-network-protocol",
+        cfg!(feature = "manager-network-protocol"),
+    ),
+    ("memory", cfg!(feature = "memory")),
+    ("message-registries", cfg!(feature = "message-registries")),
+    ("network-adapters", cfg!(feature = "network-adapters")),
+    (
+        "network-device-functions",
+        cfg!(feature = "network-device-functions"),
+    ),
+    ("pcie-devices", cfg!(feature = "pcie-devices")),
+    ("power", cfg!(feature = "power")),
+    ("power-equipment", cfg!(feature = "power-equipment")),
+    ("power-supplies", cfg!(feature = "power-supplies")),
+    ("processors", cfg!(feature = "processors")),
+    ("secure-boot", cfg!(feature = "secure-boot")),
+    ("sensors", cfg!(feature = "sensors")),
+    ("session-service", cfg!(feature = "session-service")),
+    ("storages", cfg!(feature = "storages")),
+    ("task-service", cfg!(feature = "task-service")),
+    ("telemetry-service", cfg!(feature = "telemetry-service")),
+    ("thermal", cfg!(feature = "thermal")),
+    ("update-service", cfg!(feature = "update-service")),
+    ("usb-controllers", cfg!(feature = "usb-controllers")),
+    ("virtual-media", cfg!(feature = "virtual-media")),
+];
+
 /// Represents `ServiceRoot` in the BMC model.
 pub struct ServiceRoot<B: Bmc> {
     /// Content of the root.
     pub root: Arc<SchemaServiceRoot>,
     #[allow(dead_code)] // feature-enabled field
     bmc: NvBmc<B>,
+    /// Message registries downloaded by language. Shared by clones of
+    /// the root.
+    #[cfg(feature = "message-registries")]
+    message_registries: Arc<Mutex<HashMap<String, MessageRegistries>>>,
 }
 
 // Implement Clone manually to avoid requiring B: Clone; cloning only
@@ -97,6 +202,8 @@ impl<B: Bmc> Clone for ServiceRoot<B> {
         Self {
             root: self.root.clone(),
             bmc: self.bmc.clone(),
+            #[cfg(feature = "message-registries")]
+            message_registries: self.message_registries.clone(),
         }
     }
 }
@@ -112,36 +219,110 @@ impl<B: Bmc> ServiceRoot<B> {
             .get(bmc.as_ref())
             .await
             .map_err(Error::Bmc)?;
-        let quirks = BmcQuirks::new(&root);
-        let mut protocol_features = root
-            .protocol_features_supported
-            .as_ref()
-            .map(ProtocolFeatures::new)
-            .unwrap_or_default();
+        let bmc = NvBmcBuilder::from_root(bmc, &root).build();
+        Ok(Self::from_parts(root, bmc))
+    }
+
+    /// Create a new service root that uses explicitly configured
+    /// `bmc` instead of configuration detected from the root.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the root path via Redfish fails.
+    pub async fn with_bmc(bmc: NvBmc<B>) -> Result<Self, Error<B>> {
+        let root = NavProperty::<SchemaServiceRoot>::new_reference(ODataId::service_root())
+            .get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)?;
+        Ok(Self::from_parts(root, bmc))
+    }
 
-        if quirks.expand_is_not_working_properly() {
-            protocol_features.expand.expand_all = false;
-            protocol_features.expand.no_links = false;
+    fn from_parts(root: Arc<SchemaServiceRoot>, bmc: NvBmc<B>) -> Self {
+        Self {
+            root,
+            bmc,
+            #[cfg(feature = "message-registries")]
+            message_registries: Arc::default(),
         }
+    }
+
+    /// Read the service root again. Protocol features and quirks are
+    /// detected from the new root (see [`NvBmcBuilder::from_root`]), so
+    /// changes made by a firmware update are taken in account.
+    /// Settings of the BMC that don't depend on the root (aggregation
+    /// routing, fan-out limit and adaptation to slow BMC) are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the root path via Redfish fails.
+    pub async fn refresh(&self) -> Result<Self, Error<B>> {
+        let root = NavProperty::<SchemaServiceRoot>::new_reference(ODataId::service_root())
+            .get(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)?;
+        let bmc = NvBmcBuilder::from_root(self.bmc.shared(), &root)
+            .settings_of(&self.bmc)
+            .build();
+        Ok(Self::from_parts(root, bmc))
+    }
 
-        let bmc = NvBmc::new(bmc, protocol_features, quirks);
-        Ok(Self { root, bmc })
+    /// Create a new service root and verify attestation evidence of
+    /// the service against `policy` before returning it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::AttestationFailed` if the policy rejects the
+    /// evidence, or error if retrieving the root or evidence fails.
+    #[cfg(feature = "component-integrity")]
+    pub async fn new_attested(
+        bmc: Arc<B>,
+        policy: &impl AttestationPolicy,
+    ) -> Result<Self, Error<B>> {
+        let root = Self::new(bmc).await?;
+        root.attest(policy).await?;
+        Ok(root)
     }
 
     /// Replace BMC in this root.
     #[must_use]
     pub fn replace_bmc(self, bmc: Arc<B>) -> Self {
-        let root = self.root;
         let bmc = self.bmc.replace_bmc(bmc);
-        Self { root, bmc }
+        Self { bmc, ..self }
     }
 
     /// Restrict usage of expand.
     #[must_use]
     pub fn restrict_expand(self) -> Self {
-        let root = self.root;
         let bmc = self.bmc.restrict_expand();
-        Self { root, bmc }
+        Self { bmc, ..self }
+    }
+
+    /// Protocol features advertised by the service and taken in
+    /// account by this root (for example, expand is not reported
+    /// after [`Self::restrict_expand`]).
+    #[must_use]
+    pub fn protocol_features(&self) -> &ProtocolFeatures {
+        self.bmc.protocol_features()
+    }
+
+    /// Shared BMC of this service root.
+    pub(crate) fn shared_bmc(&self) -> Arc<B> {
+        self.bmc.shared()
+    }
+
+    /// Names of cargo features of resource modules compiled into this
+    /// build of the crate.
+    ///
+    /// Allows dynamic applications to report why some functionality is
+    /// absent. See also `stubs` feature that turns calls to accessors
+    /// of disabled features into `Error::FeatureNotCompiled` errors.
+    #[must_use]
+    pub fn available_modules() -> Vec<&'static str> {
+        MODULES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| *name)
+            .collect()
     }
 
     /// The vendor or manufacturer associated with this Redfish service.
@@ -172,6 +353,87 @@ impl<B: Bmc> ServiceRoot<B> {
             .map(RedfishVersion::new)
     }
 
+    /// Identifier of the message registry file collection.
+    ///
+    /// Available regardless of enabled features.
+    #[must_use]
+    pub fn registries_id(&self) -> Option<&ODataId> {
+        self.root.registries.as_ref().map(LinkId::link_id)
+    }
+
+    /// Identifier of the JSON schema file collection.
+    ///
+    /// Available regardless of enabled features.
+    #[must_use]
+    pub fn json_schemas_id(&self) -> Option<&ODataId> {
+        self.root.json_schemas.as_ref().map(LinkId::link_id)
+    }
+
+    /// Identifier of the session service.
+    ///
+    /// Available regardless of enabled features.
+    #[must_use]
+    pub fn session_service_id(&self) -> Option<&ODataId> {
+        self.root.session_service.as_ref().map(LinkId::link_id)
+    }
+
+    /// Identifier of the task service.
+    ///
+    /// Available regardless of enabled features.
+    #[must_use]
+    pub fn task_service_id(&self) -> Option<&ODataId> {
+        self.root.tasks.as_ref().map(LinkId::link_id)
+    }
+
+    /// Identifier of the event service.
+    ///
+    /// Available regardless of enabled features.
+    #[must_use]
+    pub fn event_service_id(&self) -> Option<&ODataId> {
+        self.root.event_service.as_ref().map(LinkId::link_id)
+    }
+
+    /// Fetch raw JSON of any resource of the BMC.
+    ///
+    /// Useful for services that are not modeled by this crate or not
+    /// enabled by features, together with identifiers returned by
+    /// methods like [`Self::registries_id`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the resource fails.
+    pub async fn raw_json(&self, id: &ODataId) -> Result<JsonValue, Error<B>> {
+        NavProperty::<RawResource>::new_reference(id.clone())
+            .get(self.bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(|resource| resource.payload.clone())
+    }
+
+    /// Sync members of the `collection` (for example,
+    /// `/redfish/v1/Systems`) with the state of the previous sync.
+    ///
+    /// Returns members added to the collection, members whose ETag
+    /// changed and identifiers of removed members, together with the
+    /// state for the next sync. Pass [`MemberSyncState::default`] for
+    /// the first sync. Periodic polls of large collections then don't
+    /// need to expand them: with `HttpBmc` members that didn't change
+    /// are answered with `304 Not Modified`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the collection or its members fails.
+    pub async fn sync_members<T>(
+        &self,
+        collection: &ODataId,
+        previous: &MemberSyncState,
+    ) -> Result<MemberChanges<T>, Error<B>>
+    where
+        T: EntityTypeRef + for<'de> Deserialize<'de> + 'static,
+    {
+        member_sync::sync_members(&self.bmc, collection, previous).await
+    }
+
     /// Get the account service belonging to the BMC.
     ///
     /// Returns `Ok(None)` when the BMC does not expose AccountService.
@@ -184,6 +446,68 @@ impl<B: Bmc> ServiceRoot<B> {
         AccountService::new(&self.bmc, self).await
     }
 
+    /// Get the element manager that is authoritative for the resource
+    /// identified by `resource`.
+    ///
+    /// In an aggregation service, writes to aggregated resources
+    /// sometimes have to be issued to their element managers. The
+    /// element manager is the manager that represents a remote manager
+    /// (see [`Manager::is_element_manager`]) and manages the resource
+    /// or the system, chassis or switch that contains it (see
+    /// [`Manager::manages`]). Write operations of the returned manager
+    /// (reset, date and time, network protocol, virtual media,
+    /// accounts of `RemoteAccountService`) target the element manager.
+    ///
+    /// Returns `Ok(None)` if there is no such manager or routing is
+    /// disabled by [`NvBmcBuilder::disable_aggregation_routing`].
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving managers fails.
+    #[cfg(feature = "managers")]
+    pub async fn element_manager_for(
+        &self,
+        resource: &ODataId,
+    ) -> Result<Option<Manager<B>>, Error<B>> {
+        if !self.bmc.aggregation_routing {
+            return Ok(None);
+        }
+        let Some(managers) = self.managers().await? else {
+            return Ok(None);
+        };
+        Ok(managers
+            .members()
+            .await?
+            .into_iter()
+            .find(|manager| manager.is_element_manager() && manager.manages(resource)))
+    }
+
+    /// Get the account service that is authoritative for the resource
+    /// identified by `resource`, for example a computer system.
+    ///
+    /// In an aggregation service, accounts of aggregated systems are
+    /// managed by their element managers. If the element manager of
+    /// the resource (see [`Self::element_manager_for`]) provides
+    /// `RemoteAccountService`, it is returned. Otherwise, the account
+    /// service of this service is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving managers or account service data
+    /// fails.
+    #[cfg(all(feature = "accounts", feature = "managers"))]
+    pub async fn account_service_for_system(
+        &self,
+        resource: &ODataId,
+    ) -> Result<Option<AccountService<B>>, Error<B>> {
+        if let Some(manager) = self.element_manager_for(resource).await? {
+            if let Some(service) = manager.remote_account_service().await? {
+                return Ok(Some(service));
+            }
+        }
+        self.account_service().await
+    }
+
     /// Get chassis collection in BMC
     ///
     /// Returns `Ok(None)` when the BMC does not expose Chassis.
@@ -236,6 +560,145 @@ impl<B: Bmc> ServiceRoot<B> {
         SystemCollection::new(&self.bmc, self).await
     }
 
+    /// Get fabric collection in BMC
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose Fabrics.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving fabric collection data fails.
+    #[cfg(feature = "fabrics")]
+    pub async fn fabrics(&self) -> Result<Option<FabricCollection<B>>, Error<B>> {
+        FabricCollection::new(&self.bmc, self).await
+    }
+
+    /// Collect hardware identifiers of all chassis and computer systems
+    /// together with their components into a flat inventory list.
+    ///
+    /// Components are walked only when the corresponding features are
+    /// enabled: `network-adapters` for chassis, and `processors`,
+    /// `memory` and `storages` for computer systems.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving any of the walked resources fails.
+    #[cfg(any(feature = "chassis", feature = "computer-systems"))]
+    pub async fn collect_hardware_ids(&self) -> Result<Vec<HardwareInventoryItem>, Error<B>> {
+        let mut inventory = Vec::new();
+        #[cfg(feature = "chassis")]
+        if let Some(collection) = self.chassis().await? {
+            for chassis in collection.members().await? {
+                inventory.push(HardwareInventoryItem::new(
+                    HardwareKind::Chassis,
+                    chassis.odata_id(),
+                    chassis.hardware_id(),
+                ));
+                #[cfg(feature = "network-adapters")]
+                for adapter in chassis.network_adapters().await?.unwrap_or_default() {
+                    inventory.push(HardwareInventoryItem::new(
+                        HardwareKind::NetworkAdapter,
+                        adapter.odata_id(),
+                        adapter.hardware_id(),
+                    ));
+                }
+            }
+        }
+        #[cfg(feature = "computer-systems")]
+        if let Some(collection) = self.systems().await? {
+            for system in collection.members().await? {
+                inventory.push(HardwareInventoryItem::new(
+                    HardwareKind::ComputerSystem,
+                    system.odata_id(),
+                    system.hardware_id(),
+                ));
+                #[cfg(feature = "processors")]
+                for processor in system.processors().await?.unwrap_or_default() {
+                    inventory.push(HardwareInventoryItem::new(
+                        HardwareKind::Processor,
+                        processor.odata_id(),
+                        processor.hardware_id(),
+                    ));
+                }
+                #[cfg(feature = "memory")]
+                for memory in system.memory_modules().await?.unwrap_or_default() {
+                    inventory.push(HardwareInventoryItem::new(
+                        HardwareKind::Memory,
+                        memory.odata_id(),
+                        memory.hardware_id(),
+                    ));
+                }
+                #[cfg(feature = "storages")]
+                for storage in system.storage_controllers().await?.unwrap_or_default() {
+                    for drive in storage.drives().await?.unwrap_or_default() {
+                        inventory.push(HardwareInventoryItem::new(
+                            HardwareKind::Drive,
+                            drive.odata_id(),
+                            drive.hardware_id(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(inventory)
+    }
+
+    /// Collect hardware identifiers like [`Self::collect_hardware_ids`]
+    /// or stop the walk when `token` is cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] if `token` is cancelled and errors
+    /// of [`Self::collect_hardware_ids`] otherwise.
+    #[cfg(all(
+        feature = "cancellation",
+        any(feature = "chassis", feature = "computer-systems")
+    ))]
+    pub async fn collect_hardware_ids_cancellable(
+        &self,
+        token: &CancellationToken,
+    ) -> Result<Vec<HardwareInventoryItem>, Error<B>> {
+        run_until_cancelled(token, self.collect_hardware_ids()).await
+    }
+
+    /// Get component integrity collection in BMC
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose ComponentIntegrity.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving component integrity collection fails.
+    #[cfg(feature = "component-integrity")]
+    pub async fn component_integrity(
+        &self,
+    ) -> Result<Option<ComponentIntegrityCollection<B>>, Error<B>> {
+        if let Some(nav) = &self.root.component_integrity {
+            ComponentIntegrityCollection::new(&self.bmc, nav)
+                .await
+                .map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Verify attestation evidence of the service against `policy`.
+    ///
+    /// Evidence is empty if the service does not expose
+    /// ComponentIntegrity, so the policy decides whether such services
+    /// are trusted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::AttestationFailed` if the policy rejects the
+    /// evidence, or error if retrieving evidence fails.
+    #[cfg(feature = "component-integrity")]
+    pub async fn attest(&self, policy: &impl AttestationPolicy) -> Result<(), Error<B>> {
+        let evidence = match self.component_integrity().await? {
+            Some(collection) => collection.evidence().await?,
+            None => Vec::new(),
+        };
+        policy.verify(&evidence).map_err(Error::AttestationFailed)
+    }
+
     /// Get update service in BMC
     ///
     /// Returns `Ok(None)` when the BMC does not expose UpdateService.
@@ -272,6 +735,18 @@ impl<B: Bmc> ServiceRoot<B> {
         EventService::new(&self.bmc, self).await
     }
 
+    /// Get license service in BMC
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose LicenseService.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving license service data fails.
+    #[cfg(feature = "license-service")]
+    pub async fn license_service(&self) -> Result<Option<LicenseService<B>>, Error<B>> {
+        LicenseService::new(&self.bmc, self).await
+    }
+
     /// Get telemetry service in BMC
     ///
     /// Returns `Ok(None)` when the BMC does not expose TelemetryService.
@@ -284,6 +759,89 @@ impl<B: Bmc> ServiceRoot<B> {
         TelemetryService::new(&self.bmc, self).await
     }
 
+    /// Download message registries of the service.
+    ///
+    /// Registry files are taken from the registry file collection
+    /// (`/redfish/v1/Registries`) in `language` (for example, `en`),
+    /// or in English when a registry is not available in `language`.
+    /// Registries that the service only publishes externally are
+    /// skipped.
+    ///
+    /// Registries are downloaded once per language and cached by the
+    /// root (and its clones); a refreshed root downloads them again.
+    /// Registry files that cannot be read or parsed are skipped and
+    /// reported by [`MessageRegistries::failures`].
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose Registries.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the registry file collection fails.
+    #[cfg(feature = "message-registries")]
+    pub async fn message_registries(
+        &self,
+        language: &str,
+    ) -> Result<Option<MessageRegistries>, Error<B>> {
+        if let Some(cached) = self.cached_message_registries(language) {
+            return Ok(Some(cached));
+        }
+        let registries = MessageRegistries::fetch(&self.bmc, self, language).await?;
+        if let Some(registries) = &registries {
+            if let Ok(mut cache) = self.message_registries.lock() {
+                cache.insert(language.to_string(), registries.clone());
+            }
+        }
+        Ok(registries)
+    }
+
+    #[cfg(feature = "message-registries")]
+    fn cached_message_registries(&self, language: &str) -> Option<MessageRegistries> {
+        self.message_registries
+            .lock()
+            .ok()
+            .and_then(|cache| cache.get(language).cloned())
+    }
+
+    /// Recovery guidance for `error` built with message registries of
+    /// the service.
+    ///
+    /// Registries are downloaded (see [`Self::message_registries`]) in
+    /// the language of the error response, or in English when the
+    /// service didn't report it, so guidance follows the registry the
+    /// service localized its messages with. Messages that are not
+    /// defined by the registries fall back to built-in guidance (see
+    /// [`Error::recovery_guidance`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the registry file collection fails.
+    #[cfg(feature = "message-registries")]
+    pub async fn recovery_guidance(&self, error: &Error<B>) -> Result<Vec<String>, Error<B>> {
+        let language = error.response_language().unwrap_or("en");
+        let registries = self.message_registries(language).await?;
+        Ok(error.recovery_guidance(registries.as_ref()))
+    }
+
+    /// Download attribute registry `registry` (for example, the value
+    /// of `Bios.AttributeRegistry`) in `language`, or in English when
+    /// the registry is not available in `language`.
+    ///
+    /// Returns `Ok(None)` when the BMC does not expose Registries or
+    /// doesn't host the registry.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving the registry file fails or the file
+    /// is not an attribute registry.
+    #[cfg(feature = "message-registries")]
+    pub async fn attribute_registry(
+        &self,
+        registry: &str,
+        language: &str,
+    ) -> Result<Option<AttributeRegistry>, Error<B>> {
+        AttributeRegistry::fetch(&self.bmc, self, registry, language).await
+    }
+
     /// Get session service in BMC
     ///
     /// Returns `Ok(None)` when the BMC does not expose SessionService.
@@ -308,6 +866,76 @@ impl<B: Bmc> ServiceRoot<B> {
         ManagerCollection::new(&self.bmc, self).await
     }
 
+    /// Get the largest clock skew among managers of this BMC.
+    ///
+    /// Skew of each manager is measured by [`Manager::measure_clock_skew`]
+    /// around an individual request of the manager. Returns `Ok(None)`
+    /// when no manager reports `DateTime`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving manager data fails.
+    #[cfg(feature = "managers")]
+    pub async fn clock_skew(&self) -> Result<Option<ClockSkew>, Error<B>> {
+        let Some(managers) = self.managers().await? else {
+            return Ok(None);
+        };
+        let mut max_skew: Option<ClockSkew> = None;
+        for manager in managers.members().await? {
+            let (_, Some(skew)) = manager.measure_clock_skew().await? else {
+                continue;
+            };
+            if max_skew.is_none_or(|max| max.magnitude() < skew.magnitude()) {
+                max_skew = Some(skew);
+            }
+        }
+        Ok(max_skew)
+    }
+
+    /// Correct clocks of managers which skew exceeds `tolerance`.
+    ///
+    /// Returns the largest skew detected before correction, or `Ok(None)`
+    /// when no manager reports `DateTime`.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if retrieving manager data or applying correction
+    /// fails.
+    #[cfg(feature = "managers")]
+    pub async fn correct_clock_skew(
+        &self,
+        tolerance: Duration,
+        correction: &ClockCorrection,
+    ) -> Result<Option<ClockSkew>, Error<B>> {
+        let Some(managers) = self.managers().await? else {
+            return Ok(None);
+        };
+        let mut max_skew: Option<ClockSkew> = None;
+        for manager in managers.members().await? {
+            let (manager, skew) = manager.measure_clock_skew().await?;
+            let Some(skew) = skew else {
+                continue;
+            };
+            if skew.magnitude() > tolerance {
+                match correction {
+                    ClockCorrection::SetDateTime => {
+                        manager.set_date_time(SystemTime::now().into()).await?;
+                    }
+                    #[cfg(feature = "manager-network-protocol")]
+                    ClockCorrection::Ntp(servers) => {
+                        if let Some(protocol) = manager.network_protocol().await? {
+                            protocol.enable_ntp(servers.clone()).await?;
+                        }
+                    }
+                }
+            }
+            if max_skew.is_none_or(|max| max.magnitude() < skew.magnitude()) {
+                max_skew = Some(skew);
+            }
+        }
+        Ok(max_skew)
+    }
+
     /// Get power equipment in BMC
     ///
     /// Returns `Ok(None)` when the BMC does not expose PowerEquipment.
@@ -346,7 +974,60 @@ impl<B: Bmc> ServiceRoot<B> {
 }
 
 impl<B: Bmc> Resource for ServiceRoot<B> {
+    type Entity = SchemaServiceRoot;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.root.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.root
+    }
+}
+
+/// Navigation properties are compiled to typed references only when
+/// the feature of the target resource is enabled. Otherwise, they are
+/// compiled to `ReferenceLeaf`. This trait provides identifier in both
+/// cases.
+pub(crate) trait LinkId {
+    fn link_id(&self) -> &ODataId;
+}
+
+impl<T: EntityTypeRef> LinkId for NavProperty<T> {
+    fn link_id(&self) -> &ODataId {
+        self.id()
+    }
+}
+
+impl LinkId for ReferenceLeaf {
+    fn link_id(&self) -> &ODataId {
+        &self.odata_id
+    }
+}
+
+/// Resource of any type with untouched JSON payload.
+struct RawResource {
+    id: ODataId,
+    payload: JsonValue,
+}
+
+impl EntityTypeRef for RawResource {
+    fn odata_id(&self) -> &ODataId {
+        &self.id
+    }
+    fn etag(&self) -> Option<&ODataETag> {
+        None
+    }
+}
+
+impl<'de> Deserialize<'de> for RawResource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Self {
+            id: String::new().into(),
+            payload: JsonValue::deserialize(deserializer)?,
+        })
+    }
 }
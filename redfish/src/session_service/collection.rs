@@ -15,6 +15,8 @@
 
 //! Session collection utilities.
 
+#[cfg(feature = "bmc-http")]
+use crate::bmc_http::BmcCredentials;
 use crate::schema::session::Session as SessionSchema;
 use crate::schema::session_collection::SessionCollection as SessionCollectionSchema;
 use crate::session_service::Session;
@@ -23,12 +25,13 @@ use crate::Error;
 use crate::NvBmc;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
 use std::sync::Arc;
 
 /// Session collection.
 ///
-/// Provides functions to list and create sessions.
+/// Provides functions to list, create and close sessions.
 pub struct SessionCollection<B: Bmc> {
     bmc: NvBmc<B>,
     collection: Arc<SessionCollectionSchema>,
@@ -56,6 +59,34 @@ impl<B: Bmc> SessionCollection<B> {
         Ok(members)
     }
 
+    /// Delete (force logout) sessions selected by `filter`.
+    ///
+    /// Sessions are deleted one by one in the collection order. The
+    /// returned responses follow the order of deleted sessions. Note
+    /// that the session used by this client is not excluded
+    /// automatically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching session data or deleting any of
+    /// the selected sessions fails. Sessions that were deleted before
+    /// the failure stay deleted.
+    pub async fn close_sessions<F>(
+        &self,
+        filter: F,
+    ) -> Result<Vec<ModificationResponse<Session<B>>>, Error<B>>
+    where
+        F: Fn(&Session<B>) -> bool,
+    {
+        let mut responses = Vec::new();
+        for session in self.members().await? {
+            if filter(&session) {
+                responses.push(session.delete().await?);
+            }
+        }
+        Ok(responses)
+    }
+
     /// Create a new session.
     ///
     /// # Errors
@@ -75,4 +106,29 @@ impl<B: Bmc> SessionCollection<B> {
             Some(response.location),
         ))
     }
+
+    /// Create a new session with the username and password of
+    /// `credentials`, for example looked up in a
+    /// [`CredentialsProvider`](crate::bmc_http::CredentialsProvider).
+    /// Returns `Ok(None)` if `credentials` don't have both username
+    /// and password.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating the session fails.
+    #[cfg(feature = "bmc-http")]
+    pub async fn create_session_with_credentials(
+        &self,
+        credentials: &BmcCredentials,
+    ) -> Result<Option<Session<B>>, Error<B>> {
+        let BmcCredentials::UsernamePassword {
+            username,
+            password: Some(password),
+        } = credentials
+        else {
+            return Ok(None);
+        };
+        let create = SessionCreate::builder(username.clone(), password.clone()).build();
+        self.create_session(&create).await.map(Some)
+    }
 }
@@ -16,11 +16,13 @@
 //! Redfish Session - high-level wrapper.
 
 use crate::schema::session::Session as SessionSchema;
+use crate::session_service::SessionTypes;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
 use nv_redfish_core::Bmc;
+use nv_redfish_core::EdmDateTimeOffset;
 use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::NavProperty;
@@ -83,6 +85,33 @@ impl<B: Bmc> Session<B> {
         self.delete_location.as_ref()
     }
 
+    /// Name of the user that owns the session.
+    #[must_use]
+    pub fn user_name(&self) -> Option<&str> {
+        self.data.user_name.as_ref().and_then(Option::as_deref)
+    }
+
+    /// IP address of the client that created the session.
+    #[must_use]
+    pub fn client_origin_ip_address(&self) -> Option<&str> {
+        self.data
+            .client_origin_ip_address
+            .as_ref()
+            .and_then(Option::as_deref)
+    }
+
+    /// Date and time when the session was created.
+    #[must_use]
+    pub fn created_time(&self) -> Option<EdmDateTimeOffset> {
+        self.data.created_time
+    }
+
+    /// Type of the session.
+    #[must_use]
+    pub fn session_type(&self) -> Option<SessionTypes> {
+        self.data.session_type.flatten()
+    }
+
     /// Delete the current session.
     ///
     /// Returns one of the following modification outcomes:
@@ -112,7 +141,13 @@ impl<B: Bmc> Session<B> {
 }
 
 impl<B: Bmc> Resource for Session<B> {
+    type Entity = SessionSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
 }
@@ -0,0 +1,183 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Service root shared by long-lived applications.
+//!
+//! [`ServiceRoot`] is a snapshot of the root read when it was created.
+//! [`SharedServiceRoot`] keeps the current root behind a lock, so it
+//! can be shared between tasks and refreshed in place:
+//! [`SharedServiceRoot::refresh`] reads the root again and, if it
+//! changed (for example, new services or protocol features were
+//! advertised after a firmware update), replaces the current root and
+//! sends the new one to all subscribers.
+//!
+//! Service objects created from the previous root keep working: they
+//! use the BMC, not the root. Subscribers only need to create objects
+//! of services that appeared in the new root.
+//!
+//! ```ignore
+//! let shared = Arc::new(SharedServiceRoot::new(root));
+//! let mut changes = shared.subscribe();
+//! // Periodically, or after a firmware update:
+//! shared.refresh().await?;
+//! // In another task:
+//! while let Some(root) = changes.next().await {
+//!     if let Some(update_service) = root.update_service().await? {
+//!         // Service appeared after the firmware update.
+//!     }
+//! }
+//! ```
+
+use crate::Error;
+use crate::ProtocolFeatures;
+use crate::Resource as _;
+use crate::ServiceRoot;
+use futures_channel::mpsc::unbounded;
+use futures_channel::mpsc::UnboundedReceiver;
+use futures_channel::mpsc::UnboundedSender;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ODataId;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+/// Service root that can be shared between tasks and refreshed in
+/// place.
+pub struct SharedServiceRoot<B: Bmc> {
+    current: RwLock<ServiceRoot<B>>,
+    subscribers: Mutex<Vec<UnboundedSender<ServiceRoot<B>>>>,
+}
+
+impl<B: Bmc> SharedServiceRoot<B> {
+    /// Create shared service root that starts with `root`.
+    #[must_use]
+    pub const fn new(root: ServiceRoot<B>) -> Self {
+        Self {
+            current: RwLock::new(root),
+            subscribers: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Current service root. Cloning the root is cheap: data of the
+    /// root and the BMC are shared.
+    pub fn current(&self) -> ServiceRoot<B> {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Subscribe to changes of the service root. Every time the root
+    /// changes the receiver gets the new root.
+    ///
+    /// Dropping the receiver unsubscribes it.
+    pub fn subscribe(&self) -> UnboundedReceiver<ServiceRoot<B>> {
+        let (sender, receiver) = unbounded();
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(sender);
+        receiver
+    }
+
+    /// Read the service root again (see [`ServiceRoot::refresh`]).
+    ///
+    /// Returns the new root if it differs from the current one,
+    /// `None` otherwise. Roots are compared by `@odata.etag` when the
+    /// service provides it, and by advertised services, protocol
+    /// features, vendor, product and Redfish version otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading the service root fails. The current
+    /// root is kept in this case.
+    pub async fn refresh(&self) -> Result<Option<ServiceRoot<B>>, Error<B>> {
+        let current = self.current();
+        let root = current.refresh().await?;
+        let unchanged = match (root.root.etag(), current.root.etag()) {
+            (Some(etag), Some(current_etag)) => etag == current_etag,
+            _ => RootState::new(&root) == RootState::new(&current),
+        };
+        if unchanged {
+            return Ok(None);
+        }
+        *self.current.write().unwrap_or_else(PoisonError::into_inner) = root.clone();
+        self.subscribers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|subscriber| subscriber.unbounded_send(root.clone()).is_ok());
+        Ok(Some(root))
+    }
+}
+
+// Services of the service root that are compared when the service
+// doesn't provide `@odata.etag` of the root.
+const SERVICES: [&str; 24] = [
+    "AccountService",
+    "AggregationService",
+    "Cables",
+    "CertificateService",
+    "Chassis",
+    "ComponentIntegrity",
+    "CompositionService",
+    "EventService",
+    "Fabrics",
+    "Facilities",
+    "JobService",
+    "JsonSchemas",
+    "KeyService",
+    "LicenseService",
+    "Managers",
+    "PowerEquipment",
+    "Registries",
+    "SessionService",
+    "Storage",
+    "Systems",
+    "TaskService",
+    "TelemetryService",
+    "ThermalEquipment",
+    "UpdateService",
+];
+
+// Properties of the service root that define what the service
+// provides and how it is accessed.
+#[derive(PartialEq)]
+struct RootState<'a> {
+    redfish_version: Option<&'a str>,
+    vendor: Option<&'a str>,
+    product: Option<&'a str>,
+    protocol_features: Option<ProtocolFeatures>,
+    services: Vec<Option<&'a ODataId>>,
+}
+
+impl<'a> RootState<'a> {
+    fn new<B: Bmc>(root: &'a ServiceRoot<B>) -> Self {
+        Self {
+            redfish_version: root.redfish_version().map(|v| *v.inner()),
+            vendor: root.vendor().map(|v| *v.inner()),
+            product: root.product().map(|v| *v.inner()),
+            protocol_features: root
+                .root
+                .protocol_features_supported
+                .as_ref()
+                .map(ProtocolFeatures::new),
+            services: SERVICES
+                .iter()
+                .map(|name| root.navigation_property(name))
+                .collect(),
+        }
+    }
+}
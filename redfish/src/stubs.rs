@@ -0,0 +1,61 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stubs of [`ServiceRoot`] accessors for disabled features.
+//!
+//! Applications that are built with varying feature sets can call
+//! these stubs unconditionally and report precisely why functionality
+//! is absent: each stub fails with [`Error::FeatureNotCompiled`] that
+//! contains the name of the missing feature.
+
+use crate::Error;
+use crate::ServiceRoot;
+use nv_redfish_core::Bmc;
+use std::convert::Infallible;
+
+macro_rules! feature_stubs {
+    ($($feature:literal => $method:ident),* $(,)?) => {
+        impl<B: Bmc> ServiceRoot<B> {
+            $(
+                #[doc = concat!("Stub of `", stringify!($method), "` for builds without `", $feature, "` feature.")]
+                #[doc = ""]
+                #[doc = "# Errors"]
+                #[doc = ""]
+                #[doc = concat!("Always returns [`Error::FeatureNotCompiled`] with `", $feature, "`.")]
+                #[cfg(not(feature = $feature))]
+                #[allow(clippy::unused_async, clippy::unused_self)]
+                pub async fn $method(&self) -> Result<Option<Infallible>, Error<B>> {
+                    Err(Error::FeatureNotCompiled($feature))
+                }
+            )*
+        }
+    };
+}
+
+feature_stubs! {
+    "accounts" => account_service,
+    "chassis" => chassis,
+    "component-integrity" => component_integrity,
+    "computer-systems" => systems,
+    "event-service" => event_service,
+    "fabrics" => fabrics,
+    "license-service" => license_service,
+    "managers" => managers,
+    "power-equipment" => power_equipment,
+    "session-service" => session_service,
+    "task-service" => task_service,
+    "telemetry-service" => telemetry_service,
+    "update-service" => update_service,
+}
@@ -0,0 +1,134 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Task returned by an asynchronous operation.
+
+use std::future::Future;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use crate::core::Bmc;
+use crate::core::EntityTypeRef as _;
+use crate::core::NavProperty;
+use crate::entity_link::FromLink;
+use crate::schema::message::Message;
+use crate::schema::task::Task as TaskSchema;
+use crate::schema::task::TaskState;
+use crate::Error;
+use crate::NvBmc;
+use crate::Resource;
+use crate::ResourceSchema;
+
+use super::monitor::is_finished;
+
+/// Redfish task.
+///
+/// Exposes state and progress of an asynchronous operation.
+pub struct Task<B: Bmc> {
+    data: Arc<TaskSchema>,
+    _bmc: PhantomData<B>,
+}
+
+impl<B: Bmc> Task<B> {
+    /// Fetch the task.
+    pub(crate) async fn new(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<TaskSchema>,
+    ) -> Result<Self, Error<B>> {
+        nav.get(bmc.as_ref())
+            .await
+            .map_err(Error::Bmc)
+            .map(Self::from_data)
+    }
+
+    /// Task with already fetched data.
+    pub(crate) const fn from_data(data: Arc<TaskSchema>) -> Self {
+        Self {
+            data,
+            _bmc: PhantomData,
+        }
+    }
+
+    /// Get the raw schema data for this task.
+    #[must_use]
+    pub fn raw(&self) -> Arc<TaskSchema> {
+        self.data.clone()
+    }
+
+    /// State of the task.
+    #[must_use]
+    pub fn state(&self) -> Option<TaskState> {
+        self.data.task_state
+    }
+
+    /// Task is in one of the final states: `Completed`, `Exception`,
+    /// `Killed` or `Cancelled`.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        is_finished(&self.data)
+    }
+
+    /// Percentage of completion of the task (`PercentComplete`).
+    #[must_use]
+    pub fn percent_complete(&self) -> Option<i64> {
+        self.data.percent_complete.flatten()
+    }
+
+    /// Messages reported by the task.
+    #[must_use]
+    pub fn messages(&self) -> &[Message] {
+        self.data.messages.as_deref().unwrap_or_default()
+    }
+
+    /// Convert finished task into the result of the operation.
+    pub(crate) fn into_result(self) -> Result<Self, Error<B>> {
+        if self.state() == Some(TaskState::Completed) {
+            Ok(self)
+        } else {
+            Err(Error::TaskFailed {
+                task: self.data.odata_id().clone(),
+                state: self.state(),
+                messages: self
+                    .messages()
+                    .iter()
+                    .filter_map(|message| message.message.clone())
+                    .collect(),
+            })
+        }
+    }
+}
+
+impl<B: Bmc> Resource for Task<B> {
+    type Entity = TaskSchema;
+
+    fn resource_ref(&self) -> &ResourceSchema {
+        &self.data.as_ref().base
+    }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+}
+
+impl<B: Bmc> FromLink<B> for Task<B> {
+    type Schema = TaskSchema;
+
+    fn from_link(
+        bmc: &NvBmc<B>,
+        nav: &NavProperty<Self::Schema>,
+    ) -> impl Future<Output = Result<Self, Error<B>>> + Send {
+        Self::new(bmc, nav)
+    }
+}
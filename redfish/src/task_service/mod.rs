@@ -19,7 +19,12 @@
 //! A `TaskService` value is a lightweight handle to the service schema and BMC
 //! transport. It validates task locations returned by asynchronous operations
 //! against this service's Tasks collection and returns lazy task links that can
-//! be fetched when polling is needed.
+//! be fetched when polling is needed. [`TaskMonitor`] waits for task
+//! completion and [`Task`] exposes state, progress and messages of the
+//! task.
+
+mod item;
+mod monitor;
 
 use std::sync::Arc;
 
@@ -33,10 +38,19 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use crate::ServiceRoot;
 
 use nv_redfish_core::AsyncTask;
 
+#[doc(inline)]
+pub use crate::schema::message::Message;
+#[doc(inline)]
+pub use item::Task;
+#[doc(inline)]
+pub use monitor::TaskMonitor;
+
 /// Link to a Redfish Task returned by an asynchronous operation.
 pub type TaskLink<B> = EntityLink<B, TaskSchema>;
 
@@ -118,10 +132,33 @@ impl<B: Bmc> TaskService<B> {
         let task_ref = NavProperty::new_reference(task_location);
         Ok(TaskLink::new(&self.bmc, task_ref))
     }
+
+    /// Create a monitor of a task returned by an asynchronous
+    /// operation.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::task_link`].
+    pub fn task_monitor(&self, task: AsyncTask) -> Result<TaskMonitor<B>, Error<B>> {
+        let retry_after = task.retry_after;
+        self.task_link(task)
+            .map(|link| TaskMonitor::new(link, retry_after))
+    }
 }
 
 impl<B: Bmc> Resource for TaskService<B> {
+    type Entity = TaskServiceSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
@@ -0,0 +1,437 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Waiting for task completion.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::schema::task::Task as TaskSchema;
+use crate::schema::task::TaskState;
+use crate::task_service::Task;
+use crate::task_service::TaskLink;
+use crate::Error;
+
+use futures_util::Stream;
+use futures_util::StreamExt as _;
+use nv_redfish_core::AsyncTaskResult;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::TaskMonitorResponse;
+
+#[cfg(feature = "cancellation")]
+use crate::cancellation::run_until_cancelled;
+#[cfg(feature = "cancellation")]
+use crate::cancellation::CancellationToken;
+#[cfg(feature = "event-service")]
+use crate::event_service::EventService;
+#[cfg(feature = "event-service")]
+use crate::event_service::EventStreamOptions;
+#[cfg(feature = "event-service")]
+use futures_util::future;
+#[cfg(feature = "event-service")]
+use futures_util::stream;
+#[cfg(feature = "event-service")]
+use serde_json::Value as JsonValue;
+
+/// Prefix of messages of the `TaskEvent` registry.
+#[cfg(feature = "event-service")]
+const TASK_EVENT_REGISTRY_PREFIX: &str = "TaskEvent.";
+
+/// Monitor of a task returned by an asynchronous operation.
+pub struct TaskMonitor<B: Bmc> {
+    link: TaskLink<B>,
+    retry_after: Option<Duration>,
+}
+
+impl<B: Bmc> TaskMonitor<B> {
+    pub(crate) const fn new(link: TaskLink<B>, retry_after: Option<Duration>) -> Self {
+        Self { link, retry_after }
+    }
+
+    /// Link to the monitored task.
+    #[must_use]
+    pub const fn link(&self) -> &TaskLink<B> {
+        &self.link
+    }
+
+    /// Fetch the current state of the task.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the task fails.
+    pub async fn fetch(&self) -> Result<Arc<TaskSchema>, Error<B>> {
+        self.link.fetch().await
+    }
+
+    /// Fetch the current state of the task as [`Task`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fetching the task fails.
+    pub async fn task(&self) -> Result<Task<B>, Error<B>> {
+        self.link.upgrade().await
+    }
+
+    /// Wait until the task finishes polling its task monitor.
+    ///
+    /// `sleep` is called with the delay before each poll (for example,
+    /// `tokio::time::sleep`). The delay is `Retry-After` returned by
+    /// the last poll or `poll_interval` if the service didn't return
+    /// it. The task is polled directly when the service doesn't
+    /// provide a task monitor. Progress of a running task can be
+    /// observed with [`Self::task`] concurrently.
+    ///
+    /// Returns the result of the completed operation: `Location` and
+    /// payload returned by the task monitor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - fetching the task or its task monitor fails
+    /// - the task finishes in `Exception`, `Killed` or `Cancelled`
+    ///   state ([`Error::TaskFailed`])
+    pub async fn wait_for_completion<F, Fut>(
+        &self,
+        poll_interval: Duration,
+        sleep: F,
+    ) -> Result<AsyncTaskResult, Error<B>>
+    where
+        F: Fn(Duration) -> Fut + Send + Sync,
+        Fut: Future<Output = ()> + Send,
+    {
+        let (task, result) = self
+            .poll_until_finished(Sleep {
+                poll_interval,
+                sleep,
+            })
+            .await?;
+        Task::<B>::from_data(task).into_result().map(|_| result)
+    }
+
+    /// Wait until the task finishes like [`Self::wait_for_completion`]
+    /// or `token` is cancelled. The task itself is not cancelled on
+    /// the service.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] if `token` is cancelled and errors
+    /// of [`Self::wait_for_completion`] otherwise.
+    #[cfg(feature = "cancellation")]
+    pub async fn wait_for_completion_cancellable<F, Fut>(
+        &self,
+        poll_interval: Duration,
+        sleep: F,
+        token: &CancellationToken,
+    ) -> Result<AsyncTaskResult, Error<B>>
+    where
+        F: Fn(Duration) -> Fut + Send + Sync,
+        Fut: Future<Output = ()> + Send,
+    {
+        run_until_cancelled(token, self.wait_for_completion(poll_interval, sleep)).await
+    }
+
+    /// Wait until the task finishes polling it on each item of
+    /// `ticks` (for example, ticks of an interval timer).
+    ///
+    /// Returns the task in its final state: `Completed`, `Exception`,
+    /// `Killed` or `Cancelled`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - fetching the task or its task monitor fails
+    /// - `ticks` end before the task finishes
+    ///   ([`Error::TaskNotFinished`])
+    pub async fn wait_polling<S>(&self, ticks: S) -> Result<Arc<TaskSchema>, Error<B>>
+    where
+        S: Stream + Send,
+    {
+        self.poll_until_finished(Ticks(Box::pin(ticks)))
+            .await
+            .map(|(task, _)| task)
+    }
+
+    /// Wait until the task finishes using events of `event_service`
+    /// instead of polling.
+    ///
+    /// The task is fetched once before subscribing and then polled
+    /// every time the event stream delivers an event about the task
+    /// (`TaskEvent` registry message with the task id or an event
+    /// with the task as `OriginOfCondition`). Each item of `fallback`
+    /// (for example, ticks of an interval timer) also polls the task,
+    /// so completion is detected even if the BMC doesn't deliver task
+    /// events or the event stream cannot be opened or breaks.
+    ///
+    /// Returns the task in its final state: `Completed`, `Exception`,
+    /// `Killed` or `Cancelled`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - fetching the task or its task monitor fails
+    /// - both the event stream and `fallback` end before the task
+    ///   finishes ([`Error::TaskNotFinished`])
+    #[cfg(feature = "event-service")]
+    pub async fn wait_via_events<S>(
+        &self,
+        event_service: &EventService<B>,
+        fallback: S,
+    ) -> Result<Arc<TaskSchema>, Error<B>>
+    where
+        B: 'static,
+        B::Error: 'static,
+        S: Stream + Send,
+    {
+        let task = self.fetch().await?;
+        if is_finished(&task) {
+            return Ok(task);
+        }
+
+        let task_id = self.link.odata_id().to_string();
+        // Event stream is optional: without it the task is polled on
+        // fallback ticks only.
+        let events = event_service
+            .json_events(EventStreamOptions::default())
+            .await
+            .map_or_else(|_| stream::empty().boxed(), |events| events.boxed());
+        let task_events = events
+            .take_while(|payload| future::ready(payload.is_ok()))
+            .filter_map(move |payload| {
+                future::ready(
+                    payload
+                        .ok()
+                        .filter(|payload| is_task_event(payload, &task_id))
+                        .map(|_| ()),
+                )
+            });
+        let triggers = stream::select(task_events, fallback.map(|_| ()));
+        self.poll_until_finished(Ticks(Box::pin(triggers)))
+            .await
+            .map(|(task, _)| task)
+    }
+
+    /// Poll the task until it finishes waiting with `wait` between
+    /// polls.
+    ///
+    /// Each poll reads the task monitor of the task if the service
+    /// provides it: `202 Accepted` means that the operation is still
+    /// running and its `Retry-After` is passed to `wait`. Otherwise,
+    /// the task itself is fetched. `Retry-After` returned with the
+    /// asynchronous operation is used before the first poll.
+    async fn poll_until_finished<W: Wait + Send>(
+        &self,
+        mut wait: W,
+    ) -> Result<(Arc<TaskSchema>, AsyncTaskResult), Error<B>> {
+        let task = self.fetch().await?;
+        let mut monitor = task.task_monitor.clone();
+        let mut poll = if is_finished(&task) {
+            Poll::Finished(task, self.finished_result(&mut monitor).await?)
+        } else {
+            Poll::Running(self.retry_after)
+        };
+        loop {
+            match poll {
+                Poll::Finished(task, result) => return Ok((task, result)),
+                Poll::Running(retry_after) => {
+                    if !wait.wait(retry_after).await {
+                        return Err(Error::TaskNotFinished(self.link.odata_id().clone()));
+                    }
+                }
+            }
+            poll = self.poll(&mut monitor).await?;
+        }
+    }
+
+    /// Poll the task monitor if it is known or the task otherwise.
+    async fn poll(&self, monitor: &mut Option<String>) -> Result<Poll, Error<B>> {
+        match self.read_monitor(monitor).await? {
+            Some(TaskMonitorResponse::Running { retry_after }) => Ok(Poll::Running(retry_after)),
+            Some(TaskMonitorResponse::Finished(result)) => {
+                Ok(Poll::Finished(self.fetch().await?, result))
+            }
+            None => {
+                let task = self.fetch().await?;
+                if is_finished(&task) {
+                    let result = self.finished_result(monitor).await?;
+                    Ok(Poll::Finished(task, result))
+                } else {
+                    Ok(Poll::Running(None))
+                }
+            }
+        }
+    }
+
+    /// Result of the operation of the finished task returned by its
+    /// task monitor. Empty if the monitor is unknown or doesn't
+    /// return the result.
+    async fn finished_result(
+        &self,
+        monitor: &mut Option<String>,
+    ) -> Result<AsyncTaskResult, Error<B>> {
+        match self.read_monitor(monitor).await? {
+            Some(TaskMonitorResponse::Finished(result)) => Ok(result),
+            Some(TaskMonitorResponse::Running { .. }) | None => Ok(AsyncTaskResult::default()),
+        }
+    }
+
+    /// Read the task monitor. `None` if the monitor is unknown or the
+    /// BMC cannot read it.
+    ///
+    /// The monitor of a failed operation returns an error response
+    /// (details are kept in the task) and services may remove the
+    /// monitor once the operation finished, so the monitor is dropped
+    /// after the first error response and the task is polled instead.
+    async fn read_monitor(
+        &self,
+        monitor: &mut Option<String>,
+    ) -> Result<Option<TaskMonitorResponse>, Error<B>> {
+        let Some(uri) = monitor.as_deref() else {
+            return Ok(None);
+        };
+        match self.link.bmc().as_ref().task_monitor(uri).await {
+            Ok(response) => Ok(response),
+            Err(err) if B::error_status_code(&err).is_some() => {
+                *monitor = None;
+                Ok(None)
+            }
+            Err(err) => Err(Error::Bmc(err)),
+        }
+    }
+}
+
+/// Result of a single poll of the task.
+enum Poll {
+    /// Operation is running. Contains recommended delay before the
+    /// next poll.
+    Running(Option<Duration>),
+    /// Task finished.
+    Finished(Arc<TaskSchema>, AsyncTaskResult),
+}
+
+/// Delay between polls of the task.
+trait Wait {
+    /// Wait before the next poll. `retry_after` is returned by the
+    /// last poll. Returns `false` if the task should not be polled
+    /// anymore.
+    fn wait(&mut self, retry_after: Option<Duration>) -> impl Future<Output = bool> + Send;
+}
+
+/// Sleep `Retry-After` or poll interval between polls.
+struct Sleep<F> {
+    poll_interval: Duration,
+    sleep: F,
+}
+
+impl<F, Fut> Wait for Sleep<F>
+where
+    F: Fn(Duration) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send,
+{
+    async fn wait(&mut self, retry_after: Option<Duration>) -> bool {
+        (self.sleep)(retry_after.unwrap_or(self.poll_interval)).await;
+        true
+    }
+}
+
+/// Poll on each item of the stream.
+struct Ticks<S>(Pin<Box<S>>);
+
+impl<S: Stream + Send> Wait for Ticks<S> {
+    async fn wait(&mut self, _retry_after: Option<Duration>) -> bool {
+        self.0.next().await.is_some()
+    }
+}
+
+/// Task is in one of the final states.
+pub(super) const fn is_finished(task: &TaskSchema) -> bool {
+    matches!(
+        task.task_state,
+        Some(
+            TaskState::Completed | TaskState::Exception | TaskState::Killed | TaskState::Cancelled
+        )
+    )
+}
+
+/// Event payload contains a record about the `task_id` task.
+#[cfg(feature = "event-service")]
+fn is_task_event(payload: &JsonValue, task_id: &str) -> bool {
+    let short_id = task_id.rsplit('/').next().unwrap_or(task_id);
+    payload
+        .get("Events")
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .any(|record| {
+            let origin = record
+                .get("OriginOfCondition")
+                .and_then(|origin| origin.get("@odata.id"))
+                .and_then(JsonValue::as_str);
+            if origin == Some(task_id) {
+                return true;
+            }
+            let is_task_message = record
+                .get("MessageId")
+                .and_then(JsonValue::as_str)
+                .is_some_and(|id| id.starts_with(TASK_EVENT_REGISTRY_PREFIX));
+            is_task_message
+                && record
+                    .get("MessageArgs")
+                    .and_then(JsonValue::as_array)
+                    .is_some_and(|args| args.iter().any(|arg| arg.as_str() == Some(short_id)))
+        })
+}
+
+#[cfg(all(test, feature = "event-service"))]
+mod tests {
+    use super::is_task_event;
+    use serde_json::json;
+
+    const TASK: &str = "/redfish/v1/TaskService/Tasks/42";
+
+    #[test]
+    fn task_events_are_matched_by_message_args_and_origin() {
+        let by_args = json!({
+            "Events": [{
+                "MessageId": "TaskEvent.1.0.TaskCompletedOK",
+                "MessageArgs": ["42"]
+            }]
+        });
+        let by_origin = json!({
+            "Events": [{
+                "MessageId": "ResourceEvent.1.0.ResourceChanged",
+                "OriginOfCondition": { "@odata.id": TASK }
+            }]
+        });
+        let other_task = json!({
+            "Events": [{
+                "MessageId": "TaskEvent.1.0.TaskCompletedOK",
+                "MessageArgs": ["43"]
+            }]
+        });
+        let other_registry = json!({
+            "Events": [{
+                "MessageId": "Base.1.0.Success",
+                "MessageArgs": ["42"]
+            }]
+        });
+        assert!(is_task_event(&by_args, TASK));
+        assert!(is_task_event(&by_origin, TASK));
+        assert!(!is_task_event(&other_task, TASK));
+        assert!(!is_task_event(&other_registry, TASK));
+        assert!(!is_task_event(&json!({}), TASK));
+    }
+}
@@ -75,7 +75,7 @@ impl<B: Bmc> MetricDefinition<B> {
                 update,
             )
             .await
-            .map_err(Error::Bmc)?
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))?
             .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
             .await
     }
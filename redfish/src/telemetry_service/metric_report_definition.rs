@@ -80,7 +80,7 @@ impl<B: Bmc> MetricReportDefinition<B> {
                 update,
             )
             .await
-            .map_err(Error::Bmc)?
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))?
             .try_map_entity_async(|nav| async move { Self::new(&self.bmc, &nav).await })
             .await
     }
@@ -30,6 +30,8 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use crate::ServiceRoot;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EntityTypeRef as _;
@@ -117,7 +119,7 @@ impl<B: Bmc> TelemetryService<B> {
                 &update,
             )
             .await
-            .map_err(Error::Bmc)?
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))?
             .try_map_entity_async(|nav| async move {
                 let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
 
@@ -282,7 +284,18 @@ impl<B: Bmc> TelemetryService<B> {
 }
 
 impl<B: Bmc> Resource for TelemetryService<B> {
+    type Entity = TelemetryServiceSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
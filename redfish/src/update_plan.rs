@@ -0,0 +1,511 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Orchestration of multi-step updates.
+//!
+//! Components of a platform often have to be updated in a particular
+//! order: for example, BMC firmware must be activated before the BIOS
+//! image that requires it is applied. [`UpdatePlan`] is a graph of
+//! [`UpdateStep`]s: each step starts an update with `UpdateService`,
+//! may depend on other steps and may require a reset to activate the
+//! image.
+//!
+//! Steps are executed one at a time in dependency order. Steps that
+//! don't depend on each other keep the order they were added to the
+//! plan. Asynchronous updates are monitored with `TaskService` until
+//! the task finishes.
+//!
+//! Resets are deferred: reset required by a step is performed right
+//! before the first step that depends on it, and resets that are still
+//! pending when all steps complete are performed at the end. Steps that
+//! require reset of the same resource share one reset.
+//!
+//! If a step fails, steps that were completed are rolled back in
+//! reverse order using [`UpdateHooks::rollback`]. Pending resets are
+//! not performed after a failure.
+//!
+//! Resets, rollbacks and delays between task polls are provided by the
+//! caller with [`UpdateHooks`], so the plan doesn't depend on a
+//! particular async runtime or on the way resources are reset.
+//!
+//! ```ignore
+//! let plan = UpdatePlan::new()
+//!     .step(UpdateStep::simple_update("bmc", bmc_image).reset(manager_id))
+//!     .step(UpdateStep::simple_update("bios", bios_image).depends_on("bmc"))
+//!     .step(UpdateStep::start_update("activate").depends_on("bios"));
+//! let report = plan.run(&root, &hooks).await?;
+//! if let Some((step, err)) = &report.failure {
+//!     // Completed steps are already rolled back.
+//! }
+//! ```
+
+#[cfg(feature = "cancellation")]
+use crate::cancellation::run_until_cancelled;
+#[cfg(feature = "cancellation")]
+use crate::cancellation::CancellationToken;
+use crate::schema::task::TaskState;
+use crate::task_service::TaskService;
+use crate::update_service::SimpleUpdateParameters;
+use crate::update_service::UpdateService;
+use crate::Error;
+use crate::ServiceRoot;
+use futures_util::stream;
+use nv_redfish_core::ActionError;
+use nv_redfish_core::Bmc;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use std::collections::HashMap;
+use std::future::Future;
+
+/// Update action performed by a step.
+pub enum UpdateAction {
+    /// `SimpleUpdate` action of the update service.
+    SimpleUpdate(Box<SimpleUpdateParameters>),
+    /// `StartUpdate` action of the update service. Starts updates
+    /// that were staged by previous steps.
+    StartUpdate,
+}
+
+/// Step of an update plan.
+pub struct UpdateStep {
+    name: String,
+    action: UpdateAction,
+    depends_on: Vec<String>,
+    reset: Option<ODataId>,
+}
+
+impl UpdateStep {
+    /// Create a step that performs `action`. Name identifies the step
+    /// in dependencies and reports.
+    #[must_use]
+    pub fn new(name: impl Into<String>, action: UpdateAction) -> Self {
+        Self {
+            name: name.into(),
+            action,
+            depends_on: Vec::new(),
+            reset: None,
+        }
+    }
+
+    /// Create a step that performs `SimpleUpdate` action.
+    #[must_use]
+    pub fn simple_update(name: impl Into<String>, parameters: SimpleUpdateParameters) -> Self {
+        Self::new(name, UpdateAction::SimpleUpdate(Box::new(parameters)))
+    }
+
+    /// Create a step that performs `StartUpdate` action.
+    #[must_use]
+    pub fn start_update(name: impl Into<String>) -> Self {
+        Self::new(name, UpdateAction::StartUpdate)
+    }
+
+    /// Execute the step only after the step `name` is completed and
+    /// its reset (if any) is performed.
+    #[must_use]
+    pub fn depends_on(mut self, name: impl Into<String>) -> Self {
+        self.depends_on.push(name.into());
+        self
+    }
+
+    /// Reset the resource `target` (for example, the manager for BMC
+    /// firmware) to activate the image of this step.
+    #[must_use]
+    pub fn reset(mut self, target: ODataId) -> Self {
+        self.reset = Some(target);
+        self
+    }
+
+    /// Name of the step.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Action of the step.
+    #[must_use]
+    pub const fn action(&self) -> &UpdateAction {
+        &self.action
+    }
+
+    /// Names of steps this step depends on.
+    #[must_use]
+    pub fn dependencies(&self) -> &[String] {
+        &self.depends_on
+    }
+
+    /// Resource that is reset to activate the image of this step.
+    #[must_use]
+    pub const fn reset_target(&self) -> Option<&ODataId> {
+        self.reset.as_ref()
+    }
+}
+
+/// Operations of an update plan provided by the caller.
+pub trait UpdateHooks<B: Bmc>: Send + Sync {
+    /// Reset the resource `target` and return when it is ready to
+    /// continue updates (for example, the BMC is reachable again).
+    fn reset(&self, target: &ODataId) -> impl Future<Output = Result<(), Error<B>>> + Send;
+
+    /// Delay between polls of an update task.
+    fn poll_delay(&self) -> impl Future<Output = ()> + Send;
+
+    /// Roll back the completed `step` after a failure of the plan.
+    /// Default implementation does nothing.
+    fn rollback(&self, _step: &UpdateStep) -> impl Future<Output = Result<(), Error<B>>> + Send {
+        async { Ok(()) }
+    }
+}
+
+/// Event of an update plan run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UpdateEvent {
+    /// Step completed. Contains name of the step.
+    StepCompleted(String),
+    /// Resource was reset. Contains the reset target.
+    Reset(ODataId),
+    /// Step was rolled back. Contains name of the step.
+    RolledBack(String),
+}
+
+/// Report of an update plan run.
+pub struct UpdateReport<B: Bmc> {
+    /// Events of the run in execution order.
+    pub events: Vec<UpdateEvent>,
+    /// Step that failed together with the error. Failure of a reset is
+    /// reported for the step that required the reset.
+    pub failure: Option<(String, Error<B>)>,
+    /// Rollbacks of steps that failed.
+    pub rollback_failures: Vec<(String, Error<B>)>,
+}
+
+impl<B: Bmc> UpdateReport<B> {
+    /// All steps of the plan are completed.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Graph of update steps.
+///
+/// See [module documentation](self) for details.
+#[derive(Default)]
+pub struct UpdatePlan {
+    steps: Vec<UpdateStep>,
+}
+
+impl UpdatePlan {
+    /// Create an empty plan.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add step to the plan.
+    #[must_use]
+    pub fn step(mut self, step: UpdateStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Steps of the plan in the order they were added.
+    #[must_use]
+    pub fn steps(&self) -> &[UpdateStep] {
+        &self.steps
+    }
+
+    /// Names of steps in execution order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if step names are not unique, a step depends
+    /// on an unknown step or dependencies form a cycle.
+    pub fn execution_order<B: Bmc>(&self) -> Result<Vec<&str>, Error<B>> {
+        Ok(self
+            .order()?
+            .into_iter()
+            .map(|index| self.steps[index].name())
+            .collect())
+    }
+
+    /// Run the plan against the update service of `root`.
+    ///
+    /// Returns a report of the run. Errors of steps, resets and
+    /// rollbacks are reported in [`UpdateReport`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the plan is invalid (see
+    /// [`Self::execution_order`]) or the update service cannot be
+    /// retrieved. Nothing is modified in this case.
+    pub async fn run<B, H>(
+        &self,
+        root: &ServiceRoot<B>,
+        hooks: &H,
+    ) -> Result<UpdateReport<B>, Error<B>>
+    where
+        B: Bmc,
+        B::Error: ActionError,
+        H: UpdateHooks<B>,
+    {
+        let order = self.order()?;
+        let run = Run::new(root, hooks).await?;
+        Ok(self.run_steps(order, run).await)
+    }
+
+    /// Run the plan like [`Self::run`] until `token` is cancelled.
+    ///
+    /// Cancellation aborts the running step or reset, which is then
+    /// reported as failed with [`Error::Cancelled`], and completed steps
+    /// are rolled back as after any other failure. Rollback itself is
+    /// not cancelled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] if `token` is cancelled before the
+    /// run starts and errors of [`Self::run`] otherwise.
+    #[cfg(feature = "cancellation")]
+    pub async fn run_cancellable<'a, B, H>(
+        &self,
+        root: &'a ServiceRoot<B>,
+        hooks: &'a H,
+        token: &'a CancellationToken,
+    ) -> Result<UpdateReport<B>, Error<B>>
+    where
+        B: Bmc,
+        B::Error: ActionError,
+        H: UpdateHooks<B>,
+    {
+        let order = self.order()?;
+        let mut run = run_until_cancelled(token, Run::new(root, hooks)).await?;
+        run.token = Some(token);
+        Ok(self.run_steps(order, run).await)
+    }
+
+    async fn run_steps<B, H>(&self, order: Vec<usize>, mut run: Run<'_, B, H>) -> UpdateReport<B>
+    where
+        B: Bmc,
+        B::Error: ActionError,
+        H: UpdateHooks<B>,
+    {
+        let mut completed = Vec::with_capacity(order.len());
+        for index in order {
+            let step = &self.steps[index];
+            if let Err((name, err)) = run.execute(step).await {
+                run.report.failure = Some((name, err));
+                run.rollback(completed).await;
+                return run.report;
+            }
+            completed.push(step);
+        }
+        while let Some((name, target)) = run.pending_resets.first().cloned() {
+            if let Err(err) = run.reset_cancellable(&target).await {
+                run.report.failure = Some((name, err));
+                run.rollback(completed).await;
+                break;
+            }
+        }
+        run.report
+    }
+
+    /// Indices of steps in execution order.
+    fn order<B: Bmc>(&self) -> Result<Vec<usize>, Error<B>> {
+        let mut indices = HashMap::with_capacity(self.steps.len());
+        for (index, step) in self.steps.iter().enumerate() {
+            if indices.insert(step.name(), index).is_some() {
+                return Err(Error::UpdatePlanDuplicateStep(step.name.clone()));
+            }
+        }
+        let mut dependencies = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            let step_dependencies = step
+                .depends_on
+                .iter()
+                .map(|dependency| {
+                    indices.get(dependency.as_str()).copied().ok_or_else(|| {
+                        Error::UpdatePlanUnknownDependency {
+                            step: step.name.clone(),
+                            dependency: dependency.clone(),
+                        }
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            dependencies.push(step_dependencies);
+        }
+
+        // Stable topological sort: on each pass the first step whose
+        // dependencies are done is taken.
+        let mut done = vec![false; self.steps.len()];
+        let mut order = Vec::with_capacity(self.steps.len());
+        while order.len() < self.steps.len() {
+            let next = (0..self.steps.len())
+                .find(|&index| !done[index] && dependencies[index].iter().all(|&dep| done[dep]));
+            let Some(next) = next else {
+                let index = done.iter().position(|done| !done).unwrap_or_default();
+                return Err(Error::UpdatePlanCycle(self.steps[index].name.clone()));
+            };
+            done[next] = true;
+            order.push(next);
+        }
+        Ok(order)
+    }
+}
+
+/// State of a plan run.
+struct Run<'a, B: Bmc, H> {
+    root: &'a ServiceRoot<B>,
+    hooks: &'a H,
+    update_service: UpdateService<B>,
+    task_service: Option<TaskService<B>>,
+    /// Resets that are not performed yet: name of the step that
+    /// requires the reset and the reset target.
+    pending_resets: Vec<(String, ODataId)>,
+    report: UpdateReport<B>,
+    /// Token that cancels steps and resets of the run.
+    #[cfg(feature = "cancellation")]
+    token: Option<&'a CancellationToken>,
+}
+
+impl<'a, B, H> Run<'a, B, H>
+where
+    B: Bmc,
+    B::Error: ActionError,
+    H: UpdateHooks<B>,
+{
+    async fn new(root: &'a ServiceRoot<B>, hooks: &'a H) -> Result<Self, Error<B>> {
+        let update_service = root
+            .update_service()
+            .await?
+            .ok_or(Error::UpdatePlanResourceNotAvailable("UpdateService"))?;
+        Ok(Self {
+            root,
+            hooks,
+            update_service,
+            task_service: None,
+            pending_resets: Vec::new(),
+            report: UpdateReport {
+                events: Vec::new(),
+                failure: None,
+                rollback_failures: Vec::new(),
+            },
+            #[cfg(feature = "cancellation")]
+            token: None,
+        })
+    }
+
+    /// Execute the step. Error contains name of the failed step.
+    async fn execute(&mut self, step: &UpdateStep) -> Result<(), (String, Error<B>)> {
+        for dependency in &step.depends_on {
+            let target = self
+                .pending_resets
+                .iter()
+                .find(|(name, _)| name == dependency)
+                .map(|(_, target)| target.clone());
+            if let Some(target) = target {
+                self.reset_cancellable(&target)
+                    .await
+                    .map_err(|err| (dependency.clone(), err))?;
+            }
+        }
+        self.apply_cancellable(step)
+            .await
+            .map_err(|err| (step.name.clone(), err))?;
+        self.report
+            .events
+            .push(UpdateEvent::StepCompleted(step.name.clone()));
+        if let Some(target) = &step.reset {
+            self.pending_resets
+                .push((step.name.clone(), target.clone()));
+        }
+        Ok(())
+    }
+
+    /// [`Self::reset`] that is aborted when the token of the run is
+    /// cancelled.
+    async fn reset_cancellable(&mut self, target: &ODataId) -> Result<(), Error<B>> {
+        #[cfg(feature = "cancellation")]
+        if let Some(token) = self.token {
+            return run_until_cancelled(token, self.reset(target)).await;
+        }
+        self.reset(target).await
+    }
+
+    /// [`Self::apply`] that is aborted when the token of the run is
+    /// cancelled.
+    async fn apply_cancellable(&mut self, step: &UpdateStep) -> Result<(), Error<B>> {
+        #[cfg(feature = "cancellation")]
+        if let Some(token) = self.token {
+            return run_until_cancelled(token, self.apply(step)).await;
+        }
+        self.apply(step).await
+    }
+
+    async fn apply(&mut self, step: &UpdateStep) -> Result<(), Error<B>> {
+        let response = match &step.action {
+            UpdateAction::SimpleUpdate(parameters) => {
+                self.update_service
+                    .simple_update_with_parameters(parameters)
+                    .await?
+            }
+            UpdateAction::StartUpdate => self.update_service.start_update().await?,
+        };
+        let ModificationResponse::Task(task) = response else {
+            return Ok(());
+        };
+        let task_service = match self.task_service.take() {
+            Some(task_service) => task_service,
+            None => self
+                .root
+                .task_service()
+                .await?
+                .ok_or(Error::UpdatePlanResourceNotAvailable("TaskService"))?,
+        };
+        let monitor = self.task_service.insert(task_service).task_monitor(task)?;
+        let hooks = self.hooks;
+        let ticks = stream::unfold((), move |()| async move {
+            hooks.poll_delay().await;
+            Some(((), ()))
+        });
+        let task = monitor.wait_polling(ticks).await?;
+        if task.task_state == Some(TaskState::Completed) {
+            Ok(())
+        } else {
+            Err(Error::UpdateTaskFailed {
+                task: monitor.link().odata_id().clone(),
+                state: task.task_state,
+            })
+        }
+    }
+
+    /// Reset `target` and drop all pending resets of the same target.
+    async fn reset(&mut self, target: &ODataId) -> Result<(), Error<B>> {
+        self.hooks.reset(target).await?;
+        self.pending_resets.retain(|(_, pending)| pending != target);
+        self.report.events.push(UpdateEvent::Reset(target.clone()));
+        Ok(())
+    }
+
+    async fn rollback(&mut self, completed: Vec<&UpdateStep>) {
+        for step in completed.into_iter().rev() {
+            match self.hooks.rollback(step).await {
+                Ok(()) => self
+                    .report
+                    .events
+                    .push(UpdateEvent::RolledBack(step.name.clone())),
+                Err(err) => self.report.rollback_failures.push((step.name.clone(), err)),
+            }
+        }
+    }
+}
@@ -27,13 +27,16 @@ use crate::core::NavProperty;
 use crate::patch_support::Payload;
 use crate::patch_support::ReadPatchFn;
 use crate::schema::update_service::UpdateService as UpdateServiceSchema;
-use crate::schema::update_service::UpdateServiceSimpleUpdateAction;
 use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use crate::ServiceRoot;
+use nv_redfish_core::ODataId;
 
+use nv_redfish_core::AsyncTask;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::DataStream;
 #[cfg(feature = "update-service-deprecated")]
@@ -45,6 +48,7 @@ use nv_redfish_core::MultipartUpdateRequest;
 use nv_redfish_core::UploadReader;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish_core::UploadStream;
+use serde::Deserialize;
 use serde_json::Value as JsonValue;
 use software_inventory::SoftwareInventoryCollection;
 
@@ -52,6 +56,8 @@ use software_inventory::SoftwareInventoryCollection;
 pub use crate::schema::update_service::TransferProtocolType;
 #[doc(inline)]
 pub use crate::schema::update_service::UpdateParametersUpdate as MultipartUpdateParameters;
+#[doc(inline)]
+pub use crate::schema::update_service::UpdateServiceSimpleUpdateAction as SimpleUpdateParameters;
 #[cfg(feature = "update-service-deprecated")]
 #[doc(inline)]
 pub use crate::schema::update_service::UpdateServiceUpdate;
@@ -214,6 +220,33 @@ impl<B: Bmc> UpdateService<B> {
         local_image: Option<bool>,
         exclude_targets: Option<Vec<String>>,
     ) -> Result<ModificationResponse<()>, Error<B>>
+    where
+        B::Error: nv_redfish_core::ActionError,
+    {
+        self.simple_update_with_parameters(&SimpleUpdateParameters {
+            image_uri: Some(image_uri),
+            transfer_protocol,
+            targets,
+            username,
+            password,
+            force_update,
+            stage,
+            local_image,
+            exclude_targets,
+        })
+        .await
+    }
+
+    /// Perform a simple update with prepared parameters of the
+    /// `SimpleUpdate` action.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::simple_update`].
+    pub async fn simple_update_with_parameters(
+        &self,
+        parameters: &SimpleUpdateParameters,
+    ) -> Result<ModificationResponse<()>, Error<B>>
     where
         B::Error: nv_redfish_core::ActionError,
     {
@@ -224,20 +257,7 @@ impl<B: Bmc> UpdateService<B> {
             .ok_or(Error::ActionNotAvailable)?;
 
         actions
-            .simple_update(
-                self.bmc.as_ref(),
-                &UpdateServiceSimpleUpdateAction {
-                    image_uri: Some(image_uri),
-                    transfer_protocol,
-                    targets,
-                    username,
-                    password,
-                    force_update,
-                    stage,
-                    local_image,
-                    exclude_targets,
-                },
-            )
+            .simple_update(self.bmc.as_ref(), parameters)
             .await
             .map_err(Error::Bmc)
     }
@@ -287,7 +307,7 @@ impl<B: Bmc> UpdateService<B> {
                 update,
             )
             .await
-            .map_err(Error::Bmc)?
+            .map_err(|err| Error::update_failed(self.data.odata_id(), err))?
             .try_map_entity_async(|nav| async move {
                 let data = nav.get(self.bmc.as_ref()).await.map_err(Error::Bmc)?;
 
@@ -380,6 +400,52 @@ impl<B: Bmc> UpdateService<B> {
         .await
     }
 
+    /// Upload an image using this service's `MultipartHttpPushUri` and
+    /// return the task that performs the update.
+    ///
+    /// The image is streamed from `update_stream` (for example, a local
+    /// file) as `multipart/form-data` together with `UpdateParameters`,
+    /// so it is never buffered in memory as a whole. Services that
+    /// answer with the created `Task` resource instead of a task
+    /// location are supported as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `MultipartHttpPushUri` is absent or the upload fails
+    /// - The service doesn't return a task
+    ///   ([`Error::UpdateServiceTaskNotReturned`])
+    pub async fn simple_update_multipart<U>(
+        &self,
+        update_parameters: &MultipartUpdateParameters,
+        update_stream: DataStream<U>,
+        upload_timeout: Duration,
+    ) -> Result<AsyncTask, Error<B>>
+    where
+        U: UploadReader,
+    {
+        match self
+            .multipart_update_from_reader::<_, _, TaskReference>(
+                update_parameters,
+                update_stream,
+                upload_timeout,
+            )
+            .await?
+        {
+            ModificationResponse::Task(task) => Ok(task),
+            ModificationResponse::Entity(TaskReference {
+                odata_id: Some(odata_id),
+                odata_type: Some(odata_type),
+            }) if odata_type.starts_with(TASK_ODATA_TYPE_PREFIX) => Ok(AsyncTask {
+                location: odata_id.into(),
+                retry_after: None,
+            }),
+            ModificationResponse::Entity(_) | ModificationResponse::Empty => {
+                Err(Error::UpdateServiceTaskNotReturned)
+            }
+        }
+    }
+
     /// Perform a multipart upload using this service's `MultipartHttpPushUri`.
     ///
     /// Use this method when the request needs optional OEM multipart parts.
@@ -410,10 +476,33 @@ impl<B: Bmc> UpdateService<B> {
     }
 }
 
+/// Prefix of `@odata.type` of the `Task` resource.
+const TASK_ODATA_TYPE_PREFIX: &str = "#Task.";
+
+/// Response body of an upload that may contain the created task.
+#[derive(Deserialize)]
+struct TaskReference {
+    #[serde(rename = "@odata.id")]
+    odata_id: Option<ODataId>,
+    #[serde(rename = "@odata.type")]
+    odata_type: Option<String>,
+}
+
 impl<B: Bmc> Resource for UpdateService<B> {
+    type Entity = UpdateServiceSchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
 
 // `ReleaseDate` is marked as `edm.DateTimeOffset`, but some systems
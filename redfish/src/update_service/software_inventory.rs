@@ -23,9 +23,12 @@ use crate::Error;
 use crate::NvBmc;
 use crate::Resource;
 use crate::ResourceSchema;
+#[cfg(feature = "resource-status")]
+use crate::ResourceStatusSchema;
 use nv_redfish_core::Bmc;
 use nv_redfish_core::EdmDateTimeOffset;
 use nv_redfish_core::NavProperty;
+use nv_redfish_core::ODataId;
 use std::convert::identity;
 use std::sync::Arc;
 use tagged_types::TaggedType;
@@ -107,9 +110,20 @@ impl<B: Bmc> SoftwareInventory<B> {
 }
 
 impl<B: Bmc> Resource for SoftwareInventory<B> {
+    type Entity = SoftwareInventorySchema;
+
     fn resource_ref(&self) -> &ResourceSchema {
         &self.data.as_ref().base
     }
+
+    fn entity_ref(&self) -> &Self::Entity {
+        &self.data
+    }
+
+    #[cfg(feature = "resource-status")]
+    fn resource_status_ref(&self) -> Option<&ResourceStatusSchema> {
+        self.data.status.as_ref()
+    }
 }
 
 pub struct SoftwareInventoryCollection<B: Bmc> {
@@ -124,8 +138,20 @@ impl<B: Bmc> CollectionWithPatch<SoftwareInventoryCollectionSchema, SoftwareInve
     fn convert_patched(
         base: ResourceCollection,
         members: Vec<NavProperty<SoftwareInventorySchema>>,
+        members_count: Option<i64>,
+        next_link: Option<ODataId>,
     ) -> SoftwareInventoryCollectionSchema {
-        SoftwareInventoryCollectionSchema { base, members }
+        SoftwareInventoryCollectionSchema {
+            base,
+            members,
+            members_count,
+            next_link,
+        }
+    }
+    fn collection_members(
+        collection: &SoftwareInventoryCollectionSchema,
+    ) -> &[NavProperty<SoftwareInventorySchema>] {
+        &collection.members
     }
 }
 
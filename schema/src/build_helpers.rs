@@ -84,3 +84,32 @@ pub fn cargo_feature_enabled(name: &str) -> bool {
     ))
     .is_ok()
 }
+
+/// Check that every schema file in `paths` exists.
+///
+/// Used to validate completeness of a CSDL bundle before compilation so
+/// that a bundle that lacks some schemas fails with a list of all missing
+/// files instead of the first parse error.
+///
+/// # Errors
+///
+/// Returns a message that lists all missing files.
+pub fn ensure_schemas_exist<I, P>(paths: I) -> Result<(), String>
+where
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let missing = paths
+        .into_iter()
+        .filter(|path| !path.as_ref().is_file())
+        .map(|path| path.as_ref().display().to_string())
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "CSDL bundle is incomplete, missing schemas: {}",
+            missing.join(", ")
+        ))
+    }
+}
@@ -23,8 +23,11 @@
 mod build_helpers;
 mod paths;
 
-pub use build_helpers::{cargo_feature_enabled, out_dir, rerun_for, run_with_big_stack};
+pub use build_helpers::{
+    cargo_feature_enabled, ensure_schemas_exist, out_dir, rerun_for, run_with_big_stack,
+};
 pub use paths::{
-    glob_oem_xml, glob_redfish_xml, glob_swordfish_xml, oem_schema, redfish_schema,
-    swordfish_schema, OEM_DIR, REDFISH_CSDL_DIR, SWORDFISH_CSDL_DIR,
+    glob_oem_xml, glob_redfish_xml, glob_swordfish_xml, oem_schema, redfish_csdl_dir,
+    redfish_schema, swordfish_schema, OEM_DIR, REDFISH_CSDL_DIR, REDFISH_CSDL_DIR_ENV,
+    SWORDFISH_CSDL_DIR,
 };
@@ -20,6 +20,12 @@
 //! consumed as a `[build-dependencies]`, they point at the unpacked schemas
 //! inside `~/.cargo/registry/src/.../nv-redfish-schema-<ver>/` for published
 //! builds, or at the in-tree submodule checkout for in-workspace builds.
+//!
+//! The DMTF Redfish bundle can be replaced at build time by pointing
+//! [`REDFISH_CSDL_DIR_ENV`] at the `csdl` directory of another bundle
+//! version. Resolvers of Redfish schemas honor the override.
+
+use std::env;
 
 /// Directory holding the bundled DMTF Redfish CSDL schemas.
 ///
@@ -28,6 +34,21 @@
 /// git submodule.
 pub const REDFISH_CSDL_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/redfish-csdl/csdl");
 
+/// Environment variable that overrides [`REDFISH_CSDL_DIR`].
+///
+/// The value must be the `csdl` directory of a DMTF Redfish CSDL bundle
+/// (e.g. a checkout of a newer `Redfish-Publications` release).
+pub const REDFISH_CSDL_DIR_ENV: &str = "NV_REDFISH_CSDL_DIR";
+
+/// Directory of the DMTF Redfish CSDL schemas used for the build.
+///
+/// Returns value of [`REDFISH_CSDL_DIR_ENV`] if it is set, and
+/// [`REDFISH_CSDL_DIR`] otherwise.
+#[must_use]
+pub fn redfish_csdl_dir() -> String {
+    env::var(REDFISH_CSDL_DIR_ENV).unwrap_or_else(|_| REDFISH_CSDL_DIR.into())
+}
+
 /// Directory holding the bundled SNIA Swordfish CSDL schemas.
 ///
 /// Mirrors the `csdl-schema` subdirectory of the upstream
@@ -40,10 +61,10 @@ pub const SWORDFISH_CSDL_DIR: &str =
 pub const OEM_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/oem");
 
 /// Resolve `name` (e.g. `"RedfishError_v1.xml"`) to an absolute path inside
-/// [`REDFISH_CSDL_DIR`].
+/// [`redfish_csdl_dir`].
 #[must_use]
 pub fn redfish_schema(name: &str) -> String {
-    format!("{REDFISH_CSDL_DIR}/{name}")
+    format!("{}/{name}", redfish_csdl_dir())
 }
 
 /// Resolve `name` to an absolute path inside [`SWORDFISH_CSDL_DIR`].
@@ -60,10 +81,10 @@ pub fn oem_schema(vendor: &str, name: &str) -> String {
 }
 
 /// Return absolute paths for every `*.xml` file directly inside
-/// [`REDFISH_CSDL_DIR`].
+/// [`redfish_csdl_dir`].
 #[must_use]
 pub fn glob_redfish_xml() -> Vec<String> {
-    glob_xml(REDFISH_CSDL_DIR)
+    glob_xml(&redfish_csdl_dir())
 }
 
 /// Return absolute paths for every `*.xml` file directly inside
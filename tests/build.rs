@@ -15,6 +15,7 @@
 
 use nv_redfish_csdl_compiler::commands::process_command;
 use nv_redfish_csdl_compiler::commands::Commands;
+use nv_redfish_csdl_compiler::commands::OutputFormat;
 use nv_redfish_csdl_compiler::commands::DEFAULT_ROOT;
 use nv_redfish_csdl_compiler::Error;
 use nv_redfish_schema::out_dir;
@@ -31,6 +32,8 @@ fn main() -> Result<(), Error> {
     process_command(&Commands::Compile {
         root: DEFAULT_ROOT.into(),
         output: out_dir().join("base_tests.rs"),
+        split: false,
+        format: OutputFormat::Rust,
         csdls: base_csdls,
         entity_type_patterns: vec![],
         include_root_patterns: vec!["ServiceRoot.*.RootSetOnlyComplexType"
@@ -39,6 +42,7 @@ fn main() -> Result<(), Error> {
         rigid_array_patterns: vec!["ServiceRoot.*.ServiceRoot/RigidArrayValues"
             .parse()
             .expect("valid rigid array pattern")],
+        intern_strings: false,
     })?;
     Ok(())
 }
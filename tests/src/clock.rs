@@ -0,0 +1,93 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Manually advanced clock for tests of time-dependent behavior.
+
+use std::future::ready;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use nv_redfish_bmc_http::clock::Sleep;
+use nv_redfish_bmc_http::Clock;
+
+/// Clock that moves only when it is advanced by the test.
+///
+/// Sleeps complete immediately and advance the clock by the requested
+/// duration, so retry delays are observed without waiting.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockClockState>>,
+}
+
+#[derive(Debug)]
+struct MockClockState {
+    now: Instant,
+    system_time: SystemTime,
+    sleeps: Vec<Duration>,
+}
+
+impl MockClock {
+    /// Create clock that starts at current time.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockClockState {
+                now: Instant::now(),
+                system_time: SystemTime::now(),
+                sleeps: Vec::new(),
+            })),
+        }
+    }
+
+    /// Move clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.state.lock().expect("not poisoned");
+        state.now += duration;
+        state.system_time += duration;
+    }
+
+    /// Durations of all sleeps requested from the clock, in order.
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.state.lock().expect("not poisoned").sleeps.clone()
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.state.lock().expect("not poisoned").now
+    }
+
+    fn system_time(&self) -> SystemTime {
+        self.state.lock().expect("not poisoned").system_time
+    }
+
+    fn sleep(&self, duration: Duration) -> Sleep {
+        self.state
+            .lock()
+            .expect("not poisoned")
+            .sleeps
+            .push(duration);
+        self.advance(duration);
+        Box::pin(ready(()))
+    }
+}
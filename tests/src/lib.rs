@@ -17,11 +17,15 @@
 
 /// Schema compiled for base tests.
 pub mod base;
+/// Clock for tests of time-dependent behavior.
+pub mod clock;
 /// Errors used in tests.
 pub mod error;
 /// Expectations in tests.
 pub mod json_merge;
 
+#[doc(inline)]
+pub use clock::MockClock;
 #[doc(inline)]
 pub use error::Error;
 #[doc(inline)]
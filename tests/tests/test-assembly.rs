@@ -0,0 +1,188 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for assembly binary data transfer.
+
+use futures_util::io::Cursor;
+use nv_redfish::assembly::AssemblyData;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_core::UploadStream;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const CHASSIS_COLLECTION_DATA_TYPE: &str = "#ChassisCollection.ChassisCollection";
+const CHASSIS_DATA_TYPE: &str = "#Chassis.v1_22_0.Chassis";
+const ASSEMBLY_DATA_TYPE: &str = "#Assembly.v1_5_1.Assembly";
+const ASSEMBLY_MEMBER_DATA_TYPE: &str = "#Assembly.v1_5_1.AssemblyData";
+
+#[test]
+async fn assembly_binary_data_download_and_upload() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = test_ids();
+    let binary_data_uri = format!("{}/0/BinaryData", ids.assembly_id);
+    let assembly_data = get_assembly_data(bmc.clone(), &ids, Some(&binary_data_uri)).await?;
+
+    bmc.expect(Expect::binary_download(
+        &binary_data_uri,
+        b"fru-image".to_vec(),
+    ));
+    assert_eq!(
+        assembly_data.download_binary_data().await?,
+        Some(b"fru-image".to_vec())
+    );
+
+    bmc.expect(Expect::binary_upload(&binary_data_uri));
+    let response = assembly_data
+        .upload_binary_data(
+            UploadStream::new(Cursor::new(b"new-fru-image".to_vec())),
+            Duration::from_secs(60),
+        )
+        .await?;
+    assert!(matches!(response, ModificationResponse::Empty));
+
+    Ok(())
+}
+
+#[test]
+async fn assembly_binary_data_is_not_available() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = test_ids();
+    let assembly_data = get_assembly_data(bmc.clone(), &ids, None).await?;
+
+    assert_eq!(assembly_data.download_binary_data().await?, None);
+    assert!(matches!(
+        assembly_data
+            .upload_binary_data(
+                UploadStream::new(Cursor::new(b"new-fru-image".to_vec())),
+                Duration::from_secs(60),
+            )
+            .await,
+        Err(nv_redfish::Error::AssemblyBinaryDataUriNotAvailable)
+    ));
+
+    Ok(())
+}
+
+async fn get_assembly_data(
+    bmc: Arc<Bmc>,
+    ids: &TestIds,
+    binary_data_uri: Option<&str>,
+) -> Result<AssemblyData<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        json!({
+            ODATA_ID: &ids.root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "ProtocolFeaturesSupported": {
+                "ExpandQuery": {
+                    "NoLinks": true
+                }
+            },
+            "Vendor": "NVIDIA",
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: format!("{}/SessionService/Sessions", ids.root_id),
+                }
+            },
+        }),
+    ));
+    let service_root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(Expect::expand(
+        &ids.chassis_collection_id,
+        json!({
+            ODATA_ID: &ids.chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Id": "Chassis",
+            "Name": "Chassis Collection",
+            "Members": [{
+                ODATA_ID: &ids.chassis_id,
+                ODATA_TYPE: CHASSIS_DATA_TYPE,
+                "Id": "Chassis_0",
+                "Name": "Chassis_0",
+                "ChassisType": "RackMount",
+                "Assembly": { ODATA_ID: &ids.assembly_id }
+            }]
+        }),
+    ));
+    let chassis = service_root
+        .chassis()
+        .await?
+        .ok_or("missing chassis collection")?
+        .members()
+        .await?
+        .pop()
+        .ok_or("missing chassis")?;
+
+    let mut member = json!({
+        ODATA_ID: format!("{}#/Assemblies/0", ids.assembly_id),
+        ODATA_TYPE: ASSEMBLY_MEMBER_DATA_TYPE,
+        "MemberId": "0",
+        "Name": "FRU Assembly0",
+    });
+    if let Some(uri) = binary_data_uri {
+        member["BinaryDataURI"] = json!(uri);
+    }
+    bmc.expect(Expect::expand(
+        &ids.assembly_id,
+        json!({
+            ODATA_ID: &ids.assembly_id,
+            ODATA_TYPE: ASSEMBLY_DATA_TYPE,
+            "Id": "Assembly",
+            "Name": "Assembly",
+            "Assemblies": [member]
+        }),
+    ));
+    chassis
+        .assembly()
+        .await?
+        .ok_or("missing assembly")?
+        .assemblies()
+        .await?
+        .pop()
+        .ok_or_else(|| "missing assembly data".into())
+}
+
+struct TestIds {
+    root_id: ODataId,
+    chassis_collection_id: String,
+    chassis_id: String,
+    assembly_id: String,
+}
+
+fn test_ids() -> TestIds {
+    let root_id = ODataId::service_root();
+    let chassis_collection_id = format!("{root_id}/Chassis");
+    let chassis_id = format!("{chassis_collection_id}/Chassis_0");
+    let assembly_id = format!("{chassis_id}/Assembly");
+    TestIds {
+        root_id,
+        chassis_collection_id,
+        chassis_id,
+        assembly_id,
+    }
+}
@@ -16,6 +16,7 @@
 
 use nv_redfish::computer_system::Bios;
 use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::Error;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::EdmPrimitiveType;
 use nv_redfish_core::ODataId;
@@ -24,6 +25,7 @@ use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 use serde_json::json;
+use std::collections::BTreeMap;
 use std::error::Error as StdError;
 use std::sync::Arc;
 use tokio::test;
@@ -169,6 +171,46 @@ async fn bios_missing_or_empty_attributes() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+// Test 4: HPE Server Configuration Lock rejects BIOS and boot order
+// changes before anything is sent to the service.
+#[test]
+async fn bios_server_config_lock_rejects_changes() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = bios_ids();
+    let system = get_computer_system(bmc.clone(), &ids, "HPE").await?;
+    let locked = json!({
+        ODATA_ID: &ids.bios_id,
+        ODATA_TYPE: BIOS_DATA_TYPE,
+        "Id": "Bios",
+        "Name": "BIOS Settings",
+        "Attributes": {
+            "BootMode": "Uefi",
+            "ServerConfigLockStatus": "Enabled"
+        }
+    });
+
+    bmc.expect(Expect::get(&ids.bios_id, locked.clone()));
+    let bios: Bios<Bmc> = system.bios().await?.unwrap();
+    assert_eq!(bios.is_configuration_locked(), Some(true));
+    let attributes = BTreeMap::from([(
+        "BootMode".to_string(),
+        EdmPrimitiveType::String("LegacyBios".into()),
+    )]);
+    assert!(matches!(
+        bios.set_attributes(&attributes).await,
+        Err(Error::ConfigurationLocked(id)) if id.to_string() == ids.bios_id
+    ));
+
+    bmc.expect(Expect::get(&ids.bios_id, locked));
+    assert!(matches!(
+        system.set_boot_order(Vec::new()).await,
+        Err(Error::ConfigurationLocked(id)) if id.to_string() == ids.bios_id
+    ));
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
 struct BiosIds {
     root_id: ODataId,
     systems_id: String,
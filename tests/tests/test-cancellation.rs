@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of cooperative cancellation.
+
+use std::error::Error as StdError;
+use std::future::pending;
+use std::sync::Arc;
+
+use nv_redfish::cancellation::run_until_cancelled;
+use nv_redfish::cancellation::CancellationToken;
+use nv_redfish::Error;
+use nv_redfish::Resource as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use tokio::spawn;
+use tokio::test;
+
+#[test]
+async fn operation_completes_when_not_cancelled() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    bmc.expect(Expect::get(
+        "/redfish/v1",
+        json!({
+            ODATA_ID: "/redfish/v1",
+            ODATA_TYPE: "#ServiceRoot.v1_13_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: "/redfish/v1/SessionService/Sessions"
+                }
+            }
+        }),
+    ));
+
+    let token = CancellationToken::new();
+    let root = run_until_cancelled(&token, ServiceRoot::new(bmc)).await?;
+    assert_eq!(root.id().to_string(), "RootService");
+
+    Ok(())
+}
+
+#[test]
+async fn cancelled_token_does_not_start_operation() -> Result<(), Box<dyn StdError>> {
+    // No expectations: any BMC request would fail with unexpected
+    // request error.
+    let bmc = Arc::new(Bmc::default());
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let result = run_until_cancelled(&token, ServiceRoot::new(bmc)).await;
+    assert!(matches!(result, Err(Error::Cancelled)));
+
+    Ok(())
+}
+
+#[test]
+async fn pending_operation_is_aborted_on_cancel() -> Result<(), Box<dyn StdError>> {
+    let token = CancellationToken::new();
+    let canceller = token.clone();
+    spawn(async move { canceller.cancel() });
+
+    let result = run_until_cancelled(&token, pending::<Result<(), Error<Bmc>>>()).await;
+    assert!(matches!(result, Err(Error::Cancelled)));
+
+    Ok(())
+}
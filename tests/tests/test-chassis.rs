@@ -17,12 +17,31 @@
 use std::error::Error as StdError;
 use std::sync::Arc;
 
+use nv_redfish::chassis::BatteryReadings;
 use nv_redfish::chassis::Chassis;
+use nv_redfish::chassis::ChassisPower;
+use nv_redfish::chassis::ChassisPowerReadings;
+use nv_redfish::chassis::FanReading;
+use nv_redfish::chassis::PowerReport;
+use nv_redfish::chassis::PowerSource;
 use nv_redfish::chassis::PowerSupply;
+use nv_redfish::chassis::PowerSupplyReading;
+use nv_redfish::chassis::PowerSupplyReadings;
+use nv_redfish::chassis::TemperatureReading;
 use nv_redfish::control::ControlUpdate;
+use nv_redfish::resource::Health;
 use nv_redfish::resource::ResetType;
+use nv_redfish::schema::battery::ChargeState;
+use nv_redfish::schema::chassis::Chassis as ChassisSchema;
+use nv_redfish::schema::environment_metrics::EnvironmentMetrics;
+use nv_redfish::sensor::SensorThresholds;
+use nv_redfish::sensor::SensorType;
+use nv_redfish::Error;
+use nv_redfish::NvBmcBuilder;
+use nv_redfish::Resource as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::NavProperty;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::ami_service_root;
 use nv_redfish_tests::ami_viking_service_root;
@@ -36,6 +55,7 @@ use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 
+use serde::Deserialize;
 use serde_json::json;
 use serde_json::Value;
 use tokio::test;
@@ -45,6 +65,20 @@ const CHASSIS_DATA_TYPE: &str = "#Chassis.v1_23_0.Chassis";
 const POWER_SUBSYSTEM_DATA_TYPE: &str = "#PowerSubsystem.v1_1_0.PowerSubsystem";
 const POWER_SUPPLY_COLLECTION_DATA_TYPE: &str = "#PowerSupplyCollection.PowerSupplyCollection";
 const POWER_SUPPLY_DATA_TYPE: &str = "#PowerSupply.v1_5_0.PowerSupply";
+const POWER_SUPPLY_METRICS_DATA_TYPE: &str = "#PowerSupplyMetrics.v1_1_0.PowerSupplyMetrics";
+const BATTERY_COLLECTION_DATA_TYPE: &str = "#BatteryCollection.BatteryCollection";
+const BATTERY_DATA_TYPE: &str = "#Battery.v1_2_0.Battery";
+const BATTERY_METRICS_DATA_TYPE: &str = "#BatteryMetrics.v1_0_2.BatteryMetrics";
+const CONTROL_COLLECTION_DATA_TYPE: &str = "#ControlCollection.ControlCollection";
+const ENVIRONMENT_METRICS_DATA_TYPE: &str = "#EnvironmentMetrics.v1_1_0.EnvironmentMetrics";
+const POWER_DATA_TYPE: &str = "#Power.v1_7_1.Power";
+const THERMAL_DATA_TYPE: &str = "#Thermal.v1_7_1.Thermal";
+const THERMAL_SUBSYSTEM_DATA_TYPE: &str = "#ThermalSubsystem.v1_3_0.ThermalSubsystem";
+const THERMAL_METRICS_DATA_TYPE: &str = "#ThermalMetrics.v1_3_0.ThermalMetrics";
+const FAN_COLLECTION_DATA_TYPE: &str = "#FanCollection.FanCollection";
+const FAN_DATA_TYPE: &str = "#Fan.v1_5_0.Fan";
+const SENSOR_COLLECTION_DATA_TYPE: &str = "#SensorCollection.SensorCollection";
+const SENSOR_DATA_TYPE: &str = "#Sensor.v1_7_0.Sensor";
 
 #[test]
 async fn reset_invokes_chassis_reset_action() -> Result<(), Box<dyn StdError>> {
@@ -413,6 +447,61 @@ async fn ami_without_gb300_rtp_version_uses_expand() -> Result<(), Box<dyn StdEr
     Ok(())
 }
 
+#[test]
+async fn chassis_collection_follows_next_link() -> Result<(), Box<dyn StdError>> {
+    // Service returns the collection in portions: members of all pages
+    // are joined instead of truncating the collection to the first one.
+    // The first page is read once, and a link back to the collection
+    // ends it.
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_generic_ami_service_root(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+        }),
+    )
+    .await?;
+    let next_link = format!("{}?$skip=1", ids.chassis_collection_id);
+    let first_page = json!({
+        ODATA_ID: &ids.chassis_collection_id,
+        ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+        "Id": "Chassis",
+        "Name": "Chassis Collection",
+        "Members": [valid_chassis_payload(&ids)],
+        "Members@odata.count": 2,
+        "Members@odata.nextLink": &next_link,
+    });
+    bmc.expect(Expect::expand(&ids.chassis_collection_id, &first_page));
+    bmc.expect(Expect::expand(
+        &next_link,
+        json!({
+            ODATA_ID: &ids.chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Id": "Chassis",
+            "Name": "Chassis Collection",
+            "Members": [chassis_payload(&ids, json!({
+                ODATA_ID: &ids.container_chassis_id,
+                "Id": "0",
+            }))],
+            "Members@odata.count": 2,
+            "Members@odata.nextLink": &ids.chassis_collection_id,
+        }),
+    ));
+
+    let collection = root.chassis().await?.unwrap();
+    let members = collection.members().await?;
+    let members = members
+        .iter()
+        .map(|chassis| chassis.id().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(members, vec!["1", "0"]);
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
 #[test]
 async fn anonymous_1_9_0_wrong_chassis_status_state_workaround() -> Result<(), Box<dyn StdError>> {
     // Platform under test: Liteon powershelf class (anonymous Redfish 1.9.0 root).
@@ -448,6 +537,9 @@ async fn anonymous_1_9_0_wrong_chassis_status_state_workaround() -> Result<(), B
     );
     let members = collection.members().await?;
     assert_eq!(members.len(), 1);
+    let status = members[0].status().ok_or("missing status")?;
+    assert_eq!(status.health, Some(Health::Ok));
+    assert_eq!(status.health_rollup, Some(Health::Ok));
 
     Ok(())
 }
@@ -746,6 +838,15 @@ fn chassis_payload(ids: &Ids, fields: Value) -> Value {
     json_merge([&valid_chassis_payload(ids), &fields])
 }
 
+fn environment_metrics_payload(metrics_id: &str, name: &str) -> Value {
+    json!({
+        ODATA_ID: metrics_id,
+        ODATA_TYPE: ENVIRONMENT_METRICS_DATA_TYPE,
+        "Id": "EnvironmentMetrics",
+        "Name": name
+    })
+}
+
 fn control_payload(control_id: &str, set_point: f64) -> Value {
     json!({
         ODATA_ID: control_id,
@@ -761,6 +862,1026 @@ fn control_payload(control_id: &str, set_point: f64) -> Value {
     })
 }
 
+fn fan_control_payload(control_id: &str, control_mode: &str, set_point: f64) -> Value {
+    json!({
+        ODATA_ID: control_id,
+        ODATA_TYPE: "#Control.v1_7_0.Control",
+        "Id": "Fan1",
+        "Name": "Fan 1 Speed",
+        "ControlType": "Percent",
+        "PhysicalContext": "Fan",
+        "ControlMode": control_mode,
+        "SetPointType": "Single",
+        "SetPoint": set_point,
+        "SetPointUnits": "%",
+        "AllowableMin": 20.0,
+        "AllowableMax": 100.0
+    })
+}
+
+#[test]
+async fn power_supply_reports_firmware_version_and_readings() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let power_ids = power_supply_ids(&ids);
+    let metrics_id = format!("{}/Metrics", power_ids.power_supply_id);
+    let power_supply = get_power_supply(
+        bmc.clone(),
+        &ids,
+        &power_ids,
+        json!({
+            "FirmwareVersion": "1.2.3",
+            "Metrics": { ODATA_ID: &metrics_id }
+        }),
+    )
+    .await?;
+
+    assert_eq!(power_supply.firmware_version(), Some("1.2.3"));
+
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json!({
+            ODATA_ID: &metrics_id,
+            ODATA_TYPE: POWER_SUPPLY_METRICS_DATA_TYPE,
+            "Id": "Metrics",
+            "Name": "Power Supply Metrics",
+            "InputVoltage": { "Reading": 230.0 },
+            "InputCurrentAmps": { "Reading": 2.5 },
+            "InputPowerWatts": { "Reading": 575.0 },
+            "OutputPowerWatts": { "Reading": 540.0 },
+            "TemperatureCelsius": { "Reading": null }
+        }),
+    ));
+    assert_eq!(
+        power_supply.readings().await?,
+        Some(PowerSupplyReadings {
+            input_voltage: Some(230.0),
+            input_current_amps: Some(2.5),
+            input_power_watts: Some(575.0),
+            output_power_watts: Some(540.0),
+            energy_kwh: None,
+            temperature_celsius: None,
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn power_supply_without_metrics_has_no_readings() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let power_ids = power_supply_ids(&ids);
+    let power_supply = get_power_supply(bmc.clone(), &ids, &power_ids, json!({})).await?;
+
+    assert_eq!(power_supply.firmware_version(), None);
+    assert_eq!(power_supply.readings().await?, None);
+
+    Ok(())
+}
+
+#[test]
+async fn batteries_report_health_and_readings() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let power_subsystem_id = format!("{}/PowerSubsystem", ids.chassis_id);
+    let battery_collection_id = format!("{power_subsystem_id}/Batteries");
+    let battery_id = format!("{battery_collection_id}/1");
+    let metrics_id = format!("{battery_id}/Metrics");
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "PowerSubsystem": { ODATA_ID: &power_subsystem_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &power_subsystem_id,
+        json!({
+            ODATA_ID: &power_subsystem_id,
+            ODATA_TYPE: POWER_SUBSYSTEM_DATA_TYPE,
+            "Id": "PowerSubsystem",
+            "Name": "Power Subsystem",
+            "Batteries": { ODATA_ID: &battery_collection_id }
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &battery_collection_id,
+        json!({
+            ODATA_ID: &battery_collection_id,
+            ODATA_TYPE: BATTERY_COLLECTION_DATA_TYPE,
+            "Id": "Batteries",
+            "Name": "Battery Collection",
+            "Members": [{ ODATA_ID: &battery_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &battery_id,
+        json!({
+            ODATA_ID: &battery_id,
+            ODATA_TYPE: BATTERY_DATA_TYPE,
+            "Id": "1",
+            "Name": "Battery 1",
+            "FirmwareVersion": "2.0.1",
+            "ChargeState": "Idle",
+            "StateOfHealthPercent": { "Reading": 93.5 },
+            "Metrics": { ODATA_ID: &metrics_id }
+        }),
+    ));
+    let mut batteries = chassis.batteries().await?;
+    let battery = batteries
+        .pop()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "missing battery"))?;
+
+    assert_eq!(battery.firmware_version(), Some("2.0.1"));
+    assert_eq!(battery.charge_state(), Some(ChargeState::Idle));
+    assert_eq!(battery.state_of_health_percent(), Some(93.5));
+
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json!({
+            ODATA_ID: &metrics_id,
+            ODATA_TYPE: BATTERY_METRICS_DATA_TYPE,
+            "Id": "Metrics",
+            "Name": "Battery Metrics",
+            "InputVoltage": { "Reading": 12.1 },
+            "StoredEnergyWattHours": { "Reading": 150.0 },
+            "ChargePercent": { "Reading": 98.0 }
+        }),
+    ));
+    assert_eq!(
+        battery.readings().await?,
+        Some(BatteryReadings {
+            input_voltage: Some(12.1),
+            stored_energy_watt_hours: Some(150.0),
+            charge_percent: Some(98.0),
+            ..BatteryReadings::default()
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn power_report_aggregates_chassis_and_reports_failures() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let metrics_id = format!("{}/EnvironmentMetrics", ids.chassis_id);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "EnvironmentMetrics": { ODATA_ID: &metrics_id }
+            }),
+        ),
+    )
+    .await?;
+    let failing_bmc = Arc::new(Bmc::default());
+    let failing_chassis = get_chassis(
+        failing_bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Id": "2",
+                ODATA_ID: &ids.container_chassis_id,
+                "EnvironmentMetrics": { ODATA_ID: &metrics_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json_merge([
+            &environment_metrics_payload(&metrics_id, "Chassis Environment Metrics"),
+            &json!({
+                "PowerWatts": { "Reading": 450.0 },
+                "EnergykWh": { "Reading": 10.5 }
+            }),
+        ]),
+    ));
+    let report = PowerReport::collect(&[chassis, failing_chassis]).await;
+
+    assert_eq!(
+        report.chassis,
+        vec![ChassisPower {
+            chassis: ODataId::from(ids.chassis_id.clone()),
+            readings: ChassisPowerReadings {
+                power_watts: Some(450.0),
+                power_source: Some(PowerSource::EnvironmentMetrics),
+                energy_kwh: Some(10.5),
+                capacity_watts: None,
+            },
+        }]
+    );
+    assert_eq!(report.failures.len(), 1);
+    assert_eq!(
+        report.failures[0].0,
+        ODataId::from(ids.container_chassis_id.clone())
+    );
+    assert!(!report.is_complete());
+    assert_eq!(report.total_power_watts(), 450.0);
+
+    let previous = PowerReport {
+        chassis: vec![ChassisPower {
+            chassis: ODataId::from(ids.chassis_id.clone()),
+            readings: ChassisPowerReadings {
+                energy_kwh: Some(10.0),
+                ..ChassisPowerReadings::default()
+            },
+        }],
+        failures: Vec::new(),
+    };
+    assert_eq!(
+        report.interval_energy_kwh(&previous),
+        vec![(ODataId::from(ids.chassis_id.clone()), 0.5)]
+    );
+    assert_eq!(previous.interval_energy_kwh(&report), Vec::new());
+
+    Ok(())
+}
+
+#[test]
+async fn power_readings_fall_back_to_power_supplies() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let power_ids = power_supply_ids(&ids);
+    let metrics_id = format!("{}/Metrics", power_ids.power_supply_id);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "PowerSubsystem": { ODATA_ID: &power_ids.power_subsystem_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &power_ids.power_subsystem_id,
+        json!({
+            ODATA_ID: &power_ids.power_subsystem_id,
+            ODATA_TYPE: POWER_SUBSYSTEM_DATA_TYPE,
+            "Id": "PowerSubsystem",
+            "Name": "Power Subsystem",
+            "CapacityWatts": 2000.0,
+            "PowerSupplies": { ODATA_ID: &power_ids.power_supply_collection_id }
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &power_ids.power_supply_collection_id,
+        json!({
+            ODATA_ID: &power_ids.power_supply_collection_id,
+            ODATA_TYPE: POWER_SUPPLY_COLLECTION_DATA_TYPE,
+            "Id": "PowerSupplies",
+            "Name": "Power Supply Collection",
+            "Members": [{ ODATA_ID: &power_ids.power_supply_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &power_ids.power_supply_id,
+        power_supply_payload(
+            &power_ids,
+            json!({
+                "Metrics": { ODATA_ID: &metrics_id }
+            }),
+        ),
+    ));
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json!({
+            ODATA_ID: &metrics_id,
+            ODATA_TYPE: POWER_SUPPLY_METRICS_DATA_TYPE,
+            "Id": "Metrics",
+            "Name": "Power Supply Metrics",
+            "InputPowerWatts": { "Reading": 575.0 }
+        }),
+    ));
+
+    assert_eq!(
+        chassis.power_readings().await?,
+        ChassisPowerReadings {
+            power_watts: Some(575.0),
+            power_source: Some(PowerSource::PowerSupplies),
+            energy_kwh: None,
+            capacity_watts: Some(2000.0),
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn fan_speed_controls_override_and_revert() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let control_collection_id = format!("{}/Controls", ids.chassis_id);
+    let power_limit_id = format!("{control_collection_id}/PowerLimit");
+    let fan_id = format!("{control_collection_id}/Fan1");
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Controls": { ODATA_ID: &control_collection_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &control_collection_id,
+        json!({
+            ODATA_ID: &control_collection_id,
+            ODATA_TYPE: CONTROL_COLLECTION_DATA_TYPE,
+            "Id": "Controls",
+            "Name": "Control Collection",
+            "Members": [
+                { ODATA_ID: &power_limit_id },
+                { ODATA_ID: &fan_id }
+            ]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &power_limit_id,
+        control_payload(&power_limit_id, 600.0),
+    ));
+    bmc.expect(Expect::get(
+        &fan_id,
+        fan_control_payload(&fan_id, "Automatic", 40.0),
+    ));
+    let mut fans = chassis.fan_speed_controls().await?;
+    assert_eq!(fans.len(), 1);
+    let fan = fans
+        .pop()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "missing fan"))?;
+
+    assert!(matches!(
+        fan.set_fan_speed(10.0).await,
+        Err(nv_redfish::Error::FanSpeedOutOfRange { .. })
+    ));
+    assert!(matches!(
+        fan.set_fan_speed(120.0).await,
+        Err(nv_redfish::Error::FanSpeedOutOfRange { .. })
+    ));
+
+    bmc.expect(Expect::update(
+        &fan_id,
+        json!({
+            "ControlMode": "Override",
+            "SetPoint": 80.0
+        }),
+        fan_control_payload(&fan_id, "Override", 80.0),
+    ));
+    let ModificationResponse::Entity(fan) = fan.set_fan_speed(80.0).await? else {
+        return Err(
+            std::io::Error::new(std::io::ErrorKind::InvalidData, "missing updated fan").into(),
+        );
+    };
+    assert_eq!(fan.raw().set_point, Some(Some(80.0)));
+
+    bmc.expect(Expect::update(
+        &fan_id,
+        json!({
+            "ControlMode": "Automatic"
+        }),
+        fan_control_payload(&fan_id, "Automatic", 40.0),
+    ));
+    assert!(matches!(
+        fan.revert_to_automatic().await?,
+        ModificationResponse::Entity(_)
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn set_fan_speed_rejects_non_fan_control() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let control_collection_id = format!("{}/Controls", ids.chassis_id);
+    let power_limit_id = format!("{control_collection_id}/PowerLimit");
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Controls": { ODATA_ID: &control_collection_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &control_collection_id,
+        json!({
+            ODATA_ID: &control_collection_id,
+            ODATA_TYPE: CONTROL_COLLECTION_DATA_TYPE,
+            "Id": "Controls",
+            "Name": "Control Collection",
+            "Members": [{ ODATA_ID: &power_limit_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &power_limit_id,
+        control_payload(&power_limit_id, 600.0),
+    ));
+    let controls = chassis.controls().await?.unwrap_or_default();
+    assert_eq!(controls.len(), 1);
+    assert!(matches!(
+        controls[0].set_fan_speed(50.0).await,
+        Err(nv_redfish::Error::ControlNotFanSpeed)
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn chassis_fan_speed_falls_back_to_fan_speeds_percent() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let metrics_id = format!("{}/EnvironmentMetrics", ids.chassis_id);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "EnvironmentMetrics": { ODATA_ID: &metrics_id }
+            }),
+        ),
+    )
+    .await?;
+    let metrics = json_merge([
+        &environment_metrics_payload(&metrics_id, "Chassis Environment Metrics"),
+        &json!({
+            "FanSpeedsPercent": [
+                { "DeviceName": "Fan1", "Reading": 40.0 },
+                { "DeviceName": "Fan2", "Reading": 42.0 }
+            ]
+        }),
+    ]);
+
+    assert!(matches!(
+        chassis.set_fan_speed(120.0).await,
+        Err(Error::FanSpeedOutOfRange { .. })
+    ));
+
+    bmc.expect(Expect::get(&metrics_id, metrics.clone()));
+    bmc.expect(Expect::update(
+        &metrics_id,
+        json!({
+            "FanSpeedsPercent": [
+                { "DeviceName": "Fan1", "Reading": 80.0 },
+                { "DeviceName": "Fan2", "Reading": 80.0 }
+            ]
+        }),
+        metrics.clone(),
+    ));
+    chassis.set_fan_speed(80.0).await?;
+
+    bmc.expect(Expect::get(&metrics_id, metrics.clone()));
+    bmc.expect(Expect::update(
+        &metrics_id,
+        json!({
+            "FanSpeedsPercent": [
+                { "DeviceName": "Fan1", "Reading": null },
+                { "DeviceName": "Fan2", "Reading": null }
+            ]
+        }),
+        metrics,
+    ));
+    chassis.revert_fans_to_automatic().await?;
+
+    Ok(())
+}
+
+#[test]
+async fn chassis_without_fan_control_reports_not_available() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let chassis = get_chassis(bmc.clone(), &ids, chassis_payload(&ids, json!({}))).await?;
+
+    assert!(matches!(
+        chassis.set_fan_speed(80.0).await,
+        Err(Error::FanSpeedControlNotAvailable(id)) if id.to_string() == ids.chassis_id
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn durable_id_prefers_uuid_over_serial_number() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let uuid = "38947555-7742-3448-3784-823347823834";
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({ "UUID": uuid, "Manufacturer": "Contoso", "SerialNumber": "SN123" }),
+        ),
+    )
+    .await?;
+    assert_eq!(chassis.durable_id().to_string(), format!("uuid:{uuid}"));
+
+    let bmc = Arc::new(Bmc::default());
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({ "Manufacturer": "Contoso", "SerialNumber": "SN123" }),
+        ),
+    )
+    .await?;
+    assert_eq!(chassis.durable_id().to_string(), "sn:Contoso:SN123");
+
+    let bmc = Arc::new(Bmc::default());
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(&ids, json!({ "SerialNumber": "N/A" })),
+    )
+    .await?;
+    assert_eq!(
+        chassis.durable_id().to_string(),
+        format!("odata:{}", ids.chassis_id)
+    );
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct VendorLinks {
+    managed_chassis: NavProperty<ChassisSchema>,
+}
+
+#[test]
+async fn links_oem_deserialize_into_user_type() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let managed_chassis_id = "/redfish/v1/Chassis/HGX_Baseboard_0";
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Links": {
+                    "Oem": {
+                        "Vendor": {
+                            "ManagedChassis": { ODATA_ID: managed_chassis_id }
+                        }
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    assert!(chassis.links_oem().is_some());
+    let links = chassis
+        .oem_links::<VendorLinks>("Vendor")?
+        .expect("vendor OEM links");
+    assert_eq!(links.managed_chassis.id().to_string(), managed_chassis_id);
+    assert!(chassis.oem_links::<VendorLinks>("Other")?.is_none());
+    assert!(chassis.oem_links::<Vec<String>>("Vendor").is_err());
+
+    Ok(())
+}
+
+#[test]
+async fn links_oem_absent() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let chassis = get_chassis(bmc.clone(), &ids, valid_chassis_payload(&ids)).await?;
+
+    assert!(chassis.links_oem().is_none());
+    assert!(chassis.oem_links::<VendorLinks>("Vendor")?.is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn follow_fetches_navigation_property() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let metrics_id = format!("{}/Sensors/EnvironmentMetrics", ids.chassis_id);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "EnvironmentMetrics": { ODATA_ID: &metrics_id }
+            }),
+        ),
+    )
+    .await?;
+
+    assert_eq!(
+        chassis
+            .navigation_property("EnvironmentMetrics")
+            .map(ToString::to_string),
+        Some(metrics_id.clone())
+    );
+    assert!(chassis.navigation_property("Unknown").is_none());
+
+    bmc.expect(Expect::get(
+        &metrics_id,
+        environment_metrics_payload(&metrics_id, "Environment Metrics"),
+    ));
+    let nv_bmc = NvBmcBuilder::new(bmc.clone()).build();
+    let metrics = chassis
+        .follow::<EnvironmentMetrics, _>(&nv_bmc, "EnvironmentMetrics")
+        .await?;
+    assert_eq!(metrics.base.name, "Environment Metrics");
+
+    // Paths that are not navigation properties are not requested.
+    let result = chassis
+        .follow::<EnvironmentMetrics, _>(&nv_bmc, "Oem/Vendor/EnvironmentMetrics")
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::NavigationPropertyNotAvailable(id, name))
+            if id.to_string() == ids.chassis_id && name == "Oem/Vendor/EnvironmentMetrics"
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn power_subsystem_reports_power_supply_readings() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let power_ids = power_supply_ids(&ids);
+    let metrics_id = format!("{}/Metrics", power_ids.power_supply_id);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "PowerSubsystem": { ODATA_ID: &power_ids.power_subsystem_id }
+            }),
+        ),
+    )
+    .await?;
+
+    expect_power_supply(
+        bmc.clone(),
+        &power_ids,
+        power_supply_payload(
+            &power_ids,
+            json!({
+                "Metrics": { ODATA_ID: &metrics_id }
+            }),
+        ),
+    );
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json!({
+            ODATA_ID: &metrics_id,
+            ODATA_TYPE: POWER_SUPPLY_METRICS_DATA_TYPE,
+            "Id": "Metrics",
+            "Name": "Power Supply Metrics",
+            "InputVoltage": { "Reading": 230.0 },
+            "InputPowerWatts": { "Reading": 575.0 }
+        }),
+    ));
+    let subsystem = chassis.power_subsystem().await?.unwrap();
+    assert!(!subsystem.is_legacy());
+    assert_eq!(
+        subsystem.power_supply_readings().await?,
+        vec![PowerSupplyReading {
+            id: power_ids.power_supply_id.clone().into(),
+            name: Some("Power Supply 1".into()),
+            readings: PowerSupplyReadings {
+                input_voltage: Some(230.0),
+                input_power_watts: Some(575.0),
+                ..PowerSupplyReadings::default()
+            },
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn power_subsystem_falls_back_to_legacy_power() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let power_id = format!("{}/Power", ids.chassis_id);
+    let power_supply_id = format!("{power_id}#/PowerSupplies/0");
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Power": { ODATA_ID: &power_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &power_id,
+        json!({
+            ODATA_ID: &power_id,
+            ODATA_TYPE: POWER_DATA_TYPE,
+            "Id": "Power",
+            "Name": "Power",
+            "PowerSupplies": [{
+                ODATA_ID: &power_supply_id,
+                "MemberId": "0",
+                "Name": "PSU 0",
+                "LineInputVoltage": 230.0,
+                "PowerInputWatts": 575.0,
+                "LastPowerOutputWatts": 540.0
+            }]
+        }),
+    ));
+    let subsystem = chassis.power_subsystem().await?.unwrap();
+    assert!(subsystem.is_legacy());
+    assert_eq!(subsystem.capacity_watts(), None);
+    assert!(subsystem.power_supplies().await?.is_empty());
+    assert_eq!(
+        subsystem.power_supply_readings().await?,
+        vec![PowerSupplyReading {
+            id: power_supply_id.into(),
+            name: Some("PSU 0".into()),
+            readings: PowerSupplyReadings {
+                input_voltage: Some(230.0),
+                input_power_watts: Some(575.0),
+                output_power_watts: Some(540.0),
+                ..PowerSupplyReadings::default()
+            },
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn thermal_subsystem_reports_fans_and_temperatures() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let thermal_id = format!("{}/ThermalSubsystem", ids.chassis_id);
+    let fan_collection_id = format!("{thermal_id}/Fans");
+    let fan_id = format!("{fan_collection_id}/1");
+    let metrics_id = format!("{thermal_id}/ThermalMetrics");
+    let sensor_id = format!("{}/Sensors/Inlet", ids.chassis_id);
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "ThermalSubsystem": { ODATA_ID: &thermal_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &thermal_id,
+        json!({
+            ODATA_ID: &thermal_id,
+            ODATA_TYPE: THERMAL_SUBSYSTEM_DATA_TYPE,
+            "Id": "ThermalSubsystem",
+            "Name": "Thermal Subsystem",
+            "Fans": { ODATA_ID: &fan_collection_id },
+            "ThermalMetrics": { ODATA_ID: &metrics_id }
+        }),
+    ));
+    let subsystem = chassis.thermal_subsystem().await?.unwrap();
+    assert!(!subsystem.is_legacy());
+
+    bmc.expect(Expect::get(
+        &fan_collection_id,
+        json!({
+            ODATA_ID: &fan_collection_id,
+            ODATA_TYPE: FAN_COLLECTION_DATA_TYPE,
+            "Id": "Fans",
+            "Name": "Fan Collection",
+            "Members": [{ ODATA_ID: &fan_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &fan_id,
+        json!({
+            ODATA_ID: &fan_id,
+            ODATA_TYPE: FAN_DATA_TYPE,
+            "Id": "1",
+            "Name": "Fan 1",
+            "SpeedPercent": { "Reading": 45.0, "SpeedRPM": 5400.0 }
+        }),
+    ));
+    assert_eq!(
+        subsystem.fans().await?,
+        vec![FanReading {
+            id: fan_id.into(),
+            name: Some("Fan 1".into()),
+            speed_percent: Some(45.0),
+            speed_rpm: Some(5400.0),
+        }]
+    );
+
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json!({
+            ODATA_ID: &metrics_id,
+            ODATA_TYPE: THERMAL_METRICS_DATA_TYPE,
+            "Id": "ThermalMetrics",
+            "Name": "Thermal Metrics",
+            "TemperatureReadingsCelsius": [
+                { "DataSourceUri": &sensor_id, "Reading": 25.5 }
+            ]
+        }),
+    ));
+    assert_eq!(
+        subsystem.temperatures().await?,
+        vec![TemperatureReading {
+            sensor: Some(sensor_id.into()),
+            name: None,
+            reading_celsius: Some(25.5),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn thermal_subsystem_falls_back_to_legacy_thermal() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let thermal_id = format!("{}/Thermal", ids.chassis_id);
+    let fan_id = format!("{thermal_id}#/Fans/0");
+    let temperature_id = format!("{thermal_id}#/Temperatures/0");
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Thermal": { ODATA_ID: &thermal_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &thermal_id,
+        json!({
+            ODATA_ID: &thermal_id,
+            ODATA_TYPE: THERMAL_DATA_TYPE,
+            "Id": "Thermal",
+            "Name": "Thermal",
+            "Fans": [{
+                ODATA_ID: &fan_id,
+                "MemberId": "0",
+                "Name": "Fan 0",
+                "Reading": 4800,
+                "ReadingUnits": "RPM"
+            }],
+            "Temperatures": [{
+                ODATA_ID: &temperature_id,
+                "MemberId": "0",
+                "Name": "Inlet",
+                "ReadingCelsius": 24.0
+            }]
+        }),
+    ));
+    let subsystem = chassis.thermal_subsystem().await?.unwrap();
+    assert!(subsystem.is_legacy());
+    assert_eq!(
+        subsystem.fans().await?,
+        vec![FanReading {
+            id: fan_id.into(),
+            name: Some("Fan 0".into()),
+            speed_percent: None,
+            speed_rpm: Some(4800.0),
+        }]
+    );
+    assert_eq!(
+        subsystem.temperatures().await?,
+        vec![TemperatureReading {
+            sensor: Some(temperature_id.into()),
+            name: Some("Inlet".into()),
+            reading_celsius: Some(24.0),
+        }]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn sensors_report_readings_and_filter_by_type() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let sensors_id = format!("{}/Sensors", ids.chassis_id);
+    let inlet_id = format!("{sensors_id}/Inlet");
+    let power_id = format!("{sensors_id}/Power");
+    let chassis = get_chassis(
+        bmc.clone(),
+        &ids,
+        chassis_payload(
+            &ids,
+            json!({
+                "Sensors": { ODATA_ID: &sensors_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &sensors_id,
+        json!({
+            ODATA_ID: &sensors_id,
+            ODATA_TYPE: SENSOR_COLLECTION_DATA_TYPE,
+            "Id": "Sensors",
+            "Name": "Sensor Collection",
+            "Members": [{ ODATA_ID: &inlet_id }, { ODATA_ID: &power_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &inlet_id,
+        json!({
+            ODATA_ID: &inlet_id,
+            ODATA_TYPE: SENSOR_DATA_TYPE,
+            "Id": "Inlet",
+            "Name": "Inlet Temperature",
+            "ReadingType": "Temperature",
+            "Reading": 24.5,
+            "ReadingUnits": "Cel",
+            "Thresholds": {
+                "UpperCaution": { "Reading": 40.0 },
+                "UpperCritical": { "Reading": 45.0 }
+            }
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &power_id,
+        json!({
+            ODATA_ID: &power_id,
+            ODATA_TYPE: SENSOR_DATA_TYPE,
+            "Id": "Power",
+            "Name": "Chassis Power",
+            "ReadingType": "Power",
+            "Reading": 575.0,
+            "ReadingUnits": "W"
+        }),
+    ));
+    let sensors = chassis.sensors_by_type(SensorType::Temperature).await?;
+
+    assert_eq!(sensors.len(), 1);
+    let sensor = &sensors[0];
+    assert_eq!(sensor.odata_id().to_string(), inlet_id);
+    assert_eq!(sensor.reading_type(), Some(SensorType::Temperature));
+    assert_eq!(sensor.reading(), Some(24.5));
+    assert_eq!(sensor.reading_units(), Some("Cel"));
+    assert_eq!(
+        sensor.thresholds(),
+        SensorThresholds {
+            upper_caution: Some(40.0),
+            upper_critical: Some(45.0),
+            ..SensorThresholds::default()
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn sensors_absent_without_sensors_link() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let chassis = get_chassis(bmc.clone(), &ids, valid_chassis_payload(&ids)).await?;
+
+    assert!(chassis.sensors().await?.is_none());
+    assert!(chassis
+        .sensors_by_type(SensorType::Temperature)
+        .await?
+        .is_empty());
+
+    Ok(())
+}
+
 async fn get_chassis(
     bmc: Arc<Bmc>,
     ids: &Ids,
@@ -0,0 +1,157 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of Component Integrity attestation.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::component_integrity::AttestationEvidence;
+use nv_redfish::component_integrity::ComponentIntegrityType;
+use nv_redfish::component_integrity::VerificationStatus;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+const COMPONENT_INTEGRITY_PATH: &str = "/redfish/v1/ComponentIntegrity";
+const BMC_INTEGRITY_PATH: &str = "/redfish/v1/ComponentIntegrity/BMC";
+const BMC_CERTIFICATE_PATH: &str = "/redfish/v1/Managers/BMC/Certificates/SPDM";
+const BMC_PATH: &str = "/redfish/v1/Managers/BMC";
+const TRUSTED_CHAIN: &str = "-----BEGIN CERTIFICATE-----\ntrusted\n-----END CERTIFICATE-----\n";
+
+#[test]
+async fn new_attested_accepts_verified_bmc() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_component_integrity(&bmc, TRUSTED_CHAIN);
+
+    ServiceRoot::new_attested(bmc.clone(), &trusted_bmc_policy).await?;
+    Ok(())
+}
+
+#[test]
+async fn new_attested_rejects_untrusted_chain() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_component_integrity(
+        &bmc,
+        "-----BEGIN CERTIFICATE-----\nrogue\n-----END CERTIFICATE-----\n",
+    );
+
+    let result = ServiceRoot::new_attested(bmc.clone(), &trusted_bmc_policy).await;
+    assert!(
+        matches!(result, Err(Error::AttestationFailed(reason)) if reason == "BMC identity is not trusted")
+    );
+    Ok(())
+}
+
+#[test]
+async fn attest_without_component_integrity() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, None)));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    let policy = |evidence: &[AttestationEvidence]| {
+        if evidence.is_empty() {
+            Err("Service does not support attestation".to_string())
+        } else {
+            Ok(())
+        }
+    };
+    let result = root.attest(&policy).await;
+    assert!(matches!(result, Err(Error::AttestationFailed(_))));
+    Ok(())
+}
+
+fn trusted_bmc_policy(evidence: &[AttestationEvidence]) -> Result<(), String> {
+    evidence
+        .iter()
+        .find(|e| e.target_component_uri.as_deref() == Some(BMC_PATH))
+        .filter(|e| e.integrity_type == ComponentIntegrityType::Spdm)
+        .filter(|e| e.verification_status == Some(VerificationStatus::Success))
+        .filter(|e| e.certificate_chain.as_deref() == Some(TRUSTED_CHAIN))
+        .map(|_| ())
+        .ok_or_else(|| "BMC identity is not trusted".to_string())
+}
+
+fn expect_component_integrity(bmc: &Bmc, certificate_chain: &str) {
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        &root_id,
+        service_root(&root_id, Some(COMPONENT_INTEGRITY_PATH)),
+    ));
+    bmc.expect(Expect::get(
+        COMPONENT_INTEGRITY_PATH,
+        json!({
+            ODATA_ID: COMPONENT_INTEGRITY_PATH,
+            ODATA_TYPE: "#ComponentIntegrityCollection.ComponentIntegrityCollection",
+            "Name": "Component Integrity Collection",
+            "Members": [{ ODATA_ID: BMC_INTEGRITY_PATH }],
+            "Members@odata.count": 1
+        }),
+    ));
+    bmc.expect(Expect::get(
+        BMC_INTEGRITY_PATH,
+        json!({
+            ODATA_ID: BMC_INTEGRITY_PATH,
+            ODATA_TYPE: "#ComponentIntegrity.v1_2_0.ComponentIntegrity",
+            "Id": "BMC",
+            "Name": "BMC SPDM Integrity",
+            "ComponentIntegrityType": "SPDM",
+            "ComponentIntegrityTypeVersion": "1.1.0",
+            "ComponentIntegrityEnabled": true,
+            "TargetComponentURI": BMC_PATH,
+            "SPDM": {
+                "IdentityAuthentication": {
+                    "ComponentCertificate": { ODATA_ID: BMC_CERTIFICATE_PATH },
+                    "VerificationStatus": "Success"
+                }
+            }
+        }),
+    ));
+    bmc.expect(Expect::get(
+        BMC_CERTIFICATE_PATH,
+        json!({
+            ODATA_ID: BMC_CERTIFICATE_PATH,
+            ODATA_TYPE: "#Certificate.v1_8_0.Certificate",
+            "Id": "SPDM",
+            "Name": "BMC SPDM Certificate",
+            "CertificateString": certificate_chain,
+            "CertificateType": "PEMchain"
+        }),
+    ));
+}
+
+fn service_root(root_id: &ODataId, component_integrity: Option<&str>) -> serde_json::Value {
+    let mut root = json!({
+        ODATA_ID: root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "Links": {
+            "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+        }
+    });
+    if let Some(id) = component_integrity {
+        root["ComponentIntegrity"] = json!({ ODATA_ID: id });
+    }
+    root
+}
@@ -15,8 +15,11 @@
 //! Integration tests for NVIDIA Bluefield ComputerSystem OEM support.
 
 use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::oem::nvidia::bluefield::nvidia_computer_system::BaseMac;
+use nv_redfish::oem::nvidia::bluefield::nvidia_computer_system::HostPrivilegeLevel;
 use nv_redfish::oem::nvidia::bluefield::nvidia_computer_system::Mode;
 use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
 use nv_redfish_tests::Bmc;
@@ -67,8 +70,12 @@ async fn oem_nvidia_bluefield_missing_odata_id_in_oem_target_payload(
         .await?
         .expect("NVIDIA OEM extension must be available");
     assert_eq!(
-        oem.base_mac().map(|v| v.to_string()),
-        Some("1070fd010203".into())
+        oem.base_mac().transpose()?.map(|v| v.to_string()),
+        Some("10:70:fd:01:02:03".into())
+    );
+    assert_eq!(
+        oem.base_mac().transpose()?.map(BaseMac::into_inner),
+        Some("10:70:fd:01:02:03".parse()?)
     );
     assert_eq!(oem.mode(), Some(Mode::NicMode));
 
@@ -108,8 +115,8 @@ async fn oem_nvidia_bluefield_with_odata_id_still_supported() -> Result<(), Box<
         .await?
         .expect("NVIDIA OEM extension must be available");
     assert_eq!(
-        oem.base_mac().map(|v| v.to_string()),
-        Some("aabbccddeeff".into())
+        oem.base_mac().transpose()?.map(|v| v.to_string()),
+        Some("aa:bb:cc:dd:ee:ff".into())
     );
     assert_eq!(oem.mode(), Some(Mode::DpuMode));
 
@@ -168,14 +175,123 @@ async fn oem_nvidia_bluefield_inline_oem_object_shape_supported() -> Result<(),
         .await?
         .expect("NVIDIA OEM extension must be available");
     assert_eq!(
-        oem.base_mac().map(|v| v.to_string()),
-        Some("001122334455".into())
+        oem.base_mac().transpose()?.map(|v| v.to_string()),
+        Some("00:11:22:33:44:55".into())
     );
     assert_eq!(oem.mode(), Some(Mode::NicMode));
 
     Ok(())
 }
 
+#[test]
+async fn oem_nvidia_bluefield_switches_mode_and_resets_nic_firmware(
+) -> Result<(), Box<dyn StdError>> {
+    // Platform under test: NVIDIA Bluefield OEM extension.
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        system_payload(
+            &ids,
+            Some(json!({
+                "Nvidia": { ODATA_ID: &ids.nvidia_oem_id }
+            })),
+        ),
+    )
+    .await?;
+
+    let set_mode_target = format!("{}/Actions/Mode.Set", ids.nvidia_oem_id);
+    let nic_reset_target = format!(
+        "{}/Actions/NvidiaComputerSystem.NicFirmwareReset",
+        ids.nvidia_oem_id
+    );
+    bmc.expect(Expect::get(
+        &ids.nvidia_oem_id,
+        json!({
+            ODATA_ID: &ids.nvidia_oem_id,
+            ODATA_TYPE: NVIDIA_SYSTEM_DATA_TYPE,
+            "BaseMAC": "1070fd010203",
+            "Mode": "NicMode",
+            "HostPrivilegeLevel": "Restricted",
+            "Actions": {
+                "#Mode.Set": { "target": &set_mode_target },
+                "#NvidiaComputerSystem.NicFirmwareReset": { "target": &nic_reset_target }
+            }
+        }),
+    ));
+    let oem = system
+        .oem_nvidia_bluefield()
+        .await?
+        .expect("NVIDIA OEM extension must be available");
+    assert_eq!(
+        oem.host_privilege_level(),
+        Some(HostPrivilegeLevel::Restricted)
+    );
+
+    bmc.expect(Expect::action(
+        &set_mode_target,
+        json!({ "Mode": "DpuMode" }),
+        json!(null),
+    ));
+    assert!(matches!(
+        oem.set_mode(Mode::DpuMode).await?,
+        ModificationResponse::Entity(())
+    ));
+
+    bmc.expect(Expect::action(&nic_reset_target, json!({}), json!(null)));
+    assert!(matches!(
+        oem.nic_firmware_reset().await?,
+        ModificationResponse::Entity(())
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn oem_nvidia_bluefield_without_actions_returns_action_not_available(
+) -> Result<(), Box<dyn StdError>> {
+    // Platform under test: NVIDIA Bluefield OEM extension without actions.
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        system_payload(
+            &ids,
+            Some(json!({
+                "Nvidia": { ODATA_ID: &ids.nvidia_oem_id }
+            })),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        &ids.nvidia_oem_id,
+        json!({
+            ODATA_ID: &ids.nvidia_oem_id,
+            ODATA_TYPE: NVIDIA_SYSTEM_DATA_TYPE,
+            "BaseMAC": "1070fd010203",
+            "Mode": "DpuMode"
+        }),
+    ));
+    let oem = system
+        .oem_nvidia_bluefield()
+        .await?
+        .expect("NVIDIA OEM extension must be available");
+    assert!(oem.host_privilege_level().is_none());
+    assert!(matches!(
+        oem.set_mode(Mode::NicMode).await,
+        Err(nv_redfish::Error::ActionNotAvailable)
+    ));
+    assert!(matches!(
+        oem.nic_firmware_reset().await,
+        Err(nv_redfish::Error::ActionNotAvailable)
+    ));
+
+    Ok(())
+}
+
 async fn get_system(
     bmc: Arc<Bmc>,
     ids: &Ids,
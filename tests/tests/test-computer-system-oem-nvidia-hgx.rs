@@ -0,0 +1,467 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for NVIDIA HGX baseboard OEM support.
+
+use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::computer_system::Memory;
+use nv_redfish::computer_system::Processor;
+use nv_redfish::fabric::Protocol;
+use nv_redfish::fabric::Switch;
+use nv_redfish::oem::nvidia::hgx::RowRemappingCounters;
+use nv_redfish::oem::nvidia::hgx::SwitchIsolationMode;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const SYSTEM_COLLECTION_DATA_TYPE: &str = "#ComputerSystemCollection.ComputerSystemCollection";
+const SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_19_0.ComputerSystem";
+const PROCESSOR_COLLECTION_DATA_TYPE: &str = "#ProcessorCollection.ProcessorCollection";
+const PROCESSOR_DATA_TYPE: &str = "#Processor.v1_18_0.Processor";
+const MEMORY_COLLECTION_DATA_TYPE: &str = "#MemoryCollection.MemoryCollection";
+const MEMORY_DATA_TYPE: &str = "#Memory.v1_17_0.Memory";
+const MEMORY_METRICS_DATA_TYPE: &str = "#MemoryMetrics.v1_7_0.MemoryMetrics";
+const FABRIC_COLLECTION_DATA_TYPE: &str = "#FabricCollection.FabricCollection";
+const FABRIC_DATA_TYPE: &str = "#Fabric.v1_3_0.Fabric";
+const SWITCH_COLLECTION_DATA_TYPE: &str = "#SwitchCollection.SwitchCollection";
+const SWITCH_DATA_TYPE: &str = "#Switch.v1_9_0.Switch";
+const NVIDIA_SWITCH_DATA_TYPE: &str = "#NvidiaSwitch.v1_0_0.NvidiaSwitch";
+const NVIDIA_GPU_DATA_TYPE: &str = "#NvidiaProcessor.v1_0_0.NvidiaGPU";
+const NVIDIA_GPU_MEMORY_METRICS_DATA_TYPE: &str =
+    "#NvidiaMemoryMetrics.v1_0_0.NvidiaGPUMemoryMetrics";
+
+#[test]
+async fn oem_nvidia_hgx_gpu_reports_oem_data() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let gpu = get_gpu(
+        bmc.clone(),
+        &ids,
+        json!({
+            "MemorySummary": { "ECCModeEnabled": true },
+            "Oem": {
+                "Nvidia": {
+                    ODATA_TYPE: NVIDIA_GPU_DATA_TYPE,
+                    "MIGModeEnabled": false,
+                    "PCIeReferenceClockEnabled": true,
+                    "NVLinkCount": 18
+                }
+            }
+        }),
+    )
+    .await?;
+
+    let oem = gpu.oem_nvidia_hgx_gpu()?.unwrap();
+    assert_eq!(oem.mig_mode_enabled(), Some(false));
+    assert_eq!(oem.pcie_reference_clock_enabled(), Some(true));
+    assert_eq!(oem.nvlink_count(), Some(18));
+    assert_eq!(oem.ecc_mode_enabled(), Some(true));
+
+    Ok(())
+}
+
+#[test]
+async fn oem_nvidia_hgx_gpu_switches_ecc_mode() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let oem = json!({
+        "Oem": {
+            "Nvidia": {
+                ODATA_TYPE: NVIDIA_GPU_DATA_TYPE,
+                "MIGModeEnabled": false
+            }
+        }
+    });
+    let gpu = get_gpu(
+        bmc.clone(),
+        &ids,
+        json_merge([
+            &json!({ "MemorySummary": { "ECCModeEnabled": true } }),
+            &oem,
+        ]),
+    )
+    .await?;
+    let gpu = gpu.oem_nvidia_hgx_gpu()?.unwrap();
+
+    bmc.expect(Expect::update(
+        &ids.gpu_id,
+        json!({ "MemorySummary": { "ECCModeEnabled": false } }),
+        gpu_payload(
+            &ids,
+            json_merge([
+                &json!({ "MemorySummary": { "ECCModeEnabled": false } }),
+                &oem,
+            ]),
+        ),
+    ));
+    let ModificationResponse::Entity(updated) = gpu.set_ecc_mode_enabled(false).await? else {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "missing updated processor",
+        )
+        .into());
+    };
+    let updated = updated.oem_nvidia_hgx_gpu()?.unwrap();
+    assert_eq!(updated.ecc_mode_enabled(), Some(false));
+
+    Ok(())
+}
+
+#[test]
+async fn processor_without_nvidia_gpu_oem_returns_none() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let cpu = get_gpu(bmc.clone(), &ids, json!({})).await?;
+    assert!(cpu.oem_nvidia_hgx_gpu()?.is_none());
+
+    let bmc = Arc::new(Bmc::default());
+    let cpu = get_gpu(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Oem": {
+                "Nvidia": {
+                    ODATA_TYPE: "#NvidiaProcessor.v1_0_0.NvidiaCPU",
+                    "MIGModeEnabled": false
+                }
+            }
+        }),
+    )
+    .await?;
+    assert!(cpu.oem_nvidia_hgx_gpu()?.is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn oem_nvidia_hgx_gpu_memory_reports_row_remapping() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let memory = get_gpu_memory(bmc.clone(), &ids).await?;
+
+    bmc.expect(Expect::get(
+        &ids.gpu_memory_metrics_id,
+        json!({
+            ODATA_ID: &ids.gpu_memory_metrics_id,
+            ODATA_TYPE: MEMORY_METRICS_DATA_TYPE,
+            "Id": "MemoryMetrics",
+            "Name": "GPU Memory Metrics",
+            "Oem": {
+                "Nvidia": {
+                    ODATA_TYPE: NVIDIA_GPU_MEMORY_METRICS_DATA_TYPE,
+                    "RowRemappingFailed": false,
+                    "RowRemappingPending": true,
+                    "RowRemapping": {
+                        "CorrectableRowRemappingCount": 3,
+                        "UncorrectableRowRemappingCount": 1
+                    }
+                }
+            }
+        }),
+    ));
+    let metrics = memory.oem_nvidia_hgx_metrics().await?.unwrap();
+    assert_eq!(metrics.row_remapping_failed(), Some(false));
+    assert_eq!(metrics.row_remapping_pending(), Some(true));
+    assert_eq!(
+        metrics.row_remapping(),
+        Some(RowRemappingCounters {
+            correctable: Some(3),
+            uncorrectable: Some(1),
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn gpu_memory_without_nvidia_oem_returns_none() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let memory = get_gpu_memory(bmc.clone(), &ids).await?;
+
+    bmc.expect(Expect::get(
+        &ids.gpu_memory_metrics_id,
+        json!({
+            ODATA_ID: &ids.gpu_memory_metrics_id,
+            ODATA_TYPE: MEMORY_METRICS_DATA_TYPE,
+            "Id": "MemoryMetrics",
+            "Name": "GPU Memory Metrics"
+        }),
+    ));
+    assert!(memory.oem_nvidia_hgx_metrics().await?.is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn oem_nvidia_hgx_nvswitch_reports_oem_data() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let switch = get_nvswitch(
+        bmc.clone(),
+        &ids,
+        json!({
+            "Oem": {
+                "Nvidia": {
+                    ODATA_TYPE: NVIDIA_SWITCH_DATA_TYPE,
+                    "SwitchIsolationMode": "SwitchCommunicationDisabled",
+                    "PCIeReferenceClockEnabled": false,
+                    "NVLinkCount": 64
+                }
+            }
+        }),
+    )
+    .await?;
+
+    assert_eq!(switch.switch_type(), Some(Protocol::NvLink));
+    let oem = switch.oem_nvidia_hgx_switch()?.unwrap();
+    assert_eq!(
+        oem.isolation_mode(),
+        Some(SwitchIsolationMode::SwitchCommunicationDisabled)
+    );
+    assert_eq!(oem.pcie_reference_clock_enabled(), Some(false));
+    assert_eq!(oem.nvlink_count(), Some(64));
+
+    Ok(())
+}
+
+#[test]
+async fn switch_without_nvidia_oem_returns_none() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let switch = get_nvswitch(bmc.clone(), &ids, json!({})).await?;
+    assert!(switch.oem_nvidia_hgx_switch()?.is_none());
+
+    Ok(())
+}
+
+async fn get_nvswitch(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    fields: Value,
+) -> Result<Switch<Bmc>, Box<dyn StdError>> {
+    let root = expect_service_root(bmc.clone(), ids).await?;
+    bmc.expect(Expect::expand(
+        &ids.fabrics_id,
+        json!({
+            ODATA_ID: &ids.fabrics_id,
+            ODATA_TYPE: FABRIC_COLLECTION_DATA_TYPE,
+            "Id": "Fabrics",
+            "Name": "Fabric Collection",
+            "Members": [{
+                ODATA_ID: &ids.fabric_id,
+                ODATA_TYPE: FABRIC_DATA_TYPE,
+                "Id": "HGX_NVLinkFabric_0",
+                "Name": "NVLink Fabric",
+                "FabricType": "NVLink",
+                "Switches": { ODATA_ID: &ids.switches_id }
+            }]
+        }),
+    ));
+    let mut fabrics = root.fabrics().await?.unwrap().members().await?;
+    let fabric = fabrics
+        .pop()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "missing fabric"))?;
+    assert_eq!(fabric.fabric_type(), Some(Protocol::NvLink));
+
+    let switch = json!({
+        ODATA_ID: &ids.switch_id,
+        ODATA_TYPE: SWITCH_DATA_TYPE,
+        "Id": "NVSwitch_0",
+        "Name": "NVSwitch 0",
+        "SwitchType": "NVLink"
+    });
+    bmc.expect(Expect::expand(
+        &ids.switches_id,
+        json!({
+            ODATA_ID: &ids.switches_id,
+            ODATA_TYPE: SWITCH_COLLECTION_DATA_TYPE,
+            "Id": "Switches",
+            "Name": "Switch Collection",
+            "Members": [json_merge([&switch, &fields])]
+        }),
+    ));
+    let mut switches = fabric.switches().await?.unwrap_or_default();
+    switches.pop().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, "missing switch").into()
+    })
+}
+
+async fn get_gpu(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    fields: Value,
+) -> Result<Processor<Bmc>, Box<dyn StdError>> {
+    let system = get_system(bmc.clone(), ids).await?;
+    bmc.expect(Expect::expand(
+        &ids.processors_id,
+        json!({
+            ODATA_ID: &ids.processors_id,
+            ODATA_TYPE: PROCESSOR_COLLECTION_DATA_TYPE,
+            "Id": "Processors",
+            "Name": "Processor Collection",
+            "Members": [gpu_payload(ids, fields)]
+        }),
+    ));
+    let mut processors = system.processors().await?.unwrap_or_default();
+    processors.pop().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, "missing processor").into()
+    })
+}
+
+async fn get_gpu_memory(bmc: Arc<Bmc>, ids: &Ids) -> Result<Memory<Bmc>, Box<dyn StdError>> {
+    let system = get_system(bmc.clone(), ids).await?;
+    bmc.expect(Expect::expand(
+        &ids.memory_id,
+        json!({
+            ODATA_ID: &ids.memory_id,
+            ODATA_TYPE: MEMORY_COLLECTION_DATA_TYPE,
+            "Id": "Memory",
+            "Name": "Memory Collection",
+            "Members": [{
+                ODATA_ID: &ids.gpu_memory_id,
+                ODATA_TYPE: MEMORY_DATA_TYPE,
+                "Id": "GPU_SXM_1_DRAM_0",
+                "Name": "GPU 1 DRAM",
+                "Metrics": { ODATA_ID: &ids.gpu_memory_metrics_id }
+            }]
+        }),
+    ));
+    let mut memory = system.memory_modules().await?.unwrap_or_default();
+    memory.pop().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, "missing memory").into()
+    })
+}
+
+async fn get_system(bmc: Arc<Bmc>, ids: &Ids) -> Result<ComputerSystem<Bmc>, Box<dyn StdError>> {
+    let root = expect_service_root(bmc.clone(), ids).await?;
+    bmc.expect(Expect::expand(
+        &ids.systems_id,
+        json!({
+            ODATA_ID: &ids.systems_id,
+            ODATA_TYPE: SYSTEM_COLLECTION_DATA_TYPE,
+            "Id": "Systems",
+            "Name": "Computer System Collection",
+            "Members": [{
+                ODATA_ID: &ids.system_id,
+                ODATA_TYPE: SYSTEM_DATA_TYPE,
+                "Id": "HGX_Baseboard_0",
+                "Name": "HGX Baseboard",
+                "Processors": { ODATA_ID: &ids.processors_id },
+                "Memory": { ODATA_ID: &ids.memory_id }
+            }]
+        }),
+    ));
+
+    let systems = root.systems().await?.unwrap();
+    let mut members = systems.members().await?;
+    members.pop().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, "missing system").into()
+    })
+}
+
+async fn expect_service_root(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        json!({
+            ODATA_ID: &ids.root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "ProtocolFeaturesSupported": {
+                "ExpandQuery": {
+                    "NoLinks": true
+                }
+            },
+            "Systems": { ODATA_ID: &ids.systems_id },
+            "Fabrics": { ODATA_ID: &ids.fabrics_id },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: format!("{}/SessionService/Sessions", ids.root_id),
+                }
+            },
+        }),
+    ));
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+struct Ids {
+    root_id: ODataId,
+    systems_id: String,
+    system_id: String,
+    processors_id: String,
+    gpu_id: String,
+    memory_id: String,
+    gpu_memory_id: String,
+    gpu_memory_metrics_id: String,
+    fabrics_id: String,
+    fabric_id: String,
+    switches_id: String,
+    switch_id: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let systems_id = format!("{root_id}/Systems");
+    let system_id = format!("{systems_id}/HGX_Baseboard_0");
+    let processors_id = format!("{system_id}/Processors");
+    let gpu_id = format!("{processors_id}/GPU_SXM_1");
+    let memory_id = format!("{system_id}/Memory");
+    let gpu_memory_id = format!("{memory_id}/GPU_SXM_1_DRAM_0");
+    let gpu_memory_metrics_id = format!("{gpu_memory_id}/MemoryMetrics");
+    let fabrics_id = format!("{root_id}/Fabrics");
+    let fabric_id = format!("{fabrics_id}/HGX_NVLinkFabric_0");
+    let switches_id = format!("{fabric_id}/Switches");
+    let switch_id = format!("{switches_id}/NVSwitch_0");
+    Ids {
+        root_id,
+        systems_id,
+        system_id,
+        processors_id,
+        gpu_id,
+        memory_id,
+        gpu_memory_id,
+        gpu_memory_metrics_id,
+        fabrics_id,
+        fabric_id,
+        switches_id,
+        switch_id,
+    }
+}
+
+fn gpu_payload(ids: &Ids, fields: Value) -> Value {
+    let base = json!({
+        ODATA_ID: &ids.gpu_id,
+        ODATA_TYPE: PROCESSOR_DATA_TYPE,
+        "Id": "GPU_SXM_1",
+        "Name": "GPU SXM 1",
+        "ProcessorType": "GPU"
+    });
+    json_merge([&base, &fields])
+}
@@ -17,12 +17,32 @@
 use std::error::Error as StdError;
 use std::sync::Arc;
 
+use futures_util::TryStreamExt as _;
+use nv_redfish::computer_system::boot_option::UefiDevicePath;
+use nv_redfish::computer_system::fabric_adapter::LinkState;
+use nv_redfish::computer_system::fabric_adapter::LinkStatus;
+use nv_redfish::computer_system::fabric_adapter::Model;
+use nv_redfish::computer_system::fabric_adapter::Protocol;
+use nv_redfish::computer_system::graphics_controller::Model as GraphicsControllerModel;
+use nv_redfish::computer_system::operating_system::OperatingSystemTypes;
+use nv_redfish::computer_system::operating_system::VirtualMachineEngineTypes;
+use nv_redfish::computer_system::usb_controller::Manufacturer as UsbControllerManufacturer;
 use nv_redfish::computer_system::BootOptionReference;
+use nv_redfish::computer_system::BootProgressTypes;
+use nv_redfish::computer_system::BootSourceOverrideEnabled;
 use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::computer_system::HostingRole;
 use nv_redfish::computer_system::SystemCollection;
+use nv_redfish::computer_system::SystemType;
+use nv_redfish::hardware_id::HardwareKind;
+use nv_redfish::resource::Health;
+use nv_redfish::resource::PowerState;
 use nv_redfish::resource::ResetType;
+use nv_redfish::resource::State;
+use nv_redfish::resource::Status;
 use nv_redfish::Resource;
 use nv_redfish::ServiceRoot;
+use nv_redfish_core::EdmDateTimeOffset;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::ami_viking_service_root;
@@ -39,6 +59,7 @@ use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 
+use futures_util::stream;
 use serde_json::json;
 use serde_json::Value;
 use tokio::test;
@@ -46,6 +67,33 @@ use tokio::test;
 const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
 const SYSTEM_COLLECTION_DATA_TYPE: &str = "#ComputerSystemCollection.ComputerSystemCollection";
 const SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_20_0.ComputerSystem";
+const BOOT_OPTION_COLLECTION_DATA_TYPE: &str = "#BootOptionCollection.BootOptionCollection";
+const BOOT_OPTION_DATA_TYPE: &str = "#BootOption.v1_0_4.BootOption";
+const PROCESSOR_COLLECTION_DATA_TYPE: &str = "#ProcessorCollection.ProcessorCollection";
+const PROCESSOR_DATA_TYPE: &str = "#Processor.v1_18_0.Processor";
+const MEMORY_COLLECTION_DATA_TYPE: &str = "#MemoryCollection.MemoryCollection";
+const MEMORY_DATA_TYPE: &str = "#Memory.v1_17_0.Memory";
+const FABRIC_ADAPTER_COLLECTION_DATA_TYPE: &str =
+    "#FabricAdapterCollection.FabricAdapterCollection";
+const FABRIC_ADAPTER_DATA_TYPE: &str = "#FabricAdapter.v1_5_0.FabricAdapter";
+const PORT_COLLECTION_DATA_TYPE: &str = "#PortCollection.PortCollection";
+const PORT_DATA_TYPE: &str = "#Port.v1_11_0.Port";
+const GRAPHICS_CONTROLLER_COLLECTION_DATA_TYPE: &str =
+    "#GraphicsControllerCollection.GraphicsControllerCollection";
+const GRAPHICS_CONTROLLER_DATA_TYPE: &str = "#GraphicsController.v1_0_1.GraphicsController";
+const USB_CONTROLLER_COLLECTION_DATA_TYPE: &str =
+    "#USBControllerCollection.USBControllerCollection";
+const USB_CONTROLLER_DATA_TYPE: &str = "#USBController.v1_0_1.USBController";
+const PCIE_DEVICE_DATA_TYPE: &str = "#PCIeDevice.v1_11_0.PCIeDevice";
+const STORAGE_COLLECTION_DATA_TYPE: &str = "#StorageCollection.StorageCollection";
+const STORAGE_DATA_TYPE: &str = "#Storage.v1_15_0.Storage";
+const DRIVE_DATA_TYPE: &str = "#Drive.v1_18_0.Drive";
+const DRIVE_METRICS_DATA_TYPE: &str = "#DriveMetrics.v1_2_0.DriveMetrics";
+const LOG_SERVICE_COLLECTION_DATA_TYPE: &str = "#LogServiceCollection.LogServiceCollection";
+const LOG_SERVICE_DATA_TYPE: &str = "#LogService.v1_4_0.LogService";
+const LOG_ENTRY_COLLECTION_DATA_TYPE: &str = "#LogEntryCollection.LogEntryCollection";
+const LOG_ENTRY_DATA_TYPE: &str = "#LogEntry.v1_15_0.LogEntry";
+const OPERATING_SYSTEM_DATA_TYPE: &str = "#OperatingSystem.v1_0_2.OperatingSystem";
 
 #[test]
 async fn reset_invokes_computer_system_reset_action() -> Result<(), Box<dyn StdError>> {
@@ -79,64 +127,1287 @@ async fn reset_invokes_computer_system_reset_action() -> Result<(), Box<dyn StdE
     Ok(())
 }
 
+#[test]
+async fn system_type_hosting_roles_and_operating_system() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let os_id = format!("{}/OperatingSystem", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "SystemType": "DPU",
+                "HostingRoles": ["BareMetalServer", "ContainerServer"],
+                "OperatingSystem": { ODATA_ID: &os_id }
+            }),
+        ),
+    )
+    .await?;
+
+    assert_eq!(system.system_type(), Some(SystemType::Dpu));
+    assert_eq!(
+        system.hosting_roles(),
+        &[HostingRole::BareMetalServer, HostingRole::ContainerServer]
+    );
+
+    bmc.expect(Expect::get(
+        &os_id,
+        json!({
+            ODATA_ID: &os_id,
+            ODATA_TYPE: OPERATING_SYSTEM_DATA_TYPE,
+            "Id": "OperatingSystem",
+            "Name": "Operating System",
+            "Type": "Linux",
+            "VirtualMachineEngines": [{ "Type": "KVM", "Version": "8.2" }]
+        }),
+    ));
+    let os = system.operating_system().await?.unwrap();
+    assert_eq!(os.os_type(), Some(OperatingSystemTypes::Linux));
+    assert_eq!(
+        os.virtual_machine_engines(),
+        vec![VirtualMachineEngineTypes::Kvm]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn system_without_virtualization_indicators() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(bmc.clone(), &ids, computer_system(&ids, json!({}))).await?;
+
+    assert_eq!(system.system_type(), None);
+    assert!(system.hosting_roles().is_empty());
+    assert!(system.operating_system().await?.is_none());
+
+    Ok(())
+}
+
 #[test]
 async fn set_boot_order_preserves_task_and_empty_responses() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
     let ids = computer_system_ids();
-
+
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, json!({ "Boot": { "BootOrder": ["Boot0001"] } })),
+    )
+    .await?;
+
+    let task_id = "/redfish/v1/TaskService/Tasks/52";
+
+    bmc.expect(Expect::update_task(
+        &ids.system_id,
+        json!({ "Boot": { "BootOrder": ["Boot0002"] } }),
+        async_task(task_id, 7),
+    ));
+
+    assert_task(
+        system
+            .set_boot_order(vec![BootOptionReference::new("Boot0002".into())])
+            .await?,
+        task_id,
+        7,
+    );
+
+    bmc.expect(Expect::update_empty(
+        &ids.system_id,
+        json!({ "Boot": { "BootOrder": ["Boot0003"] } }),
+    ));
+
+    assert_empty(
+        system
+            .set_boot_order(vec![BootOptionReference::new("Boot0003".into())])
+            .await?,
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn set_uefi_target_boot_override_validates_device_path() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let boot_options_id = format!("{}/BootOptions", ids.system_id);
+    let boot_option_id = format!("{boot_options_id}/Boot0001");
+    let device_path = "PciRoot(0x0)/Pci(0x1,0x0)/Pci(0x0,0x0)/MAC(B83FD2000001,0x1)";
+
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({ "Boot": { "BootOptions": { ODATA_ID: &boot_options_id } } }),
+        ),
+    )
+    .await?;
+
+    let expect_boot_options = || {
+        Expect::expand(
+            &boot_options_id,
+            json!({
+                ODATA_ID: &boot_options_id,
+                ODATA_TYPE: BOOT_OPTION_COLLECTION_DATA_TYPE,
+                "Name": "Boot Options",
+                "Members": [{
+                    ODATA_ID: &boot_option_id,
+                    ODATA_TYPE: BOOT_OPTION_DATA_TYPE,
+                    "Id": "Boot0001",
+                    "Name": "Boot0001",
+                    "BootOptionReference": "Boot0001",
+                    "UefiDevicePath": device_path
+                }]
+            }),
+        )
+    };
+
+    bmc.expect(expect_boot_options());
+    let err = match system
+        .set_uefi_target_boot_override(
+            UefiDevicePath::new("PciRoot(0x1)".into()),
+            BootSourceOverrideEnabled::Once,
+        )
+        .await
+    {
+        Ok(_) => panic!("unknown UEFI device path must be rejected"),
+        Err(err) => err,
+    };
+    assert!(matches!(
+        err,
+        nv_redfish::Error::UefiTargetNotInBootOptions(ref path) if path == "PciRoot(0x1)"
+    ));
+
+    bmc.expect(expect_boot_options());
+    bmc.expect(Expect::update_empty(
+        &ids.system_id,
+        json!({
+            "Boot": {
+                "BootSourceOverrideTarget": "UefiTarget",
+                "BootSourceOverrideEnabled": "Once",
+                "UefiTargetBootSourceOverride": device_path
+            }
+        }),
+    ));
+    assert_empty(
+        system
+            .set_uefi_target_boot_override(
+                UefiDevicePath::new(device_path.into()),
+                BootSourceOverrideEnabled::Once,
+            )
+            .await?,
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn reset_rejects_reset_type_not_in_allowable_values() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let action_target = format!("{}/Actions/ComputerSystem.Reset", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "Actions": {
+                    "#ComputerSystem.Reset": {
+                        "target": &action_target,
+                        "ResetType@Redfish.AllowableValues": ["On", "ForceOff", "Unknown"]
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    assert_eq!(
+        system.allowed_reset_types(),
+        Some(vec![ResetType::On, ResetType::ForceOff])
+    );
+    match system.reset(Some(ResetType::GracefulRestart)).await {
+        Err(nv_redfish::Error::ResetTypeNotAllowed { requested, allowed }) => {
+            assert_eq!(requested, ResetType::GracefulRestart);
+            assert_eq!(allowed, vec!["On", "ForceOff", "Unknown"]);
+        }
+        _ => panic!("expected ResetTypeNotAllowed error"),
+    }
+
+    expect_redfish_reset_action(&bmc, &action_target, Some("ForceOff"));
+    assert!(matches!(
+        system.reset(Some(ResetType::ForceOff)).await?,
+        ModificationResponse::Entity(())
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn reset_returns_action_not_available_when_computer_system_reset_is_absent(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, redfish_empty_actions_payload()),
+    )
+    .await?;
+
+    assert!(matches!(
+        system.reset(Some(ResetType::GracefulRestart)).await,
+        Err(nv_redfish::Error::ActionNotAvailable)
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn summary_reports_processor_and_memory_summaries() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "PowerState": "On",
+                "ProcessorSummary": {
+                    "Count": 2,
+                    "CoreCount": 144,
+                    "Model": "Grace",
+                    "Status": { "Health": "OK", "HealthRollup": "Warning" }
+                },
+                "MemorySummary": {
+                    "TotalSystemMemoryGiB": 960,
+                    "Status": { "Health": "OK" }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    let summary = system.summary();
+    assert_eq!(summary.power_state, Some(PowerState::On));
+    assert_eq!(summary.health, Some(Health::Ok));
+
+    let processors = summary.processors.ok_or("missing processor summary")?;
+    assert_eq!(processors.count, Some(2));
+    assert_eq!(processors.core_count, Some(144));
+    assert_eq!(processors.model, Some("Grace"));
+    assert_eq!(processors.health, Some(Health::Warning));
+
+    let memory = summary.memory.ok_or("missing memory summary")?;
+    assert_eq!(memory.total_system_memory_gib, Some(960.0));
+    assert_eq!(memory.health, Some(Health::Ok));
+
+    Ok(())
+}
+
+#[test]
+async fn wait_for_boot_complete_polls_until_post_is_finished() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, json!({ "PowerState": "Off" })),
+    )
+    .await?;
+    assert!(system.boot_progress().is_none());
+
+    bmc.expect(Expect::get(
+        &ids.system_id,
+        computer_system(
+            &ids,
+            json!({
+                "PowerState": "On",
+                "BootProgress": { "LastState": "PCIResourceConfigStarted" }
+            }),
+        ),
+    ));
+    bmc.expect(Expect::get(
+        &ids.system_id,
+        computer_system(
+            &ids,
+            json!({
+                "PowerState": "On",
+                "BootProgress": {
+                    "LastState": "OSRunning",
+                    "LastBootTimeSeconds": 95.5,
+                    "OemLastState": null
+                }
+            }),
+        ),
+    ));
+    let booted = system
+        .wait_for_boot_complete(stream::iter([(), ()]))
+        .await?;
+    let progress = booted.boot_progress().ok_or("missing boot progress")?;
+    assert_eq!(progress.last_state, Some(BootProgressTypes::OsRunning));
+    assert_eq!(progress.last_boot_time_seconds, Some(95.5));
+    assert_eq!(progress.oem_last_state, None);
+    assert!(progress.is_post_complete());
+
+    bmc.expect(Expect::get(
+        &ids.system_id,
+        computer_system(
+            &ids,
+            json!({
+                "PowerState": "On",
+                "BootProgress": { "LastState": "SetupEntered" }
+            }),
+        ),
+    ));
+    let result = system.wait_for_boot_complete(stream::empty::<()>()).await;
+    assert!(matches!(
+        result,
+        Err(nv_redfish::Error::BootNotComplete(id)) if id.to_string() == ids.system_id
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn wait_for_boot_complete_skips_boot_progress_before_reset() -> Result<(), Box<dyn StdError>>
+{
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let booted = |time: &str| {
+        computer_system(
+            &ids,
+            json!({
+                "PowerState": "On",
+                "BootProgress": { "LastState": "OSRunning", "LastStateTime": time }
+            }),
+        )
+    };
+    // System read before the reset.
+    let system = get_system(bmc.clone(), &ids, booted("2026-01-01T10:00:00Z")).await?;
+
+    // Service still reports the previous boot right after the reset.
+    bmc.expect(Expect::get(&ids.system_id, booted("2026-01-01T10:00:00Z")));
+    bmc.expect(Expect::get(
+        &ids.system_id,
+        computer_system(
+            &ids,
+            json!({
+                "PowerState": "On",
+                "BootProgress": {
+                    "LastState": "MemoryInitializationStarted",
+                    "LastStateTime": "2026-01-01T10:05:00Z"
+                }
+            }),
+        ),
+    ));
+    bmc.expect(Expect::get(&ids.system_id, booted("2026-01-01T10:07:00Z")));
+    let rebooted = system
+        .wait_for_boot_complete(stream::iter([(), ()]))
+        .await?;
+    bmc.assert_consumed();
+    assert_eq!(
+        rebooted
+            .boot_progress()
+            .and_then(|progress| progress.last_state_time)
+            .map(|time| time.to_string()),
+        Some("2026-01-01T10:07:00Z".to_string())
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn status_is_typed_and_ordered_by_severity() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "Status": { "State": "Enabled", "Health": "OK", "HealthRollup": "Critical" }
+            }),
+        ),
+    )
+    .await?;
+
+    let status = system.status().ok_or("missing status")?;
+    assert_eq!(status.state, Some(State::Enabled));
+    assert_eq!(status.health, Some(Health::Ok));
+    assert_eq!(status.worst_health(), Some(Health::Critical));
+
+    assert!(Health::Ok < Health::Warning);
+    assert!(Health::Warning < Health::Critical);
+    assert!(State::Enabled < State::Disabled);
+
+    let aggregated = Status::default().max(status).max(Status {
+        state: Some(State::Absent),
+        health: Some(Health::Warning),
+        health_rollup: None,
+    });
+    assert_eq!(aggregated.state, Some(State::Absent));
+    assert_eq!(aggregated.health, Some(Health::Warning));
+    assert_eq!(aggregated.health_rollup, Some(Health::Critical));
+
+    Ok(())
+}
+
+#[test]
+async fn summary_is_empty_when_system_does_not_report_it() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let system = get_system(bmc.clone(), &ids, computer_system(&ids, json!({}))).await?;
+
+    assert!(system.processor_summary().is_none());
+    assert!(system.memory_summary().is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn collect_hardware_ids_walks_processors_and_memory() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let processors_id = format!("{}/Processors", ids.system_id);
+    let memory_id = format!("{}/Memory", ids.system_id);
+    let root = expect_service_root(bmc.clone(), &ids, "NVIDIA").await?;
+    bmc.expect(Expect::expand(
+        &ids.systems_id,
+        json!({
+            ODATA_ID: &ids.systems_id,
+            ODATA_TYPE: &SYSTEM_COLLECTION_DATA_TYPE,
+            "Id": resource_name(&ids.systems_id),
+            "Name": "Computer System Collection",
+            "Members": [computer_system(
+                &ids,
+                json!({
+                    "Manufacturer": "NVIDIA",
+                    "Model": "GB200 NVL",
+                    "SerialNumber": "1581324000123",
+                    "Processors": { ODATA_ID: &processors_id },
+                    "Memory": { ODATA_ID: &memory_id }
+                }),
+            )]
+        }),
+    ));
+    bmc.expect(Expect::expand(
+        &processors_id,
+        json!({
+            ODATA_ID: &processors_id,
+            ODATA_TYPE: PROCESSOR_COLLECTION_DATA_TYPE,
+            "Id": "Processors",
+            "Name": "Processor Collection",
+            "Members": [{
+                ODATA_ID: format!("{processors_id}/CPU_0"),
+                ODATA_TYPE: PROCESSOR_DATA_TYPE,
+                "Id": "CPU_0",
+                "Name": "CPU 0",
+                "Manufacturer": "NVIDIA",
+                "Model": "Grace A02P",
+                "PartNumber": "900-2G530-0000-000",
+                "SerialNumber": null
+            }]
+        }),
+    ));
+    bmc.expect(Expect::expand(
+        &memory_id,
+        json!({
+            ODATA_ID: &memory_id,
+            ODATA_TYPE: MEMORY_COLLECTION_DATA_TYPE,
+            "Id": "Memory",
+            "Name": "Memory Collection",
+            "Members": [{
+                ODATA_ID: format!("{memory_id}/DIMM_0"),
+                ODATA_TYPE: MEMORY_DATA_TYPE,
+                "Id": "DIMM_0",
+                "Name": "DIMM 0",
+                "Manufacturer": "Micron",
+                "PartNumber": "MT62F1G32D2DS"
+            }]
+        }),
+    ));
+
+    let inventory = root.collect_hardware_ids().await?;
+
+    assert_eq!(
+        inventory.iter().map(|item| item.kind).collect::<Vec<_>>(),
+        vec![
+            HardwareKind::ComputerSystem,
+            HardwareKind::Processor,
+            HardwareKind::Memory
+        ]
+    );
+    let system = &inventory[0].hardware_id;
+    assert_eq!(inventory[0].odata_id.to_string(), ids.system_id);
+    assert_eq!(
+        system.model.as_ref().map(|v| v.inner().as_str()),
+        Some("GB200 NVL")
+    );
+    assert_eq!(
+        system.serial_number.as_ref().map(|v| v.inner().as_str()),
+        Some("1581324000123")
+    );
+    let processor = &inventory[1].hardware_id;
+    assert_eq!(
+        inventory[1].odata_id.to_string(),
+        format!("{processors_id}/CPU_0")
+    );
+    assert_eq!(
+        processor.part_number.as_ref().map(|v| v.inner().as_str()),
+        Some("900-2G530-0000-000")
+    );
+    assert!(processor.serial_number.is_none());
+    let memory = &inventory[2].hardware_id;
+    assert_eq!(
+        memory.manufacturer.as_ref().map(|v| v.inner().as_str()),
+        Some("Micron")
+    );
+    assert!(memory.model.is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn expanded_inventory_fetches_collections_in_one_request() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let processors_id = format!("{}/Processors", ids.system_id);
+    let memory_id = format!("{}/Memory", ids.system_id);
+    let system = computer_system(
+        &ids,
+        json!({
+            "Processors": { ODATA_ID: &processors_id },
+            "Memory": { ODATA_ID: &memory_id }
+        }),
+    );
+    let root = expect_service_root_with_features(
+        bmc.clone(),
+        &ids,
+        json!({ "ExpandQuery": { "Levels": true, "MaxLevels": 2, "NoLinks": true } }),
+    )
+    .await?;
+    let system = get_root_system(bmc.clone(), &ids, &root, system).await?;
+
+    bmc.expect(Expect::expand(
+        &ids.system_id,
+        computer_system(
+            &ids,
+            json!({
+                "Processors": {
+                    ODATA_ID: &processors_id,
+                    ODATA_TYPE: PROCESSOR_COLLECTION_DATA_TYPE,
+                    "Name": "Processor Collection",
+                    "Members": [{
+                        ODATA_ID: format!("{processors_id}/CPU_0"),
+                        ODATA_TYPE: PROCESSOR_DATA_TYPE,
+                        "Id": "CPU_0",
+                        "Name": "CPU 0"
+                    }]
+                },
+                "Memory": {
+                    ODATA_ID: &memory_id,
+                    ODATA_TYPE: MEMORY_COLLECTION_DATA_TYPE,
+                    "Name": "Memory Collection",
+                    "Members": [{
+                        ODATA_ID: format!("{memory_id}/DIMM_0"),
+                        ODATA_TYPE: MEMORY_DATA_TYPE,
+                        "Id": "DIMM_0",
+                        "Name": "DIMM 0"
+                    }]
+                }
+            }),
+        ),
+    ));
+    let system = system.with_expanded_inventory().await?;
+
+    // No more requests are expected: collections and members are inline.
+    let processors = system.processors().await?.ok_or("missing processors")?;
+    assert_eq!(processors.len(), 1);
+    assert_eq!(processors[0].id().to_string(), "CPU_0");
+    let memory = system.memory_modules().await?.ok_or("missing memory")?;
+    assert_eq!(memory.len(), 1);
+    assert_eq!(memory[0].id().to_string(), "DIMM_0");
+
+    Ok(())
+}
+
+#[test]
+async fn expanded_inventory_falls_back_without_expand_levels() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let processors_id = format!("{}/Processors", ids.system_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(&ids, json!({ "Processors": { ODATA_ID: &processors_id } })),
+    )
+    .await?
+    .with_expanded_inventory()
+    .await?;
+
+    bmc.expect(Expect::expand(
+        &processors_id,
+        json!({
+            ODATA_ID: &processors_id,
+            ODATA_TYPE: PROCESSOR_COLLECTION_DATA_TYPE,
+            "Name": "Processor Collection",
+            "Members": []
+        }),
+    ));
+    assert_eq!(system.processors().await?.map(|p| p.len()), Some(0));
+
+    Ok(())
+}
+
+#[test]
+async fn expanded_inventory_falls_back_when_query_is_rejected() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let processors_id = format!("{}/Processors", ids.system_id);
+    let system = computer_system(&ids, json!({ "Processors": { ODATA_ID: &processors_id } }));
+    let root = expect_service_root_with_features(
+        bmc.clone(),
+        &ids,
+        json!({ "ExpandQuery": { "Levels": true, "MaxLevels": 2, "NoLinks": true } }),
+    )
+    .await?;
+    let system = get_root_system(bmc.clone(), &ids, &root, system).await?;
+
+    bmc.expect(Expect::expand_status(&ids.system_id, 501));
+    let expanded = system.with_expanded_inventory().await?;
+    bmc.expect(Expect::expand(
+        &processors_id,
+        json!({
+            ODATA_ID: &processors_id,
+            ODATA_TYPE: PROCESSOR_COLLECTION_DATA_TYPE,
+            "Name": "Processor Collection",
+            "Members": []
+        }),
+    ));
+    assert_eq!(expanded.processors().await?.map(|p| p.len()), Some(0));
+
+    // Failures other than rejection of the query are not hidden.
+    bmc.expect(Expect::expand_status(&ids.system_id, 503));
+    assert!(matches!(
+        system.with_expanded_inventory().await,
+        Err(nv_redfish::Error::Bmc(_))
+    ));
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn processor_metrics_reset_actions_are_invoked() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let processors_id = format!("{}/Processors", ids.system_id);
+    let processor_id = format!("{processors_id}/CPU_0");
+    let metrics_id = format!("{processor_id}/ProcessorMetrics");
+    let env_metrics_id = format!("{processor_id}/EnvironmentMetrics");
+    let clear_target = format!("{metrics_id}/Actions/ProcessorMetrics.ClearCurrentPeriod");
+    let reset_target = format!("{env_metrics_id}/Actions/EnvironmentMetrics.ResetMetrics");
     let system = get_system(
         bmc.clone(),
         &ids,
-        computer_system(&ids, json!({ "Boot": { "BootOrder": ["Boot0001"] } })),
+        computer_system(&ids, json!({ "Processors": { ODATA_ID: &processors_id } })),
     )
     .await?;
+    bmc.expect(Expect::expand(
+        &processors_id,
+        json!({
+            ODATA_ID: &processors_id,
+            ODATA_TYPE: PROCESSOR_COLLECTION_DATA_TYPE,
+            "Id": "Processors",
+            "Name": "Processor Collection",
+            "Members": [{
+                ODATA_ID: &processor_id,
+                ODATA_TYPE: PROCESSOR_DATA_TYPE,
+                "Id": "CPU_0",
+                "Name": "CPU 0",
+                "Metrics": { ODATA_ID: &metrics_id },
+                "EnvironmentMetrics": { ODATA_ID: &env_metrics_id }
+            }]
+        }),
+    ));
+    let processor = system
+        .processors()
+        .await?
+        .and_then(|mut processors| processors.pop())
+        .ok_or("missing processor")?;
 
-    let task_id = "/redfish/v1/TaskService/Tasks/52";
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json_merge([
+            &json!({
+                ODATA_ID: &metrics_id,
+                ODATA_TYPE: "#ProcessorMetrics.v1_6_1.ProcessorMetrics",
+                "Id": "ProcessorMetrics",
+                "Name": "Processor Metrics"
+            }),
+            &redfish_action_payload("ProcessorMetrics.ClearCurrentPeriod", &clear_target),
+        ]),
+    ));
+    bmc.expect(Expect::action(&clear_target, json!({}), json!(null)));
+    assert!(matches!(
+        processor.clear_metrics_current_period().await?,
+        ModificationResponse::Entity(())
+    ));
 
-    bmc.expect(Expect::update_task(
-        &ids.system_id,
-        json!({ "Boot": { "BootOrder": ["Boot0002"] } }),
-        async_task(task_id, 7),
+    bmc.expect(Expect::get(
+        &env_metrics_id,
+        json_merge([
+            &json!({
+                ODATA_ID: &env_metrics_id,
+                ODATA_TYPE: "#EnvironmentMetrics.v1_3_0.EnvironmentMetrics",
+                "Id": "EnvironmentMetrics",
+                "Name": "Processor Environment Metrics"
+            }),
+            &redfish_action_payload("EnvironmentMetrics.ResetMetrics", &reset_target),
+        ]),
+    ));
+    bmc.expect(Expect::action(&reset_target, json!({}), json!(null)));
+    assert!(matches!(
+        processor.reset_environment_metrics().await?,
+        ModificationResponse::Entity(())
     ));
 
-    assert_task(
-        system
-            .set_boot_order(vec![BootOptionReference::new("Boot0002".into())])
-            .await?,
-        task_id,
-        7,
+    Ok(())
+}
+
+#[test]
+async fn fabric_adapter_ports_report_link_state_and_speed() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let adapters_id = format!("{}/FabricAdapters", ids.system_id);
+    let adapter_id = format!("{adapters_id}/DPU_0");
+    let ports_id = format!("{adapter_id}/Ports");
+    let port_id = format!("{ports_id}/p0");
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({ "FabricAdapters": { ODATA_ID: &adapters_id } }),
+        ),
+    )
+    .await?;
+    bmc.expect(Expect::expand(
+        &adapters_id,
+        json!({
+            ODATA_ID: &adapters_id,
+            ODATA_TYPE: FABRIC_ADAPTER_COLLECTION_DATA_TYPE,
+            "Name": "Fabric Adapter Collection",
+            "Members": [{
+                ODATA_ID: &adapter_id,
+                ODATA_TYPE: FABRIC_ADAPTER_DATA_TYPE,
+                "Id": "DPU_0",
+                "Name": "DPU 0",
+                "Manufacturer": "NVIDIA",
+                "Model": "BlueField-3",
+                "Ports": { ODATA_ID: &ports_id }
+            }]
+        }),
+    ));
+    let adapter = system
+        .fabric_adapters()
+        .await?
+        .and_then(|mut adapters| adapters.pop())
+        .ok_or("missing fabric adapter")?;
+    assert_eq!(adapter.hardware_id().model, Some(Model::new("BlueField-3")));
+
+    bmc.expect(Expect::expand(
+        &ports_id,
+        json!({
+            ODATA_ID: &ports_id,
+            ODATA_TYPE: PORT_COLLECTION_DATA_TYPE,
+            "Name": "Port Collection",
+            "Members": [{
+                ODATA_ID: &port_id,
+                ODATA_TYPE: PORT_DATA_TYPE,
+                "Id": "p0",
+                "Name": "Port 0",
+                "PortId": "p0",
+                "PortProtocol": "Ethernet",
+                "LinkState": "Enabled",
+                "LinkStatus": "LinkUp",
+                "CurrentSpeedGbps": 200,
+                "MaxSpeedGbps": 400
+            }]
+        }),
+    ));
+    let ports = adapter.ports().await?.ok_or("missing ports")?;
+    assert_eq!(ports.len(), 1);
+    let port = &ports[0];
+    assert_eq!(port.port_id(), Some("p0"));
+    assert_eq!(port.protocol(), Some(Protocol::Ethernet));
+    assert_eq!(port.link_state(), Some(LinkState::Enabled));
+    assert_eq!(port.link_status(), Some(LinkStatus::LinkUp));
+    assert_eq!(port.current_speed_gbps(), Some(200.0));
+    assert_eq!(port.max_speed_gbps(), Some(400.0));
+
+    Ok(())
+}
+
+#[test]
+async fn graphics_and_usb_controllers_link_pcie_devices() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let graphics_id = format!("{}/GraphicsControllers", ids.system_id);
+    let gpu_id = format!("{graphics_id}/GPU_0");
+    let usb_id = format!("{}/USBControllers", ids.system_id);
+    let xhci_id = format!("{usb_id}/XHCI_0");
+    let gpu_pcie_id = format!("{}/Chassis/1/PCIeDevices/GPU_0", ids.root_id);
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({
+                "GraphicsControllers": { ODATA_ID: &graphics_id },
+                "USBControllers": { ODATA_ID: &usb_id }
+            }),
+        ),
+    )
+    .await?;
+
+    bmc.expect(Expect::expand(
+        &graphics_id,
+        json!({
+            ODATA_ID: &graphics_id,
+            ODATA_TYPE: GRAPHICS_CONTROLLER_COLLECTION_DATA_TYPE,
+            "Name": "Graphics Controller Collection",
+            "Members": [{
+                ODATA_ID: &gpu_id,
+                ODATA_TYPE: GRAPHICS_CONTROLLER_DATA_TYPE,
+                "Id": "GPU_0",
+                "Name": "GPU 0",
+                "Model": "RTX A4000",
+                "Links": { "PCIeDevice": { ODATA_ID: &gpu_pcie_id } }
+            }]
+        }),
+    ));
+    let gpu = system
+        .graphics_controllers()
+        .await?
+        .and_then(|mut controllers| controllers.pop())
+        .ok_or("missing graphics controller")?;
+    assert_eq!(
+        gpu.hardware_id().model,
+        Some(GraphicsControllerModel::new("RTX A4000"))
+    );
+    assert_eq!(
+        gpu.pcie_device_id(),
+        Some(&ODataId::from(gpu_pcie_id.clone()))
     );
 
-    bmc.expect(Expect::update_empty(
-        &ids.system_id,
-        json!({ "Boot": { "BootOrder": ["Boot0003"] } }),
+    bmc.expect(Expect::get(
+        &gpu_pcie_id,
+        json!({
+            ODATA_ID: &gpu_pcie_id,
+            ODATA_TYPE: PCIE_DEVICE_DATA_TYPE,
+            "Id": "GPU_0",
+            "Name": "GPU 0"
+        }),
     ));
+    let pcie_device = gpu.pcie_device().await?.ok_or("missing PCIe device")?;
+    assert_eq!(pcie_device.odata_id(), &ODataId::from(gpu_pcie_id));
 
-    assert_empty(
-        system
-            .set_boot_order(vec![BootOptionReference::new("Boot0003".into())])
-            .await?,
+    bmc.expect(Expect::expand(
+        &usb_id,
+        json!({
+            ODATA_ID: &usb_id,
+            ODATA_TYPE: USB_CONTROLLER_COLLECTION_DATA_TYPE,
+            "Name": "USB Controller Collection",
+            "Members": [{
+                ODATA_ID: &xhci_id,
+                ODATA_TYPE: USB_CONTROLLER_DATA_TYPE,
+                "Id": "XHCI_0",
+                "Name": "XHCI 0",
+                "Manufacturer": "Intel"
+            }]
+        }),
+    ));
+    let xhci = system
+        .usb_controllers()
+        .await?
+        .and_then(|mut controllers| controllers.pop())
+        .ok_or("missing USB controller")?;
+    assert_eq!(
+        xhci.hardware_id().manufacturer,
+        Some(UsbControllerManufacturer::new("Intel"))
     );
+    assert_eq!(xhci.pcie_device_id(), None);
+    assert!(xhci.pcie_device().await?.is_none());
 
     Ok(())
 }
 
 #[test]
-async fn reset_returns_action_not_available_when_computer_system_reset_is_absent(
-) -> Result<(), Box<dyn StdError>> {
+async fn drive_health_reports_nvme_smart_metrics() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
     let ids = computer_system_ids();
+    let storages_id = format!("{}/Storage", ids.system_id);
+    let storage_id = format!("{storages_id}/NVMe_0");
+    let drive_id = format!("{storage_id}/Drives/Disk_0");
+    let metrics_id = format!("{drive_id}/Metrics");
     let system = get_system(
         bmc.clone(),
         &ids,
-        computer_system(&ids, redfish_empty_actions_payload()),
+        computer_system(&ids, json!({ "Storage": { ODATA_ID: &storages_id } })),
     )
     .await?;
+    bmc.expect(Expect::expand(
+        &storages_id,
+        json!({
+            ODATA_ID: &storages_id,
+            ODATA_TYPE: STORAGE_COLLECTION_DATA_TYPE,
+            "Name": "Storage Collection",
+            "Members": [{
+                ODATA_ID: &storage_id,
+                ODATA_TYPE: STORAGE_DATA_TYPE,
+                "Id": "NVMe_0",
+                "Name": "NVMe Storage",
+                "Drives": [{ ODATA_ID: &drive_id }]
+            }]
+        }),
+    ));
+    let storage = system
+        .storage_controllers()
+        .await?
+        .and_then(|mut storages| storages.pop())
+        .ok_or("missing storage")?;
 
-    assert!(matches!(
-        system.reset(Some(ResetType::GracefulRestart)).await,
-        Err(nv_redfish::Error::ActionNotAvailable)
+    bmc.expect(Expect::get(
+        &drive_id,
+        json!({
+            ODATA_ID: &drive_id,
+            ODATA_TYPE: DRIVE_DATA_TYPE,
+            "Id": "Disk_0",
+            "Name": "Disk 0",
+            "Protocol": "NVMe",
+            "Metrics": { ODATA_ID: &metrics_id }
+        }),
+    ));
+    let drive = storage
+        .drives()
+        .await?
+        .and_then(|mut drives| drives.pop())
+        .ok_or("missing drive")?;
+
+    bmc.expect(Expect::get(
+        &metrics_id,
+        json!({
+            ODATA_ID: &metrics_id,
+            ODATA_TYPE: DRIVE_METRICS_DATA_TYPE,
+            "Id": "Metrics",
+            "Name": "Drive Metrics",
+            "BadBlockCount": 3,
+            "PowerOnHours": 1200.5,
+            "NVMeSMART": {
+                "MediaAndDataIntegrityErrors": 2,
+                "PercentageUsed": 7,
+                "AvailableSparePercent": 5,
+                "AvailableSpareThresholdPercent": 10,
+                "CriticalWarnings": {
+                    "SpareCapacityWornOut": true,
+                    "MediaInReadOnly": false
+                }
+            }
+        }),
+    ));
+    let health = drive.health().await?.ok_or("missing drive health")?;
+    assert_eq!(health.media_errors, Some(2.0));
+    assert_eq!(health.percentage_used, Some(7.0));
+    assert_eq!(health.available_spare_percent, Some(5.0));
+    assert_eq!(health.available_spare_threshold_percent, Some(10.0));
+    assert_eq!(health.critical_warning, Some(true));
+    assert_eq!(health.power_on_hours, Some(1200.5));
+    assert_eq!(health.bad_block_count, Some(3));
+    assert_eq!(health.uncorrectable_read_errors, None);
+    assert_eq!(health.spare_below_threshold(), Some(true));
+
+    Ok(())
+}
+
+#[test]
+async fn log_entries_between_pages_and_filters_on_client() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let log_services_id = format!("{}/LogServices", ids.system_id);
+    let sel_id = format!("{log_services_id}/SEL");
+    let entries_id = format!("{sel_id}/Entries");
+    let next_page_id = format!("{entries_id}?$skip=2");
+    let system = get_system(
+        bmc.clone(),
+        &ids,
+        computer_system(
+            &ids,
+            json!({ "LogServices": { ODATA_ID: &log_services_id } }),
+        ),
+    )
+    .await?;
+    bmc.expect(Expect::get(
+        &log_services_id,
+        json!({
+            ODATA_ID: &log_services_id,
+            ODATA_TYPE: LOG_SERVICE_COLLECTION_DATA_TYPE,
+            "Name": "Log Service Collection",
+            "Members": [{ ODATA_ID: &sel_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &sel_id,
+        json!({
+            ODATA_ID: &sel_id,
+            ODATA_TYPE: LOG_SERVICE_DATA_TYPE,
+            "Id": "SEL",
+            "Name": "System Event Log",
+            "Entries": { ODATA_ID: &entries_id }
+        }),
+    ));
+    let log_service = system
+        .log_services()
+        .await?
+        .and_then(|mut services| services.pop())
+        .ok_or("missing log service")?;
+
+    let entry = |id: &str, created: &str| {
+        json!({
+            ODATA_ID: format!("{entries_id}/{id}"),
+            ODATA_TYPE: LOG_ENTRY_DATA_TYPE,
+            "Id": id,
+            "Name": "Log Entry",
+            "EntryType": "SEL",
+            "Created": created
+        })
+    };
+    bmc.expect(Expect::expand(
+        &entries_id,
+        json!({
+            ODATA_ID: &entries_id,
+            ODATA_TYPE: LOG_ENTRY_COLLECTION_DATA_TYPE,
+            "Name": "Log Entry Collection",
+            "Members": [
+                entry("1", "2026-03-01T23:59:59+00:00"),
+                entry("2", "2026-03-02T08:00:00+00:00")
+            ],
+            "Members@odata.count": 3,
+            "Members@odata.nextLink": &next_page_id
+        }),
+    ));
+    bmc.expect(Expect::expand(
+        &next_page_id,
+        json!({
+            ODATA_ID: &entries_id,
+            ODATA_TYPE: LOG_ENTRY_COLLECTION_DATA_TYPE,
+            "Name": "Log Entry Collection",
+            "Members": [entry("3", "2026-03-03T00:00:00+00:00")],
+            "Members@odata.count": 3
+        }),
+    ));
+    let start: EdmDateTimeOffset = "2026-03-02T00:00:00+00:00".parse()?;
+    let end: EdmDateTimeOffset = "2026-03-03T00:00:00+00:00".parse()?;
+    let entries = log_service
+        .entries_between(start, end)
+        .await?
+        .ok_or("missing entries")?;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].base.id, "2");
+
+    Ok(())
+}
+
+#[test]
+async fn log_entries_between_filters_on_service_and_follows_next_link(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let log_services_id = format!("{}/LogServices", ids.system_id);
+    let sel_id = format!("{log_services_id}/SEL");
+    let entries_id = format!("{sel_id}/Entries");
+    let next_page_id = format!("{entries_id}?$skip=1");
+    let root = expect_service_root_with_features(
+        bmc.clone(),
+        &ids,
+        json!({ "ExpandQuery": { "NoLinks": true }, "FilterQuery": true }),
+    )
+    .await?;
+    let system = get_root_system(
+        bmc.clone(),
+        &ids,
+        &root,
+        computer_system(
+            &ids,
+            json!({ "LogServices": { ODATA_ID: &log_services_id } }),
+        ),
+    )
+    .await?;
+    bmc.expect(Expect::get(
+        &log_services_id,
+        json!({
+            ODATA_ID: &log_services_id,
+            ODATA_TYPE: LOG_SERVICE_COLLECTION_DATA_TYPE,
+            "Name": "Log Service Collection",
+            "Members": [{ ODATA_ID: &sel_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &sel_id,
+        json!({
+            ODATA_ID: &sel_id,
+            ODATA_TYPE: LOG_SERVICE_DATA_TYPE,
+            "Id": "SEL",
+            "Name": "System Event Log",
+            "Entries": { ODATA_ID: &entries_id }
+        }),
+    ));
+    let log_service = system
+        .log_services()
+        .await?
+        .and_then(|mut services| services.pop())
+        .ok_or("missing log service")?;
+
+    let entry = |id: &str, created: &str| {
+        json!({
+            ODATA_ID: format!("{entries_id}/{id}"),
+            ODATA_TYPE: LOG_ENTRY_DATA_TYPE,
+            "Id": id,
+            "Name": "Log Entry",
+            "EntryType": "SEL",
+            "Created": created
+        })
+    };
+    bmc.expect(Expect::filter(
+        &entries_id,
+        json!({
+            ODATA_ID: &entries_id,
+            ODATA_TYPE: LOG_ENTRY_COLLECTION_DATA_TYPE,
+            "Name": "Log Entry Collection",
+            "Members": [entry("1", "2026-03-02T08:00:00+00:00")],
+            "Members@odata.count": 2,
+            "Members@odata.nextLink": &next_page_id
+        }),
+    ));
+    bmc.expect(Expect::expand(
+        &next_page_id,
+        json!({
+            ODATA_ID: &entries_id,
+            ODATA_TYPE: LOG_ENTRY_COLLECTION_DATA_TYPE,
+            "Name": "Log Entry Collection",
+            "Members": [entry("2", "2026-03-02T09:00:00+00:00")],
+            "Members@odata.count": 2
+        }),
+    ));
+    let start: EdmDateTimeOffset = "2026-03-02T00:00:00+00:00".parse()?;
+    let end: EdmDateTimeOffset = "2026-03-03T00:00:00+00:00".parse()?;
+    let entries = log_service
+        .entries_between(start, end)
+        .await?
+        .ok_or("missing entries")?;
+    assert_eq!(
+        entries
+            .iter()
+            .map(|entry| entry.base.id.as_str())
+            .collect::<Vec<_>>(),
+        vec!["1", "2"]
+    );
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn log_entries_stream_pages_with_top_and_skip() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = computer_system_ids();
+    let log_services_id = format!("{}/LogServices", ids.system_id);
+    let sel_id = format!("{log_services_id}/SEL");
+    let entries_id = format!("{sel_id}/Entries");
+    let root = expect_service_root_with_features(
+        bmc.clone(),
+        &ids,
+        json!({ "ExpandQuery": { "NoLinks": true }, "TopSkipQuery": true }),
+    )
+    .await?;
+    let system = get_root_system(
+        bmc.clone(),
+        &ids,
+        &root,
+        computer_system(
+            &ids,
+            json!({ "LogServices": { ODATA_ID: &log_services_id } }),
+        ),
+    )
+    .await?;
+    bmc.expect(Expect::get(
+        &log_services_id,
+        json!({
+            ODATA_ID: &log_services_id,
+            ODATA_TYPE: LOG_SERVICE_COLLECTION_DATA_TYPE,
+            "Name": "Log Service Collection",
+            "Members": [{ ODATA_ID: &sel_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &sel_id,
+        json!({
+            ODATA_ID: &sel_id,
+            ODATA_TYPE: LOG_SERVICE_DATA_TYPE,
+            "Id": "SEL",
+            "Name": "System Event Log",
+            "Entries": { ODATA_ID: &entries_id }
+        }),
+    ));
+    let log_service = system
+        .log_services()
+        .await?
+        .and_then(|mut services| services.pop())
+        .ok_or("missing log service")?;
+
+    let entry = |id: &str| {
+        json!({
+            ODATA_ID: format!("{entries_id}/{id}"),
+            ODATA_TYPE: LOG_ENTRY_DATA_TYPE,
+            "Id": id,
+            "Name": "Log Entry",
+            "EntryType": "SEL"
+        })
+    };
+    bmc.expect(Expect::expand(
+        format!("{entries_id}?$top=2"),
+        json!({
+            ODATA_ID: &entries_id,
+            ODATA_TYPE: LOG_ENTRY_COLLECTION_DATA_TYPE,
+            "Name": "Log Entry Collection",
+            "Members": [entry("1"), entry("2")],
+            "Members@odata.count": 3
+        }),
     ));
+    bmc.expect(Expect::expand(
+        format!("{entries_id}?$skip=2&$top=2"),
+        json!({
+            ODATA_ID: &entries_id,
+            ODATA_TYPE: LOG_ENTRY_COLLECTION_DATA_TYPE,
+            "Name": "Log Entry Collection",
+            "Members": [entry("3")],
+            "Members@odata.count": 3
+        }),
+    ));
+    let entries: Vec<_> = log_service
+        .entries_stream(Some(2))
+        .ok_or("missing entries")?
+        .try_collect()
+        .await?;
+    let ids: Vec<_> = entries.iter().map(|entry| entry.base.id.as_str()).collect();
+    assert_eq!(ids, ["1", "2", "3"]);
 
     Ok(())
 }
@@ -359,6 +1630,56 @@ async fn expect_service_root(
     ServiceRoot::new(bmc).await.map_err(Into::into)
 }
 
+async fn expect_service_root_with_features(
+    bmc: Arc<Bmc>,
+    ids: &ComputerSystemIds,
+    protocol_features: Value,
+) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        json!({
+            ODATA_ID: &ids.root_id,
+            ODATA_TYPE: &SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "ProtocolFeaturesSupported": protocol_features,
+            "Systems": { ODATA_ID: &ids.systems_id },
+            "Vendor": "NVIDIA",
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: format!("{}/SessionService/Sessions", ids.root_id),
+                }
+            },
+        }),
+    ));
+
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+async fn get_root_system(
+    bmc: Arc<Bmc>,
+    ids: &ComputerSystemIds,
+    root: &ServiceRoot<Bmc>,
+    member: Value,
+) -> Result<ComputerSystem<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::expand(
+        &ids.systems_id,
+        json!({
+            ODATA_ID: &ids.systems_id,
+            ODATA_TYPE: &SYSTEM_COLLECTION_DATA_TYPE,
+            "Name": "Computer System Collection",
+            "Members": [member]
+        }),
+    ));
+    root.systems()
+        .await?
+        .ok_or("missing systems")?
+        .members()
+        .await?
+        .pop()
+        .ok_or_else(|| "missing computer system".into())
+}
+
 async fn expect_viking_service_root_without_systems(
     bmc: Arc<Bmc>,
     ids: &ComputerSystemIds,
@@ -0,0 +1,608 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests of configuration backup and restore.
+
+use std::collections::BTreeMap;
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::computer_system::ComputerSystem;
+use nv_redfish::config_backup::BootOptionBackup;
+use nv_redfish::config_backup::ConfigBackup;
+use nv_redfish::config_backup::ConfigChange;
+use nv_redfish::config_backup::NetworkProtocolBackup;
+use nv_redfish::manager::Manager;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::EdmPrimitiveType;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::anonymous_1_9_service_root;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection";
+const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
+const MANAGER_NETWORK_PROTOCOL_DATA_TYPE: &str =
+    "#ManagerNetworkProtocol.v1_5_0.ManagerNetworkProtocol";
+const SYSTEM_COLLECTION_DATA_TYPE: &str = "#ComputerSystemCollection.ComputerSystemCollection";
+const SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_20_0.ComputerSystem";
+const BIOS_DATA_TYPE: &str = "#Bios.v1_2_1.Bios";
+const ACCOUNT_SERVICE_DATA_TYPE: &str = "#AccountService.v1_5_0.AccountService";
+const ACCOUNTS_DATA_TYPE: &str = "#ManagerAccountCollection.ManagerAccountCollection";
+const MANAGER_ACCOUNT_DATA_TYPE: &str = "#ManagerAccount.v1_3_0.ManagerAccount";
+const BOOT_OPTION_COLLECTION_DATA_TYPE: &str = "#BootOptionCollection.BootOptionCollection";
+const BOOT_OPTION_DATA_TYPE: &str = "#BootOption.v1_0_4.BootOption";
+const REGISTRY_FILE_COLLECTION_DATA_TYPE: &str =
+    "#MessageRegistryFileCollection.MessageRegistryFileCollection";
+const REGISTRY_FILE_DATA_TYPE: &str = "#MessageRegistryFile.v1_1_3.MessageRegistryFile";
+const NIC_DEVICE_PATH: &str = "PciRoot(0x0)/Pci(0x1,0x0)/Pci(0x0,0x0)/MAC(B83FD2000001,0x1)";
+const DISK_DEVICE_PATH: &str = "PciRoot(0x0)/Pci(0x2,0x0)/Sata(0x0,0xFFFF,0x0)";
+
+#[test]
+async fn capture_collects_writable_configuration() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let (root, manager, system) = get_resources(bmc.clone(), &ids).await?;
+    expect_configuration(
+        &bmc,
+        &ids,
+        &[("root", "Administrator", true), ("", "", false)],
+    );
+
+    let backup = ConfigBackup::capture(&root, &manager, &system).await?;
+
+    assert_eq!(backup.model.as_deref(), Some("PowerEdge R760"));
+    assert_eq!(backup.accounts.len(), 1);
+    assert_eq!(backup.accounts[0].user_name, "root");
+    assert_eq!(backup.accounts[0].role_id.as_deref(), Some("Administrator"));
+    let network_protocol = backup
+        .network_protocol
+        .as_ref()
+        .ok_or_else(|| std::io::Error::other("missing network protocol"))?;
+    assert_eq!(network_protocol.host_name.as_deref(), Some("bmc-r12-u07"));
+    assert_eq!(
+        network_protocol.ntp_servers,
+        Some(vec!["pool.ntp.org".to_string()])
+    );
+    assert_eq!(network_protocol.protocols.get("IPMI"), Some(&false));
+    assert_eq!(network_protocol.protocols.get("NTP"), Some(&true));
+    assert_eq!(
+        backup.boot_order,
+        Some(vec!["Boot0001".to_string(), "Boot0002".to_string()])
+    );
+    assert_eq!(
+        backup.bios_attributes.get("SriovGlobalEnable"),
+        Some(&EdmPrimitiveType::String("Enabled".into()))
+    );
+    assert!(backup.event_subscriptions.is_empty());
+
+    let document = serde_json::to_value(&backup)?;
+    assert_eq!(serde_json::from_value::<ConfigBackup>(document)?, backup);
+
+    Ok(())
+}
+
+#[test]
+async fn restore_applies_only_differences() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let (root, manager, system) = get_resources(bmc.clone(), &ids).await?;
+    expect_configuration(
+        &bmc,
+        &ids,
+        &[
+            ("root", "Administrator", true),
+            ("operator", "Operator", true),
+        ],
+    );
+    let mut backup = ConfigBackup::capture(&root, &manager, &system).await?;
+    backup.accounts[1].role_id = Some("ReadOnly".into());
+    if let Some(network_protocol) = backup.network_protocol.as_mut() {
+        network_protocol.host_name = Some("bmc-r12-u08".into());
+    }
+    backup.bios_attributes.insert(
+        "SriovGlobalEnable".into(),
+        EdmPrimitiveType::String("Disabled".into()),
+    );
+
+    // Restore to the replacement BMC.
+    expect_configuration(
+        &bmc,
+        &ids,
+        &[
+            ("root", "Administrator", true),
+            ("operator", "Operator", true),
+        ],
+    );
+    expect_accounts(
+        &bmc,
+        &ids,
+        &[
+            ("root", "Administrator", true),
+            ("operator", "Operator", true),
+        ],
+    );
+    bmc.expect(Expect::update(
+        format!("{}/2", ids.accounts_id),
+        json!({ "RoleId": "ReadOnly", "Enabled": true }),
+        account_payload(&ids, 2, ("operator", "ReadOnly", true)),
+    ));
+    bmc.expect(Expect::get(
+        &ids.network_protocol_id,
+        network_protocol_payload(&ids, "bmc-r12-u07"),
+    ));
+    bmc.expect(Expect::update(
+        &ids.network_protocol_id,
+        json!({ "HostName": "bmc-r12-u08" }),
+        network_protocol_payload(&ids, "bmc-r12-u08"),
+    ));
+    bmc.expect(Expect::get(&ids.bios_id, bios_payload(&ids, "Enabled")));
+    bmc.expect(Expect::update(
+        &ids.bios_id,
+        json!({ "Attributes": { "SriovGlobalEnable": "Disabled" } }),
+        bios_payload(&ids, "Disabled"),
+    ));
+
+    let report = backup.restore(&root, &manager, &system).await?;
+
+    assert_eq!(report.changes.len(), 3);
+    assert!(matches!(
+        &report.changes[0],
+        ConfigChange::UpdateAccount(account) if account.user_name == "operator"
+    ));
+    assert_eq!(
+        report.changes[1],
+        ConfigChange::NetworkProtocol(NetworkProtocolBackup {
+            host_name: Some("bmc-r12-u08".into()),
+            ..NetworkProtocolBackup::default()
+        })
+    );
+    assert!(matches!(
+        &report.changes[2],
+        ConfigChange::BiosAttributes(attributes) if attributes.len() == 1
+    ));
+    assert!(report.account_passwords.is_empty());
+
+    Ok(())
+}
+
+#[test]
+async fn restore_skips_read_only_and_pending_values_and_remaps_boot_order(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let registries_id = format!("{}/Registries", ids.root_id);
+    let boot_options_id = format!("{}/BootOptions", ids.system_id);
+    let settings_id = format!("{}/Settings", ids.bios_id);
+    let (root, manager, system) = get_resources_with(
+        bmc.clone(),
+        &ids,
+        json!({ "Registries": { ODATA_ID: &registries_id } }),
+        json!({
+            "Boot": {
+                "BootOrder": ["Boot0001", "Boot0002"],
+                "BootOptions": { ODATA_ID: &boot_options_id }
+            }
+        }),
+    )
+    .await?;
+    // Backup of the replaced BMC: boot options have other references.
+    let backup = ConfigBackup {
+        manufacturer: Some("Dell Inc.".into()),
+        model: Some("PowerEdge R760".into()),
+        boot_order: Some(vec!["Boot0003".into(), "Boot0004".into()]),
+        boot_options: vec![
+            BootOptionBackup {
+                reference: "Boot0003".into(),
+                display_name: Some("PXE NIC 1".into()),
+                uefi_device_path: Some(NIC_DEVICE_PATH.into()),
+            },
+            BootOptionBackup {
+                reference: "Boot0004".into(),
+                display_name: Some("Hard Disk".into()),
+                uefi_device_path: None,
+            },
+        ],
+        bios_attributes: BTreeMap::from([
+            bios_attribute("SriovGlobalEnable", "Disabled"),
+            bios_attribute("BootMode", "Legacy"),
+            bios_attribute("ProcTurboMode", "Disabled"),
+        ]),
+        ..ConfigBackup::default()
+    };
+
+    expect_accounts(&bmc, &ids, &[("root", "Administrator", true)]);
+    bmc.expect(Expect::get(
+        &ids.network_protocol_id,
+        network_protocol_payload(&ids, "bmc-r12-u07"),
+    ));
+    let bios = || {
+        json!({
+            ODATA_ID: &ids.bios_id,
+            ODATA_TYPE: BIOS_DATA_TYPE,
+            "Id": "Bios",
+            "Name": "BIOS Settings",
+            "AttributeRegistry": "BiosAttributeRegistry.1.0.0",
+            "@Redfish.Settings": { "SettingsObject": { ODATA_ID: &settings_id } },
+            "Attributes": {
+                "SriovGlobalEnable": "Enabled",
+                "BootMode": "Uefi",
+                "ProcTurboMode": "Enabled"
+            }
+        })
+    };
+    bmc.expect(Expect::get(&ids.bios_id, bios()));
+    bmc.expect(Expect::get(
+        &boot_options_id,
+        json!({
+            ODATA_ID: &boot_options_id,
+            ODATA_TYPE: BOOT_OPTION_COLLECTION_DATA_TYPE,
+            "Name": "Boot Options",
+            "Members": [
+                boot_option_payload(&boot_options_id, "Boot0001", "Hard Disk", DISK_DEVICE_PATH),
+                boot_option_payload(&boot_options_id, "Boot0002", "PXE NIC 1", NIC_DEVICE_PATH)
+            ]
+        }),
+    ));
+    let registry_file_id = format!("{registries_id}/BiosAttributeRegistry.1.0.0");
+    let registry_uri = format!("{registry_file_id}/BiosAttributeRegistry.1.0.0.json");
+    bmc.expect(Expect::get(
+        &registries_id,
+        json!({
+            ODATA_ID: &registries_id,
+            ODATA_TYPE: REGISTRY_FILE_COLLECTION_DATA_TYPE,
+            "Name": "Registry File Collection",
+            "Members": [{ ODATA_ID: &registry_file_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &registry_file_id,
+        json!({
+            ODATA_ID: &registry_file_id,
+            ODATA_TYPE: REGISTRY_FILE_DATA_TYPE,
+            "Id": "BiosAttributeRegistry.1.0.0",
+            "Name": "BIOS Attribute Registry File",
+            "Languages": ["en"],
+            "Registry": "BiosAttributeRegistry.1.0",
+            "Location": [{ "Language": "en", "Uri": &registry_uri }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &registry_uri,
+        json!({
+            ODATA_TYPE: "#AttributeRegistry.v1_3_0.AttributeRegistry",
+            "Id": "BiosAttributeRegistry.1.0.0",
+            "Name": "BIOS Attribute Registry",
+            "Language": "en",
+            "RegistryVersion": "1.0.0",
+            "OwningEntity": "Dell",
+            "RegistryEntries": {
+                "Attributes": [
+                    { "AttributeName": "SriovGlobalEnable", "Type": "Enumeration" },
+                    { "AttributeName": "BootMode", "Type": "Enumeration", "ReadOnly": true },
+                    { "AttributeName": "ProcTurboMode", "Type": "Enumeration" }
+                ]
+            }
+        }),
+    ));
+    // Value written by an earlier restore is pending in the settings
+    // object.
+    bmc.expect(Expect::get(
+        &settings_id,
+        json!({
+            ODATA_ID: &settings_id,
+            ODATA_TYPE: BIOS_DATA_TYPE,
+            "Id": "Settings",
+            "Name": "BIOS Pending Settings",
+            "Attributes": { "SriovGlobalEnable": "Disabled" }
+        }),
+    ));
+    // Server configuration lock is checked in BIOS before the boot
+    // order is changed.
+    bmc.expect(Expect::get(&ids.bios_id, bios()));
+    bmc.expect(Expect::update_empty(
+        &ids.system_id,
+        json!({ "Boot": { "BootOrder": ["Boot0002", "Boot0001"] } }),
+    ));
+    bmc.expect(Expect::get(&ids.bios_id, bios()));
+    bmc.expect(Expect::update_empty(
+        &settings_id,
+        json!({ "Attributes": { "ProcTurboMode": "Disabled" } }),
+    ));
+
+    let report = backup.restore(&root, &manager, &system).await?;
+    bmc.assert_consumed();
+
+    assert_eq!(
+        report.changes,
+        vec![
+            ConfigChange::BootOrder(vec!["Boot0002".into(), "Boot0001".into()]),
+            ConfigChange::BiosAttributes(BTreeMap::from([bios_attribute(
+                "ProcTurboMode",
+                "Disabled"
+            )])),
+        ]
+    );
+
+    // Boot order is skipped when a boot option is missing on the
+    // target.
+    let backup = ConfigBackup {
+        boot_options: vec![BootOptionBackup {
+            reference: "Boot0003".into(),
+            display_name: None,
+            uefi_device_path: Some("PciRoot(0x1)".into()),
+        }],
+        ..backup
+    };
+    let current = ConfigBackup {
+        boot_order: Some(vec!["Boot0001".into(), "Boot0002".into()]),
+        ..ConfigBackup::default()
+    };
+    assert!(!backup
+        .diff(&current)
+        .iter()
+        .any(|change| matches!(change, ConfigChange::BootOrder(_))));
+
+    Ok(())
+}
+
+#[test]
+async fn restore_rejects_backup_of_different_model() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let (root, manager, system) = get_resources(bmc.clone(), &ids).await?;
+    let backup = ConfigBackup {
+        model: Some("PowerEdge R660".into()),
+        ..ConfigBackup::default()
+    };
+    expect_configuration(&bmc, &ids, &[("root", "Administrator", true)]);
+
+    let result = backup.restore(&root, &manager, &system).await;
+
+    assert!(matches!(
+        result,
+        Err(nv_redfish::Error::ConfigBackupModelMismatch { backup, target })
+            if backup.as_deref() == Some("PowerEdge R660")
+                && target.as_deref() == Some("PowerEdge R760")
+    ));
+
+    Ok(())
+}
+
+struct Ids {
+    root_id: ODataId,
+    managers_id: String,
+    manager_id: String,
+    network_protocol_id: String,
+    systems_id: String,
+    system_id: String,
+    bios_id: String,
+    account_service_id: String,
+    accounts_id: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let managers_id = format!("{root_id}/Managers");
+    let manager_id = format!("{managers_id}/1");
+    let network_protocol_id = format!("{manager_id}/NetworkProtocol");
+    let systems_id = format!("{root_id}/Systems");
+    let system_id = format!("{systems_id}/1");
+    let bios_id = format!("{system_id}/Bios");
+    let account_service_id = format!("{root_id}/AccountService");
+    let accounts_id = format!("{account_service_id}/Accounts");
+    Ids {
+        root_id,
+        managers_id,
+        manager_id,
+        network_protocol_id,
+        systems_id,
+        system_id,
+        bios_id,
+        account_service_id,
+        accounts_id,
+    }
+}
+
+async fn get_resources(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+) -> Result<(ServiceRoot<Bmc>, Manager<Bmc>, ComputerSystem<Bmc>), Box<dyn StdError>> {
+    get_resources_with(bmc, ids, json!({}), json!({})).await
+}
+
+// Resources with additional fields of the service root and the
+// computer system.
+async fn get_resources_with(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    root_fields: Value,
+    system_fields: Value,
+) -> Result<(ServiceRoot<Bmc>, Manager<Bmc>, ComputerSystem<Bmc>), Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        anonymous_1_9_service_root(
+            &ids.root_id,
+            merged(
+                json!({
+                    "Managers": { ODATA_ID: &ids.managers_id },
+                    "Systems": { ODATA_ID: &ids.systems_id },
+                    "AccountService": { ODATA_ID: &ids.account_service_id }
+                }),
+                root_fields,
+            ),
+        ),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+    bmc.expect(Expect::get(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [{
+                ODATA_ID: &ids.manager_id,
+                ODATA_TYPE: MANAGER_DATA_TYPE,
+                "Id": "1",
+                "Name": "Manager",
+                "Status": { "State": "Enabled" },
+                "NetworkProtocol": { ODATA_ID: &ids.network_protocol_id }
+            }]
+        }),
+    ));
+    let manager = root
+        .managers()
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing managers"))?
+        .members()
+        .await?
+        .pop()
+        .ok_or_else(|| std::io::Error::other("missing manager"))?;
+    bmc.expect(Expect::get(
+        &ids.systems_id,
+        json!({
+            ODATA_ID: &ids.systems_id,
+            ODATA_TYPE: SYSTEM_COLLECTION_DATA_TYPE,
+            "Id": "Systems",
+            "Name": "Computer System Collection",
+            "Members": [merged(
+                json!({
+                    ODATA_ID: &ids.system_id,
+                    ODATA_TYPE: SYSTEM_DATA_TYPE,
+                    "Id": "1",
+                    "Name": "System",
+                    "Manufacturer": "Dell Inc.",
+                    "Model": "PowerEdge R760",
+                    "Boot": { "BootOrder": ["Boot0001", "Boot0002"] },
+                    "Bios": { ODATA_ID: &ids.bios_id }
+                }),
+                system_fields,
+            )]
+        }),
+    ));
+    let system = root
+        .systems()
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing systems"))?
+        .members()
+        .await?
+        .pop()
+        .ok_or_else(|| std::io::Error::other("missing system"))?;
+    Ok((root, manager, system))
+}
+
+fn merged(mut base: Value, fields: Value) -> Value {
+    if let (Some(base), Value::Object(fields)) = (base.as_object_mut(), fields) {
+        base.extend(fields);
+    }
+    base
+}
+
+// Expectations of `ConfigBackup::capture`.
+fn expect_configuration(bmc: &Bmc, ids: &Ids, accounts: &[(&str, &str, bool)]) {
+    expect_accounts(bmc, ids, accounts);
+    bmc.expect(Expect::get(
+        &ids.network_protocol_id,
+        network_protocol_payload(ids, "bmc-r12-u07"),
+    ));
+    bmc.expect(Expect::get(&ids.bios_id, bios_payload(ids, "Enabled")));
+}
+
+fn expect_accounts(bmc: &Bmc, ids: &Ids, accounts: &[(&str, &str, bool)]) {
+    bmc.expect(Expect::get(
+        &ids.account_service_id,
+        json!({
+            ODATA_ID: &ids.account_service_id,
+            ODATA_TYPE: ACCOUNT_SERVICE_DATA_TYPE,
+            "Id": "AccountService",
+            "Name": "Account Service",
+            "Accounts": { ODATA_ID: &ids.accounts_id }
+        }),
+    ));
+    let members = (1..)
+        .zip(accounts)
+        .map(|(id, account)| account_payload(ids, id, *account))
+        .collect::<Vec<_>>();
+    bmc.expect(Expect::get(
+        &ids.accounts_id,
+        json!({
+            ODATA_ID: &ids.accounts_id,
+            ODATA_TYPE: ACCOUNTS_DATA_TYPE,
+            "Name": "User Accounts",
+            "Members": members
+        }),
+    ));
+}
+
+fn account_payload(ids: &Ids, id: u32, (user_name, role_id, enabled): (&str, &str, bool)) -> Value {
+    json!({
+        ODATA_ID: format!("{}/{id}", ids.accounts_id),
+        ODATA_TYPE: MANAGER_ACCOUNT_DATA_TYPE,
+        "Id": id.to_string(),
+        "Name": "User Account",
+        "UserName": user_name,
+        "RoleId": role_id,
+        "Enabled": enabled,
+        "AccountTypes": ["Redfish"]
+    })
+}
+
+fn network_protocol_payload(ids: &Ids, host_name: &str) -> Value {
+    json!({
+        ODATA_ID: &ids.network_protocol_id,
+        ODATA_TYPE: MANAGER_NETWORK_PROTOCOL_DATA_TYPE,
+        "Id": "NetworkProtocol",
+        "Name": "Manager Network Protocol",
+        "HostName": host_name,
+        "IPMI": { "ProtocolEnabled": false, "Port": 623 },
+        "NTP": { "ProtocolEnabled": true, "NTPServers": ["pool.ntp.org"] }
+    })
+}
+
+fn bios_payload(ids: &Ids, sriov: &str) -> Value {
+    json!({
+        ODATA_ID: &ids.bios_id,
+        ODATA_TYPE: BIOS_DATA_TYPE,
+        "Id": "Bios",
+        "Name": "BIOS Settings",
+        "Attributes": { "SriovGlobalEnable": sriov, "BootMode": "Uefi" }
+    })
+}
+
+fn boot_option_payload(
+    boot_options_id: &str,
+    id: &str,
+    display_name: &str,
+    uefi_device_path: &str,
+) -> Value {
+    json!({
+        ODATA_ID: format!("{boot_options_id}/{id}"),
+        ODATA_TYPE: BOOT_OPTION_DATA_TYPE,
+        "Id": id,
+        "Name": "Boot Option",
+        "BootOptionReference": id,
+        "DisplayName": display_name,
+        "UefiDevicePath": uefi_device_path
+    })
+}
+
+fn bios_attribute(name: &str, value: &str) -> (String, EdmPrimitiveType) {
+    (name.into(), EdmPrimitiveType::String(value.into()))
+}
@@ -0,0 +1,462 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests of Event Service.
+
+use std::error::Error as StdError;
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures_util::stream;
+use futures_util::TryStreamExt as _;
+use nv_redfish::core::ModificationResponse;
+use nv_redfish::core::ODataId;
+use nv_redfish::event_service::DeliveryRetryPolicy;
+use nv_redfish::event_service::EventDestinationProtocol;
+use nv_redfish::event_service::EventService;
+use nv_redfish::event_service::MembershipChange;
+use nv_redfish::event_service::SubscriptionConfig;
+use nv_redfish::event_service::SubscriptionFilter;
+use nv_redfish::event_service::TEST_EVENT_MESSAGE_ID;
+use nv_redfish::resource::Health;
+use nv_redfish::schema::event_service::EventService as EventServiceSchema;
+use nv_redfish::schema::manager_account::ManagerAccount;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use tokio::test;
+
+const EVENT_SERVICE_PATH: &str = EventServiceSchema::WELL_KNOWN_ODATA_ID;
+const SSE_PATH: &str = "/redfish/v1/EventService/SSE";
+const ACCOUNTS_PATH: &str = "/redfish/v1/AccountService/Accounts";
+const SUBSCRIPTIONS_PATH: &str = "/redfish/v1/EventService/Subscriptions";
+const SUBMIT_TEST_EVENT_PATH: &str =
+    "/redfish/v1/EventService/Actions/EventService.SubmitTestEvent";
+
+#[test]
+async fn membership_changes_via_events() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service(&bmc).await?;
+
+    bmc.expect(Expect::get(ACCOUNTS_PATH, accounts(&["1", "2"])));
+    bmc.expect(Expect::stream(
+        SSE_PATH,
+        json!([
+            resource_event("1", "ResourceCreated", &account_id("3")),
+            // Changes of members and duplicates are not reported.
+            resource_event("2", "ResourceChanged", &account_id("2")),
+            resource_event("3", "ResourceCreated", &account_id("3")),
+            resource_event("4", "ResourceRemoved", &account_id("1")),
+            // Event about the collection itself re-reads the collection.
+            resource_event("5", "ResourceCreated", ACCOUNTS_PATH),
+        ]),
+    ));
+    bmc.expect(Expect::get(ACCOUNTS_PATH, accounts(&["2", "3", "4"])));
+
+    let changes = event_service
+        .membership_changes::<ManagerAccount, _>(&odata_id(ACCOUNTS_PATH), stream::empty::<()>())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(
+        summary(&changes),
+        [
+            format!("+{}", account_id("3")),
+            format!("-{}", account_id("1")),
+            format!("+{}", account_id("4")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn membership_changes_via_collection_diff() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service(&bmc).await?;
+
+    bmc.expect(Expect::get(ACCOUNTS_PATH, accounts(&["1", "2"])));
+    bmc.expect(Expect::stream(SSE_PATH, json!([])));
+    // Single fallback tick re-reads the collection.
+    bmc.expect(Expect::get(ACCOUNTS_PATH, accounts(&["2", "3"])));
+
+    let changes = event_service
+        .membership_changes::<ManagerAccount, _>(&odata_id(ACCOUNTS_PATH), stream::iter([()]))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(
+        summary(&changes),
+        [
+            format!("-{}", account_id("1")),
+            format!("+{}", account_id("3")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn membership_changes_follow_next_link() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service(&bmc).await?;
+    let next_page = format!("{ACCOUNTS_PATH}?$skip=2");
+    let first_page = |ids: &[&str]| {
+        json_merge([
+            &accounts(ids),
+            &json!({ "Members@odata.nextLink": &next_page }),
+        ])
+    };
+
+    bmc.expect(Expect::get(ACCOUNTS_PATH, first_page(&["1", "2"])));
+    bmc.expect(Expect::get(&next_page, accounts(&["3"])));
+    bmc.expect(Expect::stream(SSE_PATH, json!([])));
+    // Members of the second page are not reported as removed.
+    bmc.expect(Expect::get(ACCOUNTS_PATH, first_page(&["1", "2"])));
+    bmc.expect(Expect::get(&next_page, accounts(&["3", "4"])));
+
+    let changes = event_service
+        .membership_changes::<ManagerAccount, _>(&odata_id(ACCOUNTS_PATH), stream::iter([()]))
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(summary(&changes), [format!("+{}", account_id("4"))]);
+
+    Ok(())
+}
+
+#[test]
+async fn subscribe_sends_delivery_settings() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service_with(
+        &bmc,
+        json!({ "Subscriptions": { ODATA_ID: SUBSCRIPTIONS_PATH } }),
+    )
+    .await?;
+
+    bmc.expect(Expect::get(
+        SUBSCRIPTIONS_PATH,
+        json!({
+            ODATA_ID: SUBSCRIPTIONS_PATH,
+            ODATA_TYPE: "#EventDestinationCollection.EventDestinationCollection",
+            "Name": "Event Subscriptions",
+            "Members": []
+        }),
+    ));
+    let subscriptions = event_service
+        .subscriptions()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected subscriptions"))?;
+
+    let subscription_id = format!("{SUBSCRIPTIONS_PATH}/1");
+    bmc.expect(Expect::create(
+        SUBSCRIPTIONS_PATH,
+        json!({
+            "Destination": "https://collector.example.com/events",
+            "Protocol": "Redfish",
+            "Context": "rack-12",
+            "DeliveryRetryPolicy": "RetryForeverWithBackoff",
+            "SendHeartbeat": true,
+            "HeartbeatIntervalMinutes": 2,
+            "VerifyCertificate": true
+        }),
+        json!({
+            ODATA_ID: &subscription_id,
+            ODATA_TYPE: "#EventDestination.v1_14_0.EventDestination",
+            "Id": "1",
+            "Name": "Subscription 1",
+            "Destination": "https://collector.example.com/events",
+            "Protocol": "Redfish",
+            "Context": "rack-12"
+        }),
+    ));
+    let config = SubscriptionConfig::new(
+        "https://collector.example.com/events",
+        EventDestinationProtocol::Redfish,
+    )
+    .context("rack-12")
+    .delivery_retry_policy(DeliveryRetryPolicy::RetryForeverWithBackoff)
+    .heartbeat(Duration::from_secs(90))
+    .verify_certificate(true);
+    let ModificationResponse::Entity(subscription) = subscriptions.subscribe(&config).await? else {
+        return Err(IoError::new(ErrorKind::InvalidData, "expected subscription").into());
+    };
+    assert_eq!(subscription.context(), Some("rack-12"));
+
+    Ok(())
+}
+
+#[test]
+async fn subscriptions_are_listed_created_with_filter_and_deleted() -> Result<(), Box<dyn StdError>>
+{
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service_with(
+        &bmc,
+        json!({ "Subscriptions": { ODATA_ID: SUBSCRIPTIONS_PATH } }),
+    )
+    .await?;
+
+    let existing_id = format!("{SUBSCRIPTIONS_PATH}/1");
+    let subscription = |id: &str, destination: &str| {
+        json!({
+            ODATA_ID: format!("{SUBSCRIPTIONS_PATH}/{id}"),
+            ODATA_TYPE: "#EventDestination.v1_14_0.EventDestination",
+            "Id": id,
+            "Name": format!("Subscription {id}"),
+            "Destination": destination,
+            "Protocol": "Redfish"
+        })
+    };
+    bmc.expect(Expect::get(
+        SUBSCRIPTIONS_PATH,
+        json!({
+            ODATA_ID: SUBSCRIPTIONS_PATH,
+            ODATA_TYPE: "#EventDestinationCollection.EventDestinationCollection",
+            "Name": "Event Subscriptions",
+            "Members": [{ ODATA_ID: &existing_id }]
+        }),
+    ));
+    let subscriptions = event_service
+        .subscriptions()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected subscriptions"))?;
+
+    bmc.expect(Expect::get(
+        &existing_id,
+        subscription("1", "https://old.example.com/events"),
+    ));
+    let members = subscriptions.members().await?;
+    assert_eq!(members.len(), 1);
+    assert_eq!(
+        members[0].destination(),
+        Some("https://old.example.com/events")
+    );
+
+    bmc.expect(Expect::create(
+        SUBSCRIPTIONS_PATH,
+        json!({
+            "Destination": "https://collector.example.com/events",
+            "Protocol": "Redfish",
+            "RegistryPrefixes": ["ResourceEvent"],
+            "OriginResources": [{ ODATA_ID: "/redfish/v1/Chassis/1" }],
+            "SubordinateResources": true
+        }),
+        subscription("2", "https://collector.example.com/events"),
+    ));
+    let filter = SubscriptionFilter::new()
+        .registry_prefix("ResourceEvent")
+        .origin_resource(odata_id("/redfish/v1/Chassis/1"))
+        .subordinate_resources(true);
+    let ModificationResponse::Entity(created) = subscriptions
+        .create_subscription(
+            "https://collector.example.com/events",
+            EventDestinationProtocol::Redfish,
+            filter,
+        )
+        .await?
+    else {
+        return Err(IoError::new(ErrorKind::InvalidData, "expected subscription").into());
+    };
+    assert_eq!(
+        created.destination(),
+        Some("https://collector.example.com/events")
+    );
+
+    bmc.expect(Expect::delete(&existing_id));
+    assert!(matches!(
+        members[0].delete().await?,
+        ModificationResponse::Empty
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn test_event_submits_test_event() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service_with(
+        &bmc,
+        json!({
+            "Actions": {
+                "#EventService.SubmitTestEvent": { "target": SUBMIT_TEST_EVENT_PATH }
+            }
+        }),
+    )
+    .await?;
+
+    bmc.expect(Expect::action(
+        SUBMIT_TEST_EVENT_PATH,
+        json!({
+            "Message": "Test event",
+            "MessageId": TEST_EVENT_MESSAGE_ID,
+            "OriginOfCondition": EVENT_SERVICE_PATH
+        }),
+        json!(null),
+    ));
+    event_service.test_event().await?;
+
+    Ok(())
+}
+
+#[test]
+async fn submit_test_event_sends_message_and_severity() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service_with(
+        &bmc,
+        json!({
+            "Actions": {
+                "#EventService.SubmitTestEvent": { "target": SUBMIT_TEST_EVENT_PATH }
+            }
+        }),
+    )
+    .await?;
+
+    let origin = "/redfish/v1/Chassis/1/Thermal";
+    bmc.expect(Expect::action(
+        SUBMIT_TEST_EVENT_PATH,
+        json!({
+            "MessageId": "ResourceEvent.1.0.ResourceErrorsDetected",
+            "MessageSeverity": "Critical",
+            "OriginOfCondition": origin
+        }),
+        json!(null),
+    ));
+    event_service
+        .submit_test_event(
+            "ResourceEvent.1.0.ResourceErrorsDetected".into(),
+            Some(&odata_id(origin)),
+            Some(Health::Critical),
+        )
+        .await?;
+
+    Ok(())
+}
+
+#[test]
+async fn submit_test_event_requires_action() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let event_service = get_event_service(&bmc).await?;
+
+    assert!(matches!(
+        event_service
+            .submit_test_event("Base.1.0.Success".into(), None, None)
+            .await,
+        Err(Error::ActionNotAvailable)
+    ));
+
+    Ok(())
+}
+
+async fn get_event_service(bmc: &Arc<Bmc>) -> Result<EventService<Bmc>, Box<dyn StdError>> {
+    get_event_service_with(bmc, json!({})).await
+}
+
+async fn get_event_service_with(
+    bmc: &Arc<Bmc>,
+    fields: JsonValue,
+) -> Result<EventService<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        "/redfish/v1",
+        json!({
+            ODATA_ID: "/redfish/v1",
+            ODATA_TYPE: "#ServiceRoot.v1_13_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "EventService": {
+                ODATA_ID: EVENT_SERVICE_PATH
+            },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: "/redfish/v1/SessionService/Sessions"
+                }
+            }
+        }),
+    ));
+    bmc.expect(Expect::get(
+        EVENT_SERVICE_PATH,
+        json_merge([
+            &json!({
+                ODATA_ID: EVENT_SERVICE_PATH,
+                ODATA_TYPE: "#EventService.v1_10_0.EventService",
+                "Id": "EventService",
+                "Name": "Event Service",
+                "ServerSentEventUri": SSE_PATH
+            }),
+            &fields,
+        ]),
+    ));
+    let root = ServiceRoot::new(Arc::clone(bmc)).await?;
+    let event_service = root
+        .event_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected event service"))?;
+    Ok(event_service)
+}
+
+fn odata_id(id: &str) -> ODataId {
+    ODataId::from(id.to_string())
+}
+
+fn account_id(id: &str) -> String {
+    format!("{ACCOUNTS_PATH}/{id}")
+}
+
+fn accounts(ids: &[&str]) -> JsonValue {
+    json!({
+        ODATA_ID: ACCOUNTS_PATH,
+        ODATA_TYPE: "#ManagerAccountCollection.ManagerAccountCollection",
+        "Name": "Accounts Collection",
+        "Members": ids
+            .iter()
+            .map(|id| json!({ ODATA_ID: account_id(id) }))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn resource_event(id: &str, message: &str, origin: &str) -> JsonValue {
+    json!({
+        ODATA_ID: format!("{SSE_PATH}#/Event{id}"),
+        ODATA_TYPE: "#Event.v1_6_0.Event",
+        "Id": id,
+        "Name": "Resource Event",
+        "Events": [{
+            ODATA_ID: format!("{SSE_PATH}#/Events/{id}"),
+            "MemberId": id,
+            "EventId": id,
+            "EventTimestamp": "2026-02-19T03:55:29+00:00",
+            "EventType": "Alert",
+            "MessageId": format!("ResourceEvent.1.2.{message}"),
+            "OriginOfCondition": { ODATA_ID: origin }
+        }]
+    })
+}
+
+fn summary(changes: &[MembershipChange<ManagerAccount>]) -> Vec<String> {
+    changes
+        .iter()
+        .map(|change| match change {
+            MembershipChange::MemberAdded(nav) => format!("+{}", nav.id()),
+            MembershipChange::MemberRemoved(id) => format!("-{id}"),
+        })
+        .collect()
+}
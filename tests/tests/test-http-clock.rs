@@ -0,0 +1,181 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for time-dependent behavior of the HTTP client
+//! driven by a manually advanced clock.
+
+use http::HeaderMap;
+use http::Method;
+use http::StatusCode;
+use nv_redfish_bmc_http::archive::ArchiveLimits;
+use nv_redfish_bmc_http::archive::ResponseArchive;
+use nv_redfish_bmc_http::reqwest::Client;
+use nv_redfish_bmc_http::reqwest::ClientParams;
+use nv_redfish_bmc_http::reqwest::RetryPolicy;
+use nv_redfish_bmc_http::BmcCredentials;
+use nv_redfish_bmc_http::HttpClient as _;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::MockClock;
+use serde_json::json;
+use serde_json::Value;
+use std::env;
+use std::error::Error as StdError;
+use std::fs;
+use std::process;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::test;
+use url::Url;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+const RESOURCE_PATH: &str = "/redfish/v1";
+
+fn credentials() -> BmcCredentials {
+    BmcCredentials::new("root".to_string(), "password".to_string())
+}
+
+async fn get(client: &Client, server: &MockServer) -> Result<Value, Box<dyn StdError>> {
+    let url = Url::parse(&format!("{}{RESOURCE_PATH}", server.uri()))?;
+    Ok(client
+        .get(url, &credentials(), None, &HeaderMap::new())
+        .await?)
+}
+
+#[test]
+async fn retry_delays_use_client_clock() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(RESOURCE_PATH))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(RESOURCE_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Id": "RootService" })))
+        .mount(&server)
+        .await;
+
+    let clock = MockClock::new();
+    let delay = Duration::from_secs(600);
+    let policy = RetryPolicy::new(|request, response| {
+        *request.method() == Method::GET && response.status() == StatusCode::SERVICE_UNAVAILABLE
+    })
+    .max_retries(2)
+    .delay(delay);
+    let client = Client::with_params(
+        ClientParams::new()
+            .retry(policy)
+            .clock(Arc::new(clock.clone())),
+    )?;
+
+    let response = get(&client, &server).await?;
+    assert_eq!(response["Id"], "RootService");
+    assert_eq!(clock.sleeps(), vec![delay, delay]);
+    Ok(())
+}
+
+#[test]
+async fn transient_retries_honor_retry_after() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(RESOURCE_PATH))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "7"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(RESOURCE_PATH))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(RESOURCE_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Id": "RootService" })))
+        .mount(&server)
+        .await;
+
+    let clock = MockClock::new();
+    let client = Client::with_params(
+        ClientParams::new()
+            .retry(RetryPolicy::transient())
+            .clock(Arc::new(clock.clone())),
+    )?;
+
+    let response = get(&client, &server).await?;
+    assert_eq!(response["Id"], "RootService");
+    // Retry-After of 429 is used as is, the 502 retry backs off
+    // by 1 s with up to a half of it dropped by jitter.
+    let sleeps = clock.sleeps();
+    assert_eq!(sleeps.len(), 2);
+    assert_eq!(sleeps[0], Duration::from_secs(7));
+    assert!(sleeps[1] >= Duration::from_millis(500) && sleeps[1] <= Duration::from_secs(1));
+    Ok(())
+}
+
+#[test]
+async fn idle_connections_expire_on_client_clock() -> Result<(), Box<dyn StdError>> {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(RESOURCE_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "Id": "RootService" })))
+        .mount(&server)
+        .await;
+
+    let clock = MockClock::new();
+    let client = Client::with_params(
+        ClientParams::new()
+            .idle_timeout(Duration::from_secs(90))
+            .clock(Arc::new(clock.clone())),
+    )?;
+    get(&client, &server).await?;
+    assert_eq!(client.pool_stats()[0].idle, 1);
+
+    clock.advance(Duration::from_secs(89));
+    assert_eq!(client.pool_stats()[0].idle, 1);
+    clock.advance(Duration::from_secs(1));
+    // Host without active or idle connections is forgotten.
+    assert!(client.pool_stats().is_empty());
+    Ok(())
+}
+
+#[test]
+async fn archived_responses_expire_on_archive_clock() -> Result<(), Box<dyn StdError>> {
+    let dir = env::temp_dir().join(format!("nv-redfish-clock-archive-{}", process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    let clock = MockClock::new();
+    let limits = ArchiveLimits::default().ttl(Duration::from_secs(3600));
+    let archive = ResponseArchive::open_with_clock(&dir, limits, Arc::new(clock.clone())).await?;
+    let root_id = ODataId::from(RESOURCE_PATH.to_string());
+
+    archive.record(&root_id, json!({ "v": 1 })).await?;
+    clock.advance(Duration::from_secs(1800));
+    archive.record(&root_id, json!({ "v": 2 })).await?;
+    assert_eq!(archive.responses(&root_id, ..).await?.len(), 2);
+
+    clock.advance(Duration::from_secs(1801));
+    let responses = archive.responses(&root_id, ..).await?;
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].body, json!({ "v": 2 }));
+
+    clock.advance(Duration::from_secs(1800));
+    assert!(archive.responses(&root_id, ..).await?.is_empty());
+    fs::remove_dir_all(dir)?;
+    Ok(())
+}
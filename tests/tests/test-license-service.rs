@@ -0,0 +1,333 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for License Service.
+
+use nv_redfish::license_service::License;
+use nv_redfish::manager::LicensedFeature;
+use nv_redfish::manager::Manager;
+use nv_redfish::Error;
+use nv_redfish::Resource as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection";
+const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
+const LICENSE_SERVICE_DATA_TYPE: &str = "#LicenseService.v1_1_0.LicenseService";
+const LICENSE_COLLECTION_DATA_TYPE: &str = "#LicenseCollection.LicenseCollection";
+const LICENSE_DATA_TYPE: &str = "#License.v1_1_0.License";
+const HPE_ILO_DATA_TYPE: &str = "#HpeiLO.v2_11_0.HpeiLO";
+
+#[test]
+async fn licenses_report_state_and_authorized_devices() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_service_root(bmc.clone(), &ids).await?;
+    let licenses = get_licenses(&bmc, &root, &ids, dell_licenses(&ids)).await?;
+
+    assert_eq!(
+        licenses
+            .iter()
+            .map(|license| license.name().to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "iDRAC9 Express License",
+            "iDRAC9 Enterprise License",
+            "iDRAC9 Datacenter License"
+        ]
+    );
+    assert_eq!(
+        licenses.iter().map(License::is_active).collect::<Vec<_>>(),
+        vec![true, false, true]
+    );
+    let manager = ODataId::from(ids.manager_id.clone());
+    assert_eq!(
+        licenses
+            .iter()
+            .map(|license| license.authorizes(&manager))
+            .collect::<Vec<_>>(),
+        vec![true, true, false]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn manager_license_is_read_from_license_service() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let manager = get_manager(bmc.clone(), &ids, manager_payload(&ids, json!({}))).await?;
+
+    // Enterprise license has expired and Datacenter license authorizes
+    // another manager: only Express license applies.
+    expect_licenses(&bmc, &ids, dell_licenses(&ids));
+    match manager
+        .ensure_feature_licensed(LicensedFeature::VirtualMedia)
+        .await
+    {
+        Err(Error::FeatureNotLicensed {
+            feature: LicensedFeature::VirtualMedia,
+            license,
+        }) => assert_eq!(license, "iDRAC Enterprise"),
+        Err(err) => panic!("unexpected error: {err}"),
+        Ok(()) => panic!("expected license error"),
+    }
+
+    let mut licenses = dell_licenses(&ids);
+    licenses[2]["Links"]["AuthorizedDevices"] = json!([{ ODATA_ID: &ids.manager_id }]);
+    expect_licenses(&bmc, &ids, licenses);
+    assert_eq!(
+        manager
+            .missing_license(LicensedFeature::RemoteConsole)
+            .await?,
+        None
+    );
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn hpe_oem_license_is_checked_without_known_licenses() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let manager = get_manager(
+        bmc.clone(),
+        &ids,
+        manager_payload(
+            &ids,
+            json!({
+                "Oem": {
+                    "Hpe": {
+                        ODATA_TYPE: HPE_ILO_DATA_TYPE,
+                        "License": {
+                            "LicenseString": "iLO Standard",
+                            "LicenseType": "Perpetual"
+                        }
+                    }
+                }
+            }),
+        ),
+    )
+    .await?;
+
+    expect_licenses(
+        &bmc,
+        &ids,
+        vec![license(&ids, "1", "Storage Feature License", json!({}))],
+    );
+    assert_eq!(
+        manager
+            .missing_license(LicensedFeature::VirtualMedia)
+            .await?,
+        Some("iLO Advanced")
+    );
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+async fn get_licenses(
+    bmc: &Arc<Bmc>,
+    root: &ServiceRoot<Bmc>,
+    ids: &Ids,
+    licenses: Vec<Value>,
+) -> Result<Vec<License<Bmc>>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.license_service_id,
+        license_service_payload(ids),
+    ));
+    let service = root
+        .license_service()
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing license service"))?;
+    bmc.expect(Expect::expand(
+        &ids.licenses_id,
+        license_collection_payload(ids, licenses),
+    ));
+    service
+        .licenses()
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing licenses").into())
+}
+
+fn expect_licenses(bmc: &Bmc, ids: &Ids, licenses: Vec<Value>) {
+    bmc.expect(Expect::get(&ids.root_id, service_root_payload(ids)));
+    bmc.expect(Expect::get(
+        &ids.license_service_id,
+        license_service_payload(ids),
+    ));
+    bmc.expect(Expect::expand(
+        &ids.licenses_id,
+        license_collection_payload(ids, licenses),
+    ));
+}
+
+async fn get_manager(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    manager: Value,
+) -> Result<Manager<Bmc>, Box<dyn StdError>> {
+    let root = expect_service_root(bmc.clone(), ids).await?;
+    bmc.expect(Expect::expand(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [manager]
+        }),
+    ));
+
+    let collection = root.managers().await?.unwrap();
+    let members = collection.members().await?;
+    assert_eq!(members.len(), 1);
+    Ok(members
+        .into_iter()
+        .next()
+        .expect("single manager must exist"))
+}
+
+async fn expect_service_root(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(&ids.root_id, service_root_payload(ids)));
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+fn service_root_payload(ids: &Ids) -> Value {
+    json!({
+        ODATA_ID: &ids.root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "ProtocolFeaturesSupported": {
+            "ExpandQuery": {
+                "NoLinks": true
+            }
+        },
+        "Managers": { ODATA_ID: &ids.managers_id },
+        "LicenseService": { ODATA_ID: &ids.license_service_id },
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{}/SessionService/Sessions", ids.root_id),
+            }
+        },
+    })
+}
+
+fn license_service_payload(ids: &Ids) -> Value {
+    json!({
+        ODATA_ID: &ids.license_service_id,
+        ODATA_TYPE: LICENSE_SERVICE_DATA_TYPE,
+        "Id": "LicenseService",
+        "Name": "License Service",
+        "Licenses": { ODATA_ID: &ids.licenses_id }
+    })
+}
+
+fn license_collection_payload(ids: &Ids, licenses: Vec<Value>) -> Value {
+    json!({
+        ODATA_ID: &ids.licenses_id,
+        ODATA_TYPE: LICENSE_COLLECTION_DATA_TYPE,
+        "Name": "License Collection",
+        "Members": licenses
+    })
+}
+
+fn dell_licenses(ids: &Ids) -> Vec<Value> {
+    vec![
+        license(ids, "1", "iDRAC9 Express License", json!({})),
+        license(
+            ids,
+            "2",
+            "iDRAC9 Enterprise License",
+            json!({ "Status": { "State": "Disabled" } }),
+        ),
+        license(
+            ids,
+            "3",
+            "iDRAC9 Datacenter License",
+            json!({
+                "Links": {
+                    "AuthorizedDevices": [{ ODATA_ID: format!("{}/2", ids.managers_id) }]
+                }
+            }),
+        ),
+    ]
+}
+
+fn license(ids: &Ids, id: &str, name: &str, fields: Value) -> Value {
+    json_merge([
+        &json!({
+            ODATA_ID: format!("{}/{id}", ids.licenses_id),
+            ODATA_TYPE: LICENSE_DATA_TYPE,
+            "Id": id,
+            "Name": name,
+            "LicenseType": "Production",
+            "Status": { "State": "Enabled" }
+        }),
+        &fields,
+    ])
+}
+
+fn manager_payload(ids: &Ids, fields: Value) -> Value {
+    json_merge([
+        &json!({
+            ODATA_ID: &ids.manager_id,
+            ODATA_TYPE: MANAGER_DATA_TYPE,
+            "Id": "1",
+            "Name": "Manager",
+            "ManagerType": "BMC",
+            "Status": { "State": "Enabled" }
+        }),
+        &fields,
+    ])
+}
+
+struct Ids {
+    root_id: ODataId,
+    managers_id: String,
+    manager_id: String,
+    license_service_id: String,
+    licenses_id: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let managers_id = format!("{root_id}/Managers");
+    let manager_id = format!("{managers_id}/1");
+    let license_service_id = format!("{root_id}/LicenseService");
+    let licenses_id = format!("{license_service_id}/Licenses");
+    Ids {
+        root_id,
+        managers_id,
+        manager_id,
+        license_service_id,
+        licenses_id,
+    }
+}
@@ -62,6 +62,31 @@ async fn manager_dell_attributes_lean_payload() -> Result<(), Box<dyn StdError>>
     Ok(())
 }
 
+#[test]
+async fn manager_dell_system_lockdown_locks_configuration() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = manager_ids();
+    let manager = get_manager(bmc.clone(), &ids, manager_payload(&ids, true)).await?;
+
+    bmc.expect(Expect::expand(
+        &ids.dell_attrs_id,
+        dell_attributes_payload(&ids),
+    ));
+    let attrs = manager.oem_dell_attributes().await?.unwrap();
+    assert_eq!(attrs.system_lockdown(), None);
+
+    bmc.expect(Expect::expand(
+        &ids.dell_attrs_id,
+        json_merge([
+            &dell_attributes_payload(&ids),
+            &json!({ "Attributes": { "Lockdown.1.SystemLockdown": "Enabled" } }),
+        ]),
+    ));
+    assert_eq!(manager.is_configuration_locked().await?, Some(true));
+
+    Ok(())
+}
+
 #[test]
 async fn manager_without_dell_oem_returns_not_available() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
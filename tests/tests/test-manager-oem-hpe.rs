@@ -14,7 +14,9 @@
 // limitations under the License.
 //! Integration tests for HPE Manager OEM support.
 
+use nv_redfish::manager::LicensedFeature;
 use nv_redfish::manager::Manager;
+use nv_redfish::Error;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
@@ -45,6 +47,55 @@ async fn hpe_virtual_nic_enabled_supported() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[test]
+async fn hpe_license_enables_remote_console() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let standard = get_manager(bmc.clone(), &ids, license_payload(&ids, "iLO Standard")).await?;
+
+    assert_eq!(
+        standard.oem_hpe()?.unwrap().license_string(),
+        Some("iLO Standard")
+    );
+    // Service without LicenseService: license of iLO is read from OEM
+    // data of the manager.
+    bmc.expect(Expect::get(&ids.root_id, service_root_payload(&ids)));
+    assert_eq!(
+        standard
+            .missing_license(LicensedFeature::RemoteConsole)
+            .await?,
+        Some("iLO Advanced")
+    );
+    bmc.expect(Expect::get(&ids.root_id, service_root_payload(&ids)));
+    assert!(matches!(
+        standard
+            .ensure_feature_licensed(LicensedFeature::RemoteConsole)
+            .await,
+        Err(Error::FeatureNotLicensed {
+            feature: LicensedFeature::RemoteConsole,
+            ..
+        })
+    ));
+
+    for license in ["iLO Advanced", "iLO Advanced Premium Security Edition"].iter() {
+        let advanced = get_manager(bmc.clone(), &ids, license_payload(&ids, license)).await?;
+        bmc.expect(Expect::get(&ids.root_id, service_root_payload(&ids)));
+        assert_eq!(
+            advanced
+                .missing_license(LicensedFeature::RemoteConsole)
+                .await?,
+            None
+        );
+        bmc.expect(Expect::get(&ids.root_id, service_root_payload(&ids)));
+        advanced
+            .ensure_feature_licensed(LicensedFeature::VirtualMedia)
+            .await?;
+    }
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
 #[test]
 async fn manager_without_hpe_oem_returns_none() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -80,6 +131,29 @@ async fn malformed_hpe_oem_returns_parse_error() -> Result<(), Box<dyn StdError>
     Ok(())
 }
 
+#[test]
+async fn hpe_oem_of_newer_schema_returns_unknown_schema_type() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let mut payload = manager_payload(&ids, Some(json!("true")));
+    payload["Oem"]["Hpe"][ODATA_TYPE] = json!("#HpeiLO.v3_0_0.HpeiLO");
+    let manager = get_manager(bmc.clone(), &ids, payload).await?;
+
+    match manager.oem_hpe() {
+        Err(Error::UnknownSchemaType {
+            odata_type,
+            nearest_compiled,
+        }) => {
+            assert_eq!(odata_type, "#HpeiLO.v3_0_0.HpeiLO");
+            assert_eq!(nearest_compiled.as_deref(), Some("HpeiLO.v2_11_0.HpeiLO"));
+        }
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(v) => panic!("expected unknown schema type, got: {:?}", v.is_some()),
+    }
+
+    Ok(())
+}
+
 async fn get_manager(
     bmc: Arc<Bmc>,
     ids: &Ids,
@@ -110,29 +184,30 @@ async fn expect_service_root(
     bmc: Arc<Bmc>,
     ids: &Ids,
 ) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
-    bmc.expect(Expect::get(
-        &ids.root_id,
-        json!({
-            ODATA_ID: &ids.root_id,
-            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
-            "Id": "RootService",
-            "Name": "RootService",
-            "ProtocolFeaturesSupported": {
-                "ExpandQuery": {
-                    "NoLinks": true
-                }
-            },
-            "Managers": { ODATA_ID: &ids.managers_id },
-            "Links": {
-                "Sessions": {
-                    ODATA_ID: format!("{}/SessionService/Sessions", ids.root_id),
-                }
-            },
-        }),
-    ));
+    bmc.expect(Expect::get(&ids.root_id, service_root_payload(ids)));
     ServiceRoot::new(bmc).await.map_err(Into::into)
 }
 
+fn service_root_payload(ids: &Ids) -> Value {
+    json!({
+        ODATA_ID: &ids.root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "ProtocolFeaturesSupported": {
+            "ExpandQuery": {
+                "NoLinks": true
+            }
+        },
+        "Managers": { ODATA_ID: &ids.managers_id },
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{}/SessionService/Sessions", ids.root_id),
+            }
+        },
+    })
+}
+
 struct Ids {
     root_id: ODataId,
     managers_id: String,
@@ -175,6 +250,15 @@ fn manager_payload(ids: &Ids, virtual_nic_enabled: Option<Value>) -> Value {
     json_merge([&base, &oem])
 }
 
+fn license_payload(ids: &Ids, license: &str) -> Value {
+    let mut manager = manager_payload(ids, None);
+    manager["Oem"]["Hpe"]["License"] = json!({
+        "LicenseString": license,
+        "LicenseType": "Perpetual"
+    });
+    manager
+}
+
 fn manager_payload_without_hpe(ids: &Ids) -> Value {
     json!({
         ODATA_ID: &ids.manager_id,
@@ -17,6 +17,7 @@
 use nv_redfish::manager::Manager;
 use nv_redfish::oem::supermicro::Privilege;
 use nv_redfish::ServiceRoot;
+use nv_redfish_core::EdmDateTimeOffset;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::json_merge;
 use nv_redfish_tests::Bmc;
@@ -95,6 +96,37 @@ async fn supermicro_manager_without_kcs_still_supports_sys_lockdown(
     Ok(())
 }
 
+#[test]
+async fn supermicro_sys_lockdown_rejects_manager_configuration_changes(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let manager = get_manager(
+        bmc.clone(),
+        &ids,
+        manager_payload(&ids, None, Some(ids.sys_lockdown_ref())),
+    )
+    .await?;
+
+    let locked_payload = json_merge([
+        &sys_lockdown_payload(&ids),
+        &json!({ "SysLockdownEnabled": true }),
+    ]);
+    bmc.expect(Expect::get(&ids.sys_lockdown_id, locked_payload.clone()));
+    assert_eq!(manager.is_configuration_locked().await?, Some(true));
+
+    // Lockdown is checked before PATCH is sent to the service.
+    bmc.expect(Expect::get(&ids.sys_lockdown_id, locked_payload));
+    let date_time: EdmDateTimeOffset = "2026-01-01T00:00:00Z".parse()?;
+    let result = manager.set_date_time(date_time).await;
+    assert!(matches!(
+        result,
+        Err(nv_redfish::Error::ConfigurationLocked(id)) if id.to_string() == ids.manager_id
+    ));
+
+    Ok(())
+}
+
 #[test]
 async fn manager_without_supermicro_oem_returns_none() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -102,6 +134,7 @@ async fn manager_without_supermicro_oem_returns_none() -> Result<(), Box<dyn Std
     let manager = get_manager(bmc.clone(), &ids, manager_payload_without_supermicro(&ids)).await?;
 
     assert!(manager.oem_supermicro()?.is_none());
+    assert_eq!(manager.is_configuration_locked().await?, None);
 
     Ok(())
 }
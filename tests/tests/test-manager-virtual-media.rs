@@ -0,0 +1,415 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for Manager virtual media.
+
+use nv_redfish::manager::InsertMedia;
+use nv_redfish::manager::LicensedFeature;
+use nv_redfish::manager::Manager;
+use nv_redfish::manager::TransferCredentials;
+use nv_redfish::manager::TransferMethod;
+use nv_redfish::manager::TransferProtocolType;
+use nv_redfish::manager::VirtualMedia;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::redfish_action_payload;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection";
+const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
+const VIRTUAL_MEDIA_COLLECTION_DATA_TYPE: &str = "#VirtualMediaCollection.VirtualMediaCollection";
+const VIRTUAL_MEDIA_DATA_TYPE: &str = "#VirtualMedia.v1_6_0.VirtualMedia";
+
+#[test]
+async fn insert_media_sends_typed_transfer_credentials() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let cd = get_virtual_media(
+        bmc.clone(),
+        &ids,
+        None,
+        json!({}),
+        insert_media_actions(&ids),
+    )
+    .await?;
+
+    assert_eq!(cd.image(), None);
+    assert_eq!(cd.inserted(), Some(false));
+
+    bmc.expect(Expect::action(
+        &ids.insert_media_target,
+        json!({
+            "Image": "nfs://server/export/image.iso",
+            "Inserted": true,
+            "WriteProtected": true,
+            "UserName": "user",
+            "Password": "secret",
+            "TransferProtocolType": "NFS"
+        }),
+        json!(null),
+    ));
+
+    let response = cd
+        .insert_media(InsertMedia {
+            transfer_protocol_type: Some(TransferProtocolType::Nfs),
+            credentials: Some(credentials()),
+            write_protected: Some(true),
+            ..InsertMedia::new("nfs://server/export/image.iso".into())
+        })
+        .await?;
+    assert!(matches!(response, ModificationResponse::Entity(())));
+
+    Ok(())
+}
+
+#[test]
+async fn insert_media_sends_legacy_credentials_on_hpe() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let cd = get_virtual_media(
+        bmc.clone(),
+        &ids,
+        Some("HPE"),
+        json!({}),
+        insert_media_actions(&ids),
+    )
+    .await?;
+
+    bmc.expect(Expect::action(
+        &ids.insert_media_target,
+        json!({
+            "Image": "smb://server/share/image.iso",
+            "Inserted": true,
+            "Username": "user",
+            "Password": "p@ss"
+        }),
+        json!(null),
+    ));
+
+    cd.insert_media(InsertMedia {
+        transfer_protocol_type: Some(TransferProtocolType::Cifs),
+        credentials: Some(TransferCredentials {
+            user_name: "user".into(),
+            password: "p@ss".into(),
+        }),
+        ..InsertMedia::new("smb://server/share/image.iso".into())
+    })
+    .await?;
+
+    Ok(())
+}
+
+#[test]
+async fn insert_media_requires_license_on_hpe() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let cd = get_virtual_media(
+        bmc.clone(),
+        &ids,
+        Some("HPE"),
+        json!({
+            "Oem": {
+                "Hpe": {
+                    ODATA_TYPE: "#HpeiLO.v2_11_0.HpeiLO",
+                    "License": {
+                        "LicenseString": "iLO Standard",
+                        "LicenseType": "Perpetual"
+                    }
+                }
+            }
+        }),
+        insert_media_actions(&ids),
+    )
+    .await?;
+
+    let err = match cd
+        .insert_media(InsertMedia::new("nfs://server/export/image.iso".into()))
+        .await
+    {
+        Ok(_) => panic!("expected license error"),
+        Err(err) => err,
+    };
+    assert!(
+        matches!(
+            &err,
+            Error::FeatureNotLicensed {
+                feature: LicensedFeature::VirtualMedia,
+                license,
+            } if license == "iLO Advanced"
+        ),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn eject_media_invokes_action() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let cd = get_virtual_media(
+        bmc.clone(),
+        &ids,
+        None,
+        json!({}),
+        json_merge([
+            &insert_media_actions(&ids),
+            &redfish_action_payload("VirtualMedia.EjectMedia", &ids.eject_media_target),
+        ]),
+    )
+    .await?;
+
+    bmc.expect(Expect::action(
+        &ids.eject_media_target,
+        json!({}),
+        json!(null),
+    ));
+    let response = cd.eject_media().await?;
+    assert!(matches!(response, ModificationResponse::Entity(())));
+
+    Ok(())
+}
+
+#[test]
+async fn media_is_patched_when_actions_are_absent() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let cd = get_virtual_media(bmc.clone(), &ids, None, json!({}), json!({})).await?;
+
+    bmc.expect(Expect::update(
+        &ids.cd_id,
+        json!({
+            "Image": "https://server/image.iso",
+            "Inserted": true,
+            "TransferMethod": "Stream"
+        }),
+        cd_payload(
+            &ids,
+            json!({ "Image": "https://server/image.iso", "Inserted": true }),
+        ),
+    ));
+    cd.insert_media(InsertMedia {
+        transfer_method: Some(TransferMethod::Stream),
+        ..InsertMedia::new("https://server/image.iso".into())
+    })
+    .await?;
+
+    bmc.expect(Expect::update(
+        &ids.cd_id,
+        json!({
+            "Image": null,
+            "Inserted": false
+        }),
+        cd_payload(&ids, json!({})),
+    ));
+    cd.eject_media().await?;
+
+    Ok(())
+}
+
+#[test]
+async fn virtual_media_returns_none_when_link_is_absent() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let manager = get_manager(bmc, &ids, None, manager_payload(&ids, json!({}))).await?;
+
+    assert!(manager.virtual_media().await?.is_none());
+
+    Ok(())
+}
+
+fn credentials() -> TransferCredentials {
+    TransferCredentials {
+        user_name: "user".into(),
+        password: "secret".into(),
+    }
+}
+
+async fn get_virtual_media(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    vendor: Option<&str>,
+    manager_fields: Value,
+    actions: Value,
+) -> Result<VirtualMedia<Bmc>, Box<dyn StdError>> {
+    let manager = get_manager(
+        bmc.clone(),
+        ids,
+        vendor,
+        manager_payload(
+            ids,
+            json_merge([
+                &json!({ "VirtualMedia": { ODATA_ID: &ids.virtual_media_id } }),
+                &manager_fields,
+            ]),
+        ),
+    )
+    .await?;
+
+    // License state is checked before virtual media is read.
+    bmc.expect(Expect::get(&ids.root_id, service_root_payload(ids, vendor)));
+    bmc.expect(Expect::get(
+        &ids.virtual_media_id,
+        json!({
+            ODATA_ID: &ids.virtual_media_id,
+            ODATA_TYPE: VIRTUAL_MEDIA_COLLECTION_DATA_TYPE,
+            "Name": "Virtual Media Collection",
+            "Members": [{ ODATA_ID: &ids.cd_id }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &ids.cd_id,
+        json_merge([&cd_payload(ids, json!({})), &actions]),
+    ));
+
+    let virtual_media = manager.virtual_media().await?.unwrap();
+    assert_eq!(virtual_media.len(), 1);
+    Ok(virtual_media
+        .into_iter()
+        .next()
+        .expect("single virtual media must exist"))
+}
+
+async fn get_manager(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    vendor: Option<&str>,
+    manager: Value,
+) -> Result<Manager<Bmc>, Box<dyn StdError>> {
+    let root = expect_service_root(bmc.clone(), ids, vendor).await?;
+    bmc.expect(Expect::expand(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [manager]
+        }),
+    ));
+
+    let collection = root.managers().await?.unwrap();
+    let members = collection.members().await?;
+    assert_eq!(members.len(), 1);
+    Ok(members
+        .into_iter()
+        .next()
+        .expect("single manager must exist"))
+}
+
+async fn expect_service_root(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+    vendor: Option<&str>,
+) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(&ids.root_id, service_root_payload(ids, vendor)));
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+fn service_root_payload(ids: &Ids, vendor: Option<&str>) -> Value {
+    let mut root = json!({
+        ODATA_ID: &ids.root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "ProtocolFeaturesSupported": {
+            "ExpandQuery": {
+                "NoLinks": true
+            }
+        },
+        "Managers": { ODATA_ID: &ids.managers_id },
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{}/SessionService/Sessions", ids.root_id),
+            }
+        },
+    });
+    if let Some(vendor) = vendor {
+        root["Vendor"] = json!(vendor);
+    }
+    root
+}
+
+struct Ids {
+    root_id: ODataId,
+    managers_id: String,
+    manager_id: String,
+    virtual_media_id: String,
+    cd_id: String,
+    insert_media_target: String,
+    eject_media_target: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let managers_id = format!("{root_id}/Managers");
+    let manager_id = format!("{managers_id}/1");
+    let virtual_media_id = format!("{manager_id}/VirtualMedia");
+    let cd_id = format!("{virtual_media_id}/CD1");
+    let insert_media_target = format!("{cd_id}/Actions/VirtualMedia.InsertMedia");
+    let eject_media_target = format!("{cd_id}/Actions/VirtualMedia.EjectMedia");
+    Ids {
+        root_id,
+        managers_id,
+        manager_id,
+        virtual_media_id,
+        cd_id,
+        insert_media_target,
+        eject_media_target,
+    }
+}
+
+fn cd_payload(ids: &Ids, fields: Value) -> Value {
+    json_merge([
+        &json!({
+            ODATA_ID: &ids.cd_id,
+            ODATA_TYPE: VIRTUAL_MEDIA_DATA_TYPE,
+            "Id": "CD1",
+            "Name": "Virtual CD",
+            "Image": null,
+            "Inserted": false
+        }),
+        &fields,
+    ])
+}
+
+fn insert_media_actions(ids: &Ids) -> Value {
+    redfish_action_payload("VirtualMedia.InsertMedia", &ids.insert_media_target)
+}
+
+fn manager_payload(ids: &Ids, fields: Value) -> Value {
+    json_merge([
+        &json!({
+            ODATA_ID: &ids.manager_id,
+            ODATA_TYPE: MANAGER_DATA_TYPE,
+            "Id": "1",
+            "Name": "Manager",
+            "ManagerType": "BMC",
+            "Status": { "State": "Enabled" }
+        }),
+        &fields,
+    ])
+}
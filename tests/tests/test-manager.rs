@@ -14,14 +14,22 @@
 // limitations under the License.
 //! Integration tests for Manager collection behavior.
 
+use std::convert::TryFrom as _;
 use std::error::Error as StdError;
 use std::sync::Arc;
+use std::time::Duration;
+use std::time::SystemTime;
 
+use nv_redfish::manager::ClockCorrection;
+use nv_redfish::manager::ClockSkew;
 use nv_redfish::manager::Manager;
 use nv_redfish::manager::ManagerResetToDefaultsType;
 use nv_redfish::resource::ResetType;
+use nv_redfish::NvBmcBuilder;
 use nv_redfish::Resource;
 use nv_redfish::ServiceRoot;
+use nv_redfish_core::EdmDateTimeOffset;
+use nv_redfish_core::EntityTypeRef as _;
 use nv_redfish_core::ModificationResponse;
 use nv_redfish_core::ODataId;
 use nv_redfish_tests::ami_viking_service_root;
@@ -43,6 +51,7 @@ const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection
 const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
 const MANAGER_NETWORK_PROTOCOL_DATA_TYPE: &str =
     "#ManagerNetworkProtocol.v1_5_0.ManagerNetworkProtocol";
+const ACCOUNT_SERVICE_DATA_TYPE: &str = "#AccountService.v1_5_0.AccountService";
 
 #[test]
 async fn network_protocol_returns_none_when_link_is_absent() -> Result<(), Box<dyn StdError>> {
@@ -99,6 +108,93 @@ async fn network_protocol_fetches_linked_resource() -> Result<(), Box<dyn StdErr
     Ok(())
 }
 
+#[test]
+async fn clock_skew_compares_manager_date_time_with_local_time() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let manager = get_manager(
+        bmc,
+        &ids,
+        manager_payload_with_fields(&ids, json!({ "DateTime": "2026-03-04T05:06:07Z" })),
+    )
+    .await?;
+    let manager_time = SystemTime::try_from("2026-03-04T05:06:07Z".parse::<EdmDateTimeOffset>()?)?;
+
+    assert_eq!(
+        manager.clock_skew(manager_time + Duration::from_secs(3600)),
+        Some(ClockSkew::Behind(Duration::from_secs(3600)))
+    );
+    assert_eq!(
+        manager.clock_skew(manager_time - Duration::from_secs(90)),
+        Some(ClockSkew::Ahead(Duration::from_secs(90)))
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn correct_clock_skew_enables_ntp_on_skewed_manager() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_anonymous_1_9_service_root(
+        bmc.clone(),
+        &ids,
+        json!({ "Managers": { ODATA_ID: &ids.managers_id } }),
+    )
+    .await?;
+    let manager = manager_payload_with_fields(
+        &ids,
+        json!({
+            "DateTime": "2000-01-01T00:00:00Z",
+            "NetworkProtocol": { ODATA_ID: &ids.manager_network_protocol_id }
+        }),
+    );
+    bmc.expect(Expect::get(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [&manager]
+        }),
+    ));
+    // Skew is measured around an individual request of the manager.
+    bmc.expect(Expect::get(&ids.manager_id, manager));
+    let network_protocol = |ntp: Value| {
+        json!({
+            ODATA_ID: &ids.manager_network_protocol_id,
+            ODATA_TYPE: MANAGER_NETWORK_PROTOCOL_DATA_TYPE,
+            "Id": "NetworkProtocol",
+            "Name": "Manager Network Protocol",
+            "NTP": ntp
+        })
+    };
+    bmc.expect(Expect::get(
+        &ids.manager_network_protocol_id,
+        network_protocol(json!({ "ProtocolEnabled": false })),
+    ));
+    let ntp = json!({
+        "ProtocolEnabled": true,
+        "NTPServers": ["pool.ntp.org"]
+    });
+    bmc.expect(Expect::update(
+        &ids.manager_network_protocol_id,
+        json!({ "NTP": &ntp }),
+        network_protocol(ntp.clone()),
+    ));
+
+    let skew = root
+        .correct_clock_skew(
+            Duration::from_secs(60),
+            &ClockCorrection::Ntp(vec!["pool.ntp.org".into()]),
+        )
+        .await?;
+    assert!(matches!(skew, Some(ClockSkew::Behind(_))));
+
+    Ok(())
+}
+
 #[test]
 async fn reset_invokes_manager_reset_action() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -185,6 +281,106 @@ async fn reset_helpers_return_action_not_available_when_manager_actions_are_abse
     Ok(())
 }
 
+#[test]
+async fn account_service_for_system_routes_to_element_manager() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root =
+        expect_anonymous_1_9_service_root(bmc.clone(), &ids, aggregator_root_fields(&ids)).await?;
+    expect_aggregated_managers(&bmc, &ids);
+    bmc.expect(Expect::get(
+        &ids.remote_account_service_id,
+        account_service_payload(&ids.remote_account_service_id),
+    ));
+
+    let service = root
+        .account_service_for_system(&ids.system_id.clone().into())
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing account service"))?;
+    assert_eq!(
+        service.raw().odata_id().to_string(),
+        ids.remote_account_service_id
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn element_manager_for_routes_contained_resources() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root =
+        expect_anonymous_1_9_service_root(bmc.clone(), &ids, aggregator_root_fields(&ids)).await?;
+
+    // BIOS of the aggregated system is managed by its element manager.
+    expect_aggregated_managers(&bmc, &ids);
+    let manager = root
+        .element_manager_for(&format!("{}/Bios", ids.system_id).into())
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing element manager"))?;
+    assert_eq!(manager.odata_id().to_string(), ids.manager_id);
+    assert!(manager.is_element_manager());
+
+    // System with a similar identifier is not managed by it.
+    expect_aggregated_managers(&bmc, &ids);
+    assert!(root
+        .element_manager_for(&format!("{}0", ids.system_id).into())
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn account_service_for_system_falls_back_to_aggregator() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root =
+        expect_anonymous_1_9_service_root(bmc.clone(), &ids, aggregator_root_fields(&ids)).await?;
+    expect_aggregated_managers(&bmc, &ids);
+    bmc.expect(Expect::get(
+        &ids.account_service_id,
+        account_service_payload(&ids.account_service_id),
+    ));
+
+    let other_system_id = ODataId::from(format!("{}/Systems/Other", ids.root_id));
+    let service = root
+        .account_service_for_system(&other_system_id)
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing account service"))?;
+    assert_eq!(service.raw().odata_id().to_string(), ids.account_service_id);
+
+    Ok(())
+}
+
+#[test]
+async fn account_service_for_system_is_not_routed_when_disabled() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        anonymous_1_9_service_root(&ids.root_id, aggregator_root_fields(&ids)),
+    ));
+    let root = ServiceRoot::with_bmc(
+        NvBmcBuilder::new(bmc.clone())
+            .disable_aggregation_routing()
+            .build(),
+    )
+    .await?;
+    bmc.expect(Expect::get(
+        &ids.account_service_id,
+        account_service_payload(&ids.account_service_id),
+    ));
+
+    let service = root
+        .account_service_for_system(&ids.system_id.clone().into())
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing account service"))?;
+    assert_eq!(service.raw().odata_id().to_string(), ids.account_service_id);
+
+    Ok(())
+}
+
 #[test]
 async fn ami_viking_missing_root_managers_nav_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -332,6 +528,9 @@ struct Ids {
     managers_id: String,
     manager_id: String,
     manager_network_protocol_id: String,
+    account_service_id: String,
+    remote_account_service_id: String,
+    system_id: String,
 }
 
 fn ids() -> Ids {
@@ -339,14 +538,56 @@ fn ids() -> Ids {
     let managers_id = format!("{root_id}/Managers");
     let manager_id = format!("{managers_id}/1");
     let manager_network_protocol_id = format!("{manager_id}/NetworkProtocol");
+    let account_service_id = format!("{root_id}/AccountService");
+    let remote_account_service_id = format!("{manager_id}/RemoteAccountService");
+    let system_id = format!("{root_id}/Systems/1");
     Ids {
         root_id,
         managers_id,
         manager_id,
         manager_network_protocol_id,
+        account_service_id,
+        remote_account_service_id,
+        system_id,
     }
 }
 
+fn aggregator_root_fields(ids: &Ids) -> Value {
+    json!({
+        "Managers": { ODATA_ID: &ids.managers_id },
+        "AccountService": { ODATA_ID: &ids.account_service_id }
+    })
+}
+
+fn expect_aggregated_managers(bmc: &Bmc, ids: &Ids) {
+    bmc.expect(Expect::get(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [manager_payload_with_fields(
+                ids,
+                json!({
+                    "Links": { "ManagerForServers": [{ ODATA_ID: &ids.system_id }] },
+                    "RemoteAccountService": { ODATA_ID: &ids.remote_account_service_id },
+                    "RemoteRedfishServiceUri": "https://192.0.2.10/redfish/v1"
+                }),
+            )]
+        }),
+    ));
+}
+
+fn account_service_payload(id: &str) -> Value {
+    json!({
+        ODATA_ID: id,
+        ODATA_TYPE: ACCOUNT_SERVICE_DATA_TYPE,
+        "Id": "AccountService",
+        "Name": "Account Service"
+    })
+}
+
 fn manager_payload(ids: &Ids) -> serde_json::Value {
     manager_payload_with_state(ids, "Enabled")
 }
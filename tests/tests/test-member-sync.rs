@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for incremental sync of collection members.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::member_sync::MemberSyncState;
+use nv_redfish::schema::computer_system::ComputerSystem;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::EntityTypeRef as _;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::anonymous_1_9_service_root;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const SYSTEM_COLLECTION_DATA_TYPE: &str = "#ComputerSystemCollection.ComputerSystemCollection";
+const SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_20_0.ComputerSystem";
+
+#[test]
+async fn sync_reports_added_modified_and_removed_members() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let systems_id = ODataId::from(format!("{root_id}/Systems"));
+    let system_ids = (1..=3)
+        .map(|n| format!("{systems_id}/{n}"))
+        .collect::<Vec<_>>();
+    bmc.expect(Expect::get(
+        &root_id,
+        anonymous_1_9_service_root(&root_id, json!({ "Systems": { ODATA_ID: &systems_id } })),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    // First sync reports all members as added.
+    bmc.expect(collection(&systems_id, &[&system_ids[0], &system_ids[1]]));
+    bmc.expect(Expect::get(
+        &system_ids[0],
+        system(&system_ids[0], Some("1")),
+    ));
+    bmc.expect(Expect::get(
+        &system_ids[1],
+        system(&system_ids[1], Some("1")),
+    ));
+    let changes = root
+        .sync_members::<ComputerSystem>(&systems_id, &MemberSyncState::default())
+        .await?;
+    assert_eq!(ids(&changes.added), system_ids[..2]);
+    assert!(changes.modified.is_empty());
+    assert!(changes.removed.is_empty());
+    assert_eq!(changes.state.len(), 2);
+
+    // First system is removed, second one is changed and the third
+    // one is added.
+    bmc.expect(collection(&systems_id, &[&system_ids[1], &system_ids[2]]));
+    bmc.expect(
+        Expect::get(&system_ids[1], system(&system_ids[1], Some("2")))
+            .with_if_none_match(Some("1")),
+    );
+    bmc.expect(Expect::get(&system_ids[2], system(&system_ids[2], None)).with_if_none_match(None));
+    let changes = root
+        .sync_members::<ComputerSystem>(&systems_id, &changes.state)
+        .await?;
+    assert_eq!(ids(&changes.added), system_ids[2..]);
+    assert_eq!(ids(&changes.modified), system_ids[1..2]);
+    assert_eq!(changes.removed, [ODataId::from(system_ids[0].clone())]);
+
+    // Nothing is changed, but changes of the member without ETag
+    // cannot be detected.
+    bmc.expect(collection(&systems_id, &[&system_ids[1], &system_ids[2]]));
+    bmc.expect(
+        Expect::get(&system_ids[1], system(&system_ids[1], Some("2")))
+            .with_if_none_match(Some("2")),
+    );
+    bmc.expect(Expect::get(&system_ids[2], system(&system_ids[2], None)).with_if_none_match(None));
+    let changes = root
+        .sync_members::<ComputerSystem>(&systems_id, &changes.state)
+        .await?;
+    assert!(changes.added.is_empty());
+    assert_eq!(ids(&changes.modified), system_ids[2..]);
+    assert!(changes.removed.is_empty());
+    assert_eq!(
+        changes.state.etag(&system_ids[1].clone().into()),
+        Some(&"2".to_string().into())
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn sync_follows_next_link() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let systems_id = ODataId::from(format!("{root_id}/Systems"));
+    let next_link = format!("{systems_id}?$skip=1");
+    let system_ids = [format!("{systems_id}/1"), format!("{systems_id}/2")];
+    bmc.expect(Expect::get(
+        &root_id,
+        anonymous_1_9_service_root(&root_id, json!({ "Systems": { ODATA_ID: &systems_id } })),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(Expect::get(
+        &systems_id,
+        json!({
+            ODATA_ID: &systems_id,
+            ODATA_TYPE: SYSTEM_COLLECTION_DATA_TYPE,
+            "Name": "Computer System Collection",
+            "Members": [{ ODATA_ID: &system_ids[0] }],
+            "Members@odata.nextLink": &next_link
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &next_link,
+        json!({
+            ODATA_ID: &systems_id,
+            ODATA_TYPE: SYSTEM_COLLECTION_DATA_TYPE,
+            "Name": "Computer System Collection",
+            "Members": [{ ODATA_ID: &system_ids[1] }]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &system_ids[0],
+        system(&system_ids[0], Some("1")),
+    ));
+    bmc.expect(Expect::get(
+        &system_ids[1],
+        system(&system_ids[1], Some("1")),
+    ));
+    let changes = root
+        .sync_members::<ComputerSystem>(&systems_id, &MemberSyncState::default())
+        .await?;
+    assert_eq!(ids(&changes.added), system_ids[..2]);
+
+    Ok(())
+}
+
+#[test]
+async fn sync_reports_not_found_members_as_removed() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let systems_id = ODataId::from(format!("{root_id}/Systems"));
+    let system_ids = [format!("{systems_id}/1"), format!("{systems_id}/2")];
+    bmc.expect(Expect::get(
+        &root_id,
+        anonymous_1_9_service_root(&root_id, json!({ "Systems": { ODATA_ID: &systems_id } })),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(collection(&systems_id, &[&system_ids[0], &system_ids[1]]));
+    bmc.expect(Expect::get(
+        &system_ids[0],
+        system(&system_ids[0], Some("1")),
+    ));
+    bmc.expect(Expect::get(
+        &system_ids[1],
+        system(&system_ids[1], Some("1")),
+    ));
+    let changes = root
+        .sync_members::<ComputerSystem>(&systems_id, &MemberSyncState::default())
+        .await?;
+
+    // Collection still lists the first system, but it is already
+    // deleted. Unknown members that are not found are not reported.
+    bmc.expect(collection(&systems_id, &[&system_ids[0], &system_ids[1]]));
+    bmc.expect(Expect::get_status(&system_ids[0], 404));
+    bmc.expect(Expect::get(
+        &system_ids[1],
+        system(&system_ids[1], Some("1")),
+    ));
+    let changes = root
+        .sync_members::<ComputerSystem>(&systems_id, &changes.state)
+        .await?;
+    assert!(changes.added.is_empty());
+    assert!(changes.modified.is_empty());
+    assert_eq!(changes.removed, [ODataId::from(system_ids[0].clone())]);
+    assert_eq!(changes.state.len(), 1);
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn sync_stops_on_next_link_cycle() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let systems_id = ODataId::from(format!("{root_id}/Systems"));
+    let system_id = format!("{systems_id}/1");
+    bmc.expect(Expect::get(
+        &root_id,
+        anonymous_1_9_service_root(&root_id, json!({ "Systems": { ODATA_ID: &systems_id } })),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    bmc.expect(Expect::get(
+        &systems_id,
+        json!({
+            ODATA_ID: &systems_id,
+            ODATA_TYPE: SYSTEM_COLLECTION_DATA_TYPE,
+            "Name": "Computer System Collection",
+            "Members": [{ ODATA_ID: &system_id }],
+            "Members@odata.nextLink": &systems_id
+        }),
+    ));
+    bmc.expect(Expect::get(&system_id, system(&system_id, Some("1"))));
+    let changes = root
+        .sync_members::<ComputerSystem>(&systems_id, &MemberSyncState::default())
+        .await?;
+    assert_eq!(ids(&changes.added), [system_id]);
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+fn collection(id: &ODataId, members: &[&String]) -> Expect {
+    let members = members
+        .iter()
+        .map(|member| json!({ ODATA_ID: member }))
+        .collect::<Vec<_>>();
+    Expect::get(
+        id,
+        json!({
+            ODATA_ID: id,
+            ODATA_TYPE: SYSTEM_COLLECTION_DATA_TYPE,
+            "Name": "Computer System Collection",
+            "Members": members
+        }),
+    )
+}
+
+fn system(id: &str, etag: Option<&str>) -> Value {
+    let mut system = json!({
+        ODATA_ID: id,
+        ODATA_TYPE: SYSTEM_DATA_TYPE,
+        "Id": "1",
+        "Name": "System"
+    });
+    if let Some(etag) = etag {
+        system["@odata.etag"] = json!(etag);
+    }
+    system
+}
+
+fn ids(members: &[Arc<ComputerSystem>]) -> Vec<String> {
+    members
+        .iter()
+        .map(|member| member.odata_id().to_string())
+        .collect()
+}
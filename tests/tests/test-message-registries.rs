@@ -0,0 +1,263 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for message registries.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::MessageSeverity;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+
+use serde_json::json;
+use tokio::test;
+
+const ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const REGISTRY_FILE_COLLECTION_DATA_TYPE: &str =
+    "#MessageRegistryFileCollection.MessageRegistryFileCollection";
+const REGISTRY_FILE_DATA_TYPE: &str = "#MessageRegistryFile.v1_1_3.MessageRegistryFile";
+
+async fn service_root(bmc: &Arc<Bmc>, registries: Option<&str>) -> ServiceRoot<Bmc> {
+    let root_id = ODataId::service_root();
+    let mut root = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "Links": {
+            "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+        }
+    });
+    if let Some(registries) = registries {
+        root["Registries"] = json!({ ODATA_ID: registries });
+    }
+    bmc.expect(Expect::get(&root_id, root));
+    ServiceRoot::new(bmc.clone())
+        .await
+        .expect("valid service root")
+}
+
+#[test]
+async fn message_registries_are_downloaded_in_language() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let registries_id = format!("{}/Registries", ODataId::service_root());
+    let root = service_root(&bmc, Some(&registries_id)).await;
+
+    let base_id = format!("{registries_id}/Base.1.8.0");
+    let oem_id = format!("{registries_id}/Contoso.1.0.0");
+    let base_de_uri = format!("{base_id}/Base.1.8.0.de.json");
+    bmc.expect(Expect::get(
+        &registries_id,
+        json!({
+            ODATA_ID: &registries_id,
+            ODATA_TYPE: REGISTRY_FILE_COLLECTION_DATA_TYPE,
+            "Name": "Registry File Collection",
+            "Members": [{ ODATA_ID: &base_id }, { ODATA_ID: &oem_id }],
+            "Members@odata.count": 2
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &base_id,
+        json!({
+            ODATA_ID: &base_id,
+            ODATA_TYPE: REGISTRY_FILE_DATA_TYPE,
+            "Id": "Base.1.8.0",
+            "Name": "Base Message Registry File",
+            "Languages": ["en", "de"],
+            "Registry": "Base.1.8",
+            "Location": [
+                { "Language": "en", "Uri": format!("{base_id}/Base.1.8.0.json") },
+                { "Language": "de", "Uri": &base_de_uri }
+            ]
+        }),
+    ));
+    // Registry that is only published externally is skipped.
+    bmc.expect(Expect::get(
+        &oem_id,
+        json!({
+            ODATA_ID: &oem_id,
+            ODATA_TYPE: REGISTRY_FILE_DATA_TYPE,
+            "Id": "Contoso.1.0.0",
+            "Name": "Contoso Message Registry File",
+            "Languages": ["en"],
+            "Registry": "Contoso.1.0",
+            "Location": [
+                {
+                    "Language": "en",
+                    "PublicationUri": "https://contoso.example/registries/Contoso.1.0.0.json"
+                }
+            ]
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &base_de_uri,
+        json!({
+            ODATA_TYPE: "#MessageRegistry.v1_6_0.MessageRegistry",
+            "Id": "Base.1.8.0",
+            "Name": "Base Message Registry",
+            "Language": "de",
+            "RegistryPrefix": "Base",
+            "RegistryVersion": "1.8.0",
+            "OwningEntity": "DMTF",
+            "Messages": {
+                "PropertyNotWritable": {
+                    "Description": "Indicates that a property was given a value in the request body, but the property is a read-only property.",
+                    "Message": "Die Eigenschaft %1 ist schreibgeschützt.",
+                    "MessageSeverity": "Warning",
+                    "NumberOfArgs": 1,
+                    "ParamTypes": ["string"],
+                    "Resolution": "Entfernen Sie die Eigenschaft aus der Anfrage."
+                }
+            }
+        }),
+    ));
+
+    let registries = root
+        .message_registries("de-DE")
+        .await?
+        .ok_or("missing registries")?;
+    bmc.assert_consumed();
+    assert_eq!(registries.registries().len(), 1);
+    assert_eq!(registries.registries()[0].language(), Some("de"));
+
+    let resolved = registries
+        .resolve("Base.1.8.PropertyNotWritable", &["AssetTag"])
+        .ok_or("message is not resolved")?;
+    assert_eq!(
+        resolved.message,
+        "Die Eigenschaft AssetTag ist schreibgeschützt."
+    );
+    assert_eq!(resolved.severity, Some(MessageSeverity::Warning));
+    assert_eq!(
+        resolved.resolution.as_deref(),
+        Some("Entfernen Sie die Eigenschaft aus der Anfrage.")
+    );
+    assert!(registries
+        .resolve("Contoso.1.0.FanFailed", &["Fan1"])
+        .is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn failed_registry_files_are_skipped_and_set_is_cached() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let registries_id = format!("{}/Registries", ODataId::service_root());
+    let root = service_root(&bmc, Some(&registries_id)).await;
+
+    let base_id = format!("{registries_id}/Base.1.8.0");
+    let oem_id = format!("{registries_id}/Contoso.1.0.0");
+    let base_uri = format!("{base_id}/Base.1.8.0.json");
+    bmc.expect(Expect::get(
+        &registries_id,
+        json!({
+            ODATA_ID: &registries_id,
+            ODATA_TYPE: REGISTRY_FILE_COLLECTION_DATA_TYPE,
+            "Name": "Registry File Collection",
+            "Members": [{ ODATA_ID: &base_id }, { ODATA_ID: &oem_id }],
+            "Members@odata.count": 2
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &base_id,
+        json!({
+            ODATA_ID: &base_id,
+            ODATA_TYPE: REGISTRY_FILE_DATA_TYPE,
+            "Id": "Base.1.8.0",
+            "Name": "Base Message Registry File",
+            "Languages": ["en"],
+            "Registry": "Base.1.8",
+            "Location": [{ "Language": "en", "Uri": &base_uri }]
+        }),
+    ));
+    bmc.expect(Expect::get_status(&oem_id, 500));
+    bmc.expect(Expect::get(&base_uri, json!({ "Name": "not a registry" })));
+
+    let registries = root
+        .message_registries("en")
+        .await?
+        .ok_or("missing registries")?;
+    assert!(registries.registries().is_empty());
+    let failed = registries
+        .failures()
+        .iter()
+        .map(|failure| failure.uri.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(failed, [oem_id.as_str(), base_uri.as_str()]);
+
+    // The set is cached: no more requests are sent.
+    let cached = root
+        .clone()
+        .message_registries("en")
+        .await?
+        .ok_or("missing registries")?;
+    assert_eq!(cached.failures(), registries.failures());
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn payload_of_unknown_type_rejected_by_bmc_is_reported() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let registries_id = format!("{}/Registries", ODataId::service_root());
+    let root = service_root(&bmc, Some(&registries_id)).await;
+
+    let odata_type = "#MessageRegistryFileCollection.v2_0_0.MessageRegistryFileCollection";
+    bmc.expect(Expect::get(
+        &registries_id,
+        json!({
+            ODATA_ID: &registries_id,
+            ODATA_TYPE: odata_type,
+            "Name": "Registry File Collection",
+            "Members": "not a collection"
+        }),
+    ));
+
+    match root.message_registries("en").await {
+        Err(Error::UnknownSchemaType {
+            odata_type: reported,
+            nearest_compiled,
+        }) => {
+            assert_eq!(reported, odata_type);
+            assert_eq!(
+                nearest_compiled.as_deref(),
+                Some("MessageRegistryFileCollection.MessageRegistryFileCollection")
+            );
+        }
+        Err(err) => panic!("unexpected error: {}", err),
+        Ok(v) => panic!("expected unknown schema type, got: {:?}", v.is_some()),
+    }
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn service_without_registries_has_none() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = service_root(&bmc, None).await;
+
+    assert!(root.message_registries("en").await?.is_none());
+    bmc.assert_consumed();
+
+    Ok(())
+}
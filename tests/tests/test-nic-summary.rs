@@ -0,0 +1,358 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for per-port NIC summary.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::mac_address::MacAddrParseError;
+use nv_redfish::nic_summary::LinkStatus;
+use nv_redfish::nic_summary::NicPort;
+use nv_redfish::nic_summary::NicSummary;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::anonymous_1_9_service_root;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const CHASSIS_COLLECTION_DATA_TYPE: &str = "#ChassisCollection.ChassisCollection";
+const CHASSIS_DATA_TYPE: &str = "#Chassis.v1_23_0.Chassis";
+const SYSTEM_COLLECTION_DATA_TYPE: &str = "#ComputerSystemCollection.ComputerSystemCollection";
+const SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_20_0.ComputerSystem";
+const NETWORK_ADAPTER_COLLECTION_DATA_TYPE: &str =
+    "#NetworkAdapterCollection.NetworkAdapterCollection";
+const NETWORK_ADAPTER_DATA_TYPE: &str = "#NetworkAdapter.v1_9_0.NetworkAdapter";
+const NETWORK_DEVICE_FUNCTION_COLLECTION_DATA_TYPE: &str =
+    "#NetworkDeviceFunctionCollection.NetworkDeviceFunctionCollection";
+const NETWORK_DEVICE_FUNCTION_DATA_TYPE: &str =
+    "#NetworkDeviceFunction.v1_9_0.NetworkDeviceFunction";
+const PORT_COLLECTION_DATA_TYPE: &str = "#PortCollection.PortCollection";
+const PORT_DATA_TYPE: &str = "#Port.v1_11_0.Port";
+const PCIE_DEVICE_COLLECTION_DATA_TYPE: &str = "#PCIeDeviceCollection.PCIeDeviceCollection";
+const PCIE_DEVICE_DATA_TYPE: &str = "#PCIeDevice.v1_11_0.PCIeDevice";
+const ETHERNET_INTERFACE_COLLECTION_DATA_TYPE: &str =
+    "#EthernetInterfaceCollection.EthernetInterfaceCollection";
+const ETHERNET_INTERFACE_DATA_TYPE: &str = "#EthernetInterface.v1_10_0.EthernetInterface";
+
+#[test]
+async fn summary_merges_adapters_functions_and_interfaces() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let root = expect_service_root(bmc.clone(), &ids).await?;
+
+    bmc.expect(collection(
+        &ids.systems_id,
+        SYSTEM_COLLECTION_DATA_TYPE,
+        &[&ids.system_id],
+    ));
+    bmc.expect(Expect::get(
+        &ids.system_id,
+        json!({
+            ODATA_ID: &ids.system_id,
+            ODATA_TYPE: SYSTEM_DATA_TYPE,
+            "Id": "1",
+            "Name": "System",
+            "EthernetInterfaces": { ODATA_ID: &ids.interfaces_id }
+        }),
+    ));
+    let systems = root.systems().await?.unwrap().members().await?;
+
+    bmc.expect(collection(
+        &ids.chassis_collection_id,
+        CHASSIS_COLLECTION_DATA_TYPE,
+        &[&ids.chassis_id],
+    ));
+    bmc.expect(Expect::get(
+        &ids.chassis_id,
+        json!({
+            ODATA_ID: &ids.chassis_id,
+            ODATA_TYPE: CHASSIS_DATA_TYPE,
+            "Id": "1",
+            "Name": "Chassis",
+            "ChassisType": "RackMount",
+            "NetworkAdapters": { ODATA_ID: &ids.adapters_id },
+            "PCIeDevices": { ODATA_ID: &ids.pcie_devices_id }
+        }),
+    ));
+    let chassis = root.chassis().await?.unwrap().members().await?;
+
+    // Adapter doesn't report firmware: it is taken from PCIe device.
+    bmc.expect(collection(
+        &ids.adapters_id,
+        NETWORK_ADAPTER_COLLECTION_DATA_TYPE,
+        &[&ids.adapter_id],
+    ));
+    bmc.expect(Expect::get(
+        &ids.adapter_id,
+        json!({
+            ODATA_ID: &ids.adapter_id,
+            ODATA_TYPE: NETWORK_ADAPTER_DATA_TYPE,
+            "Id": "NIC1",
+            "Name": "Network Adapter",
+            "Manufacturer": "NVIDIA",
+            "Model": "ConnectX-7",
+            "Controllers": [{
+                "Links": {
+                    "PCIeDevices": [{ ODATA_ID: &ids.pcie_device_id }]
+                }
+            }],
+            "NetworkDeviceFunctions": { ODATA_ID: &ids.functions_id },
+            "Ports": { ODATA_ID: &ids.ports_id }
+        }),
+    ));
+    bmc.expect(collection(
+        &ids.pcie_devices_id,
+        PCIE_DEVICE_COLLECTION_DATA_TYPE,
+        &[&ids.pcie_device_id],
+    ));
+    bmc.expect(Expect::get(
+        &ids.pcie_device_id,
+        json!({
+            ODATA_ID: &ids.pcie_device_id,
+            ODATA_TYPE: PCIE_DEVICE_DATA_TYPE,
+            "Id": "NIC1",
+            "Name": "PCIe Device",
+            "FirmwareVersion": "28.39.1002"
+        }),
+    ));
+    // Link of the second port is down. The third function is not
+    // assigned to a port and its link status is unknown.
+    let port_ids = [format!("{}/0", ids.ports_id), format!("{}/1", ids.ports_id)];
+    bmc.expect(collection(
+        &ids.ports_id,
+        PORT_COLLECTION_DATA_TYPE,
+        &[&port_ids[0], &port_ids[1]],
+    ));
+    for (port_id, link_status) in port_ids.iter().zip(["LinkUp", "LinkDown"]) {
+        bmc.expect(Expect::get(
+            port_id,
+            json!({
+                ODATA_ID: port_id,
+                ODATA_TYPE: PORT_DATA_TYPE,
+                "Id": "0",
+                "Name": "Port",
+                "LinkStatus": link_status
+            }),
+        ));
+    }
+    let function_ids = [
+        format!("{}/0", ids.functions_id),
+        format!("{}/1", ids.functions_id),
+        format!("{}/2", ids.functions_id),
+    ];
+    bmc.expect(collection(
+        &ids.functions_id,
+        NETWORK_DEVICE_FUNCTION_COLLECTION_DATA_TYPE,
+        &[&function_ids[0], &function_ids[1], &function_ids[2]],
+    ));
+    for (function_id, mac, port_id) in [
+        (&function_ids[0], "B8:3F:D2:00:00:01", Some(&port_ids[0])),
+        (&function_ids[1], "B8:3F:D2:00:00:02", Some(&port_ids[1])),
+        (&function_ids[2], "B8:3F:D2:00:00:03", None),
+    ]
+    .iter()
+    {
+        let links = port_id.map_or_else(
+            || json!({}),
+            |port_id| json!({ "PhysicalPortAssignment": { ODATA_ID: port_id } }),
+        );
+        bmc.expect(Expect::get(
+            function_id,
+            json!({
+                ODATA_ID: function_id,
+                ODATA_TYPE: NETWORK_DEVICE_FUNCTION_DATA_TYPE,
+                "Id": "0",
+                "Name": "Network Device Function",
+                "Ethernet": { "PermanentMACAddress": mac },
+                "Links": links
+            }),
+        ));
+    }
+
+    // First interface is attached to the first port of the adapter.
+    // Second one is on-board NIC that is not described by adapters.
+    let interface_ids = [
+        format!("{}/eth0", ids.interfaces_id),
+        format!("{}/eth1", ids.interfaces_id),
+    ];
+    bmc.expect(collection(
+        &ids.interfaces_id,
+        ETHERNET_INTERFACE_COLLECTION_DATA_TYPE,
+        &[&interface_ids[0], &interface_ids[1]],
+    ));
+    bmc.expect(Expect::get(
+        &interface_ids[0],
+        ethernet_interface(&interface_ids[0], "b8-3f-d2-00-00-01", "LinkUp", 400_000),
+    ));
+    bmc.expect(Expect::get(
+        &interface_ids[1],
+        ethernet_interface(&interface_ids[1], "3c:ec:ef:00:00:0", "LinkDown", 1000),
+    ));
+
+    let summary = NicSummary::collect(&systems, &chassis).await?;
+    let invalid_mac = MacAddrParseError {
+        value: "3c:ec:ef:00:00:0".into(),
+    };
+    let adapter_port = NicPort {
+        adapter: Some(ids.adapter_id.clone().into()),
+        manufacturer: Some("NVIDIA".into()),
+        model: Some("ConnectX-7".into()),
+        firmware_version: Some("28.39.1002".into()),
+        ..NicPort::default()
+    };
+    assert_eq!(
+        summary.ports,
+        vec![
+            NicPort {
+                port: Some(port_ids[0].clone().into()),
+                network_device_function: Some(function_ids[0].clone().into()),
+                ethernet_interface: Some(interface_ids[0].clone().into()),
+                system: Some(ids.system_id.clone().into()),
+                mac_address: Some(Ok("b8:3f:d2:00:00:01".parse()?)),
+                permanent_mac_address: Some(Ok("b8:3f:d2:00:00:01".parse()?)),
+                speed_mbps: Some(400_000),
+                link_status: Some(LinkStatus::LinkUp),
+                ..adapter_port.clone()
+            },
+            NicPort {
+                port: Some(port_ids[1].clone().into()),
+                network_device_function: Some(function_ids[1].clone().into()),
+                permanent_mac_address: Some(Ok("b8:3f:d2:00:00:02".parse()?)),
+                link_status: Some(LinkStatus::LinkDown),
+                ..adapter_port.clone()
+            },
+            NicPort {
+                network_device_function: Some(function_ids[2].clone().into()),
+                permanent_mac_address: Some(Ok("b8:3f:d2:00:00:03".parse()?)),
+                ..adapter_port
+            },
+            NicPort {
+                ethernet_interface: Some(interface_ids[1].clone().into()),
+                system: Some(ids.system_id.clone().into()),
+                // Malformed MAC address is kept as reported.
+                mac_address: Some(Err(invalid_mac.clone())),
+                permanent_mac_address: Some(Err(invalid_mac)),
+                speed_mbps: Some(1000),
+                link_status: Some(LinkStatus::LinkDown),
+                ..NicPort::default()
+            },
+        ]
+    );
+    assert_eq!(
+        summary
+            .link_down()
+            .filter_map(|port| port.port.as_ref().or(port.ethernet_interface.as_ref()))
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        vec![port_ids[1].clone(), interface_ids[1].clone()]
+    );
+
+    Ok(())
+}
+
+async fn expect_service_root(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        anonymous_1_9_service_root(
+            &ids.root_id,
+            json!({
+                "Systems": { ODATA_ID: &ids.systems_id },
+                "Chassis": { ODATA_ID: &ids.chassis_collection_id }
+            }),
+        ),
+    ));
+    ServiceRoot::new(bmc).await.map_err(Into::into)
+}
+
+fn collection(id: &str, data_type: &str, members: &[&String]) -> Expect {
+    let members = members
+        .iter()
+        .map(|member| json!({ ODATA_ID: member }))
+        .collect::<Vec<_>>();
+    Expect::get(
+        id,
+        json!({
+            ODATA_ID: id,
+            ODATA_TYPE: data_type,
+            "Name": "Collection",
+            "Members": members
+        }),
+    )
+}
+
+fn ethernet_interface(id: &str, mac: &str, link_status: &str, speed_mbps: i64) -> Value {
+    json!({
+        ODATA_ID: id,
+        ODATA_TYPE: ETHERNET_INTERFACE_DATA_TYPE,
+        "Id": "eth",
+        "Name": "Ethernet Interface",
+        "MACAddress": mac,
+        "PermanentMACAddress": mac,
+        "LinkStatus": link_status,
+        "SpeedMbps": speed_mbps
+    })
+}
+
+struct Ids {
+    root_id: ODataId,
+    systems_id: String,
+    system_id: String,
+    interfaces_id: String,
+    chassis_collection_id: String,
+    chassis_id: String,
+    adapters_id: String,
+    adapter_id: String,
+    functions_id: String,
+    ports_id: String,
+    pcie_devices_id: String,
+    pcie_device_id: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let systems_id = format!("{root_id}/Systems");
+    let system_id = format!("{systems_id}/1");
+    let interfaces_id = format!("{system_id}/EthernetInterfaces");
+    let chassis_collection_id = format!("{root_id}/Chassis");
+    let chassis_id = format!("{chassis_collection_id}/1");
+    let adapters_id = format!("{chassis_id}/NetworkAdapters");
+    let adapter_id = format!("{adapters_id}/NIC1");
+    let functions_id = format!("{adapter_id}/NetworkDeviceFunctions");
+    let ports_id = format!("{adapter_id}/Ports");
+    let pcie_devices_id = format!("{chassis_id}/PCIeDevices");
+    let pcie_device_id = format!("{pcie_devices_id}/NIC1");
+    Ids {
+        root_id,
+        systems_id,
+        system_id,
+        interfaces_id,
+        chassis_collection_id,
+        chassis_id,
+        adapters_id,
+        adapter_id,
+        functions_id,
+        ports_id,
+        pcie_devices_id,
+        pcie_device_id,
+    }
+}
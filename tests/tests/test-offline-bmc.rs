@@ -0,0 +1,174 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for offline BMC backed by captured data.
+
+use std::error::Error as StdError;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use nv_redfish::core::NullPolicy;
+use nv_redfish::resource::ResetType;
+use nv_redfish::NvBmcBuilder;
+use nv_redfish::Resource;
+use nv_redfish::ServiceRoot;
+use nv_redfish_bmc_mock::offline::OfflineError;
+use nv_redfish_bmc_mock::InventorySnapshot;
+use nv_redfish_bmc_mock::OfflineBmc;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const SYSTEM_COLLECTION_DATA_TYPE: &str = "#ComputerSystemCollection.ComputerSystemCollection";
+const SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_20_0.ComputerSystem";
+
+#[test]
+async fn offline_bmc_serves_snapshot_and_rejects_writes() -> Result<(), Box<dyn StdError>> {
+    let snapshot = captured_resources(false)
+        .into_iter()
+        .collect::<InventorySnapshot>();
+    let root = ServiceRoot::new(Arc::new(OfflineBmc::from_snapshot(snapshot))).await?;
+
+    let systems = root.systems().await?.ok_or("missing systems")?;
+    let mut members = systems.members().await?;
+    let system = members.pop().ok_or("missing system")?;
+    assert_eq!(system.id().to_string(), "System-1");
+    assert_eq!(
+        system.hardware_id().serial_number.map(|v| v.to_string()),
+        Some("SN-0001".to_string())
+    );
+
+    assert!(matches!(
+        system.reset(Some(ResetType::ForceRestart)).await,
+        Err(nv_redfish::Error::Bmc(OfflineError::ReadOnly))
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn offline_bmc_expands_collections_from_mockup_dir() -> Result<(), Box<dyn StdError>> {
+    let dir = std::env::temp_dir().join(format!("nv-redfish-offline-{}", std::process::id()));
+    for (id, payload) in captured_resources(true) {
+        write_mockup_resource(&dir, &id, &payload)?;
+    }
+    let root = ServiceRoot::new(Arc::new(OfflineBmc::from_mockup_dir(&dir))).await?;
+
+    let systems = root.systems().await?.ok_or("missing systems")?;
+    let names = systems
+        .members()
+        .await?
+        .iter()
+        .map(|system| system.name().to_string())
+        .collect::<Vec<_>>();
+    fs::remove_dir_all(&dir)?;
+
+    assert_eq!(names, vec!["Captured System".to_string()]);
+
+    Ok(())
+}
+
+#[test]
+async fn offline_bmc_follows_null_policy_of_builder() -> Result<(), Box<dyn StdError>> {
+    let snapshot = captured_resources(false)
+        .into_iter()
+        .map(|(id, mut payload)| {
+            if id.ends_with("/System-1") {
+                payload["Status"] = Value::Null;
+            }
+            (id, payload)
+        })
+        .collect::<InventorySnapshot>();
+    let bmc = Arc::new(OfflineBmc::from_snapshot(snapshot));
+
+    let root = ServiceRoot::with_bmc(NvBmcBuilder::new(bmc.clone()).build()).await?;
+    let systems = root.systems().await?.ok_or("missing systems")?;
+    assert_eq!(systems.members().await?.len(), 1);
+
+    let root = ServiceRoot::with_bmc(
+        NvBmcBuilder::new(bmc)
+            .null_policy(NullPolicy::Strict)
+            .build(),
+    )
+    .await?;
+    let systems = root.systems().await?.ok_or("missing systems")?;
+    assert!(matches!(
+        systems.members().await,
+        Err(nv_redfish::Error::Bmc(OfflineError::BadResource(..)))
+    ));
+
+    Ok(())
+}
+
+fn captured_resources(expand: bool) -> Vec<(String, Value)> {
+    let root_id = "/redfish/v1";
+    let systems_id = format!("{root_id}/Systems");
+    let system_id = format!("{systems_id}/System-1");
+    let mut root = json!({
+        ODATA_ID: root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "Systems": { ODATA_ID: &systems_id },
+        "Links": {
+            "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+        }
+    });
+    if expand {
+        root["ProtocolFeaturesSupported"] = json!({ "ExpandQuery": { "NoLinks": true } });
+    }
+    vec![
+        (root_id.to_string(), root),
+        (
+            systems_id.clone(),
+            json!({
+                ODATA_ID: &systems_id,
+                ODATA_TYPE: SYSTEM_COLLECTION_DATA_TYPE,
+                "Id": "Systems",
+                "Name": "Computer System Collection",
+                "Members": [{ ODATA_ID: &system_id }]
+            }),
+        ),
+        (
+            system_id.clone(),
+            json!({
+                ODATA_ID: &system_id,
+                ODATA_TYPE: SYSTEM_DATA_TYPE,
+                "Id": "System-1",
+                "Name": "Captured System",
+                "SerialNumber": "SN-0001",
+                "Actions": {
+                    "#ComputerSystem.Reset": {
+                        "target": format!("{system_id}/Actions/ComputerSystem.Reset")
+                    }
+                }
+            }),
+        ),
+    ]
+}
+
+fn write_mockup_resource(dir: &Path, id: &str, payload: &Value) -> Result<(), Box<dyn StdError>> {
+    let resource_dir = dir.join(id.trim_start_matches('/'));
+    fs::create_dir_all(&resource_dir)?;
+    fs::write(
+        resource_dir.join("index.json"),
+        serde_json::to_vec(payload)?,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,105 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests for probing BMC.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::schema::computer_system::ComputerSystem;
+use nv_redfish::ProbingBmc;
+use nv_redfish::ProbingError;
+use nv_redfish_core::AllowedMethods;
+use nv_redfish_core::Bmc as _;
+use nv_redfish_core::HttpMethod;
+use nv_redfish_core::ModificationResponse;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const COMPUTER_SYSTEM_DATA_TYPE: &str = "#ComputerSystem.v1_20_0.ComputerSystem";
+
+#[test]
+async fn probing_bmc_rejects_operations_declared_unsupported() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let probing = ProbingBmc::new(bmc.clone());
+    let system_id = ODataId::from("/redfish/v1/Systems/1".to_string());
+    let reset_id = ODataId::from(format!("{system_id}/Actions/ComputerSystem.Reset"));
+
+    bmc.expect(Expect::allowed_methods(
+        &system_id,
+        Some("GET, HEAD, PATCH"),
+    ));
+    bmc.expect(Expect::get(
+        &system_id,
+        json!({
+            ODATA_ID: &system_id,
+            ODATA_TYPE: COMPUTER_SYSTEM_DATA_TYPE,
+            "Id": "1",
+            "Name": "System",
+            "Actions": {
+                "#ComputerSystem.Reset": {
+                    "target": &reset_id,
+                    "@Redfish.OperationApplyTimeSupport": {
+                        "SupportedValues": ["OnReset"]
+                    }
+                },
+                "Oem": {}
+            }
+        }),
+    ));
+    let capabilities = probing.probe(&system_id).await?;
+    assert_eq!(
+        capabilities.methods,
+        Some(AllowedMethods::from_header("GET, PATCH"))
+    );
+    assert_eq!(capabilities.apply_times, None);
+    assert_eq!(
+        probing
+            .capabilities(&reset_id)
+            .and_then(|capabilities| capabilities.apply_times),
+        Some(vec!["OnReset".to_string()])
+    );
+
+    // Nothing is expected by the mock: request must not be sent.
+    assert!(matches!(
+        probing.delete::<ComputerSystem>(&system_id).await,
+        Err(ProbingError::NotSupportedByEndpoint {
+            method: HttpMethod::Delete,
+            apply_time: None,
+            ..
+        })
+    ));
+    let immediate = json!({ "@Redfish.OperationApplyTime": "Immediate" });
+    assert!(matches!(
+        probing.create::<_, Value>(&reset_id, &immediate).await,
+        Err(ProbingError::NotSupportedByEndpoint {
+            method: HttpMethod::Post,
+            apply_time: Some(apply_time),
+            ..
+        }) if apply_time == "Immediate"
+    ));
+
+    let on_reset = json!({ "@Redfish.OperationApplyTime": "OnReset" });
+    bmc.expect(Expect::create(&reset_id, &on_reset, json!({})));
+    let response = probing.create::<_, Value>(&reset_id, &on_reset).await?;
+    assert!(matches!(response, ModificationResponse::Entity(_)));
+
+    Ok(())
+}
@@ -0,0 +1,280 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests of out-of-box provisioning.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::account::ServiceAccountPasswordPolicy;
+use nv_redfish::manager::Manager;
+use nv_redfish::manager::ManagerNetworkProtocolUpdate;
+use nv_redfish::provision::ProvisionPlan;
+use nv_redfish::provision::ProvisionStepKind;
+use nv_redfish::provision::StepOutcome;
+use nv_redfish::provision::StepReport;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::anonymous_1_9_service_root;
+use nv_redfish_tests::json_merge;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use tokio::test;
+
+const MANAGER_COLLECTION_DATA_TYPE: &str = "#ManagerCollection.ManagerCollection";
+const MANAGER_DATA_TYPE: &str = "#Manager.v1_16_0.Manager";
+const MANAGER_NETWORK_PROTOCOL_DATA_TYPE: &str =
+    "#ManagerNetworkProtocol.v1_5_0.ManagerNetworkProtocol";
+const ACCOUNT_SERVICE_DATA_TYPE: &str = "#AccountService.v1_5_0.AccountService";
+const ACCOUNTS_DATA_TYPE: &str = "#ManagerAccountCollection.ManagerAccountCollection";
+const MANAGER_ACCOUNT_DATA_TYPE: &str = "#ManagerAccount.v1_3_0.ManagerAccount";
+
+#[test]
+async fn provision_applies_steps_and_detects_existing_service_account(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let (root, manager) = get_root_and_manager(bmc.clone(), &ids).await?;
+    let plan = ProvisionPlan::new()
+        .change_password("root".into(), "N3w-Passw0rd!".into())
+        .network_protocol(
+            ManagerNetworkProtocolUpdate::builder()
+                .with_host_name("bmc-r12-u07".into())
+                .build(),
+        )
+        .service_account(
+            "automation".into(),
+            "Administrator".into(),
+            ServiceAccountPasswordPolicy::NeverExpires("Aut0-Passw0rd!".into()),
+        );
+
+    expect_accounts(&bmc, &ids, &["root", "automation"]);
+    bmc.expect(Expect::update(
+        format!("{}/1", ids.accounts_id),
+        json!({ "Password": "N3w-Passw0rd!" }),
+        account_payload(&ids, 1, "root"),
+    ));
+    bmc.expect(Expect::get(
+        &ids.network_protocol_id,
+        network_protocol_payload(&ids, json!({})),
+    ));
+    bmc.expect(Expect::update(
+        &ids.network_protocol_id,
+        json!({ "HostName": "bmc-r12-u07" }),
+        network_protocol_payload(&ids, json!({ "HostName": "bmc-r12-u07" })),
+    ));
+    expect_accounts(&bmc, &ids, &["root", "automation"]);
+
+    let report = plan.run(&root, &manager).await;
+
+    assert!(report.is_complete());
+    assert_eq!(
+        report.steps,
+        vec![
+            StepReport {
+                step: ProvisionStepKind::ChangePassword,
+                outcome: StepOutcome::Applied,
+            },
+            StepReport {
+                step: ProvisionStepKind::NetworkProtocol,
+                outcome: StepOutcome::Applied,
+            },
+            StepReport {
+                step: ProvisionStepKind::ServiceAccount,
+                outcome: StepOutcome::AlreadyApplied,
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn provision_stops_at_failed_step_and_resumes() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let ids = ids();
+    let (root, manager) = get_root_and_manager(bmc.clone(), &ids).await?;
+    let plan = ProvisionPlan::new()
+        .change_password("admin".into(), "N3w-Passw0rd!".into())
+        .service_account(
+            "automation".into(),
+            "Administrator".into(),
+            ServiceAccountPasswordPolicy::NeverExpires("Aut0-Passw0rd!".into()),
+        );
+
+    expect_accounts(&bmc, &ids, &["root"]);
+
+    let report = plan.run(&root, &manager).await;
+
+    assert!(!report.is_complete());
+    assert_eq!(report.completed_steps(), 0);
+    assert!(matches!(
+        &report.failure,
+        Some((
+            ProvisionStepKind::ChangePassword,
+            nv_redfish::Error::ProvisionAccountNotFound(user_name)
+        )) if user_name == "admin"
+    ));
+
+    expect_accounts(&bmc, &ids, &["root"]);
+    expect_accounts(&bmc, &ids, &["root"]);
+    bmc.expect(Expect::create(
+        &ids.accounts_id,
+        json!({
+            "UserName": "automation",
+            "Password": "Aut0-Passw0rd!",
+            "RoleId": "Administrator",
+            "AccountTypes": ["Redfish"],
+            "PasswordChangeRequired": false
+        }),
+        account_payload(&ids, 2, "automation"),
+    ));
+
+    let report = plan.resume(&root, &manager, 1).await;
+
+    assert!(report.is_complete());
+    assert_eq!(
+        report
+            .steps
+            .iter()
+            .map(|report| report.outcome)
+            .collect::<Vec<_>>(),
+        vec![StepOutcome::Skipped, StepOutcome::Applied]
+    );
+
+    Ok(())
+}
+
+struct Ids {
+    root_id: ODataId,
+    managers_id: String,
+    manager_id: String,
+    network_protocol_id: String,
+    account_service_id: String,
+    accounts_id: String,
+}
+
+fn ids() -> Ids {
+    let root_id = ODataId::service_root();
+    let managers_id = format!("{root_id}/Managers");
+    let manager_id = format!("{managers_id}/1");
+    let network_protocol_id = format!("{manager_id}/NetworkProtocol");
+    let account_service_id = format!("{root_id}/AccountService");
+    let accounts_id = format!("{account_service_id}/Accounts");
+    Ids {
+        root_id,
+        managers_id,
+        manager_id,
+        network_protocol_id,
+        account_service_id,
+        accounts_id,
+    }
+}
+
+async fn get_root_and_manager(
+    bmc: Arc<Bmc>,
+    ids: &Ids,
+) -> Result<(ServiceRoot<Bmc>, Manager<Bmc>), Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        &ids.root_id,
+        anonymous_1_9_service_root(
+            &ids.root_id,
+            json!({
+                "Managers": { ODATA_ID: &ids.managers_id },
+                "AccountService": { ODATA_ID: &ids.account_service_id }
+            }),
+        ),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+    bmc.expect(Expect::get(
+        &ids.managers_id,
+        json!({
+            ODATA_ID: &ids.managers_id,
+            ODATA_TYPE: MANAGER_COLLECTION_DATA_TYPE,
+            "Id": "Managers",
+            "Name": "Manager Collection",
+            "Members": [{
+                ODATA_ID: &ids.manager_id,
+                ODATA_TYPE: MANAGER_DATA_TYPE,
+                "Id": "1",
+                "Name": "Manager",
+                "Status": { "State": "Enabled" },
+                "NetworkProtocol": { ODATA_ID: &ids.network_protocol_id }
+            }]
+        }),
+    ));
+    let manager = root
+        .managers()
+        .await?
+        .ok_or_else(|| std::io::Error::other("missing managers"))?
+        .members()
+        .await?
+        .pop()
+        .ok_or_else(|| std::io::Error::other("missing manager"))?;
+    Ok((root, manager))
+}
+
+fn expect_accounts(bmc: &Bmc, ids: &Ids, user_names: &[&str]) {
+    bmc.expect(Expect::get(
+        &ids.account_service_id,
+        json!({
+            ODATA_ID: &ids.account_service_id,
+            ODATA_TYPE: ACCOUNT_SERVICE_DATA_TYPE,
+            "Id": "AccountService",
+            "Name": "Account Service",
+            "Accounts": { ODATA_ID: &ids.accounts_id }
+        }),
+    ));
+    let members = (1..)
+        .zip(user_names)
+        .map(|(id, user_name)| account_payload(ids, id, user_name))
+        .collect::<Vec<_>>();
+    bmc.expect(Expect::get(
+        &ids.accounts_id,
+        json!({
+            ODATA_ID: &ids.accounts_id,
+            ODATA_TYPE: ACCOUNTS_DATA_TYPE,
+            "Name": "User Accounts",
+            "Members": members
+        }),
+    ));
+}
+
+fn account_payload(ids: &Ids, id: u32, user_name: &str) -> Value {
+    json!({
+        ODATA_ID: format!("{}/{id}", ids.accounts_id),
+        ODATA_TYPE: MANAGER_ACCOUNT_DATA_TYPE,
+        "Id": id.to_string(),
+        "Name": "User Account",
+        "UserName": user_name,
+        "RoleId": "Administrator",
+        "AccountTypes": ["Redfish"]
+    })
+}
+
+fn network_protocol_payload(ids: &Ids, fields: Value) -> Value {
+    json_merge([
+        &json!({
+            ODATA_ID: &ids.network_protocol_id,
+            ODATA_TYPE: MANAGER_NETWORK_PROTOCOL_DATA_TYPE,
+            "Id": "NetworkProtocol",
+            "Name": "Manager Network Protocol"
+        }),
+        &fields,
+    ])
+}
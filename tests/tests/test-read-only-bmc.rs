@@ -0,0 +1,124 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for read-only BMC.
+
+use std::error::Error as StdError;
+use std::sync::Arc;
+
+use nv_redfish::Error;
+use nv_redfish::ReadOnlyBmc;
+use nv_redfish::ReadOnlyError;
+use nv_redfish::Resource as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_13_0.ServiceRoot";
+const ACCOUNT_SERVICE_DATA_TYPE: &str = "#AccountService.v1_5_0.AccountService";
+const ACCOUNTS_DATA_TYPE: &str = "#ManagerAccountCollection.ManagerAccountCollection";
+const MANAGER_ACCOUNT_DATA_TYPE: &str = "#ManagerAccount.v1_3_0.ManagerAccount";
+
+#[test]
+async fn read_only_bmc_reads_and_rejects_modifications() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service_id = format!("{root_id}/AccountService");
+    let accounts_id = format!("{account_service_id}/Accounts");
+
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "ProtocolFeaturesSupported": {
+                "ExpandQuery": {
+                    "NoLinks": true
+                }
+            },
+            "AccountService": {
+                ODATA_ID: &account_service_id,
+            },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: format!("{root_id}/SessionService/Sessions"),
+                }
+            },
+        }),
+    ));
+    let root = ServiceRoot::new(Arc::new(ReadOnlyBmc::new(bmc.clone()))).await?;
+
+    bmc.expect(Expect::get(
+        &account_service_id,
+        json!({
+            ODATA_ID: &account_service_id,
+            ODATA_TYPE: ACCOUNT_SERVICE_DATA_TYPE,
+            "Id": "AccountService",
+            "Name": "AccountService",
+            "Accounts": {
+                ODATA_ID: &accounts_id,
+            },
+        }),
+    ));
+    let account_service = root
+        .account_service()
+        .await?
+        .ok_or("missing account service")?;
+
+    bmc.expect(Expect::expand(
+        &accounts_id,
+        json!({
+            ODATA_ID: &accounts_id,
+            ODATA_TYPE: ACCOUNTS_DATA_TYPE,
+            "Name": "User Accounts",
+            "Members": [{
+                ODATA_ID: format!("{accounts_id}/1"),
+                ODATA_TYPE: MANAGER_ACCOUNT_DATA_TYPE,
+                "Id": "1",
+                "Name": "User Account",
+                "UserName": "Administrator",
+                "RoleId": "Administrator",
+                "AccountTypes": ["Redfish"]
+            }],
+        }),
+    ));
+    let accounts = account_service
+        .accounts()
+        .await?
+        .ok_or("missing accounts")?;
+    let mut accounts = accounts.all_accounts_data().await?;
+    let account = accounts.pop().ok_or("missing account")?;
+    assert_eq!(account.id().to_string(), "1");
+
+    // No expectations are queued: rejected operations must not reach
+    // the BMC.
+    assert!(matches!(
+        account.update_password("password".into()).await,
+        Err(Error::Bmc(ReadOnlyError::ReadOnlyMode))
+    ));
+    assert!(matches!(
+        account.delete().await,
+        Err(Error::Bmc(ReadOnlyError::ReadOnlyMode))
+    ));
+
+    Ok(())
+}
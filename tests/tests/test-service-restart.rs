@@ -0,0 +1,191 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for Redfish service restart detection.
+
+use nv_redfish::service_restart::RestartDetectingBmc;
+use nv_redfish::service_restart::RestartEvidence;
+use nv_redfish::service_restart::RestartMonitor;
+use nv_redfish::service_restart::ServiceEvent;
+use nv_redfish::session_service::SessionCreate;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::EdmGuid;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use std::sync::Mutex;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+const SESSION_DATA_TYPE: &str = "#Session.v1_5_0.Session";
+const UUID_BEFORE: &str = "92384634-2938-2342-8820-489239905423";
+const UUID_AFTER: &str = "c5b1a3d2-0e57-4a43-9f7c-3a1d8e6f2b10";
+
+#[test]
+async fn check_without_restart_reports_nothing() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_BEFORE)));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    let monitor = RestartMonitor::new(&root);
+    let mut events = monitor.subscribe();
+
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_BEFORE)));
+    assert!(monitor.check().await?.is_none());
+    assert!(events.try_next().is_err());
+
+    Ok(())
+}
+
+#[test]
+async fn changed_uuid_renews_session_and_notifies() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let sessions_id = format!("{root_id}/SessionService/Sessions");
+    let session_id = format!("{sessions_id}/renewed");
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_BEFORE)));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    let token = Arc::new(Mutex::new(None));
+    let installed = token.clone();
+    let monitor = RestartMonitor::new(&root).with_session_renewal(session_create(), move |t| {
+        *installed.lock().expect("not poisoned") = Some(t.to_string());
+    });
+    let mut events = monitor.subscribe();
+
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_AFTER)));
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_AFTER)));
+    bmc.expect(Expect::create_session(
+        &sessions_id,
+        serde_json::to_value(session_create())?,
+        json!({
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "renewed",
+            "Name": "User Session",
+            "UserName": "Administrator"
+        }),
+        "renewed-token",
+        &session_id,
+    ));
+
+    let new_root = monitor.check().await?.expect("restart must be detected");
+    assert_eq!(
+        new_root.root.uuid.as_ref().map(ToString::to_string),
+        Some(UUID_AFTER.to_string())
+    );
+    assert_eq!(
+        token.lock().expect("not poisoned").as_deref(),
+        Some("renewed-token")
+    );
+    assert_eq!(
+        events.try_next()?,
+        Some(ServiceEvent::ServiceRestarted(
+            RestartEvidence::UuidChanged {
+                previous: Some(UUID_BEFORE.parse::<EdmGuid>()?),
+                current: Some(UUID_AFTER.parse::<EdmGuid>()?),
+            }
+        ))
+    );
+
+    // New UUID is remembered.
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_AFTER)));
+    assert!(monitor.check().await?.is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn rejected_session_is_renewed_automatically() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let sessions_id = format!("{root_id}/SessionService/Sessions");
+    let session_id = format!("{sessions_id}/renewed");
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_BEFORE)));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    let token = Arc::new(Mutex::new(None));
+    let installed = token.clone();
+    let monitor = Arc::new(RestartMonitor::new(&root).with_session_renewal(
+        session_create(),
+        move |t| {
+            *installed.lock().expect("not poisoned") = Some(t.to_string());
+        },
+    ));
+    let mut events = monitor.subscribe();
+    let detecting = Arc::new(RestartDetectingBmc::new(monitor));
+
+    // Request is rejected, session is renewed and request is sent
+    // again.
+    bmc.expect(Expect::get_status(&root_id, 401));
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_BEFORE)));
+    bmc.expect(Expect::create_session(
+        &sessions_id,
+        serde_json::to_value(session_create())?,
+        json!({
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "renewed",
+            "Name": "User Session",
+            "UserName": "Administrator"
+        }),
+        "renewed-token",
+        &session_id,
+    ));
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, UUID_BEFORE)));
+
+    let root = ServiceRoot::new(detecting).await?;
+    assert_eq!(
+        root.root.uuid.as_ref().map(ToString::to_string),
+        Some(UUID_BEFORE.to_string())
+    );
+    assert_eq!(
+        token.lock().expect("not poisoned").as_deref(),
+        Some("renewed-token")
+    );
+    assert_eq!(
+        events.try_next()?,
+        Some(ServiceEvent::ServiceRestarted(
+            RestartEvidence::SessionRejected(401)
+        ))
+    );
+
+    Ok(())
+}
+
+fn session_create() -> SessionCreate {
+    SessionCreate::builder("Administrator".into(), "password".into()).build()
+}
+
+fn service_root(root_id: &ODataId, uuid: &str) -> Value {
+    json!({
+        ODATA_ID: root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "UUID": uuid,
+        "SessionService": { ODATA_ID: format!("{root_id}/SessionService") },
+        "Links": {
+            "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+        }
+    })
+}
@@ -0,0 +1,392 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Integration tests for ServiceRoot links.
+
+use nv_redfish::protocol_features::ExpandQueryFeatures;
+use nv_redfish::schema::event_service::EventService;
+use nv_redfish::schema::session_service::SessionService;
+use nv_redfish::schema::task_service::TaskService;
+use nv_redfish::BmcQuirks;
+use nv_redfish::NvBmcBuilder;
+use nv_redfish::ProtocolFeatures;
+use nv_redfish::Resource as _;
+use nv_redfish::ServiceRoot;
+use nv_redfish_core::LatencyClass;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+const CHASSIS_COLLECTION_DATA_TYPE: &str = "#ChassisCollection.ChassisCollection";
+const CHASSIS_DATA_TYPE: &str = "#Chassis.v1_23_0.Chassis";
+
+#[test]
+async fn service_root_exposes_service_link_ids() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let registries_id = format!("{root_id}/Registries");
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "Registries": { ODATA_ID: &registries_id },
+            "JsonSchemas": { ODATA_ID: format!("{root_id}/JsonSchemas") },
+            "SessionService": { ODATA_ID: SessionService::WELL_KNOWN_ODATA_ID },
+            "Tasks": { ODATA_ID: TaskService::WELL_KNOWN_ODATA_ID },
+            "EventService": { ODATA_ID: EventService::WELL_KNOWN_ODATA_ID },
+            "Links": {
+                "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+            }
+        }),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    assert_eq!(
+        root.registries_id().map(ToString::to_string),
+        Some(registries_id.clone())
+    );
+    assert_eq!(
+        root.json_schemas_id().map(ToString::to_string),
+        Some(format!("{root_id}/JsonSchemas"))
+    );
+    assert_eq!(
+        root.session_service_id().map(ToString::to_string),
+        Some(SessionService::WELL_KNOWN_ODATA_ID.to_string())
+    );
+    assert_eq!(
+        root.task_service_id().map(ToString::to_string),
+        Some(TaskService::WELL_KNOWN_ODATA_ID.to_string())
+    );
+    assert_eq!(
+        root.event_service_id().map(ToString::to_string),
+        Some(EventService::WELL_KNOWN_ODATA_ID.to_string())
+    );
+
+    let registries = json!({
+        ODATA_ID: &registries_id,
+        ODATA_TYPE: "#MessageRegistryFileCollection.MessageRegistryFileCollection",
+        "Name": "Registry File Collection",
+        "Members": [{ ODATA_ID: format!("{registries_id}/Base") }],
+        "Members@odata.count": 1
+    });
+    bmc.expect(Expect::get(&registries_id, registries.clone()));
+    let registries_id = root.registries_id().cloned().unwrap();
+    assert_eq!(root.raw_json(&registries_id).await?, registries);
+
+    Ok(())
+}
+
+#[test]
+async fn service_root_without_links_has_no_ids() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "Links": {
+                "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+            }
+        }),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    assert!(root.registries_id().is_none());
+    assert!(root.json_schemas_id().is_none());
+    assert!(root.session_service_id().is_none());
+    assert!(root.task_service_id().is_none());
+    assert!(root.event_service_id().is_none());
+
+    Ok(())
+}
+
+#[test]
+async fn available_modules_lists_compiled_features() {
+    let modules = ServiceRoot::<Bmc>::available_modules();
+
+    assert!(modules.contains(&"accounts"));
+    assert!(modules.contains(&"computer-systems"));
+    assert!(modules.contains(&"graphics-controllers"));
+    assert!(modules.contains(&"usb-controllers"));
+}
+
+#[test]
+async fn service_root_with_bmc_uses_explicit_configuration() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let chassis_collection_id = format!("{root_id}/Chassis");
+    let chassis_id = format!("{chassis_collection_id}/1");
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "ProtocolFeaturesSupported": {
+                "ExpandQuery": {
+                    "NoLinks": true
+                }
+            },
+            "Chassis": { ODATA_ID: &chassis_collection_id },
+            "Links": {
+                "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+            }
+        }),
+    ));
+    let root =
+        ServiceRoot::with_bmc(NvBmcBuilder::new(bmc.clone()).restrict_expand().build()).await?;
+
+    // Expand is advertised by the service but restricted by the
+    // builder, so collection and members are retrieved by GET.
+    bmc.expect(Expect::get(
+        &chassis_collection_id,
+        json!({
+            ODATA_ID: &chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Name": "Chassis Collection",
+            "Members": [{ ODATA_ID: &chassis_id }],
+            "Members@odata.count": 1
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &chassis_id,
+        json!({
+            ODATA_ID: &chassis_id,
+            ODATA_TYPE: CHASSIS_DATA_TYPE,
+            "Id": "1",
+            "Name": "Chassis",
+            "ChassisType": "RackMount"
+        }),
+    ));
+    let members = root
+        .chassis()
+        .await?
+        .ok_or("missing chassis collection")?
+        .members()
+        .await?;
+    assert_eq!(members.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+async fn protocol_features_report_query_capabilities() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "ProtocolFeaturesSupported": {
+                "ExpandQuery": {
+                    "ExpandAll": true,
+                    "Levels": true,
+                    "MaxLevels": 2,
+                    "NoLinks": true
+                },
+                "FilterQuery": true,
+                "OnlyMemberQuery": false
+            },
+            "Links": {
+                "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+            }
+        }),
+    ));
+    let root = ServiceRoot::new(bmc.clone()).await?;
+
+    let features = root.protocol_features();
+    assert!(features.supports_expand_levels(1));
+    assert!(features.supports_expand_levels(2));
+    assert!(!features.supports_expand_levels(3));
+    assert!(features.supports_filter());
+    assert!(!features.supports_only());
+    assert!(!features.supports_select());
+
+    let root = root.restrict_expand();
+    assert!(!root.protocol_features().supports_expand_levels(1));
+    assert!(root.protocol_features().supports_filter());
+
+    Ok(())
+}
+
+#[test]
+async fn expand_is_avoided_for_quirky_resource_class() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let chassis_collection_id = format!("{root_id}/Chassis");
+    let chassis_id = format!("{chassis_collection_id}/1");
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "Chassis": { ODATA_ID: &chassis_collection_id },
+            "Links": {
+                "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+            }
+        }),
+    ));
+    let nv_bmc = NvBmcBuilder::new(bmc.clone())
+        .protocol_features(ProtocolFeatures {
+            expand: ExpandQueryFeatures {
+                no_links: true,
+                ..ExpandQueryFeatures::default()
+            },
+            ..ProtocolFeatures::default()
+        })
+        .quirks(BmcQuirks::none().avoid_expand("Chassis"))
+        .build();
+    let root = ServiceRoot::with_bmc(nv_bmc).await?;
+
+    bmc.expect(Expect::get(
+        &chassis_collection_id,
+        json!({
+            ODATA_ID: &chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Name": "Chassis Collection",
+            "Members": [{ ODATA_ID: &chassis_id }],
+            "Members@odata.count": 1
+        }),
+    ));
+    bmc.expect(Expect::get(
+        &chassis_id,
+        json!({
+            ODATA_ID: &chassis_id,
+            ODATA_TYPE: CHASSIS_DATA_TYPE,
+            "Id": "1",
+            "Name": "Chassis",
+            "ChassisType": "RackMount"
+        }),
+    ));
+    let members = root
+        .chassis()
+        .await?
+        .ok_or("missing chassis collection")?
+        .members()
+        .await?;
+    assert_eq!(members.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+async fn chassis_members_are_fetched_with_fan_out_limit() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let chassis_collection_id = format!("{root_id}/Chassis");
+    bmc.expect(Expect::get(
+        &root_id,
+        json!({
+            ODATA_ID: &root_id,
+            ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+            "Id": "RootService",
+            "Name": "RootService",
+            "Chassis": { ODATA_ID: &chassis_collection_id },
+            "Links": {
+                "Sessions": { ODATA_ID: format!("{root_id}/SessionService/Sessions") }
+            }
+        }),
+    ));
+    let nv_bmc = NvBmcBuilder::new(bmc.clone()).fan_out_limit(4).build();
+    assert_eq!(nv_bmc.fan_out_limit(), 4);
+    let root = ServiceRoot::with_bmc(nv_bmc).await?;
+
+    let ids = ["1", "2", "3"];
+    bmc.expect(Expect::get(
+        &chassis_collection_id,
+        json!({
+            ODATA_ID: &chassis_collection_id,
+            ODATA_TYPE: CHASSIS_COLLECTION_DATA_TYPE,
+            "Name": "Chassis Collection",
+            "Members": ids
+                .iter()
+                .map(|id| json!({ ODATA_ID: format!("{chassis_collection_id}/{id}") }))
+                .collect::<Vec<_>>(),
+            "Members@odata.count": ids.len()
+        }),
+    ));
+    bmc.expect_sequence(ids.iter().map(|id| {
+        let chassis_id = format!("{chassis_collection_id}/{id}");
+        Expect::get(
+            &chassis_id,
+            json!({
+                ODATA_ID: &chassis_id,
+                ODATA_TYPE: CHASSIS_DATA_TYPE,
+                "Id": id,
+                "Name": "Chassis",
+                "ChassisType": "RackMount"
+            }),
+        )
+    }));
+    let members = root
+        .chassis()
+        .await?
+        .ok_or("missing chassis collection")?
+        .members()
+        .await?
+        .iter()
+        .map(|chassis| chassis.id().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(members, ids);
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn slow_bmc_is_accessed_sequentially() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let nv_bmc = NvBmcBuilder::new(bmc.clone())
+        .fan_out_limit(4)
+        .adapt_to_slow_bmc()
+        .build();
+    assert!(!nv_bmc.is_slow());
+    assert_eq!(nv_bmc.fan_out_limit(), 4);
+
+    bmc.set_latency_class(Some(LatencyClass::Slow));
+    assert!(nv_bmc.is_slow());
+    assert_eq!(nv_bmc.fan_out_limit(), 1);
+
+    // BMC gets configured behavior back when it recovers.
+    bmc.set_latency_class(Some(LatencyClass::Normal));
+    assert_eq!(nv_bmc.fan_out_limit(), 4);
+
+    // Without the policy classification is ignored.
+    bmc.set_latency_class(Some(LatencyClass::Slow));
+    let nv_bmc = NvBmcBuilder::new(bmc).fan_out_limit(4).build();
+    assert!(!nv_bmc.is_slow());
+    assert_eq!(nv_bmc.fan_out_limit(), 4);
+
+    Ok(())
+}
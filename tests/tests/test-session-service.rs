@@ -18,6 +18,8 @@
 use std::error::Error as StdError;
 use std::sync::Arc;
 
+use nv_redfish::bmc_http::BmcCredentials;
+use nv_redfish::schema::session_service::SessionService as SessionServiceSchema;
 use nv_redfish::session_service::SessionCollection;
 use nv_redfish::session_service::SessionCreate;
 use nv_redfish::session_service::SessionService;
@@ -75,6 +77,46 @@ async fn list_sessions() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[test]
+async fn create_session_with_credentials() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let session_service = get_session_service(bmc.clone(), &root_id).await?;
+    let sessions = get_session_collection(bmc.clone(), &session_service, json!([])).await?;
+    let sessions_id = format!("{}/Sessions", session_service.raw().odata_id());
+    let session_id = format!("{sessions_id}/1234567890ABCDEF");
+    let create = SessionCreate::builder("Administrator".into(), "password".into()).build();
+
+    bmc.expect(Expect::create_session(
+        &sessions_id,
+        serde_json::to_value(&create)?,
+        json!({
+            ODATA_ID: &session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "1234567890ABCDEF",
+            "Name": "User Session",
+            "UserName": "Administrator"
+        }),
+        "session-token-123",
+        &session_id,
+    ));
+
+    let credentials = BmcCredentials::new("Administrator".into(), "password".into());
+    let session = sessions
+        .create_session_with_credentials(&credentials)
+        .await?
+        .ok_or("expected session")?;
+    assert_eq!(session.auth_token(), Some("session-token-123"));
+
+    // Session cannot be created without password.
+    let token = BmcCredentials::token("session-token-123".into());
+    assert!(sessions
+        .create_session_with_credentials(&token)
+        .await?
+        .is_none());
+    Ok(())
+}
+
 #[test]
 async fn create_session() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -187,11 +229,65 @@ async fn delete_session_preserves_async_task() -> Result<(), Box<dyn StdError>>
     Ok(())
 }
 
+#[test]
+async fn close_sessions_of_user() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let session_service = get_session_service(bmc.clone(), &root_id).await?;
+    let sessions_id = format!("{}/Sessions", session_service.raw().odata_id());
+    let operator_session_id = format!("{sessions_id}/operator");
+    let admin_session_id = format!("{sessions_id}/admin");
+    let sessions = get_session_collection(
+        bmc.clone(),
+        &session_service,
+        json!([{
+            ODATA_ID: &operator_session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "operator",
+            "Name": "User Session",
+            "UserName": "operator",
+            "ClientOriginIPAddress": "10.0.0.7",
+            "CreatedTime": "2026-03-18T00:47:59-05:00",
+            "SessionType": "Redfish"
+        }, {
+            ODATA_ID: &admin_session_id,
+            ODATA_TYPE: SESSION_DATA_TYPE,
+            "Id": "admin",
+            "Name": "User Session",
+            "UserName": "Administrator",
+            "ClientOriginIPAddress": null,
+            "SessionType": "ManagerConsole"
+        }]),
+    )
+    .await?;
+
+    let members = sessions.members().await?;
+    assert_eq!(members.len(), 2);
+    assert_eq!(members[0].user_name(), Some("operator"));
+    assert_eq!(members[0].client_origin_ip_address(), Some("10.0.0.7"));
+    assert_eq!(
+        members[0].created_time().map(ToString::to_string),
+        Some("2026-03-18T00:47:59-05:00".into())
+    );
+    assert_eq!(members[0].session_type(), Some(SessionTypes::Redfish));
+    assert_eq!(members[1].client_origin_ip_address(), None);
+    assert_eq!(members[1].created_time(), None);
+
+    bmc.expect(Expect::delete(&operator_session_id));
+    let closed = sessions
+        .close_sessions(|session| session.user_name() == Some("operator"))
+        .await?;
+    assert_eq!(closed.len(), 1);
+    assert_empty(closed.into_iter().next().unwrap());
+
+    Ok(())
+}
+
 async fn get_session_service(
     bmc: Arc<Bmc>,
     root_id: &ODataId,
 ) -> Result<SessionService<Bmc>, Box<dyn StdError>> {
-    let session_service_id = format!("{root_id}/SessionService");
+    let session_service_id = SessionServiceSchema::WELL_KNOWN_ODATA_ID;
     let sessions_id = format!("{session_service_id}/Sessions");
     bmc.expect(Expect::get(
         root_id,
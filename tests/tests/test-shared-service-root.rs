@@ -0,0 +1,104 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests of shared service root.
+
+use nv_redfish::ServiceRoot;
+use nv_redfish::SharedServiceRoot;
+use nv_redfish_core::ODataId;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::sync::Arc;
+use tokio::test;
+
+const SERVICE_ROOT_DATA_TYPE: &str = "#ServiceRoot.v1_17_0.ServiceRoot";
+
+#[test]
+async fn refresh_replaces_changed_root_and_notifies() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, false)));
+    let shared = SharedServiceRoot::new(ServiceRoot::new(bmc.clone()).await?);
+    let mut changes = shared.subscribe();
+
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, false)));
+    assert!(shared.refresh().await?.is_none());
+    assert!(changes.try_next().is_err());
+
+    bmc.expect(Expect::get(&root_id, service_root(&root_id, true)));
+    let new_root = shared.refresh().await?.expect("change must be detected");
+    assert!(new_root.root.update_service.is_some());
+    assert!(shared.current().root.update_service.is_some());
+    let notified = changes.try_next()?.expect("subscriber must be notified");
+    assert!(notified.root.update_service.is_some());
+
+    Ok(())
+}
+
+#[test]
+async fn refresh_compares_etags_and_detects_protocol_features() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let with_etag = |etag: &str, expand: bool| {
+        let mut root = service_root(&root_id, false);
+        root["@odata.etag"] = json!(etag);
+        if expand {
+            root["ProtocolFeaturesSupported"] = json!({ "ExpandQuery": { "ExpandAll": true } });
+        }
+        root
+    };
+    bmc.expect(Expect::get(&root_id, with_etag("W/\"1\"", false)));
+    let shared = SharedServiceRoot::new(ServiceRoot::new(bmc.clone()).await?);
+    assert!(!shared
+        .current()
+        .protocol_features()
+        .supports_expand_levels(1));
+
+    bmc.expect(Expect::get(&root_id, with_etag("W/\"1\"", false)));
+    assert!(shared.refresh().await?.is_none());
+
+    // Firmware update advertised expand support.
+    bmc.expect(Expect::get(&root_id, with_etag("W/\"2\"", true)));
+    let new_root = shared.refresh().await?.expect("change must be detected");
+    assert!(new_root.protocol_features().supports_expand_levels(1));
+    assert!(shared
+        .current()
+        .protocol_features()
+        .supports_expand_levels(1));
+
+    Ok(())
+}
+
+fn service_root(root_id: &ODataId, with_update_service: bool) -> Value {
+    let mut root = json!({
+        ODATA_ID: root_id,
+        ODATA_TYPE: SERVICE_ROOT_DATA_TYPE,
+        "Id": "RootService",
+        "Name": "RootService",
+        "Links": {
+            "Sessions": {
+                ODATA_ID: format!("{root_id}/SessionService/Sessions")
+            }
+        }
+    });
+    if with_update_service {
+        root["UpdateService"] = json!({ ODATA_ID: format!("{root_id}/UpdateService") });
+    }
+    root
+}
@@ -16,24 +16,38 @@
 //! Integration tests of Task Service.
 
 use std::error::Error as StdError;
+use std::future;
 use std::io::Error as IoError;
 use std::io::ErrorKind;
 use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
 
+use futures_util::stream;
+use nv_redfish::cancellation::CancellationToken;
 use nv_redfish::core::AsyncTask;
+use nv_redfish::core::AsyncTaskResult;
 use nv_redfish::core::ODataId;
+use nv_redfish::schema::event_service::EventService;
 use nv_redfish::schema::resource::Health as TaskStatus;
 use nv_redfish::schema::task::TaskState;
+use nv_redfish::schema::task_service::TaskService;
+use nv_redfish::Error;
 use nv_redfish::ServiceRoot;
 use nv_redfish_tests::Bmc;
 use nv_redfish_tests::Expect;
 use nv_redfish_tests::ODATA_ID;
 use nv_redfish_tests::ODATA_TYPE;
 use serde_json::json;
+use serde_json::Value as JsonValue;
 use tokio::test;
 
-const TASK_SERVICE_PATH: &str = "/redfish/v1/TaskService";
+const TASK_SERVICE_PATH: &str = TaskService::WELL_KNOWN_ODATA_ID;
 const TASK_PATH: &str = "/redfish/v1/TaskService/Tasks/42";
+const TASK_MONITOR_PATH: &str = "/redfish/v1/TaskService/TaskMonitors/42";
+const EVENT_SERVICE_PATH: &str = EventService::WELL_KNOWN_ODATA_ID;
+const SSE_PATH: &str = "/redfish/v1/EventService/SSE";
 
 #[test]
 async fn task_link_fetch_exposes_schema_fields() -> Result<(), Box<dyn StdError>> {
@@ -149,3 +163,388 @@ async fn task_link_fetch_exposes_schema_fields() -> Result<(), Box<dyn StdError>
 
     Ok(())
 }
+
+fn expect_root_with_task_and_event_services(bmc: &Bmc) {
+    bmc.expect(Expect::get(
+        "/redfish/v1",
+        json!({
+            ODATA_ID: "/redfish/v1",
+            ODATA_TYPE: "#ServiceRoot.v1_13_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "Tasks": {
+                ODATA_ID: TASK_SERVICE_PATH
+            },
+            "EventService": {
+                ODATA_ID: EVENT_SERVICE_PATH
+            },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: "/redfish/v1/SessionService/Sessions"
+                }
+            }
+        }),
+    ));
+    bmc.expect(Expect::get(
+        TASK_SERVICE_PATH,
+        json!({
+            ODATA_ID: TASK_SERVICE_PATH,
+            ODATA_TYPE: "#TaskService.v1_1_4.TaskService",
+            "Id": "TaskService",
+            "Name": "Task Service",
+            "Tasks": {
+                ODATA_ID: "/redfish/v1/TaskService/Tasks"
+            }
+        }),
+    ));
+    bmc.expect(Expect::get(
+        EVENT_SERVICE_PATH,
+        json!({
+            ODATA_ID: EVENT_SERVICE_PATH,
+            ODATA_TYPE: "#EventService.v1_10_0.EventService",
+            "Id": "EventService",
+            "Name": "Event Service",
+            "ServerSentEventUri": SSE_PATH
+        }),
+    ));
+}
+
+fn task(state: &str) -> JsonValue {
+    json!({
+        ODATA_ID: TASK_PATH,
+        ODATA_TYPE: "#Task.v1_4_3.Task",
+        "Id": "42",
+        "Name": "Task 42",
+        "TaskState": state
+    })
+}
+
+fn monitored_task(state: &str) -> JsonValue {
+    let mut task = task(state);
+    task["TaskMonitor"] = json!(TASK_MONITOR_PATH);
+    task
+}
+
+fn task_event(id: &str, task_id: &str) -> JsonValue {
+    json!({
+        ODATA_ID: format!("{SSE_PATH}#/Event{id}"),
+        ODATA_TYPE: "#Event.v1_6_0.Event",
+        "Id": id,
+        "Name": "Task Event",
+        "Events": [{
+            ODATA_ID: format!("{SSE_PATH}#/Events/{id}"),
+            "MemberId": id,
+            "EventId": id,
+            "EventTimestamp": "2026-02-19T03:55:29+00:00",
+            "EventType": "Alert",
+            "MessageId": "TaskEvent.1.0.TaskCompletedOK",
+            "MessageArgs": [task_id]
+        }]
+    })
+}
+
+#[test]
+async fn task_monitor_waits_for_task_event() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_root_with_task_and_event_services(&bmc);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+    let event_service = root
+        .event_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected event service"))?;
+
+    let monitor = task_service.task_monitor(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: None,
+    })?;
+
+    bmc.expect(Expect::get(TASK_PATH, task("Running")));
+    // Event about another task must not cause fetch of the task.
+    bmc.expect(Expect::stream(
+        SSE_PATH,
+        json!([task_event("1", "41"), task_event("2", "42")]),
+    ));
+    bmc.expect(Expect::get(TASK_PATH, task("Completed")));
+
+    let task = monitor
+        .wait_via_events(&event_service, stream::empty::<()>())
+        .await?;
+    assert_eq!(task.task_state, Some(TaskState::Completed));
+
+    Ok(())
+}
+
+#[test]
+async fn task_monitor_reports_unfinished_task_when_streams_end() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_root_with_task_and_event_services(&bmc);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+    let event_service = root
+        .event_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected event service"))?;
+
+    let monitor = task_service.task_monitor(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: None,
+    })?;
+
+    bmc.expect(Expect::get(TASK_PATH, task("Running")));
+    bmc.expect(Expect::stream(SSE_PATH, json!([])));
+    // Single fallback tick polls the task.
+    bmc.expect(Expect::get(TASK_PATH, task("Running")));
+
+    let result = monitor
+        .wait_via_events(&event_service, stream::iter([()]))
+        .await;
+    assert!(matches!(result, Err(Error::TaskNotFinished(id)) if id.to_string() == TASK_PATH));
+
+    Ok(())
+}
+
+#[test]
+async fn task_monitor_waits_for_completion() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_root_with_task_and_event_services(&bmc);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    let monitor = task_service.task_monitor(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: Some(Duration::from_secs(5)),
+    })?;
+
+    let mut running = task("Running");
+    running["PercentComplete"] = json!(40);
+    bmc.expect(Expect::get(TASK_PATH, running));
+    let progress = monitor.task().await?;
+    assert_eq!(progress.state(), Some(TaskState::Running));
+    assert_eq!(progress.percent_complete(), Some(40));
+
+    let mut completed = task("Completed");
+    completed["PercentComplete"] = json!(100);
+    completed["Messages"] = json!([{
+        "MessageId": "TaskEvent.1.0.TaskCompletedOK",
+        "Message": "The task has completed successfully."
+    }]);
+    bmc.expect(Expect::get(TASK_PATH, task("Running")));
+    bmc.expect(Expect::get(TASK_PATH, completed.clone()));
+
+    let delays = Mutex::new(Vec::new());
+    let result = monitor
+        .wait_for_completion(Duration::from_secs(1), |delay| {
+            delays
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(delay);
+            future::ready(())
+        })
+        .await?;
+    // Task without task monitor has no operation result.
+    assert!(result.location.is_none());
+    assert!(result.payload.is_none());
+    // Retry-After is honored before the first poll.
+    assert_eq!(
+        *delays.lock().unwrap_or_else(PoisonError::into_inner),
+        vec![Duration::from_secs(5)]
+    );
+
+    bmc.expect(Expect::get(TASK_PATH, completed));
+    let task = monitor.task().await?;
+    assert_eq!(task.state(), Some(TaskState::Completed));
+    assert_eq!(task.percent_complete(), Some(100));
+    assert_eq!(
+        task.messages()
+            .iter()
+            .filter_map(|message| message.message.as_deref())
+            .collect::<Vec<_>>(),
+        vec!["The task has completed successfully."]
+    );
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn task_monitor_returns_operation_result() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_root_with_task_and_event_services(&bmc);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    let monitor = task_service.task_monitor(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: Some(Duration::from_secs(5)),
+    })?;
+
+    bmc.expect(Expect::get(TASK_PATH, monitored_task("Running")));
+    bmc.expect(Expect::task_monitor_running(
+        TASK_MONITOR_PATH,
+        Some(Duration::from_secs(3)),
+    ));
+    bmc.expect(Expect::task_monitor_running(TASK_MONITOR_PATH, None));
+    bmc.expect(Expect::task_monitor_finished(
+        TASK_MONITOR_PATH,
+        AsyncTaskResult {
+            location: Some(ODataId::from("/redfish/v1/Systems/1".to_string())),
+            payload: Some(json!({ "Id": "1" })),
+        },
+    ));
+    bmc.expect(Expect::get(TASK_PATH, monitored_task("Completed")));
+
+    let delays = Mutex::new(Vec::new());
+    let result = monitor
+        .wait_for_completion(Duration::from_secs(1), |delay| {
+            delays
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(delay);
+            future::ready(())
+        })
+        .await?;
+    assert_eq!(
+        result.location.map(|location| location.to_string()),
+        Some("/redfish/v1/Systems/1".to_string())
+    );
+    assert_eq!(result.payload, Some(json!({ "Id": "1" })));
+    // Retry-After of every poll is honored, poll interval is used
+    // when the poll doesn't return it.
+    assert_eq!(
+        *delays.lock().unwrap_or_else(PoisonError::into_inner),
+        vec![
+            Duration::from_secs(5),
+            Duration::from_secs(3),
+            Duration::from_secs(1)
+        ]
+    );
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn task_monitor_polls_task_after_monitor_error() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_root_with_task_and_event_services(&bmc);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    let monitor = task_service.task_monitor(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: None,
+    })?;
+
+    bmc.expect(Expect::get(TASK_PATH, monitored_task("Running")));
+    // Failed operation: the monitor returns error status and the task
+    // has the details.
+    bmc.expect(Expect::task_monitor_status(TASK_MONITOR_PATH, 400));
+    bmc.expect(Expect::get(TASK_PATH, monitored_task("Exception")));
+
+    let result = monitor
+        .wait_for_completion(Duration::ZERO, |_| future::ready(()))
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::TaskFailed { task, state, .. })
+            if task.to_string() == TASK_PATH && state == Some(TaskState::Exception)
+    ));
+    bmc.assert_consumed();
+
+    Ok(())
+}
+
+#[test]
+async fn task_monitor_reports_failed_task() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_root_with_task_and_event_services(&bmc);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    let monitor = task_service.task_monitor(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: None,
+    })?;
+
+    let mut exception = task("Exception");
+    exception["Messages"] = json!([{
+        "MessageId": "TaskEvent.1.0.TaskAborted",
+        "Message": "The task has been aborted."
+    }]);
+    bmc.expect(Expect::get(TASK_PATH, exception));
+
+    let result = monitor
+        .wait_for_completion(Duration::ZERO, |_| future::ready(()))
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::TaskFailed { task, state, messages })
+            if task.to_string() == TASK_PATH
+                && state == Some(TaskState::Exception)
+                && messages == vec!["The task has been aborted.".to_string()]
+    ));
+
+    Ok(())
+}
+
+#[test]
+async fn task_monitor_wait_is_cancellable() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    expect_root_with_task_and_event_services(&bmc);
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let task_service = root
+        .task_service()
+        .await?
+        .ok_or_else(|| IoError::new(ErrorKind::NotFound, "expected task service"))?;
+
+    let monitor = task_service.task_monitor(AsyncTask {
+        location: ODataId::from(TASK_PATH.to_string()).into(),
+        retry_after: None,
+    })?;
+
+    bmc.expect(Expect::get(TASK_PATH, task("Running")));
+
+    // Cancel while waiting for the next poll: the task is never polled
+    // again.
+    let token = CancellationToken::new();
+    let result = monitor
+        .wait_for_completion_cancellable(
+            Duration::from_secs(1),
+            |_| {
+                token.cancel();
+                future::pending()
+            },
+            &token,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::Cancelled)));
+    bmc.assert_consumed();
+
+    Ok(())
+}
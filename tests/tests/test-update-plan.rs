@@ -0,0 +1,245 @@
+// SPDX-FileCopyrightText: Copyright (c) 2026 NVIDIA CORPORATION & AFFILIATES. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Integration tests of multi-step update orchestration.
+
+use std::error::Error as StdError;
+use std::future::ready;
+use std::future::Future;
+use std::sync::Arc;
+
+use nv_redfish::core::AsyncTask;
+use nv_redfish::core::ODataId;
+use nv_redfish::schema::task::TaskState;
+use nv_redfish::update_plan::UpdateEvent;
+use nv_redfish::update_plan::UpdateHooks;
+use nv_redfish::update_plan::UpdatePlan;
+use nv_redfish::update_plan::UpdateStep;
+use nv_redfish::update_service::SimpleUpdateParameters;
+use nv_redfish::Error;
+use nv_redfish::ServiceRoot;
+use nv_redfish_tests::Bmc;
+use nv_redfish_tests::Expect;
+use nv_redfish_tests::ODATA_ID;
+use nv_redfish_tests::ODATA_TYPE;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use tokio::test;
+
+const UPDATE_SERVICE_PATH: &str = "/redfish/v1/UpdateService";
+const SIMPLE_UPDATE_PATH: &str = "/redfish/v1/UpdateService/Actions/UpdateService.SimpleUpdate";
+const TASK_SERVICE_PATH: &str = "/redfish/v1/TaskService";
+const TASK_PATH: &str = "/redfish/v1/TaskService/Tasks/7";
+const MANAGER_PATH: &str = "/redfish/v1/Managers/BMC";
+
+struct Hooks;
+
+impl UpdateHooks<Bmc> for Hooks {
+    fn reset(&self, _target: &ODataId) -> impl Future<Output = Result<(), Error<Bmc>>> + Send {
+        ready(Ok(()))
+    }
+
+    fn poll_delay(&self) -> impl Future<Output = ()> + Send {
+        ready(())
+    }
+}
+
+#[test]
+async fn update_plan_monitors_tasks_and_resets_before_dependent_steps(
+) -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone()).await?;
+    let plan = UpdatePlan::new()
+        .step(UpdateStep::simple_update("bios", image("bios.bin")).depends_on("bmc"))
+        .step(UpdateStep::simple_update("bmc", image("bmc.bin")).reset(odata_id(MANAGER_PATH)));
+    assert_eq!(plan.execution_order::<Bmc>()?, vec!["bmc", "bios"]);
+
+    expect_update_service(&bmc);
+    bmc.expect(Expect::action_task(
+        SIMPLE_UPDATE_PATH,
+        json!({ "ImageURI": "bmc.bin" }),
+        AsyncTask {
+            location: odata_id(TASK_PATH).into(),
+            retry_after: None,
+        },
+    ));
+    expect_task_service(&bmc);
+    bmc.expect(Expect::get(TASK_PATH, task("Running")));
+    bmc.expect(Expect::get(TASK_PATH, task("Completed")));
+    bmc.expect(Expect::action(
+        SIMPLE_UPDATE_PATH,
+        json!({ "ImageURI": "bios.bin" }),
+        json!(null),
+    ));
+    let report = plan.run(&root, &Hooks).await?;
+
+    assert!(report.is_complete());
+    assert_eq!(
+        report.events,
+        vec![
+            UpdateEvent::StepCompleted("bmc".into()),
+            UpdateEvent::Reset(odata_id(MANAGER_PATH)),
+            UpdateEvent::StepCompleted("bios".into()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn update_plan_rolls_back_completed_steps_on_failed_task() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root = get_root(bmc.clone()).await?;
+    let plan = UpdatePlan::new()
+        .step(UpdateStep::simple_update("bmc", image("bmc.bin")).reset(odata_id(MANAGER_PATH)))
+        .step(UpdateStep::simple_update("cpld", image("cpld.bin")));
+
+    expect_update_service(&bmc);
+    bmc.expect(Expect::action(
+        SIMPLE_UPDATE_PATH,
+        json!({ "ImageURI": "bmc.bin" }),
+        json!(null),
+    ));
+    bmc.expect(Expect::action_task(
+        SIMPLE_UPDATE_PATH,
+        json!({ "ImageURI": "cpld.bin" }),
+        AsyncTask {
+            location: odata_id(TASK_PATH).into(),
+            retry_after: None,
+        },
+    ));
+    expect_task_service(&bmc);
+    bmc.expect(Expect::get(TASK_PATH, task("Exception")));
+    let report = plan.run(&root, &Hooks).await?;
+
+    assert!(matches!(
+        report.failure,
+        Some((ref step, Error::UpdateTaskFailed {
+            state: Some(TaskState::Exception),
+            ..
+        })) if step == "cpld"
+    ));
+    // Pending reset of the completed step is not performed.
+    assert_eq!(
+        report.events,
+        vec![
+            UpdateEvent::StepCompleted("bmc".into()),
+            UpdateEvent::RolledBack("bmc".into()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+async fn update_plan_rejects_dependency_cycle() {
+    let plan = UpdatePlan::new()
+        .step(UpdateStep::start_update("a").depends_on("b"))
+        .step(UpdateStep::start_update("b").depends_on("a"));
+    assert!(matches!(
+        plan.execution_order::<Bmc>(),
+        Err(Error::UpdatePlanCycle(step)) if step == "a"
+    ));
+
+    let plan = UpdatePlan::new().step(UpdateStep::start_update("a").depends_on("c"));
+    assert!(matches!(
+        plan.execution_order::<Bmc>(),
+        Err(Error::UpdatePlanUnknownDependency { step, dependency })
+            if step == "a" && dependency == "c"
+    ));
+}
+
+async fn get_root(bmc: Arc<Bmc>) -> Result<ServiceRoot<Bmc>, Box<dyn StdError>> {
+    bmc.expect(Expect::get(
+        "/redfish/v1",
+        json!({
+            ODATA_ID: "/redfish/v1",
+            ODATA_TYPE: "#ServiceRoot.v1_13_0.ServiceRoot",
+            "Id": "RootService",
+            "Name": "Root Service",
+            "UpdateService": {
+                ODATA_ID: UPDATE_SERVICE_PATH
+            },
+            "Tasks": {
+                ODATA_ID: TASK_SERVICE_PATH
+            },
+            "Links": {
+                "Sessions": {
+                    ODATA_ID: "/redfish/v1/SessionService/Sessions"
+                }
+            }
+        }),
+    ));
+    Ok(ServiceRoot::new(bmc).await?)
+}
+
+fn expect_update_service(bmc: &Bmc) {
+    bmc.expect(Expect::get(
+        UPDATE_SERVICE_PATH,
+        json!({
+            ODATA_ID: UPDATE_SERVICE_PATH,
+            ODATA_TYPE: "#UpdateService.v1_9_0.UpdateService",
+            "Id": "UpdateService",
+            "Name": "Update Service",
+            "Actions": {
+                "#UpdateService.SimpleUpdate": {
+                    "target": SIMPLE_UPDATE_PATH
+                }
+            }
+        }),
+    ));
+}
+
+fn expect_task_service(bmc: &Bmc) {
+    bmc.expect(Expect::get(
+        TASK_SERVICE_PATH,
+        json!({
+            ODATA_ID: TASK_SERVICE_PATH,
+            ODATA_TYPE: "#TaskService.v1_1_4.TaskService",
+            "Id": "TaskService",
+            "Name": "Task Service",
+            "Tasks": {
+                ODATA_ID: "/redfish/v1/TaskService/Tasks"
+            }
+        }),
+    ));
+}
+
+fn task(state: &str) -> JsonValue {
+    json!({
+        ODATA_ID: TASK_PATH,
+        ODATA_TYPE: "#Task.v1_4_3.Task",
+        "Id": "7",
+        "Name": "Task 7",
+        "TaskState": state
+    })
+}
+
+fn image(uri: &str) -> SimpleUpdateParameters {
+    SimpleUpdateParameters {
+        image_uri: Some(uri.into()),
+        transfer_protocol: None,
+        targets: None,
+        username: None,
+        password: None,
+        force_update: None,
+        stage: None,
+        local_image: None,
+        exclude_targets: None,
+    }
+}
+
+fn odata_id(id: &str) -> ODataId {
+    ODataId::from(id.to_string())
+}
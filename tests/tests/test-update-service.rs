@@ -22,6 +22,7 @@ use std::time::Duration;
 use futures_util::io::Cursor;
 #[cfg(feature = "update-service-deprecated")]
 use nv_redfish::schema::update_service::HttpPushUriOptionsUpdate;
+use nv_redfish::schema::update_service::UpdateService as UpdateServiceSchema;
 use nv_redfish::update_service::MultipartUpdateParameters;
 use nv_redfish::update_service::UpdateService;
 #[cfg(feature = "update-service-deprecated")]
@@ -48,7 +49,7 @@ const UPDATE_SERVICE_DATA_TYPE: &str = "#UpdateService.v1_9_0.UpdateService";
 const SW_INVENTORIES_DATA_TYPE: &str = "#SoftwareInventoryCollection.SoftwareInventoryCollection";
 const SW_INVENTORY_DATA_TYPE: &str = "#SoftwareInventory.v1_4_0.SoftwareInventory";
 
-const UPDATE_SERVICE_URI: &str = "/redfish/v1/UpdateService";
+const UPDATE_SERVICE_URI: &str = UpdateServiceSchema::WELL_KNOWN_ODATA_ID;
 #[cfg(feature = "update-service-deprecated")]
 const HTTP_PUSH_URI: &str = "/redfish/v1/UpdateService/update";
 const MULTIPART_URI: &str = "/redfish/v1/UpdateService/update-multipart";
@@ -121,7 +122,7 @@ async fn list_dell_fw_inventores() -> Result<(), Box<dyn StdError>> {
 async fn ami_viking_missing_root_update_service_nav_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
     let root_id = ODataId::service_root();
-    let update_service_id = format!("{root_id}/UpdateService");
+    let update_service_id = UPDATE_SERVICE_URI;
     let fw_inventory_id = format!("{update_service_id}/FirmwareInventory");
 
     bmc.expect(Expect::get(
@@ -153,7 +154,7 @@ async fn ami_viking_missing_root_update_service_nav_workaround() -> Result<(), B
 async fn ami_viking_missing_update_service_name_workaround() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
     let root_id = ODataId::service_root();
-    let update_service_id = format!("{root_id}/UpdateService");
+    let update_service_id = UPDATE_SERVICE_URI;
 
     bmc.expect(Expect::get(
         &root_id,
@@ -188,7 +189,7 @@ async fn get_update_service(
     root_id: &ODataId,
     vendor: &str,
 ) -> Result<UpdateService<Bmc>, Box<dyn StdError>> {
-    let update_service_id = format!("{root_id}/UpdateService");
+    let update_service_id = UPDATE_SERVICE_URI;
     let data_type = "#ServiceRoot.v1_13_0.ServiceRoot";
     bmc.expect(Expect::get(
         &root_id,
@@ -282,6 +283,62 @@ async fn uses_multipart_http_push_uri() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[tokio::test]
+async fn simple_update_multipart_returns_created_task() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+
+    bmc.expect(Expect::get("/redfish/v1", service_root_json()));
+    bmc.expect(Expect::get(
+        UPDATE_SERVICE_URI,
+        update_service_json(Some(MULTIPART_URI)),
+    ));
+
+    let root = ServiceRoot::new(Arc::clone(&bmc)).await?;
+    let update_service = root
+        .update_service()
+        .await?
+        .ok_or("expected update service")?;
+    let parameters = MultipartUpdateParameters::builder()
+        .with_force_update(true)
+        .build();
+    let firmware = || {
+        DataStream::new("firmware.bin", Cursor::new(b"firmware".to_vec())).with_content_length(8)
+    };
+
+    bmc.expect(Expect::multipart_update(
+        MULTIPART_URI,
+        json!({ "ForceUpdate": true }),
+        "firmware.bin",
+        json!({
+            "@odata.id": "/redfish/v1/TaskService/Tasks/42",
+            "@odata.type": "#Task.v1_4_3.Task",
+            "Id": "42"
+        }),
+    ));
+    let task = update_service
+        .simple_update_multipart(&parameters, firmware(), Duration::from_secs(600))
+        .await?;
+    assert_eq!(
+        task.location.0,
+        ODataId::from("/redfish/v1/TaskService/Tasks/42".to_string())
+    );
+
+    bmc.expect(Expect::multipart_update(
+        MULTIPART_URI,
+        json!({ "ForceUpdate": true }),
+        "firmware.bin",
+        json!({
+            "@Message.ExtendedInfo": []
+        }),
+    ));
+    let result = update_service
+        .simple_update_multipart(&parameters, firmware(), Duration::from_secs(600))
+        .await;
+    assert!(matches!(result, Err(Error::UpdateServiceTaskNotReturned)));
+
+    Ok(())
+}
+
 #[cfg(feature = "update-service-deprecated")]
 #[tokio::test]
 async fn uses_http_push_uri_without_update_parameters() -> Result<(), Box<dyn StdError>> {
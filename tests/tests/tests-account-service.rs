@@ -24,6 +24,10 @@ use nv_redfish::account::AccountService;
 use nv_redfish::account::AccountTypes;
 use nv_redfish::account::ManagerAccountCreate;
 use nv_redfish::account::ManagerAccountUpdate;
+use nv_redfish::account::MfaBypassType;
+use nv_redfish::account::ServiceAccountPasswordPolicy;
+use nv_redfish::schema::account_service::AccountService as AccountServiceSchema;
+use nv_redfish::Resource as _;
 use nv_redfish::ServiceRoot;
 use nv_redfish_core::AsyncTask;
 use nv_redfish_core::EntityTypeRef;
@@ -42,6 +46,7 @@ use tokio::test;
 const ACCOUNT_SERVICE_DATA_TYPE: &str = "#AccountService.v1_5_0.AccountService";
 const ACCOUNTS_DATA_TYPE: &str = "#ManagerAccountCollection.ManagerAccountCollection";
 const MANAGER_ACCOUNT_DATA_TYPE: &str = "#ManagerAccount.v1_3_0.ManagerAccount";
+const MANAGER_SERVICE_ACCOUNT_DATA_TYPE: &str = "#ManagerAccount.v1_8_0.ManagerAccount";
 
 type TestResult<T> = Result<T, Box<dyn StdError>>;
 
@@ -126,6 +131,34 @@ async fn list_accounts() -> Result<(), Box<dyn StdError>> {
     Ok(())
 }
 
+#[test]
+async fn account_capabilities() -> Result<(), Box<dyn StdError>> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service(bmc.clone(), &root_id, "Contoso").await?;
+    let maccount_id = format!("{}/Accounts/1", account_service.raw().odata_id());
+    let accounts = get_account_collection(
+        bmc.clone(),
+        &account_service,
+        json! {[{
+            ODATA_ID: maccount_id,
+            ODATA_TYPE: MANAGER_ACCOUNT_DATA_TYPE,
+            "Id": "1",
+            "Name": "User Account",
+            "UserName": "Administrator",
+            "RoleId": "AdministratorRole",
+            "AccountTypes": []
+        }]},
+    )
+    .await?;
+    let accounts = accounts.all_accounts_data().await?;
+    let capabilities = accounts.first().unwrap().capabilities();
+    assert!(!capabilities.insertable);
+    assert!(capabilities.updatable);
+    assert!(capabilities.deletable);
+    Ok(())
+}
+
 #[test]
 async fn list_hpe_accounts() -> Result<(), Box<dyn StdError>> {
     let bmc = Arc::new(Bmc::default());
@@ -181,7 +214,16 @@ async fn get_account_service(
     root_id: &ODataId,
     vendor: &str,
 ) -> Result<AccountService<Bmc>, Box<dyn StdError>> {
-    let account_service_id = format!("{root_id}/AccountService");
+    get_account_service_with(bmc, root_id, vendor, json!({})).await
+}
+
+async fn get_account_service_with(
+    bmc: Arc<Bmc>,
+    root_id: &ODataId,
+    vendor: &str,
+    service_fields: JsonValue,
+) -> Result<AccountService<Bmc>, Box<dyn StdError>> {
+    let account_service_id = AccountServiceSchema::WELL_KNOWN_ODATA_ID;
     let data_type = "#ServiceRoot.v1_13_0.ServiceRoot";
 
     bmc.expect(Expect::get(
@@ -212,15 +254,18 @@ async fn get_account_service(
     let accounts_id = format!("{account_service_id}/Accounts");
     bmc.expect(Expect::get(
         &account_service_id,
-        json!({
-            ODATA_ID: &account_service_id,
-            ODATA_TYPE: &ACCOUNT_SERVICE_DATA_TYPE,
-            "Id": "AccountService",
-            "Name": "AccountService",
-            "Accounts": {
-                ODATA_ID: &accounts_id,
-            },
-        }),
+        json_merge([
+            &json!({
+                ODATA_ID: &account_service_id,
+                ODATA_TYPE: &ACCOUNT_SERVICE_DATA_TYPE,
+                "Id": "AccountService",
+                "Name": "AccountService",
+                "Accounts": {
+                    ODATA_ID: &accounts_id,
+                },
+            }),
+            &service_fields,
+        ]),
     ));
     Ok(service_root.account_service().await?.unwrap())
 }
@@ -403,6 +448,175 @@ async fn create_account_hpe_patched() -> TestResult<()> {
     Ok(())
 }
 
+// Create service account (standard vendor with multi-factor
+// authentication): request restricts account to Redfish, doesn't
+// require password change and bypasses multi-factor authentication.
+#[test]
+async fn create_service_account_standard() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service_with(
+        bmc.clone(),
+        &root_id,
+        "Contoso",
+        json!({
+            "MultiFactorAuth": {
+                "GoogleAuthenticator": {
+                    "Enabled": true
+                }
+            }
+        }),
+    )
+    .await?;
+    let accounts = get_account_collection(bmc.clone(), &account_service, json!([])).await?;
+    let accounts_id = accounts.odata_id().to_string();
+
+    bmc.expect(Expect::create(
+        &accounts_id,
+        json!({
+            "UserName": "svc",
+            "Password": "password",
+            "RoleId": "Operator",
+            "AccountTypes": ["Redfish"],
+            "PasswordChangeRequired": false,
+            "MFABypass": {
+                "BypassTypes": ["All"]
+            }
+        }),
+        json!({
+            ODATA_ID: format!("{accounts_id}/1"),
+            ODATA_TYPE: MANAGER_SERVICE_ACCOUNT_DATA_TYPE,
+            "Id": "1",
+            "Name": "User Account",
+            "UserName": "svc",
+            "RoleId": "Operator",
+            "AccountTypes": ["Redfish"],
+            "MFABypass": {
+                "BypassTypes": ["All"]
+            }
+        }),
+    ));
+
+    let account = into_entity(
+        accounts
+            .create_service_account(
+                "svc".into(),
+                "Operator".into(),
+                ServiceAccountPasswordPolicy::NeverExpires("password".into()),
+            )
+            .await?,
+    );
+
+    assert_eq!(account.account_types(), &[AccountTypes::Redfish]);
+    assert_eq!(account.mfa_bypass_types(), &[MfaBypassType::All]);
+
+    Ok(())
+}
+
+// Create service account (HPE-like vendor): only basic properties and
+// password expiration are sent.
+#[test]
+async fn create_service_account_hpe_basic_properties() -> TestResult<()> {
+    let (bmc, accounts_id, accounts) = account_fixture("HPE", &[]).await?;
+
+    bmc.expect(Expect::create(
+        &accounts_id,
+        json!({
+            "UserName": "svc",
+            "Password": "password",
+            "RoleId": "Operator",
+            "PasswordExpiration": "2027-01-01T00:00:00Z"
+        }),
+        json!({
+            ODATA_ID: format!("{accounts_id}/1"),
+            ODATA_TYPE: MANAGER_ACCOUNT_DATA_TYPE,
+            "Id": "1",
+            "Name": "User Account",
+            "UserName": "svc",
+            "RoleId": "Operator"
+        }),
+    ));
+
+    let account = into_entity(
+        accounts
+            .create_service_account(
+                "svc".into(),
+                "Operator".into(),
+                ServiceAccountPasswordPolicy::ExpiresAt(
+                    "password".into(),
+                    "2027-01-01T00:00:00Z".parse()?,
+                ),
+            )
+            .await?,
+    );
+
+    assert_eq!(account.account_types(), &[AccountTypes::Redfish]);
+    assert!(account.mfa_bypass_types().is_empty());
+
+    Ok(())
+}
+
+// Create service account (standard vendor with multi-factor
+// authentication disabled): request doesn't bypass multi-factor
+// authentication.
+#[test]
+async fn create_service_account_without_enabled_mfa() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service_with(
+        bmc.clone(),
+        &root_id,
+        "Contoso",
+        json!({
+            "MultiFactorAuth": {
+                "GoogleAuthenticator": {
+                    "Enabled": false
+                },
+                "ClientCertificate": {
+                    "Enabled": false
+                }
+            }
+        }),
+    )
+    .await?;
+    let accounts = get_account_collection(bmc.clone(), &account_service, json!([])).await?;
+    let accounts_id = accounts.odata_id().to_string();
+
+    bmc.expect(Expect::create(
+        &accounts_id,
+        json!({
+            "UserName": "svc",
+            "Password": "password",
+            "RoleId": "Operator",
+            "AccountTypes": ["Redfish"],
+            "PasswordChangeRequired": false
+        }),
+        json!({
+            ODATA_ID: format!("{accounts_id}/1"),
+            ODATA_TYPE: MANAGER_SERVICE_ACCOUNT_DATA_TYPE,
+            "Id": "1",
+            "Name": "User Account",
+            "UserName": "svc",
+            "RoleId": "Operator",
+            "AccountTypes": ["Redfish"]
+        }),
+    ));
+
+    let account = into_entity(
+        accounts
+            .create_service_account(
+                "svc".into(),
+                "Operator".into(),
+                ServiceAccountPasswordPolicy::NeverExpires("password".into()),
+            )
+            .await?,
+    );
+
+    assert!(account.mfa_bypass_types().is_empty());
+
+    Ok(())
+}
+
 // Create account (Dell slot-defined): choose first disabled slot with id >= min_slot (3).
 #[test]
 async fn create_account_dell_slot_defined_first_available() -> TestResult<()> {
@@ -575,3 +789,60 @@ async fn list_dell_accounts_hide_disabled() -> TestResult<()> {
 
     Ok(())
 }
+
+#[test]
+async fn account_keys_are_listed_and_removed() -> TestResult<()> {
+    let bmc = Arc::new(Bmc::default());
+    let root_id = ODataId::service_root();
+    let account_service = get_account_service(bmc.clone(), &root_id, "Contoso").await?;
+    let account_id = format!("{}/Accounts/1", account_service.raw().odata_id());
+    let keys_id = format!("{account_id}/Keys");
+    let key_id = format!("{keys_id}/1");
+    let accounts = get_account_collection(
+        bmc.clone(),
+        &account_service,
+        json!([{
+            ODATA_ID: &account_id,
+            ODATA_TYPE: "#ManagerAccount.v1_11_0.ManagerAccount",
+            "Id": "1",
+            "Name": "User Account",
+            "UserName": "Administrator",
+            "AccountTypes": ["Redfish", "ManagerConsole"],
+            "Keys": { ODATA_ID: &keys_id }
+        }]),
+    )
+    .await?;
+    let account = accounts
+        .all_accounts_data()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or("missing account")?;
+    assert!(account.certificates().await?.is_none());
+
+    bmc.expect(Expect::expand(
+        &keys_id,
+        json!({
+            ODATA_ID: &keys_id,
+            ODATA_TYPE: "#KeyCollection.KeyCollection",
+            "Name": "Keys",
+            "Members": [{
+                ODATA_ID: &key_id,
+                ODATA_TYPE: "#Key.v1_0_0.Key",
+                "Id": "1",
+                "Name": "SSH Key",
+                "KeyType": "SSH",
+                "KeyString": "ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIB admin@example"
+            }]
+        }),
+    ));
+    let keys = account.keys().await?.ok_or("missing keys")?;
+    assert_eq!(keys.len(), 1);
+    let key = keys.first().ok_or("missing key")?;
+    assert_eq!(key.raw().base.id, "1");
+
+    bmc.expect(Expect::delete(&key_id));
+    assert_empty(key.delete().await?);
+
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use nv_redfish_bmc_mock::Error as BmcError;
 use nv_redfish_core::Creatable;
 use nv_redfish_core::EntityTypeRef;
 use nv_redfish_core::ModificationResponse;
@@ -469,6 +470,75 @@ async fn update_rigid_array_property_test() -> Result<(), Error> {
     Ok(())
 }
 
+// Check request matchers: partial body, If-Match and ordered
+// sequence of expectations.
+#[test]
+async fn update_request_matchers_test() -> Result<(), Error> {
+    let bmc = Bmc::default();
+    let data_type = "ServiceRoot.v1_0_0.ServiceRoot";
+    let updatable_name = "Updatable";
+    let updatable_guid_name = "UpdatableGuid";
+    let root_id = ODataId::service_root();
+    let etag = "W/\"1\"";
+    let root_json = json!({
+        ODATA_ID: &root_id,
+        ODATA_TYPE: &data_type,
+        "@odata.etag": etag,
+    });
+    bmc.expect(Expect::get(root_id.clone(), &root_json));
+    let service_root = get_service_root(&bmc).await.map_err(Error::Bmc)?;
+
+    let uuid_str = "a1a2a3a4-b1b2-c1c2-d1d2-d3d4d5d6d7d8";
+    let update = ServiceRootUpdate {
+        updatable: Some("Value".into()),
+        read_only_complex: None,
+        rigid_array_values: None,
+        updatable_guid: Some(uuid_str.parse().expect("uuid must be parsed")),
+        write_only: None,
+    };
+    bmc.expect_sequence([
+        Expect::update(
+            root_id.clone(),
+            json!({ updatable_name: "Value" }),
+            &root_json,
+        )
+        .partial_body()
+        .with_if_match(Some(etag)),
+        Expect::update(
+            root_id.clone(),
+            json!({ updatable_name: "Value", updatable_guid_name: uuid_str }),
+            &root_json,
+        )
+        .with_if_match(None),
+    ]);
+    let response = service_root
+        .update(&bmc, &update)
+        .await
+        .map_err(Error::Bmc)?;
+    assert!(matches!(response, ModificationResponse::Entity(_)));
+    let err = service_root
+        .update(&bmc, &update)
+        .await
+        .expect_err("If-Match must not match");
+    assert!(matches!(err, BmcError::UnexpectedIfMatch(_, Some(_), None)));
+    bmc.assert_consumed();
+
+    // Mismatched body is reported with JSON pointer to the field.
+    bmc.expect(Expect::update(
+        root_id.clone(),
+        json!({ updatable_name: "Other" }),
+        &root_json,
+    ));
+    let err = service_root
+        .update(&bmc, &update)
+        .await
+        .expect_err("body must not match");
+    assert!(err
+        .to_string()
+        .contains(r#"/Updatable: expected "Other", got "Value""#));
+    Ok(())
+}
+
 // Check that write only is not generated in read structures.
 #[test]
 async fn no_write_only_in_read_struct() {